@@ -157,6 +157,21 @@ fn main() -> anyhow::Result<()> {
 
     remove_useless_comments(&mirror_out_dir.join("proto.rs"))?;
 
+    // block node
+    // the block node API is still changing, so it's only built with the `unstable` feature.
+    if env::var_os("CARGO_FEATURE_UNSTABLE").is_some() {
+        // NOTE: must be compiled in a separate folder otherwise it will overwrite the previous build
+        let block_out_dir = Path::new(&env::var("OUT_DIR")?).join("block");
+        create_dir_all(&block_out_dir)?;
+
+        tonic_build::configure()
+            .build_server(false)
+            .out_dir(&block_out_dir)
+            .compile_protos(&["./block/block_service.proto"], &["./block/"])?;
+
+        remove_useless_comments(&block_out_dir.join("org.hiero.block.api.rs"))?;
+    }
+
     // sdk
     // NOTE: must be compiled in a separate folder otherwise it will overwrite the previous build
     let sdk_out_dir = Path::new(&env::var("OUT_DIR")?).join("sdk");
@@ -21,6 +21,13 @@ pub mod mirror {
     tonic::include_proto!("mirror/com.hedera.mirror.api.proto");
 }
 
+// fixme: Do this, just, don't warn 70 times in generated code.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[cfg(feature = "unstable")]
+pub mod block {
+    tonic::include_proto!("block/org.hiero.block.api");
+}
+
 // fixme: Do this, just, don't warn 70 times in generated code.
 #[allow(clippy::derive_partial_eq_without_eq)]
 pub mod sdk {
@@ -12,7 +12,9 @@ use crate::transaction::{
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -21,6 +23,7 @@ use crate::{
     Hbar,
     NftId,
     ToProtobuf,
+    TokenAmount,
     TokenId,
     Transaction,
     ValidateChecksums,
@@ -88,6 +91,36 @@ impl AccountAllowanceApproveTransaction {
         self
     }
 
+    /// Approves a token allowance of `amount`.
+    ///
+    /// Unlike [`approve_token_allowance`](Self::approve_token_allowance),
+    /// the amount carries the token's decimals, so it can be built from whole tokens with [`TokenAmount::from_decimal`].
+    ///
+    /// The allowance is approved in the smallest denomination of the token ([`TokenAmount::get_amount`]).
+    /// Allowances have no expected decimals, so unlike [`TransferTransaction::token_amount_transfer`](crate::TransferTransaction::token_amount_transfer),
+    /// the network can't reject an amount built with the wrong decimals;
+    /// build it with [`TokenAmount::from_token_info`] to be sure they match.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `amount` is larger than [`i64::MAX`].
+    pub fn approve_token_amount_allowance(
+        &mut self,
+        token_id: TokenId,
+        owner_account_id: AccountId,
+        spender_account_id: AccountId,
+        amount: TokenAmount,
+    ) -> crate::Result<&mut Self> {
+        // checked, since the allowance is sent as an `i64`.
+        amount.to_i64()?;
+
+        Ok(self.approve_token_allowance(
+            token_id,
+            owner_account_id,
+            spender_account_id,
+            amount.get_amount(),
+        ))
+    }
+
     /// Returns the fungible token allowances approved by the account owner
     pub fn token_approvals(&self) -> &[TokenAllowance] {
         self.data().token_allowances.as_ref()
@@ -202,7 +235,52 @@ pub struct NftAllowance {
     pub delegating_spender_account_id: Option<AccountId>,
 }
 
-impl TransactionData for AccountAllowanceApproveTransactionData {}
+impl TransactionData for AccountAllowanceApproveTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        if self.hbar_allowances.is_empty()
+            && self.token_allowances.is_empty()
+            && self.nft_allowances.is_empty()
+        {
+            problems.push(ValidationProblem::MissingField { field: "allowances" });
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        for it in &self.hbar_allowances {
+            description.field(
+                "hbar_allowance",
+                format!("{} to {}, {}", it.owner_account_id, it.spender_account_id, it.amount),
+            );
+        }
+
+        for it in &self.token_allowances {
+            description.field(
+                "token_allowance",
+                format!(
+                    "{} to {}, {} of token {}",
+                    it.owner_account_id, it.spender_account_id, it.amount, it.token_id
+                ),
+            );
+        }
+
+        for it in &self.nft_allowances {
+            let serials = if it.approved_for_all == Some(true) {
+                "all serials".to_owned()
+            } else {
+                let serials: Vec<_> = it.serials.iter().map(ToString::to_string).collect();
+                format!("serials {}", serials.join(", "))
+            };
+
+            description.field(
+                "nft_allowance",
+                format!(
+                    "{} to {}, {serials} of token {}",
+                    it.owner_account_id, it.spender_account_id, it.token_id
+                ),
+            );
+        }
+    }
+}
 
 impl TransactionExecute for AccountAllowanceApproveTransactionData {
     fn execute(
@@ -382,6 +460,7 @@ mod tests {
         AccountId,
         AnyTransaction,
         Hbar,
+        TokenAmount,
         TokenId,
     };
 
@@ -684,4 +763,28 @@ mod tests {
         assert!(!tx.token_approvals().is_empty());
         assert!(!tx.token_approvals().is_empty());
     }
+
+    #[test]
+    fn approve_token_amount_allowance() {
+        let mut tx = AccountAllowanceApproveTransaction::new();
+        tx.approve_token_amount_allowance(
+            TokenId::new(0, 0, 5),
+            AccountId::new(0, 0, 7),
+            AccountId::new(0, 0, 8),
+            TokenAmount::from_decimal("100.55".parse().unwrap(), 2).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(tx.token_approvals()[0].amount, 10055);
+
+        let result = tx.approve_token_amount_allowance(
+            TokenId::new(0, 0, 5),
+            AccountId::new(0, 0, 7),
+            AccountId::new(0, 0, 8),
+            TokenAmount::new(u64::MAX, 0),
+        );
+
+        assert!(matches!(result, Err(crate::Error::BasicParse(_))));
+        assert_eq!(tx.token_approvals().len(), 1);
+    }
 }
@@ -15,7 +15,9 @@ use crate::transaction::{
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -82,7 +84,29 @@ impl AccountAllowanceDeleteTransaction {
     }
 }
 
-impl TransactionData for AccountAllowanceDeleteTransactionData {}
+impl TransactionData for AccountAllowanceDeleteTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        if self.nft_allowances.is_empty() {
+            problems.push(ValidationProblem::MissingField { field: "nft_allowances" });
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        for it in &self.nft_allowances {
+            let serials: Vec<_> = it.serials.iter().map(ToString::to_string).collect();
+
+            description.field(
+                "removed_nft_allowance",
+                format!(
+                    "{}, serials {} of token {}",
+                    it.owner_account_id,
+                    serials.join(", "),
+                    it.token_id
+                ),
+            );
+        }
+    }
+}
 
 impl TransactionExecute for AccountAllowanceDeleteTransactionData {
     fn execute(
@@ -43,6 +43,46 @@ impl AccountBalance {
         FromProtobuf::<services::CryptoGetAccountBalanceResponse>::from_bytes(bytes)
     }
 
+    /// Returns the decimals of the token `token_id`, if known.
+    ///
+    /// Consensus nodes no longer return token balances,
+    /// see [`fetch_token_balances`](Self::fetch_token_balances) to get them from the mirror node instead.
+    #[must_use]
+    #[allow(deprecated)]
+    pub fn get_token_decimals(&self, token_id: TokenId) -> Option<u32> {
+        self.token_decimals.get(&token_id).copied()
+    }
+
+    /// Returns the balance of the token `token_id` in whole units, IE, `"12.345"` for a balance of `12345` with 3 decimals.
+    ///
+    /// Returns `None` if the balance or the decimals of the token aren't known.
+    #[must_use]
+    pub fn format_token_balance(&self, token_id: TokenId) -> Option<String> {
+        let balance = *self.tokens.get(&token_id)?;
+
+        Some(format_token_amount(balance, self.get_token_decimals(token_id)?))
+    }
+
+    /// Replaces the token balances and decimals of `self` with the ones from the mirror node,
+    /// following pagination, for accounts with many token associations.
+    ///
+    /// # Errors
+    /// - Any error [`AccountTokenBalancesQuery::execute`](crate::AccountTokenBalancesQuery::execute) can return.
+    #[cfg(feature = "mirror-rest")]
+    #[allow(deprecated)]
+    pub async fn fetch_token_balances(&mut self, client: &crate::Client) -> crate::Result<()> {
+        let balances = crate::AccountTokenBalancesQuery::new()
+            .account_id(self.account_id)
+            .execute(client)
+            .await?;
+
+        self.tokens = balances.iter().map(|it| (it.token_id, it.balance)).collect();
+
+        self.token_decimals = balances.iter().map(|it| (it.token_id, it.decimals)).collect();
+
+        Ok(())
+    }
+
     /// Convert `self` to a protobuf-encoded [`Vec<u8>`].
     #[must_use]
     pub fn to_bytes(&self) -> Vec<u8> {
@@ -57,6 +97,25 @@ impl AccountBalance {
     }
 }
 
+/// The most decimals a token can have.
+pub(crate) const MAX_TOKEN_DECIMALS: u32 = 18;
+
+/// Formats `amount` of a token with the given `decimals` in whole units, IE, `"0.005"` for `5` with 3 decimals.
+///
+/// `decimals` is clamped to [`MAX_TOKEN_DECIMALS`], so an invalid value can't make the width of the output unbounded.
+pub(crate) fn format_token_amount(amount: u64, decimals: u32) -> String {
+    let decimals = decimals.min(MAX_TOKEN_DECIMALS) as usize;
+
+    if decimals == 0 {
+        return amount.to_string();
+    }
+
+    let digits = format!("{amount:0>width$}", width = decimals + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals);
+
+    format!("{whole}.{fraction}")
+}
+
 impl FromProtobuf<services::CryptoGetAccountBalanceResponse> for AccountBalance {
     #[allow(deprecated)]
     fn from_protobuf(pb: services::CryptoGetAccountBalanceResponse) -> crate::Result<Self>
@@ -89,3 +148,44 @@ impl FromProtobuf<services::response::Response> for AccountBalance {
         Self::from_protobuf(response)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::format_token_amount;
+    use crate::{
+        AccountBalance,
+        AccountId,
+        Hbar,
+        TokenId,
+    };
+
+    #[test]
+    fn format_amount() {
+        assert_eq!(format_token_amount(12345, 0), "12345");
+        assert_eq!(format_token_amount(12345, 3), "12.345");
+        assert_eq!(format_token_amount(5, 3), "0.005");
+        assert_eq!(format_token_amount(0, 2), "0.00");
+        assert_eq!(format_token_amount(u64::MAX, 8), "184467440737.09551615");
+        assert_eq!(format_token_amount(5, 18), "0.000000000000000005");
+        assert_eq!(format_token_amount(5, u32::MAX), "0.000000000000000005");
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn format_token_balance() {
+        let token_id = TokenId::new(0, 0, 5005);
+
+        let balance = AccountBalance {
+            account_id: AccountId::new(0, 0, 1001),
+            hbars: Hbar::new(1),
+            tokens: HashMap::from([(token_id, 150)]),
+            token_decimals: HashMap::from([(token_id, 2)]),
+        };
+
+        assert_eq!(balance.get_token_decimals(token_id), Some(2));
+        assert_eq!(balance.format_token_balance(token_id).as_deref(), Some("1.50"));
+        assert_eq!(balance.format_token_balance(TokenId::new(0, 0, 5006)), None);
+    }
+}
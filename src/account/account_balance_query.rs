@@ -18,6 +18,7 @@ use crate::{
     BoxGrpcFuture,
     ContractId,
     Error,
+    FromProtobuf,
     ToProtobuf,
     ValidateChecksums,
 };
@@ -107,6 +108,21 @@ impl ToQueryProtobuf for AccountBalanceQueryData {
     }
 }
 
+impl FromProtobuf<services::CryptoGetAccountBalanceQuery> for AccountBalanceQueryData {
+    fn from_protobuf(pb: services::CryptoGetAccountBalanceQuery) -> crate::Result<Self> {
+        let source = match pb_getf!(pb, balance_source)? {
+            BalanceSource::AccountId(id) => {
+                AccountBalanceSource::AccountId(AccountId::from_protobuf(id)?)
+            }
+            BalanceSource::ContractId(id) => {
+                AccountBalanceSource::ContractId(ContractId::from_protobuf(id)?)
+            }
+        };
+
+        Ok(Self { source })
+    }
+}
+
 impl QueryExecute for AccountBalanceQueryData {
     type Response = AccountBalance;
 
@@ -5,6 +5,7 @@ use hedera_proto::services::crypto_service_client::CryptoServiceClient;
 use time::Duration;
 use tonic::transport::Channel;
 
+use super::multisig_account::threshold_key_list;
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::{
     FromProtobuf,
@@ -12,12 +13,16 @@ use crate::protobuf::{
 };
 use crate::staked_id::StakedId;
 use crate::transaction::{
+    check_key,
+    check_memo,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -26,6 +31,7 @@ use crate::{
     EvmAddress,
     Hbar,
     Key,
+    PublicKey,
     Transaction,
     ValidateChecksums,
 };
@@ -135,6 +141,36 @@ impl AccountCreateTransaction {
         self
     }
 
+    /// Sets an ECDSA(secp256k1) public key as the account key, and the EVM address derived from it as the alias.
+    ///
+    /// Unlike [`set_ecdsa_key_with_alias`](Self::set_ecdsa_key_with_alias), only the public key is needed,
+    /// IE, when the private key stays in the user's wallet.
+    ///
+    /// # Errors
+    /// - [`Error::KeyParse`](crate::Error::KeyParse) if `ecdsa_key` isn't an ECDSA(secp256k1) key.
+    pub fn key_with_alias(&mut self, ecdsa_key: PublicKey) -> crate::Result<&mut Self> {
+        let evm_address = ecdsa_key.to_evm_address().ok_or_else(|| {
+            crate::Error::key_parse("Provided key is not an ECDSA(secp256k1) public key")
+        })?;
+
+        self.data_mut().key = Some(ecdsa_key.into());
+        Ok(self.alias(evm_address))
+    }
+
+    /// Sets the key for this account to a threshold key, where `threshold` of `keys` must sign.
+    ///
+    /// Like [`set_key_without_alias`](Self::set_key_without_alias), this clears any alias.
+    ///
+    /// # Panics
+    /// - If `threshold` is `0` or greater than the number of keys.
+    pub fn with_threshold_key(
+        &mut self,
+        keys: impl IntoIterator<Item = impl Into<Key>>,
+        threshold: u32,
+    ) -> &mut Self {
+        self.set_key_without_alias(threshold_key_list(keys, threshold))
+    }
+
     /// Get the key this account will be created with.
     ///
     /// Returns `Some(key)` if previously set, `None` otherwise.
@@ -295,7 +331,22 @@ impl AccountCreateTransaction {
     }
 }
 
-impl TransactionData for AccountCreateTransactionData {}
+impl TransactionData for AccountCreateTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        match &self.key {
+            Some(key) => check_key(problems, "key", key),
+            None => problems.push(ValidationProblem::MissingField { field: "key" }),
+        }
+
+        check_memo(problems, "account_memo", &self.account_memo);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("auto_renew_account", self.auto_renew_account_id.as_ref())
+            .key("key", self.key.as_ref());
+    }
+}
 
 impl TransactionExecute for AccountCreateTransactionData {
     fn execute(
@@ -932,4 +983,59 @@ mod tests {
         assert_eq!(tx.get_key(), Some(&generic_key));
         assert_eq!(tx.get_alias(), None);
     }
+
+    #[test]
+    fn key_with_alias_sets_key_and_alias() {
+        use crate::PrivateKey;
+        let public_key = PrivateKey::generate_ecdsa().public_key();
+        let evm_address = public_key.to_evm_address().unwrap();
+
+        let mut tx = AccountCreateTransaction::new();
+        tx.key_with_alias(public_key).unwrap();
+
+        assert_eq!(tx.get_key(), Some(&public_key.into()));
+        assert_eq!(tx.get_alias(), Some(evm_address));
+
+        tx.set_key_without_alias(key());
+
+        assert_eq!(tx.get_key(), Some(&key().into()));
+        assert_eq!(tx.get_alias(), None);
+    }
+
+    #[test]
+    fn key_with_alias_rejects_ed25519() {
+        let mut tx = AccountCreateTransaction::new();
+
+        assert!(matches!(tx.key_with_alias(key()), Err(crate::Error::KeyParse(_))));
+        assert_eq!(tx.get_key(), None);
+        assert_eq!(tx.get_alias(), None);
+    }
+
+    #[test]
+    fn with_threshold_key_sets_key_list() {
+        use crate::{
+            Key,
+            KeyList,
+            PrivateKey,
+        };
+        let keys = [PrivateKey::generate_ed25519().public_key(), key()];
+
+        let mut tx = AccountCreateTransaction::new();
+        tx.with_threshold_key(keys, 1);
+
+        assert_eq!(
+            tx.get_key(),
+            Some(&Key::KeyList(KeyList {
+                keys: keys.into_iter().map(Key::from).collect(),
+                threshold: Some(1),
+            }))
+        );
+        assert_eq!(tx.get_alias(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "threshold must be between 1 and the number of keys")]
+    fn with_threshold_key_zero_threshold() {
+        AccountCreateTransaction::new().with_threshold_key([key()], 0);
+    }
 }
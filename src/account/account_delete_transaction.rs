@@ -10,12 +10,15 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -67,7 +70,18 @@ impl AccountDeleteTransaction {
     }
 }
 
-impl TransactionData for AccountDeleteTransactionData {}
+impl TransactionData for AccountDeleteTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "account_id", &self.account_id);
+        check_required(problems, "transfer_account_id", &self.transfer_account_id);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("account", self.account_id.as_ref())
+            .optional_entity("transfer_account", self.transfer_account_id.as_ref());
+    }
+}
 
 impl TransactionExecute for AccountDeleteTransactionData {
     fn execute(
@@ -1,5 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::cmp::Ordering;
 use std::fmt::{
     self,
     Debug,
@@ -12,6 +13,7 @@ use hedera_proto::services;
 
 use crate::entity_id::{
     Checksum,
+    EntityIdRange,
     PartialEntityId,
     ValidateChecksums,
 };
@@ -22,6 +24,7 @@ use crate::{
     Error,
     EvmAddress,
     FromProtobuf,
+    LedgerId,
     PublicKey,
     ToProtobuf,
 };
@@ -54,6 +57,42 @@ impl AccountId {
         Self { shard, realm, num, alias: None, evm_address: None, checksum: None }
     }
 
+    /// Returns the ID after `self` in the same shard and realm, IE, `0.0.1002` after `0.0.1001`.
+    ///
+    /// Returns `None` if `self.num` is `u64::MAX`.
+    /// Also returns `None` if `self` is an alias or EVM address.
+    #[must_use]
+    pub fn next(&self) -> Option<Self> {
+        if self.alias.is_some() || self.evm_address.is_some() {
+            return None;
+        }
+
+        self.num.checked_add(1).map(|num| Self::new(self.shard, self.realm, num))
+    }
+
+    /// Returns the ID before `self` in the same shard and realm, IE, `0.0.1000` before `0.0.1001`.
+    ///
+    /// Returns `None` if `self.num` is `0`.
+    /// Also returns `None` if `self` is an alias or EVM address.
+    #[must_use]
+    pub fn prev(&self) -> Option<Self> {
+        if self.alias.is_some() || self.evm_address.is_some() {
+            return None;
+        }
+
+        self.num.checked_sub(1).map(|num| Self::new(self.shard, self.realm, num))
+    }
+
+    /// Returns an iterator over the account IDs from `start` (inclusive) to `end` (exclusive).
+    ///
+    /// Only `shard`, `realm`, and `num` are used, checksums, aliases, and EVM addresses are ignored.
+    ///
+    /// The range is empty if `start` and `end` aren't in the same shard and realm.
+    #[must_use]
+    pub fn range(start: Self, end: Self) -> EntityIdRange<Self> {
+        EntityIdRange::new((start.shard, start.realm, start.num), (end.shard, end.realm, end.num))
+    }
+
     /// Create a new `AccountId` from protobuf-encoded `bytes`.
     ///
     /// # Errors
@@ -119,6 +158,51 @@ impl AccountId {
             EntityId::validate_checksum(self.shard, self.realm, self.num, self.checksum, client)
         }
     }
+
+    /// Convert `self` to a string with a checksum for `ledger_id`,
+    /// see [`EntityId::to_string_with_checksum_for_ledger`](crate::EntityId::to_string_with_checksum_for_ledger).
+    ///
+    /// # Errors
+    /// - [`Error::CannotCreateChecksum`] if self has an `alias` or `evm_address`.
+    pub fn to_string_with_checksum_for_ledger(
+        &self,
+        ledger_id: &LedgerId,
+    ) -> Result<String, Error> {
+        if self.alias.is_some() || self.evm_address.is_some() {
+            Err(Error::CannotCreateChecksum)
+        } else {
+            Ok(EntityId::to_string_with_checksum_for_ledger_id(
+                self.to_string(),
+                ledger_id.as_ref_ledger_id(),
+            ))
+        }
+    }
+
+    /// Validates `self.checksum` for `ledger_id`,
+    /// see [`EntityId::validate_checksum_for_ledger`](crate::EntityId::validate_checksum_for_ledger).
+    pub fn validate_checksum_for_ledger(&self, ledger_id: &LedgerId) -> crate::Result<()> {
+        self.validate_checksums(ledger_id.as_ref_ledger_id())
+    }
+}
+
+impl Ord for AccountId {
+    /// Account IDs are ordered by shard, then realm, then num,
+    /// and then by alias, EVM address, and checksum, so that the ordering agrees with `Eq`.
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.shard, self.realm, self.num)
+            .cmp(&(other.shard, other.realm, other.num))
+            .then_with(|| {
+                self.alias.map(|it| it.to_bytes_der()).cmp(&other.alias.map(|it| it.to_bytes_der()))
+            })
+            .then_with(|| self.evm_address.cmp(&other.evm_address))
+            .then_with(|| self.checksum.cmp(&other.checksum))
+    }
+}
+
+impl PartialOrd for AccountId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl ValidateChecksums for AccountId {
@@ -259,6 +343,8 @@ mod tests {
     use crate::{
         AccountId,
         Client,
+        LedgerId,
+        PrivateKey,
         ValidateChecksums,
     };
 
@@ -357,6 +443,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn to_string_with_checksum_for_ledger() {
+        let account_id = AccountId::from_str("0.0.123").unwrap();
+
+        assert_eq!(
+            account_id.to_string_with_checksum_for_ledger(&LedgerId::testnet()).unwrap(),
+            "0.0.123-esxsf"
+        );
+    }
+
+    #[test]
+    fn validate_checksum_for_ledger() {
+        let account_id = AccountId::from_str("0.0.123-ogizo").unwrap();
+
+        account_id.validate_checksum_for_ledger(&LedgerId::previewnet()).unwrap();
+
+        assert_matches!(
+            account_id.validate_checksum_for_ledger(&LedgerId::mainnet()),
+            Err(crate::Error::BadEntityId { num: 123, .. })
+        );
+    }
+
     #[tokio::test]
     async fn bad_checksum_on_previewnet() {
         let client = Client::for_previewnet();
@@ -489,4 +597,61 @@ mod tests {
         expect_test::expect!["0x302a300506032b6570032100114e6abc371b82da"]
             .assert_eq(&id.to_string());
     }
+
+    #[test]
+    fn next_prev() {
+        let id = AccountId::new(1, 2, 1001);
+
+        assert_eq!(id.next(), Some(AccountId::new(1, 2, 1002)));
+        assert_eq!(id.prev(), Some(AccountId::new(1, 2, 1000)));
+        assert_eq!(AccountId::new(0, 0, 0).prev(), None);
+        assert_eq!(AccountId::new(0, 0, u64::MAX).next(), None);
+
+        let evm_address =
+            EvmAddress::from_str("0x302a300506032b6570032100114e6abc371b82da").unwrap();
+
+        assert_eq!(AccountId::from_evm_address(&evm_address, 0, 0).next(), None);
+    }
+
+    #[test]
+    fn range() {
+        let ids: Vec<_> =
+            AccountId::range(AccountId::new(0, 0, 1001), AccountId::new(0, 0, 1004)).collect();
+
+        assert_eq!(
+            ids,
+            [AccountId::new(0, 0, 1001), AccountId::new(0, 0, 1002), AccountId::new(0, 0, 1003)]
+        );
+    }
+
+    #[test]
+    fn ord() {
+        let evm_address =
+            EvmAddress::from_str("0x302a300506032b6570032100114e6abc371b82da").unwrap();
+        let evm_address_id = AccountId::from_evm_address(&evm_address, 0, 0);
+        let alias_id = AccountId {
+            alias: Some(PrivateKey::generate_ed25519().public_key()),
+            ..AccountId::new(0, 0, 0)
+        };
+
+        let mut ids = [
+            AccountId::new(0, 1, 1),
+            AccountId::new(0, 0, 1002),
+            evm_address_id,
+            AccountId::new(0, 0, 1001),
+            alias_id,
+        ];
+        ids.sort();
+
+        assert_eq!(
+            ids,
+            [
+                evm_address_id,
+                alias_id,
+                AccountId::new(0, 0, 1001),
+                AccountId::new(0, 0, 1002),
+                AccountId::new(0, 1, 1),
+            ]
+        );
+    }
 }
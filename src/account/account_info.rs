@@ -173,7 +173,7 @@ impl FromProtobuf<services::crypto_get_info_response::AccountInfo> for AccountIn
             proxy_received: Hbar::from_tinybars(pb.proxy_received),
             key: Key::from_protobuf(key)?,
             balance: Hbar::from_tinybars(pb.balance as Tinybar),
-            expiration_time: pb.expiration_time.map(Into::into),
+            expiration_time: Option::from_protobuf(pb.expiration_time)?,
             auto_renew_period: pb.auto_renew_period.map(Into::into),
             account_memo: pb.memo,
             owned_nfts: pb.owned_nfts as u64,
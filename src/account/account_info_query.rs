@@ -15,6 +15,7 @@ use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
+    FromProtobuf,
     Query,
     ToProtobuf,
     ValidateChecksums,
@@ -65,6 +66,12 @@ impl ToQueryProtobuf for AccountInfoQueryData {
     }
 }
 
+impl FromProtobuf<services::CryptoGetInfoQuery> for AccountInfoQueryData {
+    fn from_protobuf(pb: services::CryptoGetInfoQuery) -> crate::Result<Self> {
+        Ok(Self { account_id: Option::from_protobuf(pb.account_id)? })
+    }
+}
+
 impl QueryExecute for AccountInfoQueryData {
     type Response = AccountInfo;
 
@@ -63,6 +63,12 @@ impl ToQueryProtobuf for AccountRecordsQueryData {
     }
 }
 
+impl FromProtobuf<services::CryptoGetAccountRecordsQuery> for AccountRecordsQueryData {
+    fn from_protobuf(pb: services::CryptoGetAccountRecordsQuery) -> crate::Result<Self> {
+        Ok(Self { account_id: Option::from_protobuf(pb.account_id)? })
+    }
+}
+
 impl QueryExecute for AccountRecordsQueryData {
     type Response = Vec<TransactionRecord>;
 
@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+use std::str::FromStr;
+
+use super::account_balance::{
+    format_token_amount,
+    MAX_TOKEN_DECIMALS,
+};
+use crate::mirror_rest::{
+    Links,
+    MirrorStream,
+};
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    TokenId,
+};
+
+/// The number of token balances requested per page.
+const PAGE_SIZE: u64 = 100;
+
+/// Query the balances of every token associated with an account, with their decimals, using the mirror node REST API.
+///
+/// Returns the balances ordered by token ID.
+/// For accounts with many token associations, [`stream`](Self::stream) yields balances as each page arrives.
+#[derive(Debug, Clone, Default)]
+pub struct AccountTokenBalancesQuery {
+    account_id: Option<AccountId>,
+    limit: u64,
+}
+
+/// The balance of a single token held by an account, returned by [`AccountTokenBalancesQuery`].
+///
+/// The [`Display`](fmt::Display) implementation formats the balance in whole units, IE, `12.345`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TokenBalance {
+    /// The token.
+    pub token_id: TokenId,
+
+    /// The balance, in the smallest denomination of the token.
+    pub balance: u64,
+
+    /// The number of decimal places the token is divisible by.
+    pub decimals: u32,
+}
+
+impl TokenBalance {
+    /// Returns the balance in whole units, IE, `"12.345"` for a balance of `12345` with 3 decimals.
+    #[must_use]
+    pub fn to_decimal_string(&self) -> String {
+        format_token_amount(self.balance, self.decimals)
+    }
+}
+
+impl fmt::Display for TokenBalance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_decimal_string())
+    }
+}
+
+impl AccountTokenBalancesQuery {
+    /// Create a new query.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the account to get the token balances of.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.account_id
+    }
+
+    /// Sets the account to get the token balances of.
+    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.account_id = Some(account_id);
+        self
+    }
+
+    /// Returns the maximum number of token balances to return.
+    #[must_use]
+    pub fn get_limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Sets the maximum number of token balances to return.
+    ///
+    /// Defaults to `0`, which returns every token balance.
+    pub fn limit(&mut self, limit: u64) -> &mut Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Execute this query against the mirror node of the provided client, following pagination.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `account_id` isn't set, or the mirror node returns malformed balances.
+    /// - [`Error::MirrorRest`] if a mirror node request fails.
+    pub async fn execute(&self, client: &Client) -> crate::Result<Vec<TokenBalance>> {
+        self.stream(client).collect_all(self.limit).await
+    }
+
+    /// Returns a stream of the token balances, fetching each page from the mirror node as the previous one is consumed.
+    ///
+    /// The stream ends after the first error.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `account_id` isn't set, or the mirror node returns malformed balances.
+    /// - [`Error::MirrorRest`] if a mirror node request fails.
+    pub fn stream(&self, client: &Client) -> MirrorStream<TokenBalance> {
+        client.rest_client().paginate(
+            self.first_page_path().map(|it| vec![it]),
+            self.limit,
+            |page: RestTokenBalances| {
+                (page.tokens.into_iter().map(RestTokenBalance::into_balance), page.links.next)
+            },
+        )
+    }
+
+    fn first_page_path(&self) -> crate::Result<String> {
+        let account_id = self.account_id.ok_or_else(|| {
+            Error::basic_parse("`account_id` must be set to query token balances")
+        })?;
+
+        let limit = match self.limit {
+            0 => PAGE_SIZE,
+            limit => limit.min(PAGE_SIZE),
+        };
+
+        Ok(format!("/accounts/{account_id}/tokens?order=asc&limit={limit}"))
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestTokenBalances {
+    tokens: Vec<RestTokenBalance>,
+    links: Links,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestTokenBalance {
+    token_id: String,
+    balance: u64,
+    decimals: u32,
+}
+
+impl RestTokenBalance {
+    fn into_balance(self) -> crate::Result<TokenBalance> {
+        if self.decimals > MAX_TOKEN_DECIMALS {
+            return Err(Error::basic_parse(format!(
+                "token `{}` has {} decimals, more than the maximum of {MAX_TOKEN_DECIMALS}",
+                self.token_id, self.decimals
+            )));
+        }
+
+        Ok(TokenBalance {
+            token_id: TokenId::from_str(&self.token_id)?,
+            balance: self.balance,
+            decimals: self.decimals,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AccountTokenBalancesQuery,
+        RestTokenBalances,
+    };
+    use crate::mirror_rest::test_page;
+    use crate::{
+        AccountId,
+        TokenId,
+    };
+
+    #[test]
+    fn first_page_path() {
+        let mut query = AccountTokenBalancesQuery::new();
+
+        assert!(query.first_page_path().is_err());
+
+        query.account_id(AccountId::new(0, 0, 1001));
+
+        assert_eq!(
+            query.first_page_path().unwrap(),
+            "/accounts/0.0.1001/tokens?order=asc&limit=100"
+        );
+
+        query.limit(10);
+
+        assert_eq!(
+            query.first_page_path().unwrap(),
+            "/accounts/0.0.1001/tokens?order=asc&limit=10"
+        );
+    }
+
+    #[test]
+    fn parse_page() {
+        let page: RestTokenBalances = test_page(
+            "tokens",
+            serde_json::json!([
+                {
+                    "automatic_association": false,
+                    "balance": 12345,
+                    "created_timestamp": "1700000000.000000005",
+                    "decimals": 3,
+                    "freeze_status": "UNFROZEN",
+                    "kyc_status": "NOT_APPLICABLE",
+                    "token_id": "0.0.5005"
+                }
+            ]),
+            Some("/api/v1/accounts/0.0.1001/tokens?order=asc&limit=100&token.id=gt:0.0.5005"),
+        );
+
+        let balance = page.tokens.into_iter().next().unwrap().into_balance().unwrap();
+
+        assert_eq!(balance.token_id, TokenId::new(0, 0, 5005));
+        assert_eq!(balance.balance, 12345);
+        assert_eq!(balance.decimals, 3);
+        assert_eq!(balance.to_string(), "12.345");
+        assert!(page.links.next.is_some());
+    }
+}
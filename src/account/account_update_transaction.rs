@@ -15,12 +15,17 @@ use crate::protobuf::{
 };
 use crate::staked_id::StakedId;
 use crate::transaction::{
+    check_memo,
+    check_required,
+    check_updated_key,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -273,7 +278,27 @@ impl AccountUpdateTransaction {
     }
 }
 
-impl TransactionData for AccountUpdateTransactionData {}
+impl TransactionData for AccountUpdateTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "account_id", &self.account_id);
+
+        if let Some(key) = &self.key {
+            check_updated_key(problems, "key", key);
+        }
+
+        if let Some(account_memo) = &self.account_memo {
+            check_memo(problems, "account_memo", account_memo);
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("account", self.account_id.as_ref())
+            .optional_entity("auto_renew_account", self.auto_renew_account_id.as_ref())
+            .staked_id(self.staked_id)
+            .key("key", self.key.as_ref());
+    }
+}
 
 impl TransactionExecute for AccountUpdateTransactionData {
     fn execute(
@@ -334,7 +359,7 @@ impl FromProtobuf<services::CryptoUpdateTransactionBody> for AccountUpdateTransa
             auto_renew_period: pb.auto_renew_period.map(Into::into),
             auto_renew_account_id: None,
             proxy_account_id: Option::from_protobuf(pb.proxy_account_id)?,
-            expiration_time: pb.expiration_time.map(Into::into),
+            expiration_time: Option::from_protobuf(pb.expiration_time)?,
             account_memo: pb.memo,
             max_automatic_token_associations: pb.max_automatic_token_associations,
             staked_id: Option::from_protobuf(pb.staked_id)?,
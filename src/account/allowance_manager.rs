@@ -0,0 +1,334 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::mirror_rest::Links;
+use crate::{
+    AccountAllowanceApproveTransaction,
+    AccountId,
+    Client,
+    Hbar,
+    TokenId,
+};
+
+/// The number of allowances requested per page.
+const PAGE_SIZE: u64 = 100;
+
+/// Keeps the hbar and fungible token allowances an owner grants a spender topped up,
+/// IE, for a service that collects recurring payments with approved transfers.
+///
+/// [`top_up`](Self::top_up) checks the remaining allowances with the mirror node REST API,
+/// and builds an [`AccountAllowanceApproveTransaction`] for every allowance that dropped below its threshold.
+/// Approving an allowance replaces it, so each one is approved at its target, rather than increased by the difference.
+///
+/// Nothing runs in the background, call `top_up` whenever the allowances should be checked (IE, after each billing run).
+#[derive(Debug, Clone)]
+pub struct AllowanceManager {
+    owner_account_id: AccountId,
+    spender_account_id: AccountId,
+    hbar_top_up: Option<(Hbar, Hbar)>,
+    token_top_ups: Vec<(TokenId, u64, u64)>,
+}
+
+impl AllowanceManager {
+    /// Create a new manager for the allowances `owner_account_id` grants `spender_account_id`.
+    #[must_use]
+    pub fn new(owner_account_id: AccountId, spender_account_id: AccountId) -> Self {
+        Self { owner_account_id, spender_account_id, hbar_top_up: None, token_top_ups: Vec::new() }
+    }
+
+    /// Returns the account that grants the allowances.
+    #[must_use]
+    pub fn get_owner_account_id(&self) -> AccountId {
+        self.owner_account_id
+    }
+
+    /// Returns the account that spends the allowances.
+    #[must_use]
+    pub fn get_spender_account_id(&self) -> AccountId {
+        self.spender_account_id
+    }
+
+    /// Returns the threshold and target of the hbar allowance, if it's managed.
+    #[must_use]
+    pub fn get_hbar_top_up(&self) -> Option<(Hbar, Hbar)> {
+        self.hbar_top_up
+    }
+
+    /// Top the hbar allowance up to `target` when less than `threshold` remains.
+    pub fn hbar_top_up(&mut self, threshold: Hbar, target: Hbar) -> &mut Self {
+        self.hbar_top_up = Some((threshold, target));
+        self
+    }
+
+    /// Returns the token, threshold, and target of every managed token allowance,
+    /// in the smallest denomination of the token.
+    #[must_use]
+    pub fn get_token_top_ups(&self) -> &[(TokenId, u64, u64)] {
+        &self.token_top_ups
+    }
+
+    /// Top the allowance of `token_id` up to `target` when less than `threshold` remains,
+    /// both in the smallest denomination of the token.
+    ///
+    /// Replaces the threshold and target previously set for the token, if any.
+    pub fn token_top_up(&mut self, token_id: TokenId, threshold: u64, target: u64) -> &mut Self {
+        match self.token_top_ups.iter_mut().find(|(it, ..)| *it == token_id) {
+            Some(top_up) => *top_up = (token_id, threshold, target),
+            None => self.token_top_ups.push((token_id, threshold, target)),
+        }
+
+        self
+    }
+
+    /// Check the remaining allowances, and build a transaction that tops up every allowance below its threshold.
+    ///
+    /// Returns `None` if no allowance needs a top up.
+    /// Allowances that were never approved (or were removed) count as having nothing left.
+    /// The transaction has to be signed by the owner.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if the mirror node returns malformed allowances.
+    /// - [`Error::MirrorRest`](crate::Error::MirrorRest) if a mirror node request fails.
+    pub async fn top_up(
+        &self,
+        client: &Client,
+    ) -> crate::Result<Option<AccountAllowanceApproveTransaction>> {
+        let rest = client.rest_client();
+
+        let hbar_remaining = match self.hbar_top_up {
+            Some(_) => {
+                let allowances = rest
+                    .paginate(Ok(vec![self.hbar_path()]), 0, |page: RestAllowances| {
+                        (page.allowances.into_iter().map(Ok), page.links.next)
+                    })
+                    .collect_all(0)
+                    .await?;
+
+                self.remaining(&allowances).unwrap_or(0)
+            }
+            None => 0,
+        };
+
+        let mut token_remaining = HashMap::new();
+
+        if !self.token_top_ups.is_empty() {
+            let allowances = rest
+                .paginate(Ok(self.token_paths()), 0, |page: RestAllowances| {
+                    (page.allowances.into_iter().map(Ok), page.links.next)
+                })
+                .collect_all(0)
+                .await?;
+
+            for it in &allowances {
+                if let Some(token_id) = &it.token_id {
+                    if self.is_managed(it) {
+                        token_remaining.insert(TokenId::from_str(token_id)?, it.amount);
+                    }
+                }
+            }
+        }
+
+        Ok(self.top_up_transaction(Hbar::from_tinybars(hbar_remaining), &token_remaining))
+    }
+
+    fn hbar_path(&self) -> String {
+        format!(
+            "/accounts/{}/allowances/crypto?spender.id={}&limit={PAGE_SIZE}",
+            self.owner_account_id, self.spender_account_id
+        )
+    }
+
+    /// Returns the path of the allowance of each managed token, so the mirror node only returns those.
+    fn token_paths(&self) -> Vec<String> {
+        self.token_top_ups
+            .iter()
+            .map(|(token_id, _, _)| {
+                format!(
+                    "/accounts/{}/allowances/tokens?spender.id={}&token.id={token_id}&limit={PAGE_SIZE}",
+                    self.owner_account_id, self.spender_account_id
+                )
+            })
+            .collect()
+    }
+
+    fn is_managed(&self, allowance: &RestAllowance) -> bool {
+        allowance.spender == self.spender_account_id.to_string()
+    }
+
+    fn remaining(&self, allowances: &[RestAllowance]) -> Option<i64> {
+        allowances.iter().find(|it| self.is_managed(it)).map(|it| it.amount)
+    }
+
+    /// Returns the transaction that tops up every managed allowance below its threshold, given what remains of them.
+    fn top_up_transaction(
+        &self,
+        hbar_remaining: Hbar,
+        token_remaining: &HashMap<TokenId, i64>,
+    ) -> Option<AccountAllowanceApproveTransaction> {
+        let mut tx = AccountAllowanceApproveTransaction::new();
+        let mut is_empty = true;
+
+        if let Some((threshold, target)) = self.hbar_top_up {
+            if hbar_remaining < threshold {
+                tx.approve_hbar_allowance(self.owner_account_id, self.spender_account_id, target);
+                is_empty = false;
+            }
+        }
+
+        for &(token_id, threshold, target) in &self.token_top_ups {
+            let remaining = token_remaining.get(&token_id).copied().unwrap_or(0);
+
+            if u64::try_from(remaining).map_or(true, |it| it < threshold) {
+                tx.approve_token_allowance(
+                    token_id,
+                    self.owner_account_id,
+                    self.spender_account_id,
+                    target,
+                );
+                is_empty = false;
+            }
+        }
+
+        (!is_empty).then_some(tx)
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestAllowances {
+    allowances: Vec<RestAllowance>,
+    links: Links,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestAllowance {
+    /// The remaining allowance, in tinybars or the smallest denomination of the token.
+    amount: i64,
+    spender: String,
+    #[serde(default)]
+    token_id: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{
+        AllowanceManager,
+        RestAllowances,
+    };
+    use crate::mirror_rest::test_page;
+    use crate::{
+        AccountId,
+        Hbar,
+        TokenId,
+    };
+
+    const OWNER: AccountId = AccountId::new(0, 0, 1001);
+    const SPENDER: AccountId = AccountId::new(0, 0, 1002);
+    const TOKEN: TokenId = TokenId::new(0, 0, 5005);
+    const OTHER_TOKEN: TokenId = TokenId::new(0, 0, 5006);
+
+    fn manager() -> AllowanceManager {
+        let mut manager = AllowanceManager::new(OWNER, SPENDER);
+        manager
+            .hbar_top_up(Hbar::new(10), Hbar::new(100))
+            .token_top_up(TOKEN, 5, 50)
+            .token_top_up(OTHER_TOKEN, 1, 10)
+            .token_top_up(OTHER_TOKEN, 10, 100);
+
+        manager
+    }
+
+    #[test]
+    fn paths() {
+        let manager = manager();
+
+        assert_eq!(
+            manager.hbar_path(),
+            "/accounts/0.0.1001/allowances/crypto?spender.id=0.0.1002&limit=100"
+        );
+        assert_eq!(
+            manager.token_paths(),
+            [
+                "/accounts/0.0.1001/allowances/tokens?spender.id=0.0.1002&token.id=0.0.5005&limit=100",
+                "/accounts/0.0.1001/allowances/tokens?spender.id=0.0.1002&token.id=0.0.5006&limit=100",
+            ]
+        );
+    }
+
+    #[test]
+    fn token_top_up_replaces() {
+        assert_eq!(manager().get_token_top_ups(), [(TOKEN, 5, 50), (OTHER_TOKEN, 10, 100)]);
+    }
+
+    #[test]
+    fn top_up_transaction() {
+        let manager = manager();
+
+        let tx = manager
+            .top_up_transaction(Hbar::new(9), &HashMap::from([(TOKEN, 5), (OTHER_TOKEN, 9)]))
+            .unwrap();
+
+        assert_eq!(tx.hbar_approvals().len(), 1);
+        assert_eq!(tx.hbar_approvals()[0].amount, Hbar::new(100));
+
+        let token_approvals = tx.token_approvals();
+
+        assert_eq!(token_approvals.len(), 1);
+        assert_eq!(token_approvals[0].token_id, OTHER_TOKEN);
+        assert_eq!(token_approvals[0].owner_account_id, OWNER);
+        assert_eq!(token_approvals[0].spender_account_id, SPENDER);
+        assert_eq!(token_approvals[0].amount, 100);
+    }
+
+    #[test]
+    fn top_up_transaction_missing_allowance() {
+        let tx = manager().top_up_transaction(Hbar::new(10), &HashMap::new()).unwrap();
+
+        assert!(tx.hbar_approvals().is_empty());
+        assert_eq!(tx.token_approvals().len(), 2);
+    }
+
+    #[test]
+    fn top_up_transaction_none_needed() {
+        let manager = manager();
+
+        assert!(manager
+            .top_up_transaction(Hbar::new(10), &HashMap::from([(TOKEN, 5), (OTHER_TOKEN, 10)]))
+            .is_none());
+    }
+
+    #[test]
+    fn parse_page() {
+        let page: RestAllowances = test_page(
+            "allowances",
+            serde_json::json!([
+                {
+                    "amount": 75,
+                    "amount_granted": 100,
+                    "owner": "0.0.1001",
+                    "spender": "0.0.1002",
+                    "timestamp": { "from": "1700000000.000000005", "to": null },
+                    "token_id": "0.0.5005"
+                },
+                {
+                    "amount": 1,
+                    "amount_granted": 1,
+                    "owner": "0.0.1001",
+                    "spender": "0.0.1003",
+                    "timestamp": { "from": "1700000000.000000005", "to": null },
+                    "token_id": "0.0.5006"
+                }
+            ]),
+            None,
+        );
+
+        let manager = manager();
+
+        assert_eq!(manager.remaining(&page.allowances), Some(75));
+        assert!(!manager.is_managed(&page.allowances[1]));
+        assert!(page.links.next.is_none());
+    }
+}
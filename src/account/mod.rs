@@ -13,8 +13,15 @@ mod account_info;
 pub mod account_info_flow;
 mod account_info_query;
 mod account_records_query;
+#[cfg(feature = "mirror-rest")]
+mod account_token_balances_query;
 mod account_update_transaction;
+#[cfg(feature = "mirror-rest")]
+mod allowance_manager;
+mod multisig_account;
 mod proxy_staker;
+#[cfg(feature = "mirror-rest")]
+mod staking_rewards_query;
 
 pub use account_allowance_approve_transaction::AccountAllowanceApproveTransaction;
 pub(crate) use account_allowance_approve_transaction::AccountAllowanceApproveTransactionData;
@@ -33,9 +40,25 @@ pub use account_info_query::AccountInfoQuery;
 pub(crate) use account_info_query::AccountInfoQueryData;
 pub use account_records_query::AccountRecordsQuery;
 pub(crate) use account_records_query::AccountRecordsQueryData;
+#[cfg(feature = "mirror-rest")]
+pub use account_token_balances_query::{
+    AccountTokenBalancesQuery,
+    TokenBalance,
+};
 pub use account_update_transaction::AccountUpdateTransaction;
 pub(crate) use account_update_transaction::AccountUpdateTransactionData;
+#[cfg(feature = "mirror-rest")]
+pub use allowance_manager::AllowanceManager;
+pub use multisig_account::{
+    MultisigAccount,
+    SigningChecklist,
+};
 pub use proxy_staker::{
     AllProxyStakers,
     ProxyStaker,
 };
+#[cfg(feature = "mirror-rest")]
+pub use staking_rewards_query::{
+    StakingReward,
+    StakingRewardsQuery,
+};
@@ -0,0 +1,357 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+use crate::transaction::TransactionExecute;
+use crate::{
+    AccountCreateTransaction,
+    AccountId,
+    Key,
+    KeyList,
+    PublicKey,
+    Transaction,
+};
+
+/// An account controlled by a threshold key (`threshold` of `keys` must sign).
+///
+/// Tracks the key structure of the account, to create it,
+/// and to check which signatures a transaction still needs before it can be submitted.
+#[derive(Debug, Clone)]
+pub struct MultisigAccount {
+    account_id: Option<AccountId>,
+    key_list: KeyList,
+}
+
+/// Which keys of a [`MultisigAccount`] have signed a transaction, made by [`MultisigAccount::signing_checklist`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SigningChecklist {
+    /// The number of keys that must sign.
+    pub threshold: u32,
+
+    /// Each key of the account, and whether its signature requirement is met.
+    pub keys: Vec<(Key, bool)>,
+}
+
+/// Returns a key list that requires `threshold` of `keys` to sign.
+///
+/// # Panics
+/// - If `threshold` is `0` or greater than the number of keys.
+pub(crate) fn threshold_key_list(
+    keys: impl IntoIterator<Item = impl Into<Key>>,
+    threshold: u32,
+) -> KeyList {
+    let keys: Vec<Key> = keys.into_iter().map(Into::into).collect();
+
+    assert!(
+        threshold != 0 && threshold as usize <= keys.len(),
+        "threshold must be between 1 and the number of keys ({}), but was {threshold}",
+        keys.len()
+    );
+
+    KeyList { keys, threshold: Some(threshold) }
+}
+
+impl MultisigAccount {
+    /// Create a new multisig account where `threshold` of `keys` must sign.
+    ///
+    /// Keys may themselves be key lists, for nested signing requirements.
+    ///
+    /// # Panics
+    /// - If `threshold` is `0` or greater than the number of keys.
+    #[must_use]
+    pub fn new(keys: impl IntoIterator<Item = impl Into<Key>>, threshold: u32) -> Self {
+        Self { account_id: None, key_list: threshold_key_list(keys, threshold) }
+    }
+
+    /// Returns the ID of the account, once it's been set.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.account_id
+    }
+
+    /// Sets the ID of the account, IE, after it's been created.
+    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.account_id = Some(account_id);
+        self
+    }
+
+    /// Returns the number of keys that must sign.
+    #[must_use]
+    pub fn get_threshold(&self) -> u32 {
+        self.key_list.threshold.unwrap_or_default()
+    }
+
+    /// Returns the keys of the account.
+    #[must_use]
+    pub fn get_keys(&self) -> &[Key] {
+        &self.key_list.keys
+    }
+
+    /// Returns the threshold key of the account.
+    #[must_use]
+    pub fn key(&self) -> Key {
+        Key::KeyList(self.key_list.clone())
+    }
+
+    /// Returns a transaction that creates this account.
+    ///
+    /// Once it's been executed, set the ID of the new account with [`account_id`](Self::account_id).
+    #[must_use]
+    pub fn create_transaction(&self) -> AccountCreateTransaction {
+        let mut transaction = AccountCreateTransaction::new();
+        transaction.set_key_without_alias(self.key());
+
+        transaction
+    }
+
+    /// Returns which keys of this account have signed `transaction`, and whether enough of them have.
+    ///
+    /// Signatures added with [`sign`](Transaction::sign) and friends are counted,
+    /// as are signatures on a transaction that was loaded from bytes, once they're verified
+    /// against the body of every node's transaction.
+    #[must_use]
+    pub fn signing_checklist<D: TransactionExecute>(
+        &self,
+        transaction: &Transaction<D>,
+    ) -> SigningChecklist {
+        let signed = signed_public_keys(&self.key(), transaction);
+
+        SigningChecklist {
+            threshold: self.get_threshold(),
+            keys: self
+                .key_list
+                .keys
+                .iter()
+                .map(|key| (key.clone(), is_satisfied(key, &signed)))
+                .collect(),
+        }
+    }
+}
+
+impl SigningChecklist {
+    /// Returns the number of keys that have signed.
+    #[must_use]
+    pub fn signed(&self) -> usize {
+        self.keys.iter().filter(|(_, signed)| *signed).count()
+    }
+
+    /// Returns the number of additional keys that need to sign.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        (self.threshold as usize).saturating_sub(self.signed())
+    }
+
+    /// Returns true if enough keys have signed.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Returns the keys that haven't signed yet.
+    pub fn unsigned_keys(&self) -> impl Iterator<Item = &Key> {
+        self.keys.iter().filter(|(_, signed)| !*signed).map(|(key, _)| key)
+    }
+}
+
+impl fmt::Display for SigningChecklist {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} of {} required signatures", self.signed(), self.threshold)?;
+
+        for (key, signed) in &self.keys {
+            let mark = if *signed { 'x' } else { ' ' };
+
+            match key {
+                Key::Single(key) => writeln!(f, "[{mark}] {key}")?,
+                Key::ContractId(id) => writeln!(f, "[{mark}] contract {id}")?,
+                Key::DelegateContractId(id) => writeln!(f, "[{mark}] delegatable contract {id}")?,
+                Key::KeyList(list) => writeln!(f, "[{mark}] key list ({} keys)", list.len())?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the public keys of `key` that have validly signed `transaction`.
+///
+/// A signature loaded from bytes only counts if it verifies against the body it was made for,
+/// so a signature pair with the right prefix but the wrong (or a forged) signature isn't counted.
+fn signed_public_keys<D: TransactionExecute>(
+    key: &Key,
+    transaction: &Transaction<D>,
+) -> Vec<PublicKey> {
+    let signers: Vec<PublicKey> = transaction.signers().map(|it| it.public_key()).collect();
+
+    let mut keys = Vec::new();
+    collect_public_keys(key, &mut keys);
+
+    keys.retain(|key| {
+        signers.contains(key)
+            || transaction
+                .sources()
+                .is_some_and(|sources| key.verify_transaction_sources(sources).is_ok())
+    });
+
+    keys
+}
+
+/// Adds every public key in `key` (including those of nested key lists) to `keys`.
+fn collect_public_keys(key: &Key, keys: &mut Vec<PublicKey>) {
+    match key {
+        Key::Single(key) => {
+            if !keys.contains(key) {
+                keys.push(*key);
+            }
+        }
+        Key::ContractId(_) | Key::DelegateContractId(_) => {}
+        Key::KeyList(list) => {
+            for key in &list.keys {
+                collect_public_keys(key, keys);
+            }
+        }
+    }
+}
+
+/// Returns true if the signatures of `signed` satisfy `key`.
+fn is_satisfied(key: &Key, signed: &[PublicKey]) -> bool {
+    match key {
+        Key::Single(key) => signed.contains(key),
+        // contracts authorize by being the caller, not with signatures.
+        Key::ContractId(_) | Key::DelegateContractId(_) => false,
+        Key::KeyList(list) => {
+            let count = list.keys.iter().filter(|it| is_satisfied(it, signed)).count();
+
+            count >= list.threshold.map_or(list.len(), |it| it as usize)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hedera_proto::services;
+    use prost::Message;
+
+    use super::MultisigAccount;
+    use crate::transaction::test_helpers::TEST_TX_ID;
+    use crate::{
+        AccountId,
+        Key,
+        KeyList,
+        PrivateKey,
+        TransferTransaction,
+    };
+
+    fn keys() -> [PrivateKey; 3] {
+        [
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ecdsa(),
+        ]
+    }
+
+    #[test]
+    fn create_transaction() {
+        let keys = keys();
+        let account = MultisigAccount::new(keys.iter().map(PrivateKey::public_key), 2);
+
+        let tx = account.create_transaction();
+
+        assert_eq!(
+            tx.get_key(),
+            Some(&Key::KeyList(KeyList {
+                keys: keys.iter().map(|it| it.public_key().into()).collect(),
+                threshold: Some(2),
+            }))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "threshold must be between 1 and the number of keys")]
+    fn unreachable_threshold() {
+        let _ = MultisigAccount::new(keys().iter().map(PrivateKey::public_key), 4);
+    }
+
+    #[test]
+    fn signing_checklist() {
+        let [a, b, c] = keys();
+        let mut account = MultisigAccount::new([a.public_key(), b.public_key(), c.public_key()], 2);
+        account.account_id(AccountId::new(0, 0, 1001));
+
+        let mut tx = TransferTransaction::new();
+        tx.transaction_id(TEST_TX_ID).node_account_ids([AccountId::new(0, 0, 3)]).freeze().unwrap();
+
+        let checklist = account.signing_checklist(&tx);
+        assert_eq!(checklist.remaining(), 2);
+
+        tx.sign(a);
+
+        let checklist = account.signing_checklist(&tx);
+        assert_eq!(checklist.signed(), 1);
+        assert!(!checklist.is_complete());
+        assert_eq!(
+            checklist.unsigned_keys().cloned().collect::<Vec<_>>(),
+            [Key::from(b.public_key()), Key::from(c.public_key())]
+        );
+
+        // round trip through bytes, so that the signature is only in the sources.
+        tx.sign(c);
+        let tx = crate::AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let checklist = account.signing_checklist(&tx);
+        assert!(checklist.is_complete());
+        assert_eq!(checklist.remaining(), 0);
+    }
+
+    #[test]
+    fn nested_key_lists() {
+        let [a, b, c] = keys();
+
+        let inner =
+            KeyList { keys: vec![b.public_key().into(), c.public_key().into()], threshold: None };
+        let account = MultisigAccount::new([Key::from(a.public_key()), Key::KeyList(inner)], 1);
+
+        let mut tx = TransferTransaction::new();
+        tx.transaction_id(TEST_TX_ID).node_account_ids([AccountId::new(0, 0, 3)]).freeze().unwrap();
+        tx.sign(b);
+
+        assert!(!account.signing_checklist(&tx).is_complete());
+
+        tx.sign(c);
+
+        assert!(account.signing_checklist(&tx).is_complete());
+    }
+
+    #[test]
+    fn forged_signature_is_not_counted() {
+        let [a, b, c] = keys();
+        let account = MultisigAccount::new([a.public_key(), b.public_key(), c.public_key()], 2);
+
+        let mut tx = TransferTransaction::new();
+        tx.transaction_id(TEST_TX_ID).node_account_ids([AccountId::new(0, 0, 3)]).freeze().unwrap();
+        tx.sign(a);
+
+        // claim `b` signed, by reusing `a`'s signature with `b`'s key.
+        let bytes = tx.to_bytes().unwrap();
+        let mut list = hedera_proto::sdk::TransactionList::decode(bytes.as_slice()).unwrap();
+
+        for transaction in &mut list.transaction_list {
+            let mut signed =
+                services::SignedTransaction::decode(&*transaction.signed_transaction_bytes)
+                    .unwrap();
+
+            let sig_map = signed.sig_map.as_mut().unwrap();
+            let mut forged = sig_map.sig_pair[0].clone();
+            forged.pub_key_prefix = b.public_key().to_bytes_raw();
+            sig_map.sig_pair.push(forged);
+
+            transaction.signed_transaction_bytes = signed.encode_to_vec();
+        }
+
+        let tx = crate::AnyTransaction::from_bytes(&list.encode_to_vec()).unwrap();
+
+        let checklist = account.signing_checklist(&tx);
+        assert_eq!(checklist.signed(), 1);
+        assert!(!checklist.is_complete());
+    }
+}
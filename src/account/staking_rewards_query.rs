@@ -0,0 +1,254 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use time::OffsetDateTime;
+
+use crate::mirror_rest::{
+    Links,
+    MirrorStream,
+};
+use crate::protobuf::time::{
+    format_mirror_timestamp,
+    parse_mirror_timestamp,
+};
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    Hbar,
+};
+
+/// The number of rewards requested per page.
+const PAGE_SIZE: u64 = 100;
+
+/// Query the staking rewards paid to an account, using the mirror node REST API.
+///
+/// Returns the rewards oldest first, optionally only those paid within a period.
+#[derive(Debug, Clone, Default)]
+pub struct StakingRewardsQuery {
+    account_id: Option<AccountId>,
+    start_time: Option<OffsetDateTime>,
+    end_time: Option<OffsetDateTime>,
+    limit: u64,
+}
+
+/// A single staking reward payment, returned by [`StakingRewardsQuery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct StakingReward {
+    /// The account the reward was paid to.
+    pub account_id: AccountId,
+
+    /// The amount of the reward.
+    pub amount: Hbar,
+
+    /// The consensus timestamp of the transaction that paid the reward.
+    pub timestamp: OffsetDateTime,
+}
+
+impl StakingRewardsQuery {
+    /// Create a new query.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the account to get the rewards of.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.account_id
+    }
+
+    /// Sets the account to get the rewards of.
+    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.account_id = Some(account_id);
+        self
+    }
+
+    /// Returns the start of the period to get rewards for, if set.
+    #[must_use]
+    pub fn get_start_time(&self) -> Option<OffsetDateTime> {
+        self.start_time
+    }
+
+    /// Sets to include rewards paid on or after this time.
+    pub fn start_time(&mut self, time: OffsetDateTime) -> &mut Self {
+        self.start_time = Some(time);
+        self
+    }
+
+    /// Returns the end of the period to get rewards for, if set.
+    #[must_use]
+    pub fn get_end_time(&self) -> Option<OffsetDateTime> {
+        self.end_time
+    }
+
+    /// Sets to include rewards paid before this time.
+    pub fn end_time(&mut self, time: OffsetDateTime) -> &mut Self {
+        self.end_time = Some(time);
+        self
+    }
+
+    /// Returns the maximum number of rewards to return.
+    #[must_use]
+    pub fn get_limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Sets the maximum number of rewards to return.
+    ///
+    /// Defaults to `0`, which returns every reward.
+    pub fn limit(&mut self, limit: u64) -> &mut Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Execute this query against the mirror node of the provided client, following pagination.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `account_id` isn't set, or the mirror node returns malformed rewards.
+    /// - [`Error::MirrorRest`] if a mirror node request fails.
+    pub async fn execute(&self, client: &Client) -> crate::Result<Vec<StakingReward>> {
+        self.stream(client).collect_all(self.limit).await
+    }
+
+    /// Returns a stream of the rewards, fetching each page from the mirror node as the previous one is consumed.
+    ///
+    /// The stream ends after the first error.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `account_id` isn't set, or the mirror node returns malformed rewards.
+    /// - [`Error::MirrorRest`] if a mirror node request fails.
+    pub fn stream(&self, client: &Client) -> MirrorStream<StakingReward> {
+        client.rest_client().paginate(
+            self.first_page_path().map(|it| vec![it]),
+            self.limit,
+            |page: RestRewards| {
+                (page.rewards.into_iter().map(RestReward::into_reward), page.links.next)
+            },
+        )
+    }
+
+    /// Execute this query, and return the sum of the rewards.
+    ///
+    /// # Errors
+    /// - Any error returned by [`execute`](Self::execute).
+    pub async fn execute_total(&self, client: &Client) -> crate::Result<Hbar> {
+        let rewards = self.execute(client).await?;
+
+        Ok(rewards.iter().fold(Hbar::ZERO, |total, it| total + it.amount))
+    }
+
+    fn first_page_path(&self) -> crate::Result<String> {
+        let account_id = self.account_id.ok_or_else(|| {
+            Error::basic_parse("`account_id` must be set to query staking rewards")
+        })?;
+
+        let limit = match self.limit {
+            0 => PAGE_SIZE,
+            limit => limit.min(PAGE_SIZE),
+        };
+
+        let mut path = format!("/accounts/{account_id}/rewards?order=asc&limit={limit}");
+
+        if let Some(start_time) = self.start_time {
+            path.push_str(&format!("&timestamp=gte:{}", format_mirror_timestamp(start_time)));
+        }
+
+        if let Some(end_time) = self.end_time {
+            path.push_str(&format!("&timestamp=lt:{}", format_mirror_timestamp(end_time)));
+        }
+
+        Ok(path)
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestRewards {
+    rewards: Vec<RestReward>,
+    links: Links,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestReward {
+    account_id: String,
+    amount: i64,
+    timestamp: String,
+}
+
+impl RestReward {
+    fn into_reward(self) -> crate::Result<StakingReward> {
+        Ok(StakingReward {
+            account_id: AccountId::from_str(&self.account_id)?,
+            amount: Hbar::from_tinybars(self.amount),
+            timestamp: parse_mirror_timestamp(&self.timestamp)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use super::{
+        RestRewards,
+        StakingRewardsQuery,
+    };
+    use crate::mirror_rest::test_page;
+    use crate::{
+        AccountId,
+        Hbar,
+    };
+
+    #[test]
+    fn first_page_path() {
+        let mut query = StakingRewardsQuery::new();
+
+        assert!(query.first_page_path().is_err());
+
+        query.account_id(AccountId::new(0, 0, 1001));
+
+        assert_eq!(
+            query.first_page_path().unwrap(),
+            "/accounts/0.0.1001/rewards?order=asc&limit=100"
+        );
+
+        query
+            .limit(10)
+            .start_time(OffsetDateTime::UNIX_EPOCH + Duration::new(1_700_000_000, 0))
+            .end_time(OffsetDateTime::UNIX_EPOCH + Duration::new(1_700_086_400, 0));
+
+        assert_eq!(
+            query.first_page_path().unwrap(),
+            "/accounts/0.0.1001/rewards?order=asc&limit=10\
+             &timestamp=gte:1700000000.000000000\
+             &timestamp=lt:1700086400.000000000"
+        );
+    }
+
+    #[test]
+    fn parse_page() {
+        let page: RestRewards = test_page(
+            "rewards",
+            serde_json::json!([
+                {
+                    "account_id": "0.0.1001",
+                    "amount": 12345,
+                    "timestamp": "1700000000.000000005"
+                }
+            ]),
+            Some("/api/v1/accounts/0.0.1001/rewards?order=asc&timestamp=gt:1700000000.000000005"),
+        );
+
+        let reward = page.rewards.into_iter().next().unwrap().into_reward().unwrap();
+
+        assert_eq!(reward.account_id, AccountId::new(0, 0, 1001));
+        assert_eq!(reward.amount, Hbar::from_tinybars(12345));
+        assert_eq!(reward.timestamp, OffsetDateTime::UNIX_EPOCH + Duration::new(1_700_000_000, 5));
+        assert!(page.links.next.is_some());
+    }
+}
@@ -1,21 +1,27 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::Ipv4Addr;
-
 use hedera_proto::services;
 use hedera_proto::services::address_book_service_client::AddressBookServiceClient;
 use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
-use crate::protobuf::FromProtobuf;
+use crate::protobuf::{
+    FromProtobuf,
+    FromProtobufContext,
+};
 use crate::service_endpoint::ServiceEndpoint;
 use crate::transaction::{
+    check_key,
+    check_memo,
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -199,7 +205,35 @@ impl NodeCreateTransaction {
     }
 }
 
-impl TransactionData for NodeCreateTransactionData {}
+impl TransactionData for NodeCreateTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "account_id", &self.account_id);
+        check_memo(problems, "description", &self.description);
+
+        if self.gossip_endpoints.is_empty() {
+            problems.push(ValidationProblem::MissingField { field: "gossip_endpoints" });
+        }
+
+        if self.service_endpoints.is_empty() {
+            problems.push(ValidationProblem::MissingField { field: "service_endpoints" });
+        }
+
+        if self.gossip_ca_certificate.is_empty() {
+            problems.push(ValidationProblem::MissingField { field: "gossip_ca_certificate" });
+        }
+
+        match &self.admin_key {
+            Some(key) => check_key(problems, "admin_key", key),
+            None => problems.push(ValidationProblem::MissingField { field: "admin_key" }),
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("account", self.account_id.as_ref())
+            .key("admin_key", self.admin_key.as_ref());
+    }
+}
 
 impl TransactionExecute for NodeCreateTransactionData {
     fn execute(
@@ -247,30 +281,24 @@ impl FromProtobuf<services::NodeCreateTransactionBody> for NodeCreateTransaction
     fn from_protobuf(pb: services::NodeCreateTransactionBody) -> crate::Result<Self> {
         let gossip_endpoints = pb
             .gossip_endpoint
-            .iter()
-            .map(|it| {
-                let ip_addr_v4 = &it.ip_address_v4[..];
-                let ip = Ipv4Addr::new(ip_addr_v4[0], ip_addr_v4[1], ip_addr_v4[2], ip_addr_v4[3]);
-                ServiceEndpoint {
-                    ip_address_v4: Some(ip),
-                    port: it.port,
-                    domain_name: it.domain_name.clone(),
-                }
+            .into_iter()
+            .enumerate()
+            .map(|(index, it)| {
+                ServiceEndpoint::from_protobuf_verbatim(it)
+                    .at_index(index)
+                    .in_field("gossip_endpoint")
             })
-            .collect();
+            .collect::<crate::Result<_>>()?;
         let service_endpoints = pb
             .service_endpoint
-            .iter()
-            .map(|it| {
-                let ip_addr_v4 = &it.ip_address_v4[..];
-                let ip = Ipv4Addr::new(ip_addr_v4[0], ip_addr_v4[1], ip_addr_v4[2], ip_addr_v4[3]);
-                ServiceEndpoint {
-                    ip_address_v4: Some(ip),
-                    port: it.port,
-                    domain_name: it.domain_name.clone(),
-                }
+            .into_iter()
+            .enumerate()
+            .map(|(index, it)| {
+                ServiceEndpoint::from_protobuf_verbatim(it)
+                    .at_index(index)
+                    .in_field("service_endpoint")
             })
-            .collect();
+            .collect::<crate::Result<_>>()?;
 
         Ok(Self {
             account_id: FromProtobuf::from_protobuf(pb.account_id)?,
@@ -281,16 +309,11 @@ impl FromProtobuf<services::NodeCreateTransactionBody> for NodeCreateTransaction
             grpc_certificate_hash: pb.grpc_certificate_hash,
             admin_key: Option::from_protobuf(pb.admin_key)?,
             decline_reward: pb.decline_reward,
-            grpc_proxy_endpoint: pb.grpc_proxy_endpoint.map(|it| ServiceEndpoint {
-                ip_address_v4: Some(Ipv4Addr::new(
-                    it.ip_address_v4[0],
-                    it.ip_address_v4[1],
-                    it.ip_address_v4[2],
-                    it.ip_address_v4[3],
-                )),
-                port: it.port,
-                domain_name: it.domain_name.clone(),
-            }),
+            grpc_proxy_endpoint: pb
+                .grpc_proxy_endpoint
+                .map(ServiceEndpoint::from_protobuf_verbatim)
+                .transpose()
+                .in_field("grpc_proxy_endpoint")?,
         })
     }
 }
@@ -425,6 +448,25 @@ mod tests {
         assert_eq!(data.admin_key, Some(Key::from(unused_private_key().public_key())));
     }
 
+    #[test]
+    fn from_proto_body_short_ip_address() {
+        let mut endpoint = make_ip_address_list()[0].to_protobuf();
+        endpoint.ip_address_v4.truncate(3);
+
+        let tx = services::NodeCreateTransactionBody {
+            account_id: Some(TEST_ACCOUNT_ID.to_protobuf()),
+            gossip_endpoint: vec![endpoint],
+            ..Default::default()
+        };
+
+        let err = NodeCreateTransactionData::from_protobuf(tx).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "failed to create a SDK type from a protobuf response: `gossip_endpoint[0]`: expected 4 byte ip address, got `3` bytes"
+        );
+    }
+
     #[test]
     fn get_set_account_id() {
         let account_id = TEST_ACCOUNT_ID;
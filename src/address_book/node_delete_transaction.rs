@@ -12,7 +12,9 @@ use crate::transaction::{
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -61,7 +63,15 @@ impl NodeDeleteTransaction {
     }
 }
 
-impl TransactionData for NodeDeleteTransactionData {}
+impl TransactionData for NodeDeleteTransactionData {
+    fn validate_data(&self, _problems: &mut Vec<ValidationProblem>) {
+        // `node_id` is always set, and 0 is a valid node.
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.entity("node", self.node_id);
+    }
+}
 
 impl TransactionExecute for NodeDeleteTransactionData {
     fn execute(
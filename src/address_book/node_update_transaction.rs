@@ -1,21 +1,26 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::Ipv4Addr;
-
 use hedera_proto::services;
 use hedera_proto::services::address_book_service_client::AddressBookServiceClient;
 use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
-use crate::protobuf::FromProtobuf;
+use crate::protobuf::{
+    FromProtobuf,
+    FromProtobufContext,
+};
 use crate::service_endpoint::ServiceEndpoint;
 use crate::transaction::{
+    check_memo,
+    check_updated_key,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -235,7 +240,24 @@ impl NodeUpdateTransaction {
     }
 }
 
-impl TransactionData for NodeUpdateTransactionData {}
+impl TransactionData for NodeUpdateTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        if let Some(description) = &self.description {
+            check_memo(problems, "description", description);
+        }
+
+        if let Some(key) = &self.admin_key {
+            check_updated_key(problems, "admin_key", key);
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .entity("node", self.node_id)
+            .optional_entity("account", self.account_id.as_ref())
+            .key("admin_key", self.admin_key.as_ref());
+    }
+}
 
 impl TransactionExecute for NodeUpdateTransactionData {
     fn execute(
@@ -283,30 +305,24 @@ impl FromProtobuf<services::NodeUpdateTransactionBody> for NodeUpdateTransaction
     fn from_protobuf(pb: services::NodeUpdateTransactionBody) -> crate::Result<Self> {
         let gossip_endpoints = pb
             .gossip_endpoint
-            .iter()
-            .map(|it| {
-                let ip_addr_v4 = &it.ip_address_v4[..];
-                let ip = Ipv4Addr::new(ip_addr_v4[0], ip_addr_v4[1], ip_addr_v4[2], ip_addr_v4[3]);
-                ServiceEndpoint {
-                    ip_address_v4: Some(ip),
-                    port: it.port,
-                    domain_name: it.domain_name.clone(),
-                }
+            .into_iter()
+            .enumerate()
+            .map(|(index, it)| {
+                ServiceEndpoint::from_protobuf_verbatim(it)
+                    .at_index(index)
+                    .in_field("gossip_endpoint")
             })
-            .collect();
+            .collect::<crate::Result<_>>()?;
         let service_endpoints = pb
             .service_endpoint
-            .iter()
-            .map(|it| {
-                let ip_addr_v4 = &it.ip_address_v4[..];
-                let ip = Ipv4Addr::new(ip_addr_v4[0], ip_addr_v4[1], ip_addr_v4[2], ip_addr_v4[3]);
-                ServiceEndpoint {
-                    ip_address_v4: Some(ip),
-                    port: it.port,
-                    domain_name: it.domain_name.clone(),
-                }
+            .into_iter()
+            .enumerate()
+            .map(|(index, it)| {
+                ServiceEndpoint::from_protobuf_verbatim(it)
+                    .at_index(index)
+                    .in_field("service_endpoint")
             })
-            .collect();
+            .collect::<crate::Result<_>>()?;
 
         Ok(Self {
             node_id: pb.node_id,
@@ -318,16 +334,11 @@ impl FromProtobuf<services::NodeUpdateTransactionBody> for NodeUpdateTransaction
             grpc_certificate_hash: pb.grpc_certificate_hash,
             admin_key: Option::from_protobuf(pb.admin_key)?,
             decline_reward: pb.decline_reward,
-            grpc_proxy_endpoint: pb.grpc_proxy_endpoint.map(|it| ServiceEndpoint {
-                ip_address_v4: Some(Ipv4Addr::new(
-                    it.ip_address_v4[0],
-                    it.ip_address_v4[1],
-                    it.ip_address_v4[2],
-                    it.ip_address_v4[3],
-                )),
-                port: it.port,
-                domain_name: it.domain_name.clone(),
-            }),
+            grpc_proxy_endpoint: pb
+                .grpc_proxy_endpoint
+                .map(ServiceEndpoint::from_protobuf_verbatim)
+                .transpose()
+                .in_field("grpc_proxy_endpoint")?,
         })
     }
 }
@@ -12,7 +12,9 @@ use crate::transaction::{
     ChunkInfo,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AnyTransaction,
@@ -193,6 +195,18 @@ impl TransactionData for BatchTransactionData {
     fn default_max_transaction_fee(&self) -> Hbar {
         Hbar::new(2)
     }
+
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        if self.inner_transactions.is_empty() {
+            problems.push(ValidationProblem::MissingField { field: "inner_transactions" });
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        for transaction in &self.inner_transactions {
+            description.nested("inner_transaction", transaction.describe());
+        }
+    }
 }
 
 impl ToTransactionDataProtobuf for BatchTransactionData {
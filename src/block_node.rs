@@ -0,0 +1,386 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A client for the block node streaming API.
+//!
+//! Block nodes serve the block stream, which will replace record files and the record queries of consensus nodes.
+//! The block node API is still changing, so this module is only available with the `unstable` feature,
+//! and may change in any release.
+//!
+//! Blocks are returned as a list of encoded `BlockItem`s (`com.hedera.hapi.block.stream.BlockItem`),
+//! only the block number is decoded.
+
+use std::ops::RangeInclusive;
+
+use backoff::backoff::Backoff;
+use backoff::ExponentialBackoff;
+use futures_core::stream::BoxStream;
+use hedera_proto::block;
+use hedera_proto::block::block_access_service_client::BlockAccessServiceClient;
+use hedera_proto::block::block_stream_subscribe_service_client::BlockStreamSubscribeServiceClient;
+use hedera_proto::block::subscribe_stream_response::Response;
+use hyper::Uri;
+use prost::Message;
+use tokio::time::sleep;
+use tonic::transport::Channel;
+
+use crate::client::grpc_channel;
+use crate::Error;
+
+/// A block from the block stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Block {
+    /// The number of the block.
+    pub number: u64,
+
+    /// The items of the block, in order, each an encoded `BlockItem`.
+    ///
+    /// The first item is always the block header.
+    pub items: Vec<Vec<u8>>,
+}
+
+impl Block {
+    fn from_items(items: Vec<Vec<u8>>) -> crate::Result<Self> {
+        let header = items
+            .first()
+            .map(|it| block::BlockItemHeader::decode(it.as_slice()))
+            .transpose()
+            .map_err(Error::from_protobuf)?
+            .and_then(|it| it.block_header)
+            .ok_or_else(|| Error::from_protobuf("block doesn't start with a block header"))?;
+
+        Ok(Self { number: header.number, items })
+    }
+}
+
+/// A client for a single block node.
+///
+/// The connection is established lazily, on the first request.
+#[derive(Debug, Clone)]
+pub struct BlockNodeClient {
+    address: String,
+    channel: Channel,
+}
+
+impl BlockNodeClient {
+    /// Create a client for the block node at `address` (`host:port`).
+    ///
+    /// Connections to `localhost` and `127.0.0.1` are plaintext, any other address uses TLS.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `address` isn't a valid `host:port`.
+    pub fn for_address(address: &str) -> crate::Result<Self> {
+        let uri: Uri = format!("http://{address}").parse().map_err(Error::basic_parse)?;
+
+        if uri.port().is_none() || uri.path() != "/" {
+            return Err(Error::basic_parse(format!("expected `host:port`, found `{address}`")));
+        }
+
+        Ok(Self { address: address.to_owned(), channel: grpc_channel(address) })
+    }
+
+    /// Returns the `host:port` address of the block node.
+    #[must_use]
+    pub fn get_address(&self) -> &str {
+        &self.address
+    }
+
+    /// Subscribe to blocks, starting at `start_block_number`.
+    ///
+    /// Historical blocks are streamed first, followed by every new block, indefinitely.
+    /// If the block node becomes unavailable, or the connection drops (the stream ends without a status),
+    /// the subscription reconnects, resuming after the last complete block.
+    ///
+    /// # Errors
+    /// The stream yields an error, and then ends, if:
+    /// - [`Error::BlockNode`] the block node ends the stream with a failure status, IE, `start_block_number` isn't available.
+    /// - [`Error::GrpcStatus`] the request fails for any other reason than the block node being unavailable.
+    /// - [`Error::FromProtobuf`] a block can't be decoded.
+    #[must_use]
+    pub fn subscribe_blocks(
+        &self,
+        start_block_number: u64,
+    ) -> BoxStream<'static, crate::Result<Block>> {
+        stream_blocks(self.channel.clone(), start_block_number, 0)
+    }
+
+    /// Returns a stream of the historical blocks in `range`.
+    ///
+    /// If the block node becomes unavailable, the stream reconnects, resuming after the last complete block.
+    ///
+    /// # Errors
+    /// See [`subscribe_blocks`](Self::subscribe_blocks).
+    /// Additionally, [`Error::BasicParse`] is yielded if `range` is empty, or ends at block 0.
+    #[must_use]
+    pub fn get_blocks(
+        &self,
+        range: RangeInclusive<u64>,
+    ) -> BoxStream<'static, crate::Result<Block>> {
+        let (start, end) = range.into_inner();
+
+        // an `end_block_number` of zero means "stream indefinitely".
+        if end < start || end == 0 {
+            let error = Error::basic_parse(format!("invalid block range {start}..={end}"));
+            return Box::pin(futures_util::stream::iter([Err(error)]));
+        }
+
+        stream_blocks(self.channel.clone(), start, end)
+    }
+
+    /// Get the block with number `block_number`.
+    ///
+    /// # Errors
+    /// - [`Error::BlockNode`] if the block node fails the request, IE, it doesn't have the block.
+    /// - [`Error::GrpcStatus`] if the request fails.
+    /// - [`Error::FromProtobuf`] if the block can't be decoded.
+    pub async fn get_block(&self, block_number: u64) -> crate::Result<Block> {
+        self.request_block(block::block_request::BlockSpecifier::BlockNumber(block_number)).await
+    }
+
+    /// Get the latest block the block node has.
+    ///
+    /// # Errors
+    /// See [`get_block`](Self::get_block).
+    pub async fn get_latest_block(&self) -> crate::Result<Block> {
+        self.request_block(block::block_request::BlockSpecifier::RetrieveLatest(true)).await
+    }
+
+    async fn request_block(
+        &self,
+        specifier: block::block_request::BlockSpecifier,
+    ) -> crate::Result<Block> {
+        let response = BlockAccessServiceClient::new(self.channel.clone())
+            .get_block(block::BlockRequest { block_specifier: Some(specifier) })
+            .await?
+            .into_inner();
+
+        match block::block_response::Code::try_from(response.status) {
+            Ok(block::block_response::Code::Success) => {}
+            Ok(code) => {
+                return Err(Error::block_node(format!(
+                    "block request failed with status `{}`",
+                    code.as_str_name()
+                )))
+            }
+            Err(_) => {
+                return Err(Error::block_node(format!(
+                    "block request failed with unrecognized status `{}`",
+                    response.status
+                )))
+            }
+        }
+
+        let block = response
+            .block
+            .ok_or_else(|| Error::from_protobuf("unexpected missing `block` in block response"))?;
+
+        Block::from_items(block.items)
+    }
+}
+
+fn stream_blocks(
+    channel: Channel,
+    start_block_number: u64,
+    end_block_number: u64,
+) -> BoxStream<'static, crate::Result<Block>> {
+    let stream = async_stream::stream! {
+        let mut next_block_number = start_block_number;
+
+        let mut backoff = ExponentialBackoff { max_elapsed_time: None, ..ExponentialBackoff::default() };
+
+        loop {
+            let status = 'request: {
+                let request = block::SubscribeStreamRequest {
+                    start_block_number: next_block_number,
+                    end_block_number,
+                };
+
+                let mut stream = match BlockStreamSubscribeServiceClient::new(channel.clone())
+                    .subscribe_block_stream(request)
+                    .await
+                {
+                    Ok(response) => response.into_inner(),
+                    Err(status) => break 'request Some(status),
+                };
+
+                let mut assembler = BlockAssembler::default();
+
+                loop {
+                    let response = match stream.message().await {
+                        Ok(Some(response)) => response,
+                        // every requested block was delivered.
+                        Ok(None) if end_block_number != 0 && next_block_number > end_block_number => return,
+                        // the block node always ends a stream with a status, so the connection was dropped.
+                        Ok(None) => break 'request None,
+                        Err(status) => break 'request Some(status),
+                    };
+
+                    match assembler.push(response) {
+                        Ok(None) => {}
+                        Ok(Some(StreamEvent::Block(block))) => {
+                            backoff.reset();
+                            next_block_number = block.number + 1;
+
+                            yield Ok(block);
+                        }
+                        Ok(Some(StreamEvent::End)) => return,
+                        Err(e) => {
+                            yield Err(e);
+                            return;
+                        }
+                    }
+                }
+            };
+
+            if let Some(status) = status {
+                if !matches!(status.code(), tonic::Code::Unavailable | tonic::Code::ResourceExhausted) {
+                    yield Err(Error::from(status));
+                    return;
+                }
+            }
+
+            // encountered a temporarily down or overloaded block node, or a dropped connection
+            sleep(backoff.next_backoff().unwrap()).await;
+        }
+    };
+
+    Box::pin(stream)
+}
+
+enum StreamEvent {
+    Block(Block),
+    End,
+}
+
+/// Collects the items of each block from the responses of a block stream.
+#[derive(Default)]
+struct BlockAssembler {
+    items: Vec<Vec<u8>>,
+}
+
+impl BlockAssembler {
+    /// Returns the next block, or the end of the stream, if `response` completes it.
+    fn push(
+        &mut self,
+        response: block::SubscribeStreamResponse,
+    ) -> crate::Result<Option<StreamEvent>> {
+        use block::subscribe_stream_response::Code;
+
+        match response.response {
+            Some(Response::BlockItems(set)) => {
+                self.items.extend(set.block_items);
+                Ok(None)
+            }
+            Some(Response::EndOfBlock(end)) => {
+                let block = Block::from_items(std::mem::take(&mut self.items))?;
+
+                if block.number != end.block_number {
+                    return Err(Error::from_protobuf(format!(
+                        "block header number {} doesn't match end of block {}",
+                        block.number, end.block_number
+                    )));
+                }
+
+                Ok(Some(StreamEvent::Block(block)))
+            }
+            Some(Response::Status(status)) => match Code::try_from(status) {
+                Ok(Code::Success) => Ok(Some(StreamEvent::End)),
+                Ok(code) => Err(Error::block_node(format!(
+                    "block stream ended with status `{}`",
+                    code.as_str_name()
+                ))),
+                Err(_) => Err(Error::block_node(format!(
+                    "block stream ended with unrecognized status `{status}`"
+                ))),
+            },
+            None => Err(Error::from_protobuf("unexpected empty block stream response")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hedera_proto::block;
+    use hedera_proto::block::subscribe_stream_response::{
+        Code,
+        Response,
+    };
+    use prost::Message;
+
+    use super::{
+        BlockAssembler,
+        BlockNodeClient,
+        StreamEvent,
+    };
+    use crate::Error;
+
+    fn header(number: u64) -> Vec<u8> {
+        block::BlockItemHeader { block_header: Some(block::BlockHeader { number }) }.encode_to_vec()
+    }
+
+    fn response(response: Response) -> block::SubscribeStreamResponse {
+        block::SubscribeStreamResponse { response: Some(response) }
+    }
+
+    #[test]
+    fn assemble_blocks() {
+        let mut assembler = BlockAssembler::default();
+
+        let items =
+            |items| response(Response::BlockItems(block::BlockItemSet { block_items: items }));
+
+        assert_matches!(assembler.push(items(Vec::from([header(7)]))), Ok(None));
+        assert_matches!(assembler.push(items(Vec::from([Vec::from([1, 2, 3])]))), Ok(None));
+
+        let block = assert_matches!(
+            assembler.push(response(Response::EndOfBlock(block::BlockEnd { block_number: 7 }))),
+            Ok(Some(StreamEvent::Block(block))) => block
+        );
+
+        assert_eq!(block.number, 7);
+        assert_eq!(block.items, [header(7), Vec::from([1, 2, 3])]);
+
+        assert_matches!(
+            assembler.push(response(Response::Status(Code::Success.into()))),
+            Ok(Some(StreamEvent::End))
+        );
+    }
+
+    #[test]
+    fn assemble_mismatched_block() {
+        let mut assembler = BlockAssembler::default();
+
+        let items = block::BlockItemSet { block_items: Vec::from([header(7)]) };
+
+        assert_matches!(assembler.push(response(Response::BlockItems(items))), Ok(None));
+        assert_matches!(
+            assembler.push(response(Response::EndOfBlock(block::BlockEnd { block_number: 8 }))),
+            Err(Error::FromProtobuf(_))
+        );
+    }
+
+    #[test]
+    fn failure_status() {
+        let mut assembler = BlockAssembler::default();
+
+        assert_matches!(
+            assembler.push(response(Response::Status(Code::NotAvailable.into()))),
+            Err(Error::BlockNode(_))
+        );
+    }
+
+    #[tokio::test]
+    async fn for_address() {
+        assert_eq!(
+            BlockNodeClient::for_address("127.0.0.1:8080").unwrap().get_address(),
+            "127.0.0.1:8080"
+        );
+
+        assert_matches!(BlockNodeClient::for_address("127.0.0.1"), Err(Error::BasicParse(_)));
+        assert_matches!(
+            BlockNodeClient::for_address("127.0.0.1:8080/path"),
+            Err(Error::BasicParse(_))
+        );
+    }
+}
@@ -0,0 +1,383 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::client::InFlightRequest;
+use crate::execute::execute_in_flight;
+use crate::transaction::{
+    ChunkData,
+    TransactionData,
+    TransactionExecuteChunked,
+};
+use crate::{
+    Client,
+    Error,
+    Status,
+    Transaction,
+    TransactionId,
+    TransactionReceipt,
+    TransactionRecord,
+    TransactionResponse,
+};
+
+/// Response from [`Transaction::execute_chunked`][crate::Transaction::execute_chunked].
+///
+/// Holds the [`TransactionResponse`] of every chunk that was submitted, in chunk order.
+///
+/// Chunks are submitted one at a time, and submission stops at the first chunk that fails.
+/// If that happens after some chunks were submitted, the response of those chunks is returned in
+/// [`Error::ChunkedTransactionIncomplete`], and [`resume`](Self::resume) submits the rest.
+#[derive(Debug)]
+pub struct ChunkedTransactionResponse {
+    // invariant: never empty.
+    responses: Vec<TransactionResponse>,
+    total_chunks: usize,
+}
+
+impl ChunkedTransactionResponse {
+    /// Returns `None` if `responses` is empty, since then there's nothing to get receipts for or resume.
+    pub(crate) fn new(responses: Vec<TransactionResponse>, total_chunks: usize) -> Option<Self> {
+        if responses.is_empty() {
+            return None;
+        }
+
+        let total_chunks = total_chunks.max(responses.len());
+
+        Some(Self { responses, total_chunks })
+    }
+
+    /// Returns the response of each chunk, in chunk order.
+    #[must_use]
+    pub fn get_responses(&self) -> &[TransactionResponse] {
+        &self.responses
+    }
+
+    /// Returns the response of the first chunk, IE, the one [`Transaction::execute`](crate::Transaction::execute) would return.
+    #[must_use]
+    pub fn get_first_response(&self) -> &TransactionResponse {
+        &self.responses[0]
+    }
+
+    /// Returns the transaction ID of each chunk, in chunk order.
+    #[must_use]
+    pub fn get_transaction_ids(&self) -> Vec<TransactionId> {
+        self.responses.iter().map(|it| it.transaction_id).collect()
+    }
+
+    /// Returns the number of chunks that were submitted.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.responses.len()
+    }
+
+    /// Returns the number of chunks the transaction has, including any that weren't submitted.
+    #[must_use]
+    pub fn get_total_chunks(&self) -> usize {
+        self.total_chunks
+    }
+
+    /// Returns `true` if every chunk of the transaction was submitted.
+    ///
+    /// This doesn't mean every chunk was accepted, their receipts can still fail.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.responses.len() == self.total_chunks
+    }
+
+    /// Returns `false`, a chunked transaction always has at least one chunk.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.responses.is_empty()
+    }
+
+    /// Sets whether the receipt/record status of every chunk should be validated.
+    pub fn validate_status(&mut self, validate: bool) -> &mut Self {
+        for response in &mut self.responses {
+            response.validate_status(validate);
+        }
+
+        self
+    }
+
+    /// Get the receipt of every chunk, in chunk order.
+    /// Will wait for consensus.
+    ///
+    /// Receipts are fetched one at a time, stopping at the first one that fails.
+    ///
+    /// # Errors
+    /// - if [`validate_status`](TransactionResponse::validate_status) is `true`:
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for the first failing receipt.
+    /// - Any error [`TransactionResponse::get_receipt`] can return.
+    pub async fn get_all_receipts(
+        &self,
+        client: &Client,
+    ) -> crate::Result<Vec<TransactionReceipt>> {
+        let mut receipts = Vec::with_capacity(self.responses.len());
+
+        for response in &self.responses {
+            receipts.push(response.get_receipt(client).await?);
+        }
+
+        Ok(receipts)
+    }
+
+    /// Get the record of every chunk, in chunk order.
+    /// Will wait for consensus.
+    ///
+    /// Records are fetched one at a time, stopping at the first one that fails.
+    ///
+    /// # Errors
+    /// - if [`validate_status`](TransactionResponse::validate_status) is `true`:
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for the first failing receipt in a record.
+    /// - Any error [`TransactionResponse::get_record`] can return.
+    pub async fn get_all_records(&self, client: &Client) -> crate::Result<Vec<TransactionRecord>> {
+        let mut records = Vec::with_capacity(self.responses.len());
+
+        for response in &self.responses {
+            records.push(response.get_record(client).await?);
+        }
+
+        Ok(records)
+    }
+
+    /// Finish a chunked submission that partly failed, submitting only the chunks that weren't accepted.
+    ///
+    /// `transaction` must be the transaction this response is from.
+    ///
+    /// The receipt of every submitted chunk is checked first, chunks that reached consensus successfully are kept,
+    /// and every other chunk (failed, never reached consensus, or not submitted at all) is submitted again, in order.
+    /// Resubmitted chunks keep their chunk number and the transaction ID of the first chunk,
+    /// so consumers (IE, of a topic) can still put the message together.
+    /// Because of that, the first chunk itself can't be resubmitted:
+    /// if it wasn't accepted, the transaction has to be executed again from the start.
+    ///
+    /// Only a chunk whose receipt failed is known not to have been accepted.
+    /// A missing receipt could also mean that the chunk is still pending, or that it reached consensus
+    /// longer ago than the network keeps receipts for (a few minutes), so such a chunk is never submitted again.
+    ///
+    /// The responses are replaced as chunks are resubmitted,
+    /// so if this fails, it can be called again to continue from where it stopped.
+    ///
+    /// # Errors
+    /// - [`Error::ChunkedTransactionResume`] if `transaction` doesn't have the same number of chunks as this response,
+    ///   if the first chunk wasn't accepted, or if the receipt of a submitted chunk can't be found.
+    /// - Any error [`TransactionResponse::get_receipt`] can return, other than a failing or missing receipt.
+    /// - Any error [`Transaction::execute_chunked`] can return, for the first chunk that fails to be resubmitted.
+    pub async fn resume<D>(
+        &mut self,
+        client: &Client,
+        transaction: &Transaction<D>,
+    ) -> crate::Result<()>
+    where
+        D: TransactionExecuteChunked,
+    {
+        let chunk_data = transaction.data().maybe_chunk_data();
+
+        let used_chunks = chunk_data.map_or(1, ChunkData::used_chunks);
+
+        if used_chunks != self.total_chunks {
+            return Err(Error::chunked_transaction_resume(format!(
+                "`transaction` has {used_chunks} chunks, but the response is for {} chunks",
+                self.total_chunks
+            )));
+        }
+
+        // like the original submission, the whole resumption is a single request,
+        // so that every chunk and receipt goes to the same environment.
+        let in_flight = client.in_flight_request().await;
+
+        let first = &self.responses[0];
+
+        // every other chunk refers to the transaction ID of the first one,
+        // so it can't be submitted again with a new one.
+        if !is_accepted(first, client, &in_flight).await? {
+            return Err(Error::chunked_transaction_resume(
+                "the first chunk wasn't accepted, execute the transaction again from the start",
+            ));
+        }
+
+        let initial_transaction_id = first.transaction_id;
+        let node_account_id = chunk_data.filter(|it| it.sticky_node).map(|_| first.node_account_id);
+
+        for chunk in 1..self.total_chunks {
+            if let Some(response) = self.responses.get(chunk) {
+                if is_accepted(response, client, &in_flight).await? {
+                    continue;
+                }
+            }
+
+            let mut response = transaction
+                .submit_chunk(
+                    client,
+                    &in_flight,
+                    initial_transaction_id,
+                    chunk,
+                    node_account_id,
+                    None,
+                )
+                .await?;
+
+            match self.responses.get_mut(chunk) {
+                Some(it) => {
+                    response.validate_status(it.validate_status);
+                    *it = response;
+                }
+                None => self.responses.push(response),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns whether `response`'s transaction reached consensus successfully.
+async fn is_accepted(
+    response: &TransactionResponse,
+    client: &Client,
+    in_flight: &InFlightRequest<'_>,
+) -> crate::Result<bool> {
+    let mut query = response.get_receipt_query();
+    query.validate_status(true);
+
+    receipt_accepted(
+        execute_in_flight(client, in_flight, &query, None).await,
+        response.transaction_id,
+    )
+}
+
+/// Returns whether a transaction reached consensus successfully, from the `result` of a receipt query that validates the status.
+///
+/// Only a failing receipt means that it wasn't, a missing receipt means that it's pending,
+/// or that it reached consensus too long ago for the network to still have the receipt.
+fn receipt_accepted(
+    result: crate::Result<TransactionReceipt>,
+    transaction_id: TransactionId,
+) -> crate::Result<bool> {
+    match result {
+        Ok(_) => Ok(true),
+        Err(Error::ReceiptStatus { .. }) => Ok(false),
+        Err(e) if is_receipt_not_found(&e) => Err(Error::chunked_transaction_resume(format!(
+            "the receipt of `{transaction_id}` can't be found, it may still be pending or may have already reached consensus"
+        ))),
+        Err(e) => Err(e),
+    }
+}
+
+fn is_receipt_not_found(error: &Error) -> bool {
+    match error {
+        Error::QueryPreCheckStatus { status, .. } => *status == Status::ReceiptNotFound,
+        Error::TimedOut(inner) => is_receipt_not_found(inner),
+        _ => false,
+    }
+}
+
+impl From<ChunkedTransactionResponse> for Vec<TransactionResponse> {
+    fn from(value: ChunkedTransactionResponse) -> Self {
+        value.responses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        is_receipt_not_found,
+        receipt_accepted,
+        ChunkedTransactionResponse,
+    };
+    use crate::transaction::test_helpers::TEST_TX_ID;
+    use crate::transaction_receipt::make_receipt;
+    use crate::{
+        AccountId,
+        Error,
+        Status,
+        TransactionHash,
+        TransactionResponse,
+    };
+
+    fn response() -> TransactionResponse {
+        TransactionResponse {
+            node_account_id: AccountId::new(0, 0, 3),
+            transaction_id: TEST_TX_ID,
+            transaction_hash: TransactionHash([0; 48]),
+            validate_status: true,
+        }
+    }
+
+    #[test]
+    fn incomplete() {
+        let response = ChunkedTransactionResponse::new(Vec::from([response()]), 3).unwrap();
+
+        assert!(!response.is_complete());
+        assert_eq!(response.len(), 1);
+        assert_eq!(response.get_total_chunks(), 3);
+
+        assert!(ChunkedTransactionResponse::new(Vec::new(), 3).is_none());
+
+        let error = Error::ChunkedTransactionIncomplete {
+            response: Box::new(response),
+            source: Box::new(Error::Cancelled),
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "chunked transaction was only partly submitted (1 of 3 chunks): request was cancelled"
+        );
+    }
+
+    #[cfg(feature = "topics")]
+    #[tokio::test]
+    async fn resume_mismatched_transaction() {
+        use crate::{
+            Client,
+            TopicMessageSubmitTransaction,
+        };
+
+        let mut response = ChunkedTransactionResponse::new(Vec::from([response()]), 3).unwrap();
+
+        let mut transaction = TopicMessageSubmitTransaction::new();
+        transaction.message(vec![0; 10]);
+
+        let error = response.resume(&Client::for_testnet(), &transaction).await.unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "failed to resume chunked transaction: `transaction` has 1 chunks, but the response is for 3 chunks"
+        );
+    }
+
+    #[test]
+    fn receipt_not_found() {
+        let not_found = Error::QueryPreCheckStatus {
+            status: Status::ReceiptNotFound,
+            transaction_id: Box::new(TEST_TX_ID),
+        };
+
+        assert!(is_receipt_not_found(&not_found));
+        assert!(is_receipt_not_found(&Error::TimedOut(Box::new(not_found))));
+        assert!(!is_receipt_not_found(&Error::QueryPreCheckStatus {
+            status: Status::Busy,
+            transaction_id: Box::new(TEST_TX_ID),
+        }));
+    }
+
+    #[test]
+    fn missing_receipt_isnt_resubmitted() {
+        assert!(receipt_accepted(Ok(make_receipt()), TEST_TX_ID).unwrap());
+
+        let failed = Error::ReceiptStatus {
+            status: Status::InvalidSignature,
+            transaction_id: Some(Box::new(TEST_TX_ID)),
+        };
+
+        assert!(!receipt_accepted(Err(failed), TEST_TX_ID).unwrap());
+
+        // the chunk was accepted, but its receipt expired, so it mustn't be submitted again.
+        let not_found = Error::QueryPreCheckStatus {
+            status: Status::ReceiptNotFound,
+            transaction_id: Box::new(TEST_TX_ID),
+        };
+
+        assert!(matches!(
+            receipt_accepted(Err(Error::TimedOut(Box::new(not_found))), TEST_TX_ID),
+            Err(Error::ChunkedTransactionResume(_))
+        ));
+    }
+}
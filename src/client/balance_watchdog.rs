@@ -0,0 +1,377 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use tokio::task::JoinHandle;
+use tokio::time::{
+    Interval,
+    MissedTickBehavior,
+};
+use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
+
+use super::Client;
+use crate::logger::client_log;
+use crate::{
+    AccountBalanceQuery,
+    AccountId,
+    Error,
+    Hbar,
+};
+
+type LowBalanceCallback = Arc<dyn Fn(AccountId, Hbar) + Send + Sync>;
+
+/// Periodically checks the balance of an account (by default the client's operator),
+/// and reacts when it falls below a threshold.
+///
+/// This lets long batch jobs stop cleanly, rather than half completing
+/// once transactions start failing with `INSUFFICIENT_PAYER_BALANCE`.
+///
+/// The balance is checked with [`AccountBalanceQuery`], which is free.
+#[derive(Clone)]
+pub struct BalanceWatchdog {
+    threshold: Hbar,
+    account_id: Option<AccountId>,
+    check_period: Duration,
+    fail_fast: bool,
+    on_low_balance: Option<LowBalanceCallback>,
+}
+
+impl std::fmt::Debug for BalanceWatchdog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BalanceWatchdog")
+            .field("threshold", &self.threshold)
+            .field("account_id", &self.account_id)
+            .field("check_period", &self.check_period)
+            .field("fail_fast", &self.fail_fast)
+            .finish_non_exhaustive()
+    }
+}
+
+impl BalanceWatchdog {
+    /// Create a new watchdog that reacts when the balance falls below `threshold`.
+    #[must_use]
+    pub fn new(threshold: Hbar) -> Self {
+        Self {
+            threshold,
+            account_id: None,
+            check_period: Duration::from_secs(60),
+            fail_fast: false,
+            on_low_balance: None,
+        }
+    }
+
+    /// Returns the balance below which the watchdog reacts.
+    #[must_use]
+    pub fn get_threshold(&self) -> Hbar {
+        self.threshold
+    }
+
+    /// Sets the balance below which the watchdog reacts.
+    pub fn threshold(&mut self, threshold: Hbar) -> &mut Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Returns the account to watch, if set.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.account_id
+    }
+
+    /// Sets the account to watch.
+    ///
+    /// Defaults to the client's operator.
+    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.account_id = Some(account_id);
+        self
+    }
+
+    /// Returns how often the balance is checked.
+    #[must_use]
+    pub fn get_check_period(&self) -> Duration {
+        self.check_period
+    }
+
+    /// Sets how often the balance is checked.
+    ///
+    /// Defaults to once a minute.
+    pub fn check_period(&mut self, check_period: Duration) -> &mut Self {
+        self.check_period = check_period;
+        self
+    }
+
+    /// Returns whether transactions fail fast while the balance is low.
+    #[must_use]
+    pub fn get_fail_fast(&self) -> bool {
+        self.fail_fast
+    }
+
+    /// Sets whether transactions paid for by the watched account fail with
+    /// [`Error::OperatorBalanceLow`] instead of being submitted while the balance is low.
+    ///
+    /// Defaults to `false`.
+    pub fn fail_fast(&mut self, fail_fast: bool) -> &mut Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// Sets a callback that's called with the account and its balance when the balance falls below the threshold.
+    ///
+    /// The callback is called once each time the balance falls below the threshold,
+    /// not on every check while it stays there.
+    pub fn on_low_balance<F: Fn(AccountId, Hbar) + Send + Sync + 'static>(
+        &mut self,
+        callback: F,
+    ) -> &mut Self {
+        self.on_low_balance =
+            Some(Arc::new(callback).unsize(Coercion!(to dyn Fn(AccountId, Hbar) + Send + Sync)));
+        self
+    }
+
+    /// Start watching the balance, in a background task, using `client`.
+    ///
+    /// The watchdog replaces any other watchdog started for `client`,
+    /// and runs until the returned handle is dropped.
+    ///
+    /// The background task is spawned on the current tokio runtime.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if no [`account_id`](Self::account_id) was set and `client` has no operator.
+    ///
+    /// # Panics
+    /// - If called outside of a tokio runtime.
+    /// - If [`check_period`](Self::check_period) is zero.
+    #[must_use = "the watchdog stops when its handle is dropped"]
+    pub fn start(&self, client: &Client) -> crate::Result<BalanceWatchdogHandle> {
+        let Some(account_id) = self.account_id.or_else(|| client.get_operator_account_id()) else {
+            return Err(Error::basic_parse(
+                "`account_id` must be set to watch the balance with a client that has no operator",
+            ));
+        };
+
+        let state = Arc::new(WatchdogState {
+            account_id,
+            threshold: self.threshold,
+            fail_fast: self.fail_fast,
+            balance: Mutex::new(None),
+        });
+
+        let mut interval = tokio::time::interval(self.check_period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        client.set_balance_watchdog_state(Some(Arc::clone(&state)));
+
+        let task = tokio::task::spawn(watch(
+            client.clone(),
+            Arc::clone(&state),
+            interval,
+            self.on_low_balance.clone(),
+        ));
+
+        Ok(BalanceWatchdogHandle { client: client.clone(), state, task })
+    }
+}
+
+/// A running [`BalanceWatchdog`].
+///
+/// The watchdog is stopped when this is dropped.
+pub struct BalanceWatchdogHandle {
+    client: Client,
+    state: Arc<WatchdogState>,
+    task: JoinHandle<()>,
+}
+
+impl BalanceWatchdogHandle {
+    /// Returns the account being watched.
+    #[must_use]
+    pub fn get_account_id(&self) -> AccountId {
+        self.state.account_id
+    }
+
+    /// Returns the balance from the most recent successful check, if there's been one.
+    #[must_use]
+    pub fn get_last_balance(&self) -> Option<Hbar> {
+        *self.state.balance.lock()
+    }
+
+    /// Returns true if the most recent successful check found the balance below the threshold.
+    #[must_use]
+    pub fn is_low(&self) -> bool {
+        self.state.is_low()
+    }
+}
+
+impl std::fmt::Debug for BalanceWatchdogHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BalanceWatchdogHandle")
+            .field("account_id", &self.state.account_id)
+            .field("last_balance", &self.get_last_balance())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for BalanceWatchdogHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+        self.client.clear_balance_watchdog_state(&self.state);
+    }
+}
+
+/// The state of a watchdog shared between its task, its handle, and the client (to fail fast).
+pub(crate) struct WatchdogState {
+    account_id: AccountId,
+    threshold: Hbar,
+    fail_fast: bool,
+    balance: Mutex<Option<Hbar>>,
+}
+
+impl WatchdogState {
+    fn is_low(&self) -> bool {
+        self.balance.lock().is_some_and(|it| it < self.threshold)
+    }
+
+    /// Records `balance`, and returns true if it just fell below the threshold.
+    fn update(&self, balance: Hbar) -> bool {
+        let was_low = self.balance.lock().replace(balance).is_some_and(|it| it < self.threshold);
+
+        !was_low && balance < self.threshold
+    }
+
+    /// Returns an error if transactions paid for by `payer` should fail fast.
+    pub(crate) fn check(&self, payer: AccountId) -> crate::Result<()> {
+        if !self.fail_fast || payer != self.account_id {
+            return Ok(());
+        }
+
+        match *self.balance.lock() {
+            Some(balance) if balance < self.threshold => Err(Error::OperatorBalanceLow {
+                account_id: self.account_id,
+                balance,
+                threshold: self.threshold,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+async fn watch(
+    client: Client,
+    state: Arc<WatchdogState>,
+    mut interval: Interval,
+    on_low_balance: Option<LowBalanceCallback>,
+) {
+    loop {
+        interval.tick().await;
+
+        let balance =
+            match AccountBalanceQuery::new().account_id(state.account_id).execute(&client).await {
+                Ok(it) => it.hbars,
+                Err(e) => {
+                    client_log!(
+                        client.logger(),
+                        Execution,
+                        Warn,
+                        "balance watchdog failed to check the balance of `{}`: {e}",
+                        state.account_id
+                    );
+                    continue;
+                }
+            };
+
+        if state.update(balance) {
+            client_log!(
+                client.logger(),
+                Execution,
+                Warn,
+                "balance of `{}` is {balance}, below the watchdog threshold of {}",
+                state.account_id,
+                state.threshold
+            );
+
+            if let Some(callback) = &on_low_balance {
+                callback(state.account_id, balance);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use parking_lot::Mutex;
+
+    use super::WatchdogState;
+    use crate::{
+        AccountId,
+        BalanceWatchdog,
+        Client,
+        Error,
+        Hbar,
+    };
+
+    const ACCOUNT_ID: AccountId = AccountId::new(0, 0, 1001);
+
+    fn state(fail_fast: bool) -> WatchdogState {
+        WatchdogState {
+            account_id: ACCOUNT_ID,
+            threshold: Hbar::new(10),
+            fail_fast,
+            balance: Mutex::new(None),
+        }
+    }
+
+    #[test]
+    fn update_reports_falling_below_once() {
+        let state = state(false);
+
+        assert!(!state.is_low());
+        assert!(!state.update(Hbar::new(20)));
+        assert!(state.update(Hbar::new(5)));
+        assert!(!state.update(Hbar::new(4)));
+        assert!(state.is_low());
+
+        // topped up, then low again.
+        assert!(!state.update(Hbar::new(10)));
+        assert!(state.update(Hbar::new(9)));
+    }
+
+    #[test]
+    fn check_fails_fast_only_when_low() {
+        let state = state(true);
+
+        assert_matches!(state.check(ACCOUNT_ID), Ok(()));
+
+        state.update(Hbar::new(5));
+
+        assert_matches!(state.check(ACCOUNT_ID), Err(Error::OperatorBalanceLow { .. }));
+        // other payers aren't affected.
+        assert_matches!(state.check(AccountId::new(0, 0, 2)), Ok(()));
+
+        state.update(Hbar::new(50));
+
+        assert_matches!(state.check(ACCOUNT_ID), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn start_without_operator() {
+        let client = Client::for_testnet();
+
+        assert_matches!(
+            BalanceWatchdog::new(Hbar::new(10)).start(&client),
+            Err(Error::BasicParse(_))
+        );
+    }
+
+    #[test]
+    fn check_without_fail_fast() {
+        let state = state(false);
+        state.update(Hbar::new(5));
+
+        assert_matches!(state.check(ACCOUNT_ID), Ok(()));
+    }
+}
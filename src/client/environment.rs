@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use super::network::mirror;
+use super::network::{
+    NetworkData,
+    MAINNET,
+    PREVIEWNET,
+    TESTNET,
+};
+use crate::{
+    AccountId,
+    Error,
+    LedgerId,
+};
+
+/// The address of a local consensus node, IE, one run with `hiero-local-node`.
+const LOCAL_NODE: &str = "127.0.0.1:50211";
+
+/// The address of a local mirror node.
+const LOCAL_MIRROR_NODE: &str = "127.0.0.1:5600";
+
+/// A network a [`Client`](crate::Client) can be configured for:
+/// its consensus nodes, mirror nodes, and ledger ID together.
+///
+/// An environment can be picked by name (IE, from configuration) with [`FromStr`],
+/// and an existing client can be switched to another environment
+/// with [`Client::switch_environment`](crate::Client::switch_environment).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Environment {
+    /// The Hedera mainnet.
+    Mainnet,
+
+    /// The Hedera testnet.
+    Testnet,
+
+    /// The Hedera previewnet.
+    Previewnet,
+
+    /// A single local node and mirror node at their default ports, IE, as run by `hiero-local-node`.
+    Local,
+
+    /// Any other network.
+    Custom {
+        /// The consensus nodes, as a map of `host:port` addresses to node account IDs.
+        network: HashMap<String, AccountId>,
+
+        /// The `host:port` addresses of the mirror nodes.
+        mirror_network: Vec<String>,
+
+        /// The ledger ID of the network, if known.
+        ledger_id: Option<LedgerId>,
+    },
+}
+
+impl Environment {
+    /// Returns the consensus nodes of this environment, as a map of `host:port` addresses to node account IDs.
+    #[must_use]
+    pub fn network(&self) -> HashMap<String, AccountId> {
+        match self {
+            Self::Mainnet => NetworkData::from_static(MAINNET).addresses(),
+            Self::Testnet => NetworkData::from_static(TESTNET).addresses(),
+            Self::Previewnet => NetworkData::from_static(PREVIEWNET).addresses(),
+            Self::Local => HashMap::from([(LOCAL_NODE.to_owned(), AccountId::new(0, 0, 3))]),
+            Self::Custom { network, .. } => network.clone(),
+        }
+    }
+
+    /// Returns the `host:port` addresses of the mirror nodes of this environment.
+    #[must_use]
+    pub fn mirror_network(&self) -> Vec<String> {
+        match self {
+            Self::Mainnet => Vec::from([mirror::MAINNET.to_owned()]),
+            Self::Testnet => Vec::from([mirror::TESTNET.to_owned()]),
+            Self::Previewnet => Vec::from([mirror::PREVIEWNET.to_owned()]),
+            Self::Local => Vec::from([LOCAL_MIRROR_NODE.to_owned()]),
+            Self::Custom { mirror_network, .. } => mirror_network.clone(),
+        }
+    }
+
+    /// Returns the ledger ID of this environment, if known.
+    #[must_use]
+    pub fn ledger_id(&self) -> Option<LedgerId> {
+        match self {
+            Self::Mainnet => Some(LedgerId::mainnet()),
+            Self::Testnet => Some(LedgerId::testnet()),
+            Self::Previewnet => Some(LedgerId::previewnet()),
+            Self::Local => None,
+            Self::Custom { ledger_id, .. } => ledger_id.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Mainnet => "mainnet",
+            Self::Testnet => "testnet",
+            Self::Previewnet => "previewnet",
+            Self::Local => "local",
+            Self::Custom { .. } => "custom",
+        })
+    }
+}
+
+impl FromStr for Environment {
+    type Err = Error;
+
+    /// Parses an environment name, one of `mainnet`, `testnet`, `previewnet`, or `local` (also `localhost`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mainnet" => Ok(Self::Mainnet),
+            "testnet" => Ok(Self::Testnet),
+            "previewnet" => Ok(Self::Previewnet),
+            "local" | "localhost" => Ok(Self::Local),
+            _ => Err(Error::basic_parse(format!("Unknown environment name {s}"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    use super::Environment;
+    use crate::{
+        AccountId,
+        LedgerId,
+    };
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Environment::from_str("mainnet").unwrap(), Environment::Mainnet);
+        assert_eq!(Environment::from_str("testnet").unwrap(), Environment::Testnet);
+        assert_eq!(Environment::from_str("previewnet").unwrap(), Environment::Previewnet);
+        assert_eq!(Environment::from_str("localhost").unwrap(), Environment::Local);
+        assert_eq!(Environment::from_str("local").unwrap(), Environment::Local);
+        assert!(Environment::from_str("devnet").is_err());
+    }
+
+    #[test]
+    fn testnet() {
+        let env = Environment::Testnet;
+
+        assert_eq!(env.ledger_id(), Some(LedgerId::testnet()));
+        assert_eq!(env.mirror_network(), ["testnet.mirrornode.hedera.com:443"]);
+        assert_eq!(env.network().get("0.testnet.hedera.com:50211"), Some(&AccountId::new(0, 0, 3)));
+    }
+
+    #[test]
+    fn custom() {
+        let network = HashMap::from([("10.0.0.1:50211".to_owned(), AccountId::new(0, 0, 3))]);
+
+        let env = Environment::Custom {
+            network: network.clone(),
+            mirror_network: Vec::from(["10.0.0.2:5600".to_owned()]),
+            ledger_id: None,
+        };
+
+        assert_eq!(env.network(), network);
+        assert_eq!(env.mirror_network(), ["10.0.0.2:5600"]);
+        assert_eq!(env.ledger_id(), None);
+    }
+}
@@ -14,36 +14,70 @@ use std::sync::atomic::{
 };
 use std::time::Duration;
 
+pub use balance_watchdog::{
+    BalanceWatchdog,
+    BalanceWatchdogHandle,
+};
+pub use environment::Environment;
+#[cfg(feature = "unstable")]
+pub(crate) use network::mirror::grpc_channel;
+pub use network::NodeStats;
 pub(crate) use network::{
     Network,
     NetworkData,
 };
+pub use network_validation::NetworkProblem;
 pub(crate) use operator::Operator;
 use parking_lot::RwLock;
 use tokio::sync::watch;
 use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
 
+use self::balance_watchdog::WatchdogState;
 use self::network::managed::ManagedNetwork;
 use self::network::mirror::MirrorNetwork;
-pub(crate) use self::network::mirror::MirrorNetworkData;
+pub(crate) use self::network::mirror::{
+    MirrorChannel,
+    MirrorHeaders,
+    MirrorNetworkData,
+};
+use crate::logger::{
+    client_log,
+    ClientLogger,
+};
 use crate::ping_query::PingQuery;
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
     ArcSwapOption,
+    Clock,
     Error,
     Hbar,
     LedgerId,
+    LogLevel,
+    LogSubsystem,
+    Logger,
+    MemoTemplate,
     NodeAddressBook,
     NodeAddressBookQuery,
     PrivateKey,
     PublicKey,
+    ResponseMismatch,
+    RetryEvent,
+    SystemClock,
+    TransactionId,
 };
 
+mod balance_watchdog;
 #[cfg(feature = "serde")]
 mod config;
 
+mod environment;
 mod network;
+mod network_validation;
 mod operator;
 
 #[derive(Copy, Clone)]
@@ -68,6 +102,17 @@ impl Default for ClientBackoff {
     }
 }
 
+/// How often the network is updated from the address book, unless configured otherwise.
+const DEFAULT_NETWORK_UPDATE_PERIOD: Duration = Duration::from_secs(24 * 60 * 60);
+
+type RetryHook = std::sync::Arc<dyn Fn(&RetryEvent) + Send + Sync>;
+
+type ResponseMismatchHook = std::sync::Arc<dyn Fn(&ResponseMismatch) + Send + Sync>;
+
+type MemoValuesHook = std::sync::Arc<dyn Fn() -> HashMap<String, String> + Send + Sync>;
+
+pub(crate) type SharedClock = Arc<dyn Clock>;
+
 // yes, client is complicated enough for this, even if it's only internal.
 struct ClientBuilder {
     network: ManagedNetwork,
@@ -121,7 +166,7 @@ impl ClientBuilder {
         let network_update_tx = match update_network {
             true => network::managed::spawn_network_update(
                 network.clone(),
-                Some(Duration::from_secs(24 * 60 * 60)),
+                Some(DEFAULT_NETWORK_UPDATE_PERIOD),
             ),
             // yeah, we just drop the rx.
             false => watch::channel(None).0,
@@ -138,7 +183,17 @@ impl ClientBuilder {
             auto_validate_checksums: AtomicBool::new(auto_validate_checksums),
             regenerate_transaction_ids: AtomicBool::new(regenerate_transaction_ids),
             network_update_tx,
+            environment_lock: tokio::sync::RwLock::new(()),
+            environment: AtomicU64::new(0),
+            in_flight_requests: watch::channel(0).0,
             backoff: RwLock::new(backoff),
+            auto_fee_bump_limit_tinybar: AtomicU64::new(0),
+            retry_hook: RwLock::new(None),
+            response_mismatch_hook: RwLock::new(None),
+            memo_template: ArcSwapOption::new(None),
+            memo_values_hook: RwLock::new(None),
+            balance_watchdog: RwLock::new(None),
+            clock: RwLock::new(Arc::new(SystemClock).unsize(Coercion!(to dyn Clock))),
         }))
     }
 }
@@ -152,7 +207,29 @@ struct ClientInner {
     auto_validate_checksums: AtomicBool,
     regenerate_transaction_ids: AtomicBool,
     network_update_tx: watch::Sender<Option<Duration>>,
+    // held for reading by every request, and for writing while switching environments.
+    environment_lock: tokio::sync::RwLock<()>,
+    // incremented by every environment switch, so that transactions frozen before a switch can be rejected.
+    environment: AtomicU64,
+    // the number of requests to the consensus nodes that haven't finished yet, waited on while switching environments.
+    in_flight_requests: watch::Sender<usize>,
     backoff: RwLock<ClientBackoff>,
+    auto_fee_bump_limit_tinybar: AtomicU64,
+    retry_hook: RwLock<Option<RetryHook>>,
+    response_mismatch_hook: RwLock<Option<ResponseMismatchHook>>,
+    memo_template: ArcSwapOption<MemoTemplate>,
+    memo_values_hook: RwLock<Option<MemoValuesHook>>,
+    balance_watchdog: RwLock<Option<Arc<WatchdogState>>>,
+    clock: RwLock<SharedClock>,
+}
+
+/// A request that [`Client::switch_environment`] waits for, see [`Client::in_flight_request`].
+pub(crate) struct InFlightRequest<'a>(&'a watch::Sender<usize>);
+
+impl Drop for InFlightRequest<'_> {
+    fn drop(&mut self) {
+        self.0.send_modify(|it| *it -= 1);
+    }
 }
 
 /// Managed client for use on the Hiero network.
@@ -251,12 +328,71 @@ impl Client {
     ///
     /// This is mostly useful if you used [`Self::for_network`] and need to set a mirror network.
     pub fn set_mirror_network<I: IntoIterator<Item = String>>(&self, addresses: I) {
+        let headers = self.mirrornet().load().headers().clone();
+
         self.mirrornet().store(
             MirrorNetworkData::from_addresses(addresses.into_iter().map(Cow::Owned).collect())
+                .with_headers(headers)
                 .into(),
         );
     }
 
+    /// Returns the headers added to every request to the mirror network, as `(name, value)` pairs.
+    ///
+    /// The values of auth headers (IE, API keys and bearer tokens) are replaced with `<sensitive>`.
+    #[must_use]
+    pub fn mirror_headers(&self) -> Vec<(String, String)> {
+        self.mirrornet().load().headers().to_pairs()
+    }
+
+    /// Sets the headers added to every gRPC and REST request to the mirror network, replacing any set before.
+    ///
+    /// Hosted mirror node providers usually require authentication,
+    /// see [`set_mirror_api_key`](Self::set_mirror_api_key) and [`set_mirror_bearer_token`](Self::set_mirror_bearer_token).
+    ///
+    /// The headers are kept when the mirror network is changed.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if a name isn't a valid header name, or a value isn't a valid header value.
+    pub fn set_mirror_headers<K: AsRef<str>, V: AsRef<str>>(
+        &self,
+        headers: impl IntoIterator<Item = (K, V)>,
+    ) -> crate::Result<()> {
+        let headers = MirrorHeaders::parse(headers)?;
+
+        let mirrornet = self.mirrornet().load();
+        self.mirrornet().store(mirrornet.with_headers(headers).into());
+
+        Ok(())
+    }
+
+    /// Sets the API key sent to the mirror network in the `x-api-key` header, keeping any other headers.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `api_key` isn't a valid header value.
+    pub fn set_mirror_api_key(&self, api_key: &str) -> crate::Result<()> {
+        self.add_mirror_header("x-api-key", api_key)
+    }
+
+    /// Sets the bearer token sent to the mirror network in the `authorization` header, keeping any other headers.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `token` isn't a valid header value.
+    pub fn set_mirror_bearer_token(&self, token: &str) -> crate::Result<()> {
+        self.add_mirror_header("authorization", &format!("Bearer {token}"))
+    }
+
+    fn add_mirror_header(&self, name: &str, value: &str) -> crate::Result<()> {
+        let mirrornet = self.mirrornet().load();
+
+        let mut headers = mirrornet.headers().as_header_map().clone();
+        headers.extend(MirrorHeaders::parse([(name, value)])?.as_header_map().clone());
+
+        self.mirrornet().store(mirrornet.with_headers(MirrorHeaders::from(headers)).into());
+
+        Ok(())
+    }
+
     /// Construct a client with the given nodes configured.
     ///
     /// Note that this disables network auto-updating.
@@ -266,10 +402,15 @@ impl Client {
     // allowed for API compatibility.
     #[allow(clippy::needless_pass_by_value)]
     pub fn for_network(network: HashMap<String, AccountId>) -> crate::Result<Self> {
+        let problems = network_validation::validate_offline(&network, None);
+
         let network =
             ManagedNetwork::new(Network::from_addresses(&network)?, MirrorNetwork::default());
 
-        Ok(ClientBuilder::new(network).disable_network_updating().build())
+        let client = ClientBuilder::new(network).disable_network_updating().build();
+        client.warn_network_problems(&problems);
+
+        Ok(client)
     }
 
     /// Construct a client from a select mirror network
@@ -345,17 +486,61 @@ impl Client {
     // allowed for API compatibility.
     #[allow(clippy::needless_pass_by_value)]
     pub fn set_network(&self, network: HashMap<String, AccountId>) -> crate::Result<()> {
+        self.warn_network_problems(&network_validation::validate_offline(
+            &network,
+            self.ledger_id_internal().as_deref(),
+        ));
+
         self.net().update_from_addresses(&network)?;
 
         Ok(())
     }
 
+    /// Checks the configured nodes for problems that can be found without any network access.
+    ///
+    /// This checks that every address is a valid `host:port` endpoint, that no endpoint is mapped to more than one node,
+    /// and that addresses of the known networks are mapped to the right node, of the right ledger.
+    ///
+    /// [`for_network`](Self::for_network) and [`set_network`](Self::set_network) log these problems as warnings.
+    #[must_use]
+    pub fn validate_network(&self) -> Vec<NetworkProblem> {
+        network_validation::validate_offline(&self.network(), self.ledger_id_internal().as_deref())
+    }
+
+    /// Checks the configured nodes for problems, including ones that need network access.
+    ///
+    /// In addition to the checks done by [`validate_network`](Self::validate_network),
+    /// this checks that every endpoint resolves, and that the nodes match the network's own address book,
+    /// which is queried from the mirror network.
+    ///
+    /// # Errors
+    /// - Any error that occurs querying the address book.
+    pub async fn validate_network_with_address_book(&self) -> crate::Result<Vec<NetworkProblem>> {
+        let network = self.network();
+
+        let mut problems = self.validate_network();
+        problems.extend(network_validation::unresolvable_endpoints(&network).await);
+
+        let address_book = NodeAddressBookQuery::new().execute(self).await?;
+        problems.extend(network_validation::validate_address_book(&network, &address_book));
+
+        Ok(problems)
+    }
+
     /// Returns the nodes associated with this client.
     #[must_use]
     pub fn network(&self) -> HashMap<String, AccountId> {
         self.net().0.load().addresses()
     }
 
+    /// Returns statistics of the requests sent to each node by this client.
+    ///
+    /// Nodes that have recently been busy or unreachable are tried after other nodes.
+    #[must_use]
+    pub fn network_stats(&self) -> HashMap<AccountId, NodeStats> {
+        self.net().0.load().stats().snapshot()
+    }
+
     /// Returns the max number of times a node can be retried before removing it from the network.
     pub fn max_node_attempts(&self) -> Option<NonZeroUsize> {
         self.net().0.load().max_node_attempts()
@@ -409,6 +594,102 @@ impl Client {
         }
     }
 
+    /// Construct a client pre-configured for access to the given environment.
+    ///
+    /// As with [`for_network`](Self::for_network), network auto-updating is only enabled for the public networks.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if any node address of a custom environment is unparsable.
+    pub fn for_environment(environment: Environment) -> crate::Result<Self> {
+        let client = match environment {
+            Environment::Mainnet => Self::for_mainnet(),
+            Environment::Testnet => Self::for_testnet(),
+            Environment::Previewnet => Self::for_previewnet(),
+            Environment::Local | Environment::Custom { .. } => {
+                let client = Self::for_network(environment.network())?;
+                client.set_mirror_network(environment.mirror_network());
+                client.set_ledger_id(environment.ledger_id());
+                client
+            }
+        };
+
+        Ok(client)
+    }
+
+    /// Switches this client, and every clone of it, to the given environment.
+    ///
+    /// This replaces the nodes, mirror nodes, and ledger ID of the client.
+    /// Requests to the consensus nodes that are in-flight finish against the old environment (including their retries),
+    /// and requests started while switching wait for it to complete, so no request mixes the two environments.
+    /// The switch waits for the in-flight requests to finish (drains them),
+    /// so once this returns no more requests are sent to the old environment.
+    ///
+    /// Transactions frozen with this client before the switch (which picked their nodes and transaction ID
+    /// from the old environment) fail with [`Error::EnvironmentSwitched`] when executed afterwards.
+    ///
+    /// The operator is kept, so an operator that doesn't exist in the new environment should be replaced afterwards.
+    /// The network update period is also kept,
+    /// so a client that doesn't auto-update its network (IE, one from [`for_network`](Self::for_network)) still won't.
+    ///
+    /// Every chunk of a chunked transaction (and the receipts waited for between them) counts as a single request,
+    /// so a switch waits for the whole transaction, and its chunks never go to different environments.
+    ///
+    /// Mirror node subscriptions and queries aren't waited on,
+    /// and requests made after switching (IE, fetching the receipt of an earlier transaction) go to the new environment.
+    ///
+    /// Note: a request that's started and waited for by another one that's in-flight
+    /// waits for the switch, which waits for the request it was started by, so neither finishes.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if any node address of a custom environment is unparsable.
+    pub async fn switch_environment(&self, environment: Environment) -> crate::Result<()> {
+        let network = environment.network();
+        let ledger_id = environment.ledger_id();
+
+        let _guard = self.0.environment_lock.write().await;
+
+        // new requests can't start while the lock is held, so this only waits for the ones started before it.
+        // the sender is owned by `self`, so this can't fail.
+        let _ = self.0.in_flight_requests.subscribe().wait_for(|it| *it == 0).await;
+
+        self.0.environment.fetch_add(1, Ordering::Relaxed);
+
+        self.net().update_from_addresses(&network)?;
+        self.set_mirror_network(environment.mirror_network());
+        self.set_ledger_id(ledger_id.clone());
+
+        self.warn_network_problems(&network_validation::validate_offline(
+            &network,
+            ledger_id.as_ref(),
+        ));
+
+        Ok(())
+    }
+
+    /// Returns the number of times this client has switched environments.
+    pub(crate) fn environment(&self) -> u64 {
+        self.0.environment.load(Ordering::Relaxed)
+    }
+
+    /// Waits for any environment switch to finish, and then marks a request as in-flight until the returned value is dropped,
+    /// so that switching environments waits for it to finish.
+    ///
+    /// The environment can't change while the request is in-flight,
+    /// so the client's state can be read without holding any lock.
+    ///
+    /// Every request that's part of the in-flight request (IE, the chunks of a chunked transaction) has to be sent with
+    /// [`execute_in_flight`](crate::execute::execute_in_flight) rather than registering another in-flight request:
+    /// if a switch is waiting, that would wait for the switch, which waits for the first one, and neither would finish.
+    pub(crate) async fn in_flight_request(&self) -> InFlightRequest<'_> {
+        // the lock is only held for registering, a switch holds it while waiting for in-flight requests,
+        // so new requests can't start after a switch began waiting.
+        let _environment = self.0.environment_lock.read().await;
+
+        self.0.in_flight_requests.send_modify(|it| *it += 1);
+
+        InFlightRequest(&self.0.in_flight_requests)
+    }
+
     // optimized function to avoid allocations/pointer chasing.
     // this shouldn't be exposed because it exposes repr.
     pub(crate) fn ledger_id_internal(&self) -> arc_swap::Guard<Option<Arc<LedgerId>>> {
@@ -452,9 +733,7 @@ impl Client {
     ///
     /// The operator private key is used to sign all transactions executed by this client.
     pub fn set_operator(&self, id: AccountId, key: PrivateKey) {
-        self.0
-            .operator
-            .store(Some(Arc::new(Operator { account_id: id, signer: AnySigner::PrivateKey(key) })));
+        self.store_operator(Operator { account_id: id, signer: AnySigner::PrivateKey(key) });
     }
 
     /// Sets the account that will, by default, be paying for transactions and queries built with
@@ -470,10 +749,40 @@ impl Client {
         public_key: PublicKey,
         f: F,
     ) {
-        self.0.operator.store(Some(Arc::new(Operator {
+        self.store_operator(Operator {
             account_id: id,
             signer: AnySigner::arbitrary(Box::new(public_key), f),
-        })));
+        });
+    }
+
+    /// Sets the account that will, by default, be paying for transactions and queries built with
+    /// this client, with a signer that can fail, IE, a hardware wallet.
+    ///
+    /// A failure is returned by whatever signs the transaction,
+    /// IE, [`Transaction::execute`](crate::Transaction::execute).
+    pub fn try_set_operator_with<F: Fn(&[u8]) -> crate::Result<Vec<u8>> + Send + Sync + 'static>(
+        &self,
+        id: AccountId,
+        public_key: PublicKey,
+        f: F,
+    ) {
+        self.store_operator(Operator {
+            account_id: id,
+            signer: AnySigner::fallible(Box::new(public_key), f),
+        });
+    }
+
+    fn store_operator(&self, operator: Operator) {
+        client_log!(
+            self.logger(),
+            Crypto,
+            Debug,
+            "Operator set to {} with public key {}",
+            operator.account_id,
+            operator.signer.public_key()
+        );
+
+        self.0.operator.store(Some(Arc::new(operator)));
     }
 
     /// Gets a reference to the configured network.
@@ -486,6 +795,12 @@ impl Client {
         &self.0.network.mirror
     }
 
+    /// Returns a client for the REST API of the configured mirror network, that waits on this client's clock.
+    #[cfg(feature = "mirror-rest")]
+    pub(crate) fn rest_client(&self) -> crate::mirror_rest::MirrorRestClient {
+        self.mirrornet().load().rest_client().with_clock(self.clock())
+    }
+
     /// Sets the maximum transaction fee to be used when no explicit max transaction fee is set.
     ///
     /// Note: Setting `amount` to zero is "unlimited"
@@ -522,6 +837,221 @@ impl Client {
         self.0.max_query_payment_tinybar.store(amount.to_tinybars() as u64, Ordering::Relaxed);
     }
 
+    /// Returns the highest max transaction fee a transaction will automatically be resubmitted with
+    /// after failing with [`Status::InsufficientTxFee`](crate::Status::InsufficientTxFee).
+    ///
+    /// `None` (the default) means transactions are never resubmitted with a higher fee.
+    #[must_use]
+    pub fn auto_fee_bump_limit(&self) -> Option<Hbar> {
+        let val = self.0.auto_fee_bump_limit_tinybar.load(Ordering::Relaxed);
+
+        (val > 0).then(|| Hbar::from_tinybars(val as i64))
+    }
+
+    /// Sets the highest max transaction fee a transaction will automatically be resubmitted with
+    /// after failing with [`Status::InsufficientTxFee`](crate::Status::InsufficientTxFee).
+    ///
+    /// When a transaction fails that way, the node reports the fee that this kind of transaction actually requires;
+    /// if that's no more than `limit`, the transaction is resubmitted (once) with that as its max transaction fee,
+    /// and [`RetryEvent::FeeBumped`] is passed to the [retry hook](Self::set_retry_hook).
+    ///
+    /// Only transactions that the SDK signs itself are resubmitted
+    /// (not ones loaded from bytes, or with signatures added via `add_signature`), since changing the fee invalidates existing signatures.
+    ///
+    /// Setting `None` disables automatic fee bumping.
+    ///
+    /// # Panics
+    /// - if `limit` is negative
+    pub fn set_auto_fee_bump_limit(&self, limit: Option<Hbar>) {
+        let limit = limit.unwrap_or(Hbar::ZERO);
+        assert!(limit >= Hbar::ZERO);
+        self.0.auto_fee_bump_limit_tinybar.store(limit.to_tinybars() as u64, Ordering::Relaxed);
+    }
+
+    /// Sets a hook that's called whenever the SDK adjusts a request while retrying it.
+    ///
+    /// The hook is called synchronously on the task executing the request, so it should be quick.
+    pub fn set_retry_hook<F: Fn(&RetryEvent) + Send + Sync + 'static>(&self, hook: F) {
+        *self.0.retry_hook.write() = Some(std::sync::Arc::new(hook));
+    }
+
+    /// Removes the hook set with [`set_retry_hook`](Self::set_retry_hook).
+    pub fn clear_retry_hook(&self) {
+        *self.0.retry_hook.write() = None;
+    }
+
+    /// Sets a hook that's called whenever a node's response to a [fanned out](crate::Query::fan_out) query
+    /// differs from the response that was returned.
+    ///
+    /// Mismatches are also logged as warnings.
+    /// Nodes that respond before the query returns are reported on the task executing the query,
+    /// the rest are reported from a background task, after the query has already returned.
+    pub fn set_response_mismatch_hook<F: Fn(&ResponseMismatch) + Send + Sync + 'static>(
+        &self,
+        hook: F,
+    ) {
+        *self.0.response_mismatch_hook.write() = Some(std::sync::Arc::new(hook));
+    }
+
+    /// Removes the hook set with [`set_response_mismatch_hook`](Self::set_response_mismatch_hook).
+    pub fn clear_response_mismatch_hook(&self) {
+        *self.0.response_mismatch_hook.write() = None;
+    }
+
+    /// Returns the template for the memo of transactions that don't set one, if any.
+    #[must_use]
+    pub fn memo_template(&self) -> Option<MemoTemplate> {
+        self.0.memo_template.load().as_deref().cloned()
+    }
+
+    /// Sets the template for the memo of transactions that don't set one.
+    ///
+    /// The memo is rendered when a transaction is frozen (IE, when it's executed),
+    /// so transactions that were already frozen, or that have a memo, are left as-is.
+    ///
+    /// Setting `None` (the default) leaves the memo of such transactions empty.
+    pub fn set_memo_template(&self, template: Option<MemoTemplate>) {
+        self.0.memo_template.store(template.map(Arc::new));
+    }
+
+    /// Sets a hook returning request-scoped values for the [memo template](Self::set_memo_template),
+    /// IE, the trace ID of the current request, read from a task-local.
+    ///
+    /// The hook is called synchronously each time a memo is rendered, on the task freezing the transaction,
+    /// and the values it returns take precedence over the template's own.
+    pub fn set_memo_values_hook<F: Fn() -> HashMap<String, String> + Send + Sync + 'static>(
+        &self,
+        hook: F,
+    ) {
+        *self.0.memo_values_hook.write() = Some(std::sync::Arc::new(hook));
+    }
+
+    /// Removes the hook set with [`set_memo_values_hook`](Self::set_memo_values_hook).
+    pub fn clear_memo_values_hook(&self) {
+        *self.0.memo_values_hook.write() = None;
+    }
+
+    /// Renders the memo template for the transaction with the given ID, if there is a template.
+    pub(crate) fn render_memo(&self, transaction_id: &TransactionId) -> Option<String> {
+        let template = self.0.memo_template.load_full()?;
+
+        // note: clone the hook out so that the lock isn't held while it runs.
+        let hook = self.0.memo_values_hook.read().clone();
+        let request_values = hook.map(|hook| hook()).unwrap_or_default();
+
+        Some(template.render(transaction_id, &request_values))
+    }
+
+    /// Sets the logger that receives this client's log messages.
+    ///
+    /// Defaults to [`DefaultLogger`](crate::DefaultLogger), which forwards to the `log` crate,
+    /// with the `tracing` feature, [`TracingLogger`](crate::TracingLogger) emits `tracing` events instead.
+    ///
+    /// Messages that aren't tied to a client (IE, a warning from a setter) always go to the `log` crate.
+    pub fn set_logger<L: Logger + 'static>(&self, logger: L) {
+        self.logger().set_logger(std::sync::Arc::new(logger));
+    }
+
+    /// Sets the source of time for this client,
+    /// used for the valid start of generated transaction IDs, the backoff between attempts of a request
+    /// (including rate limited mirror node requests), and everything else that needs the current time.
+    ///
+    /// Defaults to [`SystemClock`].
+    pub fn set_clock<C: Clock + 'static>(&self, clock: C) {
+        *self.0.clock.write() = Arc::new(clock).unsize(Coercion!(to dyn Clock));
+    }
+
+    pub(crate) fn clock(&self) -> SharedClock {
+        self.0.clock.read().clone()
+    }
+
+    /// Returns the minimum level of messages logged for `subsystem`.
+    #[must_use]
+    pub fn get_log_level(&self, subsystem: LogSubsystem) -> LogLevel {
+        self.logger().level(subsystem)
+    }
+
+    /// Sets the minimum level of messages logged for `subsystem`.
+    ///
+    /// All subsystems default to [`LogLevel::Trace`], leaving the filtering to the logger.
+    pub fn set_log_level(&self, subsystem: LogSubsystem, level: LogLevel) {
+        self.logger().set_level(subsystem, level);
+    }
+
+    /// Sets the minimum level of messages logged for every subsystem.
+    pub fn set_log_levels(&self, level: LogLevel) {
+        self.logger().set_all_levels(level);
+    }
+
+    /// Returns true if logging is turned off for this client.
+    #[must_use]
+    pub fn is_log_silent(&self) -> bool {
+        self.logger().is_silent()
+    }
+
+    /// Turn all logging off for this client, or back on.
+    ///
+    /// Turning logging back on restores the levels set with [`set_log_level`](Self::set_log_level).
+    pub fn set_log_silent(&self, silent: bool) {
+        self.logger().set_silent(silent);
+    }
+
+    pub(crate) fn logger(&self) -> &Arc<ClientLogger> {
+        &self.0.network.logger
+    }
+
+    fn warn_network_problems(&self, problems: &[NetworkProblem]) {
+        for problem in problems {
+            client_log!(self.logger(), Network, Warn, "network map: {problem}");
+        }
+    }
+
+    pub(crate) fn emit_retry_event(&self, event: &RetryEvent) {
+        // note: clone the hook out so that the lock isn't held while it runs.
+        let hook = self.0.retry_hook.read().clone();
+
+        if let Some(hook) = hook {
+            hook(event);
+        }
+    }
+
+    pub(crate) fn emit_response_mismatch(&self, mismatch: &ResponseMismatch) {
+        client_log!(
+            self.logger(),
+            Execution,
+            Warn,
+            "node {} responded differently to a query than node {}",
+            mismatch.mismatched_node_account_id,
+            mismatch.node_account_id
+        );
+
+        let hook = self.0.response_mismatch_hook.read().clone();
+
+        if let Some(hook) = hook {
+            hook(mismatch);
+        }
+    }
+
+    fn set_balance_watchdog_state(&self, state: Option<Arc<WatchdogState>>) {
+        *self.0.balance_watchdog.write() = state;
+    }
+
+    /// Removes `state`, unless another watchdog has replaced it since.
+    fn clear_balance_watchdog_state(&self, state: &Arc<WatchdogState>) {
+        let mut current = self.0.balance_watchdog.write();
+
+        if current.as_ref().is_some_and(|it| Arc::ptr_eq(it, state)) {
+            *current = None;
+        }
+    }
+
+    /// Returns an error if a [`BalanceWatchdog`] wants transactions paid for by `payer` to fail fast.
+    pub(crate) fn check_balance_watchdog(&self, payer: AccountId) -> crate::Result<()> {
+        let state = self.0.balance_watchdog.read().clone();
+
+        state.map_or(Ok(()), |it| it.check(payer))
+    }
+
     /// Returns the maximum amount of time that will be spent on a request.
     #[must_use]
     pub fn request_timeout(&self) -> Option<Duration> {
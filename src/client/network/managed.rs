@@ -6,6 +6,10 @@ use triomphe::Arc;
 
 use super::mirror::MirrorNetwork;
 use super::Network;
+use crate::logger::{
+    client_log,
+    ClientLogger,
+};
 use crate::NodeAddressBookQuery;
 
 #[derive(Clone)]
@@ -20,7 +24,7 @@ impl ManagedNetwork {
         mirror: MirrorNetwork,
         // first_update_delay: Duration,
     ) -> Self {
-        Self(Arc::new(ManagedNetworkInner { primary, mirror }))
+        Self(Arc::new(ManagedNetworkInner { primary, mirror, logger: Arc::default() }))
     }
 
     pub(crate) fn mainnet() -> Self {
@@ -49,6 +53,9 @@ pub(crate) struct ManagedNetworkInner {
     pub(crate) primary: Network,
     //
     pub(crate) mirror: MirrorNetwork,
+
+    /// Lives here rather than on the client because the network update task needs it too.
+    pub(crate) logger: Arc<ClientLogger>,
 }
 
 pub(crate) fn spawn_network_update(
@@ -73,18 +80,18 @@ async fn update_network(
     tokio::time::sleep(ManagedNetwork::NETWORK_FIRST_UPDATE_DELAY).await;
 
     'outer: loop {
-        // log::debug!("updating network");
+        client_log!(network.logger, Network, Debug, "updating network");
         let start = tokio::time::Instant::now();
 
         // note: ideally we'd have a `select!` on the channel closing, but, we can't
         // since there's no `async fn closed()`, and honestly, I'm not 100% certain these futures are cancel safe.
         match NodeAddressBookQuery::new()
-            .execute_mirrornet(network.mirror.load().channel(), None)
+            .execute_mirrornet(network.mirror.load().channel(), None, Arc::clone(&network.logger))
             .await
         {
             Ok(it) => network.primary.update_from_address_book(&it),
             Err(e) => {
-                log::warn!("{e:?}");
+                client_log!(network.logger, Network, Warn, "failed to update network: {e:?}");
             }
         }
 
@@ -103,7 +110,12 @@ async fn update_network(
                 // the value is `Some` so this unwrap is okay (although unfortunate)
                 Ok(it) => it.unwrap(),
                 Err(e) => {
-                    log::debug!("client network update shutdown: {e}");
+                    client_log!(
+                        network.logger,
+                        Network,
+                        Debug,
+                        "client network update shutdown: {e}"
+                    );
                     return;
                 }
             };
@@ -4,6 +4,11 @@ use std::borrow::Cow;
 use std::ops::Deref;
 use std::time::Duration;
 
+use hyper::header::{
+    HeaderMap,
+    HeaderName,
+    HeaderValue,
+};
 use hyper::Uri;
 use hyper_openssl::client::legacy::HttpsConnector;
 use hyper_util::client::legacy::connect::HttpConnector;
@@ -13,13 +18,21 @@ use openssl::ssl::{
     SslMethod,
     SslVerifyMode,
 };
+use tonic::metadata::MetadataMap;
+use tonic::service::interceptor::InterceptedService;
+use tonic::service::Interceptor;
 use tonic::transport::{
     Channel,
     Endpoint,
 };
 use triomphe::Arc;
 
-use crate::ArcSwap;
+#[cfg(feature = "mirror-rest")]
+use crate::mirror_rest::MirrorRestClient;
+use crate::{
+    ArcSwap,
+    Error,
+};
 
 pub(crate) const MAINNET: &str = "mainnet-public.mirrornode.hedera.com:443";
 
@@ -27,6 +40,44 @@ pub(crate) const TESTNET: &str = "testnet.mirrornode.hedera.com:443";
 
 pub(crate) const PREVIEWNET: &str = "previewnet.mirrornode.hedera.com:443";
 
+/// A channel to the mirror network, which adds the configured [`MirrorHeaders`] to every request.
+pub(crate) type MirrorChannel = InterceptedService<Channel, MirrorHeaders>;
+
+/// Creates a lazily connected channel to the gRPC service at `endpoint` (`host:port`).
+///
+/// Uses plaintext for `localhost` and `127.0.0.1`, and TLS for everything else.
+pub(crate) fn grpc_channel(endpoint: &str) -> Channel {
+    // Check if endpoint is localhost or 127.0.0.1 to determine protocol
+    let is_localhost = endpoint.contains("localhost") || endpoint.contains("127.0.0.1");
+    let protocol = if is_localhost { "http" } else { "https" };
+    let uri = format!("{protocol}://{endpoint}");
+    let uri_parsed = Uri::from_maybe_shared(uri).unwrap();
+
+    let endpoint = Endpoint::from_shared(uri_parsed.to_string())
+        .unwrap()
+        .connect_timeout(Duration::from_secs(10))
+        .keep_alive_timeout(Duration::from_secs(10))
+        .keep_alive_while_idle(true)
+        .tcp_keepalive(Some(Duration::from_secs(10)));
+
+    if is_localhost {
+        // Use HTTP for localhost
+        endpoint.connect_lazy()
+    } else {
+        // Configure OpenSSL for HTTPS
+        let mut ssl_builder = SslConnector::builder(SslMethod::tls()).unwrap();
+        ssl_builder.set_verify(SslVerifyMode::PEER);
+        ssl_builder.set_alpn_protos(b"\x02h2").unwrap();
+
+        // Create HTTPS connector with OpenSSL
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        let https = HttpsConnector::with_connector(http, ssl_builder).unwrap();
+
+        endpoint.connect_with_connector_lazy(https)
+    }
+}
+
 #[derive(Default)]
 pub(crate) struct MirrorNetwork(ArcSwap<MirrorNetworkData>);
 
@@ -61,57 +112,147 @@ impl MirrorNetwork {
     }
 }
 
+/// Headers added to every gRPC and REST request to the mirror network, IE, the API key of a hosted mirror node.
+///
+/// This is cheap to clone.
+#[derive(Clone)]
+pub(crate) struct MirrorHeaders(Arc<HeaderMap>);
+
+impl MirrorHeaders {
+    /// Parse `headers`, marking the values of auth headers as sensitive.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if a name isn't a valid header name, or a value isn't a valid header value.
+    pub(crate) fn parse<K: AsRef<str>, V: AsRef<str>>(
+        headers: impl IntoIterator<Item = (K, V)>,
+    ) -> crate::Result<Self> {
+        let mut map = HeaderMap::new();
+
+        for (name, value) in headers {
+            let (name, value) = (name.as_ref(), value.as_ref());
+
+            let name = HeaderName::from_bytes(name.as_bytes()).map_err(|_| {
+                Error::basic_parse(format!("invalid mirror node header name `{name}`"))
+            })?;
+
+            let mut value = HeaderValue::from_str(value).map_err(|_| {
+                Error::basic_parse(format!("invalid value for mirror node header `{name}`"))
+            })?;
+
+            value.set_sensitive(is_auth_header(&name));
+
+            map.insert(name, value);
+        }
+
+        Ok(Self(Arc::new(map)))
+    }
+
+    pub(crate) fn as_header_map(&self) -> &HeaderMap {
+        &self.0
+    }
+
+    /// Returns the headers as `(name, value)` pairs, with `<sensitive>` in place of the value of auth headers.
+    pub(crate) fn to_pairs(&self) -> Vec<(String, String)> {
+        self.0
+            .iter()
+            .map(|(name, value)| {
+                let value = match value.is_sensitive() {
+                    true => "<sensitive>".to_owned(),
+                    false => String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                };
+
+                (name.as_str().to_owned(), value)
+            })
+            .collect()
+    }
+}
+
+impl From<HeaderMap> for MirrorHeaders {
+    fn from(headers: HeaderMap) -> Self {
+        Self(Arc::new(headers))
+    }
+}
+
+impl Default for MirrorHeaders {
+    fn default() -> Self {
+        Self(Arc::new(HeaderMap::new()))
+    }
+}
+
+impl Interceptor for MirrorHeaders {
+    fn call(
+        &mut self,
+        mut request: tonic::Request<()>,
+    ) -> Result<tonic::Request<()>, tonic::Status> {
+        if !self.0.is_empty() {
+            let mut headers = std::mem::take(request.metadata_mut()).into_headers();
+
+            for (name, value) in self.0.iter() {
+                headers.insert(name.clone(), value.clone());
+            }
+
+            *request.metadata_mut() = MetadataMap::from_headers(headers);
+        }
+
+        Ok(request)
+    }
+}
+
+fn is_auth_header(name: &HeaderName) -> bool {
+    name == hyper::header::AUTHORIZATION || name.as_str().contains("api-key")
+}
+
 #[derive(Clone, Default)]
 pub(crate) struct MirrorNetworkData {
     addresses: Vec<Cow<'static, str>>,
-    channel: OnceCell<Channel>,
+    headers: MirrorHeaders,
+    channel: OnceCell<MirrorChannel>,
+    #[cfg(feature = "mirror-rest")]
+    rest: OnceCell<MirrorRestClient>,
 }
 
 impl MirrorNetworkData {
     pub(crate) fn from_addresses(addresses: Vec<Cow<'static, str>>) -> Self {
-        Self { addresses, channel: OnceCell::new() }
+        Self {
+            addresses,
+            headers: MirrorHeaders::default(),
+            channel: OnceCell::new(),
+            #[cfg(feature = "mirror-rest")]
+            rest: OnceCell::new(),
+        }
+    }
+
+    /// Returns a copy of `self` that uses `headers`, with new connections.
+    pub(crate) fn with_headers(&self, headers: MirrorHeaders) -> Self {
+        Self { headers, ..Self::from_addresses(self.addresses.clone()) }
+    }
+
+    pub(crate) fn headers(&self) -> &MirrorHeaders {
+        &self.headers
     }
 
     pub(crate) fn from_static(network: &[&'static str]) -> Self {
         let addresses = network.iter().map(|&addr| Cow::Borrowed(addr)).collect();
 
-        Self { addresses, channel: OnceCell::new() }
+        Self::from_addresses(addresses)
     }
 
-    pub(crate) fn channel(&self) -> Channel {
+    pub(crate) fn channel(&self) -> MirrorChannel {
         self.channel
             .get_or_init(|| {
-                let endpoint = self.addresses.iter().next().unwrap();
-
-                // Check if endpoint is localhost or 127.0.0.1 to determine protocol
-                let is_localhost = endpoint.contains("localhost") || endpoint.contains("127.0.0.1");
-                let protocol = if is_localhost { "http" } else { "https" };
-                let uri = format!("{protocol}://{endpoint}");
-                let uri_parsed = Uri::from_maybe_shared(uri).unwrap();
-
-                let endpoint = Endpoint::from_shared(uri_parsed.to_string())
-                    .unwrap()
-                    .connect_timeout(Duration::from_secs(10))
-                    .keep_alive_timeout(Duration::from_secs(10))
-                    .keep_alive_while_idle(true)
-                    .tcp_keepalive(Some(Duration::from_secs(10)));
-
-                if is_localhost {
-                    // Use HTTP for localhost
-                    endpoint.connect_lazy()
-                } else {
-                    // Configure OpenSSL for HTTPS
-                    let mut ssl_builder = SslConnector::builder(SslMethod::tls()).unwrap();
-                    ssl_builder.set_verify(SslVerifyMode::PEER);
-                    ssl_builder.set_alpn_protos(b"\x02h2").unwrap();
-
-                    // Create HTTPS connector with OpenSSL
-                    let mut http = HttpConnector::new();
-                    http.enforce_http(false);
-                    let https = HttpsConnector::with_connector(http, ssl_builder).unwrap();
-
-                    endpoint.connect_with_connector_lazy(https)
-                }
+                InterceptedService::new(
+                    grpc_channel(self.addresses.iter().next().unwrap()),
+                    self.headers.clone(),
+                )
+            })
+            .clone()
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    pub(crate) fn rest_client(&self) -> MirrorRestClient {
+        self.rest
+            .get_or_init(|| {
+                MirrorRestClient::new(self.addresses.iter().next().unwrap(), self.headers.clone())
             })
             .clone()
     }
@@ -120,3 +261,39 @@ impl MirrorNetworkData {
         self.addresses.iter().cloned().map(Cow::into_owned)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tonic::service::Interceptor;
+
+    use super::MirrorHeaders;
+
+    #[test]
+    fn parse_headers() {
+        let headers =
+            MirrorHeaders::parse([("x-api-key", "secret"), ("X-Provider", "hashio")]).unwrap();
+
+        assert_eq!(
+            headers.to_pairs(),
+            [
+                ("x-api-key".to_owned(), "<sensitive>".to_owned()),
+                ("x-provider".to_owned(), "hashio".to_owned())
+            ]
+        );
+
+        assert!(MirrorHeaders::parse([("bad header", "value")]).is_err());
+        assert!(MirrorHeaders::parse([("x-api-key", "bad\nvalue")]).is_err());
+    }
+
+    #[test]
+    fn intercept() {
+        let mut headers = MirrorHeaders::parse([("authorization", "Bearer token")]).unwrap();
+
+        let request = headers.call(tonic::Request::new(())).unwrap();
+
+        assert_eq!(
+            request.metadata().get("authorization").unwrap().to_str().unwrap(),
+            "Bearer token"
+        );
+    }
+}
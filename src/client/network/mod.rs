@@ -2,6 +2,7 @@
 
 pub(super) mod managed;
 pub(super) mod mirror;
+mod stats;
 
 use std::collections::{
     BTreeSet,
@@ -23,6 +24,12 @@ use tonic::transport::{
 };
 use triomphe::Arc;
 
+pub(crate) use self::stats::NetworkStats;
+pub use self::stats::NodeStats;
+use crate::logger::{
+    client_log,
+    ClientLogger,
+};
 use crate::{
     AccountId,
     ArcSwap,
@@ -160,6 +167,8 @@ pub(crate) struct NetworkData {
     // Health stuff has to be in an Arc because it needs to stick around even if the map changes.
     health: Box<[Arc<parking_lot::RwLock<NodeHealth>>]>,
     connections: Box<[NodeConnection]>,
+    // Stats are in an Arc for the same reason, they're keyed by node account ID so that they survive nodes leaving and rejoining.
+    stats: Arc<NetworkStats>,
 }
 
 impl NetworkData {
@@ -188,6 +197,7 @@ impl NetworkData {
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            stats: Arc::default(),
         }
     }
 
@@ -247,6 +257,7 @@ impl NetworkData {
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            stats: Arc::clone(&old.stats),
         }
     }
 
@@ -287,6 +298,7 @@ impl NetworkData {
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            stats: Arc::clone(&self.stats),
         })
     }
 
@@ -340,16 +352,26 @@ impl NetworkData {
         self.backoff.read().min_backoff
     }
 
-    pub(crate) fn mark_node_unhealthy(&self, node_index: usize) {
+    /// Returns true if the node has reached the max number of attempts.
+    pub(crate) fn mark_node_unhealthy(&self, node_index: usize) -> bool {
         let now = Instant::now();
 
-        self.health[node_index].write().mark_unhealthy(*self.backoff.read(), now);
+        self.health[node_index].write().mark_unhealthy(*self.backoff.read(), now)
     }
 
     pub(crate) fn mark_node_healthy(&self, node_index: usize) {
         self.health[node_index].write().mark_healthy(Instant::now());
     }
 
+    pub(crate) fn stats(&self) -> &NetworkStats {
+        &self.stats
+    }
+
+    /// Returns true if the node at `node_index` has recently been failing to serve requests.
+    pub(crate) fn is_node_congested(&self, node_index: usize) -> bool {
+        self.stats.is_congested(self.node_ids[node_index])
+    }
+
     pub(crate) fn is_node_healthy(&self, node_index: usize, now: Instant) -> bool {
         // a healthy node has a healthiness before now.
 
@@ -367,12 +389,17 @@ impl NetworkData {
     pub(crate) fn healthy_node_ids(&self) -> impl Iterator<Item = AccountId> + '_ {
         self.healthy_node_indexes(Instant::now()).map(|it| self.node_ids[it])
     }
-    pub(crate) fn random_node_ids(&self) -> Vec<AccountId> {
+    pub(crate) fn random_node_ids(&self, logger: &ClientLogger) -> Vec<AccountId> {
         let mut node_ids: Vec<_> = self.healthy_node_ids().collect();
         // self.remove_dead_nodes();
 
         if node_ids.is_empty() {
-            log::warn!("No healthy nodes, randomly picking some unhealthy ones");
+            client_log!(
+                logger,
+                Network,
+                Warn,
+                "No healthy nodes, randomly picking some unhealthy ones"
+            );
             // hack, slowpath, don't care perf, fix this better later tho.
             node_ids = self.node_ids.to_vec();
         }
@@ -393,6 +420,11 @@ impl NetworkData {
         (id, channel)
     }
 
+    /// Returns the `host:port` endpoints of the node at `index`.
+    pub(crate) fn node_endpoints(&self, index: usize) -> Vec<String> {
+        self.connections[index].addresses.iter().cloned().collect()
+    }
+
     pub(crate) fn addresses(&self) -> HashMap<String, AccountId> {
         self.map
             .iter()
@@ -463,16 +495,14 @@ impl NodeHealth {
         )
     }
 
-    pub(crate) fn mark_unhealthy(&mut self, backoff_config: NodeBackoff, now: Instant) {
+    /// Returns true if the node has reached the max number of attempts.
+    pub(crate) fn mark_unhealthy(&mut self, backoff_config: NodeBackoff, now: Instant) -> bool {
         let (mut backoff, unhealthy_node_attempts) = self.backoff(backoff_config);
 
         // Remove node if max_attempts has been reached and max_attempts is not 0
-        if backoff_config
+        let max_attempts_reached = backoff_config
             .max_attempts
-            .map_or(false, |max_attempts| unhealthy_node_attempts > max_attempts.get())
-        {
-            log::debug!("Node has reached the max amount of retries, removing from network")
-        }
+            .map_or(false, |max_attempts| unhealthy_node_attempts > max_attempts.get());
 
         // Generates the next current_interval with a random duration
         let next_backoff = backoff.next_backoff().expect("`max_elapsed_time` is hardwired to None");
@@ -489,6 +519,8 @@ impl NodeHealth {
             healthy_at,
             attempts: unhealthy_node_attempts,
         };
+
+        max_attempts_reached
     }
 
     pub(crate) fn mark_healthy(&mut self, now: Instant) {
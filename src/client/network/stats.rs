@@ -0,0 +1,278 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+use crate::execute::is_node_failure;
+use crate::{
+    AccountId,
+    Status,
+};
+
+/// The number of latency samples kept per node, to compute percentiles with.
+const LATENCY_SAMPLES: usize = 256;
+
+/// The number of recent outcomes kept per node, to decide whether it's congested.
+const RECENT_OUTCOMES: usize = 20;
+
+/// Statistics of the requests a client has sent to a node, returned by [`Client::network_stats`](crate::Client::network_stats).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct NodeStats {
+    /// The number of requests sent to the node.
+    pub requests: u64,
+
+    /// The number of requests the node responded to with `BUSY`.
+    pub busy: u64,
+
+    /// The number of requests the node responded to with `PLATFORM_NOT_ACTIVE`.
+    pub platform_not_active: u64,
+
+    /// The number of requests that failed because of the node or the connection to it
+    /// (IE, the node was unreachable, overloaded, or the request timed out).
+    ///
+    /// Other gRPC errors (IE, `INVALID_ARGUMENT`) mean the node rejected the request, and aren't counted here.
+    pub transport_errors: u64,
+
+    /// The median latency of the node's recent responses.
+    pub latency_p50: Option<Duration>,
+
+    /// The 90th percentile latency of the node's recent responses.
+    pub latency_p90: Option<Duration>,
+
+    /// The 99th percentile latency of the node's recent responses.
+    pub latency_p99: Option<Duration>,
+}
+
+#[derive(Default)]
+struct NodeRecord {
+    requests: u64,
+    busy: u64,
+    platform_not_active: u64,
+    transport_errors: u64,
+    latencies: VecDeque<Duration>,
+    // `true` for requests the node couldn't serve (busy, not active, or a transport error).
+    recent_failures: VecDeque<bool>,
+}
+
+impl NodeRecord {
+    fn push_outcome(&mut self, failed: bool) {
+        self.requests += 1;
+
+        if self.recent_failures.len() == RECENT_OUTCOMES {
+            self.recent_failures.pop_front();
+        }
+
+        self.recent_failures.push_back(failed);
+    }
+
+    fn push_latency(&mut self, latency: Duration) {
+        if self.latencies.len() == LATENCY_SAMPLES {
+            self.latencies.pop_front();
+        }
+
+        self.latencies.push_back(latency);
+    }
+
+    fn stats(&self) -> NodeStats {
+        let mut latencies: Vec<_> = self.latencies.iter().copied().collect();
+        latencies.sort_unstable();
+
+        NodeStats {
+            requests: self.requests,
+            busy: self.busy,
+            platform_not_active: self.platform_not_active,
+            transport_errors: self.transport_errors,
+            latency_p50: percentile(&latencies, 50),
+            latency_p90: percentile(&latencies, 90),
+            latency_p99: percentile(&latencies, 99),
+        }
+    }
+}
+
+/// Returns the `p`th percentile of `sorted`, with the nearest-rank method.
+fn percentile(sorted: &[Duration], p: usize) -> Option<Duration> {
+    if sorted.is_empty() {
+        return None;
+    }
+
+    let rank = (p * sorted.len()).div_ceil(100).max(1);
+
+    Some(sorted[rank - 1])
+}
+
+/// Per node request statistics.
+///
+/// Shared between every version of the network, so that they stick around when the network updates.
+#[derive(Default)]
+pub(crate) struct NetworkStats(Mutex<HashMap<AccountId, NodeRecord>>);
+
+impl NetworkStats {
+    pub(crate) fn record_response(
+        &self,
+        node_account_id: AccountId,
+        latency: Duration,
+        status: Option<Status>,
+    ) {
+        let mut records = self.0.lock();
+        let record = records.entry(node_account_id).or_default();
+
+        let failed = match status {
+            Some(Status::Busy) => {
+                record.busy += 1;
+                true
+            }
+            Some(Status::PlatformNotActive) => {
+                record.platform_not_active += 1;
+                true
+            }
+            _ => false,
+        };
+
+        record.push_outcome(failed);
+        record.push_latency(latency);
+    }
+
+    /// Records a request that failed with the gRPC error `status`.
+    ///
+    /// Only node failures count as transport errors, anything else is a response like any other.
+    pub(crate) fn record_grpc_error(
+        &self,
+        node_account_id: AccountId,
+        latency: Duration,
+        status: &tonic::Status,
+    ) {
+        if is_node_failure(status) {
+            self.record_transport_error(node_account_id);
+        } else {
+            self.record_response(node_account_id, latency, None);
+        }
+    }
+
+    pub(crate) fn record_transport_error(&self, node_account_id: AccountId) {
+        let mut records = self.0.lock();
+        let record = records.entry(node_account_id).or_default();
+
+        record.transport_errors += 1;
+        record.push_outcome(true);
+    }
+
+    /// Returns true if at least half of the node's recent requests failed.
+    pub(crate) fn is_congested(&self, node_account_id: AccountId) -> bool {
+        let records = self.0.lock();
+
+        let Some(record) = records.get(&node_account_id) else {
+            return false;
+        };
+
+        let failures = record.recent_failures.iter().filter(|it| **it).count();
+
+        failures > 0 && failures * 2 >= record.recent_failures.len()
+    }
+
+    pub(crate) fn snapshot(&self) -> HashMap<AccountId, NodeStats> {
+        self.0.lock().iter().map(|(id, record)| (*id, record.stats())).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        percentile,
+        NetworkStats,
+        NodeStats,
+    };
+    use crate::{
+        AccountId,
+        Status,
+    };
+
+    const NODE: AccountId = AccountId::new(0, 0, 3);
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn percentiles() {
+        let latencies: Vec<_> = (1..=100).map(ms).collect();
+
+        assert_eq!(percentile(&latencies, 50), Some(ms(50)));
+        assert_eq!(percentile(&latencies, 90), Some(ms(90)));
+        assert_eq!(percentile(&latencies, 99), Some(ms(99)));
+        assert_eq!(percentile(&[ms(7)], 99), Some(ms(7)));
+        assert_eq!(percentile(&[], 50), None);
+    }
+
+    #[test]
+    fn record() {
+        let stats = NetworkStats::default();
+
+        stats.record_response(NODE, ms(30), Some(Status::Ok));
+        stats.record_response(NODE, ms(10), Some(Status::Busy));
+        stats.record_response(NODE, ms(20), Some(Status::PlatformNotActive));
+        stats.record_transport_error(NODE);
+
+        assert_eq!(
+            stats.snapshot()[&NODE],
+            NodeStats {
+                requests: 4,
+                busy: 1,
+                platform_not_active: 1,
+                transport_errors: 1,
+                latency_p50: Some(ms(20)),
+                latency_p90: Some(ms(30)),
+                latency_p99: Some(ms(30)),
+            }
+        );
+    }
+
+    #[test]
+    fn grpc_errors() {
+        let stats = NetworkStats::default();
+
+        stats.record_grpc_error(NODE, ms(10), &tonic::Status::unavailable("down"));
+        stats.record_grpc_error(NODE, ms(10), &tonic::Status::resource_exhausted("busy"));
+        stats.record_grpc_error(NODE, ms(10), &tonic::Status::invalid_argument("bad request"));
+        stats.record_grpc_error(NODE, ms(10), &tonic::Status::unimplemented("no such method"));
+
+        let stats = stats.snapshot()[&NODE].clone();
+
+        assert_eq!(stats.requests, 4);
+        assert_eq!(stats.transport_errors, 2);
+        assert_eq!(stats.latency_p50, Some(ms(10)));
+    }
+
+    #[test]
+    fn congested() {
+        let stats = NetworkStats::default();
+
+        assert!(!stats.is_congested(NODE));
+
+        for _ in 0..10 {
+            stats.record_response(NODE, ms(10), Some(Status::Ok));
+        }
+
+        assert!(!stats.is_congested(NODE));
+
+        for _ in 0..10 {
+            stats.record_response(NODE, ms(10), Some(Status::Busy));
+        }
+
+        assert!(stats.is_congested(NODE));
+
+        // old failures age out.
+        for _ in 0..20 {
+            stats.record_response(NODE, ms(10), Some(Status::Ok));
+        }
+
+        assert!(!stats.is_congested(NODE));
+    }
+}
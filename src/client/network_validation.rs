@@ -0,0 +1,499 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+    HashMap,
+};
+use std::fmt;
+
+use tonic::transport::Endpoint;
+
+use super::network::{
+    MAINNET,
+    PREVIEWNET,
+    TESTNET,
+};
+use crate::{
+    AccountId,
+    LedgerId,
+    NodeAddressBook,
+};
+
+/// A problem with a network map, found by [`Client::validate_network`](crate::Client::validate_network).
+///
+/// A misconfigured network map usually shows up as `INVALID_NODE_ACCOUNT` errors at execution time,
+/// these describe the cause instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NetworkProblem {
+    /// The address isn't a valid `host:port` endpoint.
+    InvalidEndpoint {
+        /// The address, as given in the network map.
+        address: String,
+
+        /// The node the address is mapped to.
+        node_account_id: AccountId,
+    },
+
+    /// The address doesn't resolve to any IP address.
+    UnresolvableEndpoint {
+        /// The address, as given in the network map.
+        address: String,
+
+        /// The node the address is mapped to.
+        node_account_id: AccountId,
+    },
+
+    /// The same endpoint is mapped to more than one node.
+    DuplicateEndpoint {
+        /// The address, as given in the network map.
+        address: String,
+
+        /// The nodes the address is mapped to.
+        node_account_ids: Vec<AccountId>,
+    },
+
+    /// The address belongs to a different node than the one it's mapped to.
+    NodeAccountMismatch {
+        /// The address, as given in the network map.
+        address: String,
+
+        /// The node the address is mapped to.
+        node_account_id: AccountId,
+
+        /// The node the address actually belongs to.
+        expected: AccountId,
+    },
+
+    /// The address belongs to a node of a different ledger than the client's.
+    LedgerMismatch {
+        /// The address, as given in the network map.
+        address: String,
+
+        /// The ledger the address belongs to.
+        ledger_id: LedgerId,
+
+        /// The ledger of the client.
+        expected: LedgerId,
+    },
+
+    /// The network map has nodes of more than one of the known ledgers (IE, mainnet and testnet).
+    MixedLedgers {
+        /// The ledgers that have nodes in the map.
+        ledger_ids: Vec<LedgerId>,
+    },
+
+    /// The node isn't in the network's address book.
+    NotInAddressBook {
+        /// The node account ID.
+        node_account_id: AccountId,
+    },
+}
+
+impl NetworkProblem {
+    /// Returns true if the problem will cause requests to fail, rather than being suspicious.
+    ///
+    /// [`MixedLedgers`](Self::MixedLedgers) is the only warning,
+    /// as every other problem also gets reported for the offending nodes.
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        !matches!(self, Self::MixedLedgers { .. })
+    }
+}
+
+impl fmt::Display for NetworkProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidEndpoint { address, node_account_id } => {
+                write!(
+                    f,
+                    "`{address}` (node {node_account_id}) is not a valid `host:port` endpoint"
+                )
+            }
+            Self::UnresolvableEndpoint { address, node_account_id } => {
+                write!(f, "`{address}` (node {node_account_id}) does not resolve")
+            }
+            Self::DuplicateEndpoint { address, node_account_ids } => {
+                write!(f, "`{address}` is mapped to more than one node (")?;
+                write_account_ids(f, node_account_ids)?;
+                f.write_str(")")
+            }
+            Self::NodeAccountMismatch { address, node_account_id, expected } => write!(
+                f,
+                "`{address}` is mapped to node {node_account_id}, but belongs to node {expected}"
+            ),
+            Self::LedgerMismatch { address, ledger_id, expected } => {
+                write!(f, "`{address}` is a {ledger_id} node, but the client is for {expected}")
+            }
+            Self::MixedLedgers { ledger_ids } => {
+                f.write_str("network has nodes of more than one ledger (")?;
+
+                for (i, ledger_id) in ledger_ids.iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    write!(f, "{ledger_id}")?;
+                }
+
+                f.write_str(")")
+            }
+            Self::NotInAddressBook { node_account_id } => {
+                write!(f, "node {node_account_id} is not in the network's address book")
+            }
+        }
+    }
+}
+
+fn write_account_ids(f: &mut fmt::Formatter<'_>, ids: &[AccountId]) -> fmt::Result {
+    for (i, id) in ids.iter().enumerate() {
+        if i != 0 {
+            f.write_str(", ")?;
+        }
+
+        write!(f, "{id}")?;
+    }
+
+    Ok(())
+}
+
+/// Splits `address` into its host and port, if it's a valid endpoint.
+fn parse_endpoint(address: &str) -> Option<(&str, u16)> {
+    let (host, port) = address.rsplit_once(':')?;
+    let port = port.parse().ok()?;
+
+    if host.is_empty() || Endpoint::from_shared(format!("tcp://{address}")).is_err() {
+        return None;
+    }
+
+    Some((host, port))
+}
+
+/// Returns the known ledger and node account ID that `host` belongs to.
+fn known_node(host: &str) -> Option<(LedgerId, AccountId)> {
+    let networks = [
+        (LedgerId::mainnet(), MAINNET),
+        (LedgerId::testnet(), TESTNET),
+        (LedgerId::previewnet(), PREVIEWNET),
+    ];
+
+    networks.into_iter().find_map(|(ledger_id, nodes)| {
+        nodes
+            .iter()
+            .find(|(_, hosts)| hosts.iter().any(|it| it.eq_ignore_ascii_case(host)))
+            .map(|(num, _)| (ledger_id, AccountId::from(*num)))
+    })
+}
+
+fn sort_account_ids(ids: &mut [AccountId]) {
+    ids.sort_unstable_by_key(|it| (it.shard, it.realm, it.num));
+}
+
+/// Returns the addresses of `network`, sorted, so that problems are reported in a stable order.
+fn sorted(network: &HashMap<String, AccountId>) -> BTreeMap<&str, AccountId> {
+    network.iter().map(|(address, node)| (address.as_str(), *node)).collect()
+}
+
+/// Checks `network` for problems that can be found without any network access.
+pub(super) fn validate_offline(
+    network: &HashMap<String, AccountId>,
+    ledger_id: Option<&LedgerId>,
+) -> Vec<NetworkProblem> {
+    let mut problems = Vec::new();
+    let mut endpoints: BTreeMap<(String, u16), (&str, Vec<AccountId>)> = BTreeMap::new();
+    let mut ledger_ids: Vec<LedgerId> = Vec::new();
+
+    for (address, node_account_id) in sorted(network) {
+        let Some((host, port)) = parse_endpoint(address) else {
+            problems.push(NetworkProblem::InvalidEndpoint {
+                address: address.to_owned(),
+                node_account_id,
+            });
+
+            continue;
+        };
+
+        let (_, node_account_ids) = endpoints
+            .entry((host.to_ascii_lowercase(), port))
+            .or_insert_with(|| (address, Vec::new()));
+
+        if !node_account_ids.contains(&node_account_id) {
+            node_account_ids.push(node_account_id);
+        }
+
+        let Some((known_ledger_id, expected)) = known_node(host) else {
+            continue;
+        };
+
+        if expected != node_account_id {
+            problems.push(NetworkProblem::NodeAccountMismatch {
+                address: address.to_owned(),
+                node_account_id,
+                expected,
+            });
+        }
+
+        if let Some(ledger_id) = ledger_id.filter(|it| **it != known_ledger_id) {
+            problems.push(NetworkProblem::LedgerMismatch {
+                address: address.to_owned(),
+                ledger_id: known_ledger_id.clone(),
+                expected: ledger_id.clone(),
+            });
+        }
+
+        if !ledger_ids.contains(&known_ledger_id) {
+            ledger_ids.push(known_ledger_id);
+        }
+    }
+
+    for (address, mut node_account_ids) in endpoints.into_values() {
+        if node_account_ids.len() > 1 {
+            sort_account_ids(&mut node_account_ids);
+
+            problems.push(NetworkProblem::DuplicateEndpoint {
+                address: address.to_owned(),
+                node_account_ids,
+            });
+        }
+    }
+
+    if ledger_ids.len() > 1 {
+        problems.push(NetworkProblem::MixedLedgers { ledger_ids });
+    }
+
+    problems
+}
+
+/// Checks `network` against the network's own `address_book`.
+pub(super) fn validate_address_book(
+    network: &HashMap<String, AccountId>,
+    address_book: &NodeAddressBook,
+) -> Vec<NetworkProblem> {
+    let mut problems = Vec::new();
+
+    let book_nodes: HashMap<AccountId, BTreeSet<String>> = address_book
+        .node_addresses
+        .iter()
+        .map(|it| {
+            let endpoints = it.service_endpoints.iter().map(|it| it.to_ascii_lowercase()).collect();
+            (it.node_account_id, endpoints)
+        })
+        .collect();
+
+    let mut nodes: Vec<AccountId> = network.values().copied().collect();
+    sort_account_ids(&mut nodes);
+    nodes.dedup();
+
+    for node_account_id in nodes {
+        if !book_nodes.contains_key(&node_account_id) {
+            problems.push(NetworkProblem::NotInAddressBook { node_account_id });
+        }
+    }
+
+    for (address, node_account_id) in sorted(network) {
+        let address_lower = address.to_ascii_lowercase();
+
+        let expected = book_nodes
+            .iter()
+            .find(|(_, endpoints)| endpoints.contains(&address_lower))
+            .map(|(id, _)| *id);
+
+        if let Some(expected) = expected.filter(|it| *it != node_account_id) {
+            problems.push(NetworkProblem::NodeAccountMismatch {
+                address: address.to_owned(),
+                node_account_id,
+                expected,
+            });
+        }
+    }
+
+    problems
+}
+
+/// Returns the endpoints of `network` that don't resolve to any IP address.
+///
+/// Invalid endpoints are skipped, as they're already reported by [`validate_offline`].
+pub(super) async fn unresolvable_endpoints(
+    network: &HashMap<String, AccountId>,
+) -> Vec<NetworkProblem> {
+    let mut problems = Vec::new();
+
+    for (address, node_account_id) in sorted(network) {
+        if parse_endpoint(address).is_none() {
+            continue;
+        }
+
+        let resolves = tokio::net::lookup_host(address)
+            .await
+            .is_ok_and(|mut addresses| addresses.next().is_some());
+
+        if !resolves {
+            problems.push(NetworkProblem::UnresolvableEndpoint {
+                address: address.to_owned(),
+                node_account_id,
+            });
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{
+        unresolvable_endpoints,
+        validate_address_book,
+        validate_offline,
+        NetworkProblem,
+    };
+    use crate::{
+        AccountId,
+        LedgerId,
+        NodeAddress,
+        NodeAddressBook,
+    };
+
+    fn network(entries: &[(&str, u64)]) -> HashMap<String, AccountId> {
+        entries
+            .iter()
+            .map(|(address, num)| ((*address).to_owned(), AccountId::from(*num)))
+            .collect()
+    }
+
+    #[test]
+    fn valid_custom_network() {
+        let network = network(&[("127.0.0.1:50211", 3), ("127.0.0.1:50212", 4)]);
+
+        assert_eq!(validate_offline(&network, None), []);
+    }
+
+    #[test]
+    fn invalid_endpoint() {
+        let network = network(&[("127.0.0.1", 3), (":50211", 4), ("127.0.0.1:50211", 5)]);
+
+        assert_eq!(
+            validate_offline(&network, None),
+            [
+                NetworkProblem::InvalidEndpoint {
+                    address: "127.0.0.1".to_owned(),
+                    node_account_id: AccountId::from(3),
+                },
+                NetworkProblem::InvalidEndpoint {
+                    address: ":50211".to_owned(),
+                    node_account_id: AccountId::from(4),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicate_endpoint() {
+        let network = network(&[("node.example.com:50211", 3), ("NODE.example.com:50211", 4)]);
+
+        assert_eq!(
+            validate_offline(&network, None),
+            [NetworkProblem::DuplicateEndpoint {
+                address: "NODE.example.com:50211".to_owned(),
+                node_account_ids: vec![AccountId::from(3), AccountId::from(4)],
+            }]
+        );
+    }
+
+    #[test]
+    fn node_account_mismatch() {
+        // 0.testnet.hedera.com is 0.0.3
+        let network = network(&[("0.testnet.hedera.com:50211", 4)]);
+
+        assert_eq!(
+            validate_offline(&network, Some(&LedgerId::testnet())),
+            [NetworkProblem::NodeAccountMismatch {
+                address: "0.testnet.hedera.com:50211".to_owned(),
+                node_account_id: AccountId::from(4),
+                expected: AccountId::from(3),
+            }]
+        );
+    }
+
+    #[test]
+    fn mixed_ledgers() {
+        let network = network(&[("35.237.200.180:50211", 3), ("1.testnet.hedera.com:50211", 4)]);
+
+        let problems = validate_offline(&network, Some(&LedgerId::mainnet()));
+
+        assert_eq!(
+            problems,
+            [
+                NetworkProblem::LedgerMismatch {
+                    address: "1.testnet.hedera.com:50211".to_owned(),
+                    ledger_id: LedgerId::testnet(),
+                    expected: LedgerId::mainnet(),
+                },
+                NetworkProblem::MixedLedgers {
+                    ledger_ids: vec![LedgerId::testnet(), LedgerId::mainnet()],
+                },
+            ]
+        );
+
+        assert!(problems[0].is_error());
+        assert!(!problems[1].is_error());
+    }
+
+    #[test]
+    fn address_book() {
+        let node = |num: u64, endpoint: &str| NodeAddress {
+            node_id: num - 3,
+            rsa_public_key: Vec::new(),
+            node_account_id: AccountId::from(num),
+            tls_certificate_hash: Vec::new(),
+            service_endpoints: vec![endpoint.to_owned()],
+            description: String::new(),
+        };
+
+        let address_book = NodeAddressBook {
+            node_addresses: vec![node(3, "10.0.0.1:50211"), node(4, "10.0.0.2:50211")],
+        };
+
+        let network = network(&[("10.0.0.1:50211", 4), ("10.0.0.3:50211", 7)]);
+
+        assert_eq!(
+            validate_address_book(&network, &address_book),
+            [
+                NetworkProblem::NotInAddressBook { node_account_id: AccountId::from(7) },
+                NetworkProblem::NodeAccountMismatch {
+                    address: "10.0.0.1:50211".to_owned(),
+                    node_account_id: AccountId::from(4),
+                    expected: AccountId::from(3),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn unresolvable() {
+        let network = network(&[("127.0.0.1:50211", 3), ("node.invalid:50211", 4)]);
+
+        assert_eq!(
+            unresolvable_endpoints(&network).await,
+            [NetworkProblem::UnresolvableEndpoint {
+                address: "node.invalid:50211".to_owned(),
+                node_account_id: AccountId::from(4),
+            }]
+        );
+    }
+
+    #[test]
+    fn display() {
+        let problem = NetworkProblem::DuplicateEndpoint {
+            address: "127.0.0.1:50211".to_owned(),
+            node_account_ids: vec![AccountId::from(3), AccountId::from(4)],
+        };
+
+        assert_eq!(
+            problem.to_string(),
+            "`127.0.0.1:50211` is mapped to more than one node (0.0.3, 0.0.4)"
+        );
+    }
+}
@@ -12,8 +12,7 @@ pub(crate) struct Operator {
 }
 
 impl Operator {
-    #[must_use]
-    pub(crate) fn sign(&self, body_bytes: &[u8]) -> (PublicKey, Vec<u8>) {
+    pub(crate) fn sign(&self, body_bytes: &[u8]) -> crate::Result<(PublicKey, Vec<u8>)> {
         self.signer.sign(body_bytes)
     }
 
@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use futures_core::future::BoxFuture;
+use time::OffsetDateTime;
+
+/// The source of time for a [`Client`](crate::Client).
+///
+/// The clock is used for the valid start of generated transaction IDs,
+/// for the backoff between attempts of a request,
+/// and wherever else the client needs the current time,
+/// IE, [`ExpiryMonitor::check`](crate::expiry::ExpiryMonitor::check).
+///
+/// Defaults to [`SystemClock`], a `MockClock` (with the `test-utils` feature)
+/// makes tests of expiry and retry behavior deterministic.
+pub trait Clock: Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> OffsetDateTime;
+
+    /// Returns a future that completes after `duration` has passed.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+}
+
+/// The system's clock, with sleeps on the tokio timer.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> OffsetDateTime {
+        OffsetDateTime::now_utc()
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+pub use mock::MockClock;
+
+#[cfg(any(test, feature = "test-utils"))]
+mod mock {
+    use std::time::Duration;
+
+    use futures_core::future::BoxFuture;
+    use parking_lot::Mutex;
+    use time::OffsetDateTime;
+    use triomphe::Arc;
+
+    use super::Clock;
+
+    /// A [`Clock`] that only moves when it's told to.
+    ///
+    /// Sleeping completes immediately, moving the clock forward by the duration slept,
+    /// so backoff between attempts doesn't slow tests down.
+    ///
+    /// Clones share the same time, so keep a clone to move the clock after giving it to a [`Client`](crate::Client).
+    #[derive(Debug, Clone)]
+    pub struct MockClock(Arc<Mutex<MockClockState>>);
+
+    #[derive(Debug)]
+    struct MockClockState {
+        now: OffsetDateTime,
+        sleeps: Vec<Duration>,
+    }
+
+    impl MockClock {
+        /// Create a new clock, stopped at `now`.
+        #[must_use]
+        pub fn new(now: OffsetDateTime) -> Self {
+            Self(Arc::new(Mutex::new(MockClockState { now, sleeps: Vec::new() })))
+        }
+
+        /// Sets the current time.
+        pub fn set_now(&self, now: OffsetDateTime) {
+            self.0.lock().now = now;
+        }
+
+        /// Moves the current time forward by `duration`.
+        pub fn advance(&self, duration: time::Duration) {
+            self.0.lock().now += duration;
+        }
+
+        /// Returns the durations slept so far, in order.
+        #[must_use]
+        pub fn get_sleeps(&self) -> Vec<Duration> {
+            self.0.lock().sleeps.clone()
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> OffsetDateTime {
+            self.0.lock().now
+        }
+
+        fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+            let mut state = self.0.lock();
+
+            state.now += duration;
+            state.sleeps.push(duration);
+
+            Box::pin(std::future::ready(()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use time::OffsetDateTime;
+
+    use super::{
+        Clock,
+        MockClock,
+    };
+
+    #[tokio::test]
+    async fn mock_clock() {
+        let clock = MockClock::new(OffsetDateTime::UNIX_EPOCH);
+        let handle = clock.clone();
+
+        handle.advance(time::Duration::seconds(10));
+        assert_eq!(clock.now(), OffsetDateTime::UNIX_EPOCH + time::Duration::seconds(10));
+
+        clock.sleep(Duration::from_millis(250)).await;
+        clock.sleep(Duration::from_millis(500)).await;
+
+        assert_eq!(handle.now(), OffsetDateTime::UNIX_EPOCH + time::Duration::milliseconds(10_750));
+        assert_eq!(handle.get_sleeps(), [Duration::from_millis(250), Duration::from_millis(500)]);
+    }
+}
@@ -62,6 +62,12 @@ impl ToQueryProtobuf for ContractBytecodeQueryData {
     }
 }
 
+impl FromProtobuf<services::ContractGetBytecodeQuery> for ContractBytecodeQueryData {
+    fn from_protobuf(pb: services::ContractGetBytecodeQuery) -> crate::Result<Self> {
+        Ok(Self { contract_id: Option::from_protobuf(pb.contract_id)? })
+    }
+}
+
 impl QueryExecute for ContractBytecodeQueryData {
     type Response = Vec<u8>;
 
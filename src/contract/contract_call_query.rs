@@ -17,6 +17,7 @@ use crate::{
     ContractFunctionResult,
     ContractId,
     Error,
+    FromProtobuf,
     Query,
     ToProtobuf,
     ValidateChecksums,
@@ -138,6 +139,17 @@ impl ToQueryProtobuf for ContractCallQueryData {
     }
 }
 
+impl FromProtobuf<services::ContractCallLocalQuery> for ContractCallQueryData {
+    fn from_protobuf(pb: services::ContractCallLocalQuery) -> crate::Result<Self> {
+        Ok(Self {
+            contract_id: Option::from_protobuf(pb.contract_id)?,
+            gas: u64::try_from(pb.gas).map_err(Error::from_protobuf)?,
+            function_parameters: pb.function_parameters,
+            sender_account_id: Option::from_protobuf(pb.sender_id)?,
+        })
+    }
+}
+
 impl QueryExecute for ContractCallQueryData {
     type Response = ContractFunctionResult;
 
@@ -161,15 +173,19 @@ impl ValidateChecksums for ContractCallQueryData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services;
 
+    use super::ContractCallQueryData;
     use crate::query::ToQueryProtobuf;
     use crate::{
         AccountId,
         ContractCallQuery,
         ContractFunctionParameters,
         ContractId,
+        Error,
+        FromProtobuf,
         Hbar,
     };
 
@@ -717,4 +733,11 @@ mod tests {
 
         assert_eq!(query.get_sender_account_id(), Some(AccountId::new(1, 2, 3)));
     }
+
+    #[test]
+    fn from_protobuf_negative_gas() {
+        let pb = services::ContractCallLocalQuery { gas: -1, ..Default::default() };
+
+        assert_matches!(ContractCallQueryData::from_protobuf(pb), Err(Error::FromProtobuf(_)));
+    }
 }
@@ -9,12 +9,16 @@ use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
 use crate::staked_id::StakedId;
 use crate::transaction::{
+    check_key,
+    check_memo,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -246,6 +250,34 @@ impl TransactionData for ContractCreateTransactionData {
     fn default_max_transaction_fee(&self) -> crate::Hbar {
         crate::Hbar::new(20)
     }
+
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        if self.bytecode.is_none() && self.bytecode_file_id.is_none() {
+            problems.push(ValidationProblem::MissingField { field: "bytecode" });
+        }
+
+        if self.gas == 0 {
+            problems.push(ValidationProblem::MissingField { field: "gas" });
+        }
+
+        if let Some(key) = &self.admin_key {
+            check_key(problems, "admin_key", key);
+        }
+
+        check_memo(problems, "contract_memo", &self.contract_memo);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("bytecode_file", self.bytecode_file_id.as_ref())
+            .optional_entity("auto_renew_account", self.auto_renew_account_id.as_ref())
+            .staked_id(self.staked_id)
+            .key("admin_key", self.admin_key.as_ref());
+
+        if self.initial_balance != Hbar::ZERO {
+            description.field("initial_balance", self.initial_balance);
+        }
+    }
 }
 
 impl TransactionExecute for ContractCreateTransactionData {
@@ -10,12 +10,15 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -78,7 +81,23 @@ impl ContractDeleteTransaction {
     }
 }
 
-impl TransactionData for ContractDeleteTransactionData {}
+impl TransactionData for ContractDeleteTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "contract_id", &self.contract_id);
+
+        // the network rejects this with `OBTAINER_REQUIRED`.
+        if self.transfer_account_id.is_none() && self.transfer_contract_id.is_none() {
+            problems.push(ValidationProblem::MissingField { field: "transfer_account_id" });
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("contract", self.contract_id.as_ref())
+            .optional_entity("transfer_account", self.transfer_account_id.as_ref())
+            .optional_entity("transfer_contract", self.transfer_contract_id.as_ref());
+    }
+}
 
 impl TransactionExecute for ContractDeleteTransactionData {
     fn execute(
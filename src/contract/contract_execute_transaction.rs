@@ -7,12 +7,15 @@ use tonic::transport::Channel;
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -114,9 +117,148 @@ impl ContractExecuteTransaction {
     ) -> &mut Self {
         self.function_parameters(parameters.to_bytes(Some(name)))
     }
+
+    /// Estimate the gas needed for this call using the mirror node, and set [`gas`](Self::gas) to it.
+    ///
+    /// The estimate is multiplied by 1.2 to leave room for state changes
+    /// between estimating and executing, use [`estimate_gas_with_multiplier`](Self::estimate_gas_with_multiplier)
+    /// to change it.
+    ///
+    /// Returns the gas that was set.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `contract_id` isn't set, or if this transaction is frozen.
+    /// - [`Error::MirrorRest`] if the mirror node request fails.
+    #[cfg(feature = "mirror-rest")]
+    pub async fn estimate_gas(&mut self, client: &crate::Client) -> crate::Result<u64> {
+        self.estimate_gas_with_multiplier(client, DEFAULT_GAS_SAFETY_MULTIPLIER).await
+    }
+
+    /// Estimate the gas needed for this call using the mirror node, multiply it by `multiplier`,
+    /// and set [`gas`](Self::gas) to the result.
+    ///
+    /// The payer is the account of this transaction's ID if set, or the client's operator.
+    ///
+    /// Returns the gas that was set.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `contract_id` isn't set, if this transaction is frozen
+    ///   (IE, its gas can't be changed anymore), or if `multiplier` isn't finite or is less than 1.
+    /// - [`Error::MirrorRest`] if the mirror node request fails.
+    #[cfg(feature = "mirror-rest")]
+    pub async fn estimate_gas_with_multiplier(
+        &mut self,
+        client: &crate::Client,
+        multiplier: f64,
+    ) -> crate::Result<u64> {
+        if self.is_frozen() {
+            return Err(Error::basic_parse(
+                "cannot estimate gas for a frozen transaction, its gas can't be changed",
+            ));
+        }
+
+        check_gas_multiplier(multiplier)?;
+
+        let from = self
+            .get_transaction_id()
+            .map(|it| it.account_id)
+            .or_else(|| client.get_operator_account_id());
+
+        let body = self.data().estimate_gas_request(from)?;
+
+        let rest = client.rest_client();
+
+        let response: ContractCallResponse = rest.post("/contracts/call", &body).await?;
+
+        let gas = apply_gas_multiplier(parse_gas_estimate(&response.result)?, multiplier);
+
+        self.data_mut().gas = gas;
+
+        Ok(gas)
+    }
+}
+
+/// The multiplier [`ContractExecuteTransaction::estimate_gas`] applies to the mirror node's estimate.
+#[cfg(feature = "mirror-rest")]
+const DEFAULT_GAS_SAFETY_MULTIPLIER: f64 = 1.2;
+
+/// The response of the mirror node's `/contracts/call` endpoint.
+#[cfg(feature = "mirror-rest")]
+#[derive(serde_derive::Deserialize)]
+struct ContractCallResponse {
+    /// The hex encoded result, for estimates this is the gas.
+    result: String,
 }
 
-impl TransactionData for ContractExecuteTransactionData {}
+#[cfg(feature = "mirror-rest")]
+impl ContractExecuteTransactionData {
+    /// Returns the body of a `/contracts/call` gas estimate request for this call, paid for by `from`.
+    fn estimate_gas_request(
+        &self,
+        from: Option<crate::AccountId>,
+    ) -> crate::Result<serde_json::Value> {
+        let contract_id = self
+            .contract_id
+            .ok_or_else(|| Error::basic_parse("`contract_id` must be set to estimate gas"))?;
+
+        let mut body = serde_json::json!({
+            "block": "latest",
+            "data": format!("0x{}", hex::encode(&self.function_parameters)),
+            "estimate": true,
+            "to": format!("0x{}", contract_id.to_solidity_address()?),
+            "value": self.payable_amount.to_tinybars(),
+        });
+
+        if let Some(from) = from {
+            body["from"] = format!("0x{}", from.to_solidity_address()?).into();
+        }
+
+        Ok(body)
+    }
+}
+
+/// Parse the hex encoded gas estimate returned by the mirror node.
+#[cfg(feature = "mirror-rest")]
+fn parse_gas_estimate(result: &str) -> crate::Result<u64> {
+    let digits = result.strip_prefix("0x").unwrap_or(result);
+
+    u64::from_str_radix(digits, 16).map_err(|_| {
+        Error::mirror_rest(format!("invalid gas estimate `{result}` from the mirror node"))
+    })
+}
+
+/// Check that `multiplier` is a safety margin, IE, that it doesn't lower the estimate.
+#[cfg(feature = "mirror-rest")]
+fn check_gas_multiplier(multiplier: f64) -> crate::Result<()> {
+    if multiplier.is_finite() && multiplier >= 1.0 {
+        Ok(())
+    } else {
+        Err(Error::basic_parse(format!(
+            "gas multiplier must be finite and at least 1, but was `{multiplier}`"
+        )))
+    }
+}
+
+/// Multiply `estimate` by `multiplier`, rounding up.
+#[cfg(feature = "mirror-rest")]
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::cast_sign_loss)]
+fn apply_gas_multiplier(estimate: u64, multiplier: f64) -> u64 {
+    (estimate as f64 * multiplier).ceil() as u64
+}
+
+impl TransactionData for ContractExecuteTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "contract_id", &self.contract_id);
+
+        if self.gas == 0 {
+            problems.push(ValidationProblem::MissingField { field: "gas" });
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.optional_entity("contract", self.contract_id.as_ref());
+    }
+}
 
 impl TransactionExecute for ContractExecuteTransactionData {
     fn execute(
@@ -205,6 +347,7 @@ mod tests {
         transaction_body,
     };
     use crate::{
+        AccountId,
         AnyTransaction,
         ContractExecuteTransaction,
         ContractId,
@@ -352,4 +495,72 @@ mod tests {
     fn get_set_function_parameters_frozen_panics() {
         make_transaction().function_parameters(function_parameters());
     }
+
+    #[cfg(feature = "mirror-rest")]
+    #[test]
+    fn estimate_gas_request() {
+        let mut tx = ContractExecuteTransaction::new();
+        tx.contract_id(CONTRACT_ID)
+            .payable_amount(PAYABLE_AMOUNT)
+            .function_parameters(function_parameters());
+
+        let body = tx.data().estimate_gas_request(Some(AccountId::new(0, 0, 1001))).unwrap();
+
+        expect![[r#"
+            {
+              "block": "latest",
+              "data": "0x182b0b",
+              "estimate": true,
+              "from": "0x00000000000000000000000000000000000003e9",
+              "to": "0x000000000000000000000000000000000000138f",
+              "value": 1000
+            }"#]]
+        .assert_eq(&serde_json::to_string_pretty(&body).unwrap());
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    #[test]
+    fn estimate_gas_request_requires_contract_id() {
+        assert!(ContractExecuteTransaction::new().data().estimate_gas_request(None).is_err());
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    #[tokio::test]
+    async fn estimate_gas_frozen() {
+        let mut tx = make_transaction();
+
+        let result = tx.estimate_gas(&crate::Client::for_testnet()).await;
+
+        assert!(matches!(result, Err(crate::Error::BasicParse(_))), "{result:?}");
+        assert_eq!(tx.get_gas(), GAS);
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    #[test]
+    fn gas_multiplier() {
+        use super::check_gas_multiplier;
+
+        check_gas_multiplier(1.0).unwrap();
+        check_gas_multiplier(1.2).unwrap();
+
+        for multiplier in [0.0, 0.9, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(check_gas_multiplier(multiplier).is_err(), "{multiplier}");
+        }
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    #[test]
+    fn gas_estimate() {
+        use super::{
+            apply_gas_multiplier,
+            parse_gas_estimate,
+        };
+
+        assert_eq!(parse_gas_estimate("0x5208").unwrap(), 21_000);
+        assert!(parse_gas_estimate("0xnope").is_err());
+
+        assert_eq!(apply_gas_multiplier(21_000, 1.2), 25_200);
+        assert_eq!(apply_gas_multiplier(3, 1.5), 5);
+        assert_eq!(apply_gas_multiplier(3, 1.0), 3);
+    }
 }
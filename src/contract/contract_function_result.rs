@@ -9,6 +9,10 @@ use num_bigint::{
     BigUint,
 };
 
+use super::contract_result_decoder::{
+    ContractResultDecoder,
+    FromContractResult,
+};
 use crate::protobuf::ToProtobuf;
 use crate::{
     AccountId,
@@ -20,20 +24,26 @@ use crate::{
 
 /// The result returned by a call to a smart contract function.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ContractFunctionResult {
     /// The smart contract instance whose function was called.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::display"))]
     pub contract_id: ContractId,
 
     /// The new contract's 20-byte EVM address.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub evm_address: Option<ContractId>,
 
     /// The raw bytes returned by the function.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::hex"))]
     pub bytes: Vec<u8>,
 
     /// Message if there was an error during smart contract execution.
     pub error_message: Option<String>,
 
     /// Bloom filter for record.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::hex"))]
     pub bloom: Vec<u8>,
 
     /// Units of gas used to execute contract.
@@ -46,16 +56,20 @@ pub struct ContractFunctionResult {
     pub hbar_amount: u64,
 
     /// The parameters passed into the contract call.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::hex"))]
     pub contract_function_parameters_bytes: Vec<u8>,
 
     /// The account that is the "sender." If not present it is the accountId from the transactionId.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub sender_account_id: Option<AccountId>,
 
     /// Logs that this call and any called functions produced.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub logs: Vec<ContractLogInfo>,
 
     /// A list of updated contract account nonces containing the new nonce value for each contract account.
     /// This is always empty in a ContractLocalCallQuery response, since no internal creations can happen in a static EVM call.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub contract_nonces: Vec<ContractNonceInfo>,
 
     /// If not null this field specifies what the value of the signer account nonce is post transaction execution.
@@ -67,7 +81,7 @@ impl ContractFunctionResult {
     const SLOT_SIZE: usize = 32;
 
     #[must_use]
-    fn get_fixed_bytes<const N: usize>(&self, slot: usize) -> Option<&[u8; N]> {
+    pub(super) fn get_fixed_bytes<const N: usize>(&self, slot: usize) -> Option<&[u8; N]> {
         self.get_fixed_bytes_at(slot * Self::SLOT_SIZE + (Self::SLOT_SIZE - N))
     }
 
@@ -91,6 +105,20 @@ impl ContractFunctionResult {
         Some((offset, len))
     }
 
+    /// Returns a decoder that reads the values of this result in slot order.
+    #[must_use]
+    pub fn decoder(&self) -> ContractResultDecoder<'_> {
+        ContractResultDecoder::new(self)
+    }
+
+    /// Decode the whole function result as a `T`.
+    ///
+    /// # Errors
+    /// - [`Error::ContractResultDecode`](crate::Error::ContractResultDecode) if a value is missing or malformed.
+    pub fn decode<T: FromContractResult>(&self) -> crate::Result<T> {
+        T::from_contract_result(self)
+    }
+
     /// Get the whole raw function result.
     #[must_use]
     pub fn as_bytes(&self) -> &[u8] {
@@ -190,6 +218,21 @@ impl ContractFunctionResult {
         self.get_fixed_bytes(index).copied().map(i64::from_be_bytes)
     }
 
+    /// Get the value at `index` as a solidity `int64[]`.
+    #[must_use]
+    pub fn get_i64_array(&self, index: usize) -> Option<Vec<i64>> {
+        let (offset, len) = self.offset_len_pair(index)?;
+
+        (0..len)
+            .map(|i| {
+                let offset = offset + Self::SLOT_SIZE + (i * Self::SLOT_SIZE);
+                self.get_fixed_bytes_at(offset + Self::SLOT_SIZE - 8)
+                    .copied()
+                    .map(i64::from_be_bytes)
+            })
+            .collect()
+    }
+
     /// Get the value at `index` as a solidity `u256` (`uint`).
     ///
     /// This is the native unsigned integer size for the solidity ABI.
@@ -12,6 +12,7 @@ use hedera_proto::services;
 
 use crate::entity_id::{
     Checksum,
+    EntityIdRange,
     PartialEntityId,
     ValidateChecksums,
 };
@@ -22,11 +23,12 @@ use crate::{
     EntityId,
     Error,
     FromProtobuf,
+    LedgerId,
     ToProtobuf,
 };
 
 /// A unique identifier for a smart contract on Hiero.
-#[derive(Hash, PartialEq, Eq, Clone, Copy)]
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub struct ContractId {
     /// A non-negative number identifying the shard containing this contract instance.
     pub shard: u64,
@@ -55,6 +57,42 @@ impl ContractId {
         Self { shard, realm, num, evm_address: None, checksum: None }
     }
 
+    /// Returns the ID after `self` in the same shard and realm, IE, `0.0.1002` after `0.0.1001`.
+    ///
+    /// Returns `None` if `self.num` is `u64::MAX`.
+    /// Also returns `None` if `self` is an EVM address.
+    #[must_use]
+    pub fn next(&self) -> Option<Self> {
+        if self.evm_address.is_some() {
+            return None;
+        }
+
+        self.num.checked_add(1).map(|num| Self::new(self.shard, self.realm, num))
+    }
+
+    /// Returns the ID before `self` in the same shard and realm, IE, `0.0.1000` before `0.0.1001`.
+    ///
+    /// Returns `None` if `self.num` is `0`.
+    /// Also returns `None` if `self` is an EVM address.
+    #[must_use]
+    pub fn prev(&self) -> Option<Self> {
+        if self.evm_address.is_some() {
+            return None;
+        }
+
+        self.num.checked_sub(1).map(|num| Self::new(self.shard, self.realm, num))
+    }
+
+    /// Returns an iterator over the contract IDs from `start` (inclusive) to `end` (exclusive).
+    ///
+    /// Only `shard`, `realm`, and `num` are used, checksums and EVM addresses are ignored.
+    ///
+    /// The range is empty if `start` and `end` aren't in the same shard and realm.
+    #[must_use]
+    pub fn range(start: Self, end: Self) -> EntityIdRange<Self> {
+        EntityIdRange::new((start.shard, start.realm, start.num), (end.shard, end.realm, end.num))
+    }
+
     /// Create a `ContractId` from a `shard.realm.evm_address` set.
     #[must_use]
     pub fn from_evm_address_bytes(shard: u64, realm: u64, evm_address: [u8; 20]) -> Self {
@@ -136,6 +174,31 @@ impl ContractId {
             EntityId::validate_checksum(self.shard, self.realm, self.num, self.checksum, client)
         }
     }
+
+    /// Convert `self` to a string with a checksum for `ledger_id`,
+    /// see [`EntityId::to_string_with_checksum_for_ledger`](crate::EntityId::to_string_with_checksum_for_ledger).
+    ///
+    /// # Errors
+    /// - [`Error::CannotCreateChecksum`] if self has an `evm_address`.
+    pub fn to_string_with_checksum_for_ledger(
+        &self,
+        ledger_id: &LedgerId,
+    ) -> Result<String, Error> {
+        if self.evm_address.is_some() {
+            Err(Error::CannotCreateChecksum)
+        } else {
+            Ok(EntityId::to_string_with_checksum_for_ledger_id(
+                self.to_string(),
+                ledger_id.as_ref_ledger_id(),
+            ))
+        }
+    }
+
+    /// Validates `self.checksum` for `ledger_id`,
+    /// see [`EntityId::validate_checksum_for_ledger`](crate::EntityId::validate_checksum_for_ledger).
+    pub fn validate_checksum_for_ledger(&self, ledger_id: &LedgerId) -> crate::Result<()> {
+        self.validate_checksums(ledger_id.as_ref_ledger_id())
+    }
 }
 
 impl ValidateChecksums for ContractId {
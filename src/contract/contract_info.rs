@@ -101,7 +101,7 @@ impl FromProtobuf<services::contract_get_info_response::ContractInfo> for Contra
     {
         let contract_id = pb_getf!(pb, contract_id)?;
         let account_id = pb_getf!(pb, account_id)?;
-        let expiration_time = pb.expiration_time.map(Into::into);
+        let expiration_time = Option::from_protobuf(pb.expiration_time)?;
         let auto_renew_period = pb.auto_renew_period.map(Into::into);
         let auto_renew_account_id = Option::from_protobuf(pb.auto_renew_account_id)?;
         let admin_key = Option::from_protobuf(pb.admin_key)?;
@@ -15,6 +15,7 @@ use crate::{
     ContractId,
     ContractInfo,
     Error,
+    FromProtobuf,
     Query,
     ToProtobuf,
     ValidateChecksums,
@@ -63,6 +64,12 @@ impl ToQueryProtobuf for ContractInfoQueryData {
     }
 }
 
+impl FromProtobuf<services::ContractGetInfoQuery> for ContractInfoQueryData {
+    fn from_protobuf(pb: services::ContractGetInfoQuery) -> crate::Result<Self> {
+        Ok(Self { contract_id: Option::from_protobuf(pb.contract_id)? })
+    }
+}
+
 impl QueryExecute for ContractInfoQueryData {
     type Response = ContractInfo;
 
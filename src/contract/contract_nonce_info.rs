@@ -11,8 +11,11 @@ use crate::ContractId;
 /// Info about a contract account's nonce value.
 /// The nonce for a contract is only incremented when that contract creates another contract.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ContractNonceInfo {
     /// The contract's ID.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::display"))]
     pub contract_id: ContractId,
     /// The contract's nonce.
     pub nonce: u64,
@@ -0,0 +1,283 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use num_bigint::{
+    BigInt,
+    BigUint,
+};
+
+use crate::{
+    ContractFunctionResult,
+    Error,
+    EvmAddress,
+};
+
+/// A solidity value that can be read from a single slot of a [`ContractFunctionResult`].
+pub trait FromContractValue: Sized {
+    /// Read the value at slot `index` of `result`, returning `None` if it isn't there.
+    fn from_contract_value(result: &ContractFunctionResult, index: usize) -> Option<Self>;
+}
+
+macro_rules! impl_from_contract_value {
+    ($($ty:ty => $get:ident),* $(,)?) => {
+        $(
+            impl FromContractValue for $ty {
+                fn from_contract_value(result: &ContractFunctionResult, index: usize) -> Option<Self> {
+                    result.$get(index)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_contract_value! {
+    bool => get_bool,
+    u8 => get_u8,
+    i8 => get_i8,
+    u32 => get_u32,
+    i32 => get_i32,
+    u64 => get_u64,
+    i64 => get_i64,
+    BigUint => get_u256,
+    BigInt => get_i256,
+}
+
+impl FromContractValue for String {
+    fn from_contract_value(result: &ContractFunctionResult, index: usize) -> Option<Self> {
+        result.get_str(index).map(std::borrow::Cow::into_owned)
+    }
+}
+
+impl FromContractValue for Vec<String> {
+    fn from_contract_value(result: &ContractFunctionResult, index: usize) -> Option<Self> {
+        result
+            .get_str_array(index)
+            .map(|it| it.into_iter().map(std::borrow::Cow::into_owned).collect())
+    }
+}
+
+impl FromContractValue for Vec<i64> {
+    fn from_contract_value(result: &ContractFunctionResult, index: usize) -> Option<Self> {
+        result.get_i64_array(index)
+    }
+}
+
+impl FromContractValue for Vec<u8> {
+    fn from_contract_value(result: &ContractFunctionResult, index: usize) -> Option<Self> {
+        result.get_bytes(index).map(<[u8]>::to_vec)
+    }
+}
+
+impl FromContractValue for [u8; 32] {
+    fn from_contract_value(result: &ContractFunctionResult, index: usize) -> Option<Self> {
+        result.get_bytes32(index).copied()
+    }
+}
+
+impl FromContractValue for EvmAddress {
+    fn from_contract_value(result: &ContractFunctionResult, index: usize) -> Option<Self> {
+        result.get_fixed_bytes::<20>(index).copied().map(EvmAddress::from)
+    }
+}
+
+/// A type that can be decoded from the output of a contract function.
+///
+/// Implemented for tuples of [`FromContractValue`]s (in slot order),
+/// and can be implemented for structs using a [`ContractResultDecoder`]:
+///
+/// ```
+/// use hedera::{ContractFunctionResult, FromContractResult};
+///
+/// struct Balance {
+///     owner: String,
+///     amount: u64,
+/// }
+///
+/// impl FromContractResult for Balance {
+///     fn from_contract_result(result: &ContractFunctionResult) -> hedera::Result<Self> {
+///         let mut decoder = result.decoder();
+///
+///         Ok(Self { owner: decoder.read()?, amount: decoder.read()? })
+///     }
+/// }
+/// ```
+pub trait FromContractResult: Sized {
+    /// Decode `Self` from `result`.
+    ///
+    /// # Errors
+    /// - [`Error::ContractResultDecode`] if a value is missing or malformed.
+    fn from_contract_result(result: &ContractFunctionResult) -> crate::Result<Self>;
+}
+
+macro_rules! impl_from_contract_result_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: FromContractValue),+> FromContractResult for ($($name,)+) {
+            fn from_contract_result(result: &ContractFunctionResult) -> crate::Result<Self> {
+                let mut decoder = result.decoder();
+
+                Ok(($(decoder.read::<$name>()?,)+))
+            }
+        }
+    };
+}
+
+impl_from_contract_result_tuple!(A);
+impl_from_contract_result_tuple!(A, B);
+impl_from_contract_result_tuple!(A, B, C);
+impl_from_contract_result_tuple!(A, B, C, D);
+impl_from_contract_result_tuple!(A, B, C, D, E);
+impl_from_contract_result_tuple!(A, B, C, D, E, F);
+impl_from_contract_result_tuple!(A, B, C, D, E, F, G);
+impl_from_contract_result_tuple!(A, B, C, D, E, F, G, H);
+
+/// Reads the values of a [`ContractFunctionResult`] in slot order.
+///
+/// Created by [`ContractFunctionResult::decoder`].
+#[derive(Debug, Clone)]
+pub struct ContractResultDecoder<'a> {
+    result: &'a ContractFunctionResult,
+    index: usize,
+}
+
+impl<'a> ContractResultDecoder<'a> {
+    pub(super) fn new(result: &'a ContractFunctionResult) -> Self {
+        Self { result, index: 0 }
+    }
+
+    /// Returns the slot index of the next value to read.
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Read the next value as a `T`.
+    ///
+    /// Dynamic values (`string`, `bytes`, arrays) take one slot here, the slot with their offset.
+    ///
+    /// # Errors
+    /// - [`Error::ContractResultDecode`] if the value is missing or malformed.
+    pub fn read<T: FromContractValue>(&mut self) -> crate::Result<T> {
+        let index = self.index;
+
+        let value = T::from_contract_value(self.result, index)
+            .ok_or(Error::ContractResultDecode { index, type_name: std::any::type_name::<T>() })?;
+
+        self.index += 1;
+
+        Ok(value)
+    }
+
+    /// Skip the next value.
+    pub fn skip(&mut self) -> &mut Self {
+        self.index += 1;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hex_literal::hex;
+    use num_bigint::BigUint;
+
+    use crate::{
+        ContractFunctionResult,
+        ContractId,
+        Error,
+        EvmAddress,
+        FromContractResult,
+    };
+
+    const CALL_RESULT: [u8; 320] = hex!(
+        "00000000000000000000000000000000000000000000000000000000ffffffff"
+        "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+        "00000000000000000000000011223344556677889900aabbccddeeff00112233"
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+        "00000000000000000000000000000000000000000000000000000000000000c0"
+        "0000000000000000000000000000000000000000000000000000000000000100"
+        "000000000000000000000000000000000000000000000000000000000000000d"
+        "48656c6c6f2c20776f726c642100000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000014"
+        "48656c6c6f2c20776f726c642c20616761696e21000000000000000000000000"
+    );
+
+    fn result() -> ContractFunctionResult {
+        ContractFunctionResult {
+            contract_id: ContractId::from(3),
+            evm_address: None,
+            bytes: CALL_RESULT.to_vec(),
+            error_message: None,
+            bloom: Vec::new(),
+            gas_used: 0,
+            logs: Vec::new(),
+            gas: 0,
+            hbar_amount: 0,
+            contract_function_parameters_bytes: Vec::new(),
+            sender_account_id: None,
+            contract_nonces: Vec::new(),
+            signer_nonce: None,
+        }
+    }
+
+    struct Output {
+        value: u32,
+        address: EvmAddress,
+        greeting: String,
+    }
+
+    impl FromContractResult for Output {
+        fn from_contract_result(result: &ContractFunctionResult) -> crate::Result<Self> {
+            let mut decoder = result.decoder();
+
+            let value = decoder.read()?;
+            let address = decoder.skip().read()?;
+            let greeting = decoder.skip().read()?;
+
+            Ok(Self { value, address, greeting })
+        }
+    }
+
+    #[test]
+    fn tuple() {
+        let (value, max, address, _, greeting, greeting_2): (
+            u32,
+            BigUint,
+            EvmAddress,
+            i64,
+            String,
+            String,
+        ) = result().decode().unwrap();
+
+        assert_eq!(value, u32::MAX);
+        assert_eq!(max, (BigUint::from(1_u8) << 255) - 1_u8);
+        assert_eq!(address, EvmAddress::from(hex!("11223344556677889900aabbccddeeff00112233")));
+        assert_eq!(greeting, "Hello, world!");
+        assert_eq!(greeting_2, "Hello, world, again!");
+    }
+
+    #[test]
+    fn custom_struct() {
+        let output: Output = result().decode().unwrap();
+
+        assert_eq!(output.value, u32::MAX);
+        assert_eq!(
+            output.address,
+            EvmAddress::from(hex!("11223344556677889900aabbccddeeff00112233"))
+        );
+        assert_eq!(output.greeting, "Hello, world!");
+    }
+
+    #[test]
+    fn missing_value() {
+        let result = result();
+        let mut decoder = result.decoder();
+
+        for _ in 0..10 {
+            decoder.skip();
+        }
+
+        assert_matches!(
+            decoder.read::<u64>(),
+            Err(Error::ContractResultDecode { index: 10, type_name: "u64" })
+        );
+    }
+}
@@ -0,0 +1,283 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use time::OffsetDateTime;
+
+use crate::mirror_rest::{
+    Links,
+    MirrorStream,
+};
+use crate::protobuf::time::{
+    format_mirror_timestamp,
+    parse_mirror_timestamp,
+};
+use crate::{
+    Client,
+    ContractId,
+    Error,
+};
+
+/// The number of slots requested per page.
+const PAGE_SIZE: u64 = 100;
+
+/// Query the storage of a smart contract, using the mirror node REST API.
+///
+/// Returns the contract's storage slots, ordered by slot,
+/// either as they are now, or as they were at a given [`timestamp`](Self::timestamp).
+#[derive(Debug, Clone, Default)]
+pub struct ContractStateQuery {
+    contract_id: Option<ContractId>,
+    slot: Option<[u8; 32]>,
+    timestamp: Option<OffsetDateTime>,
+    limit: u64,
+}
+
+/// A single storage slot of a smart contract, returned by [`ContractStateQuery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ContractStateSlot {
+    /// The contract the slot belongs to.
+    pub contract_id: ContractId,
+
+    /// The storage slot.
+    pub slot: [u8; 32],
+
+    /// The value stored in the slot.
+    pub value: [u8; 32],
+
+    /// The consensus timestamp of the transaction that last set the slot.
+    pub timestamp: OffsetDateTime,
+}
+
+impl ContractStateQuery {
+    /// Create a new query.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the contract to get the storage of.
+    #[must_use]
+    pub fn get_contract_id(&self) -> Option<ContractId> {
+        self.contract_id
+    }
+
+    /// Sets the contract to get the storage of.
+    pub fn contract_id(&mut self, contract_id: ContractId) -> &mut Self {
+        self.contract_id = Some(contract_id);
+        self
+    }
+
+    /// Returns the single slot to get, if set.
+    #[must_use]
+    pub fn get_slot(&self) -> Option<&[u8; 32]> {
+        self.slot.as_ref()
+    }
+
+    /// Sets the query to only get the given slot.
+    pub fn slot(&mut self, slot: [u8; 32]) -> &mut Self {
+        self.slot = Some(slot);
+        self
+    }
+
+    /// Returns the time to get the storage at, if set.
+    #[must_use]
+    pub fn get_timestamp(&self) -> Option<OffsetDateTime> {
+        self.timestamp
+    }
+
+    /// Sets the time to get the storage at.
+    ///
+    /// Defaults to the latest state.
+    pub fn timestamp(&mut self, timestamp: OffsetDateTime) -> &mut Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Returns the maximum number of slots to return.
+    #[must_use]
+    pub fn get_limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Sets the maximum number of slots to return.
+    ///
+    /// Defaults to `0`, which returns every slot.
+    pub fn limit(&mut self, limit: u64) -> &mut Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Execute this query against the mirror node of the provided client, following pagination.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `contract_id` isn't set, or the mirror node returns malformed slots.
+    /// - [`Error::MirrorRest`] if a mirror node request fails.
+    pub async fn execute(&self, client: &Client) -> crate::Result<Vec<ContractStateSlot>> {
+        self.stream(client).collect_all(self.limit).await
+    }
+
+    /// Returns a stream of the storage slots, fetching each page from the mirror node as the previous one is consumed.
+    ///
+    /// The stream ends after the first error.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `contract_id` isn't set, or the mirror node returns malformed slots.
+    /// - [`Error::MirrorRest`] if a mirror node request fails.
+    pub fn stream(&self, client: &Client) -> MirrorStream<ContractStateSlot> {
+        client.rest_client().paginate(
+            self.first_page_path().map(|it| vec![it]),
+            self.limit,
+            |page: RestContractState| {
+                (page.state.into_iter().map(RestContractStateSlot::into_slot), page.links.next)
+            },
+        )
+    }
+
+    fn first_page_path(&self) -> crate::Result<String> {
+        let contract_id = self.contract_id.ok_or_else(|| {
+            Error::basic_parse("`contract_id` must be set to query contract state")
+        })?;
+
+        let contract = match contract_id.evm_address {
+            Some(_) => format!("0x{}", contract_id.to_solidity_address()?),
+            None => contract_id.to_string(),
+        };
+
+        let limit = match self.limit {
+            0 => PAGE_SIZE,
+            limit => limit.min(PAGE_SIZE),
+        };
+
+        let mut path = format!("/contracts/{contract}/state?order=asc&limit={limit}");
+
+        if let Some(slot) = &self.slot {
+            path.push_str(&format!("&slot=0x{}", hex::encode(slot)));
+        }
+
+        if let Some(timestamp) = self.timestamp {
+            path.push_str(&format!("&timestamp=lte:{}", format_mirror_timestamp(timestamp)));
+        }
+
+        Ok(path)
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestContractState {
+    state: Vec<RestContractStateSlot>,
+    links: Links,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestContractStateSlot {
+    contract_id: String,
+    slot: String,
+    value: String,
+    timestamp: String,
+}
+
+impl RestContractStateSlot {
+    fn into_slot(self) -> crate::Result<ContractStateSlot> {
+        Ok(ContractStateSlot {
+            contract_id: ContractId::from_str(&self.contract_id)?,
+            slot: parse_word(&self.slot)?,
+            value: parse_word(&self.value)?,
+            timestamp: parse_mirror_timestamp(&self.timestamp)?,
+        })
+    }
+}
+
+/// Parse a (possibly unpadded) hex encoded 32 byte word.
+fn parse_word(s: &str) -> crate::Result<[u8; 32]> {
+    let digits = s.strip_prefix("0x").unwrap_or(s);
+
+    if digits.len() > 64 {
+        return Err(Error::basic_parse(format!("`{s}` is longer than 32 bytes")));
+    }
+
+    let bytes = hex::decode(format!("{digits:0>64}")).map_err(Error::basic_parse)?;
+
+    let mut word = [0; 32];
+    word.copy_from_slice(&bytes);
+
+    Ok(word)
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use super::{
+        parse_word,
+        ContractStateQuery,
+        RestContractState,
+    };
+    use crate::mirror_rest::test_page;
+    use crate::ContractId;
+
+    #[test]
+    fn first_page_path() {
+        let mut query = ContractStateQuery::new();
+
+        assert!(query.first_page_path().is_err());
+
+        query.contract_id(ContractId::new(0, 0, 1234));
+
+        assert_eq!(
+            query.first_page_path().unwrap(),
+            "/contracts/0.0.1234/state?order=asc&limit=100"
+        );
+
+        let mut slot = [0; 32];
+        slot[31] = 2;
+
+        query
+            .limit(5)
+            .slot(slot)
+            .timestamp(OffsetDateTime::UNIX_EPOCH + Duration::new(1_700_000_000, 5));
+
+        assert_eq!(
+            query.first_page_path().unwrap(),
+            "/contracts/0.0.1234/state?order=asc&limit=5\
+             &slot=0x0000000000000000000000000000000000000000000000000000000000000002\
+             &timestamp=lte:1700000000.000000005"
+        );
+    }
+
+    #[test]
+    fn parse_page() {
+        let page: RestContractState = test_page(
+            "state",
+            serde_json::json!([
+                {
+                    "address": "0x00000000000000000000000000000000000004d2",
+                    "contract_id": "0.0.1234",
+                    "timestamp": "1700000000.000000005",
+                    "slot": "0x00",
+                    "value": "0x000000000000000000000000000000000000000000000000000000000000002a"
+                }
+            ]),
+            None,
+        );
+
+        let slot = page.state.into_iter().next().unwrap().into_slot().unwrap();
+
+        assert_eq!(slot.contract_id, ContractId::new(0, 0, 1234));
+        assert_eq!(slot.slot, [0; 32]);
+        assert_eq!(slot.value[31], 42);
+        assert_eq!(slot.timestamp, OffsetDateTime::UNIX_EPOCH + Duration::new(1_700_000_000, 5));
+        assert!(page.links.next.is_none());
+    }
+
+    #[test]
+    fn parse_words() {
+        assert_eq!(parse_word("0x1").unwrap()[31], 1);
+        assert!(parse_word(&format!("0x{}", "0".repeat(66))).is_err());
+        assert!(parse_word("0xzz").is_err());
+    }
+}
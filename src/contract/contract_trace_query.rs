@@ -0,0 +1,447 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::mirror_rest::{
+    format_transaction_id,
+    Links,
+};
+use crate::{
+    Client,
+    Error,
+    TransactionHash,
+    TransactionId,
+};
+
+/// The selector of solidity's `Error(string)`, used for `revert("reason")` and `require(cond, "reason")`.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Get the call trace of a contract transaction, using the mirror node REST API.
+///
+/// This is meant for debugging failed contract calls (such as `CONTRACT_REVERT_EXECUTED`),
+/// the trace contains every call made during the transaction, nested by call depth, including the frames that reverted.
+///
+/// The opcode level trace can be included with [`opcodes`](Self::opcodes),
+/// this requires the mirror node to have opcode tracing enabled.
+#[derive(Debug, Clone, Default)]
+pub struct ContractTraceQuery {
+    transaction: Option<TraceTransaction>,
+    opcodes: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TraceTransaction {
+    Id(TransactionId),
+    Hash(TransactionHash),
+}
+
+/// The trace of a contract transaction, returned by [`ContractTraceQuery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ContractTrace {
+    /// The top level calls of the transaction, each containing the calls it made.
+    pub calls: Vec<ContractCallFrame>,
+
+    /// The opcodes executed by the transaction, if they were requested.
+    pub opcodes: Option<Vec<ContractOpcode>>,
+}
+
+/// A single call made during a contract transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ContractCallFrame {
+    /// The depth of the call, the top level call has a depth of `0`.
+    pub depth: u32,
+
+    /// The kind of call, IE `CALL`, `CREATE`, `PRECOMPILE`, or `SYSTEM`.
+    pub call_type: String,
+
+    /// The EVM operation that made the call, IE `CALL`, `DELEGATECALL`, or `STATICCALL`.
+    pub call_operation_type: String,
+
+    /// The EVM address of the caller.
+    pub from: String,
+
+    /// The EVM address of the callee, if there is one.
+    pub to: Option<String>,
+
+    /// The gas given to the call.
+    pub gas: u64,
+
+    /// The gas used by the call.
+    pub gas_used: u64,
+
+    /// The tinybars sent with the call.
+    pub value: u64,
+
+    /// The input data of the call.
+    pub input: Vec<u8>,
+
+    /// The output of the call.
+    pub result: ContractCallResult,
+
+    /// The calls made by this call, in order.
+    pub children: Vec<ContractCallFrame>,
+}
+
+/// The outcome of a [`ContractCallFrame`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ContractCallResult {
+    /// The call succeeded and returned the given data.
+    Output(Vec<u8>),
+
+    /// The call reverted with the given revert data.
+    Revert(Vec<u8>),
+
+    /// The call failed with the given (non-revert) error.
+    Error(Vec<u8>),
+}
+
+/// A single opcode executed during a contract transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ContractOpcode {
+    /// The program counter.
+    pub pc: u64,
+
+    /// The name of the opcode, IE `PUSH1` or `REVERT`.
+    pub op: String,
+
+    /// The gas remaining before the opcode was executed.
+    pub gas: u64,
+
+    /// The gas cost of the opcode.
+    pub gas_cost: u64,
+
+    /// The call depth the opcode was executed at.
+    pub depth: u32,
+
+    /// The EVM stack before the opcode was executed, as hex encoded words.
+    pub stack: Vec<String>,
+
+    /// The hex encoded revert reason, if the opcode reverted.
+    pub reason: Option<String>,
+}
+
+impl ContractCallFrame {
+    /// Returns true if this call reverted or failed.
+    #[must_use]
+    pub fn is_failure(&self) -> bool {
+        !matches!(self.result, ContractCallResult::Output(_))
+    }
+
+    /// Returns the revert reason of this call, if it reverted with solidity's `Error(string)`.
+    #[must_use]
+    pub fn revert_reason(&self) -> Option<String> {
+        let ContractCallResult::Revert(data) = &self.result else { return None };
+
+        decode_revert_reason(data)
+    }
+}
+
+impl ContractTrace {
+    /// Returns the deepest failed call, which is usually the origin of a revert.
+    #[must_use]
+    pub fn failure(&self) -> Option<&ContractCallFrame> {
+        fn deepest_failure(frame: &ContractCallFrame) -> Option<&ContractCallFrame> {
+            frame
+                .children
+                .iter()
+                .rev()
+                .find_map(deepest_failure)
+                .or(frame.is_failure().then_some(frame))
+        }
+
+        self.calls.iter().rev().find_map(deepest_failure)
+    }
+}
+
+impl ContractTraceQuery {
+    /// Create a new query.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the transaction to get the trace of, by its ID.
+    pub fn transaction_id(&mut self, transaction_id: TransactionId) -> &mut Self {
+        self.transaction = Some(TraceTransaction::Id(transaction_id));
+        self
+    }
+
+    /// Sets the transaction to get the trace of, by its hash.
+    pub fn transaction_hash(&mut self, hash: TransactionHash) -> &mut Self {
+        self.transaction = Some(TraceTransaction::Hash(hash));
+        self
+    }
+
+    /// Returns true if the opcode level trace will be included.
+    #[must_use]
+    pub fn get_opcodes(&self) -> bool {
+        self.opcodes
+    }
+
+    /// Sets whether to include the opcode level trace (with the stack at each opcode).
+    ///
+    /// Defaults to `false`.
+    pub fn opcodes(&mut self, opcodes: bool) -> &mut Self {
+        self.opcodes = opcodes;
+        self
+    }
+
+    /// Execute this query against the mirror node of the provided client.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if neither a transaction ID nor hash is set, or the mirror node returns malformed data.
+    /// - [`Error::MirrorRest`] if a mirror node request fails.
+    pub async fn execute(&self, client: &Client) -> crate::Result<ContractTrace> {
+        let transaction = self.transaction_path()?;
+
+        let rest = client.rest_client();
+
+        let path = format!("/contracts/results/{transaction}/actions?order=asc&limit=100");
+
+        let actions = rest
+            .paginate(Ok(vec![path]), 0, |page: RestActions| {
+                (page.actions.into_iter().map(Ok), page.links.next)
+            })
+            .collect_all(0)
+            .await?;
+
+        let opcodes = if self.opcodes {
+            let path = format!(
+                "/contracts/results/{transaction}/opcodes?stack=true&memory=false&storage=false"
+            );
+
+            let response: RestOpcodes = rest.get(&path).await?;
+
+            Some(response.opcodes.into_iter().map(RestOpcode::into_opcode).collect())
+        } else {
+            None
+        };
+
+        Ok(ContractTrace { calls: build_call_tree(actions)?, opcodes })
+    }
+
+    fn transaction_path(&self) -> crate::Result<String> {
+        match &self.transaction {
+            Some(TraceTransaction::Id(id)) => Ok(format_transaction_id(id)),
+            Some(TraceTransaction::Hash(hash)) => Ok(format!("0x{}", hex::encode(hash.0))),
+            None => Err(Error::basic_parse(
+                "a transaction ID or hash must be set to get a contract trace",
+            )),
+        }
+    }
+}
+
+/// Nest the (call depth ordered) `actions` into a tree of calls.
+fn build_call_tree(actions: Vec<RestAction>) -> crate::Result<Vec<ContractCallFrame>> {
+    // the frames that are still open, from the top level call to the most recent.
+    let mut stack: Vec<ContractCallFrame> = Vec::new();
+    let mut calls = Vec::new();
+
+    for action in actions {
+        let frame = action.into_frame()?;
+
+        while stack.last().is_some_and(|it| it.depth >= frame.depth) {
+            close_frame(&mut stack, &mut calls);
+        }
+
+        stack.push(frame);
+    }
+
+    while !stack.is_empty() {
+        close_frame(&mut stack, &mut calls);
+    }
+
+    Ok(calls)
+}
+
+fn close_frame(stack: &mut Vec<ContractCallFrame>, calls: &mut Vec<ContractCallFrame>) {
+    let Some(frame) = stack.pop() else { return };
+
+    match stack.last_mut() {
+        Some(parent) => parent.children.push(frame),
+        None => calls.push(frame),
+    }
+}
+
+/// Decode solidity's `Error(string)` revert data.
+fn decode_revert_reason(data: &[u8]) -> Option<String> {
+    let data = data.strip_prefix(&ERROR_SELECTOR)?;
+
+    // offset word, length word, then the string itself.
+    let len = data.get(32..64)?;
+    let len = usize::try_from(u64::from_be_bytes(len[24..].try_into().ok()?)).ok()?;
+
+    let reason = data.get(64..)?.get(..len)?;
+
+    Some(String::from_utf8_lossy(reason).into_owned())
+}
+
+fn parse_hex(s: Option<&str>) -> crate::Result<Vec<u8>> {
+    let s = s.unwrap_or_default();
+
+    hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(Error::basic_parse)
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestActions {
+    actions: Vec<RestAction>,
+    links: Links,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestAction {
+    call_depth: u32,
+    call_type: String,
+    call_operation_type: String,
+    from: String,
+    to: Option<String>,
+    gas: u64,
+    gas_used: u64,
+    value: u64,
+    input: Option<String>,
+    result_data: Option<String>,
+    result_data_type: String,
+}
+
+impl RestAction {
+    fn into_frame(self) -> crate::Result<ContractCallFrame> {
+        let data = parse_hex(self.result_data.as_deref())?;
+
+        let result = match self.result_data_type.as_str() {
+            "OUTPUT" => ContractCallResult::Output(data),
+            "REVERT_REASON" => ContractCallResult::Revert(data),
+            _ => ContractCallResult::Error(data),
+        };
+
+        Ok(ContractCallFrame {
+            depth: self.call_depth,
+            call_type: self.call_type,
+            call_operation_type: self.call_operation_type,
+            from: self.from,
+            to: self.to,
+            gas: self.gas,
+            gas_used: self.gas_used,
+            value: self.value,
+            input: parse_hex(self.input.as_deref())?,
+            result,
+            children: Vec::new(),
+        })
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestOpcodes {
+    opcodes: Vec<RestOpcode>,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestOpcode {
+    pc: u64,
+    op: String,
+    gas: u64,
+    gas_cost: u64,
+    depth: u32,
+    #[serde(default)]
+    stack: Vec<String>,
+    reason: Option<String>,
+}
+
+impl RestOpcode {
+    fn into_opcode(self) -> ContractOpcode {
+        ContractOpcode {
+            pc: self.pc,
+            op: self.op,
+            gas: self.gas,
+            gas_cost: self.gas_cost,
+            depth: self.depth,
+            stack: self.stack,
+            reason: self.reason,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::{
+        build_call_tree,
+        decode_revert_reason,
+        ContractCallResult,
+        ContractTrace,
+        ContractTraceQuery,
+        RestActions,
+    };
+    use crate::transaction::test_helpers::TEST_TX_ID;
+
+    const REVERT_DATA: [u8; 100] = hex!(
+        "08c379a0"
+        "0000000000000000000000000000000000000000000000000000000000000020"
+        "000000000000000000000000000000000000000000000000000000000000000a"
+        "6e6f7420656e6f75676800000000000000000000000000000000000000000000"
+    );
+
+    fn action(depth: u32, result_data_type: &str, result_data: &str) -> serde_json::Value {
+        serde_json::json!({
+            "call_depth": depth,
+            "call_type": "CALL",
+            "call_operation_type": "CALL",
+            "from": "0x00000000000000000000000000000000000003e9",
+            "to": "0x00000000000000000000000000000000000003ea",
+            "gas": 100_000,
+            "gas_used": 21_000,
+            "value": 0,
+            "input": "0x",
+            "result_data": result_data,
+            "result_data_type": result_data_type,
+        })
+    }
+
+    #[test]
+    fn revert_reason() {
+        assert_eq!(decode_revert_reason(&REVERT_DATA).as_deref(), Some("not enough"));
+        assert_eq!(decode_revert_reason(&REVERT_DATA[..40]), None);
+        assert_eq!(decode_revert_reason(&[]), None);
+    }
+
+    #[test]
+    fn call_tree() {
+        let revert = format!("0x{}", hex::encode(REVERT_DATA));
+
+        let page: RestActions = serde_json::from_value(serde_json::json!({
+            "actions": [
+                action(0, "REVERT_REASON", &revert),
+                action(1, "OUTPUT", "0x01"),
+                action(2, "OUTPUT", "0x02"),
+                action(1, "REVERT_REASON", &revert),
+            ],
+            "links": { "next": null },
+        }))
+        .unwrap();
+
+        let trace = ContractTrace { calls: build_call_tree(page.actions).unwrap(), opcodes: None };
+
+        assert_eq!(trace.calls.len(), 1);
+
+        let root = &trace.calls[0];
+        assert_eq!(root.children.len(), 2);
+        assert_eq!(root.children[0].children[0].result, ContractCallResult::Output(vec![2]));
+
+        let failure = trace.failure().unwrap();
+        assert_eq!(failure.depth, 1);
+        assert_eq!(failure.revert_reason().as_deref(), Some("not enough"));
+    }
+
+    #[test]
+    fn transaction_path() {
+        let mut query = ContractTraceQuery::new();
+
+        assert!(query.transaction_path().is_err());
+
+        query.transaction_id(TEST_TX_ID);
+
+        assert_eq!(query.transaction_path().unwrap(), "0.0.5006-1554158542-000000000");
+    }
+}
@@ -12,12 +12,17 @@ use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
 use crate::staked_id::StakedId;
 use crate::transaction::{
+    check_memo,
+    check_required,
+    check_updated_key,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -197,7 +202,27 @@ impl ContractUpdateTransaction {
     }
 }
 
-impl TransactionData for ContractUpdateTransactionData {}
+impl TransactionData for ContractUpdateTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "contract_id", &self.contract_id);
+
+        if let Some(key) = &self.admin_key {
+            check_updated_key(problems, "admin_key", key);
+        }
+
+        if let Some(contract_memo) = &self.contract_memo {
+            check_memo(problems, "contract_memo", contract_memo);
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("contract", self.contract_id.as_ref())
+            .optional_entity("auto_renew_account", self.auto_renew_account_id.as_ref())
+            .staked_id(self.staked_id)
+            .key("admin_key", self.admin_key.as_ref());
+    }
+}
 
 impl TransactionExecute for ContractUpdateTransactionData {
     fn execute(
@@ -244,7 +269,7 @@ impl FromProtobuf<services::ContractUpdateTransactionBody> for ContractUpdateTra
 
         Ok(Self {
             contract_id: Option::from_protobuf(pb.contract_id)?,
-            expiration_time: pb.expiration_time.map(Into::into),
+            expiration_time: Option::from_protobuf(pb.expiration_time)?,
             admin_key: Option::from_protobuf(pb.admin_key)?,
             auto_renew_period: pb.auto_renew_period.map(Into::into),
             contract_memo: pb.memo_field.map(|it| match it {
@@ -7,6 +7,7 @@ use hedera_proto::services;
 
 use crate::entity_id::{
     Checksum,
+    EntityIdRange,
     PartialEntityId,
 };
 use crate::ethereum::SolidityAddress;
@@ -20,7 +21,7 @@ use crate::{
 };
 
 /// A unique identifier for a smart contract on Hiero.
-#[derive(Hash, PartialEq, Eq, Clone, Copy)]
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub struct DelegateContractId {
     /// A non-negative number identifying the shard containing this contract instance.
     pub shard: u64,
@@ -49,6 +50,42 @@ impl DelegateContractId {
         Self { shard, realm, num, evm_address: None, checksum: None }
     }
 
+    /// Returns the ID after `self` in the same shard and realm, IE, `0.0.1002` after `0.0.1001`.
+    ///
+    /// Returns `None` if `self.num` is `u64::MAX`.
+    /// Also returns `None` if `self` is an EVM address.
+    #[must_use]
+    pub fn next(&self) -> Option<Self> {
+        if self.evm_address.is_some() {
+            return None;
+        }
+
+        self.num.checked_add(1).map(|num| Self::new(self.shard, self.realm, num))
+    }
+
+    /// Returns the ID before `self` in the same shard and realm, IE, `0.0.1000` before `0.0.1001`.
+    ///
+    /// Returns `None` if `self.num` is `0`.
+    /// Also returns `None` if `self` is an EVM address.
+    #[must_use]
+    pub fn prev(&self) -> Option<Self> {
+        if self.evm_address.is_some() {
+            return None;
+        }
+
+        self.num.checked_sub(1).map(|num| Self::new(self.shard, self.realm, num))
+    }
+
+    /// Returns an iterator over the contract IDs from `start` (inclusive) to `end` (exclusive).
+    ///
+    /// Only `shard`, `realm`, and `num` are used, checksums and EVM addresses are ignored.
+    ///
+    /// The range is empty if `start` and `end` aren't in the same shard and realm.
+    #[must_use]
+    pub fn range(start: Self, end: Self) -> EntityIdRange<Self> {
+        EntityIdRange::new((start.shard, start.realm, start.num), (end.shard, end.realm, end.num))
+    }
+
     /// Create a `DelegateContractId` from a solidity address.
     ///
     /// # Errors
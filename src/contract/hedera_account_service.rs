@@ -0,0 +1,254 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use hedera_proto::services;
+use prost::Message;
+use sha3::Digest;
+
+use crate::{
+    AccountId,
+    ContractFunctionParameters,
+    ContractId,
+    Error,
+    PublicKey,
+};
+
+/// Helpers for calling the Hedera Account Service (HAS) system contract, as described in HIP-632.
+///
+/// The HAS system contract lets contracts check Hedera-native signatures.
+/// These helpers build the call data for its functions,
+/// so a contract relying on them can be exercised with a [`ContractCallQuery`](crate::ContractCallQuery)
+/// or [`ContractExecuteTransaction`](crate::ContractExecuteTransaction),
+/// or the system contract can be called directly at [`CONTRACT_ID`](Self::CONTRACT_ID).
+///
+/// Both functions return a single `bool`, IE, [`ContractFunctionResult::get_bool(0)`](crate::ContractFunctionResult::get_bool).
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct HederaAccountService;
+
+impl HederaAccountService {
+    /// The ID of the HAS system contract, `0.0.362`.
+    pub const CONTRACT_ID: ContractId = ContractId::new(0, 0, 362);
+
+    /// Returns the call data for `isAuthorizedRaw(address,bytes,bytes)`,
+    /// which checks that `signature` is a signature on `message` by the single key of `account`.
+    ///
+    /// The arguments are converted to the form the system contract expects:
+    /// for ED25519 keys, the message is passed as-is;
+    /// for ECDSA(secp256k1) keys, the keccak-256 hash of the message is passed,
+    /// and the signature is extended with its recovery ID (`v`).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `account` can't be converted to a solidity address.
+    /// - [`Error::SignatureVerify`] if `signature` isn't a valid signature on `message` by `public_key`.
+    pub fn is_authorized_raw_call_data(
+        account: &AccountId,
+        public_key: &PublicKey,
+        message: &[u8],
+        signature: &[u8],
+    ) -> crate::Result<Vec<u8>> {
+        let address = account_address(account)?;
+
+        let (message_hash, signature) = if public_key.is_ecdsa() {
+            let v = 27 + public_key.ecdsa_recovery_id(message, signature)?;
+
+            let mut signature = signature.to_vec();
+            signature.push(v);
+
+            (sha3::Keccak256::digest(message).to_vec(), signature)
+        } else {
+            public_key.verify(message, signature)?;
+
+            (message.to_vec(), signature.to_vec())
+        };
+
+        Ok(ContractFunctionParameters::new()
+            .add_address(&address)
+            .add_bytes(&message_hash)
+            .add_bytes(&signature)
+            .to_bytes(Some("isAuthorizedRaw")))
+    }
+
+    /// Returns the call data for `isAuthorized(address,bytes,bytes)`,
+    /// which checks that `signatures` on `message` satisfy the (possibly complex) key of `account`.
+    ///
+    /// `signatures` are pairs of the public key that signed, and its signature,
+    /// and are passed as a protobuf encoded `SignatureMap`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `account` can't be converted to a solidity address.
+    /// - [`Error::SignatureVerify`] if any signature isn't a valid signature on `message` by its public key.
+    pub fn is_authorized_call_data<'a>(
+        account: &AccountId,
+        message: &[u8],
+        signatures: impl IntoIterator<Item = (&'a PublicKey, &'a [u8])>,
+    ) -> crate::Result<Vec<u8>> {
+        use services::signature_pair::Signature;
+
+        let address = account_address(account)?;
+
+        let sig_pair = signatures
+            .into_iter()
+            .map(|(public_key, signature)| {
+                public_key.verify(message, signature)?;
+
+                let signature = if public_key.is_ecdsa() {
+                    Signature::EcdsaSecp256k1(signature.to_vec())
+                } else {
+                    Signature::Ed25519(signature.to_vec())
+                };
+
+                Ok(services::SignaturePair {
+                    pub_key_prefix: public_key.to_bytes_raw(),
+                    signature: Some(signature),
+                })
+            })
+            .collect::<crate::Result<_>>()?;
+
+        let signature_blob = services::SignatureMap { sig_pair }.encode_to_vec();
+
+        Ok(ContractFunctionParameters::new()
+            .add_address(&address)
+            .add_bytes(message)
+            .add_bytes(&signature_blob)
+            .to_bytes(Some("isAuthorized")))
+    }
+}
+
+/// Returns the address the system contract knows `account` by.
+pub(super) fn account_address(account: &AccountId) -> crate::Result<String> {
+    match account.evm_address {
+        Some(evm_address) => Ok(evm_address.to_string()),
+        None if account.alias.is_some() => {
+            Err(Error::basic_parse("accounts referenced by key alias have no solidity address"))
+        }
+        None => account.to_solidity_address(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hedera_proto::services;
+    use prost::Message;
+    use sha3::Digest;
+
+    use super::HederaAccountService;
+    use crate::{
+        AccountId,
+        ContractFunctionParameters,
+        Error,
+        PrivateKey,
+    };
+
+    const ACCOUNT_ID: AccountId = AccountId::new(0, 0, 1001);
+    const MESSAGE: &[u8] = b"hello, hedera";
+
+    #[test]
+    fn is_authorized_raw_ed25519() {
+        let key = PrivateKey::generate_ed25519();
+        let signature = key.sign(MESSAGE);
+
+        let call_data = HederaAccountService::is_authorized_raw_call_data(
+            &ACCOUNT_ID,
+            &key.public_key(),
+            MESSAGE,
+            &signature,
+        )
+        .unwrap();
+
+        let expected = ContractFunctionParameters::new()
+            .add_address(&ACCOUNT_ID.to_solidity_address().unwrap())
+            .add_bytes(MESSAGE)
+            .add_bytes(&signature)
+            .to_bytes(Some("isAuthorizedRaw"));
+
+        assert_eq!(call_data, expected);
+    }
+
+    #[test]
+    fn is_authorized_raw_ecdsa() {
+        let key = PrivateKey::generate_ecdsa();
+        let signature = key.sign(MESSAGE);
+
+        let call_data = HederaAccountService::is_authorized_raw_call_data(
+            &ACCOUNT_ID,
+            &key.public_key(),
+            MESSAGE,
+            &signature,
+        )
+        .unwrap();
+
+        let v = 27 + key.public_key().ecdsa_recovery_id(MESSAGE, &signature).unwrap();
+
+        let mut signature = signature;
+        signature.push(v);
+
+        let expected = ContractFunctionParameters::new()
+            .add_address(&ACCOUNT_ID.to_solidity_address().unwrap())
+            .add_bytes(&sha3::Keccak256::digest(MESSAGE))
+            .add_bytes(&signature)
+            .to_bytes(Some("isAuthorizedRaw"));
+
+        assert_eq!(call_data, expected);
+        assert!(v == 27 || v == 28);
+    }
+
+    #[test]
+    fn is_authorized_raw_invalid_signature() {
+        let key = PrivateKey::generate_ecdsa();
+        let signature = PrivateKey::generate_ecdsa().sign(MESSAGE);
+
+        let result = HederaAccountService::is_authorized_raw_call_data(
+            &ACCOUNT_ID,
+            &key.public_key(),
+            MESSAGE,
+            &signature,
+        );
+
+        assert!(matches!(result, Err(Error::SignatureVerify(_))));
+    }
+
+    #[test]
+    fn is_authorized() {
+        let ed25519 = PrivateKey::generate_ed25519();
+        let ecdsa = PrivateKey::generate_ecdsa();
+
+        let ed25519_signature = ed25519.sign(MESSAGE);
+        let ecdsa_signature = ecdsa.sign(MESSAGE);
+
+        let call_data = HederaAccountService::is_authorized_call_data(
+            &ACCOUNT_ID,
+            MESSAGE,
+            [
+                (&ed25519.public_key(), ed25519_signature.as_slice()),
+                (&ecdsa.public_key(), ecdsa_signature.as_slice()),
+            ],
+        )
+        .unwrap();
+
+        let signature_blob = services::SignatureMap {
+            sig_pair: Vec::from([
+                services::SignaturePair {
+                    pub_key_prefix: ed25519.public_key().to_bytes_raw(),
+                    signature: Some(services::signature_pair::Signature::Ed25519(
+                        ed25519_signature,
+                    )),
+                },
+                services::SignaturePair {
+                    pub_key_prefix: ecdsa.public_key().to_bytes_raw(),
+                    signature: Some(services::signature_pair::Signature::EcdsaSecp256k1(
+                        ecdsa_signature,
+                    )),
+                },
+            ]),
+        }
+        .encode_to_vec();
+
+        let expected = ContractFunctionParameters::new()
+            .add_address(&ACCOUNT_ID.to_solidity_address().unwrap())
+            .add_bytes(MESSAGE)
+            .add_bytes(&signature_blob)
+            .to_bytes(Some("isAuthorized"));
+
+        assert_eq!(call_data, expected);
+    }
+}
@@ -0,0 +1,396 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::hedera_account_service::account_address;
+use crate::{
+    AccountId,
+    ContractFunctionParameters,
+    ContractFunctionResult,
+    ContractId,
+    Error,
+    FromContractResult,
+    Status,
+    TokenId,
+};
+
+/// Helpers for calling the Hedera Token Service (HTS) system contract, as described in HIP-206.
+///
+/// The HTS system contract (sometimes called the HTS precompile) exposes native token operations to contracts.
+/// These helpers build the call data for its functions from SDK types,
+/// so the system contract can be called directly at [`CONTRACT_ID`](Self::CONTRACT_ID)
+/// with a [`ContractExecuteTransaction`](crate::ContractExecuteTransaction),
+/// and native and system contract flows can be mixed.
+///
+/// Every function returns a response code first, decode it with [`response_code`](Self::response_code),
+/// or decode the whole result of `mintToken` as a [`MintTokenResult`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct HederaTokenService;
+
+impl HederaTokenService {
+    /// The ID of the HTS system contract, `0.0.359` (`0x167`).
+    pub const CONTRACT_ID: ContractId = ContractId::new(0, 0, 359);
+
+    /// Returns the call data for `associateToken(address,address)`,
+    /// which associates `account` with `token_id`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `account` or `token_id` can't be converted to a solidity address.
+    pub fn associate_token_call_data(
+        account: &AccountId,
+        token_id: TokenId,
+    ) -> crate::Result<Vec<u8>> {
+        Ok(ContractFunctionParameters::new()
+            .add_address(&account_address(account)?)
+            .add_address(&token_id.to_solidity_address()?)
+            .to_bytes(Some("associateToken")))
+    }
+
+    /// Returns the call data for `associateTokens(address,address[])`,
+    /// which associates `account` with every token in `token_ids`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `account` or any token can't be converted to a solidity address.
+    pub fn associate_tokens_call_data(
+        account: &AccountId,
+        token_ids: &[TokenId],
+    ) -> crate::Result<Vec<u8>> {
+        let tokens = token_ids
+            .iter()
+            .map(TokenId::to_solidity_address)
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        let tokens: Vec<_> = tokens.iter().map(String::as_str).collect();
+
+        Ok(ContractFunctionParameters::new()
+            .add_address(&account_address(account)?)
+            .add_address_array(&tokens)
+            .to_bytes(Some("associateTokens")))
+    }
+
+    /// Returns the call data for `transferToken(address,address,address,int64)`,
+    /// which transfers `amount` of the fungible token `token_id` from `sender` to `receiver`.
+    ///
+    /// `amount` is in the smallest denomination of the token.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an account or the token can't be converted to a solidity address,
+    ///   or if `amount` doesn't fit in an `int64`.
+    pub fn transfer_token_call_data(
+        token_id: TokenId,
+        sender: &AccountId,
+        receiver: &AccountId,
+        amount: u64,
+    ) -> crate::Result<Vec<u8>> {
+        let amount = i64::try_from(amount).map_err(Error::basic_parse)?;
+
+        Ok(ContractFunctionParameters::new()
+            .add_address(&token_id.to_solidity_address()?)
+            .add_address(&account_address(sender)?)
+            .add_address(&account_address(receiver)?)
+            .add_int64(amount)
+            .to_bytes(Some("transferToken")))
+    }
+
+    /// Returns the call data for `transferNFT(address,address,address,int64)`,
+    /// which transfers the NFT `serial` of `token_id` from `sender` to `receiver`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an account or the token can't be converted to a solidity address,
+    ///   or if `serial` doesn't fit in an `int64`.
+    pub fn transfer_nft_call_data(
+        token_id: TokenId,
+        serial: u64,
+        sender: &AccountId,
+        receiver: &AccountId,
+    ) -> crate::Result<Vec<u8>> {
+        let serial = i64::try_from(serial).map_err(Error::basic_parse)?;
+
+        Ok(ContractFunctionParameters::new()
+            .add_address(&token_id.to_solidity_address()?)
+            .add_address(&account_address(sender)?)
+            .add_address(&account_address(receiver)?)
+            .add_int64(serial)
+            .to_bytes(Some("transferNFT")))
+    }
+
+    /// Returns the call data for `mintToken(address,int64,bytes[])`,
+    /// which mints `amount` of the fungible token `token_id`,
+    /// or, if `metadata` isn't empty, one NFT of `token_id` per entry.
+    ///
+    /// `amount` should be `0` when minting NFTs.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the token can't be converted to a solidity address,
+    ///   or if `amount` doesn't fit in an `int64`.
+    pub fn mint_token_call_data(
+        token_id: TokenId,
+        amount: u64,
+        metadata: &[Vec<u8>],
+    ) -> crate::Result<Vec<u8>> {
+        let amount = i64::try_from(amount).map_err(Error::basic_parse)?;
+
+        let metadata: Vec<_> = metadata.iter().map(Vec::as_slice).collect();
+
+        Ok(ContractFunctionParameters::new()
+            .add_address(&token_id.to_solidity_address()?)
+            .add_int64(amount)
+            .add_bytes_array(&metadata)
+            .to_bytes(Some("mintToken")))
+    }
+
+    /// Decode the response code that every HTS function returns first.
+    ///
+    /// A call that fails inside the system contract still succeeds as a contract call,
+    /// so the response code should be checked (IE, for `Status::Success`).
+    ///
+    /// # Errors
+    /// - [`Error::ContractResultDecode`] if `result` doesn't start with a response code.
+    /// - [`Error::BasicParse`] if the response code is out of range for a [`Status`].
+    /// - [`Error::ResponseStatusUnrecognized`] if the response code isn't a known [`Status`].
+    pub fn response_code(result: &ContractFunctionResult) -> crate::Result<Status> {
+        let code = result
+            .get_i64(0)
+            .ok_or(Error::ContractResultDecode { index: 0, type_name: "int64" })?;
+
+        status_from_code(code)
+    }
+}
+
+/// The result of `mintToken(address,int64,bytes[])`.
+///
+/// Decode it with [`ContractFunctionResult::decode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MintTokenResult {
+    /// The response code of the call.
+    pub status: Status,
+
+    /// The total supply of the token after the mint.
+    pub new_total_supply: u64,
+
+    /// The serial numbers of the minted NFTs, empty for fungible tokens.
+    pub serial_numbers: Vec<i64>,
+}
+
+impl FromContractResult for MintTokenResult {
+    fn from_contract_result(result: &ContractFunctionResult) -> crate::Result<Self> {
+        let mut decoder = result.decoder();
+
+        let status = status_from_code(decoder.read()?)?;
+        let new_total_supply = decoder.read()?;
+        let serial_numbers = decoder.read()?;
+
+        Ok(Self { status, new_total_supply, serial_numbers })
+    }
+}
+
+fn status_from_code(code: i64) -> crate::Result<Status> {
+    let code = i32::try_from(code).map_err(|_| {
+        Error::basic_parse(format!("response code `{code}` is out of range for a status"))
+    })?;
+
+    Status::try_from(code).map_err(|_| Error::ResponseStatusUnrecognized(code))
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::{
+        HederaTokenService,
+        MintTokenResult,
+    };
+    use crate::{
+        AccountId,
+        ContractFunctionParameters,
+        ContractFunctionResult,
+        ContractId,
+        Error,
+        Status,
+        TokenId,
+    };
+
+    const ACCOUNT_ID: AccountId = AccountId::new(0, 0, 1001);
+    const RECEIVER_ID: AccountId = AccountId::new(0, 0, 1002);
+    const TOKEN_ID: TokenId = TokenId::new(0, 0, 5005);
+
+    fn result(bytes: Vec<u8>) -> ContractFunctionResult {
+        ContractFunctionResult {
+            contract_id: HederaTokenService::CONTRACT_ID,
+            evm_address: None,
+            bytes,
+            error_message: None,
+            bloom: Vec::new(),
+            gas_used: 0,
+            logs: Vec::new(),
+            gas: 0,
+            hbar_amount: 0,
+            contract_function_parameters_bytes: Vec::new(),
+            sender_account_id: None,
+            contract_nonces: Vec::new(),
+            signer_nonce: None,
+        }
+    }
+
+    #[test]
+    fn contract_id() {
+        assert_eq!(
+            HederaTokenService::CONTRACT_ID.to_solidity_address().unwrap(),
+            "0000000000000000000000000000000000000167"
+        );
+        assert_ne!(HederaTokenService::CONTRACT_ID, ContractId::new(0, 0, 362));
+    }
+
+    #[test]
+    fn associate_token() {
+        let call_data =
+            HederaTokenService::associate_token_call_data(&ACCOUNT_ID, TOKEN_ID).unwrap();
+
+        // keccak256("associateToken(address,address)")[..4]
+        assert_eq!(call_data[..4], hex!("49146bde"));
+
+        let expected = ContractFunctionParameters::new()
+            .add_address(&ACCOUNT_ID.to_solidity_address().unwrap())
+            .add_address(&TOKEN_ID.to_solidity_address().unwrap())
+            .to_bytes(Some("associateToken"));
+
+        assert_eq!(call_data, expected);
+    }
+
+    #[test]
+    fn associate_tokens() {
+        let other = TokenId::new(0, 0, 5006);
+
+        let call_data =
+            HederaTokenService::associate_tokens_call_data(&ACCOUNT_ID, &[TOKEN_ID, other])
+                .unwrap();
+
+        let expected = ContractFunctionParameters::new()
+            .add_address(&ACCOUNT_ID.to_solidity_address().unwrap())
+            .add_address_array(&[
+                &TOKEN_ID.to_solidity_address().unwrap(),
+                &other.to_solidity_address().unwrap(),
+            ])
+            .to_bytes(Some("associateTokens"));
+
+        assert_eq!(call_data, expected);
+    }
+
+    #[test]
+    fn transfer_token() {
+        let call_data =
+            HederaTokenService::transfer_token_call_data(TOKEN_ID, &ACCOUNT_ID, &RECEIVER_ID, 10)
+                .unwrap();
+
+        // keccak256("transferToken(address,address,address,int64)")[..4]
+        assert_eq!(call_data[..4], hex!("eca36917"));
+
+        let expected = ContractFunctionParameters::new()
+            .add_address(&TOKEN_ID.to_solidity_address().unwrap())
+            .add_address(&ACCOUNT_ID.to_solidity_address().unwrap())
+            .add_address(&RECEIVER_ID.to_solidity_address().unwrap())
+            .add_int64(10)
+            .to_bytes(Some("transferToken"));
+
+        assert_eq!(call_data, expected);
+    }
+
+    #[test]
+    fn transfer_token_amount_too_large() {
+        let result = HederaTokenService::transfer_token_call_data(
+            TOKEN_ID,
+            &ACCOUNT_ID,
+            &RECEIVER_ID,
+            u64::MAX,
+        );
+
+        assert!(matches!(result, Err(Error::BasicParse(_))));
+    }
+
+    #[test]
+    fn transfer_nft() {
+        let call_data =
+            HederaTokenService::transfer_nft_call_data(TOKEN_ID, 3, &ACCOUNT_ID, &RECEIVER_ID)
+                .unwrap();
+
+        let expected = ContractFunctionParameters::new()
+            .add_address(&TOKEN_ID.to_solidity_address().unwrap())
+            .add_address(&ACCOUNT_ID.to_solidity_address().unwrap())
+            .add_address(&RECEIVER_ID.to_solidity_address().unwrap())
+            .add_int64(3)
+            .to_bytes(Some("transferNFT"));
+
+        assert_eq!(call_data, expected);
+    }
+
+    #[test]
+    fn mint_token() {
+        let metadata = [b"first".to_vec(), b"second".to_vec()];
+
+        let call_data = HederaTokenService::mint_token_call_data(TOKEN_ID, 0, &metadata).unwrap();
+
+        let expected = ContractFunctionParameters::new()
+            .add_address(&TOKEN_ID.to_solidity_address().unwrap())
+            .add_int64(0)
+            .add_bytes_array(&[b"first", b"second"])
+            .to_bytes(Some("mintToken"));
+
+        assert_eq!(call_data, expected);
+    }
+
+    #[test]
+    fn response_code() {
+        let bytes = ContractFunctionParameters::new().add_int64(22).to_bytes(None);
+
+        assert_eq!(HederaTokenService::response_code(&result(bytes)).unwrap(), Status::Success);
+
+        let bytes = ContractFunctionParameters::new().add_int64(167).to_bytes(None);
+
+        assert_eq!(
+            HederaTokenService::response_code(&result(bytes)).unwrap(),
+            Status::InvalidTokenId
+        );
+    }
+
+    #[test]
+    fn response_code_unrecognized() {
+        let bytes = ContractFunctionParameters::new().add_int64(-1).to_bytes(None);
+
+        assert!(matches!(
+            HederaTokenService::response_code(&result(bytes)),
+            Err(Error::ResponseStatusUnrecognized(-1))
+        ));
+
+        let bytes = ContractFunctionParameters::new().add_int64(i64::MAX).to_bytes(None);
+
+        let Err(Error::BasicParse(error)) = HederaTokenService::response_code(&result(bytes))
+        else {
+            panic!("expected a `BasicParse` error");
+        };
+        assert!(error.to_string().contains(&i64::MAX.to_string()));
+
+        assert!(matches!(
+            HederaTokenService::response_code(&result(Vec::new())),
+            Err(Error::ContractResultDecode { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn mint_token_result() {
+        let bytes = ContractFunctionParameters::new()
+            .add_int64(22)
+            .add_int64(2)
+            .add_int64_array(&[1, 2])
+            .to_bytes(None);
+
+        let mint: MintTokenResult = result(bytes).decode().unwrap();
+
+        assert_eq!(
+            mint,
+            MintTokenResult {
+                status: Status::Success,
+                new_total_supply: 2,
+                serial_numbers: Vec::from([1, 2]),
+            }
+        );
+    }
+}
@@ -1,41 +1,103 @@
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "contracts")]
 mod contract_bytecode_query;
+#[cfg(feature = "contracts")]
 mod contract_call_query;
+#[cfg(feature = "contracts")]
 mod contract_create_flow;
+#[cfg(feature = "contracts")]
 mod contract_create_transaction;
+#[cfg(feature = "contracts")]
 mod contract_delete_transaction;
+#[cfg(feature = "contracts")]
 mod contract_execute_transaction;
+#[cfg(feature = "contracts")]
 mod contract_function_parameters;
 mod contract_function_result;
+#[cfg(feature = "contracts")]
 mod contract_function_selector;
 mod contract_id;
+#[cfg(feature = "contracts")]
 mod contract_info;
+#[cfg(feature = "contracts")]
 mod contract_info_query;
 mod contract_log_info;
 mod contract_nonce_info;
+mod contract_result_decoder;
+#[cfg(all(feature = "contracts", feature = "mirror-rest"))]
+mod contract_state_query;
+#[cfg(all(feature = "contracts", feature = "mirror-rest"))]
+mod contract_trace_query;
+#[cfg(feature = "contracts")]
 mod contract_update_transaction;
 mod delegate_contract_id;
+#[cfg(feature = "contracts")]
+mod hedera_account_service;
+#[cfg(feature = "contracts")]
+mod hedera_token_service;
 
+#[cfg(feature = "contracts")]
 pub use contract_bytecode_query::ContractBytecodeQuery;
+#[cfg(feature = "contracts")]
 pub(crate) use contract_bytecode_query::ContractBytecodeQueryData;
+#[cfg(feature = "contracts")]
 pub use contract_call_query::ContractCallQuery;
+#[cfg(feature = "contracts")]
 pub(crate) use contract_call_query::ContractCallQueryData;
+#[cfg(feature = "contracts")]
 pub use contract_create_flow::ContractCreateFlow;
+#[cfg(feature = "contracts")]
 pub use contract_create_transaction::ContractCreateTransaction;
+#[cfg(feature = "contracts")]
 pub(crate) use contract_create_transaction::ContractCreateTransactionData;
+#[cfg(feature = "contracts")]
 pub use contract_delete_transaction::ContractDeleteTransaction;
+#[cfg(feature = "contracts")]
 pub(crate) use contract_delete_transaction::ContractDeleteTransactionData;
+#[cfg(feature = "contracts")]
 pub use contract_execute_transaction::ContractExecuteTransaction;
+#[cfg(feature = "contracts")]
 pub(crate) use contract_execute_transaction::ContractExecuteTransactionData;
+#[cfg(feature = "contracts")]
 pub use contract_function_parameters::ContractFunctionParameters;
 pub use contract_function_result::ContractFunctionResult;
 pub use contract_id::ContractId;
+#[cfg(feature = "contracts")]
 pub use contract_info::ContractInfo;
+#[cfg(feature = "contracts")]
 pub use contract_info_query::ContractInfoQuery;
+#[cfg(feature = "contracts")]
 pub(crate) use contract_info_query::ContractInfoQueryData;
 pub use contract_log_info::ContractLogInfo;
 pub use contract_nonce_info::ContractNonceInfo;
+pub use contract_result_decoder::{
+    ContractResultDecoder,
+    FromContractResult,
+    FromContractValue,
+};
+#[cfg(all(feature = "contracts", feature = "mirror-rest"))]
+pub use contract_state_query::{
+    ContractStateQuery,
+    ContractStateSlot,
+};
+#[cfg(all(feature = "contracts", feature = "mirror-rest"))]
+pub use contract_trace_query::{
+    ContractCallFrame,
+    ContractCallResult,
+    ContractOpcode,
+    ContractTrace,
+    ContractTraceQuery,
+};
+#[cfg(feature = "contracts")]
 pub use contract_update_transaction::ContractUpdateTransaction;
+#[cfg(feature = "contracts")]
 pub(crate) use contract_update_transaction::ContractUpdateTransactionData;
 pub use delegate_contract_id::DelegateContractId;
+#[cfg(feature = "contracts")]
+pub use hedera_account_service::HederaAccountService;
+#[cfg(feature = "contracts")]
+pub use hedera_token_service::{
+    HederaTokenService,
+    MintTokenResult,
+};
@@ -84,7 +84,7 @@ impl ToProtobuf for CustomFixedFee {
 
 impl FromProtobuf<services::FixedCustomFee> for CustomFixedFee {
     fn from_protobuf(pb: services::FixedCustomFee) -> crate::Result<Self> {
-        let fee = FixedFeeData::from_protobuf(pb.fixed_fee.unwrap())?;
+        let fee = FixedFeeData::from_protobuf(pb_getf!(pb, fixed_fee)?)?;
 
         Ok(Self {
             amount: fee.amount as u64,
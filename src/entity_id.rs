@@ -6,6 +6,9 @@ use std::fmt::{
     Display,
     Formatter,
 };
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ops::Range;
 use std::str::FromStr;
 
 use tinystr::TinyAsciiStr;
@@ -15,9 +18,10 @@ use crate::ledger_id::RefLedgerId;
 use crate::{
     Client,
     Error,
+    LedgerId,
 };
 
-#[derive(Hash, PartialEq, Eq, Clone, Copy)]
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub struct Checksum(TinyAsciiStr<5>);
 
 impl Checksum {
@@ -72,7 +76,9 @@ impl<T: ValidateChecksums> ValidateChecksums for Option<T> {
 }
 
 /// The ID of an entity on the Hiero network.
-#[derive(Hash, PartialEq, Eq, Clone, Copy)]
+///
+/// IDs are ordered by shard, then realm, then num.
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub struct EntityId {
     /// A non-negative number identifying the shard containing this entity.
     pub shard: u64,
@@ -274,18 +280,57 @@ impl EntityId {
         }
     }
 
-    pub(crate) fn to_string_with_checksum(mut entity_id_string: String, client: &Client) -> String {
+    pub(crate) fn to_string_with_checksum(entity_id_string: String, client: &Client) -> String {
         let ledger_id = client.ledger_id_internal();
         let ledger_id = ledger_id
             .as_ref()
             .expect("Client had no ledger ID (help: call `client.set_ledger_id()`");
 
-        let checksum = Self::generate_checksum(&entity_id_string, ledger_id.as_ref_ledger_id());
+        Self::to_string_with_checksum_for_ledger_id(entity_id_string, ledger_id.as_ref_ledger_id())
+    }
+
+    pub(crate) fn to_string_with_checksum_for_ledger_id(
+        mut entity_id_string: String,
+        ledger_id: &RefLedgerId,
+    ) -> String {
+        let checksum = Self::generate_checksum(&entity_id_string, ledger_id);
         entity_id_string.push('-');
         entity_id_string.push_str(&checksum.0);
 
         entity_id_string
     }
+
+    /// Convert `self` to a string with a valid checksum for `ledger_id`.
+    ///
+    /// The checksum is derived from the ID and the bytes of the ledger ID,
+    /// so the same ID has a different checksum on each ledger.
+    /// Unlike the `to_string_with_checksum` methods, this doesn't need a client,
+    /// so it works for any ledger, including custom networks and previewnet after a reset.
+    ///
+    /// Every ID type has a `to_string_with_checksum_for_ledger` method that does the same.
+    #[must_use]
+    pub fn to_string_with_checksum_for_ledger(&self, ledger_id: &LedgerId) -> String {
+        Self::to_string_with_checksum_for_ledger_id(self.to_string(), ledger_id.as_ref_ledger_id())
+    }
+
+    /// Validates `self.checksum` (if it exists) for `ledger_id`.
+    ///
+    /// An ID without a checksum is always valid.
+    /// Like [`to_string_with_checksum_for_ledger`](Self::to_string_with_checksum_for_ledger), this works for any ledger.
+    ///
+    /// Every ID type has a `validate_checksum_for_ledger` method that does the same.
+    ///
+    /// # Errors
+    /// - [`Error::BadEntityId`] if there is a checksum, and the checksum is not valid for `ledger_id`.
+    pub fn validate_checksum_for_ledger(&self, ledger_id: &LedgerId) -> crate::Result<()> {
+        Self::validate_checksum_for_ledger_id(
+            self.shard,
+            self.realm,
+            self.num,
+            self.checksum,
+            ledger_id.as_ref_ledger_id(),
+        )
+    }
 }
 
 impl Debug for EntityId {
@@ -314,11 +359,64 @@ impl FromStr for EntityId {
     }
 }
 
+/// An iterator over entity IDs with contiguous nums in a single shard and realm,
+/// IE, as returned by [`AccountId::range`](crate::AccountId::range).
+#[derive(Debug, Clone)]
+pub struct EntityIdRange<T> {
+    shard: u64,
+    realm: u64,
+    nums: Range<u64>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> EntityIdRange<T> {
+    /// Returns the IDs from `start` (inclusive) to `end` (exclusive), as `(shard, realm, num)`.
+    ///
+    /// The range is empty if `start` and `end` aren't in the same shard and realm.
+    pub(crate) fn new(start: (u64, u64, u64), end: (u64, u64, u64)) -> Self {
+        let (shard, realm, start) = start;
+
+        let end = if (shard, realm) == (end.0, end.1) { end.2 } else { start };
+
+        Self { shard, realm, nums: start..end, _marker: PhantomData }
+    }
+
+    fn entity_id(&self, num: u64) -> EntityId {
+        EntityId { shard: self.shard, realm: self.realm, num, checksum: None }
+    }
+}
+
+impl<T: From<EntityId>> Iterator for EntityIdRange<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nums.next().map(|num| self.entity_id(num).into())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.nums.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.nums.nth(n).map(|num| self.entity_id(num).into())
+    }
+}
+
+impl<T: From<EntityId>> DoubleEndedIterator for EntityIdRange<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nums.next_back().map(|num| self.entity_id(num).into())
+    }
+}
+
+impl<T: From<EntityId>> FusedIterator for EntityIdRange<T> {}
+
 #[cfg(test)]
 mod tests {
+    use crate::entity_id::EntityIdRange;
     use crate::ledger_id::RefLedgerId;
     use crate::{
         EntityId,
+        LedgerId,
         TopicId,
     };
 
@@ -435,4 +533,60 @@ mod tests {
             assert_eq!(expected, &actual);
         }
     }
+
+    #[test]
+    fn checksum_for_custom_ledger() {
+        let ledger_id = LedgerId::from_bytes(Vec::from([0x03]));
+        let id = EntityId { shard: 1, realm: 2, num: 3, checksum: None };
+
+        let s = id.to_string_with_checksum_for_ledger(&ledger_id);
+        let (_, checksum) = s.split_once('-').unwrap();
+
+        assert_eq!(
+            checksum,
+            EntityId::generate_checksum("1.2.3", ledger_id.as_ref_ledger_id()).to_string()
+        );
+
+        // the same ID has a different checksum on each ledger.
+        for other in [LedgerId::mainnet(), LedgerId::testnet(), LedgerId::previewnet()] {
+            assert_ne!(s, id.to_string_with_checksum_for_ledger(&other));
+        }
+
+        let parsed: EntityId = s.parse().unwrap();
+
+        parsed.validate_checksum_for_ledger(&ledger_id).unwrap();
+        assert!(parsed.validate_checksum_for_ledger(&LedgerId::mainnet()).is_err());
+    }
+
+    #[test]
+    fn ord() {
+        let id = |shard, realm, num| EntityId { shard, realm, num, checksum: None };
+
+        let mut ids = [id(1, 0, 0), id(0, 1, 0), id(0, 0, 2), id(0, 0, 1)];
+        ids.sort();
+
+        assert_eq!(ids, [id(0, 0, 1), id(0, 0, 2), id(0, 1, 0), id(1, 0, 0)]);
+    }
+
+    #[test]
+    fn range() {
+        let ids: Vec<TopicId> = EntityIdRange::new((1, 2, 5), (1, 2, 8)).collect();
+
+        assert_eq!(ids, [TopicId::new(1, 2, 5), TopicId::new(1, 2, 6), TopicId::new(1, 2, 7)]);
+
+        let mut range = EntityIdRange::<TopicId>::new((0, 0, 5), (0, 0, 8));
+
+        assert_eq!(range.size_hint(), (3, Some(3)));
+        assert_eq!(range.next_back(), Some(TopicId::new(0, 0, 7)));
+        assert_eq!(range.nth(1), Some(TopicId::new(0, 0, 6)));
+        assert_eq!(range.next(), None);
+
+        assert_eq!(EntityIdRange::<TopicId>::new((0, 0, 8), (0, 0, 5)).count(), 0);
+    }
+
+    #[test]
+    fn range_across_realms_is_empty() {
+        assert_eq!(EntityIdRange::<TopicId>::new((0, 0, 5), (0, 1, 8)).count(), 0);
+        assert_eq!(EntityIdRange::<TopicId>::new((0, 0, 5), (1, 0, 8)).count(), 0);
+    }
 }
@@ -2,12 +2,17 @@
 
 use std::error::Error as StdError;
 use std::result::Result as StdResult;
+use std::time::Duration;
 
 use crate::entity_id::Checksum;
+use crate::protobuf::FromProtobufError;
 use crate::{
     AccountId,
+    ChunkedTransactionResponse,
     Hbar,
+    KeyAlgorithm,
     Status,
+    TokenId,
     TransactionId,
 };
 
@@ -24,11 +29,25 @@ pub enum Error {
     #[error("failed to complete request within the maximum time allowed; most recent attempt failed with: {0}")]
     TimedOut(#[source] Box<Error>),
 
+    /// Request was cancelled with its `CancellationToken`.
+    #[error("request was cancelled")]
+    Cancelled,
+
     /// GRPC status code was an error.
     #[error("grpc: {0:?}")]
     GrpcStatus(#[from] tonic::Status),
 
+    /// A request to a consensus node failed in transport, IE, the node was unreachable, or didn't respond in time.
+    ///
+    /// Unlike [`GrpcStatus`](Self::GrpcStatus), this says which node failed, and when.
+    /// Statuses where the node rejected the request (such as `InvalidArgument` or `Unauthenticated`)
+    /// are returned as [`GrpcStatus`](Self::GrpcStatus) instead.
+    #[error(transparent)]
+    NodeError(Box<NodeError>),
+
     /// Failed to parse an SDK type from a protobuf response.
+    ///
+    /// The error is a [`FromProtobufError`](crate::FromProtobufError), which says where in the message parsing failed.
     #[error("failed to create a SDK type from a protobuf response: {0}")]
     FromProtobuf(#[source] BoxStdError),
 
@@ -120,6 +139,12 @@ pub enum Error {
     #[error("failed to parse a key: {0}")]
     KeyParse(#[source] BoxStdError),
 
+    /// A key or signature uses an algorithm this SDK doesn't support, IE, an RSA-3072 key in the address book.
+    ///
+    /// This can happen when the SDK is outdated, try updating your SDK.
+    #[error("unsupported key algorithm `{0}`")]
+    UnsupportedKeyAlgorithm(KeyAlgorithm),
+
     /// Failed to derive a [`PrivateKey`](crate::PrivateKey) from another `PrivateKey`.
     ///
     /// Examples of when this can happen (non-exhaustive):
@@ -188,11 +213,206 @@ pub enum Error {
     /// Failed to verify a signature.
     #[error("failed to verify a signature: {0}")]
     SignatureVerify(#[source] BoxStdError),
+
+    /// A buffered mirror subscription's buffer filled up while using [`SubscriptionOverflowPolicy::Error`](crate::SubscriptionOverflowPolicy::Error).
+    #[error("subscription buffer exceeded its capacity of {capacity} items")]
+    SubscriptionBufferOverflow {
+        /// The capacity of the buffer.
+        capacity: usize,
+    },
+
+    /// A value couldn't be decoded from a [`ContractFunctionResult`](crate::ContractFunctionResult).
+    #[error("failed to decode a `{type_name}` from slot {index} of a contract function result")]
+    ContractResultDecode {
+        /// The slot index of the value.
+        index: usize,
+
+        /// The name of the type that was being decoded.
+        type_name: &'static str,
+    },
+
+    /// A [`TokenAmount`](crate::TokenAmount) has different decimals than the token it's used with.
+    #[error("token `{token_id}` has {expected} decimals, but the amount has {actual}")]
+    TokenDecimalsMismatch {
+        /// The token the amount was used with.
+        token_id: TokenId,
+
+        /// The decimals of the token.
+        expected: u32,
+
+        /// The decimals of the amount.
+        actual: u32,
+    },
+
+    /// Minting would take the total supply of a token past its maximum supply.
+    #[error("minting {amount} of token `{token_id}` would exceed its max supply of {max_supply} (total supply is {total_supply})")]
+    TokenMaxSupplyExceeded {
+        /// The token being minted.
+        token_id: TokenId,
+
+        /// The amount being minted.
+        amount: u64,
+
+        /// The total supply of the token.
+        total_supply: u64,
+
+        /// The maximum supply of the token.
+        max_supply: u64,
+    },
+
+    /// Burning would take the total supply of a token below zero.
+    #[error(
+        "burning {amount} of token `{token_id}` would exceed its total supply of {total_supply}"
+    )]
+    TokenSupplyExceeded {
+        /// The token being burned.
+        token_id: TokenId,
+
+        /// The amount being burned.
+        amount: u64,
+
+        /// The total supply of the token.
+        total_supply: u64,
+    },
+
+    /// A request to the mirror node REST API failed.
+    #[error("mirror node REST request failed: {0}")]
+    MirrorRest(#[source] BoxStdError),
+
+    /// A request to a block node failed, IE, it doesn't have the requested block.
+    #[cfg(feature = "unstable")]
+    #[error("block node request failed: {0}")]
+    BlockNode(#[source] BoxStdError),
+
+    /// A request to a Ledger device failed, IE, the device isn't connected, or the Hedera app isn't open.
+    #[cfg(feature = "ledger")]
+    #[error("Ledger request failed: {0}")]
+    Ledger(#[source] BoxStdError),
+
+    /// The user rejected a request on their Ledger device, IE, declined to sign a transaction.
+    #[cfg(feature = "ledger")]
+    #[error("request rejected on the Ledger device")]
+    LedgerRejected,
+
+    /// A request to a wallet over WalletConnect failed, IE, the user rejected it, or the wallet's response was invalid.
+    #[cfg(feature = "walletconnect")]
+    #[error("WalletConnect request failed: {0}")]
+    WalletConnect(#[source] BoxStdError),
+
+    /// Compressing or decompressing data failed, IE, an [HCS-1](crate::hcs1) file's payload isn't valid zstd.
+    #[cfg(feature = "hcs1")]
+    #[error("failed to compress or decompress data: {0}")]
+    Compression(#[source] BoxStdError),
+
+    /// A [`StateProof`](crate::StateProof) didn't prove a record or receipt,
+    /// IE, not enough nodes signed the record file, or the record file doesn't contain it.
+    #[error("state proof verification failed: {0}")]
+    StateProof(#[source] BoxStdError),
+
+    /// A [`BalanceWatchdog`](crate::BalanceWatchdog) with [`fail_fast`](crate::BalanceWatchdog::fail_fast) set
+    /// found the balance of the account paying for the transaction below its threshold.
+    #[error("balance of `{account_id}` is {balance}, below the watchdog threshold of {threshold}")]
+    OperatorBalanceLow {
+        /// The account being watched.
+        account_id: AccountId,
+
+        /// The balance of the account, as of the watchdog's most recent check.
+        balance: Hbar,
+
+        /// The threshold the balance fell below.
+        threshold: Hbar,
+    },
+
+    /// A transaction was frozen with a [valid duration](crate::Transaction::transaction_valid_duration)
+    /// that isn't more than 0 seconds and at most 180 seconds.
+    #[error("transaction valid duration of {0} is out of range, it must be more than 0s and at most 180s")]
+    TransactionValidDurationOutOfRange(time::Duration),
+
+    /// A [`TransactionJournal`](crate::TransactionJournal) failed to read or write an entry.
+    #[error("transaction journal failed: {0}")]
+    TransactionJournal(#[source] BoxStdError),
+
+    /// A transaction with more than one chunk was given to [`execute_once`](crate::Transaction::execute_once),
+    /// which only journals a single transaction ID.
+    #[error("transaction has {0} chunks, only a transaction with a single chunk can be journaled")]
+    TransactionJournalChunked(usize),
+
+    /// A transaction was frozen with a client that has since [switched environments](crate::Client::switch_environment),
+    /// so its nodes and transaction ID belong to the old environment.
+    #[error("transaction was frozen before the client switched environments")]
+    EnvironmentSwitched,
+
+    /// A transaction recorded in a [`TransactionJournal`](crate::TransactionJournal) expired
+    /// before it could be confirmed whether it reached consensus.
+    #[error("outcome of transaction `{0}` is unknown, it expired before it could be confirmed")]
+    TransactionOutcomeUnknown(Box<TransactionId>),
+
+    /// A [`TransactionRecord`](crate::TransactionRecord) couldn't be reversed into a refund.
+    #[error("failed to build refund: {0}")]
+    Refund(#[source] BoxStdError),
+
+    /// A transfer sends a token to an account that isn't associated with it.
+    #[error("account `{account_id}` isn't associated with token `{token_id}`")]
+    TokenNotAssociated {
+        /// The account that would receive the token.
+        account_id: Box<AccountId>,
+
+        /// The token.
+        token_id: Box<TokenId>,
+    },
+
+    /// A chunk of a chunked transaction failed after some chunks were already submitted.
+    ///
+    /// [`ChunkedTransactionResponse::resume`] on `response` submits the chunks that weren't accepted.
+    ///
+    /// Every error after the first chunk is submitted is wrapped in this, even ones that would otherwise be returned
+    /// as another variant (IE, [`ReceiptStatus`](Self::ReceiptStatus) while waiting for the receipt of a chunk),
+    /// match on `source` for those.
+    #[error(
+        "chunked transaction was only partly submitted ({} of {} chunks): {source}",
+        .response.len(),
+        .response.get_total_chunks()
+    )]
+    ChunkedTransactionIncomplete {
+        /// The responses of the chunks that were submitted.
+        response: Box<ChunkedTransactionResponse>,
+
+        /// The error the failing chunk failed with.
+        #[source]
+        source: Box<Error>,
+    },
+
+    /// [`ChunkedTransactionResponse::resume`] can't continue the submission, it has to be started over.
+    #[error("failed to resume chunked transaction: {0}")]
+    ChunkedTransactionResume(#[source] BoxStdError),
+
+    /// The nodes a [fanned out](crate::Query::fan_out) query was sent to responded differently,
+    /// and no response was returned by a majority of them.
+    #[error(
+        "fanned out query got {distinct_responses} different responses from {responses} nodes, none from a quorum of {quorum}"
+    )]
+    FanOutNoQuorum {
+        /// The number of nodes that responded successfully.
+        responses: usize,
+
+        /// The number of different responses among them.
+        distinct_responses: usize,
+
+        /// The number of nodes that had to agree on a response.
+        quorum: usize,
+    },
+
+    /// A time or duration can't be converted to another representation without losing precision,
+    /// or is out of range for it.
+    ///
+    /// See [`protobuf::time`](crate::protobuf::time).
+    #[error("failed to convert a time: {0}")]
+    TimeConversion(#[source] BoxStdError),
 }
 
 impl Error {
     pub(crate) fn from_protobuf<E: Into<BoxStdError>>(error: E) -> Self {
-        Self::FromProtobuf(error.into())
+        Self::FromProtobuf(Box::new(FromProtobufError::new(error)))
     }
 
     pub(crate) fn key_parse<E: Into<BoxStdError>>(error: E) -> Self {
@@ -210,6 +430,88 @@ impl Error {
     pub(crate) fn signature_verify(error: impl Into<BoxStdError>) -> Self {
         Self::SignatureVerify(error.into())
     }
+
+    #[cfg(feature = "mirror-rest")]
+    pub(crate) fn mirror_rest(error: impl Into<BoxStdError>) -> Self {
+        Self::MirrorRest(error.into())
+    }
+
+    #[cfg(feature = "unstable")]
+    pub(crate) fn block_node(error: impl Into<BoxStdError>) -> Self {
+        Self::BlockNode(error.into())
+    }
+
+    #[cfg(feature = "ledger")]
+    pub(crate) fn ledger(error: impl Into<BoxStdError>) -> Self {
+        Self::Ledger(error.into())
+    }
+
+    #[cfg(feature = "walletconnect")]
+    pub(crate) fn wallet_connect(error: impl Into<BoxStdError>) -> Self {
+        Self::WalletConnect(error.into())
+    }
+
+    #[cfg(feature = "hcs1")]
+    pub(crate) fn compression(error: impl Into<BoxStdError>) -> Self {
+        Self::Compression(error.into())
+    }
+
+    pub(crate) fn state_proof(error: impl Into<BoxStdError>) -> Self {
+        Self::StateProof(error.into())
+    }
+
+    pub(crate) fn transaction_journal(error: impl Into<BoxStdError>) -> Self {
+        Self::TransactionJournal(error.into())
+    }
+
+    pub(crate) fn refund(error: impl Into<BoxStdError>) -> Self {
+        Self::Refund(error.into())
+    }
+
+    pub(crate) fn chunked_transaction_resume(error: impl Into<BoxStdError>) -> Self {
+        Self::ChunkedTransactionResume(error.into())
+    }
+
+    pub(crate) fn time_conversion(error: impl Into<BoxStdError>) -> Self {
+        Self::TimeConversion(error.into())
+    }
+}
+
+/// A request to a consensus node failed in transport.
+///
+/// Returned in [`Error::NodeError`].
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "node `{node_account_id}` ({}) failed on attempt {attempt}, {elapsed:?} into the request: {status}",
+    endpoints.join(", ")
+)]
+#[non_exhaustive]
+pub struct NodeError {
+    /// The account ID of the node.
+    pub node_account_id: AccountId,
+
+    /// The `host:port` endpoints of the node (requests to a node are balanced between them).
+    pub endpoints: Vec<String>,
+
+    /// The attempt of the request that failed, starting at `1`.
+    ///
+    /// Every node tried before backing off is part of the same attempt.
+    pub attempt: usize,
+
+    /// The time between the start of the request and the failure, including earlier attempts.
+    pub elapsed: Duration,
+
+    /// The gRPC status the request failed with.
+    #[source]
+    pub status: tonic::Status,
+}
+
+impl NodeError {
+    /// Returns `true` if the node couldn't be reached, or didn't respond in time.
+    #[must_use]
+    pub fn is_unreachable(&self) -> bool {
+        matches!(self.status.code(), tonic::Code::Unavailable | tonic::Code::DeadlineExceeded)
+    }
 }
 
 /// Failed to parse a mnemonic.
@@ -263,3 +565,27 @@ pub enum MnemonicEntropyError {
     #[error("used a passphrase with a legacy mnemonic")]
     LegacyWithPassphrase,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::NodeError;
+    use crate::AccountId;
+
+    #[test]
+    fn node_error_display() {
+        let error = NodeError {
+            node_account_id: AccountId::new(0, 0, 7),
+            endpoints: Vec::from(["4.testnet.hedera.com:50211".to_owned()]),
+            attempt: 2,
+            elapsed: Duration::from_millis(1500),
+            status: tonic::Status::unavailable("connection refused"),
+        };
+
+        assert!(error.is_unreachable());
+        assert!(error.to_string().starts_with(
+            "node `0.0.7` (4.testnet.hedera.com:50211) failed on attempt 2, 1.5s into the request: "
+        ));
+    }
+}
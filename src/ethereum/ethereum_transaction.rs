@@ -11,7 +11,9 @@ use crate::transaction::{
     ChunkInfo,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -91,7 +93,21 @@ impl EthereumTransaction {
     }
 }
 
-impl TransactionData for EthereumTransactionData {}
+impl TransactionData for EthereumTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        if self.ethereum_data.is_empty() {
+            problems.push(ValidationProblem::MissingField { field: "ethereum_data" });
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.optional_entity("call_data_file", self.call_data_file_id.as_ref());
+
+        if self.max_gas_allowance_hbar != Hbar::ZERO {
+            description.field("max_gas_allowance", self.max_gas_allowance_hbar);
+        }
+    }
+}
 
 impl TransactionExecute for EthereumTransactionData {
     fn execute(
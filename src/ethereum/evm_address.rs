@@ -11,7 +11,7 @@ use crate::{
 };
 
 /// An address as implemented in the Ethereum Virtual Machine.
-#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct EvmAddress(pub(crate) [u8; 20]);
 
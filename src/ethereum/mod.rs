@@ -1,17 +1,24 @@
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "contracts")]
 mod ethereum_data;
+#[cfg(feature = "contracts")]
 mod ethereum_flow;
+#[cfg(feature = "contracts")]
 mod ethereum_transaction;
 mod evm_address;
 
+#[cfg(feature = "contracts")]
 pub use ethereum_data::{
     Eip1559EthereumData,
     EthereumData,
     LegacyEthereumData,
 };
+#[cfg(feature = "contracts")]
 pub use ethereum_flow::EthereumFlow;
+#[cfg(feature = "contracts")]
 pub use ethereum_transaction::EthereumTransaction;
+#[cfg(feature = "contracts")]
 pub(crate) use ethereum_transaction::EthereumTransactionData;
 pub use evm_address::EvmAddress;
 pub(crate) use evm_address::SolidityAddress;
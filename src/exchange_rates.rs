@@ -8,6 +8,8 @@ use crate::ToProtobuf;
 
 /// The current and next exchange rates between [`Hbar`](crate::HbarUnit::Hbar) and USD-cents.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ExchangeRates {
     /// The current exchange rate between [`Hbar`](crate::HbarUnit::Hbar) and USD-cents.
     pub current_rate: ExchangeRate,
@@ -48,6 +50,8 @@ impl ToProtobuf for ExchangeRates {
 
 /// Denotes a conversion between Hbars and cents (USD).
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ExchangeRate {
     /// Denotes [`Hbar`](crate::HbarUnit::Hbar) equivalent to cents (USD).
     pub hbars: u32,
@@ -56,6 +60,7 @@ pub struct ExchangeRate {
     pub cents: u32,
 
     /// Expiration time of this exchange rate.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::timestamp"))]
     pub expiration_time: OffsetDateTime,
 
     /// Exchange rate in cents
@@ -81,7 +86,7 @@ impl FromProtobuf<services::ExchangeRate> for ExchangeRate {
             hbars,
             cents,
             exchange_rate_in_cents,
-            expiration_time: pb_getf!(pb, expiration_time)?.into(),
+            expiration_time: FromProtobuf::from_protobuf(pb_getf!(pb, expiration_time)?)?,
         })
     }
 }
@@ -3,12 +3,18 @@ mod error;
 
 use std::any::type_name;
 use std::borrow::Cow;
+use std::future::Future;
 use std::ops::ControlFlow;
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
 use std::time::{
     Duration,
     Instant,
 };
 
+use backoff::backoff::Backoff;
 use backoff::{
     ExponentialBackoff,
     ExponentialBackoffBuilder,
@@ -18,13 +24,23 @@ use futures_util::StreamExt;
 use prost::Message;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
+use tokio_util::sync::CancellationToken;
 use tonic::metadata::AsciiMetadataValue;
 use tonic::transport::Channel;
 use tonic::Request;
 use triomphe::Arc;
 
-use crate::client::NetworkData;
+use crate::client::{
+    InFlightRequest,
+    NetworkData,
+    SharedClock,
+};
+pub(crate) use crate::execute::error::is_node_failure;
 use crate::execute::error::is_tonic_status_transient;
+use crate::logger::{
+    client_log,
+    ClientLogger,
+};
 use crate::ping_query::PingQuery;
 use crate::{
     client,
@@ -33,6 +49,10 @@ use crate::{
     BoxGrpcFuture,
     Client,
     Error,
+    ExecutionPlan,
+    LogLevel,
+    LogSubsystem,
+    NodeError,
     Status,
     TransactionId,
     ValidateChecksums,
@@ -71,6 +91,13 @@ pub(crate) trait Execute: ValidateChecksums {
         None
     }
 
+    /// Returns the environment of the client this request was frozen with, if any.
+    ///
+    /// See [`Client::switch_environment`].
+    fn frozen_environment(&self) -> Option<u64> {
+        None
+    }
+
     /// Check whether to retry an pre-check status.
     fn should_retry_pre_check(&self, _status: Status) -> bool {
         false
@@ -127,6 +154,13 @@ pub(crate) trait Execute: ValidateChecksums {
     fn response_pre_check_status(response: &Self::GrpcResponse) -> crate::Result<i32>;
 }
 
+/// The attempt a request to a node is part of, for [`NodeError`]s.
+#[derive(Clone, Copy)]
+struct NodeAttempt {
+    attempt: usize,
+    request_start: Instant,
+}
+
 struct ExecuteContext {
     // When `Some` the `transaction_id` will be regenerated when expired.
     operator_account_id: Option<AccountId>,
@@ -135,6 +169,15 @@ struct ExecuteContext {
     max_attempts: usize,
     // timeout for a single grpc request.
     grpc_timeout: Option<Duration>,
+    logger: Arc<ClientLogger>,
+    clock: SharedClock,
+}
+
+impl ExecuteContext {
+    /// Generates a transaction ID for the operator, at the current time of the client's clock.
+    fn generate_transaction_id(&self) -> Option<TransactionId> {
+        Some(TransactionId::generate_at(self.operator_account_id?, self.clock.now()))
+    }
 }
 
 pub(crate) async fn execute<E>(
@@ -145,6 +188,28 @@ pub(crate) async fn execute<E>(
 where
     E: Execute + Sync,
 {
+    let in_flight = client.in_flight_request().await;
+
+    execute_in_flight(client, &in_flight, executable, timeout).await
+}
+
+/// Like [`execute`], but as part of a request that's already `in_flight`,
+/// IE, a chunk of a chunked transaction, so that every part of it goes to the same environment.
+pub(crate) async fn execute_in_flight<E>(
+    client: &Client,
+    _in_flight: &InFlightRequest<'_>,
+    executable: &E,
+    timeout: Option<Duration>,
+) -> crate::Result<E::Response>
+where
+    E: Execute + Sync,
+{
+    // the environment can't be switched while `_in_flight` is alive,
+    // so everything read from the client here belongs to the same environment.
+    if executable.frozen_environment().is_some_and(|it| it != client.environment()) {
+        return Err(Error::EnvironmentSwitched);
+    }
+
     if client.auto_validate_checksums() {
         let ledger_id = client.ledger_id_internal();
         let ledger_id = ledger_id
@@ -180,17 +245,76 @@ where
         backoff_builder.with_max_elapsed_time(Some(timeout));
     }
 
-    execute_inner(
-        &ExecuteContext {
-            max_attempts: backoff.max_attempts,
-            backoff_config: backoff_builder.build(),
-            operator_account_id,
-            network: client.net().0.load_full(),
-            grpc_timeout: backoff.grpc_timeout,
-        },
-        executable,
-    )
-    .await
+    let ctx = ExecuteContext {
+        max_attempts: backoff.max_attempts,
+        backoff_config: backoff_builder.build(),
+        operator_account_id,
+        network: client.net().0.load_full(),
+        grpc_timeout: backoff.grpc_timeout,
+        logger: Arc::clone(client.logger()),
+        clock: client.clock(),
+    };
+
+    execute_inner(&ctx, executable).await
+}
+
+/// Returns how a request for `node_account_ids` (or any nodes, if `None`) would be executed against `client` right now.
+///
+/// # Errors
+/// - [`Error::NodeAccountUnknown`] if any of `node_account_ids` isn't in the client's network.
+pub(crate) fn execution_plan(
+    client: &Client,
+    node_account_ids: Option<&[AccountId]>,
+    timeout: Option<Duration>,
+) -> crate::Result<ExecutionPlan> {
+    let network = client.net().0.load_full();
+
+    let node_account_ids = match node_account_ids {
+        Some(ids) => ids.to_vec(),
+        None => network.random_node_ids(client.logger()),
+    };
+
+    let mut node_indexes = network.node_indexes_for_ids(&node_account_ids)?;
+
+    // unhealthy nodes are skipped, unless every node is unhealthy.
+    if !node_indexes.is_empty() {
+        node_indexes = random_node_indexes(&network, Some(&node_indexes)).unwrap_or_default();
+    }
+
+    let backoff = client.backoff();
+
+    // the same backoff `execute` uses, without randomization or a timeout.
+    let mut backoff_config = ExponentialBackoffBuilder::new()
+        .with_initial_interval(backoff.initial_backoff)
+        .with_max_interval(backoff.max_backoff)
+        .with_randomization_factor(0.0)
+        .with_max_elapsed_time(None)
+        .build();
+
+    let backoff_schedule = std::iter::from_fn(|| backoff_config.next_backoff())
+        .take(backoff.max_attempts.saturating_sub(1))
+        .collect();
+
+    Ok(ExecutionPlan {
+        node_account_ids: node_indexes.into_iter().map(|it| network.node_ids()[it]).collect(),
+        max_attempts: backoff.max_attempts,
+        grpc_deadline: backoff.grpc_timeout,
+        // note: `execute` leaves the backoff's default max elapsed time in place when there's no timeout.
+        request_timeout: timeout
+            .or(backoff.request_timeout)
+            .unwrap_or(Duration::from_millis(backoff::default::MAX_ELAPSED_TIME_MILLIS)),
+        backoff_schedule,
+    })
+}
+
+/// Runs `fut` until it completes, or until `cancellation` is cancelled.
+///
+/// On cancellation `fut` is dropped, which aborts any in-flight gRPC request and stops any pending backoff.
+pub(crate) async fn cancellable<T>(
+    cancellation: &CancellationToken,
+    fut: impl Future<Output = crate::Result<T>>,
+) -> crate::Result<T> {
+    cancellation.run_until_cancelled(fut).await.unwrap_or(Err(Error::Cancelled))
 }
 
 async fn execute_inner<E>(ctx: &ExecuteContext, executable: &E) -> crate::Result<E::Response>
@@ -205,6 +329,8 @@ where
                 backoff_config: ctx.backoff_config.clone(),
                 max_attempts: ctx.max_attempts,
                 grpc_timeout: ctx.grpc_timeout,
+                logger: Arc::clone(&ctx.logger),
+                clock: ctx.clock.clone(),
             };
             let ping_query = PingQuery::new(ctx.network.node_ids()[index]);
 
@@ -223,7 +349,7 @@ where
     let mut transaction_id = executable
         .requires_transaction_id()
         .then_some(explicit_transaction_id)
-        .and_then(|it| it.or_else(|| ctx.operator_account_id.map(TransactionId::generate)));
+        .and_then(|it| it.or_else(|| ctx.generate_transaction_id()));
 
     // if we were explicitly given a list of nodes to use, we iterate through each
     // of the given nodes (in a random order)
@@ -234,10 +360,19 @@ where
 
     let explicit_node_indexes = explicit_node_indexes.as_deref();
 
+    let request_start = Instant::now();
+    let attempts = AtomicUsize::new(0);
+    let attempts = &attempts;
+
     let layer = move || async move {
         loop {
             let mut last_error: Option<Error> = None;
 
+            let attempt = NodeAttempt {
+                attempt: attempts.fetch_add(1, Ordering::Relaxed) + 1,
+                request_start,
+            };
+
             let random_node_indexes = random_node_indexes(&ctx.network, explicit_node_indexes)
                 .ok_or(retry::Error::EmptyTransient)?;
 
@@ -258,29 +393,38 @@ where
             let mut random_node_indexes = std::pin::pin!(random_node_indexes);
 
             while let Some(node_index) = random_node_indexes.next().await {
-                let tmp = execute_single(ctx, executable, node_index, &mut transaction_id).await;
-
-                log::log!(
-                    match &tmp {
-                        Ok(ControlFlow::Break(_)) => log::Level::Debug,
-                        Ok(ControlFlow::Continue(_)) => log::Level::Warn,
-                        Err(e) =>
-                            if e.is_transient() {
-                                log::Level::Warn
-                            } else {
-                                log::Level::Error
+                let tmp =
+                    execute_single(ctx, executable, node_index, attempt, &mut transaction_id).await;
+
+                let level = match &tmp {
+                    Ok(ControlFlow::Break(_)) => LogLevel::Debug,
+                    Ok(ControlFlow::Continue(_)) => LogLevel::Warn,
+                    Err(e) => {
+                        if e.is_transient() {
+                            LogLevel::Warn
+                        } else {
+                            LogLevel::Error
+                        }
+                    }
+                };
+
+                if ctx.logger.enabled(LogSubsystem::Execution, level) {
+                    ctx.logger.log(
+                        LogSubsystem::Execution,
+                        level,
+                        format_args!(
+                            "Execution of {} on node at index {node_index} / node id {} {}",
+                            type_name::<E>(),
+                            ctx.network.node_ids()[node_index],
+                            match &tmp {
+                                Ok(ControlFlow::Break(_)) => Cow::Borrowed("succeeded"),
+                                Ok(ControlFlow::Continue(err)) =>
+                                    format!("will continue due to {err:?}").into(),
+                                Err(err) => format!("failed due to {err:?}").into(),
                             },
-                    },
-                    "Execution of {} on node at index {node_index} / node id {} {}",
-                    type_name::<E>(),
-                    ctx.network.channel(node_index).0,
-                    match &tmp {
-                        Ok(ControlFlow::Break(_)) => Cow::Borrowed("succeeded"),
-                        Ok(ControlFlow::Continue(err)) =>
-                            format!("will continue due to {err:?}").into(),
-                        Err(err) => format!("failed due to {err:?}").into(),
-                    },
-                );
+                        ),
+                    );
+                }
 
                 match tmp? {
                     ControlFlow::Continue(err) => last_error = Some(err),
@@ -299,13 +443,44 @@ where
     // the outer loop continues until we timeout or reach the maximum number of "attempts"
     // an attempt is counted when we have a successful response from a node that must either
     // be retried immediately (on a new node) or retried after a backoff.
-    crate::retry(backoff, Some(ctx.max_attempts), layer).await
+    crate::retry(backoff, Some(ctx.max_attempts), &ctx.logger, &*ctx.clock, layer).await
+}
+
+fn mark_node_unhealthy(ctx: &ExecuteContext, node_index: usize) {
+    if ctx.network.mark_node_unhealthy(node_index) {
+        client_log!(
+            ctx.logger,
+            Network,
+            Debug,
+            "Node {} has reached the max amount of retries, removing from network",
+            ctx.network.node_ids()[node_index]
+        );
+    }
+}
+
+/// Wraps `status` in a [`NodeError`], with the context of the node at `node_index`.
+///
+/// Only for statuses where the node failed, see [`is_node_failure`].
+fn node_error(
+    status: tonic::Status,
+    ctx: &ExecuteContext,
+    node_index: usize,
+    attempt: NodeAttempt,
+) -> Error {
+    Error::NodeError(Box::new(NodeError {
+        node_account_id: ctx.network.node_ids()[node_index],
+        endpoints: ctx.network.node_endpoints(node_index),
+        attempt: attempt.attempt,
+        elapsed: attempt.request_start.elapsed(),
+        status,
+    }))
 }
 
 fn map_tonic_error(
     status: tonic::Status,
-    network: &client::NetworkData,
+    ctx: &ExecuteContext,
     node_index: usize,
+    attempt: NodeAttempt,
     request_free: bool,
 ) -> retry::Error {
     const MIME_HTML: &[u8] = b"text/html";
@@ -314,10 +489,10 @@ fn map_tonic_error(
         // if the node says it isn't available, then we should just try again with a different node.
         tonic::Code::Unavailable | tonic::Code::ResourceExhausted => {
             // NOTE: this is an "unhealthy" node
-            network.mark_node_unhealthy(node_index);
+            mark_node_unhealthy(ctx, node_index);
 
             // try the next node in our allowed list, immediately
-            retry::Error::Transient(status.into())
+            retry::Error::Transient(node_error(status, ctx, node_index, attempt))
         }
 
         // todo: find a way to make this less fragile
@@ -329,24 +504,29 @@ fn map_tonic_error(
             if status.metadata().get("content-type").map(AsciiMetadataValue::as_bytes)
                 == Some(MIME_HTML) =>
         {
-            network.mark_node_unhealthy(node_index);
+            mark_node_unhealthy(ctx, node_index);
 
             // hack to the hack:
             // if this is a free request let's try retrying it anyway...
             match request_free {
-                true => retry::Error::Transient(status.into()),
-                false => retry::Error::Permanent(status.into()),
+                true => retry::Error::Transient(node_error(status, ctx, node_index, attempt)),
+                false => retry::Error::Permanent(node_error(status, ctx, node_index, attempt)),
             }
         }
 
         _ if is_tonic_status_transient(&status) => {
-            network.mark_node_unhealthy(node_index);
+            mark_node_unhealthy(ctx, node_index);
 
-            retry::Error::Transient(status.into())
+            retry::Error::Transient(node_error(status, ctx, node_index, attempt))
         }
 
         // fail immediately
-        _ => retry::Error::Permanent(status.into()),
+        _ if is_node_failure(&status) => {
+            retry::Error::Permanent(node_error(status, ctx, node_index, attempt))
+        }
+
+        // the node rejected the request, so which node it was doesn't matter.
+        _ => retry::Error::Permanent(Error::GrpcStatus(status)),
     }
 }
 
@@ -354,21 +534,40 @@ async fn execute_single<E: Execute + Sync>(
     ctx: &ExecuteContext,
     executable: &E,
     node_index: usize,
+    attempt: NodeAttempt,
     transaction_id: &mut Option<TransactionId>,
 ) -> retry::Result<ControlFlow<E::Response, Error>> {
     let (node_account_id, channel) = ctx.network.channel(node_index);
 
-    log::debug!(
+    client_log!(
+        ctx.logger,
+        Execution,
+        Debug,
         "Preparing {} on node at index {node_index} / node id {node_account_id}",
         type_name::<E>()
     );
 
-    let (request, context) = executable
-        .make_request(transaction_id.as_ref(), node_account_id)
-        // Does not represent a network error or error returned by a node
-        .map_err(retry::Error::Permanent)?;
+    let (request, context) =
+        executable.make_request(transaction_id.as_ref(), node_account_id).map_err(|e| {
+            // besides a missing transaction ID, the only way to fail here is a signer failing.
+            if !matches!(e, Error::NoPayerAccountOrTransactionId) {
+                client_log!(
+                    ctx.logger,
+                    Crypto,
+                    Error,
+                    "Failed to sign {} for node id {node_account_id}: {e}",
+                    type_name::<E>()
+                );
+            }
 
-    log::debug!(
+            // Does not represent a network error or error returned by a node
+            retry::Error::Permanent(e)
+        })?;
+
+    client_log!(
+        ctx.logger,
+        Execution,
+        Debug,
         "Executing {} on node at index {node_index} / node id {node_account_id}",
         type_name::<E>()
     );
@@ -378,20 +577,31 @@ async fn execute_single<E: Execute + Sync>(
 
     let fut = executable.execute(channel, req.into_inner());
 
+    let start = Instant::now();
+
     let response = match ctx.grpc_timeout {
         Some(it) => match tokio::time::timeout(it, fut).await {
             Ok(it) => it,
             Err(_) => {
-                return Ok(ControlFlow::Continue(crate::Error::GrpcStatus(
+                ctx.network.stats().record_transport_error(node_account_id);
+
+                return Ok(ControlFlow::Continue(node_error(
                     tonic::Status::deadline_exceeded("explicitly given grpc timeout was exceeded"),
-                )))
+                    ctx,
+                    node_index,
+                    attempt,
+                )));
             }
         },
         None => fut.await,
     };
 
+    let latency = start.elapsed();
+
     let response = response.map(tonic::Response::into_inner).map_err(|status| {
-        map_tonic_error(status, &ctx.network, node_index, transaction_id.is_none())
+        ctx.network.stats().record_grpc_error(node_account_id, latency, &status);
+
+        map_tonic_error(status, ctx, node_index, attempt, transaction_id.is_none())
     });
 
     let response = match response {
@@ -406,12 +616,14 @@ async fn execute_single<E: Execute + Sync>(
     // at this point, any failure isn't from the node, it's from the request.
     ctx.network.mark_node_healthy(node_index);
 
-    let status = E::response_pre_check_status(&response)
-        .and_then(|status| {
-            // not sure how to proceed, fail immediately
-            Status::try_from(status).or_else(|_| Err(Error::ResponseStatusUnrecognized(status)))
-        })
-        .map_err(retry::Error::Permanent)?;
+    let status = E::response_pre_check_status(&response).and_then(|status| {
+        // not sure how to proceed, fail immediately
+        Status::try_from(status).or_else(|_| Err(Error::ResponseStatusUnrecognized(status)))
+    });
+
+    ctx.network.stats().record_response(node_account_id, latency, status.as_ref().ok().copied());
+
+    let status = status.map_err(retry::Error::Permanent)?;
 
     match status {
         Status::Ok if executable.should_retry(&response) => Err(retry::Error::Transient(
@@ -438,9 +650,7 @@ async fn execute_single<E: Execute + Sync>(
             // the transaction that was generated has since expired
             // re-generate the transaction ID and try again, immediately
 
-            let new = TransactionId::generate(ctx.operator_account_id.unwrap());
-
-            *transaction_id = Some(new);
+            *transaction_id = ctx.generate_transaction_id();
 
             Ok(ControlFlow::Continue(executable.make_error_pre_check(
                 status,
@@ -504,6 +714,9 @@ fn random_node_indexes(
 
         let (shuffled, _) = indexes.partial_shuffle(&mut rng, amount);
 
+        // try nodes that have recently been busy or unreachable last, the sort is stable so the rest stay shuffled.
+        shuffled.sort_by_key(|index| network.is_node_congested(*index));
+
         Some(shuffled.to_vec())
     }
 }
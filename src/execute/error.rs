@@ -56,6 +56,15 @@ pub(super) fn is_tonic_status_transient(status: &tonic::Status) -> bool {
     }
 }
 
+/// Returns `true` if `status` means the node failed (it was unreachable, overloaded, or didn't respond in time),
+/// rather than that the node rejected the request.
+pub(crate) fn is_node_failure(status: &tonic::Status) -> bool {
+    matches!(
+        status.code(),
+        tonic::Code::Unavailable | tonic::Code::ResourceExhausted | tonic::Code::DeadlineExceeded
+    ) || is_tonic_status_transient(status)
+}
+
 /// Tests some non-detection scenarios.
 ///
 /// Because hyper does not expose constructors for its error variants, there is no
@@ -80,3 +89,24 @@ mod test_is_tonic_status_transient {
         assert!(!is_tonic_status_transient(&input));
     }
 }
+
+#[cfg(test)]
+mod test_is_node_failure {
+    use tonic::Code;
+
+    use super::is_node_failure;
+
+    #[test]
+    fn transport_failures() {
+        for code in [Code::Unavailable, Code::ResourceExhausted, Code::DeadlineExceeded] {
+            assert!(is_node_failure(&tonic::Status::new(code, "foo")), "{code:?}");
+        }
+    }
+
+    #[test]
+    fn ignores_rejections() {
+        for code in [Code::InvalidArgument, Code::Unauthenticated, Code::Unimplemented] {
+            assert!(!is_node_failure(&tonic::Status::new(code, "foo")), "{code:?}");
+        }
+    }
+}
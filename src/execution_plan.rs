@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use crate::AccountId;
+
+/// How a request would be executed, IE, which nodes it's sent to, and how failures are retried.
+///
+/// Returned by [`Transaction::execution_plan`](crate::Transaction::execution_plan),
+/// to inspect (and tune) the routing of a client before it's used for real workloads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ExecutionPlan {
+    /// The nodes the request is sent to, in the order they're tried within an attempt.
+    ///
+    /// Unless nodes are set explicitly, they're sampled at random from the healthy nodes of the network
+    /// for every execution, so this is one possible order.
+    pub node_account_ids: Vec<AccountId>,
+
+    /// The maximum number of attempts, where an attempt ends when every node has failed with a retryable error.
+    pub max_attempts: usize,
+
+    /// The timeout of each individual gRPC request, if any.
+    pub grpc_deadline: Option<Duration>,
+
+    /// The timeout of the whole execution, including backoff.
+    ///
+    /// Execution stops when this is reached, even if attempts remain.
+    /// Without a timeout set on the client, this is the backoff's default of 15 minutes.
+    pub request_timeout: Duration,
+
+    /// The delay before each retry, IE, the first element is the delay between the first and second attempts.
+    ///
+    /// The delays are nominal: the actual delays are randomized around them.
+    pub backoff_schedule: Vec<Duration>,
+}
@@ -0,0 +1,382 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Monitor entities for expiry.
+//!
+//! Accounts, contracts, files, and topics expire unless they're renewed,
+//! either automatically (with an auto renew account or period) or with an update transaction.
+//! [`ExpiryMonitor`] finds the entities of a set that expire within a window,
+//! and [`ExpiringEntity::extend_transaction`] builds the transaction that extends one.
+//!
+//! ```no_run
+//! use hedera::expiry::ExpiryMonitor;
+//! # async fn f(client: &hedera::Client, account_id: hedera::AccountId) -> hedera::Result<()> {
+//! let expiring = ExpiryMonitor::new()
+//!     .add_entity_id(account_id)
+//!     .window(time::Duration::days(7))
+//!     .check(client)
+//!     .await?;
+//!
+//! for entity in &expiring {
+//!     println!("{} expires at {}", entity.entity_id, entity.expiration_time);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+
+use crate::{
+    AccountId,
+    AccountInfoQuery,
+    AccountUpdateTransaction,
+    AnyTransaction,
+    Client,
+};
+#[cfg(feature = "contracts")]
+use crate::{
+    ContractId,
+    ContractInfoQuery,
+    ContractUpdateTransaction,
+};
+#[cfg(feature = "files")]
+use crate::{
+    FileId,
+    FileInfoQuery,
+    FileUpdateTransaction,
+};
+#[cfg(feature = "topics")]
+use crate::{
+    TopicId,
+    TopicInfoQuery,
+    TopicUpdateTransaction,
+};
+
+/// The ID of an entity that can expire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExpiringEntityId {
+    /// An account.
+    Account(AccountId),
+
+    /// A contract.
+    #[cfg(feature = "contracts")]
+    Contract(ContractId),
+
+    /// A file.
+    #[cfg(feature = "files")]
+    File(FileId),
+
+    /// A topic.
+    #[cfg(feature = "topics")]
+    Topic(TopicId),
+}
+
+impl fmt::Display for ExpiringEntityId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Account(it) => it.fmt(f),
+            #[cfg(feature = "contracts")]
+            Self::Contract(it) => it.fmt(f),
+            #[cfg(feature = "files")]
+            Self::File(it) => it.fmt(f),
+            #[cfg(feature = "topics")]
+            Self::Topic(it) => it.fmt(f),
+        }
+    }
+}
+
+impl From<AccountId> for ExpiringEntityId {
+    fn from(value: AccountId) -> Self {
+        Self::Account(value)
+    }
+}
+
+#[cfg(feature = "contracts")]
+impl From<ContractId> for ExpiringEntityId {
+    fn from(value: ContractId) -> Self {
+        Self::Contract(value)
+    }
+}
+
+#[cfg(feature = "files")]
+impl From<FileId> for ExpiringEntityId {
+    fn from(value: FileId) -> Self {
+        Self::File(value)
+    }
+}
+
+#[cfg(feature = "topics")]
+impl From<TopicId> for ExpiringEntityId {
+    fn from(value: TopicId) -> Self {
+        Self::Topic(value)
+    }
+}
+
+/// An entity, and when it expires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ExpiringEntity {
+    /// The ID of the entity.
+    pub entity_id: ExpiringEntityId,
+
+    /// The time at which the entity expires.
+    pub expiration_time: OffsetDateTime,
+
+    /// The period the entity is automatically renewed for when it expires, if it's renewed automatically.
+    ///
+    /// Files are never renewed automatically.
+    pub auto_renew_period: Option<Duration>,
+}
+
+impl ExpiringEntity {
+    /// Create a new expiring entity.
+    #[must_use]
+    pub fn new(
+        entity_id: impl Into<ExpiringEntityId>,
+        expiration_time: OffsetDateTime,
+        auto_renew_period: Option<Duration>,
+    ) -> Self {
+        Self { entity_id: entity_id.into(), expiration_time, auto_renew_period }
+    }
+
+    /// Returns true if the entity expires at or before `deadline`.
+    #[must_use]
+    pub fn expires_by(&self, deadline: OffsetDateTime) -> bool {
+        self.expiration_time <= deadline
+    }
+
+    /// Build the update transaction that moves the expiration time of this entity `extension` later.
+    ///
+    /// The transaction must still be signed by the key that's allowed to update the entity
+    /// (IE, the account key for accounts, and the admin key for everything else).
+    #[must_use]
+    pub fn extend_transaction(&self, extension: Duration) -> AnyTransaction {
+        let expiration_time = self.expiration_time + extension;
+
+        match self.entity_id {
+            ExpiringEntityId::Account(id) => {
+                let mut tx = AccountUpdateTransaction::new();
+                tx.account_id(id).expiration_time(expiration_time);
+                tx.into()
+            }
+            #[cfg(feature = "contracts")]
+            ExpiringEntityId::Contract(id) => {
+                let mut tx = ContractUpdateTransaction::new();
+                tx.contract_id(id).expiration_time(expiration_time);
+                tx.into()
+            }
+            #[cfg(feature = "files")]
+            ExpiringEntityId::File(id) => {
+                let mut tx = FileUpdateTransaction::new();
+                tx.file_id(id).expiration_time(expiration_time);
+                tx.into()
+            }
+            #[cfg(feature = "topics")]
+            ExpiringEntityId::Topic(id) => {
+                let mut tx = TopicUpdateTransaction::new();
+                tx.topic_id(id).expiration_time(expiration_time);
+                tx.into()
+            }
+        }
+    }
+}
+
+/// Finds the entities of a set that expire within a window of time.
+///
+/// Every check queries the info of every entity, which costs a query fee each.
+#[derive(Debug, Clone, Default)]
+pub struct ExpiryMonitor {
+    entity_ids: Vec<ExpiringEntityId>,
+    window: Duration,
+}
+
+impl ExpiryMonitor {
+    /// Create a new monitor, with no entities and an empty window.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the entities to monitor.
+    #[must_use]
+    pub fn get_entity_ids(&self) -> &[ExpiringEntityId] {
+        &self.entity_ids
+    }
+
+    /// Sets the entities to monitor.
+    pub fn entity_ids(
+        &mut self,
+        entity_ids: impl IntoIterator<Item = ExpiringEntityId>,
+    ) -> &mut Self {
+        self.entity_ids = entity_ids.into_iter().collect();
+        self
+    }
+
+    /// Adds an entity to monitor.
+    pub fn add_entity_id(&mut self, entity_id: impl Into<ExpiringEntityId>) -> &mut Self {
+        self.entity_ids.push(entity_id.into());
+        self
+    }
+
+    /// Returns how far ahead of now an entity has to expire to be reported.
+    #[must_use]
+    pub fn get_window(&self) -> Duration {
+        self.window
+    }
+
+    /// Sets how far ahead of now an entity has to expire to be reported.
+    pub fn window(&mut self, window: Duration) -> &mut Self {
+        self.window = window;
+        self
+    }
+
+    /// Returns the monitored entities that expire within the window (or have already expired),
+    /// earliest expiration first.
+    ///
+    /// The window starts at the current time of the client's [clock](Client::set_clock).
+    ///
+    /// Entities without an expiration time aren't reported.
+    ///
+    /// # Errors
+    /// - If any of the info queries fail (IE, if an entity doesn't exist or has been deleted).
+    pub async fn check(&self, client: &Client) -> crate::Result<Vec<ExpiringEntity>> {
+        let mut entities = Vec::with_capacity(self.entity_ids.len());
+
+        for &entity_id in &self.entity_ids {
+            entities.extend(fetch(entity_id, client).await?);
+        }
+
+        Ok(expiring_by(entities, client.clock().now() + self.window))
+    }
+}
+
+async fn fetch(
+    entity_id: ExpiringEntityId,
+    client: &Client,
+) -> crate::Result<Option<ExpiringEntity>> {
+    let (expiration_time, auto_renew_period) = match entity_id {
+        ExpiringEntityId::Account(id) => {
+            let info = AccountInfoQuery::new().account_id(id).execute(client).await?;
+            (info.expiration_time, info.auto_renew_period)
+        }
+        #[cfg(feature = "contracts")]
+        ExpiringEntityId::Contract(id) => {
+            let info = ContractInfoQuery::new().contract_id(id).execute(client).await?;
+            (info.expiration_time, info.auto_renew_period)
+        }
+        #[cfg(feature = "files")]
+        ExpiringEntityId::File(id) => {
+            let info = FileInfoQuery::new().file_id(id).execute(client).await?;
+            (info.expiration_time, None)
+        }
+        #[cfg(feature = "topics")]
+        ExpiringEntityId::Topic(id) => {
+            let info = TopicInfoQuery::new().topic_id(id).execute(client).await?;
+            (info.expiration_time, info.auto_renew_period)
+        }
+    };
+
+    Ok(expiration_time.map(|it| ExpiringEntity::new(entity_id, it, auto_renew_period)))
+}
+
+fn expiring_by(
+    entities: impl IntoIterator<Item = ExpiringEntity>,
+    deadline: OffsetDateTime,
+) -> Vec<ExpiringEntity> {
+    let mut entities: Vec<_> = entities.into_iter().filter(|it| it.expires_by(deadline)).collect();
+
+    entities.sort_by_key(|it| it.expiration_time);
+
+    entities
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use super::{
+        expiring_by,
+        ExpiringEntity,
+        ExpiringEntityId,
+    };
+    use crate::{
+        AccountId,
+        AccountUpdateTransaction,
+    };
+
+    const NOW: OffsetDateTime = match OffsetDateTime::from_unix_timestamp(1554158728) {
+        Ok(it) => it,
+        Err(_) => panic!("Panic in `const` unwrap"),
+    };
+
+    #[cfg(all(feature = "files", feature = "topics"))]
+    #[test]
+    fn expiring_by_filters_and_sorts() {
+        use crate::{
+            FileId,
+            TopicId,
+        };
+
+        let entities = [
+            ExpiringEntity::new(TopicId::new(0, 0, 1), NOW + Duration::days(3), None),
+            ExpiringEntity::new(AccountId::new(0, 0, 2), NOW + Duration::days(30), None),
+            ExpiringEntity::new(FileId::new(0, 0, 3), NOW - Duration::days(1), None),
+            ExpiringEntity::new(AccountId::new(0, 0, 4), NOW + Duration::days(7), None),
+        ];
+
+        let expiring = expiring_by(entities, NOW + Duration::days(7));
+
+        let ids: Vec<_> = expiring.iter().map(|it| it.entity_id).collect();
+
+        assert_eq!(
+            ids,
+            [
+                ExpiringEntityId::File(FileId::new(0, 0, 3)),
+                ExpiringEntityId::Topic(TopicId::new(0, 0, 1)),
+                ExpiringEntityId::Account(AccountId::new(0, 0, 4)),
+            ]
+        );
+    }
+
+    #[test]
+    fn extend_transaction() {
+        let entity = ExpiringEntity::new(AccountId::new(0, 0, 5), NOW, Some(Duration::days(90)));
+
+        let tx = entity.extend_transaction(Duration::days(30));
+        let tx = assert_matches!(tx.downcast::<AccountUpdateTransaction>(), Ok(it) => it);
+
+        assert_eq!(tx.get_account_id(), Some(AccountId::new(0, 0, 5)));
+        assert_eq!(tx.get_expiration_time(), Some(NOW + Duration::days(30)));
+    }
+
+    #[cfg(feature = "files")]
+    #[test]
+    fn extend_file_transaction() {
+        use crate::{
+            FileId,
+            FileUpdateTransaction,
+        };
+
+        let entity = ExpiringEntity::new(FileId::new(0, 0, 6), NOW, None);
+
+        let tx = entity.extend_transaction(Duration::days(1));
+        let tx = assert_matches!(tx.downcast::<FileUpdateTransaction>(), Ok(it) => it);
+
+        assert_eq!(tx.get_file_id(), Some(FileId::new(0, 0, 6)));
+        assert_eq!(tx.get_expiration_time(), Some(NOW + Duration::days(1)));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(ExpiringEntityId::from(AccountId::new(0, 0, 7)).to_string(), "0.0.7");
+    }
+}
@@ -3,8 +3,10 @@ use time::OffsetDateTime;
 
 use crate::protobuf::{
     FromProtobuf,
+    FromProtobufContext,
     ToProtobuf,
 };
+use crate::Error;
 
 /// Contains the current and next [`FeeSchedule`]s.
 ///
@@ -92,7 +94,7 @@ impl FromProtobuf<services::FeeSchedule> for FeeSchedule {
     fn from_protobuf(pb: services::FeeSchedule) -> crate::Result<Self> {
         Ok(Self {
             transaction_fee_schedules: Vec::from_protobuf(pb.transaction_fee_schedule)?,
-            expiration_time: pb_getf!(pb, expiry_time)?.into(),
+            expiration_time: FromProtobuf::from_protobuf(pb_getf!(pb, expiry_time)?)?,
         })
     }
 }
@@ -149,7 +151,15 @@ impl FromProtobuf<services::TransactionFeeSchedule> for TransactionFeeSchedule {
     #[allow(deprecated)]
     fn from_protobuf(pb: services::TransactionFeeSchedule) -> crate::Result<Self> {
         Ok(Self {
-            request_type: RequestType::from_protobuf(pb.hedera_functionality())?,
+            request_type: services::HederaFunctionality::try_from(pb.hedera_functionality)
+                .map_err(|_| {
+                    Error::from_protobuf(format!(
+                        "unknown `HederaFunctionality` `{}`",
+                        pb.hedera_functionality
+                    ))
+                })
+                .and_then(RequestType::from_protobuf)
+                .in_field("hedera_functionality")?,
             fee_data: Option::from_protobuf(pb.fee_data)?.map(Box::new),
             fees: Vec::from_protobuf(pb.fees)?,
         })
@@ -518,7 +528,11 @@ impl FromProtobuf<services::HederaFunctionality> for RequestType {
             HederaFunctionality::TokenAirdrop => Self::TokenAirdrop,
             HederaFunctionality::TokenClaimAirdrop => Self::TokenClaimAirdrop,
             HederaFunctionality::TokenCancelAirdrop => Self::TokenCancelAirdrop,
-            _ => todo!(),
+            other => {
+                return Err(Error::from_protobuf(format!(
+                    "unsupported `HederaFunctionality` `{other:?}`"
+                )))
+            }
         };
 
         Ok(value)
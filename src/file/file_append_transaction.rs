@@ -13,6 +13,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkData,
     ChunkInfo,
@@ -20,8 +21,10 @@ use crate::transaction::{
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
     TransactionExecuteChunked,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -91,6 +94,14 @@ impl TransactionData for FileAppendTransactionData {
     fn wait_for_receipt(&self) -> bool {
         true
     }
+
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "file_id", &self.file_id);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.optional_entity("file", self.file_id.as_ref());
+    }
 }
 
 impl ChunkedTransactionData for FileAppendTransactionData {
@@ -165,7 +176,8 @@ impl FromProtobuf<Vec<services::FileAppendTransactionBody>> for FileAppendTransa
         let total_chunks = pb.len();
 
         let mut iter = pb.into_iter();
-        let pb_first = iter.next().expect("Empty transaction (should've been handled earlier)");
+        let pb_first =
+            iter.next().ok_or_else(|| Error::from_protobuf("transaction had no chunks"))?;
 
         let file_id = Option::from_protobuf(pb_first.file_id)?;
 
@@ -186,6 +198,7 @@ impl FromProtobuf<Vec<services::FileAppendTransactionBody>> for FileAppendTransa
                 chunk_size: NonZeroUsize::new(largest_chunk_size)
                     .unwrap_or_else(|| NonZeroUsize::new(1).unwrap()),
                 data: contents,
+                sticky_node: false,
             },
         })
     }
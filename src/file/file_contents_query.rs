@@ -16,6 +16,7 @@ use crate::{
     Error,
     FileContentsResponse,
     FileId,
+    FromProtobuf,
     ToProtobuf,
     ValidateChecksums,
 };
@@ -61,6 +62,12 @@ impl ToQueryProtobuf for FileContentsQueryData {
     }
 }
 
+impl FromProtobuf<services::FileGetContentsQuery> for FileContentsQueryData {
+    fn from_protobuf(pb: services::FileGetContentsQuery) -> crate::Result<Self> {
+        Ok(Self { file_id: Option::from_protobuf(pb.file_id)? })
+    }
+}
+
 impl QueryExecute for FileContentsQueryData {
     type Response = FileContentsResponse;
 
@@ -15,12 +15,15 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_memo,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -164,6 +167,17 @@ impl TransactionData for FileCreateTransactionData {
     fn default_max_transaction_fee(&self) -> crate::Hbar {
         crate::Hbar::new(5)
     }
+
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        // an empty `keys` list is allowed, it makes the file immutable.
+        check_memo(problems, "file_memo", &self.file_memo);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("auto_renew_account", self.auto_renew_account_id.as_ref())
+            .key("keys", self.keys.clone().map(Key::from).as_ref());
+    }
 }
 
 impl TransactionExecute for FileCreateTransactionData {
@@ -217,7 +231,7 @@ impl FromProtobuf<services::FileCreateTransactionBody> for FileCreateTransaction
             contents: Some(pb.contents),
             auto_renew_period: None,
             auto_renew_account_id: None,
-            expiration_time: pb.expiration_time.map(Into::into),
+            expiration_time: Option::from_protobuf(pb.expiration_time)?,
         })
     }
 }
@@ -10,12 +10,15 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -53,7 +56,15 @@ impl FileDeleteTransaction {
     }
 }
 
-impl TransactionData for FileDeleteTransactionData {}
+impl TransactionData for FileDeleteTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "file_id", &self.file_id);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.optional_entity("file", self.file_id.as_ref());
+    }
+}
 
 impl TransactionExecute for FileDeleteTransactionData {
     fn execute(
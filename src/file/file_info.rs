@@ -94,7 +94,7 @@ impl FromProtobuf<services::file_get_info_response::FileInfo> for FileInfo {
         Ok(Self {
             file_id: FileId::from_protobuf(file_id)?,
             size: pb.size as u64,
-            expiration_time: pb.expiration_time.map(Into::into),
+            expiration_time: Option::from_protobuf(pb.expiration_time)?,
             auto_renew_account_id: None,
             auto_renew_period: None,
             is_deleted: pb.deleted,
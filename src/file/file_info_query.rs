@@ -15,6 +15,7 @@ use crate::{
     Error,
     FileId,
     FileInfo,
+    FromProtobuf,
     Query,
     ToProtobuf,
     ValidateChecksums,
@@ -62,6 +63,12 @@ impl ToQueryProtobuf for FileInfoQueryData {
     }
 }
 
+impl FromProtobuf<services::FileGetInfoQuery> for FileInfoQueryData {
+    fn from_protobuf(pb: services::FileGetInfoQuery) -> crate::Result<Self> {
+        Ok(Self { file_id: Option::from_protobuf(pb.file_id)? })
+    }
+}
+
 impl QueryExecute for FileInfoQueryData {
     type Response = FileInfo;
 
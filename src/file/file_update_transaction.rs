@@ -14,12 +14,16 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_memo,
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -167,7 +171,22 @@ impl FileUpdateTransaction {
     }
 }
 
-impl TransactionData for FileUpdateTransactionData {}
+impl TransactionData for FileUpdateTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "file_id", &self.file_id);
+
+        if let Some(file_memo) = &self.file_memo {
+            check_memo(problems, "file_memo", file_memo);
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("file", self.file_id.as_ref())
+            .optional_entity("auto_renew_account", self.auto_renew_account_id.as_ref())
+            .key("keys", self.keys.clone().map(Key::from).as_ref());
+    }
+}
 
 impl TransactionExecute for FileUpdateTransactionData {
     fn execute(
@@ -217,7 +236,7 @@ impl FromProtobuf<services::FileUpdateTransactionBody> for FileUpdateTransaction
             file_memo: pb.memo,
             keys: Option::from_protobuf(pb.keys)?,
             contents: Some(pb.contents),
-            expiration_time: pb.expiration_time.map(Into::into),
+            expiration_time: Option::from_protobuf(pb.expiration_time)?,
             auto_renew_account_id: None,
             auto_renew_period: None,
         })
@@ -1,27 +1,49 @@
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "files")]
 mod file_append_transaction;
+#[cfg(feature = "files")]
 mod file_contents_query;
+#[cfg(feature = "files")]
 mod file_contents_response;
+#[cfg(feature = "files")]
 mod file_create_transaction;
+#[cfg(feature = "files")]
 mod file_delete_transaction;
 mod file_id;
+#[cfg(feature = "files")]
 mod file_info;
+#[cfg(feature = "files")]
 mod file_info_query;
+#[cfg(feature = "files")]
 mod file_update_transaction;
 
+#[cfg(feature = "files")]
 pub use file_append_transaction::FileAppendTransaction;
+#[cfg(feature = "files")]
 pub(crate) use file_append_transaction::FileAppendTransactionData;
+#[cfg(feature = "files")]
 pub use file_contents_query::FileContentsQuery;
+#[cfg(feature = "files")]
 pub(crate) use file_contents_query::FileContentsQueryData;
+#[cfg(feature = "files")]
 pub use file_contents_response::FileContentsResponse;
+#[cfg(feature = "files")]
 pub use file_create_transaction::FileCreateTransaction;
+#[cfg(feature = "files")]
 pub(crate) use file_create_transaction::FileCreateTransactionData;
+#[cfg(feature = "files")]
 pub use file_delete_transaction::FileDeleteTransaction;
+#[cfg(feature = "files")]
 pub(crate) use file_delete_transaction::FileDeleteTransactionData;
 pub use file_id::FileId;
+#[cfg(feature = "files")]
 pub use file_info::FileInfo;
+#[cfg(feature = "files")]
 pub use file_info_query::FileInfoQuery;
+#[cfg(feature = "files")]
 pub(crate) use file_info_query::FileInfoQueryData;
+#[cfg(feature = "files")]
 pub use file_update_transaction::FileUpdateTransaction;
+#[cfg(feature = "files")]
 pub(crate) use file_update_transaction::FileUpdateTransactionData;
@@ -7,6 +7,7 @@ use crate::{
     ContractId,
     Error,
     FromProtobuf,
+    KeyAlgorithm,
     KeyList,
     PublicKey,
     ToProtobuf,
@@ -46,14 +47,7 @@ impl ToProtobuf for Key {
 
         services::Key {
             key: Some(match self {
-                Self::Single(key) => {
-                    let bytes = key.to_bytes_raw();
-
-                    match key.kind() {
-                        crate::key::KeyKind::Ed25519 => Ed25519(bytes),
-                        crate::key::KeyKind::Ecdsa => EcdsaSecp256k1(bytes),
-                    }
-                }
+                Self::Single(key) => key.kind().key_protobuf(key.to_bytes_raw()),
 
                 Self::ContractId(id) => ContractId(id.to_protobuf()),
                 Self::DelegateContractId(id) => DelegatableContractId(id.to_protobuf()),
@@ -95,12 +89,8 @@ impl FromProtobuf<services::Key> for Key {
             Some(DelegatableContractId(id)) => {
                 Ok(Self::DelegateContractId(crate::DelegateContractId::from_protobuf(id)?))
             }
-            Some(Rsa3072(_)) => {
-                Err(Error::from_protobuf("unexpected unsupported RSA-3072 key in Key"))
-            }
-            Some(Ecdsa384(_)) => {
-                Err(Error::from_protobuf("unexpected unsupported ECDSA-384 key in Key"))
-            }
+            Some(Rsa3072(_)) => Err(Error::UnsupportedKeyAlgorithm(KeyAlgorithm::Rsa3072)),
+            Some(Ecdsa384(_)) => Err(Error::UnsupportedKeyAlgorithm(KeyAlgorithm::EcdsaP384)),
             Some(ThresholdKey(it)) => Ok(Self::KeyList(crate::KeyList::from_protobuf(it)?)),
             Some(KeyList(it)) => Ok(Self::KeyList(crate::KeyList::from_protobuf(it)?)),
             Some(EcdsaSecp256k1(bytes)) => Ok(Self::Single(PublicKey::from_bytes_ecdsa(&bytes)?)),
@@ -209,6 +199,16 @@ mod tests {
     fn unsupported_key_fails() {
         let key = services::Key { key: Some(services::key::Key::Rsa3072(Vec::from([0, 1, 2]))) };
 
-        assert_matches!(Key::from_protobuf(key), Err(crate::Error::FromProtobuf(_)));
+        assert_matches!(
+            Key::from_protobuf(key),
+            Err(crate::Error::UnsupportedKeyAlgorithm(crate::KeyAlgorithm::Rsa3072))
+        );
+
+        let key = services::Key { key: Some(services::key::Key::Ecdsa384(Vec::from([0, 1, 2]))) };
+
+        assert_matches!(
+            PublicKey::from_protobuf(key),
+            Err(crate::Error::UnsupportedKeyAlgorithm(crate::KeyAlgorithm::EcdsaP384))
+        );
     }
 }
@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+use hedera_proto::services;
+use pkcs8::ObjectIdentifier;
+
+/// The signature algorithm of a key.
+///
+/// This includes algorithms the network knows about that this SDK can't (yet) create, parse, or sign with,
+/// IE, RSA-3072 keys in the address book. Keys and signatures using those are rejected with
+/// [`Error::UnsupportedKeyAlgorithm`](crate::Error::UnsupportedKeyAlgorithm), see [`is_supported`](Self::is_supported).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyAlgorithm {
+    /// Ed25519.
+    Ed25519,
+
+    /// ECDSA with the secp256k1 curve.
+    EcdsaSecp256k1,
+
+    /// ECDSA with the NIST P-384 curve.
+    EcdsaP384,
+
+    /// RSA with a 3072 bit modulus.
+    Rsa3072,
+}
+
+/// `rsaEncryption`
+const RSA_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+
+/// `secp384r1`
+const P384_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+
+impl KeyAlgorithm {
+    /// Returns `true` if this SDK can use keys of this algorithm.
+    #[must_use]
+    pub fn is_supported(self) -> bool {
+        self.kind().is_some()
+    }
+
+    /// Returns the algorithm of a protobuf signature, or `None` if it isn't a cryptographic signature.
+    pub(crate) fn from_signature_protobuf(
+        signature: &services::signature_pair::Signature,
+    ) -> Option<Self> {
+        use services::signature_pair::Signature;
+
+        match signature {
+            Signature::Ed25519(_) => Some(Self::Ed25519),
+            Signature::EcdsaSecp256k1(_) => Some(Self::EcdsaSecp256k1),
+            Signature::Ecdsa384(_) => Some(Self::EcdsaP384),
+            Signature::Rsa3072(_) => Some(Self::Rsa3072),
+            Signature::Contract(_) => None,
+        }
+    }
+
+    /// Returns the algorithm identified by a DER `AlgorithmIdentifier`, if it's one known to the network.
+    ///
+    /// `rsa_modulus_bits` is only called for RSA keys, the network only knows about 3072 bit ones.
+    pub(crate) fn from_der_oid(
+        oid: ObjectIdentifier,
+        parameters_oid: Option<ObjectIdentifier>,
+        rsa_modulus_bits: impl FnOnce() -> Option<usize>,
+    ) -> Option<Self> {
+        use super::private_key::{
+            ED25519_OID,
+            K256_OID,
+        };
+        use super::public_key::EC_ALGORITM_OID;
+
+        match (oid, parameters_oid) {
+            (ED25519_OID, _) => Some(Self::Ed25519),
+            (K256_OID, _) | (EC_ALGORITM_OID, Some(K256_OID)) => Some(Self::EcdsaSecp256k1),
+            (EC_ALGORITM_OID, Some(P384_OID)) => Some(Self::EcdsaP384),
+            (RSA_OID, _) => (rsa_modulus_bits() == Some(3072)).then_some(Self::Rsa3072),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn kind(self) -> Option<KeyKind> {
+        match self {
+            Self::Ed25519 => Some(KeyKind::Ed25519),
+            Self::EcdsaSecp256k1 => Some(KeyKind::Ecdsa),
+            Self::EcdsaP384 | Self::Rsa3072 => None,
+        }
+    }
+}
+
+impl fmt::Display for KeyAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Ed25519 => "ED25519",
+            Self::EcdsaSecp256k1 => "ECDSA(secp256k1)",
+            Self::EcdsaP384 => "ECDSA(P-384)",
+            Self::Rsa3072 => "RSA-3072",
+        })
+    }
+}
+
+/// Returns the size of the modulus of a DER encoded `RSAPublicKey`, or `RSAPrivateKey` if `private`.
+pub(crate) fn rsa_modulus_bits(key: &[u8], private: bool) -> Option<usize> {
+    use pkcs8::der::asn1::UintRef;
+    use pkcs8::der::{
+        Decode,
+        Reader,
+        SliceReader,
+    };
+
+    let mut reader = SliceReader::new(key).ok()?;
+
+    reader
+        .sequence(|reader| {
+            if private {
+                // version
+                UintRef::decode(reader)?;
+            }
+
+            let modulus = UintRef::decode(reader)?.as_bytes();
+
+            // skip the rest of the key, we only care about the modulus.
+            reader.read_slice(reader.remaining_len())?;
+
+            Ok(modulus
+                .first()
+                .map_or(0, |first| modulus.len() * 8 - first.leading_zeros() as usize))
+        })
+        .ok()
+}
+
+/// The algorithms of the keys this SDK can use.
+///
+/// Every [`PublicKey`](crate::PublicKey) and [`PrivateKey`](crate::PrivateKey) is one of these,
+/// so matching on it never needs a fallback arm.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum KeyKind {
+    Ed25519,
+    Ecdsa,
+}
+
+impl KeyKind {
+    pub(crate) fn algorithm(self) -> KeyAlgorithm {
+        match self {
+            Self::Ed25519 => KeyAlgorithm::Ed25519,
+            Self::Ecdsa => KeyAlgorithm::EcdsaSecp256k1,
+        }
+    }
+
+    /// Wraps the raw bytes of a public key of this kind in a protobuf key.
+    pub(crate) fn key_protobuf(self, bytes: Vec<u8>) -> services::key::Key {
+        match self {
+            Self::Ed25519 => services::key::Key::Ed25519(bytes),
+            Self::Ecdsa => services::key::Key::EcdsaSecp256k1(bytes),
+        }
+    }
+
+    /// Wraps a signature made by a key of this kind in a protobuf signature.
+    pub(crate) fn signature_protobuf(
+        self,
+        signature: Vec<u8>,
+    ) -> services::signature_pair::Signature {
+        match self {
+            Self::Ed25519 => services::signature_pair::Signature::Ed25519(signature),
+            Self::Ecdsa => services::signature_pair::Signature::EcdsaSecp256k1(signature),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hedera_proto::services;
+
+    use super::{
+        rsa_modulus_bits,
+        KeyAlgorithm,
+        KeyKind,
+    };
+    use crate::{
+        Error,
+        PublicKey,
+    };
+
+    fn der(tag: u8, content: &[u8]) -> Vec<u8> {
+        let len = content.len();
+
+        let mut out = match len {
+            0..=0x7f => vec![tag, len as u8],
+            0x80..=0xff => vec![tag, 0x81, len as u8],
+            _ => vec![tag, 0x82, (len >> 8) as u8, len as u8],
+        };
+
+        out.extend_from_slice(content);
+        out
+    }
+
+    fn rsa_public_key(bits: usize) -> Vec<u8> {
+        let mut modulus = vec![0x00];
+        modulus.resize(bits / 8 + 1, 0xff);
+
+        der(0x30, &[der(0x02, &modulus), der(0x02, &[0x01, 0x00, 0x01])].concat())
+    }
+
+    fn rsa_public_key_info(bits: usize) -> Vec<u8> {
+        // rsaEncryption, with NULL parameters.
+        let algorithm = der(
+            0x30,
+            &[der(0x06, &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01]), vec![0x05, 0x00]]
+                .concat(),
+        );
+
+        der(0x30, &[algorithm, der(0x03, &[&[0x00], &*rsa_public_key(bits)].concat())].concat())
+    }
+
+    #[test]
+    fn supported() {
+        assert!(KeyAlgorithm::Ed25519.is_supported());
+        assert!(KeyAlgorithm::EcdsaSecp256k1.is_supported());
+        assert!(!KeyAlgorithm::EcdsaP384.is_supported());
+        assert!(!KeyAlgorithm::Rsa3072.is_supported());
+
+        for kind in [KeyKind::Ed25519, KeyKind::Ecdsa] {
+            assert_eq!(kind.algorithm().kind(), Some(kind));
+        }
+    }
+
+    #[test]
+    fn from_signature_protobuf() {
+        assert_eq!(
+            KeyAlgorithm::from_signature_protobuf(&services::signature_pair::Signature::Ecdsa384(
+                Vec::new()
+            )),
+            Some(KeyAlgorithm::EcdsaP384)
+        );
+        assert_eq!(
+            KeyAlgorithm::from_signature_protobuf(&services::signature_pair::Signature::Contract(
+                Vec::new()
+            )),
+            None
+        );
+    }
+
+    #[test]
+    fn rsa_modulus_size() {
+        assert_eq!(rsa_modulus_bits(&rsa_public_key(3072), false), Some(3072));
+        assert_eq!(rsa_modulus_bits(&rsa_public_key(2048), false), Some(2048));
+
+        // `RSAPrivateKey` starts with a version.
+        let private_key = der(
+            0x30,
+            &[der(0x02, &[0x00]), rsa_public_key(3072)[4..].to_vec(), der(0x02, &[0x01])].concat(),
+        );
+
+        assert_eq!(rsa_modulus_bits(&private_key, true), Some(3072));
+        assert_eq!(rsa_modulus_bits(&[0x30, 0x00], false), None);
+    }
+
+    #[test]
+    fn rsa_public_key_from_der() {
+        assert_matches!(
+            PublicKey::from_bytes_der(&rsa_public_key_info(3072)),
+            Err(Error::UnsupportedKeyAlgorithm(KeyAlgorithm::Rsa3072))
+        );
+
+        assert_matches!(
+            PublicKey::from_bytes_der(&rsa_public_key_info(2048)),
+            Err(Error::KeyParse(_))
+        );
+    }
+}
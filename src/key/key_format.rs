@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: Apache-2.0
+
+/// A string encoding of a [`PrivateKey`](crate::PrivateKey) or [`PublicKey`](crate::PublicKey).
+///
+/// Every format is byte for byte compatible with the other Hiero SDKs.
+///
+/// See [`PrivateKey::to_string_with_format`](crate::PrivateKey::to_string_with_format)
+/// and [`PrivateKey::from_str_with_format`](crate::PrivateKey::from_str_with_format).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyFormat {
+    /// Hex encoded DER, IE, PKCS#8 for private keys and SPKI for public keys.
+    ///
+    /// This is the format of the legacy `302e...` (Ed25519) and `3030...` (ECDSA(secp256k1)) private key strings.
+    Der,
+
+    /// Hex encoded raw key material.
+    ///
+    /// The algorithm of the key isn't part of the encoding, so it has to be known when parsing.
+    Raw,
+
+    /// PEM encoded DER, with the type label `PRIVATE KEY` or `PUBLIC KEY`.
+    Pem,
+}
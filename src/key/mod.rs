@@ -2,17 +2,19 @@
 
 #[allow(clippy::module_inception)]
 mod key;
+mod key_algorithm;
+mod key_format;
 mod key_list;
 mod private_key;
 mod public_key;
 
 pub use key::Key;
+pub use key_algorithm::KeyAlgorithm;
+pub(crate) use key_algorithm::{
+    rsa_modulus_bits,
+    KeyKind,
+};
+pub use key_format::KeyFormat;
 pub use key_list::KeyList;
 pub use private_key::PrivateKey;
 pub use public_key::PublicKey;
-
-#[derive(Copy, Clone, Debug)]
-pub(crate) enum KeyKind {
-    Ed25519,
-    Ecdsa,
-}
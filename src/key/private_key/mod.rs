@@ -35,6 +35,12 @@ use sha2::Sha512;
 use sha3::Digest;
 use triomphe::Arc;
 
+use crate::key::public_key::EC_ALGORITM_OID;
+use crate::key::{
+    rsa_modulus_bits,
+    KeyAlgorithm,
+    KeyFormat,
+};
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
@@ -228,6 +234,7 @@ impl PrivateKey {
     ///
     /// # Errors
     /// - [`Error::KeyParse`] if `bytes` cannot be parsed into a `PrivateKey`.
+    /// - [`Error::UnsupportedKeyAlgorithm`] if `bytes` is a key of an algorithm this SDK doesn't support, IE, RSA-3072.
     pub fn from_bytes_der(bytes: &[u8]) -> crate::Result<Self> {
         let info =
             pkcs8::PrivateKeyInfo::from_der(bytes).map_err(|err| Error::key_parse(err.to_string()));
@@ -237,6 +244,22 @@ impl PrivateKey {
             Err(e) => return Self::from_sec1_bytes_der(bytes).ok().ok_or(e),
         };
 
+        let algorithm = KeyAlgorithm::from_der_oid(
+            info.algorithm.oid,
+            info.algorithm.parameters_oid().ok(),
+            || rsa_modulus_bits(info.private_key, true),
+        );
+
+        let kind = match algorithm {
+            Some(algorithm) => algorithm.kind().ok_or(Error::UnsupportedKeyAlgorithm(algorithm))?,
+            None => {
+                return Err(Error::key_parse(format!(
+                    "unsupported key algorithm: {}",
+                    info.algorithm.oid
+                )))
+            }
+        };
+
         // PrivateKey is an `OctetString`, and the `PrivateKey`s we all support are `OctetStrings`.
         // So, we, awkwardly, have an `OctetString` containing an `OctetString` containing our key material.
         let inner = pkcs8::der::asn1::OctetStringRef::from_der(info.private_key)
@@ -244,10 +267,9 @@ impl PrivateKey {
 
         let inner = inner.as_bytes();
 
-        match info.algorithm.oid {
-            K256_OID => Self::from_bytes_ecdsa(inner),
-            ED25519_OID => Self::from_bytes_ed25519(inner),
-            id => Err(Error::key_parse(format!("unsupported key algorithm: {id}"))),
+        match kind {
+            super::KeyKind::Ed25519 => Self::from_bytes_ed25519(inner),
+            super::KeyKind::Ecdsa => Self::from_bytes_ecdsa(inner),
         }
     }
 
@@ -256,7 +278,10 @@ impl PrivateKey {
 
         match sec1.parameters.and_then(sec1::EcParameters::named_curve) {
             Some(K256_OID) => Self::from_bytes_ecdsa(sec1.private_key),
-            Some(oid) => Err(Error::key_parse(format!("unsupported curve OID: {oid}"))),
+            Some(oid) => match KeyAlgorithm::from_der_oid(EC_ALGORITM_OID, Some(oid), || None) {
+                Some(algorithm) => Err(Error::UnsupportedKeyAlgorithm(algorithm)),
+                None => Err(Error::key_parse(format!("unsupported curve OID: {oid}"))),
+            },
             None => Err(Error::key_parse("missing curve parameters")),
         }
     }
@@ -457,7 +482,7 @@ impl PrivateKey {
             .unwrap();
 
         let info = pkcs8::PrivateKeyInfo {
-            algorithm: self.algorithm(),
+            algorithm: self.der_algorithm(),
             private_key: &inner,
             public_key: None,
         };
@@ -506,6 +531,76 @@ impl PrivateKey {
         hex::encode(self.to_bytes_raw_internal())
     }
 
+    /// Return this `PrivateKey`, serialized as [PEM](https://www.rfc-editor.org/rfc/rfc7468#section-10) encoded DER,
+    /// with the type label `PRIVATE KEY`.
+    #[must_use]
+    pub fn to_pem(&self) -> String {
+        let pem = ::pem::Pem::new("PRIVATE KEY", self.to_bytes_der());
+
+        ::pem::encode_config(
+            &pem,
+            ::pem::EncodeConfig::new().set_line_ending(::pem::LineEnding::LF),
+        )
+    }
+
+    /// Return this `PrivateKey`, serialized as a string in the given `format`.
+    #[must_use]
+    pub fn to_string_with_format(&self, format: KeyFormat) -> String {
+        match format {
+            KeyFormat::Der => self.to_string_der(),
+            KeyFormat::Raw => self.to_string_raw(),
+            KeyFormat::Pem => self.to_pem(),
+        }
+    }
+
+    /// Parse a `PrivateKey` of the given `algorithm` from a string in the given `format`.
+    ///
+    /// Unlike [`from_str`](Self::from_str) this never guesses the algorithm of the key,
+    /// IE, a raw ECDSA(secp256k1) key is never mistaken for an Ed25519 key.
+    ///
+    /// Optionally strips a `0x` prefix from [`KeyFormat::Der`] and [`KeyFormat::Raw`] strings.
+    /// Raw Ed25519 keys may also be in the legacy 64 byte format (the private key followed by the public key).
+    ///
+    /// # Errors
+    /// - [`Error::KeyParse`] if `s` cannot be parsed into a `PrivateKey`.
+    /// - [`Error::KeyParse`] if `s` is a key of a different algorithm than `algorithm`.
+    /// - [`Error::UnsupportedKeyAlgorithm`] if `algorithm` isn't supported by this SDK.
+    pub fn from_str_with_format(
+        s: &str,
+        format: KeyFormat,
+        algorithm: KeyAlgorithm,
+    ) -> crate::Result<Self> {
+        let kind = algorithm.kind().ok_or(Error::UnsupportedKeyAlgorithm(algorithm))?;
+
+        let key = match format {
+            KeyFormat::Der => Self::from_str_der(s)?,
+            KeyFormat::Pem => Self::from_pem(s)?,
+            KeyFormat::Raw => {
+                let bytes =
+                    hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(Error::key_parse)?;
+
+                match (kind, bytes.len()) {
+                    (super::KeyKind::Ed25519, 32 | 64) => Self::from_bytes_ed25519(&bytes)?,
+                    (super::KeyKind::Ecdsa, 32) => Self::from_bytes_ecdsa(&bytes)?,
+                    (_, len) => {
+                        return Err(Error::key_parse(format!(
+                            "invalid length for a raw {algorithm} private key: {len} bytes"
+                        )))
+                    }
+                }
+            }
+        };
+
+        if key.kind() != kind {
+            return Err(Error::key_parse(format!(
+                "expected a {algorithm} private key, found a {} private key",
+                key.algorithm()
+            )));
+        }
+
+        Ok(key)
+    }
+
     /// Creates an [`AccountId`] with the given `shard`, `realm`, and `self.public_key()` as an [`alias`](AccountId::alias).
     ///
     /// # Examples
@@ -524,7 +619,7 @@ impl PrivateKey {
         self.public_key().to_account_id(shard, realm)
     }
 
-    fn algorithm(&self) -> pkcs8::AlgorithmIdentifierRef<'_> {
+    fn der_algorithm(&self) -> pkcs8::AlgorithmIdentifierRef<'_> {
         pkcs8::AlgorithmIdentifierRef {
             parameters: None,
             oid: match &self.0.data {
@@ -600,7 +695,7 @@ impl PrivateKey {
     ) -> crate::Result<Vec<u8>> {
         transaction.freeze()?;
 
-        let sig = transaction.add_signature_signer(&AnySigner::PrivateKey(self.clone()));
+        let sig = transaction.add_signature_signer(&AnySigner::PrivateKey(self.clone()))?;
 
         Ok(sig)
     }
@@ -731,8 +826,14 @@ impl PrivateKey {
         Self::from_mnemonic_seed(&seed)
     }
 
+    /// Returns the signature algorithm of this key.
+    #[must_use]
+    pub fn algorithm(&self) -> KeyAlgorithm {
+        self.kind().algorithm()
+    }
+
     #[must_use]
-    pub(crate) fn _kind(&self) -> super::KeyKind {
+    pub(crate) fn kind(&self) -> super::KeyKind {
         match &self.0.data {
             PrivateKeyData::Ed25519(_) => super::KeyKind::Ed25519,
             PrivateKeyData::Ecdsa(_) => super::KeyKind::Ecdsa,
@@ -31,6 +31,11 @@ use crate::key::private_key::{
     ED25519_OID,
     K256_OID,
 };
+use crate::key::{
+    rsa_modulus_bits,
+    KeyAlgorithm,
+    KeyFormat,
+};
 use crate::protobuf::ToProtobuf;
 use crate::signer::AnySigner;
 use crate::transaction::TransactionSources;
@@ -158,6 +163,7 @@ impl PublicKey {
     ///
     /// # Errors
     /// - [`Error::KeyParse`] if `bytes` cannot be parsed into a `PublicKey`.
+    /// - [`Error::UnsupportedKeyAlgorithm`] if `bytes` is a key of an algorithm this SDK doesn't support, IE, RSA-3072.
     pub fn from_bytes_der(bytes: &[u8]) -> crate::Result<Self> {
         let info = pkcs8::SubjectPublicKeyInfoRef::from_der(bytes)
             .map_err(|err| Error::key_parse(err.to_string()))?;
@@ -167,13 +173,19 @@ impl PublicKey {
             .as_bytes()
             .ok_or_else(|| Error::key_parse("Unexpected bitstring len"))?;
 
-        match info.algorithm.oid {
-            K256_OID => Self::from_bytes_ecdsa(bytes),
-            EC_ALGORITM_OID if info.algorithm.parameters_oid().ok() == Some(K256_OID) => {
-                Self::from_bytes_ecdsa(bytes)
+        let algorithm = KeyAlgorithm::from_der_oid(
+            info.algorithm.oid,
+            info.algorithm.parameters_oid().ok(),
+            || rsa_modulus_bits(bytes, false),
+        );
+
+        match algorithm {
+            Some(KeyAlgorithm::Ed25519) => Self::from_bytes_ed25519(bytes),
+            Some(KeyAlgorithm::EcdsaSecp256k1) => Self::from_bytes_ecdsa(bytes),
+            Some(algorithm) => Err(Error::UnsupportedKeyAlgorithm(algorithm)),
+            None => {
+                Err(Error::key_parse(format!("unsupported key algorithm: {}", info.algorithm.oid)))
             }
-            ED25519_OID => Self::from_bytes_ed25519(bytes),
-            oid => Err(Error::key_parse(format!("unsupported key algorithm: {oid}"))),
         }
     }
 
@@ -214,6 +226,70 @@ impl PublicKey {
         )
     }
 
+    /// Parse a `PublicKey` from [PEM](https://www.rfc-editor.org/rfc/rfc7468#section-13) encoded bytes.
+    ///
+    /// # Errors
+    /// - [`Error::KeyParse`] if `pem` is not valid PEM.
+    /// - [`Error::KeyParse`] if the type label (`BEGIN XYZ`) is not `PUBLIC KEY`.
+    /// - [`Error::KeyParse`] if the data contained inside the PEM is not a valid `PublicKey`.
+    pub fn from_pem(pem: impl AsRef<[u8]>) -> crate::Result<Self> {
+        let pem = ::pem::parse(pem).map_err(Error::key_parse)?;
+
+        match pem.tag() {
+            "PUBLIC KEY" => Self::from_bytes_der(pem.contents()),
+            type_label => Err(Error::key_parse(format!(
+                "incorrect PEM type label: expected: `PUBLIC KEY`, got: `{type_label}`"
+            ))),
+        }
+    }
+
+    /// Parse a `PublicKey` of the given `algorithm` from a string in the given `format`.
+    ///
+    /// Unlike [`from_str`](Self::from_str) this never guesses the algorithm of the key.
+    ///
+    /// Optionally strips a `0x` prefix from [`KeyFormat::Der`] and [`KeyFormat::Raw`] strings.
+    /// Raw ECDSA(secp256k1) keys may be compressed or uncompressed.
+    ///
+    /// # Errors
+    /// - [`Error::KeyParse`] if `s` cannot be parsed into a `PublicKey`.
+    /// - [`Error::KeyParse`] if `s` is a key of a different algorithm than `algorithm`.
+    /// - [`Error::UnsupportedKeyAlgorithm`] if `algorithm` isn't supported by this SDK.
+    pub fn from_str_with_format(
+        s: &str,
+        format: KeyFormat,
+        algorithm: KeyAlgorithm,
+    ) -> crate::Result<Self> {
+        let kind = algorithm.kind().ok_or(Error::UnsupportedKeyAlgorithm(algorithm))?;
+
+        let key = match format {
+            KeyFormat::Der => Self::from_str_der(s)?,
+            KeyFormat::Pem => Self::from_pem(s)?,
+            KeyFormat::Raw => {
+                let bytes =
+                    hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(Error::key_parse)?;
+
+                match (kind, bytes.len()) {
+                    (super::KeyKind::Ed25519, 32) => Self::from_bytes_ed25519(&bytes)?,
+                    (super::KeyKind::Ecdsa, 33 | 65) => Self::from_bytes_ecdsa(&bytes)?,
+                    (_, len) => {
+                        return Err(Error::key_parse(format!(
+                            "invalid length for a raw {algorithm} public key: {len} bytes"
+                        )))
+                    }
+                }
+            }
+        };
+
+        if key.kind() != kind {
+            return Err(Error::key_parse(format!(
+                "expected a {algorithm} public key, found a {} public key",
+                key.algorithm()
+            )));
+        }
+
+        Ok(key)
+    }
+
     /// Return this `PublicKey`, serialized as bytes.
     ///
     /// If this is an ed25519 public key, this is equivalent to [`to_bytes_raw`](Self::to_bytes_raw)
@@ -237,7 +313,7 @@ impl PublicKey {
             PublicKeyData::Ed25519(key) => {
                 let key = key.to_bytes();
                 let info = pkcs8::SubjectPublicKeyInfoRef {
-                    algorithm: self.algorithm(),
+                    algorithm: self.der_algorithm(),
                     subject_public_key: BitStringRef::from_bytes(&key).unwrap(),
                 };
 
@@ -247,7 +323,7 @@ impl PublicKey {
             PublicKeyData::Ecdsa(key) => {
                 let key = key.to_encoded_point(true);
                 let info = pkcs8::SubjectPublicKeyInfoRef {
-                    algorithm: self.algorithm(),
+                    algorithm: self.der_algorithm(),
                     subject_public_key: BitStringRef::from_bytes(key.as_bytes()).unwrap(),
                 };
 
@@ -258,7 +334,7 @@ impl PublicKey {
         buf
     }
 
-    fn algorithm(&self) -> pkcs8::AlgorithmIdentifierRef<'_> {
+    fn der_algorithm(&self) -> pkcs8::AlgorithmIdentifierRef<'_> {
         pkcs8::AlgorithmIdentifierRef {
             parameters: None,
             oid: match self.0 {
@@ -289,6 +365,28 @@ impl PublicKey {
         hex::encode(self.to_bytes_raw())
     }
 
+    /// Return this `PublicKey`, serialized as [PEM](https://www.rfc-editor.org/rfc/rfc7468#section-13) encoded DER,
+    /// with the type label `PUBLIC KEY`.
+    #[must_use]
+    pub fn to_pem(&self) -> String {
+        let pem = ::pem::Pem::new("PUBLIC KEY", self.to_bytes_der());
+
+        ::pem::encode_config(
+            &pem,
+            ::pem::EncodeConfig::new().set_line_ending(::pem::LineEnding::LF),
+        )
+    }
+
+    /// Return this `PublicKey`, serialized as a string in the given `format`.
+    #[must_use]
+    pub fn to_string_with_format(&self, format: KeyFormat) -> String {
+        match format {
+            KeyFormat::Der => self.to_string_der(),
+            KeyFormat::Raw => self.to_string_raw(),
+            KeyFormat::Pem => self.to_pem(),
+        }
+    }
+
     /// Creates an [`AccountId`] with the given `shard`, `realm`, and `self` as an [`alias`](AccountId.alias).
     ///
     /// # Examples
@@ -352,6 +450,30 @@ impl PublicKey {
         }
     }
 
+    /// Returns the recovery ID (`0` or `1`) of an ECDSA(secp256k1) `signature` on a `msg` with this public key.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if this isn't an ECDSA(secp256k1) key.
+    /// - [`Error::SignatureVerify`] if the signature is invalid for this `PublicKey`.
+    pub(crate) fn ecdsa_recovery_id(&self, msg: &[u8], signature: &[u8]) -> crate::Result<u8> {
+        let PublicKeyData::Ecdsa(key) = &self.0 else {
+            return Err(Error::signature_verify("recovery IDs only exist for ECDSA signatures"));
+        };
+
+        let signature = ecdsa::Signature::try_from(signature).map_err(Error::signature_verify)?;
+
+        (0..=1)
+            .find(|&id| {
+                ecdsa::VerifyingKey::recover_from_digest(
+                    sha3::Keccak256::new_with_prefix(msg),
+                    &signature,
+                    ecdsa::RecoveryId::new(id == 1, false),
+                )
+                .is_ok_and(|it| it == *key)
+            })
+            .ok_or_else(|| Error::signature_verify("signature is invalid for this key"))
+    }
+
     pub(crate) fn verify_transaction_sources(
         &self,
         sources: &TransactionSources,
@@ -369,10 +491,19 @@ impl PublicKey {
                 }
 
                 found = true;
-                let Some(Signature::EcdsaSecp256k1(sig) | Signature::Ed25519(sig)) =
-                    &sig_pair.signature
-                else {
-                    return Err(Error::signature_verify("Unsupported transaction signature type"));
+                let sig = match &sig_pair.signature {
+                    Some(Signature::EcdsaSecp256k1(sig) | Signature::Ed25519(sig)) => sig,
+                    signature => {
+                        let algorithm =
+                            signature.as_ref().and_then(KeyAlgorithm::from_signature_protobuf);
+
+                        return Err(match algorithm {
+                            Some(algorithm) => Error::UnsupportedKeyAlgorithm(algorithm),
+                            None => {
+                                Error::signature_verify("Unsupported transaction signature type")
+                            }
+                        });
+                    }
                 };
 
                 self.verify(&signed_transaction.body_bytes, sig)?;
@@ -391,6 +522,8 @@ impl PublicKey {
     /// # Errors
     /// - [`Error::SignatureVerify`] if the private key associated with this public key did _not_ sign this transaction,
     ///   or the signature associated was invalid.
+    /// - [`Error::UnsupportedKeyAlgorithm`] if the transaction has a signature from this key's prefix
+    ///   of an algorithm this SDK doesn't support.
     pub fn verify_transaction<D: crate::transaction::TransactionExecute>(
         &self,
         transaction: &mut Transaction<D>,
@@ -408,6 +541,12 @@ impl PublicKey {
         self.verify_transaction_sources(sources)
     }
 
+    /// Returns the signature algorithm of this key.
+    #[must_use]
+    pub fn algorithm(&self) -> KeyAlgorithm {
+        self.kind().algorithm()
+    }
+
     #[must_use]
     pub(crate) fn kind(&self) -> super::KeyKind {
         match &self.0 {
@@ -452,12 +591,8 @@ impl FromProtobuf<services::Key> for PublicKey {
             Some(DelegatableContractId(_)) => Err(Error::from_protobuf(
                 "unexpected unsupported Delegatable Contract ID key in single key",
             )),
-            Some(Rsa3072(_)) => {
-                Err(Error::from_protobuf("unexpected unsupported RSA-3072 key in single key"))
-            }
-            Some(Ecdsa384(_)) => {
-                Err(Error::from_protobuf("unexpected unsupported ECDSA-384 key in single key"))
-            }
+            Some(Rsa3072(_)) => Err(Error::UnsupportedKeyAlgorithm(KeyAlgorithm::Rsa3072)),
+            Some(Ecdsa384(_)) => Err(Error::UnsupportedKeyAlgorithm(KeyAlgorithm::EcdsaP384)),
             Some(ThresholdKey(_)) => {
                 Err(Error::from_protobuf("unexpected threshold key as single key"))
             }
@@ -472,12 +607,7 @@ impl ToProtobuf for PublicKey {
     type Protobuf = services::Key;
 
     fn to_protobuf(&self) -> Self::Protobuf {
-        let key = match self.kind() {
-            super::KeyKind::Ed25519 => services::key::Key::Ed25519(self.to_bytes_raw()),
-            super::KeyKind::Ecdsa => services::key::Key::EcdsaSecp256k1(self.to_bytes_raw()),
-        };
-
-        Self::Protobuf { key: Some(key) }
+        Self::Protobuf { key: Some(self.kind().key_protobuf(self.to_bytes_raw())) }
     }
 }
 
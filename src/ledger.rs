@@ -0,0 +1,516 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Signing with the Hedera app on Ledger hardware wallets.
+//!
+//! Keys are derived on the device, at the path `m/44'/3030'/0'/0'/{index}'`, and are always Ed25519.
+//!
+//! Every request blocks until the device responds, which, for requests the user has to confirm on the device,
+//! can take a long time. In async code, make requests with [`tokio::task::spawn_blocking`].
+
+use std::collections::HashMap;
+use std::fmt;
+
+use parking_lot::Mutex;
+use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
+
+use crate::transaction::TransactionExecute;
+use crate::{
+    Error,
+    PublicKey,
+    Transaction,
+};
+
+const CLA: u8 = 0xe0;
+
+const INS_GET_PUBLIC_KEY: u8 = 0x02;
+const INS_SIGN_TRANSACTION: u8 = 0x04;
+
+/// Show the public key on the device, and wait for the user to confirm it.
+const P1_CONFIRM: u8 = 0x00;
+const P1_NO_CONFIRM: u8 = 0x01;
+
+const SW_OK: u16 = 0x9000;
+const SW_REJECTED: u16 = 0x6985;
+const SW_INS_NOT_SUPPORTED: u16 = 0x6d00;
+const SW_CLA_NOT_SUPPORTED: u16 = 0x6e00;
+const SW_APP_NOT_OPEN: u16 = 0x6511;
+const SW_LOCKED: u16 = 0x5515;
+
+/// The largest transaction body the Hedera app can sign (the APDU data limit minus the key index).
+const MAX_BODY_LEN: usize = 255 - 4;
+
+/// A connection to a Ledger device, over which APDUs are exchanged.
+///
+/// [`HidTransport`] connects to a device over USB,
+/// implement this to connect some other way, IE, to the Speculos emulator.
+pub trait LedgerTransport: Send + Sync {
+    /// Send the APDU `command` to the device, and return its response, including the trailing status word.
+    ///
+    /// # Errors
+    /// - [`Error::Ledger`] if the exchange fails.
+    fn exchange(&self, command: &[u8]) -> crate::Result<Vec<u8>>;
+}
+
+/// The Hedera app on a Ledger device.
+#[derive(Clone)]
+pub struct Ledger {
+    transport: Arc<dyn LedgerTransport>,
+}
+
+impl fmt::Debug for Ledger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ledger").finish_non_exhaustive()
+    }
+}
+
+impl Ledger {
+    /// Connect to the first Ledger device plugged in over USB.
+    ///
+    /// # Errors
+    /// - [`Error::Ledger`] if no Ledger device is plugged in, or it can't be opened.
+    pub fn connect() -> crate::Result<Self> {
+        Ok(Self::with_transport(HidTransport::open()?))
+    }
+
+    /// Create a `Ledger` that talks to the device over `transport`.
+    #[must_use]
+    pub fn with_transport(transport: impl LedgerTransport + 'static) -> Self {
+        Self { transport: Arc::new(transport).unsize(Coercion!(to dyn LedgerTransport)) }
+    }
+
+    /// Returns the public key at `index`, without involving the user.
+    ///
+    /// # Errors
+    /// - [`Error::Ledger`] if the request fails, IE, the Hedera app isn't open.
+    pub fn get_public_key(&self, index: u32) -> crate::Result<PublicKey> {
+        self.public_key(index, P1_NO_CONFIRM)
+    }
+
+    /// Returns the public key at `index`, after the user confirms it's the key shown on the device.
+    ///
+    /// Use this when the key is first registered, so that the user can check it wasn't tampered with.
+    ///
+    /// # Errors
+    /// - [`Error::LedgerRejected`] if the user rejects the key.
+    /// - [`Error::Ledger`] if the request fails, IE, the Hedera app isn't open.
+    pub fn confirm_public_key(&self, index: u32) -> crate::Result<PublicKey> {
+        self.public_key(index, P1_CONFIRM)
+    }
+
+    fn public_key(&self, index: u32, p1: u8) -> crate::Result<PublicKey> {
+        let response = self.exchange(INS_GET_PUBLIC_KEY, p1, &index.to_le_bytes())?;
+
+        PublicKey::from_bytes_ed25519(&response).map_err(|_| {
+            Error::ledger(format!("unexpected public key response of {} bytes", response.len()))
+        })
+    }
+
+    /// Sign the encoded transaction body `body_bytes` with the key at `index`.
+    ///
+    /// The device shows the transaction, and only signs it after the user confirms it.
+    ///
+    /// # Errors
+    /// - [`Error::LedgerRejected`] if the user rejects the transaction.
+    /// - [`Error::Ledger`] if the request fails,
+    ///   IE, the Hedera app isn't open, or doesn't support the transaction, or the transaction is too large.
+    pub fn sign(&self, index: u32, body_bytes: &[u8]) -> crate::Result<Vec<u8>> {
+        if body_bytes.len() > MAX_BODY_LEN {
+            return Err(Error::ledger(format!(
+                "transaction body of {} bytes is larger than the maximum of {MAX_BODY_LEN} bytes",
+                body_bytes.len()
+            )));
+        }
+
+        let mut data = Vec::with_capacity(4 + body_bytes.len());
+        data.extend_from_slice(&index.to_le_bytes());
+        data.extend_from_slice(body_bytes);
+
+        let signature = self.exchange(INS_SIGN_TRANSACTION, 0, &data)?;
+
+        if signature.len() != 64 {
+            return Err(Error::ledger(format!(
+                "unexpected signature response of {} bytes",
+                signature.len()
+            )));
+        }
+
+        Ok(signature)
+    }
+
+    /// Sign `transaction` with the key at `index`.
+    ///
+    /// The transaction is signed once for every node (and chunk) it may be submitted to,
+    /// and the user has to confirm each one, so freeze it with a single node to only confirm it once.
+    ///
+    /// If the transaction ID is regenerated while executing the transaction,
+    /// the new transaction is signed then, and has to be confirmed again.
+    ///
+    /// # Errors
+    /// - [`Error::LedgerRejected`] if the user rejects the transaction.
+    /// - [`Error::Ledger`] if the request fails, see [`sign`](Self::sign).
+    ///
+    /// # Panics
+    /// - If `!transaction.is_frozen()`.
+    pub fn sign_transaction<D: TransactionExecute>(
+        &self,
+        index: u32,
+        transaction: &mut Transaction<D>,
+    ) -> crate::Result<()> {
+        assert!(transaction.is_frozen(), "transaction must be frozen to sign with a Ledger");
+
+        let public_key = self.get_public_key(index)?;
+
+        let mut signatures = HashMap::new();
+
+        for signed in transaction.make_sources()?.signed_transactions() {
+            if !signatures.contains_key(&signed.body_bytes) {
+                signatures.insert(signed.body_bytes.clone(), self.sign(index, &signed.body_bytes)?);
+            }
+        }
+
+        let (_, signer) = self.signer_for(index, public_key);
+
+        transaction.try_sign_with(public_key, move |body_bytes| match signatures.get(body_bytes) {
+            Some(signature) => Ok(signature.clone()),
+            None => signer(body_bytes),
+        });
+
+        Ok(())
+    }
+
+    /// Returns the public key at `index`, and a function signing with it,
+    /// for [`Client::try_set_operator_with`](crate::Client::try_set_operator_with) and
+    /// [`Transaction::try_sign_with`].
+    ///
+    /// The function blocks until the user confirms each transaction on the device.
+    /// If signing fails, IE, with [`Error::LedgerRejected`], the failure is returned
+    /// by whatever signs the transaction, IE, [`Transaction::execute`].
+    ///
+    /// # Errors
+    /// - [`Error::Ledger`] if getting the public key fails, IE, the Hedera app isn't open.
+    pub fn signer(
+        &self,
+        index: u32,
+    ) -> crate::Result<(PublicKey, impl Fn(&[u8]) -> crate::Result<Vec<u8>> + Send + Sync + 'static)>
+    {
+        let public_key = self.get_public_key(index)?;
+
+        Ok(self.signer_for(index, public_key))
+    }
+
+    fn signer_for(
+        &self,
+        index: u32,
+        public_key: PublicKey,
+    ) -> (PublicKey, impl Fn(&[u8]) -> crate::Result<Vec<u8>> + Send + Sync + 'static) {
+        let ledger = self.clone();
+
+        let signer = move |body_bytes: &[u8]| ledger.sign(index, body_bytes);
+
+        (public_key, signer)
+    }
+
+    fn exchange(&self, ins: u8, p1: u8, data: &[u8]) -> crate::Result<Vec<u8>> {
+        // `data` is always less than 256 bytes, see `MAX_BODY_LEN`.
+        let mut command = Vec::with_capacity(5 + data.len());
+        command.extend_from_slice(&[CLA, ins, p1, 0, data.len() as u8]);
+        command.extend_from_slice(data);
+
+        let mut response = self.transport.exchange(&command)?;
+
+        if response.len() < 2 {
+            return Err(Error::ledger("response is missing the status word"));
+        }
+
+        let status = response.split_off(response.len() - 2);
+
+        match u16::from_be_bytes([status[0], status[1]]) {
+            SW_OK => Ok(response),
+            SW_REJECTED => Err(Error::LedgerRejected),
+            SW_LOCKED => Err(Error::ledger("the device is locked")),
+            SW_CLA_NOT_SUPPORTED | SW_INS_NOT_SUPPORTED | SW_APP_NOT_OPEN => {
+                Err(Error::ledger("the Hedera app isn't open"))
+            }
+            status => Err(Error::ledger(format!("request failed with status {status:#06x}"))),
+        }
+    }
+}
+
+const LEDGER_VENDOR_ID: u16 = 0x2c97;
+const LEDGER_USAGE_PAGE: u16 = 0xffa0;
+
+const HID_PACKET_SIZE: usize = 64;
+const HID_CHANNEL: u16 = 0x0101;
+const HID_TAG_APDU: u8 = 0x05;
+
+/// The size of the channel, tag, and sequence number at the start of every packet.
+const HID_HEADER_SIZE: usize = 5;
+
+/// A [`LedgerTransport`] for a Ledger device plugged in over USB.
+pub struct HidTransport {
+    device: Mutex<hidapi::HidDevice>,
+}
+
+impl fmt::Debug for HidTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HidTransport").finish_non_exhaustive()
+    }
+}
+
+impl HidTransport {
+    /// Open the first Ledger device plugged in over USB.
+    ///
+    /// # Errors
+    /// - [`Error::Ledger`] if no Ledger device is plugged in, or it can't be opened.
+    pub fn open() -> crate::Result<Self> {
+        let api = hidapi::HidApi::new().map_err(Error::ledger)?;
+
+        let info = api
+            .device_list()
+            .find(|it| {
+                it.vendor_id() == LEDGER_VENDOR_ID
+                    && (it.usage_page() == LEDGER_USAGE_PAGE || it.interface_number() == 0)
+            })
+            .ok_or_else(|| Error::ledger("no Ledger device found"))?;
+
+        let device = info.open_device(&api).map_err(Error::ledger)?;
+
+        Ok(Self { device: Mutex::new(device) })
+    }
+}
+
+impl LedgerTransport for HidTransport {
+    fn exchange(&self, command: &[u8]) -> crate::Result<Vec<u8>> {
+        let device = self.device.lock();
+
+        for packet in hid_packets(command) {
+            // hidapi expects the report ID first.
+            let mut report = [0; HID_PACKET_SIZE + 1];
+            report[1..].copy_from_slice(&packet);
+
+            device.write(&report).map_err(Error::ledger)?;
+        }
+
+        let mut response = HidResponse::default();
+
+        loop {
+            let mut packet = [0; HID_PACKET_SIZE];
+            let len = device.read(&mut packet).map_err(Error::ledger)?;
+
+            if let Some(response) = response.push(&packet[..len])? {
+                return Ok(response);
+            }
+        }
+    }
+}
+
+/// Split `command` into HID packets, the first of which starts with the length of `command`.
+fn hid_packets(command: &[u8]) -> Vec<[u8; HID_PACKET_SIZE]> {
+    let mut data = Vec::with_capacity(2 + command.len());
+    data.extend_from_slice(&(command.len() as u16).to_be_bytes());
+    data.extend_from_slice(command);
+
+    data.chunks(HID_PACKET_SIZE - HID_HEADER_SIZE)
+        .enumerate()
+        .map(|(sequence, chunk)| {
+            let mut packet = [0; HID_PACKET_SIZE];
+            packet[..2].copy_from_slice(&HID_CHANNEL.to_be_bytes());
+            packet[2] = HID_TAG_APDU;
+            packet[3..HID_HEADER_SIZE].copy_from_slice(&(sequence as u16).to_be_bytes());
+            packet[HID_HEADER_SIZE..][..chunk.len()].copy_from_slice(chunk);
+            packet
+        })
+        .collect()
+}
+
+/// Reassembles a response from HID packets.
+#[derive(Default)]
+struct HidResponse {
+    len: Option<usize>,
+    data: Vec<u8>,
+    sequence: u16,
+}
+
+impl HidResponse {
+    /// Returns the response, if `packet` completes it.
+    fn push(&mut self, packet: &[u8]) -> crate::Result<Option<Vec<u8>>> {
+        if packet.len() < HID_HEADER_SIZE
+            || packet[..2] != HID_CHANNEL.to_be_bytes()
+            || packet[2] != HID_TAG_APDU
+        {
+            return Err(Error::ledger("unexpected HID packet"));
+        }
+
+        let sequence = u16::from_be_bytes([packet[3], packet[4]]);
+
+        if sequence != self.sequence {
+            return Err(Error::ledger(format!(
+                "unexpected HID packet sequence number {sequence}, expected {}",
+                self.sequence
+            )));
+        }
+
+        self.sequence += 1;
+
+        let mut chunk = &packet[HID_HEADER_SIZE..];
+
+        let len = match self.len {
+            Some(len) => len,
+            None => {
+                if chunk.len() < 2 {
+                    return Err(Error::ledger("HID response is missing its length"));
+                }
+
+                let len = usize::from(u16::from_be_bytes([chunk[0], chunk[1]]));
+                chunk = &chunk[2..];
+
+                *self.len.insert(len)
+            }
+        };
+
+        let remaining = len - self.data.len();
+        self.data.extend_from_slice(&chunk[..remaining.min(chunk.len())]);
+
+        Ok((self.data.len() == len).then(|| std::mem::take(&mut self.data)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use parking_lot::Mutex;
+    use triomphe::Arc;
+
+    use super::{
+        hid_packets,
+        HidResponse,
+        Ledger,
+        LedgerTransport,
+        HID_PACKET_SIZE,
+    };
+    use crate::transaction::test_helpers::TEST_TX_ID;
+    use crate::{
+        AccountId,
+        Error,
+        PrivateKey,
+        TransferTransaction,
+    };
+
+    /// Responds to each command with the next of `responses`, and records the commands.
+    struct MockTransport {
+        commands: Arc<Mutex<Vec<Vec<u8>>>>,
+        responses: Mutex<Vec<Vec<u8>>>,
+    }
+
+    impl LedgerTransport for MockTransport {
+        fn exchange(&self, command: &[u8]) -> crate::Result<Vec<u8>> {
+            self.commands.lock().push(command.to_vec());
+            Ok(self.responses.lock().remove(0))
+        }
+    }
+
+    fn mock(responses: Vec<Vec<u8>>) -> (Ledger, Arc<Mutex<Vec<Vec<u8>>>>) {
+        let commands = Arc::default();
+
+        let transport =
+            MockTransport { commands: Arc::clone(&commands), responses: Mutex::new(responses) };
+
+        (Ledger::with_transport(transport), commands)
+    }
+
+    fn ok(data: &[u8]) -> Vec<u8> {
+        [data, &[0x90, 0x00]].concat()
+    }
+
+    #[test]
+    fn get_public_key() {
+        let key = PrivateKey::generate_ed25519().public_key();
+
+        let (ledger, commands) = mock(Vec::from([ok(&key.to_bytes_raw())]));
+
+        assert_eq!(ledger.get_public_key(7).unwrap(), key);
+        assert_eq!(commands.lock()[0], [0xe0, 0x02, 0x01, 0x00, 0x04, 7, 0, 0, 0]);
+    }
+
+    #[test]
+    fn sign() {
+        let (ledger, commands) = mock(Vec::from([ok(&[1; 64])]));
+
+        assert_eq!(ledger.sign(1, &[0xaa, 0xbb]).unwrap(), [1; 64]);
+        assert_eq!(commands.lock()[0], [0xe0, 0x04, 0x00, 0x00, 0x06, 1, 0, 0, 0, 0xaa, 0xbb]);
+    }
+
+    #[test]
+    fn sign_rejected() {
+        let (ledger, _) = mock(Vec::from([Vec::from([0x69, 0x85])]));
+
+        assert_matches!(ledger.sign(0, &[0xaa]), Err(Error::LedgerRejected));
+    }
+
+    #[test]
+    fn signer_rejected() {
+        let key = PrivateKey::generate_ed25519().public_key();
+
+        let (ledger, _) = mock(Vec::from([ok(&key.to_bytes_raw()), Vec::from([0x69, 0x85])]));
+
+        let (public_key, signer) = ledger.signer(0).unwrap();
+
+        let mut tx = TransferTransaction::new();
+        tx.transaction_id(TEST_TX_ID)
+            .node_account_ids([AccountId::new(0, 0, 3)])
+            .freeze()
+            .unwrap()
+            .try_sign_with(public_key, signer);
+
+        assert_matches!(tx.to_bytes(), Err(Error::LedgerRejected));
+    }
+
+    #[test]
+    fn sign_too_large() {
+        let (ledger, commands) = mock(Vec::new());
+
+        assert_matches!(ledger.sign(0, &[0; 252]), Err(Error::Ledger(_)));
+        assert!(commands.lock().is_empty());
+    }
+
+    #[test]
+    fn app_not_open() {
+        let (ledger, _) = mock(Vec::from([Vec::from([0x6e, 0x00])]));
+
+        assert_matches!(ledger.get_public_key(0), Err(Error::Ledger(_)));
+    }
+
+    #[test]
+    fn hid_round_trip() {
+        let command: Vec<u8> = (0..=200).collect();
+
+        let packets = hid_packets(&command);
+
+        // 2 length bytes + 201 command bytes, 59 bytes per packet.
+        assert_eq!(packets.len(), 4);
+        assert_eq!(packets[0][..7], [0x01, 0x01, 0x05, 0x00, 0x00, 0x00, 201]);
+        assert_eq!(packets[3][3..5], [0x00, 0x03]);
+
+        let mut response = HidResponse::default();
+
+        for packet in &packets[..3] {
+            assert_matches!(response.push(packet), Ok(None));
+        }
+
+        assert_eq!(response.push(&packets[3]).unwrap().unwrap(), command);
+    }
+
+    #[test]
+    fn hid_out_of_order() {
+        let packets = hid_packets(&[0; 100]);
+
+        let mut response = HidResponse::default();
+
+        assert_matches!(response.push(&packets[1]), Err(Error::Ledger(_)));
+        assert_matches!(response.push(&[0; HID_PACKET_SIZE]), Err(Error::Ledger(_)));
+    }
+}
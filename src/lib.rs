@@ -97,13 +97,17 @@
 )]
 #![allow(clippy::enum_glob_use, clippy::enum_variant_names)]
 #[macro_use]
-mod protobuf;
+pub mod protobuf;
 
 mod account;
 mod address_book;
 
 mod batch_transaction;
+#[cfg(feature = "unstable")]
+pub mod block_node;
+mod chunked_transaction_response;
 mod client;
+mod clock;
 mod contract;
 mod custom_fee_limit;
 mod custom_fixed_fee;
@@ -113,12 +117,20 @@ mod error;
 mod ethereum;
 mod exchange_rates;
 mod execute;
+mod execution_plan;
+pub mod expiry;
 mod fee_schedules;
 mod file;
 mod hbar;
 mod key;
+#[cfg(feature = "ledger")]
+pub mod ledger;
 mod ledger_id;
+mod logger;
+mod memo_template;
 mod mirror_query;
+#[cfg(feature = "mirror-rest")]
+mod mirror_rest;
 #[cfg(feature = "mnemonic")]
 mod mnemonic;
 mod network_version_info;
@@ -131,12 +143,23 @@ mod pending_airdrop_record;
 mod ping_query;
 mod prng_transaction;
 mod query;
+pub mod reconcile;
+mod refund;
 mod retry;
+mod retry_event;
 mod schedule;
 mod semantic_version;
+#[cfg(feature = "serde")]
+mod serde_with;
 mod service_endpoint;
+mod signature_pair;
 mod signer;
+#[cfg(feature = "simulation")]
+pub mod simulation;
 mod staked_id;
+mod state_proof;
+#[cfg(feature = "mirror-rest")]
+mod state_proof_query;
 mod staking_info;
 mod system;
 mod token;
@@ -144,13 +167,17 @@ mod topic;
 mod transaction;
 mod transaction_hash;
 mod transaction_id;
+mod transaction_journal;
 mod transaction_receipt;
 mod transaction_receipt_query;
 mod transaction_record;
+mod transaction_record_export;
 mod transaction_record_query;
 mod transaction_response;
 mod transfer;
 mod transfer_transaction;
+#[cfg(feature = "walletconnect")]
+pub mod walletconnect;
 
 pub use account::{
     account_info_flow,
@@ -166,7 +193,17 @@ pub use account::{
     AccountRecordsQuery,
     AccountUpdateTransaction,
     AllProxyStakers,
+    MultisigAccount,
     ProxyStaker,
+    SigningChecklist,
+};
+#[cfg(feature = "mirror-rest")]
+pub use account::{
+    AccountTokenBalancesQuery,
+    AllowanceManager,
+    StakingReward,
+    StakingRewardsQuery,
+    TokenBalance,
 };
 pub use address_book::{
     NodeCreateTransaction,
@@ -174,8 +211,23 @@ pub use address_book::{
     NodeUpdateTransaction,
 };
 pub use batch_transaction::BatchTransaction;
-pub use client::Client;
+pub use chunked_transaction_response::ChunkedTransactionResponse;
+pub use client::{
+    BalanceWatchdog,
+    BalanceWatchdogHandle,
+    Client,
+    Environment,
+    NetworkProblem,
+    NodeStats,
+};
 pub(crate) use client::Operator;
+#[cfg(feature = "test-utils")]
+pub use clock::MockClock;
+pub use clock::{
+    Clock,
+    SystemClock,
+};
+#[cfg(feature = "contracts")]
 pub use contract::{
     ContractBytecodeQuery,
     ContractCallQuery,
@@ -184,20 +236,42 @@ pub use contract::{
     ContractDeleteTransaction,
     ContractExecuteTransaction,
     ContractFunctionParameters,
-    ContractFunctionResult,
-    ContractId,
     ContractInfo,
     ContractInfoQuery,
+    ContractUpdateTransaction,
+    HederaAccountService,
+    HederaTokenService,
+    MintTokenResult,
+};
+#[cfg(all(feature = "contracts", feature = "mirror-rest"))]
+pub use contract::{
+    ContractCallFrame,
+    ContractCallResult,
+    ContractOpcode,
+    ContractStateQuery,
+    ContractStateSlot,
+    ContractTrace,
+    ContractTraceQuery,
+};
+pub use contract::{
+    ContractFunctionResult,
+    ContractId,
     ContractLogInfo,
     ContractNonceInfo,
-    ContractUpdateTransaction,
+    ContractResultDecoder,
     DelegateContractId,
+    FromContractResult,
+    FromContractValue,
 };
 pub use custom_fixed_fee::CustomFixedFee;
-pub use entity_id::EntityId;
 pub(crate) use entity_id::ValidateChecksums;
+pub use entity_id::{
+    EntityId,
+    EntityIdRange,
+};
 pub use error::{
     Error,
+    NodeError,
     Result,
 };
 #[cfg(feature = "mnemonic")]
@@ -205,18 +279,20 @@ pub use error::{
     MnemonicEntropyError,
     MnemonicParseError,
 };
+pub use ethereum::EvmAddress;
+#[cfg(feature = "contracts")]
 pub use ethereum::{
     Eip1559EthereumData,
     EthereumData,
     EthereumFlow,
     EthereumTransaction,
-    EvmAddress,
     LegacyEthereumData,
 };
 pub use exchange_rates::{
     ExchangeRate,
     ExchangeRates,
 };
+pub use execution_plan::ExecutionPlan;
 pub use fee_schedules::{
     FeeComponents,
     FeeData,
@@ -226,13 +302,14 @@ pub use fee_schedules::{
     RequestType,
     TransactionFeeSchedule,
 };
+pub use file::FileId;
+#[cfg(feature = "files")]
 pub use file::{
     FileAppendTransaction,
     FileContentsQuery,
     FileContentsResponse,
     FileCreateTransaction,
     FileDeleteTransaction,
-    FileId,
     FileInfo,
     FileInfoQuery,
     FileUpdateTransaction,
@@ -242,19 +319,35 @@ pub use hbar::{
     HbarUnit,
     Tinybar,
 };
+/// The protobuf types used by [`ToProtobuf`] and [`FromProtobuf`].
+#[cfg(feature = "protobuf")]
+pub use hedera_proto as proto;
 pub use hedera_proto::services::ResponseCodeEnum as Status;
 pub use key::{
     Key,
+    KeyAlgorithm,
+    KeyFormat,
     KeyList,
     PrivateKey,
     PublicKey,
 };
 pub use ledger_id::LedgerId;
+#[cfg(feature = "tracing")]
+pub use logger::TracingLogger;
+pub use logger::{
+    DefaultLogger,
+    LogLevel,
+    LogSubsystem,
+    Logger,
+};
+pub use memo_template::MemoTemplate;
 pub use mirror_query::{
     AnyMirrorQuery,
     AnyMirrorQueryResponse,
     MirrorQuery,
 };
+#[cfg(feature = "mirror-rest")]
+pub use mirror_rest::MirrorStream;
 #[cfg(feature = "mnemonic")]
 pub use mnemonic::Mnemonic;
 pub use network_version_info::NetworkVersionInfo;
@@ -267,6 +360,13 @@ pub(crate) use node_address_book_query::NodeAddressBookQueryData;
 pub use pending_airdrop_id::PendingAirdropId;
 pub use pending_airdrop_record::PendingAirdropRecord;
 pub use prng_transaction::PrngTransaction;
+pub use protobuf::FromProtobufError;
+#[cfg(feature = "protobuf")]
+pub use protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+#[cfg(not(feature = "protobuf"))]
 pub(crate) use protobuf::{
     FromProtobuf,
     ToProtobuf,
@@ -275,19 +375,29 @@ pub use query::{
     AnyQuery,
     AnyQueryResponse,
     Query,
+    ResponseMismatch,
 };
 pub(crate) use retry::retry;
+pub use retry_event::RetryEvent;
+pub use schedule::ScheduleId;
+#[cfg(feature = "schedules")]
 pub use schedule::{
     ScheduleCreateTransaction,
     ScheduleDeleteTransaction,
-    ScheduleId,
     ScheduleInfo,
     ScheduleInfoQuery,
     ScheduleSignTransaction,
 };
 pub use semantic_version::SemanticVersion;
 pub use service_endpoint::ServiceEndpoint;
+pub use signature_pair::{
+    sign_body_bytes,
+    SignaturePair,
+};
 pub use staking_info::StakingInfo;
+pub use state_proof::StateProof;
+#[cfg(feature = "mirror-rest")]
+pub use state_proof_query::StateProofQuery;
 pub use system::{
     FreezeTransaction,
     FreezeType,
@@ -307,9 +417,29 @@ pub use token::{
     NftId,
     RoyaltyFee,
     RoyaltyFeeData,
+    TokenAmount,
+    TokenAssociation,
+    TokenId,
+    TokenKeyValidation,
+    TokenNftTransfer,
+    TokenSupplyType,
+    TokenType,
+};
+#[cfg(all(feature = "tokens", feature = "mirror-rest"))]
+pub use token::{
+    TokenAccountState,
+    TokenAdminAction,
+    TokenAdminApplyReport,
+    TokenAdminOutcome,
+    TokenAdminReport,
+    TokenAdminState,
+    TokenAdminTarget,
+    TokenNftInfoBatchQuery,
+};
+#[cfg(feature = "tokens")]
+pub use token::{
     TokenAirdropTransaction,
     TokenAssociateTransaction,
-    TokenAssociation,
     TokenBurnTransaction,
     TokenCancelAirdropTransaction,
     TokenClaimAirdropTransaction,
@@ -319,46 +449,61 @@ pub use token::{
     TokenFeeScheduleUpdateTransaction,
     TokenFreezeTransaction,
     TokenGrantKycTransaction,
-    TokenId,
     TokenInfo,
     TokenInfoQuery,
-    TokenKeyValidation,
     TokenMintTransaction,
     TokenNftInfo,
     TokenNftInfoQuery,
-    TokenNftTransfer,
     TokenPauseTransaction,
     TokenRejectFlow,
     TokenRejectTransaction,
     TokenRevokeKycTransaction,
-    TokenSupplyType,
-    TokenType,
     TokenUnfreezeTransaction,
     TokenUnpauseTransaction,
     TokenUpdateNftsTransaction,
     TokenUpdateTransaction,
     TokenWipeTransaction,
 };
+pub use tokio_util::sync::CancellationToken;
+#[cfg(feature = "hcs1")]
+pub use topic::hcs1;
+pub use topic::TopicId;
+#[cfg(feature = "topics")]
 pub use topic::{
+    SubscriptionMetrics,
+    SubscriptionOverflowPolicy,
     TopicCreateTransaction,
     TopicDeleteTransaction,
-    TopicId,
     TopicInfo,
     TopicInfoQuery,
     TopicMessage,
+    TopicMessageEnvelope,
     TopicMessageQuery,
     TopicMessageSubmitTransaction,
+    TopicMessageSubscription,
+    TopicSubscriber,
     TopicUpdateTransaction,
 };
 pub use transaction::{
     AnyTransaction,
+    DescribedNftTransfer,
+    DescribedTransfer,
     Transaction,
+    TransactionDescription,
+    ValidationProblem,
 };
 pub use transaction_hash::TransactionHash;
 pub use transaction_id::TransactionId;
+pub use transaction_journal::{
+    FileTransactionJournal,
+    InMemoryTransactionJournal,
+    JournalEntry,
+    TransactionJournal,
+};
 pub use transaction_receipt::TransactionReceipt;
 pub use transaction_receipt_query::TransactionReceiptQuery;
 pub use transaction_record::TransactionRecord;
+pub use transaction_record_export::TransactionRecordCsvWriter;
 pub use transaction_record_query::TransactionRecordQuery;
 pub(crate) use transaction_record_query::TransactionRecordQueryData;
 pub use transaction_response::TransactionResponse;
@@ -0,0 +1,352 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+use std::sync::atomic::{
+    AtomicBool,
+    AtomicU8,
+    Ordering,
+};
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+/// The severity of a log message, and the minimum severity a subsystem logs at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum LogLevel {
+    /// Very verbose messages, IE, every request sent.
+    Trace = 0,
+
+    /// Messages useful for debugging.
+    Debug = 1,
+
+    /// Messages about notable, but expected, events.
+    Info = 2,
+
+    /// Messages about unexpected events the SDK recovered from.
+    Warn = 3,
+
+    /// Messages about failures.
+    Error = 4,
+
+    /// Nothing is logged.
+    ///
+    /// Only meaningful as a minimum level; messages are never logged at this level.
+    Silent = 5,
+}
+
+impl LogLevel {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Trace,
+            1 => Self::Debug,
+            2 => Self::Info,
+            3 => Self::Warn,
+            4 => Self::Error,
+            _ => Self::Silent,
+        }
+    }
+
+    fn to_log_level(self) -> Option<log::Level> {
+        match self {
+            Self::Trace => Some(log::Level::Trace),
+            Self::Debug => Some(log::Level::Debug),
+            Self::Info => Some(log::Level::Info),
+            Self::Warn => Some(log::Level::Warn),
+            Self::Error => Some(log::Level::Error),
+            Self::Silent => None,
+        }
+    }
+}
+
+/// The part of the SDK a log message comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LogSubsystem {
+    /// Node health, address book updates, and network configuration.
+    Network,
+
+    /// Executing transactions and queries, including retries and backoff.
+    Execution,
+
+    /// Requests to the mirror network.
+    Mirror,
+
+    /// Keys and signatures.
+    Crypto,
+}
+
+impl LogSubsystem {
+    const ALL: [Self; 4] = [Self::Network, Self::Execution, Self::Mirror, Self::Crypto];
+
+    /// Returns the `log` target messages of this subsystem use with [`DefaultLogger`],
+    /// which is also the `tracing` target with `TracingLogger`.
+    #[must_use]
+    pub fn target(self) -> &'static str {
+        match self {
+            Self::Network => "hedera::network",
+            Self::Execution => "hedera::execution",
+            Self::Mirror => "hedera::mirror",
+            Self::Crypto => "hedera::crypto",
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Self::Network => 0,
+            Self::Execution => 1,
+            Self::Mirror => 2,
+            Self::Crypto => 3,
+        }
+    }
+}
+
+/// Receives the log messages of a [`Client`](crate::Client).
+///
+/// Messages are only passed to the logger if they're at or above the level set for their subsystem,
+/// see [`Client::set_log_level`](crate::Client::set_log_level).
+pub trait Logger: Send + Sync {
+    /// Log `message`, from `subsystem`, at `level`.
+    fn log(&self, subsystem: LogSubsystem, level: LogLevel, message: fmt::Arguments<'_>);
+}
+
+/// The default [`Logger`], which forwards messages to the [`log`] crate,
+/// with the [`target`](LogSubsystem::target) of their subsystem.
+///
+/// `tracing` users can pick these up with `tracing-log`, or use `TracingLogger` with the `tracing` feature.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultLogger;
+
+impl Logger for DefaultLogger {
+    fn log(&self, subsystem: LogSubsystem, level: LogLevel, message: fmt::Arguments<'_>) {
+        if let Some(level) = level.to_log_level() {
+            log::log!(target: subsystem.target(), level, "{message}");
+        }
+    }
+}
+
+/// A [`Logger`] that emits messages as [`tracing`] events,
+/// with the [`target`](LogSubsystem::target) of their subsystem.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TracingLogger;
+
+#[cfg(feature = "tracing")]
+impl Logger for TracingLogger {
+    fn log(&self, subsystem: LogSubsystem, level: LogLevel, message: fmt::Arguments<'_>) {
+        // `tracing` puts the target and level in a static callsite, so both have to be constants.
+        macro_rules! event {
+            ($target:literal) => {
+                match level {
+                    LogLevel::Trace => tracing::trace!(target: $target, "{message}"),
+                    LogLevel::Debug => tracing::debug!(target: $target, "{message}"),
+                    LogLevel::Info => tracing::info!(target: $target, "{message}"),
+                    LogLevel::Warn => tracing::warn!(target: $target, "{message}"),
+                    LogLevel::Error => tracing::error!(target: $target, "{message}"),
+                    LogLevel::Silent => {}
+                }
+            };
+        }
+
+        // keep in sync with `LogSubsystem::target`.
+        match subsystem {
+            LogSubsystem::Network => event!("hedera::network"),
+            LogSubsystem::Execution => event!("hedera::execution"),
+            LogSubsystem::Mirror => event!("hedera::mirror"),
+            LogSubsystem::Crypto => event!("hedera::crypto"),
+        }
+    }
+}
+
+/// A client's logger, and the level of each subsystem.
+pub(crate) struct ClientLogger {
+    logger: RwLock<Arc<dyn Logger>>,
+    levels: [AtomicU8; LogSubsystem::ALL.len()],
+    // separate from the levels so that turning silent mode off restores them.
+    silent: AtomicBool,
+}
+
+impl Default for ClientLogger {
+    fn default() -> Self {
+        // everything is passed along by default, `log` does its own filtering.
+        Self {
+            logger: RwLock::new(Arc::new(DefaultLogger)),
+            levels: LogSubsystem::ALL.map(|_| AtomicU8::new(LogLevel::Trace as u8)),
+            silent: AtomicBool::new(false),
+        }
+    }
+}
+
+impl ClientLogger {
+    pub(crate) fn set_logger(&self, logger: Arc<dyn Logger>) {
+        *self.logger.write() = logger;
+    }
+
+    pub(crate) fn level(&self, subsystem: LogSubsystem) -> LogLevel {
+        LogLevel::from_u8(self.levels[subsystem.index()].load(Ordering::Relaxed))
+    }
+
+    pub(crate) fn set_level(&self, subsystem: LogSubsystem, level: LogLevel) {
+        self.levels[subsystem.index()].store(level as u8, Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_all_levels(&self, level: LogLevel) {
+        for subsystem in LogSubsystem::ALL {
+            self.set_level(subsystem, level);
+        }
+    }
+
+    pub(crate) fn is_silent(&self) -> bool {
+        self.silent.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_silent(&self, silent: bool) {
+        self.silent.store(silent, Ordering::Relaxed);
+    }
+
+    pub(crate) fn enabled(&self, subsystem: LogSubsystem, level: LogLevel) -> bool {
+        !self.is_silent() && level != LogLevel::Silent && level >= self.level(subsystem)
+    }
+
+    pub(crate) fn log(
+        &self,
+        subsystem: LogSubsystem,
+        level: LogLevel,
+        message: fmt::Arguments<'_>,
+    ) {
+        if self.enabled(subsystem, level) {
+            // clone the logger out so that a logger that logs (or sets the logger) doesn't deadlock.
+            let logger = Arc::clone(&self.logger.read());
+            logger.log(subsystem, level, message);
+        }
+    }
+}
+
+/// Logs a message to a [`ClientLogger`], only formatting it if the subsystem is enabled at that level.
+///
+/// `client_log!(logger, Execution, Warn, "message {arg}")`
+macro_rules! client_log {
+    ($logger:expr, $subsystem:ident, $level:ident, $($arg:tt)+) => {{
+        let logger: &$crate::logger::ClientLogger = &$logger;
+        let subsystem = $crate::LogSubsystem::$subsystem;
+        let level = $crate::LogLevel::$level;
+
+        if logger.enabled(subsystem, level) {
+            logger.log(subsystem, level, format_args!($($arg)+));
+        }
+    }};
+}
+
+pub(crate) use client_log;
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+    use std::sync::Arc;
+
+    use parking_lot::Mutex;
+
+    use super::{
+        client_log,
+        ClientLogger,
+        LogLevel,
+        LogSubsystem,
+        Logger,
+    };
+    use crate::{
+        AccountId,
+        Client,
+        PrivateKey,
+    };
+
+    #[derive(Default)]
+    struct RecordingLogger(Mutex<Vec<(LogSubsystem, LogLevel, String)>>);
+
+    impl Logger for RecordingLogger {
+        fn log(&self, subsystem: LogSubsystem, level: LogLevel, message: fmt::Arguments<'_>) {
+            self.0.lock().push((subsystem, level, message.to_string()));
+        }
+    }
+
+    impl Logger for Arc<RecordingLogger> {
+        fn log(&self, subsystem: LogSubsystem, level: LogLevel, message: fmt::Arguments<'_>) {
+            RecordingLogger::log(self, subsystem, level, message);
+        }
+    }
+
+    #[test]
+    fn levels_per_subsystem() {
+        let recorder = Arc::new(RecordingLogger::default());
+        let logger = ClientLogger::default();
+        logger.set_logger(recorder.clone());
+        logger.set_level(LogSubsystem::Execution, LogLevel::Warn);
+
+        client_log!(logger, Execution, Debug, "hidden");
+        client_log!(logger, Execution, Error, "shown {}", 1);
+        client_log!(logger, Network, Debug, "shown {}", 2);
+
+        assert_eq!(
+            *recorder.0.lock(),
+            [
+                (LogSubsystem::Execution, LogLevel::Error, "shown 1".to_owned()),
+                (LogSubsystem::Network, LogLevel::Debug, "shown 2".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn silent() {
+        let recorder = Arc::new(RecordingLogger::default());
+        let logger = ClientLogger::default();
+        logger.set_logger(recorder.clone());
+        logger.set_all_levels(LogLevel::Silent);
+
+        client_log!(logger, Execution, Error, "hidden");
+        client_log!(logger, Mirror, Error, "hidden");
+
+        assert!(recorder.0.lock().is_empty());
+        assert_eq!(logger.level(LogSubsystem::Crypto), LogLevel::Silent);
+    }
+
+    #[test]
+    fn silent_mode_keeps_levels() {
+        let recorder = Arc::new(RecordingLogger::default());
+        let logger = ClientLogger::default();
+        logger.set_logger(recorder.clone());
+        logger.set_level(LogSubsystem::Mirror, LogLevel::Info);
+
+        logger.set_silent(true);
+        client_log!(logger, Mirror, Error, "hidden");
+
+        logger.set_silent(false);
+        client_log!(logger, Mirror, Debug, "hidden");
+        client_log!(logger, Mirror, Info, "shown");
+
+        assert_eq!(
+            *recorder.0.lock(),
+            [(LogSubsystem::Mirror, LogLevel::Info, "shown".to_owned())]
+        );
+    }
+
+    #[tokio::test]
+    async fn operator_logged_to_crypto() {
+        let recorder = Arc::new(RecordingLogger::default());
+        let client = Client::for_testnet();
+        client.set_logger(Arc::clone(&recorder));
+
+        let key = PrivateKey::generate_ed25519();
+        client.set_operator(AccountId::new(0, 0, 3), key.clone());
+
+        let crypto: Vec<_> =
+            recorder.0.lock().iter().filter(|it| it.0 == LogSubsystem::Crypto).cloned().collect();
+
+        assert_eq!(
+            crypto,
+            [(
+                LogSubsystem::Crypto,
+                LogLevel::Debug,
+                format!("Operator set to 0.0.3 with public key {}", key.public_key())
+            )]
+        );
+    }
+}
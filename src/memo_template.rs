@@ -0,0 +1,294 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::fmt::{
+    self,
+    Write,
+};
+use std::str::FromStr;
+
+use rand::Rng;
+
+use crate::transaction::MAX_MEMO_LEN;
+use crate::{
+    Error,
+    TransactionId,
+};
+
+/// A template for the memo of transactions that don't set one, IE, `"{app}-{version}-{uuid}"`.
+///
+/// Set on a client with [`Client::set_memo_template`](crate::Client::set_memo_template),
+/// it's rendered when a transaction is frozen, if the transaction doesn't have a memo of its own.
+///
+/// A template is text with `{name}` placeholders (use `{{` and `}}` for literal braces).
+/// Each placeholder is replaced by, in order of preference:
+/// 1. a value returned by the client's [memo values hook](crate::Client::set_memo_values_hook), for request-scoped values.
+/// 2. a value set on the template with [`value`](Self::value).
+/// 3. a built-in value:
+///    - `{uuid}`: a random (version 4) UUID, different for every transaction.
+///    - `{transaction_id}`: the ID of the transaction.
+///
+/// Placeholders without a value are replaced with nothing.
+/// The network rejects memos with NUL bytes, so they're removed from the rendered memo.
+/// Rendered memos longer than the network allows (100 bytes) are truncated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoTemplate {
+    segments: Vec<Segment>,
+    values: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Placeholder(String),
+}
+
+impl MemoTemplate {
+    /// Parses a memo template.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `template` has an unclosed placeholder, or an unmatched `}`.
+    pub fn new(template: &str) -> crate::Result<Self> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars();
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                '{' if chars.as_str().starts_with('{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.as_str().starts_with('}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let rest = chars.as_str();
+                    let Some(end) = rest.find('}') else {
+                        return Err(Error::basic_parse(format!(
+                            "unclosed placeholder in memo template `{template}`"
+                        )));
+                    };
+
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                    }
+
+                    segments.push(Segment::Placeholder(rest[..end].trim().to_owned()));
+                    chars = rest[end + 1..].chars();
+                }
+                '}' => {
+                    return Err(Error::basic_parse(format!(
+                        "unmatched `}}` in memo template `{template}`"
+                    )));
+                }
+                _ => literal.push(ch),
+            }
+        }
+
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+
+        Ok(Self { segments, values: HashMap::new() })
+    }
+
+    /// Sets the value of the placeholder `name`, IE, the name and version of the application.
+    pub fn value(&mut self, name: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        self.values.insert(name.into(), value.into());
+        self
+    }
+
+    /// Returns the value set for the placeholder `name`, if any.
+    #[must_use]
+    pub fn get_value(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    /// Renders the memo for the transaction with the given ID.
+    pub(crate) fn render(
+        &self,
+        transaction_id: &TransactionId,
+        request_values: &HashMap<String, String>,
+    ) -> String {
+        let mut memo = String::new();
+
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(it) => memo.push_str(it),
+                Segment::Placeholder(name) => {
+                    if let Some(value) = request_values.get(name).or_else(|| self.values.get(name))
+                    {
+                        memo.push_str(value);
+                        continue;
+                    }
+
+                    match name.as_str() {
+                        "uuid" => write_uuid(&mut memo),
+                        "transaction_id" => {
+                            let _ = write!(memo, "{transaction_id}");
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        // values are often request-scoped (IE, from headers), so they can't be trusted not to contain NULs.
+        memo.retain(|ch| ch != '\0');
+
+        truncate(&mut memo, MAX_MEMO_LEN);
+
+        memo
+    }
+}
+
+impl FromStr for MemoTemplate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl fmt::Display for MemoTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(it) => f.write_str(&it.replace('{', "{{").replace('}', "}}"))?,
+                Segment::Placeholder(name) => write!(f, "{{{name}}}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes a random (version 4) UUID.
+fn write_uuid(out: &mut String) {
+    let mut bytes: [u8; 16] = rand::thread_rng().gen();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let hex = hex::encode(bytes);
+
+    let _ = write!(
+        out,
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    );
+}
+
+/// Truncates `s` to at most `max_len` bytes, without splitting a character.
+fn truncate(s: &mut String, max_len: usize) {
+    if s.len() <= max_len {
+        return;
+    }
+
+    let mut len = max_len;
+    while !s.is_char_boundary(len) {
+        len -= 1;
+    }
+
+    s.truncate(len);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::MemoTemplate;
+    use crate::{
+        AccountId,
+        TransactionId,
+    };
+
+    fn transaction_id() -> TransactionId {
+        TransactionId {
+            account_id: AccountId::new(0, 0, 5005),
+            valid_start: time::OffsetDateTime::from_unix_timestamp(1_554_158_542).unwrap(),
+            nonce: None,
+            scheduled: false,
+        }
+    }
+
+    #[test]
+    fn render() {
+        let mut template = MemoTemplate::new("{app}-{version}:{transaction_id}").unwrap();
+        template.value("app", "payments").value("version", "1.2.0");
+
+        assert_eq!(
+            template.render(&transaction_id(), &HashMap::new()),
+            "payments-1.2.0:0.0.5005@1554158542.0"
+        );
+    }
+
+    #[test]
+    fn render_request_values() {
+        let mut template = MemoTemplate::new("{app} trace={trace_id}").unwrap();
+        template.value("app", "payments").value("trace_id", "none");
+
+        let request_values = HashMap::from([("trace_id".to_owned(), "4bf92f35".to_owned())]);
+
+        assert_eq!(template.render(&transaction_id(), &request_values), "payments trace=4bf92f35");
+    }
+
+    #[test]
+    fn render_uuid() {
+        let template = MemoTemplate::new("{uuid}").unwrap();
+
+        let first = template.render(&transaction_id(), &HashMap::new());
+        let second = template.render(&transaction_id(), &HashMap::new());
+
+        assert_eq!(first.len(), 36);
+        assert_eq!(&first[14..15], "4");
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn render_missing_value() {
+        let template = MemoTemplate::new("a{missing}b").unwrap();
+
+        assert_eq!(template.render(&transaction_id(), &HashMap::new()), "ab");
+    }
+
+    #[test]
+    fn render_truncates() {
+        let mut template = MemoTemplate::new("{long}").unwrap();
+        template.value("long", "é".repeat(60));
+
+        let memo = template.render(&transaction_id(), &HashMap::new());
+
+        assert_eq!(memo.len(), 100);
+        assert_eq!(memo, "é".repeat(50));
+    }
+
+    #[test]
+    fn render_strips_nul() {
+        let mut template = MemoTemplate::new("{app}\0-{trace_id}").unwrap();
+        template.value("app", "pay\0ments");
+
+        let request_values = HashMap::from([("trace_id".to_owned(), "\04bf92f35\0".to_owned())]);
+
+        assert_eq!(template.render(&transaction_id(), &request_values), "payments-4bf92f35");
+    }
+
+    #[test]
+    fn escapes() {
+        let template = MemoTemplate::new("{{literal}} {name}").unwrap();
+
+        assert_eq!(template.render(&transaction_id(), &HashMap::new()), "{literal} ");
+        assert_eq!(template.to_string(), "{{literal}} {name}");
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(MemoTemplate::new("{unclosed").is_err());
+        assert!(MemoTemplate::new("unmatched}").is_err());
+    }
+}
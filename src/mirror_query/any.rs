@@ -5,12 +5,14 @@ use futures_core::stream::BoxStream;
 use futures_util::TryStreamExt;
 
 use super::subscribe::MirrorQueryExecute;
+#[cfg(feature = "topics")]
 use crate::topic::TopicMessageQueryData;
+#[cfg(feature = "topics")]
+use crate::TopicMessage;
 use crate::{
     MirrorQuery,
     NodeAddress,
     NodeAddressBookQueryData,
-    TopicMessage,
 };
 
 /// Represents any possible query to the mirror network.
@@ -19,12 +21,14 @@ pub type AnyMirrorQuery = MirrorQuery<AnyMirrorQueryData>;
 #[derive(Debug, Clone)]
 pub enum AnyMirrorQueryData {
     NodeAddressBook(NodeAddressBookQueryData),
+    #[cfg(feature = "topics")]
     TopicMessage(TopicMessageQueryData),
 }
 
 #[derive(Debug, Clone)]
 pub enum AnyMirrorQueryMessage {
     NodeAddressBook(NodeAddress),
+    #[cfg(feature = "topics")]
     TopicMessage(TopicMessage),
 }
 
@@ -33,6 +37,7 @@ pub enum AnyMirrorQueryResponse {
     /// Response for `AnyMirrorQuery::NodeAddressBook`.
     NodeAddressBook(<NodeAddressBookQueryData as MirrorQueryExecute>::Response),
     /// Response for `AnyMirrorQuery::TopicMessage`.
+    #[cfg(feature = "topics")]
     TopicMessage(<TopicMessageQueryData as MirrorQueryExecute>::Response),
 }
 
@@ -60,6 +65,7 @@ impl MirrorQueryExecute for AnyMirrorQueryData {
                 it.subscribe_with_optional_timeout(params, client, timeout)
                     .map_ok(Self::Item::from),
             ),
+            #[cfg(feature = "topics")]
             AnyMirrorQueryData::TopicMessage(it) => Box::pin(
                 it.subscribe_with_optional_timeout(params, client, timeout)
                     .map_ok(Self::Item::from),
@@ -79,6 +85,7 @@ impl MirrorQueryExecute for AnyMirrorQueryData {
                     .await
                     .map(Self::Response::from)
             }),
+            #[cfg(feature = "topics")]
             AnyMirrorQueryData::TopicMessage(it) => Box::pin(async move {
                 it.execute_with_optional_timeout(params, client, timeout)
                     .await
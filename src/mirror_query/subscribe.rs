@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::any::type_name;
+
 use async_stream::stream;
 use backoff::backoff::Backoff;
 use backoff::ExponentialBackoff;
@@ -7,9 +9,14 @@ use futures_core::future::BoxFuture;
 use futures_core::Stream;
 use futures_util::StreamExt;
 use tokio::time::sleep;
-use tonic::transport::Channel;
 use tonic::Status;
+use triomphe::Arc;
 
+use crate::client::MirrorChannel;
+use crate::logger::{
+    client_log,
+    ClientLogger,
+};
 use crate::mirror_query::AnyMirrorQueryData;
 use crate::{
     Client,
@@ -127,7 +134,12 @@ where
         // note: we don't care about keeping the mirrornet around, so, we just take the channel (which is arc-like)
         let channel = client.mirrornet().load().channel();
 
-        Self::make_item_stream(crate::mirror_query::subscribe(channel, timeout, self.clone()))
+        Self::make_item_stream(crate::mirror_query::subscribe(
+            channel,
+            timeout,
+            self.clone(),
+            Arc::clone(client.logger()),
+        ))
     }
 
     fn execute_with_optional_timeout<'a>(
@@ -143,7 +155,12 @@ where
         // note: we don't care about keeping the mirrornet around, so, we just take the channel (which is arc-like)
         let channel = client.mirrornet().load().channel();
 
-        Self::try_collect(crate::mirror_query::subscribe(channel, timeout, self.clone()))
+        Self::try_collect(crate::mirror_query::subscribe(
+            channel,
+            timeout,
+            self.clone(),
+            Arc::clone(client.logger()),
+        ))
     }
 }
 
@@ -160,7 +177,7 @@ pub trait MirrorRequest: Send {
     fn connect(
         &self,
         context: &Self::Context,
-        channel: Channel,
+        channel: MirrorChannel,
     ) -> BoxFuture<'_, tonic::Result<Self::ConnectStream>>;
 
     /// Return `true` to retry establishing the stream, up to a configurable maximum timeout.
@@ -181,9 +198,10 @@ pub trait MirrorRequest: Send {
 }
 
 pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
-    channel: Channel,
+    channel: MirrorChannel,
     timeout: std::time::Duration,
     request: R,
+    logger: Arc<ClientLogger>,
 ) -> impl Stream<Item = crate::Result<I>> + Send {
     stream! {
         let request = request;
@@ -243,6 +261,14 @@ pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
                 }
             };
 
+            client_log!(
+                logger,
+                Mirror,
+                Debug,
+                "mirror stream for {} failed with {status:?}",
+                type_name::<R>()
+            );
+
             match status.code() {
                 tonic::Code::Unavailable | tonic::Code::ResourceExhausted => {
                     // encountered a temporarily down or overloaded service
@@ -0,0 +1,438 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::pin::Pin;
+use std::task::{
+    Context,
+    Poll,
+};
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures_core::stream::BoxStream;
+use futures_core::Stream;
+use futures_util::StreamExt;
+use http_body_util::{
+    BodyExt,
+    Full,
+};
+use hyper::header::{
+    HeaderMap,
+    ACCEPT,
+    CONTENT_TYPE,
+    RETRY_AFTER,
+};
+use hyper::{
+    Method,
+    Request,
+    StatusCode,
+};
+use hyper_openssl::client::legacy::HttpsConnector;
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::client::legacy::Client as HttpClient;
+use hyper_util::rt::TokioExecutor;
+use openssl::ssl::{
+    SslConnector,
+    SslMethod,
+    SslVerifyMode,
+};
+use serde::de::DeserializeOwned;
+use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
+
+use crate::client::{
+    MirrorHeaders,
+    SharedClock,
+};
+use crate::clock::{
+    Clock,
+    SystemClock,
+};
+use crate::{
+    Error,
+    TransactionId,
+};
+
+/// The path that all mirror node REST API endpoints live under.
+const API_PREFIX: &str = "/api/v1";
+
+/// The port the mirror node REST API listens on in a local node.
+const LOCAL_REST_PORT: u16 = 5551;
+
+/// The number of times a rate limited (`429 Too Many Requests`) request is retried before giving up.
+const MAX_RATE_LIMITED_RETRIES: u32 = 5;
+
+/// How long to wait after the first rate limited response without a `Retry-After` header.
+///
+/// Doubles with every retry.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_millis(500);
+
+/// The longest `Retry-After` that's honored.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// A client for the mirror node REST API.
+///
+/// This is cheap to clone.
+#[derive(Clone)]
+pub(crate) struct MirrorRestClient {
+    /// Scheme, host and port of the mirror node, IE `https://testnet.mirrornode.hedera.com`.
+    origin: String,
+    headers: MirrorHeaders,
+    http: HttpClient<HttpsConnector<HttpConnector>, Full<Bytes>>,
+    /// The clock rate limited requests wait on before they're retried.
+    clock: SharedClock,
+}
+
+impl MirrorRestClient {
+    /// Create a REST client for the mirror node with the given (gRPC) `address`, that adds `headers` to every request.
+    pub(crate) fn new(address: &str, headers: MirrorHeaders) -> Self {
+        let mut ssl_builder = SslConnector::builder(SslMethod::tls()).unwrap();
+        ssl_builder.set_verify(SslVerifyMode::PEER);
+        ssl_builder.set_alpn_protos(b"\x08http/1.1").unwrap();
+
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        let https = HttpsConnector::with_connector(http, ssl_builder).unwrap();
+
+        Self {
+            origin: rest_origin(address),
+            headers,
+            http: HttpClient::builder(TokioExecutor::new()).build(https),
+            clock: Arc::new(SystemClock).unsize(Coercion!(to dyn Clock)),
+        }
+    }
+
+    /// Wait on `clock` before retrying rate limited requests.
+    pub(crate) fn with_clock(self, clock: SharedClock) -> Self {
+        Self { clock, ..self }
+    }
+
+    fn url(&self, path: &str) -> String {
+        // `links.next` in paginated responses already contains the API prefix.
+        match path.starts_with(API_PREFIX) {
+            true => format!("{}{path}", self.origin),
+            false => format!("{}{API_PREFIX}{path}", self.origin),
+        }
+    }
+
+    /// `GET` the given `path` (relative to `/api/v1`, or a `links.next` value) and parse the response as JSON.
+    pub(crate) async fn get<T: DeserializeOwned>(&self, path: &str) -> crate::Result<T> {
+        self.send(Method::GET, path, Bytes::new(), None).await
+    }
+
+    /// `GET` the given `path` like [`get`](Self::get), but return `None` if the mirror node responds with `404 Not Found`.
+    pub(crate) async fn get_optional<T: DeserializeOwned>(
+        &self,
+        path: &str,
+    ) -> crate::Result<Option<T>> {
+        self.send(Method::GET, path, Bytes::new(), Some(None)).await
+    }
+
+    /// `POST` `body` to the given `path` (relative to `/api/v1`) and parse the response as JSON.
+    pub(crate) async fn post<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &serde_json::Value,
+    ) -> crate::Result<T> {
+        let body = serde_json::to_vec(body).map_err(Error::mirror_rest)?;
+
+        self.send(Method::POST, path, Bytes::from(body), None).await
+    }
+
+    /// Send a request, retrying it while the mirror node responds with `429 Too Many Requests`.
+    ///
+    /// If the mirror node responds with `404 Not Found`, `not_found` is returned when it's `Some`.
+    async fn send<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Bytes,
+        mut not_found: Option<T>,
+    ) -> crate::Result<T> {
+        let url = self.url(path);
+
+        let mut retries = 0;
+
+        loop {
+            let mut request = Request::builder()
+                .method(method.clone())
+                .uri(url.as_str())
+                .header(ACCEPT, "application/json")
+                .header(CONTENT_TYPE, "application/json")
+                .body(Full::new(body.clone()))
+                .map_err(Error::mirror_rest)?;
+
+            for (name, value) in self.headers.as_header_map() {
+                request.headers_mut().insert(name, value.clone());
+            }
+
+            let response = self.http.request(request).await.map_err(Error::mirror_rest)?;
+
+            let status = response.status();
+
+            if status == StatusCode::TOO_MANY_REQUESTS && retries < MAX_RATE_LIMITED_RETRIES {
+                let delay = retry_after(response.headers())
+                    .unwrap_or(DEFAULT_RETRY_AFTER * 2_u32.pow(retries));
+
+                retries += 1;
+                self.clock.sleep(delay).await;
+                continue;
+            }
+
+            if status == StatusCode::NOT_FOUND {
+                if let Some(not_found) = not_found.take() {
+                    return Ok(not_found);
+                }
+            }
+
+            let body = response.into_body().collect().await.map_err(Error::mirror_rest)?.to_bytes();
+
+            if !status.is_success() {
+                return Err(Error::mirror_rest(format!(
+                    "`{method} {url}` failed with status `{status}`: {}",
+                    String::from_utf8_lossy(&body)
+                )));
+            }
+
+            return serde_json::from_slice(&body).map_err(Error::mirror_rest);
+        }
+    }
+
+    /// Returns a stream of the items of a list endpoint, starting at each of `first_page_paths` in turn,
+    /// and following pagination.
+    ///
+    /// `into_items` converts a page into its items and the path of the next page.
+    /// Pages are only fetched once the items of the previous page have been consumed,
+    /// and the stream ends after `limit` items (`0` for no limit), or after the first error.
+    pub(crate) fn paginate<P, I, T, F>(
+        &self,
+        first_page_paths: crate::Result<Vec<String>>,
+        limit: u64,
+        mut into_items: F,
+    ) -> MirrorStream<T>
+    where
+        P: DeserializeOwned + Send + 'static,
+        I: IntoIterator<Item = crate::Result<T>>,
+        I::IntoIter: Send + 'static,
+        T: Send + 'static,
+        F: FnMut(P) -> (I, Option<String>) + Send + 'static,
+    {
+        let rest = self.clone();
+
+        let stream = async_stream::stream! {
+            let first_page_paths = match first_page_paths {
+                Ok(paths) => paths,
+                Err(e) => {
+                    yield Err(e);
+                    return;
+                }
+            };
+
+            let mut count = 0;
+
+            for path in first_page_paths {
+                let mut next = Some(path);
+
+                while let Some(path) = next.take() {
+                    let page: P = match rest.get(&path).await {
+                        Ok(page) => page,
+                        Err(e) => {
+                            yield Err(e);
+                            return;
+                        }
+                    };
+
+                    let (items, next_path) = into_items(page);
+
+                    for item in items {
+                        let is_err = item.is_err();
+
+                        yield item;
+
+                        count += 1;
+
+                        if is_err || (limit != 0 && count >= limit) {
+                            return;
+                        }
+                    }
+
+                    next = next_path;
+                }
+            }
+        };
+
+        MirrorStream(Box::pin(stream))
+    }
+}
+
+/// A stream of the items of a paginated mirror node REST API list, IE, token balances or NFTs.
+///
+/// Each page is fetched as the previous one is consumed, so only the pages that are needed are requested.
+/// The stream ends after the first error.
+#[must_use = "streams do nothing unless polled"]
+pub struct MirrorStream<T>(BoxStream<'static, crate::Result<T>>);
+
+impl<T> MirrorStream<T> {
+    /// Collect the items of this stream, up to `limit` items (`0` collects every item).
+    ///
+    /// # Errors
+    /// - The first error of the stream, if it comes before `limit` items.
+    pub async fn collect_all(mut self, limit: u64) -> crate::Result<Vec<T>> {
+        let mut items = Vec::new();
+
+        while let Some(item) = self.0.next().await {
+            items.push(item?);
+
+            if limit != 0 && items.len() as u64 >= limit {
+                break;
+            }
+        }
+
+        Ok(items)
+    }
+}
+
+impl<T> Stream for MirrorStream<T> {
+    type Item = crate::Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.0.as_mut().poll_next(cx)
+    }
+}
+
+impl<T> std::fmt::Debug for MirrorStream<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MirrorStream").finish_non_exhaustive()
+    }
+}
+
+/// Returns how long the `Retry-After` header of a rate limited response asks to wait, if it's a number of seconds.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let seconds: u64 = headers.get(RETRY_AFTER)?.to_str().ok()?.trim().parse().ok()?;
+
+    Some(Duration::from_secs(seconds).min(MAX_RETRY_AFTER))
+}
+
+/// Pagination links included in list responses.
+#[derive(serde_derive::Deserialize)]
+pub(crate) struct Links {
+    /// The path of the next page, if there is one.
+    pub(crate) next: Option<String>,
+}
+
+/// Parse a page of a list endpoint with `items` under `key`, and `next` as the path of the next page.
+#[cfg(test)]
+pub(crate) fn test_page<P: DeserializeOwned>(
+    key: &str,
+    items: serde_json::Value,
+    next: Option<&str>,
+) -> P {
+    serde_json::from_value(serde_json::json!({ key: items, "links": { "next": next } })).unwrap()
+}
+
+/// Returns the origin of the REST API for the mirror node with the given gRPC `address`.
+fn rest_origin(address: &str) -> String {
+    let (host, port) = address.rsplit_once(':').unwrap_or((address, "443"));
+
+    if host == "localhost" || host == "127.0.0.1" {
+        return format!("http://{host}:{LOCAL_REST_PORT}");
+    }
+
+    match port {
+        "443" => format!("https://{host}"),
+        _ => format!("https://{host}:{port}"),
+    }
+}
+
+/// Format `transaction_id` the way the mirror node expects it in paths, IE, `0.0.5005-1554158542-000000007`.
+///
+/// The nonce and scheduled flag aren't included, they're separate query parameters.
+pub(crate) fn format_transaction_id(transaction_id: &TransactionId) -> String {
+    format!(
+        "{}-{}-{:09}",
+        transaction_id.account_id,
+        transaction_id.valid_start.unix_timestamp(),
+        transaction_id.valid_start.nanosecond()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use hyper::header::{
+        HeaderMap,
+        HeaderValue,
+        RETRY_AFTER,
+    };
+
+    use super::{
+        format_transaction_id,
+        rest_origin,
+        retry_after,
+        MirrorStream,
+        MAX_RETRY_AFTER,
+    };
+    use crate::{
+        AccountId,
+        TransactionId,
+    };
+
+    #[test]
+    fn origin() {
+        assert_eq!(
+            rest_origin("testnet.mirrornode.hedera.com:443"),
+            "https://testnet.mirrornode.hedera.com"
+        );
+        assert_eq!(rest_origin("mirror.example.com:8443"), "https://mirror.example.com:8443");
+        assert_eq!(rest_origin("127.0.0.1:5600"), "http://127.0.0.1:5551");
+        assert_eq!(rest_origin("localhost:5600"), "http://localhost:5551");
+    }
+
+    #[test]
+    fn transaction_id_path() {
+        let transaction_id = TransactionId {
+            account_id: AccountId::new(0, 0, 5005),
+            valid_start: OffsetDateTime::UNIX_EPOCH + Duration::new(1_554_158_542, 7),
+            nonce: Some(1),
+            scheduled: true,
+        };
+
+        assert_eq!(format_transaction_id(&transaction_id), "0.0.5005-1554158542-000000007");
+    }
+
+    #[test]
+    fn retry_after_header() {
+        let mut headers = HeaderMap::new();
+
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("3"));
+        assert_eq!(retry_after(&headers), Some(std::time::Duration::from_secs(3)));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("3600"));
+        assert_eq!(retry_after(&headers), Some(MAX_RETRY_AFTER));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"));
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[tokio::test]
+    async fn collect_all() {
+        let items = || {
+            MirrorStream(Box::pin(futures_util::stream::iter([
+                Ok(1),
+                Ok(2),
+                Err(crate::Error::mirror_rest("rate limited")),
+            ])))
+        };
+
+        assert_eq!(items().collect_all(2).await.unwrap(), [1, 2]);
+        assert!(items().collect_all(0).await.is_err());
+    }
+}
@@ -138,6 +138,70 @@ impl Mnemonic {
         Ok(mnemonic)
     }
 
+    /// Validate the checksum of `self`.
+    ///
+    /// A legacy mnemonic's checksum is otherwise only checked when it's converted to a [`PrivateKey`],
+    /// this checks it up front.
+    /// This is also useful for the `Mnemonic` of an [`Error::MnemonicParse`], to check the rest of the mnemonic.
+    ///
+    /// # Errors
+    /// - [`Error::MnemonicEntropy`] with [`MnemonicEntropyError::ChecksumMismatch`]
+    ///   if this is a legacy mnemonic, and the computed checksum doesn't match the actual checksum.
+    /// - [`Error::MnemonicParse`] if this isn't a legacy mnemonic, and has an invalid length, uses invalid words,
+    ///   or the computed checksum doesn't match the actual checksum.
+    pub fn validate_checksum(&self) -> crate::Result<()> {
+        match &self.0 {
+            MnemonicData::V1(it) => it.to_entropy().map(drop),
+            MnemonicData::V2V3(it) => Self::from_words(it.words.clone()).map(drop),
+        }
+    }
+
+    /// Returns `true` if `word` is in the BIP-39 standard English word list.
+    ///
+    /// Words are case sensitive, IE, `"Abandon"` isn't a valid word.
+    #[must_use]
+    pub fn is_valid_word(word: &str) -> bool {
+        BIP39_WORD_LIST.binary_search(&word).is_ok()
+    }
+
+    /// Returns the words in the BIP-39 standard English word list closest to `word`, closest first.
+    ///
+    /// Returns at most `max` words, none of which are more than 2 edits (insertions, deletions,
+    /// substitutions, or swaps of adjacent letters) away from `word`.
+    ///
+    /// If `word` is valid, it's the first suggestion.
+    #[must_use]
+    pub fn suggest_words(word: &str, max: usize) -> Vec<&'static str> {
+        const MAX_DISTANCE: usize = 2;
+
+        let mut suggestions: Vec<_> = BIP39_WORD_LIST
+            .iter()
+            .filter_map(|&candidate| {
+                let distance = edit_distance(word, candidate);
+                (distance <= MAX_DISTANCE).then_some((distance, candidate))
+            })
+            .collect();
+
+        // stable sort, so words the same distance away stay in alphabetical order.
+        suggestions.sort_by_key(|&(distance, _)| distance);
+
+        suggestions.into_iter().take(max).map(|(_, candidate)| candidate).collect()
+    }
+
+    /// Returns the words in the BIP-39 standard English word list that start with `prefix`, in alphabetical order.
+    ///
+    /// Every word in the list is uniquely identified by its first 4 letters,
+    /// so any prefix of at least 4 letters completes to at most one word.
+    #[must_use]
+    pub fn complete_word(prefix: &str) -> &'static [&'static str] {
+        let words: &'static [&'static str] = &BIP39_WORD_LIST;
+
+        let start = words.partition_point(|&it| it < prefix);
+        let len = words[start..].partition_point(|it| it.starts_with(prefix));
+
+        &words[start..][..len]
+    }
+
     /// Generate a new 12 word `Mnemonic` from the BIP-39 standard English word list.
     #[must_use]
     pub fn generate_12() -> Self {
@@ -449,6 +513,41 @@ fn checksum(bytes: &[u8]) -> u8 {
     checksum[0]
 }
 
+/// The optimal string alignment distance between `a` and `b`,
+/// IE, the Levenshtein distance, where swapping two adjacent letters counts as a single edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<_> = a.chars().collect();
+    let b: Vec<_> = b.chars().collect();
+
+    // `rows[i][j]` is the distance between `a[..i]` and `b[..j]`.
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            let mut distance =
+                (rows[i - 1][j] + 1).min(rows[i][j - 1] + 1).min(rows[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+
+            rows[i][j] = distance;
+        }
+    }
+
+    rows[a.len()][b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -458,7 +557,10 @@ mod tests {
     use hex_literal::hex;
 
     use super::Mnemonic;
-    use crate::error::MnemonicParseError;
+    use crate::error::{
+        MnemonicEntropyError,
+        MnemonicParseError,
+    };
     use crate::Error;
 
     const KNOWN_GOOD_MNEMONICS: &[&str] = &[
@@ -638,6 +740,74 @@ mod tests {
         .assert_debug_eq(key.debug_pretty());
     }
 
+    #[test]
+    fn validate_checksum() {
+        for s in KNOWN_GOOD_MNEMONICS {
+            assert_matches!(Mnemonic::from_str(s).unwrap().validate_checksum(), Ok(()));
+        }
+
+        // `kidnap` and `jolly` are swapped.
+        const LEGACY_MNEMONIC: &str = concat!(
+            "kidnap jolly tom lawn drunk chick optic lust mutter mole bride ",
+            "galley dense member sage neural widow decide curb aboard margin manure"
+        );
+
+        let mnemonic = Mnemonic::from_str(LEGACY_MNEMONIC).unwrap();
+
+        assert_matches!(
+            mnemonic.validate_checksum(),
+            Err(Error::MnemonicEntropy(MnemonicEntropyError::ChecksumMismatch { .. }))
+        );
+    }
+
+    #[test]
+    fn validate_checksum_unknown_words() {
+        // `absorb` -> `adsorb`
+        const MNEMONIC: &str =
+            "abandon ability able about above absent adsorb abstract absurd abuse access accident";
+
+        let mnemonic = assert_matches!(Mnemonic::from_str(MNEMONIC), Err(Error::MnemonicParse { mnemonic, .. }) => mnemonic);
+
+        assert_matches!(
+            mnemonic.validate_checksum(),
+            Err(Error::MnemonicParse { reason: MnemonicParseError::UnknownWords(_), .. })
+        );
+    }
+
+    #[test]
+    fn is_valid_word() {
+        assert!(Mnemonic::is_valid_word("abandon"));
+        assert!(Mnemonic::is_valid_word("zoo"));
+        assert!(!Mnemonic::is_valid_word("Abandon"));
+        assert!(!Mnemonic::is_valid_word("aban"));
+        assert!(!Mnemonic::is_valid_word(""));
+    }
+
+    #[test]
+    fn suggest_words() {
+        assert_eq!(Mnemonic::suggest_words("abandon", 3), ["abandon"]);
+        assert_eq!(Mnemonic::suggest_words("adsorb", 3), ["absorb"]);
+        assert_eq!(Mnemonic::suggest_words("acquired", 3), ["acquire"]);
+        assert_eq!(Mnemonic::suggest_words("acount", 3), ["account", "amount", "about"]);
+        assert_eq!(Mnemonic::suggest_words("lorum", 1), ["forum"]);
+        assert!(Mnemonic::suggest_words("xyzzyq", 3).is_empty());
+    }
+
+    #[test]
+    fn complete_word() {
+        assert_eq!(Mnemonic::complete_word("aban"), ["abandon"]);
+        assert_eq!(Mnemonic::complete_word("zoo"), ["zoo"]);
+        assert_eq!(
+            Mnemonic::complete_word("ab"),
+            [
+                "abandon", "ability", "able", "about", "above", "absent", "absorb", "abstract",
+                "absurd", "abuse"
+            ]
+        );
+        assert!(Mnemonic::complete_word("xyz").is_empty());
+        assert_eq!(Mnemonic::complete_word("").len(), 2048);
+    }
+
     #[test]
     fn to_private_key() {
         const MNEMONIC: &str = concat!(
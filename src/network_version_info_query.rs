@@ -13,6 +13,7 @@ use crate::query::{
 use crate::{
     BoxGrpcFuture,
     Error,
+    FromProtobuf,
     NetworkVersionInfo,
     Query,
 };
@@ -41,6 +42,12 @@ impl ToQueryProtobuf for NetworkVersionInfoQueryData {
     }
 }
 
+impl FromProtobuf<services::NetworkGetVersionInfoQuery> for NetworkVersionInfoQueryData {
+    fn from_protobuf(_pb: services::NetworkGetVersionInfoQuery) -> crate::Result<Self> {
+        Ok(Self {})
+    }
+}
+
 impl QueryExecute for NetworkVersionInfoQueryData {
     type Response = NetworkVersionInfo;
 
@@ -14,9 +14,11 @@ use hedera_proto::{
     services,
 };
 use mirror::network_service_client::NetworkServiceClient;
-use tonic::transport::Channel;
 use tonic::Response;
+use triomphe::Arc;
 
+use crate::client::MirrorChannel;
+use crate::logger::ClientLogger;
 use crate::mirror_query::{
     AnyMirrorQueryData,
     AnyMirrorQueryMessage,
@@ -135,7 +137,7 @@ impl MirrorRequest for NodeAddressBookQueryData {
     fn connect(
         &self,
         _context: &Self::Context,
-        channel: Channel,
+        channel: MirrorChannel,
     ) -> BoxFuture<'_, tonic::Result<Self::ConnectStream>> {
         Box::pin(async {
             let file_id = if self.shard.is_some() && self.realm.is_some() {
@@ -189,8 +191,9 @@ impl From<NodeAddressBook> for AnyMirrorQueryResponse {
 impl NodeAddressBookQuery {
     pub(crate) async fn execute_mirrornet(
         &self,
-        channel: Channel,
+        channel: MirrorChannel,
         timeout: Option<Duration>,
+        logger: Arc<ClientLogger>,
     ) -> crate::Result<NodeAddressBook> {
         let timeout = timeout.unwrap_or_else(|| {
             std::time::Duration::from_millis(backoff::default::MAX_ELAPSED_TIME_MILLIS)
@@ -200,6 +203,7 @@ impl NodeAddressBookQuery {
             channel,
             timeout,
             self.data.clone(),
+            logger,
         ))
         .await
     }
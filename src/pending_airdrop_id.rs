@@ -22,23 +22,29 @@ use crate::{
 /// that would modify that pending airdrop (such as a `claimAirdrop` or `cancelAirdrop`).
 ///
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct PendingAirdropId {
     /// A sending account.
     ///
     /// This is the account that initiated, and SHALL fund, this pending airdrop.
     /// This field is REQUIRED.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::display"))]
     pub sender_id: AccountId,
 
     /// A receiving account.
     ///
     /// This is the ID of the account that SHALL receive the airdrop.
     /// This field is REQUIRED.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::display"))]
     pub receiver_id: AccountId,
 
     /// Token Id.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub token_id: Option<TokenId>,
 
     /// Nft Id.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub nft_id: Option<NftId>,
 }
 
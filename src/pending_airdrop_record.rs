@@ -12,6 +12,8 @@ use crate::protobuf::{
 
 /// A record of a new pending airdrop.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct PendingAirdropRecord {
     /// A unique, composite, identifier for a pending airdrop.
     /// This field is REQUIRED.
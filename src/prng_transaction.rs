@@ -14,7 +14,9 @@ use crate::transaction::{
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::Transaction;
 
@@ -56,7 +58,15 @@ impl ToProtobuf for PrngTransactionData {
     }
 }
 
-impl TransactionData for PrngTransactionData {}
+impl TransactionData for PrngTransactionData {
+    fn validate_data(&self, _problems: &mut Vec<ValidationProblem>) {
+        // `range` is optional, and any value is valid.
+    }
+
+    fn describe_data(&self, _description: &mut TransactionDescription) {
+        // a PRNG transaction touches no entities.
+    }
+}
 
 impl From<PrngTransactionData> for AnyTransactionData {
     fn from(value: PrngTransactionData) -> Self {
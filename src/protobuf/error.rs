@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::error::Error as StdError;
+use std::fmt;
+
+use crate::error::BoxStdError;
+use crate::Error;
+
+/// A protobuf message couldn't be converted into an SDK type.
+///
+/// Returned (boxed) in [`Error::FromProtobuf`](crate::Error::FromProtobuf),
+/// and says *where* in the message the problem is, for example `transaction_list[2].body_bytes`.
+#[derive(Debug)]
+pub struct FromProtobufError {
+    /// Path segments, outermost first.
+    path: Vec<PathSegment>,
+    error: BoxStdError,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum PathSegment {
+    Field(&'static str),
+    Index(usize),
+}
+
+impl FromProtobufError {
+    pub(crate) fn new(error: impl Into<BoxStdError>) -> Self {
+        Self { path: Vec::new(), error: error.into() }
+    }
+
+    /// Returns the path to the offending field, for example `transaction_list[2].body_bytes`.
+    ///
+    /// The path is empty when the problem is with the message itself.
+    #[must_use]
+    pub fn get_path(&self) -> String {
+        let mut path = String::new();
+
+        for segment in &self.path {
+            match segment {
+                PathSegment::Field(field) => {
+                    if !path.is_empty() {
+                        path.push('.');
+                    }
+
+                    path.push_str(field);
+                }
+
+                PathSegment::Index(index) => {
+                    path.push('[');
+                    path.push_str(&index.to_string());
+                    path.push(']');
+                }
+            }
+        }
+
+        path
+    }
+
+    fn prepend(mut self, segment: PathSegment) -> Self {
+        self.path.insert(0, segment);
+        self
+    }
+}
+
+impl fmt::Display for FromProtobufError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            return fmt::Display::fmt(&self.error, f);
+        }
+
+        write!(f, "`{}`: {}", self.get_path(), self.error)
+    }
+}
+
+impl StdError for FromProtobufError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&*self.error)
+    }
+}
+
+impl Error {
+    fn prepend_protobuf_path(self, segment: PathSegment) -> Self {
+        match self {
+            Self::FromProtobuf(error) => Self::FromProtobuf(Box::new(
+                match error.downcast::<FromProtobufError>() {
+                    Ok(error) => *error,
+                    Err(error) => FromProtobufError { path: Vec::new(), error },
+                }
+                .prepend(segment),
+            )),
+
+            // only decoding errors carry a path.
+            error => error,
+        }
+    }
+}
+
+/// Adds the location of a field to decoding errors, for example `transaction_list[2].body_bytes`.
+pub(crate) trait FromProtobufContext: Sized {
+    /// Marks errors as coming from the field `field` of the message being decoded.
+    fn in_field(self, field: &'static str) -> Self;
+
+    /// Marks errors as coming from element `index` of the repeated field being decoded.
+    fn at_index(self, index: usize) -> Self;
+}
+
+impl<T> FromProtobufContext for crate::Result<T> {
+    fn in_field(self, field: &'static str) -> Self {
+        self.map_err(|e| e.prepend_protobuf_path(PathSegment::Field(field)))
+    }
+
+    fn at_index(self, index: usize) -> Self {
+        self.map_err(|e| e.prepend_protobuf_path(PathSegment::Index(index)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FromProtobufContext;
+    use crate::Error;
+
+    fn error_path(error: &Error) -> String {
+        match error {
+            Error::FromProtobuf(error) => {
+                error.downcast_ref::<super::FromProtobufError>().unwrap().get_path()
+            }
+            _ => panic!("unexpected error: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn path() {
+        let result: crate::Result<()> = Err(Error::from_protobuf("oops"));
+
+        let error =
+            result.in_field("body_bytes").at_index(2).in_field("transaction_list").unwrap_err();
+
+        assert_eq!(error_path(&error), "transaction_list[2].body_bytes");
+
+        expect_test::expect![[r#"
+            "failed to create a SDK type from a protobuf response: `transaction_list[2].body_bytes`: oops"
+        "#]]
+        .assert_debug_eq(&error.to_string());
+    }
+
+    #[test]
+    fn no_path() {
+        let error = Error::from_protobuf("oops");
+
+        assert_eq!(error_path(&error), "");
+        assert_eq!(error.to_string(), "failed to create a SDK type from a protobuf response: oops");
+    }
+
+    #[test]
+    fn source_is_the_underlying_error() {
+        use std::error::Error as _;
+
+        let Err(Error::FromProtobuf(error)) =
+            Err::<(), _>(Error::from_protobuf("oops")).in_field("body_bytes")
+        else {
+            panic!("expected a protobuf error");
+        };
+
+        let error = error.downcast_ref::<super::FromProtobufError>().unwrap();
+
+        assert_eq!(error.source().unwrap().to_string(), "oops");
+    }
+
+    #[test]
+    fn other_errors_unchanged() {
+        let result: crate::Result<()> = Err(Error::basic_parse("oops"));
+
+        assert!(matches!(result.in_field("body"), Err(Error::BasicParse(_))));
+    }
+}
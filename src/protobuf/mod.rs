@@ -1,12 +1,25 @@
 // SPDX-License-Identifier: Apache-2.0
 
+//! Conversions between SDK types and the protobufs in `hedera::proto` (with the `protobuf` feature).
+//!
+//! See [`time`] for converting between the different representations of time.
+
 mod convert;
-mod time;
+mod error;
+pub mod time;
 
 #[macro_use]
 pub(crate) mod get;
 
+pub(crate) use error::FromProtobufContext;
+#[cfg(feature = "protobuf")]
+pub use convert::{
+    FromProtobuf,
+    ToProtobuf,
+};
+#[cfg(not(feature = "protobuf"))]
 pub(crate) use convert::{
     FromProtobuf,
     ToProtobuf,
 };
+pub use error::FromProtobufError;
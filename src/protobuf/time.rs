@@ -1,12 +1,152 @@
 // SPDX-License-Identifier: Apache-2.0
 
+//! Conversions between the ways time is represented around the SDK.
+//!
+//! SDK types use [`OffsetDateTime`] and [`Duration`],
+//! the mirror node API uses nanoseconds since the unix epoch, or `<seconds>.<nanoseconds>` strings,
+//! and the protobufs use `Timestamp`, `TimestampSeconds` and `Duration`
+//! (the conversions to and from those need the `protobuf` feature).
+//!
+//! Every conversion here is lossless, a conversion that would drop precision or that's out of range fails instead.
+//!
+//! ```
+//! use hedera::protobuf::time;
+//! # fn main() -> hedera::Result<()> {
+//! let consensus_timestamp = time::from_epoch_nanos(1_554_158_542_000_000_001);
+//!
+//! assert_eq!(time::format_mirror_timestamp(consensus_timestamp), "1554158542.000000001");
+//! assert_eq!(time::parse_mirror_timestamp("1554158542.000000001")?, consensus_timestamp);
+//! assert_eq!(time::to_epoch_nanos(consensus_timestamp)?, 1_554_158_542_000_000_001);
+//! # Ok(())
+//! # }
+//! ```
+
 use hedera_proto::services;
 use time::{
     Duration,
     OffsetDateTime,
 };
 
-use crate::ToProtobuf;
+use crate::{
+    Error,
+    FromProtobuf,
+    ToProtobuf,
+};
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// Convert a protobuf `Timestamp` to an [`OffsetDateTime`].
+///
+/// # Errors
+/// - [`Error::FromProtobuf`] if `nanos` isn't in `0..1_000_000_000`.
+/// - [`Error::FromProtobuf`] if the timestamp is out of range for [`OffsetDateTime`].
+#[cfg(feature = "protobuf")]
+pub fn from_timestamp(timestamp: services::Timestamp) -> crate::Result<OffsetDateTime> {
+    OffsetDateTime::from_protobuf(timestamp)
+}
+
+/// Convert `time` to a protobuf `Timestamp`.
+#[cfg(feature = "protobuf")]
+#[must_use]
+pub fn to_timestamp(time: OffsetDateTime) -> services::Timestamp {
+    time.to_protobuf()
+}
+
+/// Convert a protobuf `TimestampSeconds` to an [`OffsetDateTime`].
+///
+/// # Errors
+/// - [`Error::FromProtobuf`] if the timestamp is out of range for [`OffsetDateTime`].
+#[cfg(feature = "protobuf")]
+pub fn from_timestamp_seconds(
+    timestamp: services::TimestampSeconds,
+) -> crate::Result<OffsetDateTime> {
+    OffsetDateTime::from_protobuf(timestamp)
+}
+
+/// Convert `time` to a protobuf `TimestampSeconds`.
+///
+/// # Errors
+/// - [`Error::TimeConversion`] if `time` isn't a whole number of seconds.
+#[cfg(feature = "protobuf")]
+pub fn to_timestamp_seconds(time: OffsetDateTime) -> crate::Result<services::TimestampSeconds> {
+    if time.nanosecond() != 0 {
+        return Err(Error::time_conversion(format!(
+            "`{time}` has a fractional second, which `TimestampSeconds` can't represent"
+        )));
+    }
+
+    Ok(services::TimestampSeconds { seconds: time.unix_timestamp() })
+}
+
+/// Convert a protobuf `Duration` to a [`Duration`].
+#[cfg(feature = "protobuf")]
+#[must_use]
+pub fn from_duration(duration: services::Duration) -> Duration {
+    Duration::seconds(duration.seconds)
+}
+
+/// Convert `duration` to a protobuf `Duration`.
+///
+/// # Errors
+/// - [`Error::TimeConversion`] if `duration` isn't a whole number of seconds.
+#[cfg(feature = "protobuf")]
+pub fn to_duration(duration: Duration) -> crate::Result<services::Duration> {
+    if duration.subsec_nanoseconds() != 0 {
+        return Err(Error::time_conversion(format!(
+            "`{duration}` has a fractional second, which a protobuf `Duration` can't represent"
+        )));
+    }
+
+    Ok(services::Duration { seconds: duration.whole_seconds() })
+}
+
+/// Convert nanoseconds since the unix epoch, as used by the mirror node, to an [`OffsetDateTime`].
+#[must_use]
+pub fn from_epoch_nanos(nanos: i64) -> OffsetDateTime {
+    // every `i64` of nanoseconds (about 292 years either side of the epoch) is in range.
+    OffsetDateTime::UNIX_EPOCH + Duration::nanoseconds(nanos)
+}
+
+/// Convert `time` to nanoseconds since the unix epoch, as used by the mirror node.
+///
+/// # Errors
+/// - [`Error::TimeConversion`] if `time` is too far from the epoch for an `i64` of nanoseconds,
+///   IE, outside of the years 1677 to 2262.
+pub fn to_epoch_nanos(time: OffsetDateTime) -> crate::Result<i64> {
+    i64::try_from(time.unix_timestamp_nanos()).map_err(|_| {
+        Error::time_conversion(format!("`{time}` is out of range for epoch nanoseconds"))
+    })
+}
+
+/// Parse a mirror node timestamp, IE, `<seconds>.<nanoseconds>`.
+///
+/// The nanoseconds are a decimal fraction, so `10.5` is 10 and a half seconds.
+///
+/// # Errors
+/// - [`Error::BasicParse`] if `s` isn't a valid mirror node timestamp,
+///   or it's out of range for [`OffsetDateTime`].
+pub fn parse_mirror_timestamp(s: &str) -> crate::Result<OffsetDateTime> {
+    let (seconds, nanos) = s.split_once('.').unwrap_or((s, "0"));
+
+    if nanos.len() > 9 || !nanos.bytes().all(|it| it.is_ascii_digit()) {
+        return Err(Error::basic_parse(format!("invalid mirror node timestamp `{s}`")));
+    }
+
+    let seconds: i64 = seconds.parse().map_err(Error::basic_parse)?;
+    // `.1` is 100_000_000 nanoseconds, not 1.
+    let nanos: i64 = format!("{nanos:0<9}").parse().map_err(Error::basic_parse)?;
+
+    OffsetDateTime::from_unix_timestamp_nanos(
+        i128::from(seconds) * NANOS_PER_SECOND + i128::from(nanos),
+    )
+    .map_err(Error::basic_parse)
+}
+
+/// Format `time` as a mirror node timestamp, IE, `<seconds>.<nanoseconds>`, with all 9 digits of nanoseconds.
+#[must_use]
+pub fn format_mirror_timestamp(time: OffsetDateTime) -> String {
+    format!("{}.{:09}", time.unix_timestamp(), time.nanosecond())
+}
 
 impl ToProtobuf for Duration {
     type Protobuf = services::Duration;
@@ -23,3 +163,152 @@ impl ToProtobuf for OffsetDateTime {
         services::Timestamp { seconds: self.unix_timestamp(), nanos: self.nanosecond() as i32 }
     }
 }
+
+// note: unlike the `From` impls in `hedera_proto`, these don't panic on timestamps `OffsetDateTime` can't represent.
+impl FromProtobuf<services::Timestamp> for OffsetDateTime {
+    fn from_protobuf(pb: services::Timestamp) -> crate::Result<Self> {
+        let services::Timestamp { seconds, nanos } = pb;
+
+        if !(0..NANOS_PER_SECOND).contains(&i128::from(nanos)) {
+            return Err(Error::from_protobuf(format!(
+                "timestamp nanos `{nanos}` must be in `0..1000000000`"
+            )));
+        }
+
+        OffsetDateTime::from_unix_timestamp_nanos(
+            i128::from(seconds) * NANOS_PER_SECOND + i128::from(nanos),
+        )
+        .map_err(|_| {
+            Error::from_protobuf(format!("timestamp `{seconds}s {nanos}ns` is out of range"))
+        })
+    }
+}
+
+impl FromProtobuf<services::TimestampSeconds> for OffsetDateTime {
+    fn from_protobuf(pb: services::TimestampSeconds) -> crate::Result<Self> {
+        OffsetDateTime::from_unix_timestamp(pb.seconds).map_err(|_| {
+            Error::from_protobuf(format!("timestamp `{}s` is out of range", pb.seconds))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "protobuf")]
+    use hedera_proto::services;
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use super::{
+        format_mirror_timestamp,
+        from_epoch_nanos,
+        parse_mirror_timestamp,
+        to_epoch_nanos,
+    };
+    #[cfg(feature = "protobuf")]
+    use super::{
+        from_duration,
+        from_timestamp,
+        from_timestamp_seconds,
+        to_duration,
+        to_timestamp,
+        to_timestamp_seconds,
+    };
+
+    #[cfg(feature = "protobuf")]
+    #[test]
+    fn timestamp_round_trip() {
+        let time = OffsetDateTime::UNIX_EPOCH + Duration::new(1_554_158_542, 1);
+
+        assert_eq!(to_timestamp(time), services::Timestamp { seconds: 1_554_158_542, nanos: 1 });
+        assert_eq!(from_timestamp(to_timestamp(time)).unwrap(), time);
+    }
+
+    #[cfg(feature = "protobuf")]
+    #[test]
+    fn timestamp_before_epoch() {
+        // the nanoseconds count forwards from `seconds`, even before the epoch.
+        let time = OffsetDateTime::UNIX_EPOCH - Duration::milliseconds(500);
+
+        assert_eq!(to_timestamp(time), services::Timestamp { seconds: -1, nanos: 500_000_000 });
+        assert_eq!(from_timestamp(to_timestamp(time)).unwrap(), time);
+    }
+
+    #[cfg(feature = "protobuf")]
+    #[test]
+    fn timestamp_invalid() {
+        assert!(from_timestamp(services::Timestamp { seconds: 0, nanos: -1 }).is_err());
+        assert!(from_timestamp(services::Timestamp { seconds: 0, nanos: 1_000_000_000 }).is_err());
+        assert!(from_timestamp(services::Timestamp { seconds: i64::MAX, nanos: 0 }).is_err());
+        assert!(from_timestamp_seconds(services::TimestampSeconds { seconds: i64::MIN }).is_err());
+    }
+
+    #[cfg(feature = "protobuf")]
+    #[test]
+    fn timestamp_seconds() {
+        let time = OffsetDateTime::UNIX_EPOCH + Duration::seconds(1_554_158_542);
+
+        let pb = to_timestamp_seconds(time).unwrap();
+
+        assert_eq!(pb.seconds, 1_554_158_542);
+        assert_eq!(from_timestamp_seconds(pb).unwrap(), time);
+
+        assert!(to_timestamp_seconds(time + Duration::nanoseconds(1)).is_err());
+    }
+
+    #[cfg(feature = "protobuf")]
+    #[test]
+    fn duration() {
+        assert_eq!(to_duration(Duration::minutes(2)).unwrap(), services::Duration { seconds: 120 });
+        assert_eq!(from_duration(services::Duration { seconds: 120 }), Duration::minutes(2));
+
+        assert!(to_duration(Duration::milliseconds(1500)).is_err());
+    }
+
+    #[test]
+    fn epoch_nanos() {
+        let time = from_epoch_nanos(1_554_158_542_000_000_001);
+
+        assert_eq!(time, OffsetDateTime::UNIX_EPOCH + Duration::new(1_554_158_542, 1));
+        assert_eq!(to_epoch_nanos(time).unwrap(), 1_554_158_542_000_000_001);
+
+        assert_eq!(to_epoch_nanos(from_epoch_nanos(i64::MIN)).unwrap(), i64::MIN);
+        assert_eq!(to_epoch_nanos(from_epoch_nanos(i64::MAX)).unwrap(), i64::MAX);
+
+        assert!(to_epoch_nanos(from_epoch_nanos(i64::MAX) + Duration::nanoseconds(1)).is_err());
+    }
+
+    #[test]
+    fn mirror_timestamp_round_trip() {
+        let time = OffsetDateTime::UNIX_EPOCH + Duration::new(1_554_158_542, 1);
+
+        assert_eq!(format_mirror_timestamp(time), "1554158542.000000001");
+        assert_eq!(parse_mirror_timestamp("1554158542.000000001").unwrap(), time);
+
+        let time = OffsetDateTime::UNIX_EPOCH - Duration::milliseconds(500);
+
+        assert_eq!(parse_mirror_timestamp(&format_mirror_timestamp(time)).unwrap(), time);
+    }
+
+    #[test]
+    fn mirror_timestamp_short_nanos() {
+        assert_eq!(
+            parse_mirror_timestamp("10.5").unwrap(),
+            OffsetDateTime::UNIX_EPOCH + Duration::new(10, 500_000_000)
+        );
+        assert_eq!(
+            parse_mirror_timestamp("10").unwrap(),
+            OffsetDateTime::UNIX_EPOCH + Duration::new(10, 0)
+        );
+    }
+
+    #[test]
+    fn mirror_timestamp_invalid() {
+        assert!(parse_mirror_timestamp("abc.123").is_err());
+        assert!(parse_mirror_timestamp("1.0000000001").is_err());
+        assert!(parse_mirror_timestamp("1.-5").is_err());
+        assert!(parse_mirror_timestamp("1.+5").is_err());
+    }
+}
@@ -1,56 +1,77 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use hedera_proto::services;
+use prost::Message;
 use tonic::transport::Channel;
 
+use super::payment_transaction::{
+    PaymentTransaction,
+    SignedPayment,
+};
 use super::ToQueryProtobuf;
 use crate::account::{
     AccountBalanceQueryData,
     AccountInfoQueryData,
     AccountRecordsQueryData,
 };
+#[cfg(feature = "contracts")]
 use crate::contract::{
     ContractBytecodeQueryData,
     ContractCallQueryData,
     ContractInfoQueryData,
 };
 use crate::entity_id::ValidateChecksums;
+#[cfg(feature = "files")]
 use crate::file::{
     FileContentsQueryData,
     FileInfoQueryData,
 };
 use crate::ledger_id::RefLedgerId;
 use crate::query::QueryExecute;
+#[cfg(feature = "schedules")]
 use crate::schedule::ScheduleInfoQueryData;
+#[cfg(feature = "tokens")]
 use crate::token::{
     TokenInfoQueryData,
     TokenNftInfoQueryData,
 };
+#[cfg(feature = "topics")]
 use crate::topic::TopicInfoQueryData;
 use crate::transaction_receipt_query::TransactionReceiptQueryData;
+#[cfg(feature = "schedules")]
+use crate::ScheduleInfo;
+#[cfg(feature = "topics")]
+use crate::TopicInfo;
 use crate::{
     AccountBalance,
     AccountInfo,
     AllProxyStakers,
     BoxGrpcFuture,
-    ContractFunctionResult,
-    ContractInfo,
     Error,
-    FileContentsResponse,
-    FileInfo,
     FromProtobuf,
     Hbar,
     NetworkVersionInfo,
     NetworkVersionInfoQueryData,
     Query,
-    ScheduleInfo,
-    TokenInfo,
-    TokenNftInfo,
-    TopicInfo,
     TransactionReceipt,
     TransactionRecord,
     TransactionRecordQueryData,
 };
+#[cfg(feature = "contracts")]
+use crate::{
+    ContractFunctionResult,
+    ContractInfo,
+};
+#[cfg(feature = "files")]
+use crate::{
+    FileContentsResponse,
+    FileInfo,
+};
+#[cfg(feature = "tokens")]
+use crate::{
+    TokenInfo,
+    TokenNftInfo,
+};
 
 /// Any possible query that may be executed on the Hiero network.
 pub type AnyQuery = Query<AnyQueryData>;
@@ -62,14 +83,23 @@ pub enum AnyQueryData {
     AccountRecords(AccountRecordsQueryData),
     TransactionReceipt(TransactionReceiptQueryData),
     TransactionRecord(TransactionRecordQueryData),
+    #[cfg(feature = "files")]
     FileContents(FileContentsQueryData),
+    #[cfg(feature = "files")]
     FileInfo(FileInfoQueryData),
+    #[cfg(feature = "contracts")]
     ContractBytecode(ContractBytecodeQueryData),
+    #[cfg(feature = "contracts")]
     ContractCall(ContractCallQueryData),
+    #[cfg(feature = "tokens")]
     TokenInfo(TokenInfoQueryData),
+    #[cfg(feature = "contracts")]
     ContractInfo(ContractInfoQueryData),
+    #[cfg(feature = "tokens")]
     TokenNftInfo(TokenNftInfoQueryData),
+    #[cfg(feature = "topics")]
     TopicInfo(TopicInfoQueryData),
+    #[cfg(feature = "schedules")]
     ScheduleInfo(ScheduleInfoQueryData),
     NetworkVersionInfo(NetworkVersionInfoQueryData),
 }
@@ -97,36 +127,179 @@ pub enum AnyQueryResponse {
     TransactionRecord(Box<TransactionRecord>),
 
     /// Response from [`FileContentsQuery`](crate::FileContentsQuery).
+    #[cfg(feature = "files")]
     FileContents(FileContentsResponse),
 
     /// Response from [`FileInfoQuery`](crate::FileInfoQuery).
+    #[cfg(feature = "files")]
     FileInfo(FileInfo),
 
     /// Response from [`ContractBytecodeQuery`](crate::ContractBytecodeQuery).
+    #[cfg(feature = "contracts")]
     ContractBytecode(Vec<u8>),
 
     /// Response from [`ContractCallQuery`](crate::ContractCallQuery).
+    #[cfg(feature = "contracts")]
     ContractCall(ContractFunctionResult),
 
     /// Response from [`TokenInfoQuery`](crate::TokenInfoQuery).
+    #[cfg(feature = "tokens")]
     TokenInfo(Box<TokenInfo>),
 
     /// Response from [`TopicInfoQuery`](crate::TopicInfoQuery).
+    #[cfg(feature = "topics")]
     TopicInfo(TopicInfo),
 
     /// Response from [`ContractInfoQuery`](crate::ContractInfoQuery).
+    #[cfg(feature = "contracts")]
     ContractInfo(ContractInfo),
 
     /// Response from [`TokenNftInfoQuery`](crate::TokenNftInfoQuery).
+    #[cfg(feature = "tokens")]
     TokenNftInfo(TokenNftInfo),
 
     /// Response from [`ScheduleInfoQuery`](crate::ScheduleInfoQuery).
+    #[cfg(feature = "schedules")]
     ScheduleInfo(ScheduleInfo),
 
     /// Response from [`NetworkVersionInfoQuery`](crate::NetworkVersionInfoQuery).
     NetworkVersionInfo(NetworkVersionInfo),
 }
 
+impl AnyQuery {
+    /// Parse a query from bytes produced by [`Query::to_bytes`].
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if `bytes` isn't a valid query, the kind of query isn't supported,
+    ///   or its payment transaction is malformed.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        Self::from_query(services::Query::decode(bytes).map_err(Error::from_protobuf)?)
+    }
+
+    /// Create a query from protobuf, IE, as returned by [`Query::to_protobuf`].
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if the kind of query isn't supported, or its payment transaction is malformed.
+    #[cfg(feature = "protobuf")]
+    pub fn from_protobuf(query: services::Query) -> crate::Result<Self> {
+        Self::from_query(query)
+    }
+
+    fn from_query(query: services::Query) -> crate::Result<Self> {
+        let (header, data) = AnyQueryData::from_query_protobuf(pb_getf!(query, query)?)?;
+
+        let mut query = Self { data, payment: PaymentTransaction::default(), fan_out: 1 };
+
+        if let Some(payment) = header.and_then(|it| it.payment) {
+            query.payment.signed(SignedPayment::from_protobuf(payment)?);
+        }
+
+        Ok(query)
+    }
+}
+
+impl AnyQueryData {
+    fn from_query_protobuf(
+        query: services::query::Query,
+    ) -> crate::Result<(Option<services::QueryHeader>, Self)> {
+        use services::query::Query::*;
+
+        Ok(match query {
+            CryptogetAccountBalance(pb) => (
+                pb.header.clone(),
+                Self::AccountBalance(AccountBalanceQueryData::from_protobuf(pb)?),
+            ),
+            CryptoGetInfo(pb) => {
+                (pb.header.clone(), Self::AccountInfo(AccountInfoQueryData::from_protobuf(pb)?))
+            }
+            CryptoGetAccountRecords(pb) => (
+                pb.header.clone(),
+                Self::AccountRecords(AccountRecordsQueryData::from_protobuf(pb)?),
+            ),
+            TransactionGetReceipt(pb) => (
+                pb.header.clone(),
+                Self::TransactionReceipt(TransactionReceiptQueryData::from_protobuf(pb)?),
+            ),
+            TransactionGetRecord(pb) => (
+                pb.header.clone(),
+                Self::TransactionRecord(TransactionRecordQueryData::from_protobuf(pb)?),
+            ),
+            #[cfg(feature = "files")]
+            FileGetContents(pb) => {
+                (pb.header.clone(), Self::FileContents(FileContentsQueryData::from_protobuf(pb)?))
+            }
+            #[cfg(feature = "files")]
+            FileGetInfo(pb) => {
+                (pb.header.clone(), Self::FileInfo(FileInfoQueryData::from_protobuf(pb)?))
+            }
+            #[cfg(feature = "contracts")]
+            ContractGetBytecode(pb) => (
+                pb.header.clone(),
+                Self::ContractBytecode(ContractBytecodeQueryData::from_protobuf(pb)?),
+            ),
+            #[cfg(feature = "contracts")]
+            ContractCallLocal(pb) => {
+                (pb.header.clone(), Self::ContractCall(ContractCallQueryData::from_protobuf(pb)?))
+            }
+            #[cfg(feature = "contracts")]
+            ContractGetInfo(pb) => {
+                (pb.header.clone(), Self::ContractInfo(ContractInfoQueryData::from_protobuf(pb)?))
+            }
+            #[cfg(feature = "tokens")]
+            TokenGetInfo(pb) => {
+                (pb.header.clone(), Self::TokenInfo(TokenInfoQueryData::from_protobuf(pb)?))
+            }
+            #[cfg(feature = "tokens")]
+            TokenGetNftInfo(pb) => {
+                (pb.header.clone(), Self::TokenNftInfo(TokenNftInfoQueryData::from_protobuf(pb)?))
+            }
+            #[cfg(feature = "topics")]
+            ConsensusGetTopicInfo(pb) => {
+                (pb.header.clone(), Self::TopicInfo(TopicInfoQueryData::from_protobuf(pb)?))
+            }
+            #[cfg(feature = "schedules")]
+            ScheduleGetInfo(pb) => {
+                (pb.header.clone(), Self::ScheduleInfo(ScheduleInfoQueryData::from_protobuf(pb)?))
+            }
+            NetworkGetVersionInfo(pb) => (
+                pb.header.clone(),
+                Self::NetworkVersionInfo(NetworkVersionInfoQueryData::from_protobuf(pb)?),
+            ),
+            #[cfg(not(feature = "contracts"))]
+            ContractGetBytecode(_) | ContractCallLocal(_) | ContractGetInfo(_) => {
+                return Err(Error::from_protobuf("query requires the `contracts` feature"))
+            }
+            #[cfg(not(feature = "files"))]
+            FileGetContents(_) | FileGetInfo(_) => {
+                return Err(Error::from_protobuf("query requires the `files` feature"))
+            }
+            #[cfg(not(feature = "schedules"))]
+            ScheduleGetInfo(_) => {
+                return Err(Error::from_protobuf("query requires the `schedules` feature"))
+            }
+            #[cfg(not(feature = "tokens"))]
+            TokenGetInfo(_) | TokenGetNftInfo(_) => {
+                return Err(Error::from_protobuf("query requires the `tokens` feature"))
+            }
+            #[cfg(not(feature = "topics"))]
+            ConsensusGetTopicInfo(_) => {
+                return Err(Error::from_protobuf("query requires the `topics` feature"))
+            }
+            // Unimplemented on hedera services, or not supported by this SDK
+            TransactionGetFastRecord(_)
+            | CryptoGetLiveHash(_)
+            | CryptoGetProxyStakers(_)
+            | GetBySolidityId(_)
+            | TokenGetAccountNftInfos(_)
+            | TokenGetNftInfos(_)
+            | NetworkGetExecutionTime(_)
+            | ContractGetRecords(_)
+            | AccountDetails(_)
+            | GetByKey(_) => return Err(Error::from_protobuf("unsupported query kind")),
+        })
+    }
+}
+
 impl ToQueryProtobuf for AnyQueryData {
     fn to_query_protobuf(&self, header: services::QueryHeader) -> services::Query {
         match self {
@@ -135,14 +308,23 @@ impl ToQueryProtobuf for AnyQueryData {
             Self::AccountRecords(data) => data.to_query_protobuf(header),
             Self::TransactionReceipt(data) => data.to_query_protobuf(header),
             Self::TransactionRecord(data) => data.to_query_protobuf(header),
+            #[cfg(feature = "files")]
             Self::FileContents(data) => data.to_query_protobuf(header),
+            #[cfg(feature = "files")]
             Self::FileInfo(data) => data.to_query_protobuf(header),
+            #[cfg(feature = "contracts")]
             Self::ContractBytecode(data) => data.to_query_protobuf(header),
+            #[cfg(feature = "contracts")]
             Self::ContractCall(data) => data.to_query_protobuf(header),
+            #[cfg(feature = "contracts")]
             Self::ContractInfo(data) => data.to_query_protobuf(header),
+            #[cfg(feature = "tokens")]
             Self::TokenNftInfo(data) => data.to_query_protobuf(header),
+            #[cfg(feature = "tokens")]
             Self::TokenInfo(data) => data.to_query_protobuf(header),
+            #[cfg(feature = "topics")]
             Self::TopicInfo(data) => data.to_query_protobuf(header),
+            #[cfg(feature = "schedules")]
             Self::ScheduleInfo(data) => data.to_query_protobuf(header),
             Self::NetworkVersionInfo(data) => data.to_query_protobuf(header),
         }
@@ -159,14 +341,23 @@ impl QueryExecute for AnyQueryData {
             Self::AccountRecords(query) => query.is_payment_required(),
             Self::TransactionReceipt(query) => query.is_payment_required(),
             Self::TransactionRecord(query) => query.is_payment_required(),
+            #[cfg(feature = "files")]
             Self::FileContents(query) => query.is_payment_required(),
+            #[cfg(feature = "files")]
             Self::FileInfo(query) => query.is_payment_required(),
+            #[cfg(feature = "contracts")]
             Self::ContractBytecode(query) => query.is_payment_required(),
+            #[cfg(feature = "contracts")]
             Self::ContractCall(query) => query.is_payment_required(),
+            #[cfg(feature = "contracts")]
             Self::ContractInfo(query) => query.is_payment_required(),
+            #[cfg(feature = "tokens")]
             Self::TokenNftInfo(query) => query.is_payment_required(),
+            #[cfg(feature = "tokens")]
             Self::TokenInfo(query) => query.is_payment_required(),
+            #[cfg(feature = "topics")]
             Self::TopicInfo(query) => query.is_payment_required(),
+            #[cfg(feature = "schedules")]
             Self::ScheduleInfo(query) => query.is_payment_required(),
             Self::NetworkVersionInfo(query) => query.is_payment_required(),
         }
@@ -179,14 +370,23 @@ impl QueryExecute for AnyQueryData {
             Self::AccountRecords(query) => query.map_cost(cost),
             Self::TransactionReceipt(query) => query.map_cost(cost),
             Self::TransactionRecord(query) => query.map_cost(cost),
+            #[cfg(feature = "files")]
             Self::FileContents(query) => query.map_cost(cost),
+            #[cfg(feature = "files")]
             Self::FileInfo(query) => query.map_cost(cost),
+            #[cfg(feature = "contracts")]
             Self::ContractBytecode(query) => query.map_cost(cost),
+            #[cfg(feature = "contracts")]
             Self::ContractCall(query) => query.map_cost(cost),
+            #[cfg(feature = "contracts")]
             Self::ContractInfo(query) => query.map_cost(cost),
+            #[cfg(feature = "tokens")]
             Self::TokenNftInfo(query) => query.map_cost(cost),
+            #[cfg(feature = "tokens")]
             Self::TokenInfo(query) => query.map_cost(cost),
+            #[cfg(feature = "topics")]
             Self::TopicInfo(query) => query.map_cost(cost),
+            #[cfg(feature = "schedules")]
             Self::ScheduleInfo(query) => query.map_cost(cost),
             Self::NetworkVersionInfo(query) => query.map_cost(cost),
         }
@@ -203,14 +403,23 @@ impl QueryExecute for AnyQueryData {
             Self::AccountRecords(query) => query.execute(channel, request),
             Self::TransactionReceipt(query) => query.execute(channel, request),
             Self::TransactionRecord(query) => query.execute(channel, request),
+            #[cfg(feature = "files")]
             Self::FileContents(query) => query.execute(channel, request),
+            #[cfg(feature = "files")]
             Self::FileInfo(query) => query.execute(channel, request),
+            #[cfg(feature = "contracts")]
             Self::ContractBytecode(query) => query.execute(channel, request),
+            #[cfg(feature = "contracts")]
             Self::ContractCall(query) => query.execute(channel, request),
+            #[cfg(feature = "contracts")]
             Self::ContractInfo(query) => query.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenNftInfo(query) => query.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenInfo(query) => query.execute(channel, request),
+            #[cfg(feature = "topics")]
             Self::TopicInfo(query) => query.execute(channel, request),
+            #[cfg(feature = "schedules")]
             Self::ScheduleInfo(query) => query.execute(channel, request),
             Self::NetworkVersionInfo(query) => query.execute(channel, request),
         }
@@ -223,14 +432,23 @@ impl QueryExecute for AnyQueryData {
             Self::AccountRecords(query) => query.should_retry_pre_check(status),
             Self::TransactionReceipt(query) => query.should_retry_pre_check(status),
             Self::TransactionRecord(query) => query.should_retry_pre_check(status),
+            #[cfg(feature = "files")]
             Self::FileContents(query) => query.should_retry_pre_check(status),
+            #[cfg(feature = "files")]
             Self::FileInfo(query) => query.should_retry_pre_check(status),
+            #[cfg(feature = "contracts")]
             Self::ContractBytecode(query) => query.should_retry_pre_check(status),
+            #[cfg(feature = "contracts")]
             Self::ContractCall(query) => query.should_retry_pre_check(status),
+            #[cfg(feature = "contracts")]
             Self::ContractInfo(query) => query.should_retry_pre_check(status),
+            #[cfg(feature = "tokens")]
             Self::TokenNftInfo(query) => query.should_retry_pre_check(status),
+            #[cfg(feature = "tokens")]
             Self::TokenInfo(query) => query.should_retry_pre_check(status),
+            #[cfg(feature = "topics")]
             Self::TopicInfo(query) => query.should_retry_pre_check(status),
+            #[cfg(feature = "schedules")]
             Self::ScheduleInfo(query) => query.should_retry_pre_check(status),
             Self::NetworkVersionInfo(query) => query.should_retry_pre_check(status),
         }
@@ -243,14 +461,23 @@ impl QueryExecute for AnyQueryData {
             Self::AccountRecords(query) => query.should_retry(response),
             Self::TransactionReceipt(query) => query.should_retry(response),
             Self::TransactionRecord(query) => query.should_retry(response),
+            #[cfg(feature = "files")]
             Self::FileContents(query) => query.should_retry(response),
+            #[cfg(feature = "files")]
             Self::FileInfo(query) => query.should_retry(response),
+            #[cfg(feature = "contracts")]
             Self::ContractBytecode(query) => query.should_retry(response),
+            #[cfg(feature = "contracts")]
             Self::ContractCall(query) => query.should_retry(response),
+            #[cfg(feature = "contracts")]
             Self::ContractInfo(query) => query.should_retry(response),
+            #[cfg(feature = "tokens")]
             Self::TokenNftInfo(query) => query.should_retry(response),
+            #[cfg(feature = "tokens")]
             Self::TokenInfo(query) => query.should_retry(response),
+            #[cfg(feature = "topics")]
             Self::TopicInfo(query) => query.should_retry(response),
+            #[cfg(feature = "schedules")]
             Self::ScheduleInfo(query) => query.should_retry(response),
             Self::NetworkVersionInfo(query) => query.should_retry(response),
         }
@@ -263,14 +490,23 @@ impl QueryExecute for AnyQueryData {
             Self::AccountRecords(query) => query.transaction_id(),
             Self::TransactionReceipt(query) => query.transaction_id(),
             Self::TransactionRecord(query) => query.transaction_id(),
+            #[cfg(feature = "files")]
             Self::FileContents(query) => query.transaction_id(),
+            #[cfg(feature = "files")]
             Self::FileInfo(query) => query.transaction_id(),
+            #[cfg(feature = "contracts")]
             Self::ContractBytecode(query) => query.transaction_id(),
+            #[cfg(feature = "contracts")]
             Self::ContractCall(query) => query.transaction_id(),
+            #[cfg(feature = "tokens")]
             Self::TokenInfo(query) => query.transaction_id(),
+            #[cfg(feature = "contracts")]
             Self::ContractInfo(query) => query.transaction_id(),
+            #[cfg(feature = "tokens")]
             Self::TokenNftInfo(query) => query.transaction_id(),
+            #[cfg(feature = "topics")]
             Self::TopicInfo(query) => query.transaction_id(),
+            #[cfg(feature = "schedules")]
             Self::ScheduleInfo(query) => query.transaction_id(),
             Self::NetworkVersionInfo(query) => query.transaction_id(),
         }
@@ -296,28 +532,37 @@ impl QueryExecute for AnyQueryData {
             Self::TransactionRecord(query) => {
                 query.make_response(response).map(Box::new).map(AnyQueryResponse::TransactionRecord)
             }
+            #[cfg(feature = "files")]
             Self::FileContents(query) => {
                 query.make_response(response).map(AnyQueryResponse::FileContents)
             }
+            #[cfg(feature = "files")]
             Self::FileInfo(query) => query.make_response(response).map(AnyQueryResponse::FileInfo),
+            #[cfg(feature = "contracts")]
             Self::ContractBytecode(query) => {
                 query.make_response(response).map(AnyQueryResponse::ContractBytecode)
             }
+            #[cfg(feature = "contracts")]
             Self::ContractCall(query) => {
                 query.make_response(response).map(AnyQueryResponse::ContractCall)
             }
+            #[cfg(feature = "tokens")]
             Self::TokenInfo(query) => {
                 query.make_response(response).map(Box::new).map(AnyQueryResponse::TokenInfo)
             }
+            #[cfg(feature = "contracts")]
             Self::ContractInfo(query) => {
                 query.make_response(response).map(AnyQueryResponse::ContractInfo)
             }
+            #[cfg(feature = "tokens")]
             Self::TokenNftInfo(query) => {
                 query.make_response(response).map(AnyQueryResponse::TokenNftInfo)
             }
+            #[cfg(feature = "topics")]
             Self::TopicInfo(query) => {
                 query.make_response(response).map(AnyQueryResponse::TopicInfo)
             }
+            #[cfg(feature = "schedules")]
             Self::ScheduleInfo(query) => {
                 query.make_response(response).map(AnyQueryResponse::ScheduleInfo)
             }
@@ -336,14 +581,23 @@ impl ValidateChecksums for AnyQueryData {
             Self::AccountRecords(query) => query.validate_checksums(ledger_id),
             Self::TransactionReceipt(query) => query.validate_checksums(ledger_id),
             Self::TransactionRecord(query) => query.validate_checksums(ledger_id),
+            #[cfg(feature = "files")]
             Self::FileContents(query) => query.validate_checksums(ledger_id),
+            #[cfg(feature = "files")]
             Self::FileInfo(query) => query.validate_checksums(ledger_id),
+            #[cfg(feature = "contracts")]
             Self::ContractBytecode(query) => query.validate_checksums(ledger_id),
+            #[cfg(feature = "contracts")]
             Self::ContractCall(query) => query.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenInfo(query) => query.validate_checksums(ledger_id),
+            #[cfg(feature = "contracts")]
             Self::ContractInfo(query) => query.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenNftInfo(query) => query.validate_checksums(ledger_id),
+            #[cfg(feature = "topics")]
             Self::TopicInfo(query) => query.validate_checksums(ledger_id),
+            #[cfg(feature = "schedules")]
             Self::ScheduleInfo(query) => query.validate_checksums(ledger_id),
             Self::NetworkVersionInfo(query) => query.validate_checksums(ledger_id),
         }
@@ -365,17 +619,23 @@ impl FromProtobuf<services::response::Response> for AnyQueryResponse {
             CryptogetAccountBalance(_) => {
                 Self::AccountBalance(AccountBalance::from_protobuf(response)?)
             }
+            #[cfg(feature = "files")]
             FileGetContents(_) => {
                 Self::FileContents(FileContentsResponse::from_protobuf(response)?)
             }
+            #[cfg(feature = "contracts")]
             ContractGetBytecodeResponse(_) => {
                 Self::ContractBytecode(Vec::<u8>::from_protobuf(response)?)
             }
+            #[cfg(feature = "contracts")]
             ContractCallLocal(_) => {
                 Self::ContractCall(ContractFunctionResult::from_protobuf(response)?)
             }
+            #[cfg(feature = "contracts")]
             ContractGetInfo(_) => Self::ContractInfo(ContractInfo::from_protobuf(response)?),
+            #[cfg(feature = "topics")]
             ConsensusGetTopicInfo(_) => Self::TopicInfo(TopicInfo::from_protobuf(response)?),
+            #[cfg(feature = "schedules")]
             ScheduleGetInfo(_) => Self::ScheduleInfo(ScheduleInfo::from_protobuf(response)?),
             CryptoGetProxyStakers(_) => {
                 Self::AccountStakers(AllProxyStakers::from_protobuf(response)?)
@@ -389,11 +649,34 @@ impl FromProtobuf<services::response::Response> for AnyQueryResponse {
             NetworkGetVersionInfo(_) => {
                 Self::NetworkVersionInfo(NetworkVersionInfo::from_protobuf(response)?)
             }
+            #[cfg(feature = "files")]
             FileGetInfo(_) => Self::FileInfo(FileInfo::from_protobuf(response)?),
+            #[cfg(feature = "tokens")]
             TokenGetInfo(_) => Self::TokenInfo(Box::new(TokenInfo::from_protobuf(response)?)),
+            #[cfg(feature = "tokens")]
             TokenGetNftInfo(_) | TokenGetNftInfos(_) => {
                 Self::TokenNftInfo(TokenNftInfo::from_protobuf(response)?)
             }
+            #[cfg(not(feature = "contracts"))]
+            ContractGetBytecodeResponse(_) | ContractCallLocal(_) | ContractGetInfo(_) => {
+                return Err(Error::from_protobuf("response requires the `contracts` feature"))
+            }
+            #[cfg(not(feature = "files"))]
+            FileGetContents(_) | FileGetInfo(_) => {
+                return Err(Error::from_protobuf("response requires the `files` feature"))
+            }
+            #[cfg(not(feature = "schedules"))]
+            ScheduleGetInfo(_) => {
+                return Err(Error::from_protobuf("response requires the `schedules` feature"))
+            }
+            #[cfg(not(feature = "tokens"))]
+            TokenGetInfo(_) | TokenGetNftInfo(_) | TokenGetNftInfos(_) => {
+                return Err(Error::from_protobuf("response requires the `tokens` feature"))
+            }
+            #[cfg(not(feature = "topics"))]
+            ConsensusGetTopicInfo(_) => {
+                return Err(Error::from_protobuf("response requires the `topics` feature"))
+            }
             // Unimplemented on hedera services
             TransactionGetFastRecord(_)
             | CryptoGetLiveHash(_)
@@ -402,7 +685,9 @@ impl FromProtobuf<services::response::Response> for AnyQueryResponse {
             | NetworkGetExecutionTime(_)
             | ContractGetRecordsResponse(_)
             | AccountDetails(_)
-            | GetByKey(_) => unreachable!(),
+            | GetByKey(_) => {
+                return Err(Error::from_protobuf("response type isn't supported by the SDK"))
+            }
         })
     }
 }
@@ -24,9 +24,9 @@ use crate::{
 
 /// Describes a specific query that can be executed on the Hiero network.
 pub trait QueryExecute:
-    Sync + Send + Into<AnyQueryData> + Clone + Debug + ToQueryProtobuf + ValidateChecksums
+    Sync + Send + 'static + Into<AnyQueryData> + Clone + Debug + ToQueryProtobuf + ValidateChecksums
 {
-    type Response: FromProtobuf<services::response::Response>;
+    type Response: FromProtobuf<services::response::Response> + Send + 'static;
 
     /// Returns `true` if this query requires a payment to be submitted.
     fn is_payment_required(&self) -> bool {
@@ -83,10 +83,18 @@ where
     type Context = ();
 
     fn node_account_ids(&self) -> Option<&[AccountId]> {
+        if let Some(signed) = self.signed_payment() {
+            return Some(std::slice::from_ref(&signed.node_account_id));
+        }
+
         self.payment.node_account_ids()
     }
 
     fn transaction_id(&self) -> Option<TransactionId> {
+        if let Some(signed) = self.signed_payment() {
+            return Some(signed.transaction_id);
+        }
+
         self.payment.transaction_id()
     }
 
@@ -98,6 +106,10 @@ where
         self.payment.operator_account_id()
     }
 
+    fn frozen_environment(&self) -> Option<u64> {
+        self.payment.frozen_environment()
+    }
+
     fn should_retry_pre_check(&self, status: Status) -> bool {
         self.data.should_retry_pre_check(status)
     }
@@ -111,7 +123,9 @@ where
         transaction_id: Option<&TransactionId>,
         node_account_id: AccountId,
     ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
-        let payment = if self.data.is_payment_required() {
+        let payment = if let Some(signed) = self.signed_payment() {
+            Some(signed.transaction.clone())
+        } else if self.data.is_payment_required() {
             Some(self.payment.make_request(transaction_id, node_account_id)?.0)
         } else {
             None
@@ -206,3 +220,39 @@ pub(crate) fn response_header(
 
     header.as_ref().ok_or_else(|| Error::from_protobuf("unexpected missing `header` in `Response`"))
 }
+
+/// Removes the header from `response`, leaving only the body.
+pub(crate) fn clear_response_header(response: &mut Option<services::response::Response>) {
+    use services::response::Response::*;
+
+    let header = match response {
+        Some(CryptogetAccountBalance(response)) => &mut response.header,
+        Some(GetByKey(response)) => &mut response.header,
+        Some(GetBySolidityId(response)) => &mut response.header,
+        Some(ContractCallLocal(response)) => &mut response.header,
+        Some(ContractGetBytecodeResponse(response)) => &mut response.header,
+        Some(ContractGetInfo(response)) => &mut response.header,
+        Some(ContractGetRecordsResponse(response)) => &mut response.header,
+        Some(CryptoGetAccountRecords(response)) => &mut response.header,
+        Some(CryptoGetInfo(response)) => &mut response.header,
+        Some(CryptoGetLiveHash(response)) => &mut response.header,
+        Some(CryptoGetProxyStakers(response)) => &mut response.header,
+        Some(FileGetContents(response)) => &mut response.header,
+        Some(FileGetInfo(response)) => &mut response.header,
+        Some(TransactionGetReceipt(response)) => &mut response.header,
+        Some(TransactionGetRecord(response)) => &mut response.header,
+        Some(TransactionGetFastRecord(response)) => &mut response.header,
+        Some(ConsensusGetTopicInfo(response)) => &mut response.header,
+        Some(NetworkGetVersionInfo(response)) => &mut response.header,
+        Some(TokenGetInfo(response)) => &mut response.header,
+        Some(ScheduleGetInfo(response)) => &mut response.header,
+        Some(TokenGetAccountNftInfos(response)) => &mut response.header,
+        Some(TokenGetNftInfo(response)) => &mut response.header,
+        Some(TokenGetNftInfos(response)) => &mut response.header,
+        Some(NetworkGetExecutionTime(response)) => &mut response.header,
+        Some(AccountDetails(response)) => &mut response.header,
+        None => return,
+    };
+
+    *header = None;
+}
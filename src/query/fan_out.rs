@@ -0,0 +1,374 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use futures_util::stream::FuturesUnordered;
+use futures_util::StreamExt;
+use hedera_proto::services;
+use prost::Message;
+use tonic::transport::Channel;
+
+use crate::entity_id::ValidateChecksums;
+use crate::execute::{
+    execute,
+    execution_plan,
+    Execute,
+};
+use crate::ledger_id::RefLedgerId;
+use crate::query::execute::clear_response_header;
+use crate::query::QueryExecute;
+use crate::{
+    AccountId,
+    BoxGrpcFuture,
+    Client,
+    Error,
+    Query,
+    Status,
+    TransactionId,
+};
+
+/// A node responded differently to a [fanned out](crate::Query::fan_out) query
+/// than the node whose response was returned.
+///
+/// See [`Client::set_response_mismatch_hook`](crate::Client::set_response_mismatch_hook).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ResponseMismatch {
+    /// The node whose response was returned.
+    pub node_account_id: AccountId,
+
+    /// The node whose response differs from the returned one.
+    pub mismatched_node_account_id: AccountId,
+}
+
+/// A query that's only sent to a single node,
+/// and that keeps the encoded response body to compare it with the responses of other nodes.
+struct SingleNodeQuery<D: QueryExecute> {
+    query: Query<D>,
+    node_account_id: [AccountId; 1],
+}
+
+/// Successful responses to a fanned out query, grouped by their encoded body.
+struct ResponseGroup<R> {
+    bytes: Vec<u8>,
+    response: R,
+    node_account_ids: Vec<AccountId>,
+}
+
+impl<D: QueryExecute> Query<D> {
+    /// Send this query to up to [`fan_out`](Self::get_fan_out) nodes concurrently,
+    /// and return the first response that a majority of them agree on.
+    ///
+    /// If no majority can be reached, the response is still returned when every node that responded agreed on it.
+    ///
+    /// Nodes that haven't responded by then are left to respond in the background,
+    /// and their responses are compared with the returned one.
+    pub(super) async fn execute_fan_out(
+        &self,
+        client: &Client,
+        timeout: Option<std::time::Duration>,
+    ) -> crate::Result<D::Response> {
+        let node_account_ids =
+            execution_plan(client, Execute::node_account_ids(self), timeout)?.node_account_ids;
+
+        if self.fan_out > node_account_ids.len() {
+            return Err(Error::basic_parse(format!(
+                "query can't be fanned out to {} nodes, it can only be sent to {}",
+                self.fan_out,
+                node_account_ids.len()
+            )));
+        }
+
+        let mut pending: FuturesUnordered<_> = node_account_ids
+            .into_iter()
+            .take(self.fan_out)
+            .map(|node_account_id| {
+                let client = client.clone();
+                let query =
+                    SingleNodeQuery { query: self.clone(), node_account_id: [node_account_id] };
+
+                async move { (node_account_id, execute(&client, &query, timeout).await) }
+            })
+            .collect();
+
+        let quorum = pending.len() / 2 + 1;
+
+        let mut groups: Vec<ResponseGroup<D::Response>> = Vec::new();
+        let mut last_error = None;
+
+        while let Some((node_account_id, result)) = pending.next().await {
+            let (response, bytes) = match result {
+                Ok(it) => it,
+                Err(e) => {
+                    last_error = Some(e);
+                    continue;
+                }
+            };
+
+            let index = match groups.iter().position(|group| group.bytes == bytes) {
+                Some(index) => index,
+                None => {
+                    groups.push(ResponseGroup { bytes, response, node_account_ids: Vec::new() });
+                    groups.len() - 1
+                }
+            };
+
+            groups[index].node_account_ids.push(node_account_id);
+
+            if groups[index].node_account_ids.len() >= quorum {
+                let group = groups.swap_remove(index);
+
+                return Ok(finish(client, group, &groups, pending));
+            }
+        }
+
+        let responses = groups.iter().map(|group| group.node_account_ids.len()).sum();
+        let distinct_responses = groups.len();
+
+        match groups.pop() {
+            // every node that responded agreed.
+            Some(group) if groups.is_empty() => Ok(group.response),
+            Some(_) => Err(Error::FanOutNoQuorum { responses, distinct_responses, quorum }),
+            // every node failed, report the last failure.
+            None => Err(last_error
+                .unwrap_or_else(|| Error::basic_parse("query was fanned out to no nodes"))),
+        }
+    }
+}
+
+/// Report every response that differs from `group`,
+/// including the ones of nodes that are still pending, then return the agreed on response.
+fn finish<R, F>(
+    client: &Client,
+    group: ResponseGroup<R>,
+    others: &[ResponseGroup<R>],
+    mut pending: FuturesUnordered<F>,
+) -> R
+where
+    F: std::future::Future<Output = (AccountId, crate::Result<(R, Vec<u8>)>)> + Send + 'static,
+    R: Send + 'static,
+{
+    let ResponseGroup { bytes, response, node_account_ids } = group;
+    let node_account_id = node_account_ids[0];
+
+    for &mismatched_node_account_id in others.iter().flat_map(|other| &other.node_account_ids) {
+        client.emit_response_mismatch(&ResponseMismatch {
+            node_account_id,
+            mismatched_node_account_id,
+        });
+    }
+
+    if !pending.is_empty() {
+        let client = client.clone();
+
+        tokio::task::spawn(async move {
+            while let Some((mismatched_node_account_id, result)) = pending.next().await {
+                match result {
+                    Ok((_, other)) if other != bytes => {
+                        client.emit_response_mismatch(&ResponseMismatch {
+                            node_account_id,
+                            mismatched_node_account_id,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        });
+    }
+
+    response
+}
+
+impl<D: QueryExecute> ValidateChecksums for SingleNodeQuery<D> {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        self.query.validate_checksums(ledger_id)
+    }
+}
+
+impl<D: QueryExecute> Execute for SingleNodeQuery<D> {
+    type GrpcRequest = services::Query;
+
+    type GrpcResponse = services::Response;
+
+    type Context = ();
+
+    type Response = (D::Response, Vec<u8>);
+
+    fn operator_account_id(&self) -> Option<&AccountId> {
+        Execute::operator_account_id(&self.query)
+    }
+
+    fn node_account_ids(&self) -> Option<&[AccountId]> {
+        Some(&self.node_account_id)
+    }
+
+    fn transaction_id(&self) -> Option<TransactionId> {
+        Execute::transaction_id(&self.query)
+    }
+
+    fn requires_transaction_id(&self) -> bool {
+        Execute::requires_transaction_id(&self.query)
+    }
+
+    fn should_retry_pre_check(&self, status: Status) -> bool {
+        Execute::should_retry_pre_check(&self.query, status)
+    }
+
+    fn should_retry(&self, response: &Self::GrpcResponse) -> bool {
+        Execute::should_retry(&self.query, response)
+    }
+
+    fn make_request(
+        &self,
+        transaction_id: Option<&TransactionId>,
+        node_account_id: AccountId,
+    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+        Execute::make_request(&self.query, transaction_id, node_account_id)
+    }
+
+    fn execute(
+        &self,
+        channel: Channel,
+        request: Self::GrpcRequest,
+    ) -> BoxGrpcFuture<'_, Self::GrpcResponse> {
+        Execute::execute(&self.query, channel, request)
+    }
+
+    fn make_response(
+        &self,
+        response: Self::GrpcResponse,
+        context: Self::Context,
+        node_account_id: AccountId,
+        transaction_id: Option<&TransactionId>,
+    ) -> crate::Result<Self::Response> {
+        // the header (IE, the cost) can differ between nodes that agree on the response itself.
+        // note: prost drops unknown fields, and encodes fields in order, so the re-encoded body is canonical.
+        let mut body = response.clone();
+        clear_response_header(&mut body.response);
+        let bytes = body.encode_to_vec();
+
+        let response = Execute::make_response(
+            &self.query,
+            response,
+            context,
+            node_account_id,
+            transaction_id,
+        )?;
+
+        Ok((response, bytes))
+    }
+
+    fn make_error_pre_check(
+        &self,
+        status: Status,
+        transaction_id: Option<&TransactionId>,
+        response: Self::GrpcResponse,
+    ) -> Error {
+        Execute::make_error_pre_check(&self.query, status, transaction_id, response)
+    }
+
+    fn response_pre_check_status(response: &Self::GrpcResponse) -> crate::Result<i32> {
+        <Query<D> as Execute>::response_pre_check_status(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use hedera_proto::services;
+
+    use super::SingleNodeQuery;
+    use crate::execute::Execute;
+    use crate::transaction::test_helpers::TEST_TX_ID;
+    use crate::{
+        AccountBalanceQuery,
+        AccountId,
+        Client,
+        Error,
+        ToProtobuf,
+        TransactionReceiptQuery,
+    };
+
+    fn client() -> Client {
+        Client::for_network(HashMap::from([
+            ("127.0.0.1:1".to_owned(), AccountId::new(0, 0, 3)),
+            ("127.0.0.1:2".to_owned(), AccountId::new(0, 0, 4)),
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn single_node() {
+        let mut query = AccountBalanceQuery::new();
+        query
+            .account_id(AccountId::new(0, 0, 1001))
+            .node_account_ids([AccountId::new(0, 0, 3), AccountId::new(0, 0, 4)]);
+
+        let query = SingleNodeQuery { query, node_account_id: [AccountId::new(0, 0, 4)] };
+
+        assert_eq!(Execute::node_account_ids(&query), Some([AccountId::new(0, 0, 4)].as_slice()));
+    }
+
+    #[tokio::test]
+    async fn fan_out_all_nodes_fail() {
+        // both nodes are unreachable, so the error of one of them is returned.
+        let client = client();
+
+        let result = TransactionReceiptQuery::new()
+            .transaction_id(TEST_TX_ID)
+            .node_account_ids([AccountId::new(0, 0, 3), AccountId::new(0, 0, 4)])
+            .fan_out(2)
+            .execute_with_timeout(&client, Duration::from_millis(500))
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn responses_are_compared_without_header() {
+        let query = SingleNodeQuery {
+            query: AccountBalanceQuery::new(),
+            node_account_id: [AccountId::new(0, 0, 3)],
+        };
+
+        let response = |cost| services::Response {
+            response: Some(services::response::Response::CryptogetAccountBalance(
+                services::CryptoGetAccountBalanceResponse {
+                    header: Some(services::ResponseHeader { cost, ..Default::default() }),
+                    account_id: Some(AccountId::new(0, 0, 1001).to_protobuf()),
+                    balance: 10,
+                    ..Default::default()
+                },
+            )),
+        };
+
+        let (_, first) =
+            query.make_response(response(1), (), AccountId::new(0, 0, 3), None).unwrap();
+        let (_, second) =
+            query.make_response(response(2), (), AccountId::new(0, 0, 4), None).unwrap();
+
+        assert_eq!(first, second);
+    }
+
+    #[tokio::test]
+    async fn fan_out_to_too_few_or_many_nodes() {
+        let client = client();
+
+        let result = TransactionReceiptQuery::new()
+            .transaction_id(TEST_TX_ID)
+            .fan_out(0)
+            .execute(&client)
+            .await;
+
+        assert!(matches!(result, Err(Error::BasicParse(_))));
+
+        let result = TransactionReceiptQuery::new()
+            .transaction_id(TEST_TX_ID)
+            .node_account_ids([AccountId::new(0, 0, 3), AccountId::new(0, 0, 4)])
+            .fan_out(3)
+            .execute(&client)
+            .await;
+
+        assert!(matches!(result, Err(Error::BasicParse(_))));
+    }
+}
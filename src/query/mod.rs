@@ -1,23 +1,34 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use futures_core::future::BoxFuture;
+use hedera_proto::services;
+use prost::Message;
 use time::Duration;
 
-use crate::execute::execute;
+use crate::execute::{
+    cancellable,
+    execute,
+};
 use crate::query::cost::QueryCost;
-use crate::query::payment_transaction::PaymentTransaction;
+use crate::query::payment_transaction::{
+    PaymentTransaction,
+    SignedPayment,
+};
 use crate::{
     AccountId,
+    CancellationToken,
     Client,
     Error,
     Hbar,
     TransactionId,
     TransactionReceiptQuery,
+    TransferTransaction,
 };
 
 mod any;
 mod cost;
 mod execute;
+mod fan_out;
 pub(super) mod payment_transaction;
 mod protobuf;
 
@@ -30,16 +41,19 @@ pub(crate) use execute::{
     response_header,
     QueryExecute,
 };
+pub use fan_out::ResponseMismatch;
 pub(crate) use protobuf::ToQueryProtobuf;
 
 /// A query that can be executed on the Hiero network.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Query<D>
 where
     D: QueryExecute,
 {
     pub(crate) data: D,
     pub(crate) payment: PaymentTransaction,
+    // note: `0` (the default) means the same as `1`, that the query isn't fanned out.
+    fan_out: usize,
 }
 
 impl<D> Query<D>
@@ -177,7 +191,124 @@ where
         self
     }
 
+    /// Returns true if this query has an explicit, already signed payment transaction.
+    #[must_use]
+    pub fn has_payment_transaction(&self) -> bool {
+        self.payment.get_signed().is_some()
+    }
+
+    /// Sets an explicit, already signed payment transaction for this query.
+    ///
+    /// This allows the query to be paid for by an account other than the client's operator,
+    /// without the operator ever having access to the payer's keys:
+    /// the payer builds, freezes, and signs a [`TransferTransaction`] that moves the query payment
+    /// from their account to a single node, and the query is then sent to that node.
+    ///
+    /// When set, the query's cost isn't requested, and the [`max_payment_amount`](Self::max_payment_amount)
+    /// isn't checked; the node will reject the payment if it's insufficient.
+    ///
+    /// Since the payment has a fixed transaction ID, the query can only be successfully executed once.
+    ///
+    /// Free queries (see [`is_payment_required`](Self::is_payment_required)) ignore the payment,
+    /// and are sent without it.
+    ///
+    /// # Panics
+    /// - If `transaction` isn't frozen.
+    /// - If `transaction` isn't for exactly one node.
+    pub fn payment_transaction(&mut self, transaction: &TransferTransaction) -> &mut Self {
+        assert!(transaction.is_frozen(), "payment transaction must be frozen");
+
+        let node_account_id = match transaction.get_node_account_ids() {
+            Some(&[node_account_id]) => node_account_id,
+            _ => panic!("payment transaction must be for exactly one node"),
+        };
+
+        // note: a frozen transaction always has a transaction ID, so neither of these can fail.
+        let transaction_id = transaction.get_transaction_id().unwrap();
+        let sources = transaction.make_sources().unwrap();
+
+        self.payment.signed(SignedPayment {
+            transaction: sources.transactions()[0].clone(),
+            transaction_id,
+            node_account_id,
+        });
+
+        self
+    }
+
+    /// Returns the number of nodes this query is sent to concurrently.
+    #[must_use]
+    pub fn get_fan_out(&self) -> usize {
+        self.fan_out
+    }
+
+    /// Sets the number of nodes this query is sent to concurrently, defaults to `1`.
+    ///
+    /// The first response that a majority of the nodes agree on is returned,
+    /// which improves tail latency when some nodes are slow or far away.
+    /// If nodes fail, a response is still returned when every node that did respond agreed on it,
+    /// otherwise [`Error::FanOutNoQuorum`](crate::Error::FanOutNoQuorum) is returned.
+    ///
+    /// Nodes that haven't responded by then are left to respond in the background, and any response that differs
+    /// from the returned one is logged, and passed to the [response mismatch hook](Client::set_response_mismatch_hook).
+    ///
+    /// Only use this for queries that don't change what they return between nodes, IE, info queries.
+    /// Each node is sent its own payment for paid queries, so this can multiply the cost of the query.
+    /// Queries with a [`payment_transaction`](Self::payment_transaction) are only for one node, and aren't fanned out.
+    ///
+    /// Executing the query fails with [`Error::BasicParse`] if `nodes` is `0`,
+    /// or if it's more than the number of nodes the query can be sent to.
+    pub fn fan_out(&mut self, nodes: usize) -> &mut Self {
+        self.fan_out = nodes;
+        self
+    }
+
+    /// Convert this query to protobuf encoded bytes.
+    ///
+    /// The bytes can be sent elsewhere, IE, to a service that executes queries,
+    /// and turned back into a query there with [`AnyQuery::from_bytes`].
+    ///
+    /// Only the query itself and its [`payment_transaction`](Self::payment_transaction) (if set) are included;
+    /// without a payment transaction, the query is paid for by whoever executes it.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.make_query_protobuf().encode_to_vec()
+    }
+
+    /// Convert this query to protobuf.
+    ///
+    /// Like [`to_bytes`](Self::to_bytes), only the query itself and its
+    /// [`payment_transaction`](Self::payment_transaction) (if set) are included.
+    #[cfg(feature = "protobuf")]
+    #[must_use]
+    pub fn to_protobuf(&self) -> services::Query {
+        self.make_query_protobuf()
+    }
+
+    /// Returns the explicit payment transaction, unless this query is free.
+    fn signed_payment(&self) -> Option<&SignedPayment> {
+        self.payment.get_signed().filter(|_| self.data.is_payment_required())
+    }
+
+    fn make_query_protobuf(&self) -> services::Query {
+        let payment = self.signed_payment().map(|it| it.transaction.clone());
+
+        self.data.to_query_protobuf(services::QueryHeader { response_type: 0, payment })
+    }
+
+    /// Returns `true` if this query has to be paid for.
+    ///
+    /// Free queries (IE, [`TransactionReceiptQuery`] and [`AccountBalanceQuery`](crate::AccountBalanceQuery))
+    /// are sent without a payment, and never request their cost from the network,
+    /// so any payment amount set on them is ignored.
+    #[must_use]
+    pub fn is_payment_required(&self) -> bool {
+        self.data.is_payment_required()
+    }
+
     /// Fetch the cost of this query.
+    ///
+    /// For free queries, this returns zero without a request to the network.
     pub async fn get_cost(&self, client: &Client) -> crate::Result<Hbar> {
         self.get_cost_with_optional_timeout(client, None).await
     }
@@ -234,6 +365,10 @@ where
             })
         }
 
+        if self.fan_out == 0 {
+            return Err(Error::basic_parse("a query must be sent to at least one node"));
+        }
+
         // hack: this is a TransactionRecordQuery, which means we need to run the receipt first.
         if let Some(transaction_id) = self.data.transaction_id() {
             if self.data.is_payment_required() {
@@ -242,6 +377,10 @@ where
             }
         }
 
+        if self.signed_payment().is_some() {
+            return execute(client, self, timeout).await;
+        }
+
         if self.payment.get_amount().is_none() && self.data.is_payment_required() {
             // should this inherit the timeout?
             // payment is required but none was specified, query the cost
@@ -268,6 +407,10 @@ where
             self.payment.freeze_with(client)?;
         }
 
+        if self.get_fan_out() > 1 {
+            return self.execute_fan_out(client, timeout).await;
+        }
+
         execute(client, self, timeout).await
     }
 
@@ -281,4 +424,221 @@ where
     ) -> crate::Result<D::Response> {
         self.execute_with_optional_timeout(client, Some(timeout)).await
     }
+
+    /// Execute this query against the provided client of the Hiero network,
+    /// stopping early if `cancellation` is cancelled.
+    ///
+    /// Cancelling aborts any in-flight request and pending retry backoff,
+    /// the same as dropping the returned future would.
+    ///
+    /// # Errors
+    /// - [`Error::Cancelled`] if `cancellation` is cancelled before the query completes.
+    /// - Any error [`execute`](Self::execute) can return.
+    pub async fn execute_with_cancellation(
+        &mut self,
+        client: &Client,
+        cancellation: &CancellationToken,
+    ) -> crate::Result<D::Response> {
+        cancellable(cancellation, self.execute_with_optional_timeout(client, None)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use hedera_proto::services;
+
+    use super::AnyQueryData;
+    use crate::execute::Execute;
+    use crate::transaction::test_helpers::{
+        unused_private_key,
+        TEST_TX_ID,
+    };
+    use crate::{
+        AccountBalanceQuery,
+        AccountId,
+        AccountInfoQuery,
+        AnyQuery,
+        CancellationToken,
+        Client,
+        Error,
+        Hbar,
+        TransactionReceiptQuery,
+        TransactionRecordQuery,
+        TransferTransaction,
+    };
+
+    fn make_payment(node_account_id: AccountId) -> TransferTransaction {
+        let mut payment = TransferTransaction::new();
+        payment
+            .hbar_transfer(TEST_TX_ID.account_id, Hbar::from_tinybars(-10))
+            .hbar_transfer(node_account_id, Hbar::from_tinybars(10))
+            .node_account_ids([node_account_id])
+            .transaction_id(TEST_TX_ID)
+            .freeze()
+            .unwrap()
+            .sign(unused_private_key());
+
+        payment
+    }
+
+    #[test]
+    fn payment_transaction() {
+        let node_account_id = AccountId::new(0, 0, 3);
+
+        let payment = make_payment(node_account_id);
+
+        let mut query = AccountInfoQuery::new();
+        query.account_id(AccountId::new(0, 0, 1001)).payment_transaction(&payment);
+
+        assert!(query.has_payment_transaction());
+        assert_eq!(Execute::node_account_ids(&query), Some([node_account_id].as_slice()));
+        assert_eq!(Execute::transaction_id(&query), Some(TEST_TX_ID));
+
+        let (request, ()) = Execute::make_request(&query, None, node_account_id).unwrap();
+
+        let Some(services::query::Query::CryptoGetInfo(request)) = request.query else {
+            panic!("unexpected query kind")
+        };
+
+        assert_eq!(
+            request.header.unwrap().payment.unwrap(),
+            payment.make_sources().unwrap().transactions()[0]
+        );
+    }
+
+    #[test]
+    fn payment_transaction_free_query() {
+        let node_account_id = AccountId::new(0, 0, 3);
+
+        let mut query = TransactionReceiptQuery::new();
+        query.transaction_id(TEST_TX_ID).payment_transaction(&make_payment(node_account_id));
+
+        assert_eq!(Execute::node_account_ids(&query), None);
+
+        let (request, ()) = Execute::make_request(&query, None, node_account_id).unwrap();
+
+        let Some(services::query::Query::TransactionGetReceipt(request)) = request.query else {
+            panic!("unexpected query kind")
+        };
+
+        assert_eq!(request.header.unwrap().payment, None);
+        assert_eq!(
+            query.to_bytes(),
+            TransactionReceiptQuery::new().transaction_id(TEST_TX_ID).to_bytes()
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_with_cancellation() {
+        let client = Client::for_network(HashMap::from([(
+            "127.0.0.1:1".to_owned(),
+            AccountId::new(0, 0, 3),
+        )]))
+        .unwrap();
+
+        let cancellation = CancellationToken::new();
+
+        tokio::spawn({
+            let cancellation = cancellation.clone();
+            async move {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                cancellation.cancel();
+            }
+        });
+
+        // the node is unreachable, so without cancellation this would keep retrying.
+        let result = tokio::time::timeout(
+            Duration::from_secs(10),
+            TransactionReceiptQuery::new()
+                .transaction_id(TEST_TX_ID)
+                .execute_with_cancellation(&client, &cancellation),
+        )
+        .await
+        .expect("cancellation should stop the query");
+
+        assert!(matches!(result, Err(Error::Cancelled)));
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let mut query = TransactionReceiptQuery::new();
+        query.transaction_id(TEST_TX_ID).include_children(true);
+
+        let bytes = query.to_bytes();
+        let query2 = AnyQuery::from_bytes(&bytes).unwrap();
+
+        assert!(!query2.has_payment_transaction());
+        assert!(matches!(query2.data, AnyQueryData::TransactionReceipt(_)));
+        assert_eq!(query2.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn to_from_bytes_with_payment_transaction() {
+        let node_account_id = AccountId::new(0, 0, 3);
+
+        let payment = make_payment(node_account_id);
+
+        let mut query = AccountInfoQuery::new();
+        query.account_id(AccountId::new(0, 0, 1001)).payment_transaction(&payment);
+
+        let bytes = query.to_bytes();
+        let query2 = AnyQuery::from_bytes(&bytes).unwrap();
+
+        assert!(query2.has_payment_transaction());
+        assert_eq!(Execute::node_account_ids(&query2), Some([node_account_id].as_slice()));
+        assert_eq!(Execute::transaction_id(&query2), Some(TEST_TX_ID));
+        assert_eq!(query2.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn from_bytes_invalid() {
+        assert!(matches!(AnyQuery::from_bytes(&[0xff, 0xff]), Err(Error::FromProtobuf(_))));
+        assert!(matches!(AnyQuery::from_bytes(&[]), Err(Error::FromProtobuf(_))));
+    }
+
+    #[test]
+    fn is_payment_required() {
+        assert!(!TransactionReceiptQuery::new().is_payment_required());
+        assert!(!AccountBalanceQuery::new().is_payment_required());
+        assert!(AccountInfoQuery::new().is_payment_required());
+        assert!(TransactionRecordQuery::new().is_payment_required());
+    }
+
+    #[tokio::test]
+    async fn free_query_cost() {
+        // the node is unreachable, so this only succeeds if the cost isn't requested.
+        let client = Client::for_network(HashMap::from([(
+            "127.0.0.1:1".to_owned(),
+            AccountId::new(0, 0, 3),
+        )]))
+        .unwrap();
+
+        let cost = TransactionReceiptQuery::new()
+            .transaction_id(TEST_TX_ID)
+            .get_cost_with_timeout(&client, Duration::from_millis(100))
+            .await
+            .unwrap();
+
+        assert_eq!(cost, Hbar::ZERO);
+
+        let mut query = TransactionReceiptQuery::new();
+        query.transaction_id(TEST_TX_ID);
+
+        let (request, ()) = Execute::make_request(&query, None, AccountId::new(0, 0, 3)).unwrap();
+
+        let Some(services::query::Query::TransactionGetReceipt(request)) = request.query else {
+            panic!("unexpected query kind")
+        };
+
+        assert_eq!(request.header.unwrap().payment, None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn payment_transaction_not_frozen_panics() {
+        AccountInfoQuery::new().payment_transaction(&TransferTransaction::new());
+    }
 }
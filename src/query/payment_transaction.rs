@@ -2,6 +2,7 @@
 
 use hedera_proto::services;
 use hedera_proto::services::crypto_service_client::CryptoServiceClient;
+use prost::Message;
 use tonic::transport::Channel;
 
 use crate::transaction::{
@@ -9,14 +10,19 @@ use crate::transaction::{
     ChunkInfo,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
+    AccountId,
     BoxGrpcFuture,
     Error,
+    FromProtobuf,
     Hbar,
     ToProtobuf,
     Transaction,
+    TransactionId,
     ValidateChecksums,
 };
 
@@ -26,6 +32,37 @@ pub type PaymentTransaction = Transaction<PaymentTransactionData>;
 pub struct PaymentTransactionData {
     amount: Option<Hbar>,
     max_amount: Option<Hbar>,
+    signed: Option<SignedPayment>,
+}
+
+/// A payment transaction that was built and signed outside of the query.
+#[derive(Debug, Clone)]
+pub(super) struct SignedPayment {
+    pub(super) transaction: services::Transaction,
+    pub(super) transaction_id: TransactionId,
+    pub(super) node_account_id: AccountId,
+}
+
+impl FromProtobuf<services::Transaction> for SignedPayment {
+    #[allow(deprecated)]
+    fn from_protobuf(pb: services::Transaction) -> crate::Result<Self> {
+        let body_bytes = if pb.signed_transaction_bytes.is_empty() {
+            pb.body_bytes.clone()
+        } else {
+            services::SignedTransaction::decode(pb.signed_transaction_bytes.as_slice())
+                .map_err(Error::from_protobuf)?
+                .body_bytes
+        };
+
+        let body = services::TransactionBody::decode(body_bytes.as_slice())
+            .map_err(Error::from_protobuf)?;
+
+        Ok(Self {
+            transaction_id: TransactionId::from_protobuf(pb_getf!(body, transaction_id)?)?,
+            node_account_id: AccountId::from_protobuf(pb_getf!(body, node_account_id)?)?,
+            transaction: pb,
+        })
+    }
 }
 
 impl PaymentTransaction {
@@ -46,9 +83,26 @@ impl PaymentTransaction {
         self.data_mut().max_amount = amount.into();
         self
     }
+
+    pub(super) fn get_signed(&self) -> Option<&SignedPayment> {
+        self.data().signed.as_ref()
+    }
+
+    pub(super) fn signed(&mut self, payment: SignedPayment) -> &mut Self {
+        self.data_mut().signed = Some(payment);
+        self
+    }
 }
 
-impl TransactionData for PaymentTransactionData {}
+impl TransactionData for PaymentTransactionData {
+    fn validate_data(&self, _problems: &mut Vec<ValidationProblem>) {
+        // the amount is filled in when the query is executed.
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.optional_field("amount", self.amount.as_ref());
+    }
+}
 
 impl TransactionExecute for PaymentTransactionData {
     // noinspection DuplicatedCode
@@ -0,0 +1,680 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Reconcile locally submitted transactions against what reached consensus.
+//!
+//! Gather the [`ConsensusOutcome`]s for a period, either from [`TransactionRecord`]s
+//! (IE, records read from record stream files) or from the mirror node with `MirrorRecordSource`,
+//! then compare them to the transactions that were submitted with [`reconcile`].
+//!
+//! ```
+//! use hedera::reconcile::{reconcile, ConsensusOutcome};
+//! # fn f(submitted: Vec<hedera::TransactionId>, records: Vec<hedera::TransactionRecord>) {
+//! let report = reconcile(submitted, records.iter().map(ConsensusOutcome::from));
+//!
+//! for transaction_id in &report.missing {
+//!     println!("{transaction_id} never reached consensus");
+//! }
+//! # }
+//! ```
+
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+use time::OffsetDateTime;
+
+#[cfg(feature = "mirror-rest")]
+pub use self::mirror::MirrorRecordSource;
+#[cfg(feature = "mirror-rest")]
+pub(crate) use self::mirror::{
+    RestTransaction,
+    RestTransactions,
+};
+use crate::{
+    Status,
+    TransactionId,
+    TransactionRecord,
+};
+
+/// The outcome of a transaction that reached consensus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ConsensusOutcome {
+    /// The ID of the transaction.
+    pub transaction_id: TransactionId,
+
+    /// The final status of the transaction.
+    pub status: Status,
+
+    /// The consensus timestamp of the transaction.
+    pub consensus_timestamp: OffsetDateTime,
+}
+
+/// The result of [`reconcile`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ReconciliationReport {
+    /// The submitted transactions that reached consensus, in consensus order.
+    ///
+    /// A transaction that reached consensus with a failing status (IE, `INSUFFICIENT_PAYER_BALANCE`) is still here.
+    pub reached_consensus: Vec<ConsensusOutcome>,
+
+    /// The submitted transactions with no outcome, in the order they were submitted.
+    pub missing: Vec<TransactionId>,
+
+    /// Outcomes of transactions that weren't submitted (and aren't children of ones that were), in consensus order.
+    pub orphans: Vec<ConsensusOutcome>,
+}
+
+impl ConsensusOutcome {
+    /// Create a new outcome.
+    #[must_use]
+    pub fn new(
+        transaction_id: TransactionId,
+        status: Status,
+        consensus_timestamp: OffsetDateTime,
+    ) -> Self {
+        Self { transaction_id, status, consensus_timestamp }
+    }
+}
+
+impl From<&TransactionRecord> for ConsensusOutcome {
+    fn from(record: &TransactionRecord) -> Self {
+        Self::new(record.transaction_id, record.receipt.status, record.consensus_timestamp)
+    }
+}
+
+impl ReconciliationReport {
+    /// Returns true if every submitted transaction reached consensus with `SUCCESS`, and there are no orphans.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.missing.is_empty()
+            && self.orphans.is_empty()
+            && self.reached_consensus.iter().all(|it| it.status == Status::Success)
+    }
+
+    /// Returns the submitted transactions that reached consensus with a status other than `SUCCESS`.
+    pub fn failed(&self) -> impl Iterator<Item = &ConsensusOutcome> {
+        self.reached_consensus.iter().filter(|it| it.status != Status::Success)
+    }
+}
+
+/// Compare the `submitted` transactions to the `outcomes` that reached consensus.
+///
+/// If a transaction has more than one outcome (IE, a duplicate submission), the earliest one is used,
+/// since that's the one the network executed.
+///
+/// Outcomes of child transactions (with a nonce) and scheduled executions of submitted transactions
+/// aren't orphans, and are otherwise ignored.
+pub fn reconcile(
+    submitted: impl IntoIterator<Item = TransactionId>,
+    outcomes: impl IntoIterator<Item = ConsensusOutcome>,
+) -> ReconciliationReport {
+    let submitted: Vec<_> = submitted.into_iter().collect();
+    let submitted_set: HashSet<_> = submitted.iter().copied().collect();
+
+    let mut outcomes: Vec<_> = outcomes.into_iter().collect();
+    outcomes.sort_by_key(|it| it.consensus_timestamp);
+
+    let mut first_outcomes = HashMap::new();
+    let mut report = ReconciliationReport::default();
+
+    for outcome in outcomes {
+        let transaction_id = outcome.transaction_id;
+
+        if submitted_set.contains(&transaction_id) {
+            if first_outcomes.insert(transaction_id, outcome).is_none() {
+                report.reached_consensus.push(outcome);
+            }
+
+            continue;
+        }
+
+        let parent = TransactionId { nonce: None, scheduled: false, ..transaction_id };
+
+        if (transaction_id.nonce.is_some() || transaction_id.scheduled)
+            && submitted_set.contains(&parent)
+        {
+            continue;
+        }
+
+        report.orphans.push(outcome);
+    }
+
+    report.missing = submitted.into_iter().filter(|it| !first_outcomes.contains_key(it)).collect();
+
+    report
+}
+
+#[cfg(feature = "mirror-rest")]
+mod mirror {
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    use base64::Engine;
+    use time::OffsetDateTime;
+
+    use super::ConsensusOutcome;
+    use crate::mirror_rest::Links;
+    use crate::protobuf::time::{
+        format_mirror_timestamp,
+        parse_mirror_timestamp,
+    };
+    use crate::transfer_transaction::mirror as mirror_transfers;
+    use crate::{
+        AccountId,
+        Client,
+        Error,
+        Hbar,
+        Status,
+        TokenId,
+        TokenNftTransfer,
+        TransactionId,
+        TransactionReceipt,
+        TransactionRecord,
+        Transfer,
+    };
+
+    /// Gathers the [`ConsensusOutcome`]s of the transactions paid for by a set of accounts
+    /// in a period of time, from the mirror node REST API.
+    #[derive(Debug, Clone, Default)]
+    pub struct MirrorRecordSource {
+        payer_account_ids: Vec<AccountId>,
+        start: Option<OffsetDateTime>,
+        end: Option<OffsetDateTime>,
+    }
+
+    impl MirrorRecordSource {
+        /// Create a new source.
+        #[must_use]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Returns the accounts whose transactions are gathered.
+        #[must_use]
+        pub fn get_payer_account_ids(&self) -> &[AccountId] {
+            &self.payer_account_ids
+        }
+
+        /// Sets the accounts whose transactions are gathered (IE, the accounts that submitted them).
+        pub fn payer_account_ids(
+            &mut self,
+            payer_account_ids: impl IntoIterator<Item = AccountId>,
+        ) -> &mut Self {
+            self.payer_account_ids = payer_account_ids.into_iter().collect();
+            self
+        }
+
+        /// Returns the start (inclusive) of the period to gather transactions for.
+        #[must_use]
+        pub fn get_start(&self) -> Option<OffsetDateTime> {
+            self.start
+        }
+
+        /// Sets the start (inclusive) of the period to gather transactions for.
+        pub fn start(&mut self, start: OffsetDateTime) -> &mut Self {
+            self.start = Some(start);
+            self
+        }
+
+        /// Returns the end (exclusive) of the period to gather transactions for.
+        #[must_use]
+        pub fn get_end(&self) -> Option<OffsetDateTime> {
+            self.end
+        }
+
+        /// Sets the end (exclusive) of the period to gather transactions for.
+        pub fn end(&mut self, end: OffsetDateTime) -> &mut Self {
+            self.end = Some(end);
+            self
+        }
+
+        /// Gather the outcomes from the mirror node of the provided client, following pagination.
+        ///
+        /// # Errors
+        /// - [`Error::BasicParse`] if no payer accounts are set, or the mirror node returns malformed transactions.
+        /// - [`Error::MirrorRest`] if a mirror node request fails.
+        pub async fn execute(&self, client: &Client) -> crate::Result<Vec<ConsensusOutcome>> {
+            if self.payer_account_ids.is_empty() {
+                return Err(Error::basic_parse(
+                    "`payer_account_ids` must be set to gather transaction outcomes",
+                ));
+            }
+
+            let rest = client.rest_client();
+
+            let mut outcomes = Vec::new();
+
+            for &payer in &self.payer_account_ids {
+                let path = self.first_page_path(payer);
+
+                let stream = rest.paginate(Ok(vec![path]), 0, move |page: RestTransactions| {
+                    // `account.id` matches any transaction that touches the account, not just ones it paid for.
+                    let outcomes =
+                        page.transactions.into_iter().map(RestTransaction::into_outcome).filter(
+                            move |it| {
+                                it.as_ref().map_or(true, |it| it.transaction_id.account_id == payer)
+                            },
+                        );
+
+                    (outcomes, page.links.next)
+                });
+
+                outcomes.extend(stream.collect_all(0).await?);
+            }
+
+            outcomes.sort_by_key(|it| it.consensus_timestamp);
+
+            Ok(outcomes)
+        }
+
+        fn first_page_path(&self, payer: AccountId) -> String {
+            let mut path = format!("/transactions?account.id={payer}&order=asc&limit=100");
+
+            if let Some(start) = self.start {
+                path.push_str(&format!("&timestamp=gte:{}", format_mirror_timestamp(start)));
+            }
+
+            if let Some(end) = self.end {
+                path.push_str(&format!("&timestamp=lt:{}", format_mirror_timestamp(end)));
+            }
+
+            path
+        }
+    }
+
+    #[derive(serde_derive::Deserialize)]
+    pub(crate) struct RestTransactions {
+        pub(crate) transactions: Vec<RestTransaction>,
+        links: Links,
+    }
+
+    #[derive(serde_derive::Deserialize)]
+    pub(crate) struct RestTransaction {
+        transaction_id: String,
+        consensus_timestamp: String,
+        result: String,
+        #[serde(default)]
+        nonce: i32,
+        #[serde(default)]
+        scheduled: bool,
+        #[serde(default)]
+        name: String,
+        #[serde(default)]
+        entity_id: Option<String>,
+        #[serde(default)]
+        transaction_hash: String,
+        #[serde(default)]
+        memo_base64: String,
+        #[serde(default)]
+        charged_tx_fee: i64,
+        #[serde(default)]
+        parent_consensus_timestamp: Option<String>,
+        #[serde(default)]
+        staking_reward_transfers: Vec<mirror_transfers::HbarTransfer>,
+        #[serde(flatten)]
+        transfers: mirror_transfers::Transfers,
+    }
+
+    impl RestTransaction {
+        pub(crate) fn into_outcome(self) -> crate::Result<ConsensusOutcome> {
+            self.outcome()
+        }
+
+        /// Returns the record of this transaction, with the fields the mirror node has.
+        ///
+        /// The receipt has the status, the transaction ID and the ID of the entity the transaction created (if any),
+        /// and the record has the transfers, fee, memo, hash, and staking rewards.
+        /// Everything else (IE, the exchange rates, contract results, and child records) is empty.
+        pub(crate) fn into_record(self) -> crate::Result<TransactionRecord> {
+            let ConsensusOutcome { transaction_id, status, consensus_timestamp } =
+                self.outcome()?;
+
+            let decode = |it: &str| {
+                base64::engine::general_purpose::STANDARD.decode(it).map_err(Error::basic_parse)
+            };
+
+            let mut receipt = TransactionReceipt {
+                transaction_id: Some(transaction_id),
+                status,
+                account_id: None,
+                file_id: None,
+                contract_id: None,
+                exchange_rates: None,
+                topic_id: None,
+                topic_sequence_number: 0,
+                topic_running_hash: None,
+                topic_running_hash_version: 0,
+                token_id: None,
+                total_supply: 0,
+                schedule_id: None,
+                scheduled_transaction_id: None,
+                serials: Vec::new(),
+                duplicates: Vec::new(),
+                children: Vec::new(),
+                node_id: 0,
+            };
+
+            if let Some(entity_id) = self.entity_id.as_deref().filter(|_| status == Status::Success)
+            {
+                match self.name.as_str() {
+                    "CRYPTOCREATEACCOUNT" => receipt.account_id = Some(entity_id.parse()?),
+                    "FILECREATE" => receipt.file_id = Some(entity_id.parse()?),
+                    "CONTRACTCREATEINSTANCE" => receipt.contract_id = Some(entity_id.parse()?),
+                    "CONSENSUSCREATETOPIC" => receipt.topic_id = Some(entity_id.parse()?),
+                    "TOKENCREATION" => receipt.token_id = Some(entity_id.parse()?),
+                    "SCHEDULECREATE" => receipt.schedule_id = Some(entity_id.parse()?),
+                    _ => {}
+                }
+            }
+
+            let hbar_transfer = |it: mirror_transfers::HbarTransfer| {
+                Ok(Transfer {
+                    account_id: it.account.parse()?,
+                    amount: Hbar::from_tinybars(it.amount),
+                })
+            };
+
+            let mut token_transfers: HashMap<TokenId, HashMap<AccountId, i64>> = HashMap::new();
+
+            for it in self.transfers.token_transfers {
+                token_transfers
+                    .entry(it.token_id.parse()?)
+                    .or_default()
+                    .insert(it.account.parse()?, it.amount);
+            }
+
+            let mut token_nft_transfers: HashMap<TokenId, Vec<TokenNftTransfer>> = HashMap::new();
+
+            for it in self.transfers.nft_transfers {
+                let token_id: TokenId = it.token_id.parse()?;
+
+                // like in a record from the network, a mint comes from (and a burn goes to) `0.0.0`.
+                let account_id = |it: Option<String>| {
+                    it.map_or(Ok(AccountId::new(0, 0, 0)), |it| AccountId::from_str(&it))
+                };
+
+                token_nft_transfers.entry(token_id).or_default().push(TokenNftTransfer {
+                    token_id,
+                    serial: it.serial_number,
+                    sender: account_id(it.sender_account_id)?,
+                    receiver: account_id(it.receiver_account_id)?,
+                    is_approved: it.is_approval,
+                });
+            }
+
+            let memo = decode(&self.memo_base64)?;
+
+            Ok(TransactionRecord {
+                receipt,
+                transaction_hash: decode(&self.transaction_hash)?,
+                consensus_timestamp,
+                contract_function_result: None,
+                transfers: self
+                    .transfers
+                    .transfers
+                    .into_iter()
+                    .map(hbar_transfer)
+                    .collect::<crate::Result<_>>()?,
+                token_transfers,
+                token_nft_transfers,
+                transaction_id,
+                transaction_memo: String::from_utf8(memo).map_err(Error::basic_parse)?,
+                transaction_fee: Hbar::from_tinybars(self.charged_tx_fee),
+                schedule_ref: None,
+                assessed_custom_fees: Vec::new(),
+                automatic_token_associations: Vec::new(),
+                parent_consensus_timestamp: self
+                    .parent_consensus_timestamp
+                    .as_deref()
+                    .map(parse_mirror_timestamp)
+                    .transpose()?,
+                alias_key: None,
+                children: Vec::new(),
+                duplicates: Vec::new(),
+                ethereum_hash: Vec::new(),
+                prng_bytes: None,
+                prng_number: None,
+                evm_address: None,
+                pending_airdrop_records: Vec::new(),
+                paid_staking_rewards: self
+                    .staking_reward_transfers
+                    .into_iter()
+                    .map(hbar_transfer)
+                    .collect::<crate::Result<_>>()?,
+            })
+        }
+
+        fn outcome(&self) -> crate::Result<ConsensusOutcome> {
+            let mut transaction_id = TransactionId::from_str(&self.transaction_id)?;
+            transaction_id.nonce = (self.nonce != 0).then_some(self.nonce);
+            transaction_id.scheduled = self.scheduled;
+
+            let status = Status::from_str_name(&self.result).ok_or_else(|| {
+                Error::basic_parse(format!("unknown transaction result `{}`", self.result))
+            })?;
+
+            Ok(ConsensusOutcome {
+                transaction_id,
+                status,
+                consensus_timestamp: parse_mirror_timestamp(&self.consensus_timestamp)?,
+            })
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use time::{
+            Duration,
+            OffsetDateTime,
+        };
+
+        use super::{
+            MirrorRecordSource,
+            RestTransaction,
+            RestTransactions,
+        };
+        use crate::mirror_rest::test_page;
+        use crate::{
+            AccountId,
+            Hbar,
+            Status,
+            TokenId,
+        };
+
+        #[test]
+        fn first_page_path() {
+            let mut source = MirrorRecordSource::new();
+            source
+                .start(OffsetDateTime::UNIX_EPOCH + Duration::seconds(10))
+                .end(OffsetDateTime::UNIX_EPOCH + Duration::seconds(20));
+
+            assert_eq!(
+                source.first_page_path(AccountId::new(0, 0, 5006)),
+                "/transactions?account.id=0.0.5006&order=asc&limit=100\
+                 &timestamp=gte:10.000000000&timestamp=lt:20.000000000"
+            );
+        }
+
+        #[test]
+        fn parse_page() {
+            let page: RestTransactions = test_page(
+                "transactions",
+                serde_json::json!([
+                    {
+                        "consensus_timestamp": "1554158543.000000001",
+                        "name": "CRYPTOTRANSFER",
+                        "nonce": 1,
+                        "result": "SUCCESS",
+                        "scheduled": false,
+                        "transaction_id": "0.0.5006-1554158542-000000000"
+                    },
+                    {
+                        "consensus_timestamp": "1554158543.000000002",
+                        "name": "CRYPTOTRANSFER",
+                        "nonce": 0,
+                        "result": "INSUFFICIENT_PAYER_BALANCE",
+                        "scheduled": false,
+                        "transaction_id": "0.0.5006-1554158542-000000005"
+                    }
+                ]),
+                None,
+            );
+
+            let outcomes: Vec<_> =
+                page.transactions.into_iter().map(|it| it.into_outcome().unwrap()).collect();
+
+            assert_eq!(outcomes[0].transaction_id.to_string(), "0.0.5006@1554158542.0/1");
+            assert_eq!(outcomes[0].status, Status::Success);
+            assert_eq!(outcomes[1].transaction_id.nonce, None);
+            assert_eq!(outcomes[1].status, Status::InsufficientPayerBalance);
+            assert_eq!(
+                outcomes[1].consensus_timestamp,
+                OffsetDateTime::UNIX_EPOCH + Duration::new(1_554_158_543, 2)
+            );
+        }
+
+        #[test]
+        fn into_record() {
+            let transaction: RestTransaction = serde_json::from_str(
+                r#"{
+                    "charged_tx_fee": 100,
+                    "consensus_timestamp": "1554158543.000000001",
+                    "entity_id": "0.0.6000",
+                    "memo_base64": "aGVsbG8=",
+                    "name": "CRYPTOCREATEACCOUNT",
+                    "nft_transfers": [{
+                        "is_approval": false,
+                        "receiver_account_id": "0.0.5006",
+                        "sender_account_id": null,
+                        "serial_number": 1,
+                        "token_id": "0.0.7000"
+                    }],
+                    "nonce": 0,
+                    "result": "SUCCESS",
+                    "scheduled": false,
+                    "staking_reward_transfers": [{ "account": "0.0.5006", "amount": 5 }],
+                    "token_transfers": [],
+                    "transaction_hash": "q6ur",
+                    "transaction_id": "0.0.5006-1554158542-000000000",
+                    "transfers": [
+                        { "account": "0.0.3", "amount": 100, "is_approval": false },
+                        { "account": "0.0.5006", "amount": -100, "is_approval": false }
+                    ]
+                }"#,
+            )
+            .unwrap();
+
+            let record = transaction.into_record().unwrap();
+
+            assert_eq!(record.receipt.status, Status::Success);
+            assert_eq!(record.receipt.account_id, Some(AccountId::new(0, 0, 6000)));
+            assert_eq!(record.receipt.token_id, None);
+            assert_eq!(record.transaction_id.to_string(), "0.0.5006@1554158542.0");
+            assert_eq!(record.transaction_memo, "hello");
+            assert_eq!(record.transaction_hash, [0xab; 3]);
+            assert_eq!(record.transaction_fee, Hbar::from_tinybars(100));
+            assert_eq!(record.transfers.len(), 2);
+            assert_eq!(record.paid_staking_rewards[0].amount, Hbar::from_tinybars(5));
+
+            let nft_transfer = &record.token_nft_transfers[&TokenId::new(0, 0, 7000)][0];
+            assert_eq!(nft_transfer.sender, AccountId::new(0, 0, 0));
+            assert_eq!(nft_transfer.receiver, AccountId::new(0, 0, 5006));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use super::{
+        reconcile,
+        ConsensusOutcome,
+    };
+    use crate::transaction::test_helpers::VALID_START;
+    use crate::{
+        AccountId,
+        Status,
+        TransactionId,
+    };
+
+    fn transaction_id(seconds: i64) -> TransactionId {
+        TransactionId {
+            account_id: AccountId::new(0, 0, 5006),
+            valid_start: VALID_START + Duration::seconds(seconds),
+            nonce: None,
+            scheduled: false,
+        }
+    }
+
+    fn outcome(transaction_id: TransactionId, status: Status, seconds: i64) -> ConsensusOutcome {
+        ConsensusOutcome::new(
+            transaction_id,
+            status,
+            VALID_START + Duration::seconds(seconds + 100),
+        )
+    }
+
+    #[test]
+    fn clean() {
+        let report = reconcile(
+            [transaction_id(1), transaction_id(2)],
+            [
+                outcome(transaction_id(2), Status::Success, 2),
+                outcome(transaction_id(1), Status::Success, 1),
+            ],
+        );
+
+        assert!(report.is_clean());
+        assert_eq!(report.reached_consensus[0].transaction_id, transaction_id(1));
+    }
+
+    #[test]
+    fn missing_failed_and_orphans() {
+        let child = TransactionId { nonce: Some(1), ..transaction_id(1) };
+
+        let report = reconcile(
+            [transaction_id(1), transaction_id(2), transaction_id(3)],
+            [
+                outcome(transaction_id(1), Status::Success, 1),
+                outcome(child, Status::Success, 1),
+                // a duplicate submission, the first outcome counts.
+                outcome(transaction_id(2), Status::InsufficientPayerBalance, 2),
+                outcome(transaction_id(2), Status::DuplicateTransaction, 3),
+                outcome(transaction_id(4), Status::Success, 4),
+            ],
+        );
+
+        assert!(!report.is_clean());
+        assert_eq!(report.reached_consensus.len(), 2);
+        assert_eq!(
+            report.failed().map(|it| it.status).collect::<Vec<_>>(),
+            [Status::InsufficientPayerBalance]
+        );
+        assert_eq!(report.missing, [transaction_id(3)]);
+        assert_eq!(report.orphans, [outcome(transaction_id(4), Status::Success, 4)]);
+    }
+
+    #[test]
+    fn scheduled_execution() {
+        let scheduled = TransactionId { scheduled: true, ..transaction_id(1) };
+
+        let report = reconcile(
+            [transaction_id(1)],
+            [
+                outcome(transaction_id(1), Status::Success, 1),
+                outcome(scheduled, Status::Success, 2),
+                outcome(TransactionId { scheduled: true, ..transaction_id(2) }, Status::Success, 3),
+            ],
+        );
+
+        assert_eq!(report.reached_consensus.len(), 1);
+        assert_eq!(
+            report.orphans,
+            [outcome(TransactionId { scheduled: true, ..transaction_id(2) }, Status::Success, 3)]
+        );
+    }
+}
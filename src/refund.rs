@@ -0,0 +1,368 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crate::{
+    AccountId,
+    Error,
+    Hbar,
+    TokenId,
+    TransactionRecord,
+    TransferTransaction,
+};
+
+/// The highest account number reserved for the network (IE, node accounts, fee collection, and staking rewards).
+const MAX_SYSTEM_ACCOUNT_NUM: u64 = 1000;
+
+impl TransferTransaction {
+    /// Build a transfer that reverses the net transfers of `record`, back toward its payer.
+    ///
+    /// Every account's net change in hbar and fungible tokens is reversed, and every NFT is sent
+    /// back from the account that ended up with it to the account that first sent it.
+    ///
+    /// Fees aren't refunded: the transaction fee (paid by the payer to system accounts, `0.0.1000` and below)
+    /// and any custom fees (paid to their fee collectors) are excluded.
+    /// Neither are any staking rewards the transaction paid out.
+    ///
+    /// If nothing needs to be refunded (IE, the transaction failed), the transfer is empty.
+    /// The transfer has to be signed by every account that sends something back,
+    /// and before it's executed, [`check_token_associations`](Self::check_token_associations) can check that
+    /// every account that gets tokens back is still associated with them.
+    ///
+    /// # Errors
+    /// - [`Error::Refund`] if the transfers can't be reversed, IE, if they don't balance once fees are excluded,
+    ///   if a custom fee was charged to more than one account, if an NFT was minted or burned,
+    ///   or if an amount overflows.
+    pub fn refund(record: &TransactionRecord) -> crate::Result<Self> {
+        let payer = record.transaction_id.account_id;
+
+        let mut hbar_changes: HashMap<AccountId, i64> = HashMap::new();
+
+        for it in &record.transfers {
+            add_change(&mut hbar_changes, it.account_id, it.amount.to_tinybars())?;
+        }
+
+        // staking rewards are paid by a system account (`0.0.800`), and aren't refunded either.
+        for it in &record.paid_staking_rewards {
+            let amount = it.amount.to_tinybars().checked_neg().ok_or_else(overflow)?;
+            add_change(&mut hbar_changes, it.account_id, amount)?;
+        }
+
+        hbar_changes.retain(|account_id, _| *account_id == payer || !is_system_account(account_id));
+        add_change(&mut hbar_changes, payer, record.transaction_fee.to_tinybars())?;
+
+        let mut token_changes = record.token_transfers.clone();
+
+        for fee in &record.assessed_custom_fees {
+            let Some(collector) = fee.fee_collector_account_id else {
+                continue;
+            };
+
+            let [fee_payer] = fee.payer_account_id_list.as_slice() else {
+                return Err(Error::refund(format!(
+                    "custom fee collected by `{collector}` was charged to more than one account"
+                )));
+            };
+
+            let changes = match fee.token_id {
+                Some(token_id) => token_changes.entry(token_id).or_default(),
+                None => &mut hbar_changes,
+            };
+
+            add_change(changes, collector, fee.amount.checked_neg().ok_or_else(overflow)?)?;
+            add_change(changes, *fee_payer, fee.amount)?;
+        }
+
+        let mut tx = Self::new();
+
+        for (account_id, amount) in reversed(hbar_changes, "hbar")? {
+            tx.hbar_transfer(account_id, Hbar::from_tinybars(amount));
+        }
+
+        let mut token_changes: Vec<_> = token_changes.into_iter().collect();
+        token_changes.sort_by_key(|(token_id, _)| (token_id.shard, token_id.realm, token_id.num));
+
+        for (token_id, changes) in token_changes {
+            for (account_id, amount) in reversed(changes, &format!("token `{token_id}`"))? {
+                tx.token_transfer(token_id, account_id, amount);
+            }
+        }
+
+        let mut nft_transfers: Vec<_> = record.token_nft_transfers.values().flatten().collect();
+        nft_transfers.sort_by_key(|it| (it.token_id.shard, it.token_id.realm, it.token_id.num));
+
+        let mut owners: Vec<(TokenId, u64, AccountId, AccountId)> = Vec::new();
+
+        for it in nft_transfers {
+            if it.sender.num == 0 || it.receiver.num == 0 {
+                return Err(Error::refund(format!(
+                    "NFT `{}/{}` was minted or burned",
+                    it.token_id, it.serial
+                )));
+            }
+
+            match owners
+                .iter_mut()
+                .find(|(token_id, serial, ..)| (*token_id, *serial) == (it.token_id, it.serial))
+            {
+                Some((.., owner)) => *owner = it.receiver,
+                None => owners.push((it.token_id, it.serial, it.sender, it.receiver)),
+            }
+        }
+
+        for (token_id, serial, sender, owner) in owners {
+            if owner != sender {
+                tx.nft_transfer(token_id.nft(serial), owner, sender);
+            }
+        }
+
+        Ok(tx)
+    }
+}
+
+#[cfg(feature = "mirror-rest")]
+impl TransferTransaction {
+    /// Check that every account this transfer sends fungible tokens or NFTs to is associated with the token,
+    /// using the mirror node REST API.
+    ///
+    /// Accounts with free automatic association slots could still receive a token they aren't associated with,
+    /// but are reported anyway.
+    ///
+    /// # Errors
+    /// - [`Error::TokenNotAssociated`] for the first account that isn't associated with a token it's sent.
+    /// - [`Error::MirrorRest`] if a mirror node request fails.
+    pub async fn check_token_associations(&self, client: &crate::Client) -> crate::Result<()> {
+        let mut receivers: Vec<(AccountId, Vec<TokenId>)> = Vec::new();
+
+        for (token_id, changes) in self.get_token_transfers() {
+            for (account_id, amount) in changes {
+                if amount > 0 {
+                    add_receiver(&mut receivers, account_id, token_id);
+                }
+            }
+        }
+
+        for (token_id, transfers) in self.get_nft_transfers() {
+            for it in transfers {
+                add_receiver(&mut receivers, it.receiver, token_id);
+            }
+        }
+
+        for (account_id, token_ids) in receivers {
+            let associated: Vec<_> = crate::AccountTokenBalancesQuery::new()
+                .account_id(account_id)
+                .execute(client)
+                .await?
+                .into_iter()
+                .map(|it| it.token_id)
+                .collect();
+
+            if let Some(&token_id) = token_ids.iter().find(|it| !associated.contains(it)) {
+                return Err(Error::TokenNotAssociated {
+                    account_id: Box::new(account_id),
+                    token_id: Box::new(token_id),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mirror-rest")]
+fn add_receiver(
+    receivers: &mut Vec<(AccountId, Vec<TokenId>)>,
+    account_id: AccountId,
+    token_id: TokenId,
+) {
+    match receivers.iter_mut().find(|(it, _)| *it == account_id) {
+        Some((_, token_ids)) => token_ids.push(token_id),
+        None => receivers.push((account_id, Vec::from([token_id]))),
+    }
+}
+
+/// Adds `amount` to `account_id`'s change in `changes`.
+fn add_change(
+    changes: &mut HashMap<AccountId, i64>,
+    account_id: AccountId,
+    amount: i64,
+) -> crate::Result<()> {
+    let change = changes.entry(account_id).or_default();
+    *change = change.checked_add(amount).ok_or_else(overflow)?;
+
+    Ok(())
+}
+
+fn overflow() -> Error {
+    Error::refund("a balance change overflows")
+}
+
+/// Returns `true` if `account_id` is reserved for the network, IE, a node account or a fee collection account.
+pub(crate) fn is_system_account(account_id: &AccountId) -> bool {
+    account_id.alias.is_none()
+        && account_id.evm_address.is_none()
+        && account_id.num <= MAX_SYSTEM_ACCOUNT_NUM
+}
+
+/// Returns the reversed `changes` to balances of `what`, skipping accounts without a change, ordered by account.
+fn reversed(changes: HashMap<AccountId, i64>, what: &str) -> crate::Result<Vec<(AccountId, i64)>> {
+    if changes.values().try_fold(0_i64, |sum, it| sum.checked_add(*it)) != Some(0) {
+        return Err(Error::refund(format!(
+            "transfers of {what} don't balance once fees are excluded"
+        )));
+    }
+
+    let mut changes = changes
+        .into_iter()
+        .filter(|(_, amount)| *amount != 0)
+        .map(|(it, amount)| Ok((it, amount.checked_neg().ok_or_else(overflow)?)))
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    changes.sort_by_key(|(account_id, _)| (account_id.shard, account_id.realm, account_id.num));
+
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use assert_matches::assert_matches;
+
+    use crate::transaction::test_helpers::TEST_ACCOUNT_ID;
+    use crate::transaction_record::make_record;
+    use crate::{
+        AccountId,
+        AssessedCustomFee,
+        Error,
+        Hbar,
+        TokenId,
+        TokenNftTransfer,
+        TransactionRecord,
+        Transfer,
+        TransferTransaction,
+    };
+
+    const PAYER: AccountId = TEST_ACCOUNT_ID;
+    const MERCHANT: AccountId = AccountId::new(0, 0, 5008);
+    const COLLECTOR: AccountId = AccountId::new(0, 0, 5009);
+    const TOKEN: TokenId = TokenId::new(0, 0, 6000);
+    const NFT_TOKEN: TokenId = TokenId::new(0, 0, 6001);
+
+    fn transfer(account_id: AccountId, tinybars: i64) -> Transfer {
+        Transfer { account_id, amount: Hbar::from_tinybars(tinybars) }
+    }
+
+    fn nft_transfer(serial: u64, sender: AccountId, receiver: AccountId) -> TokenNftTransfer {
+        TokenNftTransfer { token_id: NFT_TOKEN, serial, sender, receiver, is_approved: false }
+    }
+
+    fn payment_record() -> TransactionRecord {
+        let mut record = make_record(None, None);
+
+        record.transaction_fee = Hbar::from_tinybars(100);
+        record.transfers = Vec::from([
+            transfer(PAYER, -1_100),
+            transfer(MERCHANT, 990),
+            transfer(COLLECTOR, 10),
+            transfer(AccountId::new(0, 0, 3), 20),
+            transfer(AccountId::new(0, 0, 98), 80),
+        ]);
+        record.assessed_custom_fees = Vec::from([AssessedCustomFee {
+            amount: 10,
+            token_id: None,
+            fee_collector_account_id: Some(COLLECTOR),
+            payer_account_id_list: Vec::from([PAYER]),
+        }]);
+        record.token_transfers =
+            HashMap::from([(TOKEN, HashMap::from([(PAYER, -5), (MERCHANT, 5)]))]);
+        record.token_nft_transfers = HashMap::from([(
+            NFT_TOKEN,
+            Vec::from([nft_transfer(1, PAYER, COLLECTOR), nft_transfer(1, COLLECTOR, MERCHANT)]),
+        )]);
+
+        record
+    }
+
+    #[test]
+    fn refund() {
+        let tx = TransferTransaction::refund(&payment_record()).unwrap();
+
+        assert_eq!(
+            tx.get_hbar_transfers(),
+            HashMap::from([
+                (PAYER, Hbar::from_tinybars(990)),
+                (MERCHANT, Hbar::from_tinybars(-990))
+            ])
+        );
+        assert_eq!(tx.get_token_transfers()[&TOKEN], HashMap::from([(PAYER, 5), (MERCHANT, -5)]));
+
+        let nft_transfers = &tx.get_nft_transfers()[&NFT_TOKEN];
+
+        assert_eq!(nft_transfers.len(), 1);
+        assert_eq!(nft_transfers[0].serial, 1);
+        assert_eq!(nft_transfers[0].sender, MERCHANT);
+        assert_eq!(nft_transfers[0].receiver, PAYER);
+    }
+
+    #[test]
+    fn refund_failed_transaction() {
+        let mut record = payment_record();
+        record.transfers =
+            Vec::from([transfer(PAYER, -100), transfer(AccountId::new(0, 0, 3), 100)]);
+        record.assessed_custom_fees.clear();
+        record.token_transfers.clear();
+        record.token_nft_transfers.clear();
+
+        let tx = TransferTransaction::refund(&record).unwrap();
+
+        assert!(tx.get_hbar_transfers().is_empty());
+        assert!(tx.get_token_transfers().is_empty());
+    }
+
+    #[test]
+    fn refund_unbalanced() {
+        let mut record = payment_record();
+        record.transfers.push(transfer(AccountId::new(0, 0, 800), 5));
+        record.transfers[0].amount = Hbar::from_tinybars(-1_105);
+
+        assert_matches!(TransferTransaction::refund(&record), Err(Error::Refund(_)));
+    }
+
+    #[test]
+    fn refund_mint() {
+        let mut record = payment_record();
+        record.token_nft_transfers = HashMap::from([(
+            NFT_TOKEN,
+            Vec::from([nft_transfer(2, AccountId::new(0, 0, 0), MERCHANT)]),
+        )]);
+
+        assert_matches!(TransferTransaction::refund(&record), Err(Error::Refund(_)));
+    }
+
+    #[test]
+    fn refund_staking_reward() {
+        let mut record = payment_record();
+        record.transfers[0].amount = Hbar::from_tinybars(-1_090);
+        record.transfers.push(transfer(AccountId::new(0, 0, 800), -10));
+        record.paid_staking_rewards = Vec::from([transfer(PAYER, 10)]);
+
+        let tx = TransferTransaction::refund(&record).unwrap();
+
+        assert_eq!(
+            tx.get_hbar_transfers(),
+            HashMap::from([
+                (PAYER, Hbar::from_tinybars(990)),
+                (MERCHANT, Hbar::from_tinybars(-990))
+            ])
+        );
+    }
+
+    #[test]
+    fn refund_overflow() {
+        let mut record = payment_record();
+        record.transfers.push(transfer(MERCHANT, i64::MAX));
+
+        assert_matches!(TransferTransaction::refund(&record), Err(Error::Refund(_)));
+    }
+}
@@ -1,5 +1,10 @@
 use futures_core::Future;
-use tokio::time::sleep;
+
+use crate::logger::{
+    client_log,
+    ClientLogger,
+};
+use crate::Clock;
 
 #[derive(Debug)]
 pub(crate) enum Error {
@@ -29,6 +34,8 @@ pub(crate) type Result<T> = std::result::Result<T, Error>;
 pub(crate) async fn retry<B, Fn, O, Fut>(
     mut backoff: B,
     max_attempts: Option<usize>,
+    logger: &ClientLogger,
+    clock: &dyn Clock,
     mut f: Fn,
 ) -> crate::Result<O>
 where
@@ -49,14 +56,29 @@ where
             Err(Error::Permanent(e)) => return Err(e),
         }
 
+        // no point in backing off after the final attempt.
+        if max_attempts == Some(attempt_number) {
+            break;
+        }
+
         if let Some(duration) = backoff.next_backoff() {
             let duration_ms = duration.as_millis();
             let err_suffix =
                 last_error.as_ref().map(|l| format!(" due to {l:?}")).unwrap_or_default();
 
-            log::warn!("Backing off for {duration_ms}ms after failure of attempt {attempt_number}{err_suffix}");
-            sleep(duration).await;
-            log::warn!("Backed off for {duration_ms}ms after failure of attempt {attempt_number}{err_suffix}");
+            client_log!(
+                logger,
+                Execution,
+                Warn,
+                "Backing off for {duration_ms}ms after failure of attempt {attempt_number}{err_suffix}"
+            );
+            clock.sleep(duration).await;
+            client_log!(
+                logger,
+                Execution,
+                Warn,
+                "Backed off for {duration_ms}ms after failure of attempt {attempt_number}{err_suffix}"
+            );
         } else {
             let last_error = last_error.expect("timeout while network had no healthy nodes");
             return Err(crate::Error::TimedOut(last_error.into()));
@@ -66,3 +88,42 @@ where
     let last_error = last_error.expect("timeout while network had no healthy nodes");
     Err(crate::Error::TimedOut(last_error.into()))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use backoff::ExponentialBackoffBuilder;
+    use time::OffsetDateTime;
+
+    use super::{
+        retry,
+        Error,
+    };
+    use crate::clock::MockClock;
+    use crate::Client;
+
+    #[tokio::test]
+    async fn backoff_sleeps_on_clock() {
+        let client = Client::for_testnet();
+        let clock = MockClock::new(OffsetDateTime::UNIX_EPOCH);
+
+        let backoff = ExponentialBackoffBuilder::new()
+            .with_initial_interval(Duration::from_millis(250))
+            .with_randomization_factor(0.0)
+            .with_multiplier(2.0)
+            .build();
+
+        let mut attempts = 0;
+
+        let result = retry(backoff, Some(3), client.logger(), &clock, || {
+            attempts += 1;
+            async { Err::<(), _>(Error::Transient(crate::Error::Cancelled)) }
+        })
+        .await;
+
+        assert!(matches!(result, Err(crate::Error::TimedOut(_))));
+        assert_eq!(attempts, 3);
+        assert_eq!(clock.get_sleeps(), [Duration::from_millis(250), Duration::from_millis(500)]);
+    }
+}
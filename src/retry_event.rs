@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    Hbar,
+    TransactionId,
+};
+
+/// An adjustment the SDK made while retrying a request.
+///
+/// These are passed to the hook set with [`Client::set_retry_hook`](crate::Client::set_retry_hook).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RetryEvent {
+    /// A transaction failed with `INSUFFICIENT_TX_FEE` and is being resubmitted with a higher max transaction fee.
+    ///
+    /// See [`Client::set_auto_fee_bump_limit`](crate::Client::set_auto_fee_bump_limit).
+    FeeBumped {
+        /// The ID of the transaction being resubmitted.
+        transaction_id: TransactionId,
+
+        /// The max transaction fee the transaction was originally submitted with.
+        previous_fee: Hbar,
+
+        /// The max transaction fee the transaction is being resubmitted with (the fee the node said was required).
+        new_fee: Hbar,
+    },
+}
@@ -1,20 +1,35 @@
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "schedules")]
 mod schedulable_transaction_body;
+#[cfg(feature = "schedules")]
 mod schedule_create_transaction;
+#[cfg(feature = "schedules")]
 mod schedule_delete_transaction;
 mod schedule_id;
+#[cfg(feature = "schedules")]
 mod schedule_info;
+#[cfg(feature = "schedules")]
 mod schedule_info_query;
+#[cfg(feature = "schedules")]
 mod schedule_sign_transaction;
 
+#[cfg(feature = "schedules")]
 pub use schedule_create_transaction::ScheduleCreateTransaction;
+#[cfg(feature = "schedules")]
 pub(crate) use schedule_create_transaction::ScheduleCreateTransactionData;
+#[cfg(feature = "schedules")]
 pub use schedule_delete_transaction::ScheduleDeleteTransaction;
+#[cfg(feature = "schedules")]
 pub(crate) use schedule_delete_transaction::ScheduleDeleteTransactionData;
 pub use schedule_id::ScheduleId;
+#[cfg(feature = "schedules")]
 pub use schedule_info::ScheduleInfo;
+#[cfg(feature = "schedules")]
 pub use schedule_info_query::ScheduleInfoQuery;
+#[cfg(feature = "schedules")]
 pub(crate) use schedule_info_query::ScheduleInfoQueryData;
+#[cfg(feature = "schedules")]
 pub use schedule_sign_transaction::ScheduleSignTransaction;
+#[cfg(feature = "schedules")]
 pub(crate) use schedule_sign_transaction::ScheduleSignTransactionData;
@@ -21,12 +21,14 @@ mod data {
         NodeDeleteTransactionData as NodeDelete,
         NodeUpdateTransactionData as NodeUpdate,
     };
+    #[cfg(feature = "contracts")]
     pub(super) use crate::contract::{
         ContractCreateTransactionData as ContractCreate,
         ContractDeleteTransactionData as ContractDelete,
         ContractExecuteTransactionData as ContractExecute,
         ContractUpdateTransactionData as ContractUpdate,
     };
+    #[cfg(feature = "files")]
     pub(super) use crate::file::{
         FileAppendTransactionData as FileAppend,
         FileCreateTransactionData as FileCreate,
@@ -40,6 +42,7 @@ mod data {
         SystemDeleteTransactionData as SystemDelete,
         SystemUndeleteTransactionData as SystemUndelete,
     };
+    #[cfg(feature = "tokens")]
     pub(super) use crate::token::{
         TokenAirdropTransactionData as TokenAirdrop,
         TokenAssociateTransactionData as TokenAssociate,
@@ -62,6 +65,7 @@ mod data {
         TokenUpdateTransactionData as TokenUpdate,
         TokenWipeTransactionData as TokenWipe,
     };
+    #[cfg(feature = "topics")]
     pub(super) use crate::topic::{
         TopicCreateTransactionData as TopicCreate,
         TopicDeleteTransactionData as TopicDelete,
@@ -114,36 +118,65 @@ pub(super) enum AnySchedulableTransactionData {
     AccountDelete(data::AccountDelete),
     AccountAllowanceApprove(data::AccountAllowanceApprove),
     AccountAllowanceDelete(data::AccountAllowanceDelete),
+    #[cfg(feature = "contracts")]
     ContractCreate(data::ContractCreate),
+    #[cfg(feature = "contracts")]
     ContractUpdate(data::ContractUpdate),
+    #[cfg(feature = "contracts")]
     ContractDelete(data::ContractDelete),
+    #[cfg(feature = "contracts")]
     ContractExecute(data::ContractExecute),
     Transfer(data::Transfer),
+    #[cfg(feature = "topics")]
     TopicCreate(data::TopicCreate),
+    #[cfg(feature = "topics")]
     TopicUpdate(data::TopicUpdate),
+    #[cfg(feature = "topics")]
     TopicDelete(data::TopicDelete),
+    #[cfg(feature = "topics")]
     TopicMessageSubmit(data::TopicMessageSubmit),
+    #[cfg(feature = "files")]
     FileAppend(data::FileAppend),
+    #[cfg(feature = "files")]
     FileCreate(data::FileCreate),
+    #[cfg(feature = "files")]
     FileUpdate(data::FileUpdate),
+    #[cfg(feature = "files")]
     FileDelete(data::FileDelete),
     Prng(data::Prng),
+    #[cfg(feature = "tokens")]
     TokenAssociate(data::TokenAssociate),
+    #[cfg(feature = "tokens")]
     TokenBurn(data::TokenBurn),
+    #[cfg(feature = "tokens")]
     TokenCreate(data::TokenCreate),
+    #[cfg(feature = "tokens")]
     TokenDelete(data::TokenDelete),
+    #[cfg(feature = "tokens")]
     TokenDissociate(data::TokenDissociate),
+    #[cfg(feature = "tokens")]
     TokenFeeScheduleUpdate(data::TokenFeeScheduleUpdate),
+    #[cfg(feature = "tokens")]
     TokenFreeze(data::TokenFreeze),
+    #[cfg(feature = "tokens")]
     TokenGrantKyc(data::TokenGrantKyc),
+    #[cfg(feature = "tokens")]
     TokenMint(data::TokenMint),
+    #[cfg(feature = "tokens")]
     TokenPause(data::TokenPause),
+    #[cfg(feature = "tokens")]
     TokenRevokeKyc(data::TokenRevokeKyc),
+    #[cfg(feature = "tokens")]
     TokenUnfreeze(data::TokenUnfreeze),
+    #[cfg(feature = "tokens")]
     TokenUnpause(data::TokenUnpause),
+    #[cfg(feature = "tokens")]
     TokenUpdate(data::TokenUpdate),
+    #[cfg(feature = "tokens")]
     TokenWipe(data::TokenWipe),
+    #[cfg(feature = "tokens")]
     TokenUpdateNfts(data::TokenUpdateNfts),
+    #[cfg(feature = "tokens")]
     TokenReject(data::TokenReject),
     SystemDelete(data::SystemDelete),
     SystemUndelete(data::SystemUndelete),
@@ -152,8 +185,11 @@ pub(super) enum AnySchedulableTransactionData {
     NodeCreate(data::NodeCreate),
     NodeUpdate(data::NodeUpdate),
     NodeDelete(data::NodeDelete),
+    #[cfg(feature = "tokens")]
     TokenAirdrop(data::TokenAirdrop),
+    #[cfg(feature = "tokens")]
     TokenClaimAirdrop(data::TokenClaimAirdrop),
+    #[cfg(feature = "tokens")]
     TokenCancelAirdrop(data::TokenCancelAirdrop),
 }
 
@@ -169,39 +205,68 @@ impl AnySchedulableTransactionData {
             AnySchedulableTransactionData::AccountAllowanceDelete(it) => {
                 it.default_max_transaction_fee()
             }
+            #[cfg(feature = "contracts")]
             AnySchedulableTransactionData::ContractCreate(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "contracts")]
             AnySchedulableTransactionData::ContractUpdate(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "contracts")]
             AnySchedulableTransactionData::ContractDelete(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "contracts")]
             AnySchedulableTransactionData::ContractExecute(it) => it.default_max_transaction_fee(),
             AnySchedulableTransactionData::Transfer(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "topics")]
             AnySchedulableTransactionData::TopicCreate(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "topics")]
             AnySchedulableTransactionData::TopicUpdate(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "topics")]
             AnySchedulableTransactionData::TopicDelete(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "topics")]
             AnySchedulableTransactionData::TopicMessageSubmit(it) => {
                 it.default_max_transaction_fee()
             }
+            #[cfg(feature = "files")]
             AnySchedulableTransactionData::FileAppend(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "files")]
             AnySchedulableTransactionData::FileCreate(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "files")]
             AnySchedulableTransactionData::FileUpdate(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "files")]
             AnySchedulableTransactionData::FileDelete(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenAssociate(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenBurn(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenCreate(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenDelete(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenDissociate(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenFeeScheduleUpdate(it) => {
                 it.default_max_transaction_fee()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenFreeze(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenGrantKyc(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenMint(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenUpdateNfts(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenPause(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenRevokeKyc(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenUnfreeze(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenUnpause(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenUpdate(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenWipe(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenReject(it) => it.default_max_transaction_fee(),
             AnySchedulableTransactionData::SystemDelete(it) => it.default_max_transaction_fee(),
             AnySchedulableTransactionData::SystemUndelete(it) => it.default_max_transaction_fee(),
@@ -211,10 +276,13 @@ impl AnySchedulableTransactionData {
             AnySchedulableTransactionData::NodeCreate(it) => it.default_max_transaction_fee(),
             AnySchedulableTransactionData::NodeUpdate(it) => it.default_max_transaction_fee(),
             AnySchedulableTransactionData::NodeDelete(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenAirdrop(it) => it.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenClaimAirdrop(it) => {
                 it.default_max_transaction_fee()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenCancelAirdrop(it) => {
                 it.default_max_transaction_fee()
             }
@@ -228,15 +296,19 @@ impl FromProtobuf<services::schedulable_transaction_body::Data> for AnySchedulab
     fn from_protobuf(pb: services::schedulable_transaction_body::Data) -> crate::Result<Self> {
         use services::schedulable_transaction_body::Data;
         match pb {
+            #[cfg(feature = "contracts")]
             Data::ContractCall(it) => {
                 Ok(Self::ContractExecute(data::ContractExecute::from_protobuf(it)?))
             }
+            #[cfg(feature = "contracts")]
             Data::ContractCreateInstance(it) => {
                 Ok(Self::ContractCreate(data::ContractCreate::from_protobuf(it)?))
             }
+            #[cfg(feature = "contracts")]
             Data::ContractUpdateInstance(it) => {
                 Ok(Self::ContractUpdate(data::ContractUpdate::from_protobuf(it)?))
             }
+            #[cfg(feature = "contracts")]
             Data::ContractDeleteInstance(it) => {
                 Ok(Self::ContractDelete(data::ContractDelete::from_protobuf(it)?))
             }
@@ -256,9 +328,13 @@ impl FromProtobuf<services::schedulable_transaction_body::Data> for AnySchedulab
             Data::CryptoUpdateAccount(it) => {
                 Ok(Self::AccountUpdate(data::AccountUpdate::from_protobuf(it)?))
             }
+            #[cfg(feature = "files")]
             Data::FileAppend(it) => Ok(Self::FileAppend(data::FileAppend::from_protobuf(it)?)),
+            #[cfg(feature = "files")]
             Data::FileCreate(it) => Ok(Self::FileCreate(data::FileCreate::from_protobuf(it)?)),
+            #[cfg(feature = "files")]
             Data::FileDelete(it) => Ok(Self::FileDelete(data::FileDelete::from_protobuf(it)?)),
+            #[cfg(feature = "files")]
             Data::FileUpdate(it) => Ok(Self::FileUpdate(data::FileUpdate::from_protobuf(it)?)),
             Data::SystemDelete(it) => {
                 Ok(Self::SystemDelete(data::SystemDelete::from_protobuf(it)?))
@@ -267,67 +343,135 @@ impl FromProtobuf<services::schedulable_transaction_body::Data> for AnySchedulab
                 Ok(Self::SystemUndelete(data::SystemUndelete::from_protobuf(it)?))
             }
             Data::Freeze(it) => Ok(Self::Freeze(data::Freeze::from_protobuf(it)?)),
+            #[cfg(feature = "topics")]
             Data::ConsensusCreateTopic(it) => {
                 Ok(Self::TopicCreate(data::TopicCreate::from_protobuf(it)?))
             }
+            #[cfg(feature = "topics")]
             Data::ConsensusUpdateTopic(it) => {
                 Ok(Self::TopicUpdate(data::TopicUpdate::from_protobuf(it)?))
             }
+            #[cfg(feature = "topics")]
             Data::ConsensusDeleteTopic(it) => {
                 Ok(Self::TopicDelete(data::TopicDelete::from_protobuf(it)?))
             }
+            #[cfg(feature = "topics")]
             Data::ConsensusSubmitMessage(it) => {
                 Ok(Self::TopicMessageSubmit(data::TopicMessageSubmit::from_protobuf(it)?))
             }
+            #[cfg(feature = "tokens")]
             Data::TokenCreation(it) => Ok(Self::TokenCreate(data::TokenCreate::from_protobuf(it)?)),
+            #[cfg(feature = "tokens")]
             Data::TokenFreeze(it) => Ok(Self::TokenFreeze(data::TokenFreeze::from_protobuf(it)?)),
+            #[cfg(feature = "tokens")]
             Data::TokenUnfreeze(it) => {
                 Ok(Self::TokenUnfreeze(data::TokenUnfreeze::from_protobuf(it)?))
             }
+            #[cfg(feature = "tokens")]
             Data::TokenGrantKyc(it) => {
                 Ok(Self::TokenGrantKyc(data::TokenGrantKyc::from_protobuf(it)?))
             }
+            #[cfg(feature = "tokens")]
             Data::TokenRevokeKyc(it) => {
                 Ok(Self::TokenRevokeKyc(data::TokenRevokeKyc::from_protobuf(it)?))
             }
+            #[cfg(feature = "tokens")]
             Data::TokenDeletion(it) => Ok(Self::TokenDelete(data::TokenDelete::from_protobuf(it)?)),
+            #[cfg(feature = "tokens")]
             Data::TokenUpdate(it) => Ok(Self::TokenUpdate(data::TokenUpdate::from_protobuf(it)?)),
+            #[cfg(feature = "tokens")]
             Data::TokenMint(it) => Ok(Self::TokenMint(data::TokenMint::from_protobuf(it)?)),
+            #[cfg(feature = "tokens")]
             Data::TokenBurn(it) => Ok(Self::TokenBurn(data::TokenBurn::from_protobuf(it)?)),
+            #[cfg(feature = "tokens")]
             Data::TokenWipe(it) => Ok(Self::TokenWipe(data::TokenWipe::from_protobuf(it)?)),
+            #[cfg(feature = "tokens")]
             Data::TokenAssociate(it) => {
                 Ok(Self::TokenAssociate(data::TokenAssociate::from_protobuf(it)?))
             }
+            #[cfg(feature = "tokens")]
             Data::TokenDissociate(it) => {
                 Ok(Self::TokenDissociate(data::TokenDissociate::from_protobuf(it)?))
             }
+            #[cfg(feature = "tokens")]
             Data::TokenFeeScheduleUpdate(it) => {
                 Ok(Self::TokenFeeScheduleUpdate(data::TokenFeeScheduleUpdate::from_protobuf(it)?))
             }
+            #[cfg(feature = "tokens")]
             Data::TokenPause(it) => Ok(Self::TokenPause(data::TokenPause::from_protobuf(it)?)),
+            #[cfg(feature = "tokens")]
             Data::TokenUnpause(it) => {
                 Ok(Self::TokenUnpause(data::TokenUnpause::from_protobuf(it)?))
             }
+            #[cfg(feature = "tokens")]
             Data::TokenReject(it) => Ok(Self::TokenReject(data::TokenReject::from_protobuf(it)?)),
             Data::ScheduleDelete(it) => {
                 Ok(Self::ScheduleDelete(data::ScheduleDelete::from_protobuf(it)?))
             }
             Data::UtilPrng(it) => Ok(Self::Prng(data::Prng::from_protobuf(it)?)),
+            #[cfg(feature = "tokens")]
             Data::TokenUpdateNfts(it) => {
                 Ok(Self::TokenUpdateNfts(data::TokenUpdateNfts::from_protobuf(it)?))
             }
             Data::NodeCreate(it) => Ok(Self::NodeCreate(data::NodeCreate::from_protobuf(it)?)),
             Data::NodeUpdate(it) => Ok(Self::NodeUpdate(data::NodeUpdate::from_protobuf(it)?)),
             Data::NodeDelete(it) => Ok(Self::NodeDelete(data::NodeDelete::from_protobuf(it)?)),
+            #[cfg(feature = "tokens")]
             Data::TokenAirdrop(it) => {
                 Ok(Self::TokenAirdrop(data::TokenAirdrop::from_protobuf(it)?))
             }
+            #[cfg(feature = "tokens")]
             Data::TokenClaimAirdrop(it) => {
                 Ok(Self::TokenClaimAirdrop(data::TokenClaimAirdrop::from_protobuf(it)?))
             }
+            #[cfg(feature = "tokens")]
             Data::TokenCancelAirdrop(it) => {
                 Ok(Self::TokenCancelAirdrop(data::TokenCancelAirdrop::from_protobuf(it)?))
             }
+            #[cfg(not(feature = "contracts"))]
+            Data::ContractCall(_)
+            | Data::ContractCreateInstance(_)
+            | Data::ContractUpdateInstance(_)
+            | Data::ContractDeleteInstance(_) => {
+                Err(crate::Error::from_protobuf("transaction requires the `contracts` feature"))
+            }
+            #[cfg(not(feature = "files"))]
+            Data::FileAppend(_)
+            | Data::FileCreate(_)
+            | Data::FileDelete(_)
+            | Data::FileUpdate(_) => {
+                Err(crate::Error::from_protobuf("transaction requires the `files` feature"))
+            }
+            #[cfg(not(feature = "tokens"))]
+            Data::TokenCreation(_)
+            | Data::TokenFreeze(_)
+            | Data::TokenUnfreeze(_)
+            | Data::TokenGrantKyc(_)
+            | Data::TokenRevokeKyc(_)
+            | Data::TokenDeletion(_)
+            | Data::TokenUpdate(_)
+            | Data::TokenMint(_)
+            | Data::TokenBurn(_)
+            | Data::TokenWipe(_)
+            | Data::TokenAssociate(_)
+            | Data::TokenDissociate(_)
+            | Data::TokenFeeScheduleUpdate(_)
+            | Data::TokenPause(_)
+            | Data::TokenUnpause(_)
+            | Data::TokenReject(_)
+            | Data::TokenUpdateNfts(_)
+            | Data::TokenAirdrop(_)
+            | Data::TokenClaimAirdrop(_)
+            | Data::TokenCancelAirdrop(_) => {
+                Err(crate::Error::from_protobuf("transaction requires the `tokens` feature"))
+            }
+            #[cfg(not(feature = "topics"))]
+            Data::ConsensusCreateTopic(_)
+            | Data::ConsensusUpdateTopic(_)
+            | Data::ConsensusDeleteTopic(_)
+            | Data::ConsensusSubmitMessage(_) => {
+                Err(crate::Error::from_protobuf("transaction requires the `topics` feature"))
+            }
         }
     }
 }
@@ -353,87 +497,114 @@ impl ToSchedulableTransactionDataProtobuf for AnySchedulableTransactionData {
             AnySchedulableTransactionData::AccountAllowanceDelete(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "contracts")]
             AnySchedulableTransactionData::ContractCreate(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "contracts")]
             AnySchedulableTransactionData::ContractUpdate(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "contracts")]
             AnySchedulableTransactionData::ContractDelete(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "contracts")]
             AnySchedulableTransactionData::ContractExecute(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
             AnySchedulableTransactionData::Transfer(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "topics")]
             AnySchedulableTransactionData::TopicCreate(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "topics")]
             AnySchedulableTransactionData::TopicUpdate(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "topics")]
             AnySchedulableTransactionData::TopicDelete(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "topics")]
             AnySchedulableTransactionData::TopicMessageSubmit(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "files")]
             AnySchedulableTransactionData::FileAppend(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "files")]
             AnySchedulableTransactionData::FileCreate(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "files")]
             AnySchedulableTransactionData::FileUpdate(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "files")]
             AnySchedulableTransactionData::FileDelete(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenAssociate(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenBurn(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenCreate(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenDelete(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenDissociate(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenFeeScheduleUpdate(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenFreeze(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenGrantKyc(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenMint(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenPause(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenRevokeKyc(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenUnfreeze(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenUnpause(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenUpdate(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenWipe(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
@@ -452,9 +623,11 @@ impl ToSchedulableTransactionDataProtobuf for AnySchedulableTransactionData {
             AnySchedulableTransactionData::Prng(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenUpdateNfts(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenReject(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
@@ -467,12 +640,15 @@ impl ToSchedulableTransactionDataProtobuf for AnySchedulableTransactionData {
             AnySchedulableTransactionData::NodeDelete(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenAirdrop(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenClaimAirdrop(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenCancelAirdrop(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
@@ -492,46 +668,78 @@ impl TryFrom<AnyTransactionData> for AnySchedulableTransactionData {
                 Ok(Self::AccountAllowanceApprove(it))
             }
             AnyTransactionData::AccountAllowanceDelete(it) => Ok(Self::AccountAllowanceDelete(it)),
+            #[cfg(feature = "contracts")]
             AnyTransactionData::ContractCreate(it) => Ok(Self::ContractCreate(it)),
+            #[cfg(feature = "contracts")]
             AnyTransactionData::ContractUpdate(it) => Ok(Self::ContractUpdate(it)),
+            #[cfg(feature = "contracts")]
             AnyTransactionData::ContractDelete(it) => Ok(Self::ContractDelete(it)),
+            #[cfg(feature = "contracts")]
             AnyTransactionData::ContractExecute(it) => Ok(Self::ContractExecute(it)),
             AnyTransactionData::Transfer(it) => Ok(Self::Transfer(it)),
+            #[cfg(feature = "topics")]
             AnyTransactionData::TopicCreate(it) => Ok(Self::TopicCreate(it)),
+            #[cfg(feature = "topics")]
             AnyTransactionData::TopicUpdate(it) => Ok(Self::TopicUpdate(it)),
+            #[cfg(feature = "topics")]
             AnyTransactionData::TopicDelete(it) => Ok(Self::TopicDelete(it)),
+            #[cfg(feature = "topics")]
             AnyTransactionData::TopicMessageSubmit(it) => Ok(Self::TopicMessageSubmit(it)),
+            #[cfg(feature = "files")]
             AnyTransactionData::FileAppend(it) => Ok(Self::FileAppend(it)),
+            #[cfg(feature = "files")]
             AnyTransactionData::FileCreate(it) => Ok(Self::FileCreate(it)),
+            #[cfg(feature = "files")]
             AnyTransactionData::FileUpdate(it) => Ok(Self::FileUpdate(it)),
+            #[cfg(feature = "files")]
             AnyTransactionData::FileDelete(it) => Ok(Self::FileDelete(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenAssociate(it) => Ok(Self::TokenAssociate(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenBurn(it) => Ok(Self::TokenBurn(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenCreate(it) => Ok(Self::TokenCreate(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenDelete(it) => Ok(Self::TokenDelete(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenDissociate(it) => Ok(Self::TokenDissociate(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenFeeScheduleUpdate(it) => Ok(Self::TokenFeeScheduleUpdate(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenFreeze(it) => Ok(Self::TokenFreeze(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenGrantKyc(it) => Ok(Self::TokenGrantKyc(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenMint(it) => Ok(Self::TokenMint(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenPause(it) => Ok(Self::TokenPause(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenRevokeKyc(it) => Ok(Self::TokenRevokeKyc(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenUnfreeze(it) => Ok(Self::TokenUnfreeze(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenUnpause(it) => Ok(Self::TokenUnpause(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenUpdate(it) => Ok(Self::TokenUpdate(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenWipe(it) => Ok(Self::TokenWipe(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenReject(it) => Ok(Self::TokenReject(it)),
             AnyTransactionData::SystemDelete(it) => Ok(Self::SystemDelete(it)),
             AnyTransactionData::SystemUndelete(it) => Ok(Self::SystemUndelete(it)),
             AnyTransactionData::Freeze(it) => Ok(Self::Freeze(it)),
             AnyTransactionData::ScheduleDelete(it) => Ok(Self::ScheduleDelete(it)),
             AnyTransactionData::Prng(it) => Ok(Self::Prng(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenUpdateNfts(it) => Ok(Self::TokenUpdateNfts(it)),
             AnyTransactionData::NodeCreate(it) => Ok(Self::NodeCreate(it)),
             AnyTransactionData::NodeUpdate(it) => Ok(Self::NodeUpdate(it)),
             AnyTransactionData::NodeDelete(it) => Ok(Self::NodeDelete(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenAirdrop(it) => Ok(Self::TokenAirdrop(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenClaimAirdrop(it) => Ok(Self::TokenClaimAirdrop(it)),
+            #[cfg(feature = "tokens")]
             AnyTransactionData::TokenCancelAirdrop(it) => Ok(Self::TokenCancelAirdrop(it)),
 
             // fixme: basic-parse isn't suitable for this.
@@ -541,6 +749,7 @@ impl TryFrom<AnyTransactionData> for AnySchedulableTransactionData {
             AnyTransactionData::ScheduleSign(_) => {
                 Err(crate::Error::basic_parse("Cannot schedule `ScheduleSignTransaction`"))
             }
+            #[cfg(feature = "contracts")]
             AnyTransactionData::Ethereum(_) => {
                 Err(crate::Error::basic_parse("Cannot schedule `EthereumTransaction`"))
             }
@@ -563,48 +772,80 @@ impl From<AnySchedulableTransactionData> for AnyTransactionData {
             AnySchedulableTransactionData::AccountAllowanceDelete(it) => {
                 Self::AccountAllowanceDelete(it)
             }
+            #[cfg(feature = "contracts")]
             AnySchedulableTransactionData::ContractCreate(it) => Self::ContractCreate(it),
+            #[cfg(feature = "contracts")]
             AnySchedulableTransactionData::ContractUpdate(it) => Self::ContractUpdate(it),
+            #[cfg(feature = "contracts")]
             AnySchedulableTransactionData::ContractDelete(it) => Self::ContractDelete(it),
+            #[cfg(feature = "contracts")]
             AnySchedulableTransactionData::ContractExecute(it) => Self::ContractExecute(it),
             AnySchedulableTransactionData::Transfer(it) => Self::Transfer(it),
+            #[cfg(feature = "topics")]
             AnySchedulableTransactionData::TopicCreate(it) => Self::TopicCreate(it),
+            #[cfg(feature = "topics")]
             AnySchedulableTransactionData::TopicUpdate(it) => Self::TopicUpdate(it),
+            #[cfg(feature = "topics")]
             AnySchedulableTransactionData::TopicDelete(it) => Self::TopicDelete(it),
+            #[cfg(feature = "topics")]
             AnySchedulableTransactionData::TopicMessageSubmit(it) => Self::TopicMessageSubmit(it),
+            #[cfg(feature = "files")]
             AnySchedulableTransactionData::FileAppend(it) => Self::FileAppend(it),
+            #[cfg(feature = "files")]
             AnySchedulableTransactionData::FileCreate(it) => Self::FileCreate(it),
+            #[cfg(feature = "files")]
             AnySchedulableTransactionData::FileUpdate(it) => Self::FileUpdate(it),
+            #[cfg(feature = "files")]
             AnySchedulableTransactionData::FileDelete(it) => Self::FileDelete(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenAssociate(it) => Self::TokenAssociate(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenBurn(it) => Self::TokenBurn(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenCreate(it) => Self::TokenCreate(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenDelete(it) => Self::TokenDelete(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenDissociate(it) => Self::TokenDissociate(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenFeeScheduleUpdate(it) => {
                 Self::TokenFeeScheduleUpdate(it)
             }
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenFreeze(it) => Self::TokenFreeze(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenGrantKyc(it) => Self::TokenGrantKyc(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenMint(it) => Self::TokenMint(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenPause(it) => Self::TokenPause(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenRevokeKyc(it) => Self::TokenRevokeKyc(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenUnfreeze(it) => Self::TokenUnfreeze(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenUnpause(it) => Self::TokenUnpause(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenUpdate(it) => Self::TokenUpdate(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenWipe(it) => Self::TokenWipe(it),
             AnySchedulableTransactionData::SystemDelete(it) => Self::SystemDelete(it),
             AnySchedulableTransactionData::SystemUndelete(it) => Self::SystemUndelete(it),
             AnySchedulableTransactionData::Freeze(it) => Self::Freeze(it),
             AnySchedulableTransactionData::ScheduleDelete(it) => Self::ScheduleDelete(it),
             AnySchedulableTransactionData::Prng(it) => Self::Prng(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenUpdateNfts(it) => Self::TokenUpdateNfts(it),
             AnySchedulableTransactionData::NodeCreate(it) => Self::NodeCreate(it),
             AnySchedulableTransactionData::NodeUpdate(it) => Self::NodeUpdate(it),
             AnySchedulableTransactionData::NodeDelete(it) => Self::NodeDelete(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenReject(it) => Self::TokenReject(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenAirdrop(it) => Self::TokenAirdrop(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenClaimAirdrop(it) => Self::TokenClaimAirdrop(it),
+            #[cfg(feature = "tokens")]
             AnySchedulableTransactionData::TokenCancelAirdrop(it) => Self::TokenCancelAirdrop(it),
         }
     }
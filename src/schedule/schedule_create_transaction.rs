@@ -11,12 +11,17 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_key,
+    check_memo,
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -89,6 +94,9 @@ impl ScheduleCreateTransaction {
     }
 
     /// Sets the timestamp for when the transaction should be evaluated for execution and then expire.
+    ///
+    /// Without an expiration time the schedule expires after the network's default of 30 minutes,
+    /// setting one makes it a long-term schedule (HIP-423), it isn't derived from the scheduled transaction.
     pub fn expiration_time(&mut self, time: OffsetDateTime) -> &mut Self {
         self.data_mut().expiration_time = Some(time);
         self
@@ -147,7 +155,30 @@ impl ScheduleCreateTransaction {
     }
 }
 
-impl TransactionData for ScheduleCreateTransactionData {}
+impl TransactionData for ScheduleCreateTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "scheduled_transaction", &self.scheduled_transaction);
+
+        if let Some(schedule_memo) = &self.schedule_memo {
+            check_memo(problems, "schedule_memo", schedule_memo);
+        }
+
+        if let Some(key) = &self.admin_key {
+            check_key(problems, "admin_key", key);
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("payer", self.payer_account_id.as_ref())
+            .key("admin_key", self.admin_key.as_ref());
+
+        if let Some(scheduled) = &self.scheduled_transaction {
+            let data = AnyTransactionData::from((*scheduled.data).clone());
+            description.nested("scheduled_transaction", TransactionDescription::of_data(&data));
+        }
+    }
+}
 
 impl TransactionExecute for ScheduleCreateTransactionData {
     fn execute(
@@ -217,7 +248,7 @@ impl FromProtobuf<services::ScheduleCreateTransactionBody> for ScheduleCreateTra
             schedule_memo: Some(pb.memo),
             admin_key: Option::from_protobuf(pb.admin_key)?,
             payer_account_id: Option::from_protobuf(pb.payer_account_id)?,
-            expiration_time: pb.expiration_time.map(Into::into),
+            expiration_time: Option::from_protobuf(pb.expiration_time)?,
             wait_for_expiry: pb.wait_for_expiry,
         })
     }
@@ -531,5 +562,17 @@ mod tests {
         fn wait_for_expiry_frozen_panics() {
             make_transaction().wait_for_expiry(true);
         }
+
+        #[test]
+        fn schedule_until() {
+            let mut inner = scheduled_transaction();
+            inner.transaction_valid_duration(time::Duration::seconds(60));
+
+            let tx = inner.schedule_until(EXPIRATION_TIME);
+
+            assert_eq!(tx.get_expiration_time(), Some(EXPIRATION_TIME));
+            assert_eq!(tx.get_transaction_valid_duration(), Some(time::Duration::seconds(60)));
+            assert_eq!(tx.get_wait_for_expiry(), false);
+        }
     }
 }
@@ -10,12 +10,15 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -48,7 +51,15 @@ impl ScheduleDeleteTransaction {
         self
     }
 }
-impl TransactionData for ScheduleDeleteTransactionData {}
+impl TransactionData for ScheduleDeleteTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "schedule_id", &self.schedule_id);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.optional_entity("schedule", self.schedule_id.as_ref());
+    }
+}
 
 impl TransactionExecute for ScheduleDeleteTransactionData {
     fn execute(
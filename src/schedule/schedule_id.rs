@@ -12,6 +12,7 @@ use hedera_proto::services;
 
 use crate::entity_id::{
     Checksum,
+    EntityIdRange,
     ValidateChecksums,
 };
 use crate::ledger_id::RefLedgerId;
@@ -20,11 +21,12 @@ use crate::{
     EntityId,
     Error,
     FromProtobuf,
+    LedgerId,
     ToProtobuf,
 };
 
 /// The unique identifier for a scheduled transaction on Hiero.
-#[derive(Hash, PartialEq, Eq, Clone, Copy)]
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 #[repr(C)]
 pub struct ScheduleId {
     /// A non-negative number identifying the shard containing this scheduled transaction.
@@ -46,6 +48,32 @@ impl ScheduleId {
         Self { shard, realm, num, checksum: None }
     }
 
+    /// Returns the ID after `self` in the same shard and realm, IE, `0.0.1002` after `0.0.1001`.
+    ///
+    /// Returns `None` if `self.num` is `u64::MAX`.
+    #[must_use]
+    pub fn next(&self) -> Option<Self> {
+        self.num.checked_add(1).map(|num| Self::new(self.shard, self.realm, num))
+    }
+
+    /// Returns the ID before `self` in the same shard and realm, IE, `0.0.1000` before `0.0.1001`.
+    ///
+    /// Returns `None` if `self.num` is `0`.
+    #[must_use]
+    pub fn prev(&self) -> Option<Self> {
+        self.num.checked_sub(1).map(|num| Self::new(self.shard, self.realm, num))
+    }
+
+    /// Returns an iterator over the schedule IDs from `start` (inclusive) to `end` (exclusive).
+    ///
+    /// Only `shard`, `realm`, and `num` are used, checksums are ignored.
+    ///
+    /// The range is empty if `start` and `end` aren't in the same shard and realm.
+    #[must_use]
+    pub fn range(start: Self, end: Self) -> EntityIdRange<Self> {
+        EntityIdRange::new((start.shard, start.realm, start.num), (end.shard, end.realm, end.num))
+    }
+
     /// Create a new `AccountBalance` from protobuf-encoded `bytes`.
     ///
     /// # Errors
@@ -93,6 +121,22 @@ impl ScheduleId {
     pub fn validate_checksum(&self, client: &Client) -> Result<(), Error> {
         EntityId::validate_checksum(self.shard, self.realm, self.num, self.checksum, client)
     }
+
+    /// Convert `self` to a string with a checksum for `ledger_id`,
+    /// see [`EntityId::to_string_with_checksum_for_ledger`](crate::EntityId::to_string_with_checksum_for_ledger).
+    #[must_use]
+    pub fn to_string_with_checksum_for_ledger(&self, ledger_id: &LedgerId) -> String {
+        EntityId::to_string_with_checksum_for_ledger_id(
+            self.to_string(),
+            ledger_id.as_ref_ledger_id(),
+        )
+    }
+
+    /// Validates `self.checksum` for `ledger_id`,
+    /// see [`EntityId::validate_checksum_for_ledger`](crate::EntityId::validate_checksum_for_ledger).
+    pub fn validate_checksum_for_ledger(&self, ledger_id: &LedgerId) -> crate::Result<()> {
+        self.validate_checksums(ledger_id.as_ref_ledger_id())
+    }
 }
 
 impl ValidateChecksums for ScheduleId {
@@ -89,6 +89,7 @@ impl ScheduleInfo {
                 transaction_id: Some(self.scheduled_transaction_id),
                 operator: None,
                 is_frozen: true,
+                frozen_environment: None,
                 regenerate_transaction_id: Some(false),
                 custom_fee_limits: Vec::new(),
                 batch_key: None,
@@ -137,11 +138,11 @@ impl FromProtobuf<services::ScheduleInfo> for ScheduleInfo {
 
         let (executed_at, deleted_at) = match pb.data {
             Some(services::schedule_info::Data::DeletionTime(deleted)) => {
-                (None, Some(deleted.into()))
+                (None, Some(FromProtobuf::from_protobuf(deleted)?))
             }
 
             Some(services::schedule_info::Data::ExecutionTime(executed)) => {
-                (Some(executed.into()), None)
+                (Some(FromProtobuf::from_protobuf(executed)?), None)
             }
 
             None => (None, None),
@@ -154,7 +155,7 @@ impl FromProtobuf<services::ScheduleInfo> for ScheduleInfo {
             memo: pb.memo,
             creator_account_id: AccountId::from_protobuf(creator_account_id)?,
             payer_account_id,
-            expiration_time: pb.expiration_time.map(Into::into),
+            expiration_time: Option::from_protobuf(pb.expiration_time)?,
             admin_key,
             scheduled_transaction_id,
             signatories,
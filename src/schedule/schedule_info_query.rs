@@ -12,6 +12,7 @@ use crate::query::{
 use crate::{
     BoxGrpcFuture,
     Error,
+    FromProtobuf,
     Query,
     ScheduleId,
     ScheduleInfo,
@@ -61,6 +62,12 @@ impl ToQueryProtobuf for ScheduleInfoQueryData {
     }
 }
 
+impl FromProtobuf<services::ScheduleGetInfoQuery> for ScheduleInfoQueryData {
+    fn from_protobuf(pb: services::ScheduleGetInfoQuery) -> crate::Result<Self> {
+        Ok(Self { schedule_id: Option::from_protobuf(pb.schedule_id)? })
+    }
+}
+
 impl QueryExecute for ScheduleInfoQueryData {
     type Response = ScheduleInfo;
 
@@ -9,11 +9,14 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -45,7 +48,15 @@ impl ScheduleSignTransaction {
     }
 }
 
-impl TransactionData for ScheduleSignTransactionData {}
+impl TransactionData for ScheduleSignTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "schedule_id", &self.schedule_id);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.optional_entity("schedule", self.schedule_id.as_ref());
+    }
+}
 
 impl TransactionExecute for ScheduleSignTransactionData {
     fn execute(
@@ -0,0 +1,263 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! `#[serde(with = "...")]` helpers for types that are written as strings or numbers in JSON.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Serializes a value with its `Display` impl.
+struct AsDisplay<'a, T>(&'a T);
+
+impl<T: Display> serde::Serialize for AsDisplay<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self.0)
+    }
+}
+
+/// Deserializes a value with its `FromStr` impl.
+struct FromString<T>(T);
+
+impl<'de, T> serde::Deserialize<'de> for FromString<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+
+        value.parse().map(Self).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serializes bytes as hex.
+struct AsHex<'a>(&'a [u8]);
+
+impl serde::Serialize for AsHex<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&::hex::encode(self.0))
+    }
+}
+
+/// Deserializes bytes from hex.
+struct FromHex(Vec<u8>);
+
+impl<'de> serde::Deserialize<'de> for FromHex {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+
+        ::hex::decode(&value)
+            .map(Self)
+            .map_err(|_| serde::de::Error::custom(format!("invalid hex `{value}`")))
+    }
+}
+
+/// (De)serializes a field with its `Display` and `FromStr` impls.
+pub(crate) mod display {
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    pub(crate) fn serialize<T: Display, S: serde::Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&super::AsDisplay(value), serializer)
+    }
+
+    pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: serde::Deserializer<'de>,
+    {
+        <super::FromString<T> as serde::Deserialize>::deserialize(deserializer).map(|it| it.0)
+    }
+}
+
+/// (De)serializes an optional field with its `Display` and `FromStr` impls.
+pub(crate) mod option_display {
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    pub(crate) fn serialize<T: Display, S: serde::Serializer>(
+        value: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&value.as_ref().map(super::AsDisplay), serializer)
+    }
+
+    pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: serde::Deserializer<'de>,
+    {
+        <Option<super::FromString<T>> as serde::Deserialize>::deserialize(deserializer)
+            .map(|it| it.map(|it| it.0))
+    }
+}
+
+/// (De)serializes a list with the `Display` and `FromStr` impls of its items.
+pub(crate) mod vec_display {
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    pub(crate) fn serialize<T: Display, S: serde::Serializer>(
+        value: &[T],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(value.iter().map(super::AsDisplay))
+    }
+
+    pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: serde::Deserializer<'de>,
+    {
+        <Vec<super::FromString<T>> as serde::Deserialize>::deserialize(deserializer)
+            .map(|it| it.into_iter().map(|it| it.0).collect())
+    }
+}
+
+/// (De)serializes bytes as hex.
+pub(crate) mod hex {
+    pub(crate) fn serialize<S: serde::Serializer>(
+        value: &[u8],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&super::AsHex(value), serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        <super::FromHex as serde::Deserialize>::deserialize(deserializer).map(|it| it.0)
+    }
+}
+
+/// (De)serializes optional bytes as hex.
+pub(crate) mod option_hex {
+    pub(crate) fn serialize<S: serde::Serializer>(
+        value: &Option<Vec<u8>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&value.as_deref().map(super::AsHex), serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<u8>>, D::Error> {
+        <Option<super::FromHex> as serde::Deserialize>::deserialize(deserializer)
+            .map(|it| it.map(|it| it.0))
+    }
+}
+
+/// (De)serializes a list of byte strings as hex.
+pub(crate) mod vec_hex {
+    pub(crate) fn serialize<S: serde::Serializer>(
+        value: &[Vec<u8>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(value.iter().map(|it| super::AsHex(it.as_slice())))
+    }
+
+    pub(crate) fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Vec<u8>>, D::Error> {
+        <Vec<super::FromHex> as serde::Deserialize>::deserialize(deserializer)
+            .map(|it| it.into_iter().map(|it| it.0).collect())
+    }
+}
+
+/// (De)serializes a time as a mirror node timestamp, IE `"1554158542.000000000"`.
+pub(crate) mod timestamp {
+    use time::OffsetDateTime;
+
+    use crate::protobuf::time::{
+        format_mirror_timestamp,
+        parse_mirror_timestamp,
+    };
+
+    pub(crate) fn serialize<S: serde::Serializer>(
+        value: &OffsetDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format_mirror_timestamp(*value))
+    }
+
+    pub(crate) fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<OffsetDateTime, D::Error> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+
+        parse_mirror_timestamp(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// (De)serializes an optional time as a mirror node timestamp.
+pub(crate) mod option_timestamp {
+    use time::OffsetDateTime;
+
+    pub(crate) fn serialize<S: serde::Serializer>(
+        value: &Option<OffsetDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => super::timestamp::serialize(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<OffsetDateTime>, D::Error> {
+        let Some(value) = <Option<String> as serde::Deserialize>::deserialize(deserializer)? else {
+            return Ok(None);
+        };
+
+        crate::protobuf::time::parse_mirror_timestamp(&value)
+            .map(Some)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// (De)serializes a [`Status`](crate::Status) as its protobuf name, IE `"SUCCESS"`.
+pub(crate) mod status {
+    use crate::Status;
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(crate) fn serialize<S: serde::Serializer>(
+        value: &Status,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(value.as_str_name())
+    }
+
+    pub(crate) fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Status, D::Error> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+
+        Status::from_str_name(&value)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown status `{value}`")))
+    }
+}
+
+/// (De)serializes an [`Hbar`](crate::Hbar) amount as tinybars.
+pub(crate) mod tinybars {
+    use crate::Hbar;
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(crate) fn serialize<S: serde::Serializer>(
+        value: &Hbar,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(value.to_tinybars())
+    }
+
+    pub(crate) fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Hbar, D::Error> {
+        <i64 as serde::Deserialize>::deserialize(deserializer).map(Hbar::from_tinybars)
+    }
+}
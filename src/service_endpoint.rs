@@ -13,19 +13,25 @@ use crate::{
     FromProtobuf,
 };
 
-fn parse_socket_addr_v4(ip: Vec<u8>, port: i32) -> crate::Result<SocketAddrV4> {
+fn parse_ipv4_addr(ip: Vec<u8>) -> crate::Result<Ipv4Addr> {
     let octets: Result<[u8; 4], _> = ip.try_into();
     let octets = octets.map_err(|v| {
         Error::from_protobuf(format!("expected 4 byte ip address, got `{}` bytes", v.len()))
     })?;
 
+    Ok(octets.into())
+}
+
+fn parse_socket_addr_v4(ip: Vec<u8>, port: i32) -> crate::Result<SocketAddrV4> {
+    let ip = parse_ipv4_addr(ip)?;
+
     let port = u16::try_from(port).map_err(|_| {
         Error::from_protobuf(format!(
             "expected 16 bit non-negative port number, but the port was actually `{port}`",
         ))
     })?;
 
-    Ok(SocketAddrV4::new(octets.into(), port))
+    Ok(SocketAddrV4::new(ip, port))
 }
 
 fn validate_domain_name(domain_name: String) -> crate::Result<()> {
@@ -71,6 +77,21 @@ pub struct ServiceEndpoint {
     pub domain_name: String,
 }
 
+impl ServiceEndpoint {
+    /// Like [`from_protobuf`](FromProtobuf::from_protobuf), but keeps the port and domain name as they are.
+    ///
+    /// Used for endpoints in node transactions, where the endpoint is what the transaction sets, not how to reach the node.
+    pub(crate) fn from_protobuf_verbatim(pb: services::ServiceEndpoint) -> crate::Result<Self> {
+        let ip_address_v4 = if pb.ip_address_v4.is_empty() {
+            None
+        } else {
+            Some(parse_ipv4_addr(pb.ip_address_v4)?)
+        };
+
+        Ok(Self { ip_address_v4, port: pb.port, domain_name: pb.domain_name })
+    }
+}
+
 impl FromProtobuf<services::ServiceEndpoint> for ServiceEndpoint {
     fn from_protobuf(pb: services::ServiceEndpoint) -> crate::Result<Self> {
         let mut port = pb.port;
@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use hedera_proto::services;
+
+use crate::{
+    Error,
+    FromProtobuf,
+    KeyAlgorithm,
+    PrivateKey,
+    PublicKey,
+    ToProtobuf,
+};
+
+/// A signature of a transaction body, and the public key that made it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignaturePair {
+    /// The public key of the signer.
+    pub public_key: PublicKey,
+
+    /// The signature.
+    pub signature: Vec<u8>,
+}
+
+impl SignaturePair {
+    /// Returns `Ok(())` if `self` is a valid signature of `body_bytes`.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature is invalid.
+    pub fn verify(&self, body_bytes: &[u8]) -> crate::Result<()> {
+        self.public_key.verify(body_bytes, &self.signature)
+    }
+}
+
+/// Sign the encoded transaction body `body_bytes` with `private_key`.
+///
+/// This makes the same signature as signing the transaction itself,
+/// so a signing service can sign the bodies from [`Transaction::body_bytes_per_node`](crate::Transaction::body_bytes_per_node)
+/// without the full transaction.
+#[must_use]
+pub fn sign_body_bytes(private_key: &PrivateKey, body_bytes: &[u8]) -> SignaturePair {
+    SignaturePair { public_key: private_key.public_key(), signature: private_key.sign(body_bytes) }
+}
+
+impl From<(PublicKey, Vec<u8>)> for SignaturePair {
+    fn from((public_key, signature): (PublicKey, Vec<u8>)) -> Self {
+        Self { public_key, signature }
+    }
+}
+
+impl FromProtobuf<services::SignaturePair> for SignaturePair {
+    /// The public key prefix must be the full public key.
+    fn from_protobuf(pb: services::SignaturePair) -> crate::Result<Self> {
+        use services::signature_pair::Signature;
+
+        let (public_key, signature) = match pb.signature {
+            Some(Signature::Ed25519(signature)) => {
+                (PublicKey::from_bytes_ed25519(&pb.pub_key_prefix)?, signature)
+            }
+            Some(Signature::EcdsaSecp256k1(signature)) => {
+                (PublicKey::from_bytes_ecdsa(&pb.pub_key_prefix)?, signature)
+            }
+            Some(signature) => match KeyAlgorithm::from_signature_protobuf(&signature) {
+                Some(algorithm) => return Err(Error::UnsupportedKeyAlgorithm(algorithm)),
+                None => return Err(Error::from_protobuf("unexpected contract signature")),
+            },
+            None => return Err(Error::from_protobuf("unexpected missing `signature`")),
+        };
+
+        Ok(Self { public_key, signature })
+    }
+}
+
+impl ToProtobuf for SignaturePair {
+    type Protobuf = services::SignaturePair;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::SignaturePair {
+            signature: Some(self.public_key.kind().signature_protobuf(self.signature.clone())),
+            // TODO: is there any way to utilize the _prefix_ nature of this field?
+            pub_key_prefix: self.public_key.to_bytes_raw(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hedera_proto::services;
+
+    use super::{
+        sign_body_bytes,
+        SignaturePair,
+    };
+    use crate::{
+        Error,
+        FromProtobuf,
+        KeyAlgorithm,
+        PrivateKey,
+        ToProtobuf,
+    };
+
+    #[test]
+    fn sign_and_verify() {
+        for key in [PrivateKey::generate_ed25519(), PrivateKey::generate_ecdsa()] {
+            let pair = sign_body_bytes(&key, b"body");
+
+            assert_eq!(pair.public_key, key.public_key());
+            pair.verify(b"body").unwrap();
+            assert_matches!(pair.verify(b"other body"), Err(Error::SignatureVerify(_)));
+        }
+    }
+
+    #[test]
+    fn protobuf_round_trip() {
+        for key in [PrivateKey::generate_ed25519(), PrivateKey::generate_ecdsa()] {
+            let pair = sign_body_bytes(&key, b"body");
+
+            assert_eq!(SignaturePair::from_protobuf(pair.to_protobuf()).unwrap(), pair);
+        }
+    }
+
+    #[test]
+    fn from_protobuf_unsupported() {
+        let pb = services::SignaturePair {
+            pub_key_prefix: Vec::new(),
+            signature: Some(services::signature_pair::Signature::Rsa3072(Vec::new())),
+        };
+
+        assert_matches!(
+            SignaturePair::from_protobuf(pb),
+            Err(Error::UnsupportedKeyAlgorithm(KeyAlgorithm::Rsa3072))
+        );
+    }
+}
@@ -27,17 +27,25 @@ pub(crate) enum AnySigner {
     // lint note: can't reasonably resolve this because putting the `type` on anything but the `Fn(..)` part is useless
     // but we can't do that because trait aliases don't exist.
     #[allow(clippy::type_complexity)]
-    Arbitrary(Box<PublicKey>, Arc<dyn Fn(&[u8]) -> Vec<u8> + Send + Sync>),
+    Arbitrary(Box<PublicKey>, Arc<dyn Fn(&[u8]) -> crate::Result<Vec<u8>> + Send + Sync>),
 }
 
 impl AnySigner {
     pub(crate) fn arbitrary<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
         public_key: Box<PublicKey>,
         signer: F,
+    ) -> Self {
+        Self::fallible(public_key, move |message| Ok(signer(message)))
+    }
+
+    pub(crate) fn fallible<F: Fn(&[u8]) -> crate::Result<Vec<u8>> + Send + Sync + 'static>(
+        public_key: Box<PublicKey>,
+        signer: F,
     ) -> Self {
         Self::Arbitrary(
             public_key,
-            Arc::new(signer).unsize(Coercion!(to dyn Fn(&[u8]) -> Vec<u8> + Send + Sync)),
+            Arc::new(signer)
+                .unsize(Coercion!(to dyn Fn(&[u8]) -> crate::Result<Vec<u8>> + Send + Sync)),
         )
     }
 }
@@ -62,13 +70,13 @@ impl AnySigner {
         }
     }
 
-    pub(crate) fn sign(&self, message: &[u8]) -> (PublicKey, Vec<u8>) {
+    pub(crate) fn sign(&self, message: &[u8]) -> crate::Result<(PublicKey, Vec<u8>)> {
         match self {
-            AnySigner::PrivateKey(it) => (it.public_key(), it.sign(message)),
+            AnySigner::PrivateKey(it) => Ok((it.public_key(), it.sign(message))),
             AnySigner::Arbitrary(public, signer) => {
-                let bytes = signer(message);
+                let bytes = signer(message)?;
 
-                (**public, bytes)
+                Ok((**public, bytes))
             }
         }
     }
@@ -0,0 +1,827 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Dry-run transactions against an in-memory snapshot of ledger state.
+//!
+//! A [`StateSnapshot`] holds the balances, token associations, NFT owners, and allowances that matter
+//! to a set of transactions, and [`StateSnapshot::apply`] predicts what the network would do with each one,
+//! without touching the network.
+//!
+//! This is meant for planning batches and for tests, the simulation is *not* a full ledger:
+//! - fees aren't charged,
+//! - signatures, keys, KYC, and pausing aren't checked,
+//! - custom fees aren't assessed,
+//! - any account or token that isn't in the snapshot is treated as not existing.
+
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+use std::ops::RangeInclusive;
+
+use self::private::Sealed;
+use crate::{
+    AccountAllowanceApproveTransaction,
+    AccountId,
+    Hbar,
+    NftId,
+    Status,
+    TokenAssociateTransaction,
+    TokenBurnTransaction,
+    TokenDissociateTransaction,
+    TokenId,
+    TokenMintTransaction,
+    TransferTransaction,
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::TransferTransaction {}
+    impl Sealed for crate::TokenAssociateTransaction {}
+    impl Sealed for crate::TokenDissociateTransaction {}
+    impl Sealed for crate::TokenMintTransaction {}
+    impl Sealed for crate::TokenBurnTransaction {}
+    impl Sealed for crate::AccountAllowanceApproveTransaction {}
+}
+
+/// A transaction that can be applied to a [`StateSnapshot`].
+pub trait Simulate: Sealed {
+    /// Apply `self` to `state`, returning the status the network would fail with, if any.
+    ///
+    /// `state` may be partially modified on failure, use [`StateSnapshot::apply`] instead.
+    #[doc(hidden)]
+    fn simulate(&self, state: &mut StateSnapshot) -> Result<(), Status>;
+}
+
+/// An in-memory snapshot of the ledger state that transactions can be simulated against.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateSnapshot {
+    /// Hbar balances in tinybars, every account in the snapshot has one.
+    hbar_balances: HashMap<AccountId, i64>,
+
+    /// Fungible token balances, an entry exists for every token association.
+    token_balances: HashMap<(AccountId, TokenId), u64>,
+
+    /// Token associations that are frozen.
+    frozen: HashSet<(AccountId, TokenId)>,
+
+    /// The treasury of each token in the snapshot.
+    treasuries: HashMap<TokenId, AccountId>,
+
+    /// The owner of each NFT in the snapshot.
+    nft_owners: HashMap<NftId, AccountId>,
+
+    /// The highest serial number of each token's NFTs, including burned NFTs (serials are never reused).
+    last_serials: HashMap<TokenId, u64>,
+
+    /// Hbar allowances in tinybars, keyed by `(owner, spender)`.
+    hbar_allowances: HashMap<(AccountId, AccountId), i64>,
+
+    /// Fungible token allowances, keyed by `(owner, spender, token)`.
+    token_allowances: HashMap<(AccountId, AccountId, TokenId), u64>,
+
+    /// NFT allowances for every serial of a token, keyed by `(owner, spender, token)`.
+    nft_allowances_all: HashSet<(AccountId, AccountId, TokenId)>,
+
+    /// NFT allowances for single serials, the spender of each NFT.
+    nft_spenders: HashMap<NftId, AccountId>,
+}
+
+impl StateSnapshot {
+    /// Create a new, empty, snapshot.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `account_id` to the snapshot with the given hbar balance.
+    pub fn hbar_balance(&mut self, account_id: AccountId, balance: Hbar) -> &mut Self {
+        self.hbar_balances.insert(account_id, balance.to_tinybars());
+        self
+    }
+
+    /// Adds `token_id` to the snapshot with `treasury_account_id` as its treasury.
+    ///
+    /// The treasury is associated with the token.
+    pub fn token(&mut self, token_id: TokenId, treasury_account_id: AccountId) -> &mut Self {
+        self.treasuries.insert(token_id, treasury_account_id);
+        self.token_balances.entry((treasury_account_id, token_id)).or_default();
+        self
+    }
+
+    /// Associates `account_id` with `token_id`, with the given balance.
+    pub fn token_balance(
+        &mut self,
+        account_id: AccountId,
+        token_id: TokenId,
+        balance: u64,
+    ) -> &mut Self {
+        self.token_balances.insert((account_id, token_id), balance);
+        self
+    }
+
+    /// Freezes `account_id`'s association with `token_id`.
+    pub fn freeze(&mut self, account_id: AccountId, token_id: TokenId) -> &mut Self {
+        self.frozen.insert((account_id, token_id));
+        self
+    }
+
+    /// Sets `owner_account_id` as the owner of `nft_id`.
+    pub fn nft(&mut self, nft_id: NftId, owner_account_id: AccountId) -> &mut Self {
+        self.nft_owners.insert(nft_id, owner_account_id);
+
+        let last_serial = self.last_serials.entry(nft_id.token_id).or_default();
+        *last_serial = (*last_serial).max(nft_id.serial);
+
+        self
+    }
+
+    /// Allows `spender_account_id` to spend `amount` of `owner_account_id`'s hbar.
+    pub fn hbar_allowance(
+        &mut self,
+        owner_account_id: AccountId,
+        spender_account_id: AccountId,
+        amount: Hbar,
+    ) -> &mut Self {
+        self.hbar_allowances.insert((owner_account_id, spender_account_id), amount.to_tinybars());
+        self
+    }
+
+    /// Allows `spender_account_id` to spend `amount` of `owner_account_id`'s `token_id`.
+    pub fn token_allowance(
+        &mut self,
+        token_id: TokenId,
+        owner_account_id: AccountId,
+        spender_account_id: AccountId,
+        amount: u64,
+    ) -> &mut Self {
+        self.token_allowances.insert((owner_account_id, spender_account_id, token_id), amount);
+        self
+    }
+
+    /// Allows `spender_account_id` to transfer all of `owner_account_id`'s NFTs of `token_id`.
+    pub fn nft_allowance_all_serials(
+        &mut self,
+        token_id: TokenId,
+        owner_account_id: AccountId,
+        spender_account_id: AccountId,
+    ) -> &mut Self {
+        self.nft_allowances_all.insert((owner_account_id, spender_account_id, token_id));
+        self
+    }
+
+    /// Returns the hbar balance of `account_id`, or `None` if it isn't in the snapshot.
+    #[must_use]
+    pub fn get_hbar_balance(&self, account_id: AccountId) -> Option<Hbar> {
+        self.hbar_balances.get(&account_id).copied().map(Hbar::from_tinybars)
+    }
+
+    /// Returns the `token_id` balance of `account_id`, or `None` if the account isn't associated with the token.
+    #[must_use]
+    pub fn get_token_balance(&self, account_id: AccountId, token_id: TokenId) -> Option<u64> {
+        self.token_balances.get(&(account_id, token_id)).copied()
+    }
+
+    /// Returns the owner of `nft_id`, or `None` if it isn't in the snapshot.
+    #[must_use]
+    pub fn get_nft_owner(&self, nft_id: NftId) -> Option<AccountId> {
+        self.nft_owners.get(&nft_id).copied()
+    }
+
+    /// Returns true if `account_id` is associated with `token_id`.
+    #[must_use]
+    pub fn is_associated(&self, account_id: AccountId, token_id: TokenId) -> bool {
+        self.token_balances.contains_key(&(account_id, token_id))
+    }
+
+    /// Apply `transaction` to this snapshot.
+    ///
+    /// Either the entire transaction is applied, or the snapshot is left unchanged.
+    ///
+    /// # Errors
+    /// - The [`Status`] the network would be expected to fail `transaction` with.
+    pub fn apply<T: Simulate>(&mut self, transaction: &T) -> Result<(), Status> {
+        let mut next = self.clone();
+
+        transaction.simulate(&mut next)?;
+
+        *self = next;
+
+        Ok(())
+    }
+
+    /// Returns the state this snapshot would be in after applying `transaction`, leaving `self` unchanged.
+    ///
+    /// # Errors
+    /// - The [`Status`] the network would be expected to fail `transaction` with.
+    pub fn dry_run<T: Simulate>(&self, transaction: &T) -> Result<Self, Status> {
+        let mut next = self.clone();
+
+        transaction.simulate(&mut next)?;
+
+        Ok(next)
+    }
+
+    fn check_account(&self, account_id: AccountId) -> Result<(), Status> {
+        if self.hbar_balances.contains_key(&account_id) {
+            Ok(())
+        } else {
+            Err(Status::InvalidAccountId)
+        }
+    }
+
+    fn check_token(&self, token_id: TokenId) -> Result<AccountId, Status> {
+        self.treasuries.get(&token_id).copied().ok_or(Status::InvalidTokenId)
+    }
+
+    fn check_association(&self, account_id: AccountId, token_id: TokenId) -> Result<(), Status> {
+        if !self.is_associated(account_id, token_id) {
+            return Err(Status::TokenNotAssociatedToAccount);
+        }
+
+        if self.frozen.contains(&(account_id, token_id)) {
+            return Err(Status::AccountFrozenForToken);
+        }
+
+        Ok(())
+    }
+
+    fn adjust_hbar(
+        &mut self,
+        account_id: AccountId,
+        amount: i64,
+        spender: Option<AccountId>,
+    ) -> Result<(), Status> {
+        self.check_account(account_id)?;
+
+        if let (Some(spender), true) = (spender, amount < 0) {
+            let allowance = self
+                .hbar_allowances
+                .get_mut(&(account_id, spender))
+                .ok_or(Status::SpenderDoesNotHaveAllowance)?;
+
+            *allowance = allowance
+                .checked_add(amount)
+                .filter(|it| *it >= 0)
+                .ok_or(Status::AmountExceedsAllowance)?;
+        }
+
+        let balance = self.hbar_balances.entry(account_id).or_default();
+
+        match balance.checked_add(amount) {
+            Some(it) if it >= 0 => *balance = it,
+            _ => return Err(Status::InsufficientAccountBalance),
+        }
+
+        Ok(())
+    }
+
+    fn adjust_token(
+        &mut self,
+        account_id: AccountId,
+        token_id: TokenId,
+        amount: i64,
+        spender: Option<AccountId>,
+    ) -> Result<(), Status> {
+        self.check_account(account_id)?;
+        self.check_token(token_id)?;
+        self.check_association(account_id, token_id)?;
+
+        if let (Some(spender), true) = (spender, amount < 0) {
+            let allowance = self
+                .token_allowances
+                .get_mut(&(account_id, spender, token_id))
+                .ok_or(Status::SpenderDoesNotHaveAllowance)?;
+
+            *allowance = allowance
+                .checked_sub(amount.unsigned_abs())
+                .ok_or(Status::AmountExceedsAllowance)?;
+        }
+
+        let balance = self.token_balances.entry((account_id, token_id)).or_default();
+
+        let next = if amount < 0 {
+            balance.checked_sub(amount.unsigned_abs())
+        } else {
+            balance.checked_add(amount.unsigned_abs())
+        };
+
+        *balance = next.ok_or(Status::InsufficientTokenBalance)?;
+
+        Ok(())
+    }
+
+    fn transfer_nft(
+        &mut self,
+        nft_id: NftId,
+        sender: AccountId,
+        receiver: AccountId,
+        spender: Option<AccountId>,
+    ) -> Result<(), Status> {
+        self.check_account(sender)?;
+        self.check_account(receiver)?;
+        self.check_token(nft_id.token_id)?;
+        self.check_association(sender, nft_id.token_id)?;
+        self.check_association(receiver, nft_id.token_id)?;
+
+        match self.nft_owners.get(&nft_id) {
+            Some(owner) if *owner == sender => {}
+            Some(_) => return Err(Status::SenderDoesNotOwnNftSerialNo),
+            None => return Err(Status::InvalidNftId),
+        }
+
+        if let Some(spender) = spender {
+            let approved = self.nft_spenders.get(&nft_id) == Some(&spender)
+                || self.nft_allowances_all.contains(&(sender, spender, nft_id.token_id));
+
+            if !approved {
+                return Err(Status::SpenderDoesNotHaveAllowance);
+            }
+        }
+
+        // a transfer clears any single serial allowance.
+        self.nft_spenders.remove(&nft_id);
+        self.nft_owners.insert(nft_id, receiver);
+
+        Ok(())
+    }
+
+    /// Returns the serial numbers of the next `count` NFTs minted for `token_id`, and records them as used.
+    ///
+    /// `count` must not be `0`.
+    fn next_serials(
+        &mut self,
+        token_id: TokenId,
+        count: u64,
+    ) -> Result<RangeInclusive<u64>, Status> {
+        let last_serial = self.last_serials.entry(token_id).or_default();
+
+        let last = last_serial.checked_add(count).ok_or(Status::SerialNumberLimitReached)?;
+        let first = *last_serial + 1;
+        *last_serial = last;
+
+        Ok(first..=last)
+    }
+}
+
+/// Returns the sum of `amounts`, or `None` if it overflows.
+fn checked_sum(mut amounts: impl Iterator<Item = i64>) -> Option<i64> {
+    amounts.try_fold(0_i64, i64::checked_add)
+}
+
+impl Simulate for TransferTransaction {
+    fn simulate(&self, state: &mut StateSnapshot) -> Result<(), Status> {
+        let data = self.data();
+
+        // approved transfers are spent by the payer.
+        let payer = self.get_transaction_id().map(|it| it.account_id);
+
+        let spender = |is_approval: bool| match (is_approval, payer) {
+            (true, Some(payer)) => Ok(Some(payer)),
+            (true, None) => Err(Status::PayerAccountNotFound),
+            (false, _) => Ok(None),
+        };
+
+        if checked_sum(data.transfers.iter().map(|it| it.amount)) != Some(0) {
+            return Err(Status::InvalidAccountAmounts);
+        }
+
+        // debits first, so that a credit can't cover for an overdrawn account.
+        let mut transfers: Vec<_> = data.transfers.iter().collect();
+        transfers.sort_by_key(|it| it.amount);
+
+        for transfer in transfers {
+            state.adjust_hbar(
+                transfer.account_id,
+                transfer.amount,
+                spender(transfer.is_approval)?,
+            )?;
+        }
+
+        for token_transfer in &data.token_transfers {
+            if checked_sum(token_transfer.transfers.iter().map(|it| it.amount)) != Some(0) {
+                return Err(Status::TransfersNotZeroSumForToken);
+            }
+
+            // debits first, so that a credit can't cover for an overdrawn account.
+            let mut transfers: Vec<_> = token_transfer.transfers.iter().collect();
+            transfers.sort_by_key(|it| it.amount);
+
+            for transfer in transfers {
+                state.adjust_token(
+                    transfer.account_id,
+                    token_transfer.token_id,
+                    transfer.amount,
+                    spender(transfer.is_approval)?,
+                )?;
+            }
+
+            for transfer in &token_transfer.nft_transfers {
+                state.transfer_nft(
+                    NftId { token_id: transfer.token_id, serial: transfer.serial },
+                    transfer.sender,
+                    transfer.receiver,
+                    spender(transfer.is_approved)?,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Simulate for TokenAssociateTransaction {
+    fn simulate(&self, state: &mut StateSnapshot) -> Result<(), Status> {
+        let account_id = self.get_account_id().ok_or(Status::InvalidAccountId)?;
+        state.check_account(account_id)?;
+
+        for &token_id in self.get_token_ids() {
+            state.check_token(token_id)?;
+
+            if state.token_balances.insert((account_id, token_id), 0).is_some() {
+                return Err(Status::TokenAlreadyAssociatedToAccount);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Simulate for TokenDissociateTransaction {
+    fn simulate(&self, state: &mut StateSnapshot) -> Result<(), Status> {
+        let account_id = self.get_account_id().ok_or(Status::InvalidAccountId)?;
+        state.check_account(account_id)?;
+
+        for &token_id in self.get_token_ids() {
+            if state.check_token(token_id)? == account_id {
+                return Err(Status::AccountIsTreasury);
+            }
+
+            let owns_nfts = state
+                .nft_owners
+                .iter()
+                .any(|(id, owner)| id.token_id == token_id && *owner == account_id);
+
+            match state.token_balances.remove(&(account_id, token_id)) {
+                None => return Err(Status::TokenNotAssociatedToAccount),
+                Some(balance) if balance > 0 || owns_nfts => {
+                    return Err(Status::TransactionRequiresZeroTokenBalances)
+                }
+                Some(_) => {}
+            }
+
+            state.frozen.remove(&(account_id, token_id));
+        }
+
+        Ok(())
+    }
+}
+
+impl Simulate for TokenMintTransaction {
+    fn simulate(&self, state: &mut StateSnapshot) -> Result<(), Status> {
+        let token_id = self.get_token_id().ok_or(Status::InvalidTokenId)?;
+        let treasury = state.check_token(token_id)?;
+
+        let metadata = self.get_metadata();
+
+        if metadata.is_empty() {
+            let amount = i64::try_from(self.get_amount())
+                .ok()
+                .filter(|it| *it > 0)
+                .ok_or(Status::InvalidTokenMintAmount)?;
+
+            return state.adjust_token(treasury, token_id, amount, None);
+        }
+
+        state.check_association(treasury, token_id)?;
+
+        for serial in state.next_serials(token_id, metadata.len() as u64)? {
+            state.nft_owners.insert(NftId { token_id, serial }, treasury);
+        }
+
+        Ok(())
+    }
+}
+
+impl Simulate for TokenBurnTransaction {
+    fn simulate(&self, state: &mut StateSnapshot) -> Result<(), Status> {
+        let token_id = self.get_token_id().ok_or(Status::InvalidTokenId)?;
+        let treasury = state.check_token(token_id)?;
+
+        let serials = self.get_serials();
+
+        if serials.is_empty() {
+            let amount = i64::try_from(self.get_amount())
+                .ok()
+                .filter(|it| *it > 0)
+                .ok_or(Status::InvalidTokenBurnAmount)?;
+
+            return state.adjust_token(treasury, token_id, -amount, None);
+        }
+
+        for &serial in serials {
+            let nft_id = NftId {
+                token_id,
+                serial: u64::try_from(serial).map_err(|_| Status::InvalidNftId)?,
+            };
+
+            match state.nft_owners.get(&nft_id) {
+                Some(owner) if *owner == treasury => {}
+                Some(_) => return Err(Status::TreasuryMustOwnBurnedNft),
+                None => return Err(Status::InvalidNftId),
+            }
+
+            state.nft_owners.remove(&nft_id);
+            state.nft_spenders.remove(&nft_id);
+        }
+
+        Ok(())
+    }
+}
+
+impl Simulate for AccountAllowanceApproveTransaction {
+    fn simulate(&self, state: &mut StateSnapshot) -> Result<(), Status> {
+        for allowance in self.hbar_approvals() {
+            state.check_account(allowance.owner_account_id)?;
+            state.check_account(allowance.spender_account_id)?;
+
+            state.hbar_allowance(
+                allowance.owner_account_id,
+                allowance.spender_account_id,
+                allowance.amount,
+            );
+        }
+
+        for allowance in self.token_approvals() {
+            state.check_account(allowance.owner_account_id)?;
+            state.check_account(allowance.spender_account_id)?;
+            state.check_token(allowance.token_id)?;
+
+            if !state.is_associated(allowance.owner_account_id, allowance.token_id) {
+                return Err(Status::TokenNotAssociatedToAccount);
+            }
+
+            state.token_allowance(
+                allowance.token_id,
+                allowance.owner_account_id,
+                allowance.spender_account_id,
+                allowance.amount,
+            );
+        }
+
+        for allowance in self.token_nft_approvals() {
+            state.check_account(allowance.owner_account_id)?;
+            state.check_account(allowance.spender_account_id)?;
+            state.check_token(allowance.token_id)?;
+
+            if allowance.approved_for_all == Some(true) {
+                state.nft_allowance_all_serials(
+                    allowance.token_id,
+                    allowance.owner_account_id,
+                    allowance.spender_account_id,
+                );
+            }
+
+            for &serial in &allowance.serials {
+                let nft_id = NftId {
+                    token_id: allowance.token_id,
+                    serial: u64::try_from(serial).map_err(|_| Status::InvalidNftId)?,
+                };
+
+                match state.nft_owners.get(&nft_id) {
+                    Some(owner) if *owner == allowance.owner_account_id => {}
+                    Some(_) => return Err(Status::SenderDoesNotOwnNftSerialNo),
+                    None => return Err(Status::InvalidNftId),
+                }
+
+                state.nft_spenders.insert(nft_id, allowance.spender_account_id);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StateSnapshot;
+    use crate::transaction::test_helpers::TEST_TX_ID;
+    use crate::{
+        AccountAllowanceApproveTransaction,
+        AccountId,
+        Hbar,
+        NftId,
+        Status,
+        TokenAssociateTransaction,
+        TokenBurnTransaction,
+        TokenDissociateTransaction,
+        TokenId,
+        TokenMintTransaction,
+        TransferTransaction,
+    };
+
+    const ALICE: AccountId = AccountId::new(0, 0, 1001);
+    const BOB: AccountId = AccountId::new(0, 0, 1002);
+    const TOKEN: TokenId = TokenId::new(0, 0, 2001);
+
+    fn snapshot() -> StateSnapshot {
+        let mut state = StateSnapshot::new();
+        state
+            .hbar_balance(ALICE, Hbar::new(10))
+            .hbar_balance(BOB, Hbar::new(1))
+            .token(TOKEN, ALICE)
+            .token_balance(ALICE, TOKEN, 100);
+
+        state
+    }
+
+    #[test]
+    fn hbar_transfer() {
+        let mut state = snapshot();
+
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(ALICE, Hbar::new(-4)).hbar_transfer(BOB, Hbar::new(4));
+
+        state.apply(&tx).unwrap();
+
+        assert_eq!(state.get_hbar_balance(ALICE), Some(Hbar::new(6)));
+        assert_eq!(state.get_hbar_balance(BOB), Some(Hbar::new(5)));
+    }
+
+    #[test]
+    fn failed_transfer_leaves_state_unchanged() {
+        let mut state = snapshot();
+        let before = state.clone();
+
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(ALICE, Hbar::new(-1))
+            .hbar_transfer(BOB, Hbar::new(1))
+            .token_transfer(TOKEN, ALICE, -5)
+            .token_transfer(TOKEN, BOB, 5);
+
+        assert_eq!(state.apply(&tx), Err(Status::TokenNotAssociatedToAccount));
+        assert_eq!(state, before);
+    }
+
+    #[test]
+    fn insufficient_balance() {
+        let state = snapshot();
+
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(BOB, Hbar::new(-2)).hbar_transfer(ALICE, Hbar::new(2));
+
+        assert_eq!(state.dry_run(&tx), Err(Status::InsufficientAccountBalance));
+
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(BOB, Hbar::new(-1)).hbar_transfer(ALICE, Hbar::new(2));
+
+        assert_eq!(state.dry_run(&tx), Err(Status::InvalidAccountAmounts));
+    }
+
+    #[test]
+    fn credit_doesnt_cover_overdraft() {
+        let state = snapshot();
+
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(BOB, Hbar::new(2))
+            .hbar_transfer(BOB, Hbar::new(-3))
+            .hbar_transfer(ALICE, Hbar::new(1));
+
+        assert_eq!(state.dry_run(&tx), Err(Status::InsufficientAccountBalance));
+    }
+
+    #[test]
+    fn associate_then_transfer_tokens() {
+        let mut state = snapshot();
+
+        let mut associate = TokenAssociateTransaction::new();
+        associate.account_id(BOB).token_ids([TOKEN]);
+
+        state.apply(&associate).unwrap();
+        assert_eq!(state.apply(&associate), Err(Status::TokenAlreadyAssociatedToAccount));
+
+        let mut tx = TransferTransaction::new();
+        tx.token_transfer(TOKEN, ALICE, -30).token_transfer(TOKEN, BOB, 30);
+
+        state.apply(&tx).unwrap();
+
+        assert_eq!(state.get_token_balance(ALICE, TOKEN), Some(70));
+        assert_eq!(state.get_token_balance(BOB, TOKEN), Some(30));
+
+        let mut dissociate = TokenDissociateTransaction::new();
+        dissociate.account_id(BOB).token_ids([TOKEN]);
+
+        assert_eq!(state.apply(&dissociate), Err(Status::TransactionRequiresZeroTokenBalances));
+    }
+
+    #[test]
+    fn approved_transfer() {
+        let mut state = snapshot();
+
+        let mut approve = AccountAllowanceApproveTransaction::new();
+        approve.approve_hbar_allowance(ALICE, TEST_TX_ID.account_id, Hbar::new(2));
+
+        // the spender isn't in the snapshot yet.
+        assert_eq!(state.apply(&approve), Err(Status::InvalidAccountId));
+
+        state.hbar_balance(TEST_TX_ID.account_id, Hbar::new(0));
+        state.apply(&approve).unwrap();
+
+        let mut tx = TransferTransaction::new();
+        tx.approved_hbar_transfer(ALICE, Hbar::new(-3))
+            .hbar_transfer(BOB, Hbar::new(3))
+            .transaction_id(TEST_TX_ID);
+
+        assert_eq!(state.apply(&tx), Err(Status::AmountExceedsAllowance));
+
+        let mut tx = TransferTransaction::new();
+        tx.approved_hbar_transfer(ALICE, Hbar::new(-2))
+            .hbar_transfer(BOB, Hbar::new(2))
+            .transaction_id(TEST_TX_ID);
+
+        state.apply(&tx).unwrap();
+
+        assert_eq!(state.get_hbar_balance(BOB), Some(Hbar::new(3)));
+        assert_eq!(state.apply(&tx), Err(Status::AmountExceedsAllowance));
+    }
+
+    #[test]
+    fn mint_transfer_burn_nfts() {
+        let mut state = snapshot();
+        state.token_balance(BOB, TOKEN, 0);
+
+        let mut mint = TokenMintTransaction::new();
+        mint.token_id(TOKEN).metadata([vec![1], vec![2]]);
+
+        state.apply(&mint).unwrap();
+
+        let nft = NftId { token_id: TOKEN, serial: 2 };
+        assert_eq!(state.get_nft_owner(nft), Some(ALICE));
+
+        let mut tx = TransferTransaction::new();
+        tx.nft_transfer(nft, ALICE, BOB);
+
+        state.apply(&tx).unwrap();
+        assert_eq!(state.get_nft_owner(nft), Some(BOB));
+
+        let mut burn = TokenBurnTransaction::new();
+        burn.token_id(TOKEN).serials([1, 2]);
+
+        assert_eq!(state.apply(&burn), Err(Status::TreasuryMustOwnBurnedNft));
+
+        burn.serials([1]);
+        state.apply(&burn).unwrap();
+
+        assert_eq!(state.get_nft_owner(NftId { token_id: TOKEN, serial: 1 }), None);
+    }
+
+    #[test]
+    fn overflowing_transfer_sums() {
+        let state = snapshot();
+
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(ALICE, Hbar::from_tinybars(i64::MAX))
+            .hbar_transfer(BOB, Hbar::from_tinybars(i64::MAX))
+            .hbar_transfer(TEST_TX_ID.account_id, Hbar::from_tinybars(2));
+
+        assert_eq!(state.dry_run(&tx), Err(Status::InvalidAccountAmounts));
+
+        let mut tx = TransferTransaction::new();
+        tx.token_transfer(TOKEN, ALICE, i64::MAX)
+            .token_transfer(TOKEN, BOB, i64::MAX)
+            .token_transfer(TOKEN, TEST_TX_ID.account_id, 2);
+
+        assert_eq!(state.dry_run(&tx), Err(Status::TransfersNotZeroSumForToken));
+    }
+
+    #[test]
+    fn burned_serials_are_not_reused() {
+        let mut state = snapshot();
+
+        let mut mint = TokenMintTransaction::new();
+        mint.token_id(TOKEN).metadata([vec![1], vec![2]]);
+
+        state.apply(&mint).unwrap();
+
+        let mut burn = TokenBurnTransaction::new();
+        burn.token_id(TOKEN).serials([2]);
+
+        state.apply(&burn).unwrap();
+
+        mint.metadata([vec![3]]);
+        state.apply(&mint).unwrap();
+
+        assert_eq!(state.get_nft_owner(NftId { token_id: TOKEN, serial: 2 }), None);
+        assert_eq!(state.get_nft_owner(NftId { token_id: TOKEN, serial: 3 }), Some(ALICE));
+    }
+
+    #[test]
+    fn nft_mint_checks_treasury_association() {
+        let mut state = snapshot();
+        state.freeze(ALICE, TOKEN);
+
+        let mut mint = TokenMintTransaction::new();
+        mint.token_id(TOKEN).metadata([vec![1]]);
+
+        assert_eq!(state.apply(&mint), Err(Status::AccountFrozenForToken));
+    }
+}
@@ -64,7 +64,7 @@ impl FromProtobuf<services::StakingInfo> for StakingInfo {
 
         Ok(Self {
             decline_staking_reward: pb.decline_reward,
-            stake_period_start: pb.stake_period_start.map(Into::into),
+            stake_period_start: Option::from_protobuf(pb.stake_period_start)?,
             pending_reward: Hbar::from_tinybars(pb.pending_reward),
             staked_to_me: Hbar::from_tinybars(pb.staked_to_me),
             staked_account_id,
@@ -0,0 +1,593 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use hedera_proto::services;
+use openssl::hash::MessageDigest;
+use openssl::pkey::PKey;
+use openssl::sign::Verifier;
+use prost::Message;
+use sha2::{
+    Digest,
+    Sha384,
+};
+
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::{
+    AccountId,
+    Error,
+    NodeAddress,
+    NodeAddressBook,
+    TransactionId,
+    TransactionReceipt,
+    TransactionRecord,
+};
+
+/// The class ID of a hash in an object stream.
+const HASH_CLASS_ID: u64 = 0xf422_da83_a251_741e;
+
+/// The class ID of a signature in an object stream.
+const SIGNATURE_CLASS_ID: u64 = 0x13dc_4b39_9b24_5c69;
+
+/// The class ID of a transaction and its record in a record stream.
+const RECORD_STREAM_OBJECT_CLASS_ID: u64 = 0xe370_929b_a542_9d8b;
+
+/// The digest type of a SHA-384 hash in an object stream.
+const SHA384_DIGEST_TYPE: i32 = 0x58ff_811b;
+
+/// The signature type of an RSA signature over a SHA-384 digest in an object stream.
+const SHA384_WITH_RSA_SIGNATURE_TYPE: i32 = 1;
+
+/// The only record file (and signature file) version that can be verified.
+const SUPPORTED_RECORD_FILE_VERSION: u8 = 5;
+
+/// Cryptographic evidence that a transaction reached consensus,
+/// in the form of the record file that contains it, signed by the nodes of the network.
+///
+/// Rather than trusting the node (or mirror node) that returned a record or receipt,
+/// [`verify_record`](Self::verify_record) and [`verify_receipt`](Self::verify_receipt) check that it's
+/// in a record file signed by at least a third of the nodes in the address book.
+///
+/// This is alpha: only version 5 record files can be verified, and every node counts the same (stake isn't considered).
+/// The address book isn't verified either, it must come from a trusted source.
+#[derive(Debug, Clone)]
+pub struct StateProof {
+    record_file: Vec<u8>,
+    signature_files: HashMap<AccountId, Vec<u8>>,
+    address_book: NodeAddressBook,
+}
+
+impl StateProof {
+    /// Create a state proof from the bytes of a record file, the signature files for it (by node account ID),
+    /// and the address book of the network at the time.
+    #[must_use]
+    pub fn new(
+        record_file: Vec<u8>,
+        signature_files: HashMap<AccountId, Vec<u8>>,
+        address_book: NodeAddressBook,
+    ) -> Self {
+        Self { record_file, signature_files, address_book }
+    }
+
+    /// Returns the bytes of the record file.
+    #[must_use]
+    pub fn get_record_file(&self) -> &[u8] {
+        &self.record_file
+    }
+
+    /// Returns the bytes of the signature file of each node that signed the record file.
+    #[must_use]
+    pub fn get_signature_files(&self) -> &HashMap<AccountId, Vec<u8>> {
+        &self.signature_files
+    }
+
+    /// Returns the address book the signatures are checked against.
+    #[must_use]
+    pub fn get_address_book(&self) -> &NodeAddressBook {
+        &self.address_book
+    }
+
+    /// Returns `Ok(())` if `record` is in the record file, and the record file is signed by at least a third of the nodes.
+    ///
+    /// The transaction ID, hash, consensus timestamp, memo, fee, receipt,
+    /// hbar, token, and NFT transfers, automatic token associations, and assessed custom fees of `record` are checked.
+    /// Anything else (such as the contract function result, or the children of `record`) isn't.
+    ///
+    /// # Errors
+    /// - [`Error::StateProof`] if the record file isn't signed by enough nodes, can't be parsed,
+    ///   doesn't contain the transaction, or contains a different record for it.
+    /// - [`Error::FromProtobuf`] if the record in the record file can't be decoded.
+    pub fn verify_record(&self, record: &TransactionRecord) -> crate::Result<()> {
+        let actual = self.verify(&record.transaction_id)?;
+        let expected = record.to_protobuf();
+
+        let matches = expected.transaction_hash == actual.transaction_hash
+            && expected.consensus_timestamp == actual.consensus_timestamp
+            && expected.memo == actual.memo
+            && expected.transaction_fee == actual.transaction_fee
+            && receipt_matches(expected.receipt.as_ref(), actual.receipt.as_ref());
+
+        // transfers are compared decoded, since the order of token transfers isn't preserved by `to_protobuf`.
+        let matches = matches && {
+            let actual: TransactionRecord = FromProtobuf::from_protobuf(actual)?;
+
+            record.transfers == actual.transfers
+                && record.token_transfers == actual.token_transfers
+                && record.token_nft_transfers == actual.token_nft_transfers
+                && record.automatic_token_associations == actual.automatic_token_associations
+                && record.assessed_custom_fees == actual.assessed_custom_fees
+        };
+
+        if !matches {
+            return Err(Error::state_proof(format!(
+                "record of transaction `{}` doesn't match the record file",
+                record.transaction_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Returns `Ok(())` if `receipt` is the receipt of `transaction_id` in the record file,
+    /// and the record file is signed by at least a third of the nodes.
+    ///
+    /// The status and the entity IDs, topic sequence number and running hash, total supply, and serial numbers of `receipt` are checked.
+    ///
+    /// # Errors
+    /// - [`Error::StateProof`] if the record file isn't signed by enough nodes, can't be parsed,
+    ///   doesn't contain the transaction, or contains a different receipt for it.
+    pub fn verify_receipt(
+        &self,
+        transaction_id: &TransactionId,
+        receipt: &TransactionReceipt,
+    ) -> crate::Result<()> {
+        let actual = self.verify(transaction_id)?;
+
+        if !receipt_matches(Some(&receipt.to_protobuf()), actual.receipt.as_ref()) {
+            return Err(Error::state_proof(format!(
+                "receipt of transaction `{transaction_id}` doesn't match the record file"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Checks the signatures on the record file, and returns the record of `transaction_id` in it.
+    fn verify(&self, transaction_id: &TransactionId) -> crate::Result<services::TransactionRecord> {
+        let file_hash = Sha384::digest(&self.record_file);
+
+        let nodes = self.address_book.node_addresses.len();
+        let signed = self
+            .address_book
+            .node_addresses
+            .iter()
+            .filter(|node| self.is_signed_by(node, &file_hash))
+            .count();
+
+        // at least 1/3 of the nodes must have signed the record file.
+        if nodes == 0 || signed * 3 < nodes {
+            return Err(Error::state_proof(format!(
+                "record file is signed by {signed} of {nodes} nodes, at least a third must sign it"
+            )));
+        }
+
+        let transaction_id_pb = transaction_id.to_protobuf();
+
+        parse_record_file(&self.record_file)?
+            .into_iter()
+            .find(|it| it.transaction_id.as_ref() == Some(&transaction_id_pb))
+            .ok_or_else(|| {
+                Error::state_proof(format!(
+                    "transaction `{transaction_id}` isn't in the record file"
+                ))
+            })
+    }
+
+    fn is_signed_by(&self, node: &NodeAddress, file_hash: &[u8]) -> bool {
+        let Some(signature_file) = self.signature_files.get(&node.node_account_id) else {
+            return false;
+        };
+
+        let Ok((hash, signature)) = parse_signature_file(signature_file) else {
+            return false;
+        };
+
+        hash == file_hash && verify_rsa_signature(&node.rsa_public_key, hash, signature)
+    }
+}
+
+fn receipt_matches(
+    expected: Option<&services::TransactionReceipt>,
+    actual: Option<&services::TransactionReceipt>,
+) -> bool {
+    let (Some(expected), Some(actual)) = (expected, actual) else {
+        return expected.is_none() && actual.is_none();
+    };
+
+    expected.status == actual.status
+        && expected.account_id == actual.account_id
+        && expected.file_id == actual.file_id
+        && expected.contract_id == actual.contract_id
+        && expected.topic_id == actual.topic_id
+        && expected.topic_sequence_number == actual.topic_sequence_number
+        && expected.topic_running_hash == actual.topic_running_hash
+        && expected.token_id == actual.token_id
+        && expected.new_total_supply == actual.new_total_supply
+        && expected.schedule_id == actual.schedule_id
+        && expected.serial_numbers == actual.serial_numbers
+}
+
+/// Verifies an RSA signature over the SHA-384 digest of `message`, `public_key` is a DER encoded `SubjectPublicKeyInfo`.
+fn verify_rsa_signature(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let Ok(public_key) = PKey::public_key_from_der(public_key) else {
+        return false;
+    };
+
+    let Ok(mut verifier) = Verifier::new(MessageDigest::sha384(), &public_key) else {
+        return false;
+    };
+
+    verifier.update(message).and_then(|()| verifier.verify(signature)).unwrap_or(false)
+}
+
+/// Parses a version 5 record file, returning the records it contains.
+fn parse_record_file(bytes: &[u8]) -> crate::Result<Vec<services::TransactionRecord>> {
+    let mut reader = StreamReader(bytes);
+
+    let version = reader.read_i32()?;
+    if version != i32::from(SUPPORTED_RECORD_FILE_VERSION) {
+        return Err(Error::state_proof(format!(
+            "unsupported record file version {version}, only version {SUPPORTED_RECORD_FILE_VERSION} can be verified"
+        )));
+    }
+
+    // HAPI major, minor, and patch versions.
+    reader.read_bytes(12)?;
+    // object stream version.
+    reader.read_i32()?;
+    // start running hash.
+    reader.read_hash()?;
+
+    let mut records = Vec::new();
+
+    loop {
+        match reader.read_u64()? {
+            RECORD_STREAM_OBJECT_CLASS_ID => {
+                // class version.
+                reader.read_i32()?;
+
+                let record = reader.read_length_prefixed()?;
+                // transaction.
+                reader.read_length_prefixed()?;
+
+                records.push(
+                    services::TransactionRecord::decode(record).map_err(Error::from_protobuf)?,
+                );
+            }
+            // end running hash.
+            HASH_CLASS_ID => return Ok(records),
+            class_id => {
+                return Err(Error::state_proof(format!(
+                    "unexpected object with class ID {class_id:#x} in record file"
+                )))
+            }
+        }
+    }
+}
+
+/// Parses a version 5 signature file, returning the signed file hash and its signature.
+fn parse_signature_file(bytes: &[u8]) -> crate::Result<(&[u8], &[u8])> {
+    let mut reader = StreamReader(bytes);
+
+    let version = reader.read_bytes(1)?[0];
+    if version != SUPPORTED_RECORD_FILE_VERSION {
+        return Err(Error::state_proof(format!("unsupported signature file version {version}")));
+    }
+
+    // object stream signature version.
+    reader.read_i32()?;
+
+    let file_hash = reader.read_hash()?;
+    let signature = reader.read_signature()?;
+
+    Ok((file_hash, signature))
+}
+
+/// Reads the big-endian, length-prefixed values of an object stream.
+struct StreamReader<'a>(&'a [u8]);
+
+impl<'a> StreamReader<'a> {
+    fn read_bytes(&mut self, len: usize) -> crate::Result<&'a [u8]> {
+        if self.0.len() < len {
+            return Err(Error::state_proof("unexpected end of stream file"));
+        }
+
+        let (head, tail) = self.0.split_at(len);
+        self.0 = tail;
+
+        Ok(head)
+    }
+
+    fn read_i32(&mut self) -> crate::Result<i32> {
+        Ok(i32::from_be_bytes(self.read_bytes(4)?.try_into().unwrap()))
+    }
+
+    fn read_u64(&mut self) -> crate::Result<u64> {
+        Ok(u64::from_be_bytes(self.read_bytes(8)?.try_into().unwrap()))
+    }
+
+    fn read_length_prefixed(&mut self) -> crate::Result<&'a [u8]> {
+        let len = self.read_i32()?;
+        let len = usize::try_from(len)
+            .map_err(|_| Error::state_proof(format!("invalid length {len} in stream file")))?;
+
+        self.read_bytes(len)
+    }
+
+    fn read_hash(&mut self) -> crate::Result<&'a [u8]> {
+        if self.read_u64()? != HASH_CLASS_ID {
+            return Err(Error::state_proof("expected a hash in stream file"));
+        }
+
+        // class version.
+        self.read_i32()?;
+
+        if self.read_i32()? != SHA384_DIGEST_TYPE {
+            return Err(Error::state_proof("expected a SHA-384 hash in stream file"));
+        }
+
+        let hash = self.read_length_prefixed()?;
+
+        if hash.len() != Sha384::output_size() {
+            return Err(Error::state_proof("invalid SHA-384 hash length in stream file"));
+        }
+
+        Ok(hash)
+    }
+
+    fn read_signature(&mut self) -> crate::Result<&'a [u8]> {
+        if self.read_u64()? != SIGNATURE_CLASS_ID {
+            return Err(Error::state_proof("expected a signature in stream file"));
+        }
+
+        // class version.
+        self.read_i32()?;
+
+        if self.read_i32()? != SHA384_WITH_RSA_SIGNATURE_TYPE {
+            return Err(Error::state_proof("expected a SHA384withRSA signature in stream file"));
+        }
+
+        let len = self.read_i32()?;
+        let checksum = self.read_i32()?;
+
+        if checksum != 101 - len {
+            return Err(Error::state_proof("invalid signature checksum in stream file"));
+        }
+
+        let len = usize::try_from(len)
+            .map_err(|_| Error::state_proof(format!("invalid length {len} in stream file")))?;
+
+        self.read_bytes(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use assert_matches::assert_matches;
+    use hedera_proto::services;
+    use openssl::hash::MessageDigest;
+    use openssl::pkey::{
+        PKey,
+        Private,
+    };
+    use openssl::rsa::Rsa;
+    use openssl::sign::Signer;
+    use prost::Message;
+    use sha2::{
+        Digest,
+        Sha384,
+    };
+
+    use super::{
+        StateProof,
+        HASH_CLASS_ID,
+        RECORD_STREAM_OBJECT_CLASS_ID,
+        SHA384_DIGEST_TYPE,
+        SIGNATURE_CLASS_ID,
+    };
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::{
+        AccountId,
+        Error,
+        Hbar,
+        NodeAddress,
+        NodeAddressBook,
+        Status,
+        TokenId,
+        TransactionId,
+        TransactionReceipt,
+        TransactionRecord,
+    };
+
+    fn transaction_id() -> TransactionId {
+        "0.0.5005@1554158542.000000007".parse().unwrap()
+    }
+
+    fn write_hash(out: &mut Vec<u8>, hash: &[u8]) {
+        out.extend(HASH_CLASS_ID.to_be_bytes());
+        out.extend(1_i32.to_be_bytes());
+        out.extend(SHA384_DIGEST_TYPE.to_be_bytes());
+        out.extend((hash.len() as i32).to_be_bytes());
+        out.extend(hash);
+    }
+
+    fn record(status: Status) -> services::TransactionRecord {
+        let transfer = |num, amount| services::AccountAmount {
+            account_id: Some(AccountId::new(0, 0, num).to_protobuf()),
+            amount,
+            is_approval: false,
+        };
+
+        services::TransactionRecord {
+            receipt: Some(services::TransactionReceipt {
+                status: status as i32,
+                ..Default::default()
+            }),
+            consensus_timestamp: Some(services::Timestamp { seconds: 1554158543, nanos: 0 }),
+            transaction_id: Some(transaction_id().to_protobuf()),
+            transfer_list: Some(services::TransferList {
+                account_amounts: vec![transfer(5005, -100), transfer(3, 100)],
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn record_file(status: Status) -> Vec<u8> {
+        let record = record(status).encode_to_vec();
+
+        let mut out = Vec::new();
+        out.extend(5_i32.to_be_bytes());
+        out.extend([0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0]);
+        out.extend(1_i32.to_be_bytes());
+        write_hash(&mut out, &[0; 48]);
+
+        out.extend(RECORD_STREAM_OBJECT_CLASS_ID.to_be_bytes());
+        out.extend(1_i32.to_be_bytes());
+        out.extend((record.len() as i32).to_be_bytes());
+        out.extend(&record);
+        out.extend(0_i32.to_be_bytes());
+
+        write_hash(&mut out, &[1; 48]);
+
+        out
+    }
+
+    fn signature_file(key: &PKey<Private>, record_file: &[u8]) -> Vec<u8> {
+        let hash = Sha384::digest(record_file);
+        let mut signer = Signer::new(MessageDigest::sha384(), key).unwrap();
+        signer.update(&hash).unwrap();
+        let signature = signer.sign_to_vec().unwrap();
+
+        let mut out = Vec::from([5]);
+        out.extend(1_i32.to_be_bytes());
+        write_hash(&mut out, &hash);
+
+        out.extend(SIGNATURE_CLASS_ID.to_be_bytes());
+        out.extend(1_i32.to_be_bytes());
+        out.extend(1_i32.to_be_bytes());
+        out.extend((signature.len() as i32).to_be_bytes());
+        out.extend((101 - signature.len() as i32).to_be_bytes());
+        out.extend(signature);
+
+        out
+    }
+
+    fn node(num: u64, key: &PKey<Private>) -> NodeAddress {
+        NodeAddress {
+            node_id: num - 3,
+            rsa_public_key: key.public_key_to_der().unwrap(),
+            node_account_id: AccountId::new(0, 0, num),
+            tls_certificate_hash: Vec::new(),
+            service_endpoints: Vec::new(),
+            description: String::new(),
+        }
+    }
+
+    /// A state proof for a network of 3 nodes, where `signers` of them signed the record file.
+    fn state_proof(signers: usize) -> StateProof {
+        let keys: Vec<_> =
+            (0..3).map(|_| PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap()).collect();
+
+        let record_file = record_file(Status::Success);
+
+        let signature_files = keys
+            .iter()
+            .zip(3..)
+            .take(signers)
+            .map(|(key, num)| (AccountId::new(0, 0, num), signature_file(key, &record_file)))
+            .collect();
+
+        let address_book = NodeAddressBook {
+            node_addresses: keys.iter().zip(3..).map(|(key, num)| node(num, key)).collect(),
+        };
+
+        StateProof::new(record_file, signature_files, address_book)
+    }
+
+    fn receipt(status: Status) -> TransactionReceipt {
+        TransactionReceipt::from_protobuf(services::TransactionReceipt {
+            status: status as i32,
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn verify_receipt() {
+        let proof = state_proof(1);
+
+        proof.verify_receipt(&transaction_id(), &receipt(Status::Success)).unwrap();
+
+        assert_matches!(
+            proof.verify_receipt(&transaction_id(), &receipt(Status::InvalidSignature)),
+            Err(Error::StateProof(_))
+        );
+
+        let other_transaction_id = "0.0.5006@1554158542.000000007".parse().unwrap();
+
+        assert_matches!(
+            proof.verify_receipt(&other_transaction_id, &receipt(Status::Success)),
+            Err(Error::StateProof(_))
+        );
+    }
+
+    #[test]
+    fn verify_record() {
+        let proof = state_proof(1);
+        let record =
+            TransactionRecord::from_bytes(&record(Status::Success).encode_to_vec()).unwrap();
+
+        proof.verify_record(&record).unwrap();
+
+        let mut tampered = record.clone();
+        tampered.transfers[1].amount = Hbar::from_tinybars(1000);
+
+        assert_matches!(proof.verify_record(&tampered), Err(Error::StateProof(_)));
+
+        let mut tampered = record;
+        tampered
+            .token_transfers
+            .insert(TokenId::new(0, 0, 1234), [(AccountId::new(0, 0, 3), 100)].into());
+
+        assert_matches!(proof.verify_record(&tampered), Err(Error::StateProof(_)));
+    }
+
+    #[test]
+    fn verify_not_enough_signatures() {
+        let proof = state_proof(0);
+
+        assert_matches!(
+            proof.verify_receipt(&transaction_id(), &receipt(Status::Success)),
+            Err(Error::StateProof(_))
+        );
+    }
+
+    #[test]
+    fn verify_tampered_record_file() {
+        let mut proof = state_proof(3);
+        proof.record_file = record_file(Status::InvalidSignature);
+
+        assert_matches!(
+            proof.verify_receipt(&transaction_id(), &receipt(Status::InvalidSignature)),
+            Err(Error::StateProof(_))
+        );
+    }
+}
@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use base64::Engine;
+
+use crate::mirror_rest::format_transaction_id;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    NodeAddressBook,
+    StateProof,
+    TransactionId,
+};
+
+/// Request the [`StateProof`] of a transaction from the mirror node REST API.
+///
+/// Mirror nodes only serve state proofs where they kept the record and signature files,
+/// and only version 5 record files can be verified.
+#[derive(Debug, Clone, Default)]
+pub struct StateProofQuery {
+    transaction_id: Option<TransactionId>,
+}
+
+impl StateProofQuery {
+    /// Create a new query.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the ID of the transaction to get the state proof of.
+    #[must_use]
+    pub fn get_transaction_id(&self) -> Option<TransactionId> {
+        self.transaction_id
+    }
+
+    /// Sets the ID of the transaction to get the state proof of.
+    pub fn transaction_id(&mut self, transaction_id: TransactionId) -> &mut Self {
+        self.transaction_id = Some(transaction_id);
+        self
+    }
+
+    /// Execute this query against the mirror node of the provided client.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `transaction_id` isn't set, or the mirror node returns a malformed state proof.
+    /// - [`Error::FromProtobuf`] if the address book can't be decoded.
+    /// - [`Error::MirrorRest`] if the mirror node request fails, IE, it has no state proof for the transaction.
+    pub async fn execute(&self, client: &Client) -> crate::Result<StateProof> {
+        let path = self.path()?;
+
+        let rest = client.rest_client();
+        let proof: RestStateProof = rest.get(&path).await?;
+
+        proof.into_state_proof()
+    }
+
+    fn path(&self) -> crate::Result<String> {
+        let id = self.transaction_id.ok_or_else(|| {
+            Error::basic_parse("`transaction_id` must be set to get a state proof")
+        })?;
+
+        let mut path = format!("/transactions/{}/stateproof", format_transaction_id(&id));
+
+        let mut params = Vec::new();
+
+        if let Some(nonce) = id.nonce {
+            params.push(format!("nonce={nonce}"));
+        }
+
+        if id.scheduled {
+            params.push("scheduled=true".to_owned());
+        }
+
+        if !params.is_empty() {
+            path.push('?');
+            path.push_str(&params.join("&"));
+        }
+
+        Ok(path)
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestStateProof {
+    // only a string for version 2 and 5 record files.
+    record_file: serde_json::Value,
+    signature_files: HashMap<String, String>,
+    address_books: Vec<String>,
+}
+
+impl RestStateProof {
+    fn into_state_proof(self) -> crate::Result<StateProof> {
+        let decode = |it: &str| {
+            base64::engine::general_purpose::STANDARD.decode(it).map_err(Error::basic_parse)
+        };
+
+        let serde_json::Value::String(record_file) = &self.record_file else {
+            return Err(Error::basic_parse(
+                "unsupported record file in state proof, only version 5 record files can be verified",
+            ));
+        };
+
+        let record_file = decode(record_file)?;
+
+        let signature_files = self
+            .signature_files
+            .iter()
+            .map(|(account_id, file)| Ok((AccountId::from_str(account_id)?, decode(file)?)))
+            .collect::<crate::Result<_>>()?;
+
+        let address_books = self
+            .address_books
+            .iter()
+            .map(String::as_str)
+            .map(decode)
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        if address_books.is_empty() {
+            return Err(Error::basic_parse("state proof has no address book"));
+        }
+
+        // the address book is split across the chunks of the file it was written with,
+        // a chunk on its own may still parse, but as a partial (and wrong) address book.
+        let address_book = NodeAddressBook::from_bytes(&address_books.concat())?;
+
+        Ok(StateProof::new(record_file, signature_files, address_book))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+
+    use super::{
+        RestStateProof,
+        StateProofQuery,
+    };
+    use crate::{
+        AccountId,
+        NodeAddress,
+        NodeAddressBook,
+        TransactionId,
+    };
+
+    fn node(node_id: u64) -> NodeAddress {
+        NodeAddress {
+            node_id,
+            rsa_public_key: Vec::new(),
+            node_account_id: AccountId::new(0, 0, node_id + 3),
+            tls_certificate_hash: Vec::new(),
+            service_endpoints: Vec::new(),
+            description: String::new(),
+        }
+    }
+
+    #[test]
+    fn path() {
+        let mut query = StateProofQuery::new();
+
+        assert!(query.path().is_err());
+
+        let mut id: TransactionId = "0.0.5005@1554158542.000000007".parse().unwrap();
+        query.transaction_id(id);
+
+        assert_eq!(query.path().unwrap(), "/transactions/0.0.5005-1554158542-000000007/stateproof");
+
+        id.nonce = Some(1);
+        id.scheduled = true;
+        query.transaction_id(id);
+
+        assert_eq!(
+            query.path().unwrap(),
+            "/transactions/0.0.5005-1554158542-000000007/stateproof?nonce=1&scheduled=true"
+        );
+    }
+
+    #[test]
+    fn address_book_chunks_are_concatenated() {
+        let first = NodeAddressBook { node_addresses: vec![node(0)] }.to_bytes();
+        let book = NodeAddressBook { node_addresses: vec![node(0), node(1)] }.to_bytes();
+
+        // the first chunk is a valid address book on its own, but only has the first node.
+        let (head, tail) = book.split_at(first.len());
+        assert_eq!(head, first);
+
+        let encode = |it: &[u8]| base64::engine::general_purpose::STANDARD.encode(it);
+
+        let proof = RestStateProof {
+            record_file: serde_json::Value::String(String::new()),
+            signature_files: Default::default(),
+            address_books: vec![encode(head), encode(tail)],
+        }
+        .into_state_proof()
+        .unwrap();
+
+        let node_ids: Vec<_> =
+            proof.get_address_book().node_addresses.iter().map(|it| it.node_id).collect();
+        assert_eq!(node_ids, [0, 1]);
+    }
+}
@@ -7,12 +7,15 @@ use tonic::transport::Channel;
 
 use crate::protobuf::FromProtobuf;
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -89,7 +92,41 @@ impl FreezeTransaction {
     }
 }
 
-impl TransactionData for FreezeTransactionData {}
+impl TransactionData for FreezeTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        let (start_time, upgrade_file) = match self.freeze_type {
+            FreezeType::Unknown => {
+                problems.push(ValidationProblem::MissingField { field: "freeze_type" });
+                return;
+            }
+            FreezeType::FreezeOnly => (true, false),
+            FreezeType::PrepareUpgrade => (false, true),
+            FreezeType::FreezeUpgrade | FreezeType::TelemetryUpgrade => (true, true),
+            FreezeType::FreezeAbort => (false, false),
+        };
+
+        if start_time {
+            check_required(problems, "start_time", &self.start_time);
+        }
+
+        if upgrade_file {
+            check_required(problems, "file_id", &self.file_id);
+            check_required(problems, "file_hash", &self.file_hash);
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("file", self.file_id.as_ref())
+            .field(
+                "freeze_type",
+                services::FreezeType::try_from(self.freeze_type as i32)
+                    .unwrap_or_default()
+                    .as_str_name(),
+            )
+            .optional_field("start_time", self.start_time.as_ref());
+    }
+}
 
 impl TransactionExecute for FreezeTransactionData {
     fn execute(
@@ -135,7 +172,7 @@ impl From<FreezeTransactionData> for AnyTransactionData {
 impl FromProtobuf<services::FreezeTransactionBody> for FreezeTransactionData {
     fn from_protobuf(pb: services::FreezeTransactionBody) -> crate::Result<Self> {
         Ok(Self {
-            start_time: pb.start_time.map(Into::into),
+            start_time: Option::from_protobuf(pb.start_time)?,
             file_id: Option::from_protobuf(pb.update_file)?,
             file_hash: Some(pb.file_hash),
             freeze_type: FreezeType::from(pb.freeze_type),
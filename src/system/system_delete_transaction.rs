@@ -11,12 +11,15 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -92,7 +95,21 @@ impl SystemDeleteTransaction {
     }
 }
 
-impl TransactionData for SystemDeleteTransactionData {}
+impl TransactionData for SystemDeleteTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        if self.file_id.is_none() && self.contract_id.is_none() {
+            problems.push(ValidationProblem::MissingField { field: "file_id" });
+        }
+
+        check_required(problems, "expiration_time", &self.expiration_time);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("file", self.file_id.as_ref())
+            .optional_entity("contract", self.contract_id.as_ref());
+    }
+}
 
 impl TransactionExecute for SystemDeleteTransactionData {
     #[allow(deprecated)]
@@ -152,7 +169,11 @@ impl FromProtobuf<services::SystemDeleteTransactionBody> for SystemDeleteTransac
             None => (None, None),
         };
 
-        Ok(Self { file_id, contract_id, expiration_time: pb.expiration_time.map(Into::into) })
+        Ok(Self {
+            file_id,
+            contract_id,
+            expiration_time: Option::from_protobuf(pb.expiration_time)?,
+        })
     }
 }
 
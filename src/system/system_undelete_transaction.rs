@@ -15,7 +15,9 @@ use crate::transaction::{
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -66,7 +68,19 @@ impl SystemUndeleteTransaction {
     }
 }
 
-impl TransactionData for SystemUndeleteTransactionData {}
+impl TransactionData for SystemUndeleteTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        if self.file_id.is_none() && self.contract_id.is_none() {
+            problems.push(ValidationProblem::MissingField { field: "file_id" });
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("file", self.file_id.as_ref())
+            .optional_entity("contract", self.contract_id.as_ref());
+    }
+}
 
 impl TransactionExecute for SystemUndeleteTransactionData {
     #[allow(deprecated)]
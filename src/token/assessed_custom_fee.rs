@@ -11,17 +11,22 @@ use crate::{
 
 /// A custom transfer fee that was assessed during the handling of a `CryptoTransfer`.
 #[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct AssessedCustomFee {
     /// The amount of currency charged to each payer.
     pub amount: i64,
 
     /// The currency `amount` is charged in, if `None` the fee is in HBar.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub token_id: Option<TokenId>,
 
     /// The account that receives the fees that were charged.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub fee_collector_account_id: Option<AccountId>,
 
     /// A list of all accounts that were charged this fee.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::vec_display"))]
     pub payer_account_id_list: Vec<AccountId>,
 }
 
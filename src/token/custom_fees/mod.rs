@@ -3,8 +3,10 @@
 use fraction::Fraction;
 use hedera_proto::services;
 
+use crate::protobuf::FromProtobufContext;
 use crate::{
     AccountId,
+    Error,
     FromProtobuf,
     Hbar,
     ToProtobuf,
@@ -268,9 +270,15 @@ pub struct FractionalFeeData {
 impl FromProtobuf<services::FractionalFee> for FractionalFeeData {
     fn from_protobuf(pb: services::FractionalFee) -> crate::Result<Self> {
         let amount = pb.fractional_amount.map(Fraction::from).unwrap_or_default();
+
+        // a zero denominator makes the fraction infinite (or NaN), which has no numerator/denominator.
+        let (Some(&numerator), Some(&denominator)) = (amount.numer(), amount.denom()) else {
+            return Err(Error::from_protobuf("denominator is zero")).in_field("fractional_amount");
+        };
+
         Ok(Self {
-            denominator: *amount.denom().unwrap(),
-            numerator: *amount.numer().unwrap(),
+            denominator,
+            numerator,
             assessment_method: match pb.net_of_transfers {
                 true => FeeAssessmentMethod::Exclusive,
                 false => FeeAssessmentMethod::Inclusive,
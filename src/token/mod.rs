@@ -3,36 +3,66 @@
 mod assessed_custom_fee;
 mod custom_fees;
 mod nft_id;
+#[cfg(all(feature = "tokens", feature = "mirror-rest"))]
+mod token_admin_report;
+#[cfg(feature = "tokens")]
 mod token_airdrop_transaction;
+mod token_amount;
+#[cfg(feature = "tokens")]
 mod token_associate_transaction;
 mod token_association;
+#[cfg(feature = "tokens")]
 mod token_burn_transaction;
+#[cfg(feature = "tokens")]
 mod token_cancel_airdrop_transaction;
+#[cfg(feature = "tokens")]
 mod token_claim_airdrop_transaction;
+#[cfg(feature = "tokens")]
 mod token_create_transaction;
+#[cfg(feature = "tokens")]
 mod token_delete_transaction;
+#[cfg(feature = "tokens")]
 mod token_dissociate_transaction;
+#[cfg(feature = "tokens")]
 mod token_fee_schedule_update_transaction;
+#[cfg(feature = "tokens")]
 mod token_freeze_transaction;
+#[cfg(feature = "tokens")]
 mod token_grant_kyc_transaction;
 mod token_id;
+#[cfg(feature = "tokens")]
 mod token_info;
+#[cfg(feature = "tokens")]
 mod token_info_query;
 mod token_key_validation_type;
+#[cfg(feature = "tokens")]
 mod token_mint_transaction;
+#[cfg(feature = "tokens")]
 mod token_nft_info;
+#[cfg(all(feature = "tokens", feature = "mirror-rest"))]
+mod token_nft_info_batch_query;
+#[cfg(feature = "tokens")]
 mod token_nft_info_query;
 mod token_nft_transfer;
+#[cfg(feature = "tokens")]
 mod token_pause_transaction;
+#[cfg(feature = "tokens")]
 mod token_reject_flow;
+#[cfg(feature = "tokens")]
 mod token_reject_transaction;
+#[cfg(feature = "tokens")]
 mod token_revoke_kyc_transaction;
 mod token_supply_type;
 mod token_type;
+#[cfg(feature = "tokens")]
 mod token_unfreeze_transaction;
+#[cfg(feature = "tokens")]
 mod token_unpause_transaction;
+#[cfg(feature = "tokens")]
 mod token_update_nfts_transaction;
+#[cfg(feature = "tokens")]
 mod token_update_transaction;
+#[cfg(feature = "tokens")]
 mod token_wipe_transaction;
 
 pub use assessed_custom_fee::AssessedCustomFee;
@@ -49,99 +79,137 @@ pub use custom_fees::{
     RoyaltyFeeData,
 };
 pub use nft_id::NftId;
+#[cfg(all(feature = "tokens", feature = "mirror-rest"))]
+pub use token_admin_report::{
+    TokenAccountState,
+    TokenAdminAction,
+    TokenAdminApplyReport,
+    TokenAdminOutcome,
+    TokenAdminReport,
+    TokenAdminState,
+    TokenAdminTarget,
+};
+#[cfg(feature = "tokens")]
 pub use token_airdrop_transaction::{
     TokenAirdropTransaction,
     TokenAirdropTransactionData,
 };
+pub use token_amount::TokenAmount;
+#[cfg(feature = "tokens")]
 pub use token_associate_transaction::{
     TokenAssociateTransaction,
     TokenAssociateTransactionData,
 };
 pub use token_association::TokenAssociation;
+#[cfg(feature = "tokens")]
 pub use token_burn_transaction::{
     TokenBurnTransaction,
     TokenBurnTransactionData,
 };
+#[cfg(feature = "tokens")]
 pub use token_cancel_airdrop_transaction::{
     TokenCancelAirdropTransaction,
     TokenCancelAirdropTransactionData,
 };
+#[cfg(feature = "tokens")]
 pub use token_claim_airdrop_transaction::{
     TokenClaimAirdropTransaction,
     TokenClaimAirdropTransactionData,
 };
+#[cfg(feature = "tokens")]
 pub use token_create_transaction::{
     TokenCreateTransaction,
     TokenCreateTransactionData,
 };
+#[cfg(feature = "tokens")]
 pub use token_delete_transaction::{
     TokenDeleteTransaction,
     TokenDeleteTransactionData,
 };
+#[cfg(feature = "tokens")]
 pub use token_dissociate_transaction::{
     TokenDissociateTransaction,
     TokenDissociateTransactionData,
 };
+#[cfg(feature = "tokens")]
 pub use token_fee_schedule_update_transaction::{
     TokenFeeScheduleUpdateTransaction,
     TokenFeeScheduleUpdateTransactionData,
 };
+#[cfg(feature = "tokens")]
 pub use token_freeze_transaction::{
     TokenFreezeTransaction,
     TokenFreezeTransactionData,
 };
+#[cfg(feature = "tokens")]
 pub use token_grant_kyc_transaction::{
     TokenGrantKycTransaction,
     TokenGrantKycTransactionData,
 };
 pub use token_id::TokenId;
+#[cfg(feature = "tokens")]
 pub use token_info::TokenInfo;
+#[cfg(feature = "tokens")]
 pub use token_info_query::{
     TokenInfoQuery,
     TokenInfoQueryData,
 };
 pub use token_key_validation_type::TokenKeyValidation;
+#[cfg(feature = "tokens")]
 pub use token_mint_transaction::{
     TokenMintTransaction,
     TokenMintTransactionData,
 };
+#[cfg(feature = "tokens")]
 pub use token_nft_info::TokenNftInfo;
+#[cfg(all(feature = "tokens", feature = "mirror-rest"))]
+pub use token_nft_info_batch_query::TokenNftInfoBatchQuery;
+#[cfg(feature = "tokens")]
 pub use token_nft_info_query::{
     TokenNftInfoQuery,
     TokenNftInfoQueryData,
 };
 pub use token_nft_transfer::TokenNftTransfer;
+#[cfg(feature = "tokens")]
 pub use token_pause_transaction::{
     TokenPauseTransaction,
     TokenPauseTransactionData,
 };
+#[cfg(feature = "tokens")]
 pub use token_reject_flow::TokenRejectFlow;
+#[cfg(feature = "tokens")]
 pub use token_reject_transaction::{
     TokenRejectTransaction,
     TokenRejectTransactionData,
 };
+#[cfg(feature = "tokens")]
 pub use token_revoke_kyc_transaction::{
     TokenRevokeKycTransaction,
     TokenRevokeKycTransactionData,
 };
 pub use token_supply_type::TokenSupplyType;
 pub use token_type::TokenType;
+#[cfg(feature = "tokens")]
 pub use token_unfreeze_transaction::{
     TokenUnfreezeTransaction,
     TokenUnfreezeTransactionData,
 };
+#[cfg(feature = "tokens")]
 pub use token_unpause_transaction::{
     TokenUnpauseTransaction,
     TokenUnpauseTransactionData,
 };
+#[cfg(feature = "tokens")]
 pub use token_update_nfts_transaction::{
     TokenUpdateNftsTransaction,
     TokenUpdateNftsTransactionData,
 };
+#[cfg(feature = "tokens")]
 pub use token_update_transaction::{
     TokenUpdateTransaction,
     TokenUpdateTransactionData,
 };
+#[cfg(feature = "tokens")]
 pub use token_wipe_transaction::{
     TokenWipeTransaction,
     TokenWipeTransactionData,
@@ -16,12 +16,13 @@ use crate::{
     Client,
     Error,
     FromProtobuf,
+    LedgerId,
     ToProtobuf,
     TokenId,
 };
 
 /// The unique identifier for a token on Hiero.
-#[derive(Hash, PartialEq, Eq, Clone, Copy)]
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub struct NftId {
     /// The (non-fungible) token of which this NFT is an instance.
     pub token_id: TokenId,
@@ -51,6 +52,19 @@ impl NftId {
     pub fn to_string_with_checksum(&self, client: &Client) -> String {
         format!("{}/{}", self.token_id.to_string_with_checksum(client), self.serial)
     }
+
+    /// Convert `self` to a string with a checksum for `ledger_id`,
+    /// see [`EntityId::to_string_with_checksum_for_ledger`](crate::EntityId::to_string_with_checksum_for_ledger).
+    #[must_use]
+    pub fn to_string_with_checksum_for_ledger(&self, ledger_id: &LedgerId) -> String {
+        format!("{}/{}", self.token_id.to_string_with_checksum_for_ledger(ledger_id), self.serial)
+    }
+
+    /// Validates the checksum of `self.token_id` for `ledger_id`,
+    /// see [`EntityId::validate_checksum_for_ledger`](crate::EntityId::validate_checksum_for_ledger).
+    pub fn validate_checksum_for_ledger(&self, ledger_id: &LedgerId) -> crate::Result<()> {
+        self.token_id.validate_checksum_for_ledger(ledger_id)
+    }
 }
 
 impl Debug for NftId {
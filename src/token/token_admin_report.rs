@@ -0,0 +1,742 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use super::{
+    TokenFreezeTransaction,
+    TokenGrantKycTransaction,
+    TokenId,
+    TokenPauseTransaction,
+    TokenRevokeKycTransaction,
+    TokenType,
+    TokenUnfreezeTransaction,
+    TokenUnpauseTransaction,
+    TokenWipeTransaction,
+};
+use crate::mirror_rest::Links;
+use crate::transaction::TransactionExecute;
+use crate::{
+    AccountId,
+    AnyTransaction,
+    Client,
+    Error,
+    PrivateKey,
+    Transaction,
+    TransactionReceipt,
+};
+
+/// Gather the compliance state (pause, freeze, KYC, balance) of a token for a set of accounts,
+/// and optionally bring them all to a desired state.
+///
+/// The current state is read from the mirror node REST API,
+/// so it may lag behind consensus by a few seconds.
+///
+/// The transactions made by [`apply`](Self::apply) are signed by the client's operator,
+/// and by any keys added with [`sign`](Self::sign) (IE, the token's pause, freeze, KYC, or wipe key).
+#[derive(Debug, Clone, Default)]
+pub struct TokenAdminReport {
+    token_id: Option<TokenId>,
+    account_ids: Vec<AccountId>,
+    signers: Vec<PrivateKey>,
+}
+
+/// The compliance state of a token and a set of accounts, returned by [`TokenAdminReport::execute`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TokenAdminState {
+    /// The token the state is for.
+    pub token_id: TokenId,
+
+    /// The token's type.
+    pub token_type: TokenType,
+
+    /// Whether the token is paused, or `None` if the token has no pause key.
+    pub paused: Option<bool>,
+
+    /// The token's treasury account.
+    pub treasury_account_id: AccountId,
+
+    /// The state of each account, in the order the accounts were added.
+    pub accounts: Vec<TokenAccountState>,
+}
+
+/// The state of a single account's relationship with a token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TokenAccountState {
+    /// The account the state is for.
+    pub account_id: AccountId,
+
+    /// Whether the account is associated with the token.
+    ///
+    /// If it isn't, the remaining fields are all empty.
+    pub associated: bool,
+
+    /// The account's balance of the token, in the token's smallest denomination.
+    ///
+    /// For a non-fungible token, this is the number of NFTs the account owns.
+    pub balance: u64,
+
+    /// The serial numbers of the NFTs the account owns, empty for a fungible token.
+    pub serials: Vec<u64>,
+
+    /// Whether the account is frozen, or `None` if the token has no freeze key.
+    pub frozen: Option<bool>,
+
+    /// Whether the account has been granted KYC, or `None` if the token has no KYC key.
+    pub kyc_granted: Option<bool>,
+}
+
+/// The state to bring a token and its accounts to with [`TokenAdminReport::apply`].
+///
+/// Anything left unset is left as it is.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenAdminTarget {
+    paused: Option<bool>,
+    frozen: Option<bool>,
+    kyc_granted: Option<bool>,
+    wipe: bool,
+}
+
+/// An action taken by [`TokenAdminReport::apply`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TokenAdminAction {
+    /// The token was paused.
+    Pause,
+
+    /// The token was unpaused.
+    Unpause,
+
+    /// The account was frozen.
+    Freeze,
+
+    /// The account was unfrozen.
+    Unfreeze,
+
+    /// The account was granted KYC.
+    GrantKyc,
+
+    /// The account's KYC was revoked.
+    RevokeKyc,
+
+    /// The given amount was wiped from the account.
+    ///
+    /// For a non-fungible token, this is the number of NFTs wiped.
+    Wipe(u64),
+}
+
+/// The outcome of one action taken by [`TokenAdminReport::apply`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TokenAdminOutcome {
+    /// The account the action was taken on, or `None` for actions on the token itself.
+    pub account_id: Option<AccountId>,
+
+    /// The action that was taken.
+    pub action: TokenAdminAction,
+
+    /// The receipt of the transaction, or the error that caused it to fail.
+    pub result: crate::Result<TransactionReceipt>,
+}
+
+/// The result of [`TokenAdminReport::apply`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TokenAdminApplyReport {
+    /// The state before any actions were taken.
+    pub before: TokenAdminState,
+
+    /// The outcome of each action taken, in the order they were taken.
+    ///
+    /// Actions that weren't needed (or weren't possible) aren't included.
+    pub outcomes: Vec<TokenAdminOutcome>,
+}
+
+impl TokenAdminTarget {
+    /// Create a new target, which leaves everything as it is.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the token should be paused, if set.
+    #[must_use]
+    pub fn get_paused(&self) -> Option<bool> {
+        self.paused
+    }
+
+    /// Sets whether the token should be paused.
+    pub fn paused(&mut self, paused: bool) -> &mut Self {
+        self.paused = Some(paused);
+        self
+    }
+
+    /// Returns whether the accounts should be frozen, if set.
+    #[must_use]
+    pub fn get_frozen(&self) -> Option<bool> {
+        self.frozen
+    }
+
+    /// Sets whether the accounts should be frozen.
+    pub fn frozen(&mut self, frozen: bool) -> &mut Self {
+        self.frozen = Some(frozen);
+        self
+    }
+
+    /// Returns whether the accounts should be granted KYC, if set.
+    #[must_use]
+    pub fn get_kyc_granted(&self) -> Option<bool> {
+        self.kyc_granted
+    }
+
+    /// Sets whether the accounts should be granted KYC.
+    pub fn kyc_granted(&mut self, kyc_granted: bool) -> &mut Self {
+        self.kyc_granted = Some(kyc_granted);
+        self
+    }
+
+    /// Returns whether the balance of the accounts should be wiped.
+    #[must_use]
+    pub fn get_wipe(&self) -> bool {
+        self.wipe
+    }
+
+    /// Sets whether the entire balance of the accounts should be wiped.
+    pub fn wipe(&mut self, wipe: bool) -> &mut Self {
+        self.wipe = wipe;
+        self
+    }
+}
+
+impl TokenAdminReport {
+    /// Create a new report.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the token to report on.
+    #[must_use]
+    pub fn get_token_id(&self) -> Option<TokenId> {
+        self.token_id
+    }
+
+    /// Sets the token to report on.
+    pub fn token_id(&mut self, token_id: impl Into<TokenId>) -> &mut Self {
+        self.token_id = Some(token_id.into());
+        self
+    }
+
+    /// Returns the accounts to report on.
+    #[must_use]
+    pub fn get_account_ids(&self) -> &[AccountId] {
+        &self.account_ids
+    }
+
+    /// Sets the accounts to report on.
+    pub fn account_ids(&mut self, account_ids: impl IntoIterator<Item = AccountId>) -> &mut Self {
+        self.account_ids = account_ids.into_iter().collect();
+        self
+    }
+
+    /// Adds an account to report on.
+    pub fn add_account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.account_ids.push(account_id);
+        self
+    }
+
+    /// Adds a key to sign the transactions made by [`apply`](Self::apply) with.
+    pub fn sign(&mut self, key: PrivateKey) -> &mut Self {
+        self.signers.push(key);
+        self
+    }
+
+    /// Read the current state of the token and accounts from the mirror node.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `token_id` isn't set, or the mirror node returns malformed data.
+    /// - [`Error::MirrorRest`] if a mirror node request fails.
+    pub async fn execute(&self, client: &Client) -> crate::Result<TokenAdminState> {
+        let token_id = self.get_token_id_or_err()?;
+        let rest = client.rest_client();
+
+        let token: RestToken = rest.get(&format!("/tokens/{token_id}")).await?;
+        let token_type = parse_token_type(&token.token_type)?;
+
+        let mut accounts = Vec::with_capacity(self.account_ids.len());
+
+        for &account_id in &self.account_ids {
+            let relationships: RestTokenRelationships =
+                rest.get(&format!("/accounts/{account_id}/tokens?token.id={token_id}")).await?;
+
+            let mut state = relationships.into_state(account_id, token_id)?;
+
+            if token_type == TokenType::NonFungibleUnique && state.balance > 0 {
+                let path = format!("/accounts/{account_id}/nfts?token.id={token_id}");
+
+                state.serials = rest
+                    .paginate(Ok(vec![path]), 0, |page: RestNfts| {
+                        (page.nfts.into_iter().map(|it| Ok(it.serial_number)), page.links.next)
+                    })
+                    .collect_all(0)
+                    .await?;
+            }
+
+            accounts.push(state);
+        }
+
+        Ok(TokenAdminState {
+            token_id,
+            token_type,
+            paused: parse_status(&token.pause_status)?,
+            treasury_account_id: AccountId::from_str(&token.treasury_account_id)?,
+            accounts,
+        })
+    }
+
+    /// Bring the token and accounts to `target`, submitting one transaction per change.
+    ///
+    /// A paused token's accounts can't be changed, so if any need changing the token is unpaused first,
+    /// and paused again after, unless `target` unpauses it.
+    /// Likewise, a frozen account can't be wiped, so it's unfrozen first, and frozen again after,
+    /// unless `target` unfreezes it.
+    /// The treasury's balance is never wiped, since the network doesn't allow it.
+    /// NFTs are wiped by serial number, using the serials in [`TokenAccountState::serials`].
+    ///
+    /// A failing transaction doesn't stop the others, its error is in its [`TokenAdminOutcome`].
+    ///
+    /// # Errors
+    /// - Any error returned by [`execute`](Self::execute), when reading the current state.
+    pub async fn apply(
+        &self,
+        client: &Client,
+        target: &TokenAdminTarget,
+    ) -> crate::Result<TokenAdminApplyReport> {
+        let before = self.execute(client).await?;
+
+        let mut outcomes = Vec::new();
+
+        for (account_id, action, transaction) in plan(&before, target) {
+            outcomes.push(self.submit(client, account_id, action, transaction).await);
+        }
+
+        Ok(TokenAdminApplyReport { before, outcomes })
+    }
+
+    async fn submit<D: TransactionExecute>(
+        &self,
+        client: &Client,
+        account_id: Option<AccountId>,
+        action: TokenAdminAction,
+        mut transaction: Transaction<D>,
+    ) -> TokenAdminOutcome {
+        for key in &self.signers {
+            transaction.sign(key.clone());
+        }
+
+        let result = match transaction.execute(client).await {
+            Ok(response) => response.get_receipt(client).await,
+            Err(e) => Err(e),
+        };
+
+        TokenAdminOutcome { account_id, action, result }
+    }
+
+    fn get_token_id_or_err(&self) -> crate::Result<TokenId> {
+        self.token_id
+            .ok_or_else(|| Error::basic_parse("`token_id` must be set to report on a token"))
+    }
+}
+
+/// Returns the actions (and their transactions) that bring `state` to `target`, in order.
+fn plan(
+    state: &TokenAdminState,
+    target: &TokenAdminTarget,
+) -> Vec<(Option<AccountId>, TokenAdminAction, AnyTransaction)> {
+    let token_id = state.token_id;
+
+    let mut account_actions = Vec::new();
+
+    for account in state.accounts.iter().filter(|it| it.associated) {
+        let account_id = account.account_id;
+
+        let nft = state.token_type == TokenType::NonFungibleUnique;
+        let wipe_amount = if nft { account.serials.len() as u64 } else { account.balance };
+        let wipe = target.wipe && wipe_amount > 0 && account_id != state.treasury_account_id;
+
+        let should_be_frozen = account.frozen.map(|it| target.frozen.unwrap_or(it));
+        let unfreeze = account.frozen == Some(true) && (wipe || should_be_frozen == Some(false));
+        let freeze = should_be_frozen == Some(true) && (account.frozen == Some(false) || unfreeze);
+
+        if unfreeze {
+            let mut tx = TokenUnfreezeTransaction::new();
+            tx.token_id(token_id).account_id(account_id);
+            account_actions.push((Some(account_id), TokenAdminAction::Unfreeze, tx.into()));
+        }
+
+        if let Some(granted) = target.kyc_granted.filter(|it| account.kyc_granted == Some(!it)) {
+            if granted {
+                let mut tx = TokenGrantKycTransaction::new();
+                tx.token_id(token_id).account_id(account_id);
+                account_actions.push((Some(account_id), TokenAdminAction::GrantKyc, tx.into()));
+            } else {
+                let mut tx = TokenRevokeKycTransaction::new();
+                tx.token_id(token_id).account_id(account_id);
+                account_actions.push((Some(account_id), TokenAdminAction::RevokeKyc, tx.into()));
+            }
+        }
+
+        if wipe {
+            let mut tx = TokenWipeTransaction::new();
+            tx.token_id(token_id).account_id(account_id);
+
+            if nft {
+                tx.serials(account.serials.iter().copied());
+            } else {
+                tx.amount(account.balance);
+            }
+
+            let action = TokenAdminAction::Wipe(wipe_amount);
+            account_actions.push((Some(account_id), action, tx.into()));
+        }
+
+        if freeze {
+            let mut tx = TokenFreezeTransaction::new();
+            tx.token_id(token_id).account_id(account_id);
+            account_actions.push((Some(account_id), TokenAdminAction::Freeze, tx.into()));
+        }
+    }
+
+    let paused = state.paused == Some(true);
+    let should_be_paused = state.paused.map(|it| target.paused.unwrap_or(it)) == Some(true);
+    let unpause = paused && (!account_actions.is_empty() || !should_be_paused);
+    let pause = should_be_paused && (!paused || unpause);
+
+    let mut actions = Vec::with_capacity(account_actions.len() + 2);
+
+    if unpause {
+        let mut tx = TokenUnpauseTransaction::new();
+        tx.token_id(token_id);
+        actions.push((None, TokenAdminAction::Unpause, tx.into()));
+    }
+
+    actions.extend(account_actions);
+
+    if pause {
+        let mut tx = TokenPauseTransaction::new();
+        tx.token_id(token_id);
+        actions.push((None, TokenAdminAction::Pause, tx.into()));
+    }
+
+    actions
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestToken {
+    pause_status: String,
+    treasury_account_id: String,
+    #[serde(rename = "type")]
+    token_type: String,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestTokenRelationships {
+    tokens: Vec<RestTokenRelationship>,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestNfts {
+    nfts: Vec<RestNft>,
+    links: Links,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestNft {
+    serial_number: u64,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestTokenRelationship {
+    token_id: String,
+    balance: u64,
+    freeze_status: String,
+    kyc_status: String,
+}
+
+impl RestTokenRelationships {
+    fn into_state(
+        self,
+        account_id: AccountId,
+        token_id: TokenId,
+    ) -> crate::Result<TokenAccountState> {
+        for relationship in self.tokens {
+            if TokenId::from_str(&relationship.token_id)? != token_id {
+                continue;
+            }
+
+            return Ok(TokenAccountState {
+                account_id,
+                associated: true,
+                balance: relationship.balance,
+                serials: Vec::new(),
+                frozen: parse_status(&relationship.freeze_status)?,
+                kyc_granted: parse_status(&relationship.kyc_status)?,
+            });
+        }
+
+        Ok(TokenAccountState {
+            account_id,
+            associated: false,
+            balance: 0,
+            serials: Vec::new(),
+            frozen: None,
+            kyc_granted: None,
+        })
+    }
+}
+
+/// Parse a mirror node token type.
+fn parse_token_type(token_type: &str) -> crate::Result<TokenType> {
+    match token_type {
+        "FUNGIBLE_COMMON" => Ok(TokenType::FungibleCommon),
+        "NON_FUNGIBLE_UNIQUE" => Ok(TokenType::NonFungibleUnique),
+        _ => Err(Error::basic_parse(format!("unknown token type `{token_type}`"))),
+    }
+}
+
+/// Parse a mirror node pause, freeze, or KYC status.
+fn parse_status(status: &str) -> crate::Result<Option<bool>> {
+    match status {
+        "PAUSED" | "FROZEN" | "GRANTED" => Ok(Some(true)),
+        "UNPAUSED" | "UNFROZEN" | "REVOKED" => Ok(Some(false)),
+        "NOT_APPLICABLE" => Ok(None),
+        _ => Err(Error::basic_parse(format!("unknown token status `{status}`"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::{
+        parse_status,
+        parse_token_type,
+        plan,
+        RestTokenRelationships,
+        TokenAccountState,
+        TokenAdminAction,
+        TokenAdminReport,
+        TokenAdminState,
+        TokenAdminTarget,
+    };
+    use crate::{
+        AccountId,
+        TokenId,
+        TokenType,
+        TokenWipeTransaction,
+    };
+
+    const TOKEN_ID: TokenId = TokenId::new(0, 0, 1234);
+    const ACCOUNT_ID: AccountId = AccountId::new(0, 0, 5678);
+    const TREASURY_ACCOUNT_ID: AccountId = AccountId::new(0, 0, 1000);
+
+    fn state(paused: Option<bool>, accounts: Vec<TokenAccountState>) -> TokenAdminState {
+        TokenAdminState {
+            token_id: TOKEN_ID,
+            token_type: TokenType::FungibleCommon,
+            paused,
+            treasury_account_id: TREASURY_ACCOUNT_ID,
+            accounts,
+        }
+    }
+
+    fn account(account_id: AccountId, balance: u64, frozen: Option<bool>) -> TokenAccountState {
+        TokenAccountState {
+            account_id,
+            associated: true,
+            balance,
+            serials: Vec::new(),
+            frozen,
+            kyc_granted: None,
+        }
+    }
+
+    fn actions(state: &TokenAdminState, target: &TokenAdminTarget) -> Vec<TokenAdminAction> {
+        plan(state, target).into_iter().map(|(_, action, _)| action).collect()
+    }
+
+    #[test]
+    fn plan_wipes_frozen_account_before_refreezing() {
+        let state = state(None, vec![account(ACCOUNT_ID, 500, Some(true))]);
+
+        let mut target = TokenAdminTarget::new();
+        target.wipe(true);
+
+        let plan = plan(&state, &target);
+
+        assert_eq!(
+            plan.iter().map(|(_, action, _)| *action).collect::<Vec<_>>(),
+            [TokenAdminAction::Unfreeze, TokenAdminAction::Wipe(500), TokenAdminAction::Freeze]
+        );
+
+        let (account_id, _, tx) = plan.into_iter().nth(1).unwrap();
+        assert_eq!(account_id, Some(ACCOUNT_ID));
+
+        let tx = assert_matches!(tx.downcast::<TokenWipeTransaction>(), Ok(it) => it);
+        assert_eq!(tx.get_account_id(), Some(ACCOUNT_ID));
+        assert_eq!(tx.get_amount(), Some(500));
+    }
+
+    #[test]
+    fn plan_wipes_nfts_by_serial() {
+        let mut holder = account(ACCOUNT_ID, 3, None);
+        holder.serials = vec![1, 4, 9];
+
+        let mut state = state(None, vec![holder]);
+        state.token_type = TokenType::NonFungibleUnique;
+
+        let mut target = TokenAdminTarget::new();
+        target.wipe(true);
+
+        let plan = plan(&state, &target);
+
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].1, TokenAdminAction::Wipe(3));
+
+        let (_, _, tx) = plan.into_iter().next().unwrap();
+
+        let tx = assert_matches!(tx.downcast::<TokenWipeTransaction>(), Ok(it) => it);
+        assert_eq!(tx.get_account_id(), Some(ACCOUNT_ID));
+        assert_eq!(tx.get_amount(), None);
+        assert_eq!(tx.get_serials(), [1, 4, 9]);
+    }
+
+    #[test]
+    fn plan_skips_nft_holder_without_serials() {
+        // the mirror node reported a balance, but no NFTs (yet), so there's nothing to wipe by serial.
+        let mut state = state(None, vec![account(ACCOUNT_ID, 3, None)]);
+        state.token_type = TokenType::NonFungibleUnique;
+
+        let mut target = TokenAdminTarget::new();
+        target.wipe(true);
+
+        assert!(actions(&state, &target).is_empty());
+    }
+
+    #[test]
+    fn plan_unfreezes_without_refreezing() {
+        let state = state(None, vec![account(ACCOUNT_ID, 500, Some(true))]);
+
+        let mut target = TokenAdminTarget::new();
+        target.frozen(false).wipe(true);
+
+        assert_eq!(
+            actions(&state, &target),
+            [TokenAdminAction::Unfreeze, TokenAdminAction::Wipe(500)]
+        );
+    }
+
+    #[test]
+    fn plan_keeps_paused_token_paused() {
+        let state = state(Some(true), vec![account(ACCOUNT_ID, 0, Some(false))]);
+
+        let mut target = TokenAdminTarget::new();
+        target.frozen(true);
+
+        assert_eq!(
+            actions(&state, &target),
+            [TokenAdminAction::Unpause, TokenAdminAction::Freeze, TokenAdminAction::Pause]
+        );
+
+        // nothing to change, so nothing to unpause for.
+        assert!(actions(&state, &TokenAdminTarget::new()).is_empty());
+
+        let mut target = TokenAdminTarget::new();
+        target.paused(false).frozen(true);
+
+        assert_eq!(actions(&state, &target), [TokenAdminAction::Unpause, TokenAdminAction::Freeze]);
+    }
+
+    #[test]
+    fn plan_skips_treasury_wipe() {
+        let state = state(
+            None,
+            vec![account(TREASURY_ACCOUNT_ID, 1000, None), account(ACCOUNT_ID, 500, None)],
+        );
+
+        let mut target = TokenAdminTarget::new();
+        target.wipe(true);
+
+        let plan = plan(&state, &target);
+
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].0, Some(ACCOUNT_ID));
+        assert_eq!(plan[0].1, TokenAdminAction::Wipe(500));
+    }
+
+    #[test]
+    fn requires_token_id() {
+        assert!(TokenAdminReport::new().get_token_id_or_err().is_err());
+    }
+
+    #[test]
+    fn statuses() {
+        assert_eq!(parse_status("PAUSED").unwrap(), Some(true));
+        assert_eq!(parse_status("UNFROZEN").unwrap(), Some(false));
+        assert_eq!(parse_status("GRANTED").unwrap(), Some(true));
+        assert_eq!(parse_status("NOT_APPLICABLE").unwrap(), None);
+        assert!(parse_status("MAYBE").is_err());
+    }
+
+    #[test]
+    fn token_types() {
+        assert_eq!(parse_token_type("FUNGIBLE_COMMON").unwrap(), TokenType::FungibleCommon);
+        assert_eq!(parse_token_type("NON_FUNGIBLE_UNIQUE").unwrap(), TokenType::NonFungibleUnique);
+        assert!(parse_token_type("SEMI_FUNGIBLE").is_err());
+    }
+
+    #[test]
+    fn associated_account() {
+        let relationships: RestTokenRelationships = serde_json::from_str(
+            r#"{
+                "tokens": [
+                    {
+                        "automatic_association": false,
+                        "balance": 500,
+                        "created_timestamp": "1700000000.000000001",
+                        "decimals": 2,
+                        "freeze_status": "FROZEN",
+                        "kyc_status": "NOT_APPLICABLE",
+                        "token_id": "0.0.1234"
+                    }
+                ],
+                "links": { "next": null }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            relationships.into_state(ACCOUNT_ID, TOKEN_ID).unwrap(),
+            TokenAccountState {
+                account_id: ACCOUNT_ID,
+                associated: true,
+                balance: 500,
+                serials: Vec::new(),
+                frozen: Some(true),
+                kyc_granted: None,
+            }
+        );
+    }
+
+    #[test]
+    fn unassociated_account() {
+        let relationships: RestTokenRelationships =
+            serde_json::from_str(r#"{ "tokens": [], "links": { "next": null } }"#).unwrap();
+
+        let state = relationships.into_state(ACCOUNT_ID, TOKEN_ID).unwrap();
+
+        assert!(!state.associated);
+        assert_eq!(state.frozen, None);
+    }
+}
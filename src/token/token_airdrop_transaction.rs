@@ -22,9 +22,13 @@ use crate::transaction::{
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::transfer_transaction::{
+    check_token_transfers,
+    describe_token_transfers,
     TokenTransfer,
     Transfer,
 };
@@ -286,7 +290,19 @@ impl TokenAirdropTransaction {
     }
 }
 
-impl TransactionData for TokenAirdropTransactionData {}
+impl TransactionData for TokenAirdropTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        if self.token_transfers.is_empty() {
+            problems.push(ValidationProblem::MissingField { field: "token_transfers" });
+        }
+
+        check_token_transfers(problems, &self.token_transfers);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        describe_token_transfers(description, &self.token_transfers);
+    }
+}
 
 impl TransactionExecute for TokenAirdropTransactionData {
     fn execute(
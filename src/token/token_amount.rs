@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+use rust_decimal::Decimal;
+
+use crate::Error;
+#[cfg(feature = "tokens")]
+use crate::TokenInfo;
+
+/// An amount of a fungible token, in the token's smallest denomination, along with the token's decimals.
+///
+/// Carrying the decimals with the amount lets it be checked against the token it's used with,
+/// rather than silently being off by a factor of ten.
+///
+/// # Examples
+/// ```
+/// use hedera::TokenAmount;
+/// use rust_decimal::Decimal;
+///
+/// let amount = TokenAmount::from_decimal("100.55".parse::<Decimal>().unwrap(), 2).unwrap();
+///
+/// assert_eq!(amount.get_amount(), 10055);
+/// assert_eq!(amount.to_string(), "100.55");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TokenAmount {
+    amount: u64,
+    decimals: u32,
+}
+
+impl TokenAmount {
+    /// Create a new amount from an `amount` of the smallest denomination of a token with `decimals` decimals.
+    ///
+    /// For example, 100.55 of a token with 2 decimals is `TokenAmount::new(10055, 2)`.
+    #[must_use]
+    pub const fn new(amount: u64, decimals: u32) -> Self {
+        Self { amount, decimals }
+    }
+
+    /// Create a new amount from an `amount` of the smallest denomination of the token of `info`.
+    #[cfg(feature = "tokens")]
+    #[must_use]
+    pub fn from_token_info(amount: u64, info: &TokenInfo) -> Self {
+        Self::new(amount, info.decimals)
+    }
+
+    /// Create a new amount from a `value` in whole tokens (IE, `100.55`), for a token with `decimals` decimals.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `value` is negative, has more decimal places than `decimals`,
+    ///   or is too large to be represented in the token's smallest denomination.
+    pub fn from_decimal(value: Decimal, decimals: u32) -> crate::Result<Self> {
+        if value.is_sign_negative() && !value.is_zero() {
+            return Err(Error::basic_parse(format!("token amount `{value}` is negative")));
+        }
+
+        let value = value.normalize();
+
+        if value.scale() > decimals {
+            return Err(Error::basic_parse(format!(
+                "token amount `{value}` has more than {decimals} decimal places"
+            )));
+        }
+
+        let amount = u64::try_from(value.mantissa())
+            .ok()
+            .zip(10_u64.checked_pow(decimals - value.scale()))
+            .and_then(|(mantissa, scale)| mantissa.checked_mul(scale))
+            .ok_or_else(|| {
+                Error::basic_parse(format!(
+                    "token amount `{value}` is too large for a token with {decimals} decimals"
+                ))
+            })?;
+
+        Ok(Self::new(amount, decimals))
+    }
+
+    /// Returns the amount, in the smallest denomination of the token.
+    #[must_use]
+    pub const fn get_amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// Returns the number of decimals of the token.
+    #[must_use]
+    pub const fn get_decimals(&self) -> u32 {
+        self.decimals
+    }
+
+    /// Returns the amount in whole tokens (IE, `100.55`).
+    ///
+    /// Returns `None` if the token has more decimals than a [`Decimal`] can represent (28).
+    #[must_use]
+    pub fn to_decimal(&self) -> Option<Decimal> {
+        Decimal::try_from_i128_with_scale(i128::from(self.amount), self.decimals).ok()
+    }
+
+    /// Returns the amount in the smallest denomination as an `i64`, which is what the network takes.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the amount is larger than [`i64::MAX`].
+    pub(crate) fn to_i64(&self) -> crate::Result<i64> {
+        i64::try_from(self.amount).map_err(|_| {
+            Error::basic_parse(format!("token amount `{}` is larger than `i64::MAX`", self.amount))
+        })
+    }
+
+    /// Check that this amount has the same decimals as the token of `info`.
+    #[cfg(feature = "tokens")]
+    pub(crate) fn check_decimals(&self, info: &TokenInfo) -> crate::Result<()> {
+        if self.decimals != info.decimals {
+            return Err(Error::TokenDecimalsMismatch {
+                token_id: info.token_id,
+                expected: info.decimals,
+                actual: self.decimals,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for TokenAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decimals = self.decimals as usize;
+
+        if decimals == 0 {
+            return write!(f, "{}", self.amount);
+        }
+
+        let digits = format!("{:0>width$}", self.amount, width = decimals + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - decimals);
+
+        write!(f, "{whole}.{fraction}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::TokenAmount;
+
+    fn decimal(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let amount = TokenAmount::new(10055, 2);
+
+        assert_eq!(amount.get_amount(), 10055);
+        assert_eq!(amount.get_decimals(), 2);
+    }
+
+    #[test]
+    fn from_decimal() {
+        assert_eq!(
+            TokenAmount::from_decimal(decimal("100.55"), 2).unwrap(),
+            TokenAmount::new(10055, 2)
+        );
+        assert_eq!(
+            TokenAmount::from_decimal(decimal("100.5500"), 2).unwrap(),
+            TokenAmount::new(10055, 2)
+        );
+        assert_eq!(
+            TokenAmount::from_decimal(decimal("7"), 8).unwrap(),
+            TokenAmount::new(700_000_000, 8)
+        );
+        assert_eq!(TokenAmount::from_decimal(decimal("-0"), 0).unwrap(), TokenAmount::new(0, 0));
+    }
+
+    #[test]
+    fn from_decimal_errors() {
+        // too precise
+        assert!(TokenAmount::from_decimal(decimal("1.005"), 2).is_err());
+        // negative
+        assert!(TokenAmount::from_decimal(decimal("-1"), 2).is_err());
+        // overflows a u64
+        assert!(TokenAmount::from_decimal(decimal("18446744073709551616"), 0).is_err());
+        assert!(TokenAmount::from_decimal(decimal("1"), 20).is_err());
+    }
+
+    #[test]
+    fn to_i64() {
+        assert_eq!(TokenAmount::new(10055, 2).to_i64().unwrap(), 10055);
+        assert!(TokenAmount::new(u64::MAX, 2).to_i64().is_err());
+    }
+
+    #[test]
+    fn to_decimal() {
+        assert_eq!(TokenAmount::new(10055, 2).to_decimal(), Some(decimal("100.55")));
+        assert_eq!(TokenAmount::new(1, 29).to_decimal(), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(TokenAmount::new(10055, 2).to_string(), "100.55");
+        assert_eq!(TokenAmount::new(5, 3).to_string(), "0.005");
+        assert_eq!(TokenAmount::new(0, 2).to_string(), "0.00");
+        assert_eq!(TokenAmount::new(42, 0).to_string(), "42");
+    }
+}
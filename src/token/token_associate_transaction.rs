@@ -7,12 +7,15 @@ use tonic::transport::Channel;
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -74,7 +77,23 @@ impl TokenAssociateTransaction {
     }
 }
 
-impl TransactionData for TokenAssociateTransactionData {}
+impl TransactionData for TokenAssociateTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "account_id", &self.account_id);
+
+        if self.token_ids.is_empty() {
+            problems.push(ValidationProblem::MissingField { field: "token_ids" });
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.optional_entity("account", self.account_id.as_ref());
+
+        for token_id in &self.token_ids {
+            description.entity("token", token_id);
+        }
+    }
+}
 
 impl TransactionExecute for TokenAssociateTransactionData {
     fn execute(
@@ -10,12 +10,16 @@ use crate::{
 };
 
 /// A token <-> account association.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenAssociation {
     /// The token involved in the association.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::display"))]
     pub token_id: TokenId,
 
     /// The account involved in the association.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::display"))]
     pub account_id: AccountId,
 }
 
@@ -9,17 +9,22 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
     Error,
+    TokenAmount,
     TokenId,
+    TokenInfo,
     Transaction,
     ValidateChecksums,
 };
@@ -86,6 +91,29 @@ impl TokenBurnTransaction {
         self
     }
 
+    /// Sets the token and amount to burn, after checking them against `info`, the current info of the token.
+    ///
+    /// # Errors
+    /// - [`Error::TokenDecimalsMismatch`] if `amount` has different decimals than the token.
+    /// - [`Error::TokenSupplyExceeded`] if `amount` is more than the token's total supply.
+    pub fn checked_amount(
+        &mut self,
+        amount: TokenAmount,
+        info: &TokenInfo,
+    ) -> crate::Result<&mut Self> {
+        amount.check_decimals(info)?;
+
+        if amount.get_amount() > info.total_supply {
+            return Err(Error::TokenSupplyExceeded {
+                token_id: info.token_id,
+                amount: amount.get_amount(),
+                total_supply: info.total_supply,
+            });
+        }
+
+        Ok(self.token_id(info.token_id).amount(amount.get_amount()))
+    }
+
     /// Returns the serial numbers of a non-fungible token to burn from the treasury account.
     #[must_use]
     pub fn get_serials(&self) -> &[i64] {
@@ -99,7 +127,21 @@ impl TokenBurnTransaction {
     }
 }
 
-impl TransactionData for TokenBurnTransactionData {}
+impl TransactionData for TokenBurnTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "token_id", &self.token_id);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("token", self.token_id.as_ref())
+            .serials("serials", &self.serials);
+
+        if self.amount != 0 {
+            description.field("amount", self.amount);
+        }
+    }
+}
 
 impl TransactionExecute for TokenBurnTransactionData {
     fn execute(
@@ -166,6 +208,7 @@ impl ToProtobuf for TokenBurnTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect_file;
     use hedera_proto::services;
 
@@ -181,7 +224,11 @@ mod tests {
     };
     use crate::{
         AnyTransaction,
+        Error,
+        TokenAmount,
         TokenBurnTransaction,
+        TokenInfo,
+        TokenSupplyType,
     };
 
     fn make_transaction() -> TokenBurnTransaction {
@@ -284,4 +331,25 @@ mod tests {
 
         assert_eq!(tx.get_serials(), serials);
     }
+
+    #[test]
+    fn checked_amount() {
+        let info = TokenInfo::new_for_tests(TEST_TOKEN_ID, 2, 100, TokenSupplyType::Infinite, 0);
+
+        let mut tx = TokenBurnTransaction::new();
+        tx.checked_amount(TokenAmount::new(100, 2), &info).unwrap();
+
+        assert_eq!(tx.get_token_id(), Some(TEST_TOKEN_ID));
+        assert_eq!(tx.get_amount(), 100);
+
+        assert_matches!(
+            tx.checked_amount(TokenAmount::new(101, 2), &info),
+            Err(Error::TokenSupplyExceeded { amount: 101, total_supply: 100, .. })
+        );
+
+        assert_matches!(
+            tx.checked_amount(TokenAmount::new(1, 0), &info),
+            Err(Error::TokenDecimalsMismatch { expected: 2, actual: 0, .. })
+        );
+    }
 }
@@ -16,7 +16,9 @@ use crate::transaction::{
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -77,7 +79,19 @@ impl TokenClaimAirdropTransaction {
     }
 }
 
-impl TransactionData for TokenClaimAirdropTransactionData {}
+impl TransactionData for TokenClaimAirdropTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        if self.pending_airdrop_ids.is_empty() {
+            problems.push(ValidationProblem::MissingField { field: "pending_airdrop_ids" });
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        for pending_airdrop_id in &self.pending_airdrop_ids {
+            description.entity("pending_airdrop", pending_airdrop_id);
+        }
+    }
+}
 
 impl TransactionExecute for TokenClaimAirdropTransactionData {
     fn execute(
@@ -17,12 +17,17 @@ use crate::token::custom_fees::AnyCustomFee;
 use crate::token::token_supply_type::TokenSupplyType;
 use crate::token::token_type::TokenType;
 use crate::transaction::{
+    check_key,
+    check_memo,
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -296,6 +301,8 @@ impl TokenCreateTransaction {
 
     /// Sets the default freeze status (frozen or unfrozen) of hedera accounts
     /// relative to this token. If true, an account must be unfrozen before it can receive the token.
+    ///
+    /// Setting this to `true` requires a [`freeze_key`](Self::freeze_key).
     pub fn freeze_default(&mut self, freeze_default: bool) -> &mut Self {
         self.data_mut().freeze_default = freeze_default;
         self
@@ -460,6 +467,70 @@ impl TransactionData for TokenCreateTransactionData {
     fn default_max_transaction_fee(&self) -> crate::Hbar {
         crate::Hbar::from_unit(40, crate::HbarUnit::Hbar)
     }
+
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        if self.name.is_empty() {
+            problems.push(ValidationProblem::MissingField { field: "name" });
+        }
+
+        if self.symbol.is_empty() {
+            problems.push(ValidationProblem::MissingField { field: "symbol" });
+        }
+
+        check_required(problems, "treasury_account_id", &self.treasury_account_id);
+        check_memo(problems, "token_memo", &self.token_memo);
+
+        let keys = [
+            ("admin_key", &self.admin_key),
+            ("kyc_key", &self.kyc_key),
+            ("freeze_key", &self.freeze_key),
+            ("wipe_key", &self.wipe_key),
+            ("supply_key", &self.supply_key),
+            ("fee_schedule_key", &self.fee_schedule_key),
+            ("pause_key", &self.pause_key),
+            ("metadata_key", &self.metadata_key),
+        ];
+
+        for (field, key) in keys {
+            if let Some(key) = key {
+                check_key(problems, field, key);
+            }
+        }
+
+        // the network rejects this with `TOKEN_HAS_NO_FREEZE_KEY`.
+        // there's no KYC equivalent: KYC has no default flag, accounts of a token with a `kyc_key`
+        // always start without KYC, and a token without one doesn't check KYC at all.
+        if self.freeze_default && self.freeze_key.is_none() {
+            problems.push(ValidationProblem::KeyRequired {
+                field: "freeze_key",
+                flag: "freeze_default",
+            });
+        }
+    }
+
+    fn required_signers(&self) -> Vec<(&'static str, AccountId)> {
+        [
+            ("treasury_account_id", self.treasury_account_id),
+            ("auto_renew_account_id", self.auto_renew_account_id),
+        ]
+        .into_iter()
+        .filter_map(|(field, account_id)| Some((field, account_id?)))
+        .collect()
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("treasury", self.treasury_account_id.as_ref())
+            .optional_entity("auto_renew_account", self.auto_renew_account_id.as_ref())
+            .key("admin_key", self.admin_key.as_ref())
+            .key("kyc_key", self.kyc_key.as_ref())
+            .key("freeze_key", self.freeze_key.as_ref())
+            .key("wipe_key", self.wipe_key.as_ref())
+            .key("supply_key", self.supply_key.as_ref())
+            .key("fee_schedule_key", self.fee_schedule_key.as_ref())
+            .key("pause_key", self.pause_key.as_ref())
+            .key("metadata_key", self.metadata_key.as_ref());
+    }
 }
 
 impl TransactionExecute for TokenCreateTransactionData {
@@ -557,7 +628,7 @@ impl FromProtobuf<services::TokenCreateTransactionBody> for TokenCreateTransacti
             wipe_key: Option::from_protobuf(wipe_key)?,
             supply_key: Option::from_protobuf(supply_key)?,
             freeze_default,
-            expiration_time: expiry.map(Into::into),
+            expiration_time: Option::from_protobuf(expiry)?,
             auto_renew_account_id: Option::from_protobuf(auto_renew_account)?,
             auto_renew_period: auto_renew_period.map(Into::into),
             token_memo: memo,
@@ -10,12 +10,15 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -80,7 +83,23 @@ impl TokenDissociateTransaction {
     }
 }
 
-impl TransactionData for TokenDissociateTransactionData {}
+impl TransactionData for TokenDissociateTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "account_id", &self.account_id);
+
+        if self.token_ids.is_empty() {
+            problems.push(ValidationProblem::MissingField { field: "token_ids" });
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.optional_entity("account", self.account_id.as_ref());
+
+        for token_id in &self.token_ids {
+            description.entity("token", token_id);
+        }
+    }
+}
 
 impl TransactionExecute for TokenDissociateTransactionData {
     fn execute(
@@ -10,12 +10,15 @@ use crate::protobuf::{
 };
 use crate::token::custom_fees::AnyCustomFee;
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -74,7 +77,19 @@ impl TokenFeeScheduleUpdateTransaction {
     }
 }
 
-impl TransactionData for TokenFeeScheduleUpdateTransactionData {}
+impl TransactionData for TokenFeeScheduleUpdateTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "token_id", &self.token_id);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.optional_entity("token", self.token_id.as_ref());
+
+        for fee in &self.custom_fees {
+            description.optional_entity("fee_collector", fee.fee_collector_account_id.as_ref());
+        }
+    }
+}
 
 impl TransactionExecute for TokenFeeScheduleUpdateTransactionData {
     fn execute(
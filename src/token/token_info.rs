@@ -179,7 +179,7 @@ impl FromProtobuf<services::TokenInfo> for TokenInfo {
             if expiry.seconds == -9223372036854775808 {
                 None
             } else {
-                expiry.into()
+                Some(FromProtobuf::from_protobuf(expiry)?)
             }
         } else {
             None
@@ -202,7 +202,7 @@ impl FromProtobuf<services::TokenInfo> for TokenInfo {
             is_deleted: pb.deleted,
             auto_renew_account: auto_renew_account_id,
             auto_renew_period: pb.auto_renew_period.map(Into::into),
-            expiration_time: expiry.map(Into::into),
+            expiration_time: expiry,
             token_memo: pb.memo,
             token_type,
             supply_type: token_supply_type,
@@ -271,6 +271,49 @@ impl ToProtobuf for TokenInfo {
     }
 }
 
+#[cfg(test)]
+impl TokenInfo {
+    /// Returns the info of a fungible token with the given supply, and nothing else set.
+    pub(crate) fn new_for_tests(
+        token_id: TokenId,
+        decimals: u32,
+        total_supply: u64,
+        supply_type: TokenSupplyType,
+        max_supply: u64,
+    ) -> Self {
+        Self {
+            token_id,
+            name: String::new(),
+            symbol: String::new(),
+            decimals,
+            total_supply,
+            treasury_account_id: AccountId::new(0, 0, 2),
+            admin_key: None,
+            kyc_key: None,
+            freeze_key: None,
+            wipe_key: None,
+            supply_key: None,
+            fee_schedule_key: None,
+            default_freeze_status: None,
+            default_kyc_status: None,
+            is_deleted: false,
+            auto_renew_account: None,
+            auto_renew_period: None,
+            expiration_time: None,
+            token_memo: String::new(),
+            token_type: TokenType::FungibleCommon,
+            supply_type,
+            max_supply,
+            custom_fees: Vec::new(),
+            pause_key: None,
+            pause_status: None,
+            ledger_id: LedgerId::testnet(),
+            metadata: Vec::new(),
+            metadata_key: None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
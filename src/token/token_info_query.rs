@@ -14,6 +14,7 @@ use crate::token::token_info::TokenInfo;
 use crate::{
     BoxGrpcFuture,
     Error,
+    FromProtobuf,
     Query,
     ToProtobuf,
     TokenId,
@@ -63,6 +64,12 @@ impl ToQueryProtobuf for TokenInfoQueryData {
     }
 }
 
+impl FromProtobuf<services::TokenGetInfoQuery> for TokenInfoQueryData {
+    fn from_protobuf(pb: services::TokenGetInfoQuery) -> crate::Result<Self> {
+        Ok(Self { token_id: Option::from_protobuf(pb.token)? })
+    }
+}
+
 impl QueryExecute for TokenInfoQueryData {
     type Response = TokenInfo;
 
@@ -10,17 +10,23 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
     Error,
+    TokenAmount,
     TokenId,
+    TokenInfo,
+    TokenSupplyType,
     Transaction,
     ValidateChecksums,
 };
@@ -45,6 +51,9 @@ use crate::{
 /// `BatchSizeLimitExceeded` response code will be returned.
 pub type TokenMintTransaction = Transaction<TokenMintTransactionData>;
 
+/// The maximum total supply any token can have, `2^63-1`.
+const MAX_TOKEN_SUPPLY: u64 = (1 << 63) - 1;
+
 #[derive(Debug, Clone, Default)]
 pub struct TokenMintTransactionData {
     /// The token for which to mint tokens.
@@ -82,6 +91,22 @@ impl TokenMintTransaction {
         self
     }
 
+    /// Sets the token and amount to mint, after checking them against `info`, the current info of the token.
+    ///
+    /// # Errors
+    /// - [`Error::TokenDecimalsMismatch`] if `amount` has different decimals than the token.
+    /// - [`Error::TokenMaxSupplyExceeded`] if minting `amount` would exceed the token's max supply.
+    pub fn checked_amount(
+        &mut self,
+        amount: TokenAmount,
+        info: &TokenInfo,
+    ) -> crate::Result<&mut Self> {
+        amount.check_decimals(info)?;
+        check_max_supply(info, amount.get_amount())?;
+
+        Ok(self.token_id(info.token_id).amount(amount.get_amount()))
+    }
+
     /// Returns the list of metadata for a non-fungible token to mint to the treasury account.
     #[must_use]
     pub fn get_metadata(&self) -> &[Vec<u8>] {
@@ -98,9 +123,63 @@ impl TokenMintTransaction {
 
         self
     }
+
+    /// Sets the token and metadata to mint, after checking them against `info`, the current info of the token.
+    ///
+    /// # Errors
+    /// - [`Error::TokenMaxSupplyExceeded`] if minting one NFT per metadata would exceed the token's max supply.
+    pub fn checked_metadata<Bytes>(
+        &mut self,
+        metadata: impl IntoIterator<Item = Bytes>,
+        info: &TokenInfo,
+    ) -> crate::Result<&mut Self>
+    where
+        Bytes: AsRef<[u8]>,
+    {
+        let metadata: Vec<Vec<u8>> =
+            metadata.into_iter().map(|bytes| bytes.as_ref().to_vec()).collect();
+
+        check_max_supply(info, metadata.len() as u64)?;
+
+        Ok(self.token_id(info.token_id).metadata(metadata))
+    }
 }
 
-impl TransactionData for TokenMintTransactionData {}
+/// Check that minting `amount` won't take the total supply of the token of `info` past its max supply.
+fn check_max_supply(info: &TokenInfo, amount: u64) -> crate::Result<()> {
+    let max_supply = match info.supply_type {
+        TokenSupplyType::Finite => info.max_supply,
+        TokenSupplyType::Infinite => MAX_TOKEN_SUPPLY,
+    };
+
+    match info.total_supply.checked_add(amount) {
+        Some(supply) if supply <= max_supply => Ok(()),
+        _ => Err(Error::TokenMaxSupplyExceeded {
+            token_id: info.token_id,
+            amount,
+            total_supply: info.total_supply,
+            max_supply,
+        }),
+    }
+}
+
+impl TransactionData for TokenMintTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "token_id", &self.token_id);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.optional_entity("token", self.token_id.as_ref());
+
+        if self.amount != 0 {
+            description.field("amount", self.amount);
+        }
+
+        if !self.metadata.is_empty() {
+            description.field("nfts", self.metadata.len());
+        }
+    }
+}
 
 impl TransactionExecute for TokenMintTransactionData {
     fn execute(
@@ -167,9 +246,11 @@ impl ToProtobuf for TokenMintTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services::TokenMintTransactionBody;
 
+    use super::MAX_TOKEN_SUPPLY;
     use crate::protobuf::{
         FromProtobuf,
         ToProtobuf,
@@ -181,8 +262,12 @@ mod tests {
     };
     use crate::{
         AnyTransaction,
+        Error,
+        TokenAmount,
         TokenId,
+        TokenInfo,
         TokenMintTransaction,
+        TokenSupplyType,
     };
 
     const TEST_TOKEN_ID: TokenId = TokenId::new(4, 2, 0);
@@ -354,4 +439,68 @@ mod tests {
         let mut tx = make_transaction();
         tx.metadata(metadata());
     }
+
+    #[test]
+    fn checked_amount() {
+        let info = TokenInfo::new_for_tests(TEST_TOKEN_ID, 2, 900, TokenSupplyType::Finite, 1000);
+
+        let mut tx = TokenMintTransaction::new();
+        tx.checked_amount(TokenAmount::new(100, 2), &info).unwrap();
+
+        assert_eq!(tx.get_token_id(), Some(TEST_TOKEN_ID));
+        assert_eq!(tx.get_amount(), 100);
+
+        assert_matches!(
+            tx.checked_amount(TokenAmount::new(101, 2), &info),
+            Err(Error::TokenMaxSupplyExceeded {
+                amount: 101,
+                total_supply: 900,
+                max_supply: 1000,
+                ..
+            })
+        );
+
+        assert_matches!(
+            tx.checked_amount(TokenAmount::new(1, 3), &info),
+            Err(Error::TokenDecimalsMismatch { expected: 2, actual: 3, .. })
+        );
+
+        // failed checks leave the transaction as it was.
+        assert_eq!(tx.get_amount(), 100);
+    }
+
+    #[test]
+    fn checked_amount_infinite_supply() {
+        let info = TokenInfo::new_for_tests(
+            TEST_TOKEN_ID,
+            0,
+            MAX_TOKEN_SUPPLY,
+            TokenSupplyType::Infinite,
+            0,
+        );
+
+        let mut tx = TokenMintTransaction::new();
+
+        assert_matches!(
+            tx.checked_amount(TokenAmount::new(1, 0), &info),
+            Err(Error::TokenMaxSupplyExceeded { .. })
+        );
+
+        assert!(tx.checked_amount(TokenAmount::new(0, 0), &info).is_ok());
+    }
+
+    #[test]
+    fn checked_metadata() {
+        let info = TokenInfo::new_for_tests(TEST_TOKEN_ID, 0, 9, TokenSupplyType::Finite, 10);
+
+        let mut tx = TokenMintTransaction::new();
+        tx.checked_metadata(metadata(), &info).unwrap();
+
+        assert_eq!(tx.get_metadata(), &metadata());
+
+        assert_matches!(
+            tx.checked_metadata([[1], [2]], &info),
+            Err(Error::TokenMaxSupplyExceeded { amount: 2, .. })
+        );
+    }
 }
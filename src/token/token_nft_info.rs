@@ -78,14 +78,14 @@ impl FromProtobuf<services::TokenNftInfo> for TokenNftInfo {
     {
         let nft_id = pb_getf!(pb, nft_id)?;
         let account_id = pb_getf!(pb, account_id)?;
-        let creation_time = pb.creation_time.unwrap();
+        let creation_time = pb_getf!(pb, creation_time)?;
         let metadata = pb.metadata;
         let spender_account_id = Option::from_protobuf(pb.spender_id)?;
 
         Ok(Self {
             nft_id: NftId::from_protobuf(nft_id)?,
             account_id: AccountId::from_protobuf(account_id)?,
-            creation_time: OffsetDateTime::from(creation_time),
+            creation_time: OffsetDateTime::from_protobuf(creation_time)?,
             metadata,
             spender_id: spender_account_id,
             ledger_id: LedgerId::from_bytes(pb.ledger_id),
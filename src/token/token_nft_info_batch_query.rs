@@ -0,0 +1,326 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use base64::Engine;
+
+use crate::mirror_rest::{
+    Links,
+    MirrorStream,
+};
+use crate::protobuf::time::parse_mirror_timestamp;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    LedgerId,
+    TokenId,
+    TokenNftInfo,
+};
+
+/// The number of NFTs requested per page.
+const PAGE_SIZE: u64 = 100;
+
+/// Get info on many NFTs of a token at once, by serial number, using the mirror node REST API.
+///
+/// Unlike [`TokenNftInfoQuery`](crate::TokenNftInfoQuery), which costs one query per serial,
+/// this fetches whole ranges of serials a page at a time.
+///
+/// NFTs are returned in ascending serial order, each at most once, even if the given ranges overlap.
+/// Serials that don't exist, or have been burned, are skipped.
+#[derive(Debug, Clone, Default)]
+pub struct TokenNftInfoBatchQuery {
+    token_id: Option<TokenId>,
+    serial_ranges: Vec<RangeInclusive<u64>>,
+    limit: u64,
+}
+
+impl TokenNftInfoBatchQuery {
+    /// Create a new query.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the token to get NFTs of.
+    #[must_use]
+    pub fn get_token_id(&self) -> Option<TokenId> {
+        self.token_id
+    }
+
+    /// Sets the token to get NFTs of.
+    pub fn token_id(&mut self, token_id: impl Into<TokenId>) -> &mut Self {
+        self.token_id = Some(token_id.into());
+        self
+    }
+
+    /// Returns the ranges of serials to get, as they were added.
+    #[must_use]
+    pub fn get_serial_ranges(&self) -> &[RangeInclusive<u64>] {
+        &self.serial_ranges
+    }
+
+    /// Sets the individual serials to get, replacing any serials or ranges added before.
+    ///
+    /// Defaults to every NFT of the token.
+    pub fn serials(&mut self, serials: impl IntoIterator<Item = u64>) -> &mut Self {
+        self.serial_ranges = serials.into_iter().map(|it| it..=it).collect();
+        self
+    }
+
+    /// Adds a single serial to get.
+    pub fn add_serial(&mut self, serial: u64) -> &mut Self {
+        self.serial_ranges.push(serial..=serial);
+        self
+    }
+
+    /// Adds an (inclusive) range of serials to get, IE, `1..=100`.
+    pub fn add_serial_range(&mut self, range: RangeInclusive<u64>) -> &mut Self {
+        self.serial_ranges.push(range);
+        self
+    }
+
+    /// Returns the maximum number of NFTs to return.
+    #[must_use]
+    pub fn get_limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Sets the maximum number of NFTs to return.
+    ///
+    /// Defaults to `0`, which returns every matching NFT.
+    pub fn limit(&mut self, limit: u64) -> &mut Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Execute this query against the mirror node of the provided client, following pagination.
+    ///
+    /// The [`ledger_id`](TokenNftInfo::ledger_id) of each result is the client's ledger ID, if it has one.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `token_id` isn't set, or the mirror node returns malformed NFTs.
+    /// - [`Error::MirrorRest`] if a mirror node request fails.
+    pub async fn execute(&self, client: &Client) -> crate::Result<Vec<TokenNftInfo>> {
+        self.stream(client).collect_all(self.limit).await
+    }
+
+    /// Returns a stream of the NFT info, fetching each page from the mirror node as the previous one is consumed.
+    ///
+    /// The stream ends after the first error.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `token_id` isn't set, or the mirror node returns malformed NFTs.
+    /// - [`Error::MirrorRest`] if a mirror node request fails.
+    pub fn stream(&self, client: &Client) -> MirrorStream<TokenNftInfo> {
+        let first_page_paths = self
+            .token_id
+            .ok_or_else(|| Error::basic_parse("`token_id` must be set to query NFT info"))
+            .map(|token_id| self.first_page_paths(token_id));
+
+        let ledger_id = client
+            .ledger_id_internal()
+            .as_deref()
+            .cloned()
+            .unwrap_or_else(|| LedgerId::from_bytes(Vec::new()));
+
+        client.rest_client().paginate(first_page_paths, self.limit, move |page: RestNfts| {
+            let ledger_id = ledger_id.clone();
+
+            let nfts = page
+                .nfts
+                .into_iter()
+                .filter(|it| !it.deleted)
+                .map(move |it| it.into_info(ledger_id.clone()));
+
+            (nfts, page.links.next)
+        })
+    }
+
+    /// Returns the serial ranges sorted, with overlapping and adjacent ranges merged.
+    fn merged_ranges(&self) -> Vec<RangeInclusive<u64>> {
+        let mut ranges: Vec<_> =
+            self.serial_ranges.iter().filter(|it| !it.is_empty()).cloned().collect();
+
+        ranges.sort_unstable_by_key(|it| *it.start());
+
+        let mut merged: Vec<RangeInclusive<u64>> = Vec::with_capacity(ranges.len());
+
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if *range.start() <= last.end().saturating_add(1) => {
+                    *last = *last.start()..=*last.end().max(range.end());
+                }
+                _ => merged.push(range),
+            }
+        }
+
+        merged
+    }
+
+    /// Returns the path of the first page of each range of serials.
+    fn first_page_paths(&self, token_id: TokenId) -> Vec<String> {
+        let limit = match self.limit {
+            0 => PAGE_SIZE,
+            limit => limit.min(PAGE_SIZE),
+        };
+
+        let base = format!("/tokens/{token_id}/nfts?order=asc&limit={limit}");
+
+        if self.serial_ranges.is_empty() {
+            return vec![base];
+        }
+
+        self.merged_ranges()
+            .into_iter()
+            .map(|range| {
+                if range.start() == range.end() {
+                    format!("{base}&serialnumber={}", range.start())
+                } else {
+                    format!(
+                        "{base}&serialnumber=gte:{}&serialnumber=lte:{}",
+                        range.start(),
+                        range.end()
+                    )
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestNfts {
+    nfts: Vec<RestNft>,
+    links: Links,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct RestNft {
+    account_id: Option<String>,
+    created_timestamp: String,
+    #[serde(default)]
+    deleted: bool,
+    #[serde(default)]
+    metadata: String,
+    serial_number: u64,
+    spender: Option<String>,
+    token_id: String,
+}
+
+impl RestNft {
+    fn into_info(self, ledger_id: LedgerId) -> crate::Result<TokenNftInfo> {
+        let account_id = self.account_id.ok_or_else(|| {
+            Error::basic_parse(format!("NFT {}/{} has no owner", self.token_id, self.serial_number))
+        })?;
+
+        Ok(TokenNftInfo {
+            nft_id: TokenId::from_str(&self.token_id)?.nft(self.serial_number),
+            account_id: AccountId::from_str(&account_id)?,
+            creation_time: parse_mirror_timestamp(&self.created_timestamp)?,
+            metadata: base64::engine::general_purpose::STANDARD
+                .decode(&self.metadata)
+                .map_err(Error::basic_parse)?,
+            spender_id: self.spender.as_deref().map(AccountId::from_str).transpose()?,
+            ledger_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use super::{
+        RestNfts,
+        TokenNftInfoBatchQuery,
+    };
+    use crate::mirror_rest::test_page;
+    use crate::{
+        AccountId,
+        LedgerId,
+        TokenId,
+    };
+
+    const TOKEN_ID: TokenId = TokenId::new(0, 0, 1234);
+
+    #[test]
+    fn first_page_paths() {
+        let mut query = TokenNftInfoBatchQuery::new();
+
+        assert_eq!(query.first_page_paths(TOKEN_ID), ["/tokens/0.0.1234/nfts?order=asc&limit=100"]);
+
+        query
+            .limit(5)
+            .add_serial_range(10..=20)
+            .add_serial(3)
+            .add_serial_range(15..=30)
+            .add_serial(31)
+            .add_serial_range(9..=1);
+
+        assert_eq!(
+            query.first_page_paths(TOKEN_ID),
+            [
+                "/tokens/0.0.1234/nfts?order=asc&limit=5&serialnumber=3",
+                "/tokens/0.0.1234/nfts?order=asc&limit=5&serialnumber=gte:10&serialnumber=lte:31",
+            ]
+        );
+    }
+
+    #[test]
+    fn serials_replace_ranges() {
+        let mut query = TokenNftInfoBatchQuery::new();
+        query.add_serial_range(1..=100).serials([7, 2]);
+
+        assert_eq!(query.get_serial_ranges(), [7..=7, 2..=2]);
+        assert_eq!(query.merged_ranges(), [2..=2, 7..=7]);
+    }
+
+    #[test]
+    fn parse_page() {
+        let page: RestNfts = test_page(
+            "nfts",
+            serde_json::json!([
+                {
+                    "account_id": "0.0.5",
+                    "created_timestamp": "1700000000.000000005",
+                    "delegating_spender": null,
+                    "deleted": false,
+                    "metadata": "3q2+7w==",
+                    "modified_timestamp": "1700000001.000000000",
+                    "serial_number": 2,
+                    "spender": "0.0.6",
+                    "token_id": "0.0.1234"
+                },
+                {
+                    "account_id": null,
+                    "created_timestamp": "1700000000.000000005",
+                    "deleted": true,
+                    "metadata": "",
+                    "serial_number": 3,
+                    "spender": null,
+                    "token_id": "0.0.1234"
+                }
+            ]),
+            Some("/api/v1/tokens/0.0.1234/nfts?limit=2&serialnumber=gt:3"),
+        );
+
+        assert!(page.nfts[1].deleted);
+
+        let info = page.nfts.into_iter().next().unwrap().into_info(LedgerId::testnet()).unwrap();
+
+        assert_eq!(info.nft_id, TOKEN_ID.nft(2));
+        assert_eq!(info.account_id, AccountId::new(0, 0, 5));
+        assert_eq!(
+            info.creation_time,
+            OffsetDateTime::UNIX_EPOCH + Duration::new(1_700_000_000, 5)
+        );
+        assert_eq!(info.metadata, [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(info.spender_id, Some(AccountId::new(0, 0, 6)));
+        assert_eq!(info.ledger_id, LedgerId::testnet());
+        assert!(page.links.next.is_some());
+    }
+}
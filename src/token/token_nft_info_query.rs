@@ -14,6 +14,7 @@ use crate::query::{
 use crate::{
     BoxGrpcFuture,
     Error,
+    FromProtobuf,
     NftId,
     ToProtobuf,
     TokenNftInfo,
@@ -63,6 +64,12 @@ impl ToQueryProtobuf for TokenNftInfoQueryData {
     }
 }
 
+impl FromProtobuf<services::TokenGetNftInfoQuery> for TokenNftInfoQueryData {
+    fn from_protobuf(pb: services::TokenGetNftInfoQuery) -> crate::Result<Self> {
+        Ok(Self { nft_id: Option::from_protobuf(pb.nft_id)? })
+    }
+}
+
 impl QueryExecute for TokenNftInfoQueryData {
     type Response = TokenNftInfo;
 
@@ -8,15 +8,20 @@ use crate::{
 
 /// Represents a transfer of an NFT from one account to another.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[non_exhaustive]
 pub struct TokenNftTransfer {
     /// The ID of the NFT's token.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::display"))]
     pub token_id: TokenId,
 
     /// The account that the NFT is being transferred from.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::display"))]
     pub sender: AccountId,
 
     /// The account that the NFT is being transferred to.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::display"))]
     pub receiver: AccountId,
 
     /// The serial number for the NFT being transferred.
@@ -10,12 +10,15 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -56,7 +59,15 @@ impl TokenPauseTransaction {
     }
 }
 
-impl TransactionData for TokenPauseTransactionData {}
+impl TransactionData for TokenPauseTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "token_id", &self.token_id);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.optional_entity("token", self.token_id.as_ref());
+    }
+}
 
 impl TransactionExecute for TokenPauseTransactionData {
     fn execute(
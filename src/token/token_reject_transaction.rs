@@ -15,7 +15,9 @@ use crate::transaction::{
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -101,7 +103,25 @@ impl TokenRejectTransaction {
     }
 }
 
-impl TransactionData for TokenRejectTransactionData {}
+impl TransactionData for TokenRejectTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        if self.token_ids.is_empty() && self.nft_ids.is_empty() {
+            problems.push(ValidationProblem::MissingField { field: "token_ids" });
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.optional_entity("owner", self.owner.as_ref());
+
+        for token_id in &self.token_ids {
+            description.entity("token", token_id);
+        }
+
+        for nft_id in &self.nft_ids {
+            description.entity("nft", nft_id);
+        }
+    }
+}
 
 impl TransactionExecute for TokenRejectTransactionData {
     fn execute(
@@ -10,12 +10,15 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -74,7 +77,18 @@ impl TokenUnfreezeTransaction {
     }
 }
 
-impl TransactionData for TokenUnfreezeTransactionData {}
+impl TransactionData for TokenUnfreezeTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "token_id", &self.token_id);
+        check_required(problems, "account_id", &self.account_id);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("token", self.token_id.as_ref())
+            .optional_entity("account", self.account_id.as_ref());
+    }
+}
 
 impl TransactionExecute for TokenUnfreezeTransactionData {
     fn execute(
@@ -10,12 +10,15 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -55,7 +58,15 @@ impl TokenUnpauseTransaction {
     }
 }
 
-impl TransactionData for TokenUnpauseTransactionData {}
+impl TransactionData for TokenUnpauseTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "token_id", &self.token_id);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.optional_entity("token", self.token_id.as_ref());
+    }
+}
 
 impl TransactionExecute for TokenUnpauseTransactionData {
     fn execute(
@@ -10,12 +10,15 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -85,7 +88,21 @@ impl TokenUpdateNftsTransaction {
     }
 }
 
-impl TransactionData for TokenUpdateNftsTransactionData {}
+impl TransactionData for TokenUpdateNftsTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "token_id", &self.token_id);
+
+        if self.serials.is_empty() {
+            problems.push(ValidationProblem::MissingField { field: "serials" });
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("token", self.token_id.as_ref())
+            .serials("serials", &self.serials);
+    }
+}
 
 impl TransactionExecute for TokenUpdateNftsTransactionData {
     fn execute(
@@ -15,12 +15,17 @@ use crate::protobuf::{
 };
 use crate::token::token_key_validation_type::TokenKeyValidation;
 use crate::transaction::{
+    check_memo,
+    check_required,
+    check_updated_key,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -369,7 +374,47 @@ impl TokenUpdateTransaction {
     }
 }
 
-impl TransactionData for TokenUpdateTransactionData {}
+impl TransactionData for TokenUpdateTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "token_id", &self.token_id);
+
+        if let Some(token_memo) = &self.token_memo {
+            check_memo(problems, "token_memo", token_memo);
+        }
+
+        let keys = [
+            ("admin_key", &self.admin_key),
+            ("kyc_key", &self.kyc_key),
+            ("freeze_key", &self.freeze_key),
+            ("wipe_key", &self.wipe_key),
+            ("supply_key", &self.supply_key),
+            ("fee_schedule_key", &self.fee_schedule_key),
+            ("pause_key", &self.pause_key),
+            ("metadata_key", &self.metadata_key),
+        ];
+
+        for (field, key) in keys {
+            if let Some(key) = key {
+                check_updated_key(problems, field, key);
+            }
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("token", self.token_id.as_ref())
+            .optional_entity("treasury", self.treasury_account_id.as_ref())
+            .optional_entity("auto_renew_account", self.auto_renew_account_id.as_ref())
+            .key("admin_key", self.admin_key.as_ref())
+            .key("kyc_key", self.kyc_key.as_ref())
+            .key("freeze_key", self.freeze_key.as_ref())
+            .key("wipe_key", self.wipe_key.as_ref())
+            .key("supply_key", self.supply_key.as_ref())
+            .key("fee_schedule_key", self.fee_schedule_key.as_ref())
+            .key("pause_key", self.pause_key.as_ref())
+            .key("metadata_key", self.metadata_key.as_ref());
+    }
+}
 
 impl TransactionExecute for TokenUpdateTransactionData {
     fn execute(
@@ -432,7 +477,7 @@ impl FromProtobuf<services::TokenUpdateTransactionBody> for TokenUpdateTransacti
             supply_key: Option::from_protobuf(pb.supply_key)?,
             auto_renew_account_id: Option::from_protobuf(pb.auto_renew_account)?,
             auto_renew_period: pb.auto_renew_period.map(Into::into),
-            expiration_time: pb.expiry.map(Into::into),
+            expiration_time: Option::from_protobuf(pb.expiry)?,
             token_memo: pb.memo,
             fee_schedule_key: Option::from_protobuf(pb.fee_schedule_key)?,
             pause_key: Option::from_protobuf(pb.pause_key)?,
@@ -10,12 +10,15 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -123,7 +126,20 @@ impl TokenWipeTransaction {
     }
 }
 
-impl TransactionData for TokenWipeTransactionData {}
+impl TransactionData for TokenWipeTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "token_id", &self.token_id);
+        check_required(problems, "account_id", &self.account_id);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("token", self.token_id.as_ref())
+            .optional_entity("account", self.account_id.as_ref())
+            .serials("serials", &self.serials)
+            .optional_field("amount", self.amount.as_ref());
+    }
+}
 
 impl TransactionExecute for TokenWipeTransactionData {
     fn execute(
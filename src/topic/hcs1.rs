@@ -0,0 +1,490 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+use std::io::Read;
+use std::str::FromStr;
+
+use base64::Engine;
+use sha2::{
+    Digest,
+    Sha256,
+};
+use time::OffsetDateTime;
+
+use crate::{
+    Client,
+    Error,
+    TopicCreateTransaction,
+    TopicId,
+    TopicInfoQuery,
+    TopicMessage,
+    TopicMessageQuery,
+    TopicMessageSubmitTransaction,
+};
+
+/// The only compression algorithm defined by HCS-1.
+const COMPRESSION: &str = "zstd";
+
+/// The only encoding defined by HCS-1.
+const ENCODING: &str = "base64";
+
+/// The maximum number of content bytes stored in a single message.
+///
+/// This leaves room for the JSON wrapper (`{"o":...,"c":"..."}`) while staying below the 1024 byte message limit.
+pub const MAX_CHUNK_CONTENT_SIZE: usize = 1000;
+
+/// The zstd compression level used when encoding files.
+const COMPRESSION_LEVEL: i32 = 10;
+
+/// The maximum size of a decoded file.
+///
+/// The payload is only a few bytes per message, so without a limit a tiny topic could decompress to gigabytes.
+pub const MAX_DECODED_SIZE: usize = 10 * 1024 * 1024;
+
+/// A single HCS-1 message as it appears on the topic.
+#[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+struct Chunk {
+    /// The order (index) of this chunk.
+    o: u64,
+
+    /// The content of this chunk.
+    c: String,
+}
+
+/// The memo of an HCS-1 topic, in the form `<sha256 hex>:zstd:base64`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hcs1Memo {
+    /// The SHA-256 hash of the uncompressed file.
+    pub hash: [u8; 32],
+}
+
+impl Hcs1Memo {
+    /// Create the memo for the given (uncompressed) file contents.
+    #[must_use]
+    pub fn for_contents(contents: &[u8]) -> Self {
+        Self { hash: Sha256::digest(contents).into() }
+    }
+
+    /// Returns true if `contents` hashes to the hash recorded in this memo.
+    #[must_use]
+    pub fn matches(&self, contents: &[u8]) -> bool {
+        self.hash == <[u8; 32]>::from(Sha256::digest(contents))
+    }
+}
+
+impl fmt::Display for Hcs1Memo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{COMPRESSION}:{ENCODING}", hex::encode(self.hash))
+    }
+}
+
+impl FromStr for Hcs1Memo {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(':');
+
+        let (Some(hash), Some(compression), Some(encoding), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(Error::basic_parse(format!(
+                "expecting an HCS-1 memo of the form `<hash>:{COMPRESSION}:{ENCODING}`, got `{s}`"
+            )));
+        };
+
+        if compression != COMPRESSION || encoding != ENCODING {
+            return Err(Error::basic_parse(format!(
+                "unsupported HCS-1 compression or encoding `{compression}:{encoding}`"
+            )));
+        }
+
+        let hash = hex::decode(hash).map_err(Error::basic_parse)?;
+        let hash = <[u8; 32]>::try_from(hash.as_slice()).map_err(|_| {
+            Error::basic_parse(format!("expected a 32 byte SHA-256 hash, got {} bytes", hash.len()))
+        })?;
+
+        Ok(Self { hash })
+    }
+}
+
+/// A file encoded according to HCS-1, ready to be written to a topic.
+#[derive(Debug, Clone)]
+pub struct Hcs1Encoded {
+    memo: Hcs1Memo,
+    messages: Vec<Vec<u8>>,
+}
+
+impl Hcs1Encoded {
+    /// Returns the memo that the topic must be created with.
+    #[must_use]
+    pub fn memo(&self) -> &Hcs1Memo {
+        &self.memo
+    }
+
+    /// Returns the messages that must be submitted to the topic.
+    ///
+    /// Each message fits into a single (unchunked) topic message.
+    #[must_use]
+    pub fn messages(&self) -> &[Vec<u8>] {
+        &self.messages
+    }
+
+    /// Returns a [`TopicCreateTransaction`] with the HCS-1 memo set.
+    ///
+    /// Keys, auto renew settings, and the like are left to the caller.
+    #[must_use]
+    pub fn topic_create_transaction(&self) -> TopicCreateTransaction {
+        let mut transaction = TopicCreateTransaction::new();
+        transaction.topic_memo(self.memo.to_string());
+        transaction
+    }
+
+    /// Returns one [`TopicMessageSubmitTransaction`] per message, targeting `topic_id`.
+    #[must_use]
+    pub fn submit_transactions(&self, topic_id: TopicId) -> Vec<TopicMessageSubmitTransaction> {
+        self.messages
+            .iter()
+            .map(|message| {
+                let mut transaction = TopicMessageSubmitTransaction::new();
+                transaction.topic_id(topic_id).message(message.clone());
+                transaction
+            })
+            .collect()
+    }
+}
+
+/// A file read back from an HCS-1 topic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hcs1File {
+    /// The mime type the file was stored with.
+    pub mime_type: String,
+
+    /// The (decompressed) contents of the file.
+    pub contents: Vec<u8>,
+}
+
+/// Encode `contents` as an HCS-1 file with the given `mime_type`.
+///
+/// `mime_type` must be a plain RFC 6838 `type/subtype` (IE, `image/png`), without parameters.
+///
+/// # Errors
+/// - [`Error::BasicParse`] if `mime_type` isn't a `type/subtype`.
+/// - [`Error::Compression`] if compressing the contents fails.
+pub fn encode(contents: &[u8], mime_type: &str) -> crate::Result<Hcs1Encoded> {
+    validate_mime_type(mime_type)?;
+
+    let compressed = zstd::encode_all(contents, COMPRESSION_LEVEL).map_err(Error::compression)?;
+
+    let data = format!(
+        "data:{mime_type};{ENCODING},{}",
+        base64::engine::general_purpose::STANDARD.encode(compressed)
+    );
+
+    let messages = split_content(&data)
+        .into_iter()
+        .enumerate()
+        .map(|(index, content)| {
+            let chunk = Chunk { o: index as u64, c: content.to_owned() };
+
+            serde_json::to_vec(&chunk).map_err(Error::basic_parse)
+        })
+        .collect::<crate::Result<_>>()?;
+
+    Ok(Hcs1Encoded { memo: Hcs1Memo::for_contents(contents), messages })
+}
+
+/// Check that `mime_type` is an RFC 6838 `type/subtype`, where both are restricted names.
+///
+/// This keeps the mime type from growing when the chunk containing it is JSON escaped,
+/// which could push that message over the 1024 byte limit.
+fn validate_mime_type(mime_type: &str) -> crate::Result<()> {
+    fn is_restricted_name(name: &str) -> bool {
+        let mut bytes = name.bytes();
+
+        name.len() <= 127
+            && bytes.next().is_some_and(|it| it.is_ascii_alphanumeric())
+            && bytes.all(|it| it.is_ascii_alphanumeric() || b"!#$&-^_.+".contains(&it))
+    }
+
+    match mime_type.split_once('/') {
+        Some((ty, subtype)) if is_restricted_name(ty) && is_restricted_name(subtype) => Ok(()),
+        _ => Err(Error::basic_parse(format!(
+            "expected an HCS-1 mime type of the form `type/subtype`, got `{mime_type}`"
+        ))),
+    }
+}
+
+/// Split `data` into pieces of at most [`MAX_CHUNK_CONTENT_SIZE`] bytes, without splitting a character.
+fn split_content(mut data: &str) -> Vec<&str> {
+    let mut pieces = Vec::new();
+
+    while !data.is_empty() {
+        let mut end = data.len().min(MAX_CHUNK_CONTENT_SIZE);
+
+        // the data is ASCII when it comes from `encode`, but this doesn't rely on it.
+        while !data.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        let (piece, rest) = data.split_at(end);
+        pieces.push(piece);
+        data = rest;
+    }
+
+    pieces
+}
+
+/// Reassemble an HCS-1 file from the messages of its topic.
+///
+/// Messages may be given in any order, duplicates (by order index) are ignored.
+///
+/// # Errors
+/// - [`Error::BasicParse`] if a message is not a valid HCS-1 chunk.
+/// - [`Error::BasicParse`] if there are missing chunks.
+/// - [`Error::BasicParse`] if the reassembled contents aren't a valid data URI.
+/// - [`Error::Compression`] if the payload can't be decompressed.
+/// - [`Error::BasicParse`] if the decompressed contents are larger than [`MAX_DECODED_SIZE`].
+/// - [`Error::BasicParse`] if the decoded contents don't match the hash in `memo`.
+pub fn decode<'a>(
+    memo: &Hcs1Memo,
+    messages: impl IntoIterator<Item = &'a [u8]>,
+) -> crate::Result<Hcs1File> {
+    let mut chunks = messages
+        .into_iter()
+        .map(|message| serde_json::from_slice::<Chunk>(message).map_err(Error::basic_parse))
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    chunks.sort_by_key(|it| it.o);
+    chunks.dedup_by_key(|it| it.o);
+
+    if let Some((index, _)) = chunks.iter().enumerate().find(|(index, it)| it.o != *index as u64) {
+        return Err(Error::basic_parse(format!("HCS-1 file is missing chunk {index}")));
+    }
+
+    let data: String = chunks.into_iter().map(|it| it.c).collect();
+
+    let (mime_type, payload) = data
+        .strip_prefix("data:")
+        .and_then(|it| it.split_once(','))
+        .ok_or_else(|| Error::basic_parse("HCS-1 file is missing its data URI header"))?;
+
+    let mime_type = mime_type
+        .strip_suffix(";base64")
+        .ok_or_else(|| Error::basic_parse(format!("HCS-1 data URI must be {ENCODING} encoded")))?;
+
+    let compressed =
+        base64::engine::general_purpose::STANDARD.decode(payload).map_err(Error::basic_parse)?;
+
+    let decoder =
+        zstd::stream::read::Decoder::new(compressed.as_slice()).map_err(Error::compression)?;
+
+    let mut contents = Vec::new();
+    decoder
+        .take(MAX_DECODED_SIZE as u64 + 1)
+        .read_to_end(&mut contents)
+        .map_err(Error::compression)?;
+
+    if contents.len() > MAX_DECODED_SIZE {
+        return Err(Error::basic_parse(format!(
+            "HCS-1 file is larger than the maximum of {MAX_DECODED_SIZE} bytes"
+        )));
+    }
+
+    if !memo.matches(&contents) {
+        return Err(Error::basic_parse("HCS-1 file contents don't match the topic memo hash"));
+    }
+
+    Ok(Hcs1File { mime_type: mime_type.to_owned(), contents })
+}
+
+/// Create a topic for `contents` and write the file to it, returning the new topic's ID.
+///
+/// The topic's submit key is set to the client's operator key so that nobody else can append chunks.
+///
+/// # Errors
+/// - [`Error::NoPayerAccountOrTransactionId`] if `client` has no operator.
+/// - See [`encode`].
+/// - See [`Transaction::execute`](crate::Transaction::execute) and [`TransactionResponse::get_receipt`](crate::TransactionResponse::get_receipt).
+pub async fn inscribe(client: &Client, contents: &[u8], mime_type: &str) -> crate::Result<TopicId> {
+    let operator_key =
+        client.get_operator_public_key().ok_or(Error::NoPayerAccountOrTransactionId)?;
+
+    let encoded = encode(contents, mime_type)?;
+
+    let topic_id = encoded
+        .topic_create_transaction()
+        .submit_key(operator_key)
+        .execute(client)
+        .await?
+        .get_receipt(client)
+        .await?
+        .topic_id
+        .ok_or_else(|| Error::from_protobuf("receipt for topic create had no topic ID"))?;
+
+    for mut transaction in encoded.submit_transactions(topic_id) {
+        transaction.execute(client).await?.get_receipt(client).await?;
+    }
+
+    Ok(topic_id)
+}
+
+/// Read the HCS-1 file stored on `topic_id`.
+///
+/// The topic memo is fetched with a [`TopicInfoQuery`] and the messages are read from the mirror network.
+///
+/// # Errors
+/// - [`Error::BasicParse`] if the topic memo is not an HCS-1 memo.
+/// - See [`decode`].
+/// - See [`TopicInfoQuery`] and [`TopicMessageQuery`].
+pub async fn retrieve(client: &Client, topic_id: TopicId) -> crate::Result<Hcs1File> {
+    let memo: Hcs1Memo =
+        TopicInfoQuery::new().topic_id(topic_id).execute(client).await?.topic_memo.parse()?;
+
+    let messages: Vec<TopicMessage> = TopicMessageQuery::new()
+        .topic_id(topic_id)
+        .start_time(OffsetDateTime::UNIX_EPOCH)
+        .end_time(client.clock().now())
+        .execute(client)
+        .await?;
+
+    decode(&memo, messages.iter().map(|it| it.contents.as_slice()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        decode,
+        encode,
+        inscribe,
+        split_content,
+        Hcs1Memo,
+        MAX_CHUNK_CONTENT_SIZE,
+        MAX_DECODED_SIZE,
+    };
+    use crate::{
+        Client,
+        Error,
+    };
+
+    #[test]
+    fn memo_round_trip() {
+        let memo = Hcs1Memo::for_contents(b"hello");
+
+        let s = memo.to_string();
+
+        assert!(s.ends_with(":zstd:base64"));
+        assert_eq!(s.parse::<Hcs1Memo>().unwrap(), memo);
+    }
+
+    #[test]
+    fn memo_rejects_unknown_compression() {
+        let hash = hex::encode([0; 32]);
+
+        assert!(format!("{hash}:brotli:base64").parse::<Hcs1Memo>().is_err());
+        assert!(format!("{hash}:zstd").parse::<Hcs1Memo>().is_err());
+    }
+
+    // incompressible-ish data so that we get more than one chunk.
+    fn noise(len: u32) -> Vec<u8> {
+        (0..len).map(|it| (it.wrapping_mul(2_654_435_761) >> 13) as u8).collect()
+    }
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let contents = noise(8192);
+
+        let encoded = encode(&contents, "application/octet-stream").unwrap();
+
+        assert!(encoded.messages().len() > 1);
+        assert!(encoded.messages().iter().all(|it| it.len() <= 1024));
+
+        // order shouldn't matter.
+        let file =
+            decode(encoded.memo(), encoded.messages().iter().rev().map(Vec::as_slice)).unwrap();
+
+        assert_eq!(file.mime_type, "application/octet-stream");
+        assert_eq!(file.contents, contents);
+    }
+
+    #[test]
+    fn decode_missing_chunk() {
+        let encoded = encode(&noise(8192), "image/png").unwrap();
+
+        assert!(
+            decode(encoded.memo(), encoded.messages().iter().skip(1).map(Vec::as_slice)).is_err()
+        );
+    }
+
+    #[test]
+    fn decode_hash_mismatch() {
+        let encoded = encode(b"hello", "text/plain").unwrap();
+
+        let memo = Hcs1Memo::for_contents(b"goodbye");
+
+        assert!(decode(&memo, encoded.messages().iter().map(Vec::as_slice)).is_err());
+    }
+
+    #[test]
+    fn decode_invalid_compression() {
+        let memo = Hcs1Memo::for_contents(b"hello");
+
+        let message = br#"{"o":0,"c":"data:text/plain;base64,aGVsbG8="}"#;
+
+        let result = decode(&memo, [message.as_slice()]);
+
+        assert!(matches!(result, Err(Error::Compression(_))), "{result:?}");
+    }
+
+    #[test]
+    fn decode_too_large() {
+        // compresses down to a handful of bytes, but must not be inflated past the limit.
+        let contents = vec![0; MAX_DECODED_SIZE + 1];
+
+        let encoded = encode(&contents, "application/octet-stream").unwrap();
+
+        let result = decode(encoded.memo(), encoded.messages().iter().map(Vec::as_slice));
+
+        assert!(matches!(result, Err(Error::BasicParse(_))), "{result:?}");
+
+        let contents = vec![0; MAX_DECODED_SIZE];
+
+        let encoded = encode(&contents, "application/octet-stream").unwrap();
+
+        let file = decode(encoded.memo(), encoded.messages().iter().map(Vec::as_slice)).unwrap();
+
+        assert_eq!(file.contents, contents);
+    }
+
+    #[test]
+    fn encode_rejects_invalid_mime_type() {
+        for mime_type in
+            ["text/\"plain\"", "text\\plain/x", "text/plain\u{1}", "text", "/plain", "text/é"]
+        {
+            let result = encode(b"hello", mime_type);
+
+            assert!(matches!(result, Err(Error::BasicParse(_))), "{mime_type:?}: {result:?}");
+        }
+
+        // a mime type that would be escaped is always rejected, so every message stays within the limit.
+        let encoded = encode(&noise(8192), "application/vnd.hcs-1+json").unwrap();
+
+        assert!(encoded.messages().iter().all(|it| it.len() <= 1024));
+    }
+
+    #[tokio::test]
+    async fn inscribe_without_operator() {
+        let result = inscribe(&Client::for_testnet(), b"hello", "text/plain").await;
+
+        assert!(matches!(result, Err(Error::NoPayerAccountOrTransactionId)), "{result:?}");
+    }
+
+    #[test]
+    fn split_content_respects_char_boundaries() {
+        let data = "é".repeat(MAX_CHUNK_CONTENT_SIZE);
+
+        let pieces = split_content(&data);
+
+        assert!(pieces.iter().all(|it| it.len() <= MAX_CHUNK_CONTENT_SIZE));
+        assert_eq!(pieces.concat(), data);
+    }
+}
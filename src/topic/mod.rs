@@ -1,27 +1,68 @@
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "hcs1")]
+/// HCS-1 (files stored on topics) encoding, inscription, and retrieval.
+pub mod hcs1;
+#[cfg(feature = "topics")]
 mod topic_create_transaction;
+#[cfg(feature = "topics")]
 mod topic_delete_transaction;
 mod topic_id;
+#[cfg(feature = "topics")]
 mod topic_info;
+#[cfg(feature = "topics")]
 mod topic_info_query;
+#[cfg(feature = "topics")]
 mod topic_message;
+#[cfg(feature = "topics")]
+mod topic_message_envelope;
+#[cfg(feature = "topics")]
 mod topic_message_query;
+#[cfg(feature = "topics")]
 mod topic_message_submit_transaction;
+#[cfg(feature = "topics")]
+mod topic_message_subscription;
+#[cfg(feature = "topics")]
+mod topic_subscriber;
+#[cfg(feature = "topics")]
 mod topic_update_transaction;
 
+#[cfg(feature = "topics")]
 pub use topic_create_transaction::TopicCreateTransaction;
+#[cfg(feature = "topics")]
 pub(crate) use topic_create_transaction::TopicCreateTransactionData;
+#[cfg(feature = "topics")]
 pub use topic_delete_transaction::TopicDeleteTransaction;
+#[cfg(feature = "topics")]
 pub(crate) use topic_delete_transaction::TopicDeleteTransactionData;
 pub use topic_id::TopicId;
+#[cfg(feature = "topics")]
 pub use topic_info::TopicInfo;
+#[cfg(feature = "topics")]
 pub use topic_info_query::TopicInfoQuery;
+#[cfg(feature = "topics")]
 pub(crate) use topic_info_query::TopicInfoQueryData;
+#[cfg(feature = "topics")]
 pub use topic_message::TopicMessage;
+#[cfg(feature = "topics")]
+pub use topic_message_envelope::TopicMessageEnvelope;
+#[cfg(feature = "topics")]
 pub use topic_message_query::TopicMessageQuery;
+#[cfg(feature = "topics")]
 pub(crate) use topic_message_query::TopicMessageQueryData;
+#[cfg(feature = "topics")]
 pub use topic_message_submit_transaction::TopicMessageSubmitTransaction;
+#[cfg(feature = "topics")]
 pub(crate) use topic_message_submit_transaction::TopicMessageSubmitTransactionData;
+#[cfg(feature = "topics")]
+pub use topic_message_subscription::{
+    SubscriptionMetrics,
+    SubscriptionOverflowPolicy,
+    TopicMessageSubscription,
+};
+#[cfg(feature = "topics")]
+pub use topic_subscriber::TopicSubscriber;
+#[cfg(feature = "topics")]
 pub use topic_update_transaction::TopicUpdateTransaction;
+#[cfg(feature = "topics")]
 pub(crate) use topic_update_transaction::TopicUpdateTransactionData;
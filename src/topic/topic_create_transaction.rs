@@ -12,12 +12,16 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_key,
+    check_memo,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -215,6 +219,30 @@ impl TransactionData for TopicCreateTransactionData {
     fn default_max_transaction_fee(&self) -> Hbar {
         Hbar::new(25)
     }
+
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_memo(problems, "topic_memo", &self.topic_memo);
+
+        let keys = [
+            ("admin_key", &self.admin_key),
+            ("submit_key", &self.submit_key),
+            ("fee_schedule_key", &self.fee_schedule_key),
+        ];
+
+        for (field, key) in keys {
+            if let Some(key) = key {
+                check_key(problems, field, key);
+            }
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("auto_renew_account", self.auto_renew_account_id.as_ref())
+            .key("admin_key", self.admin_key.as_ref())
+            .key("submit_key", self.submit_key.as_ref())
+            .key("fee_schedule_key", self.fee_schedule_key.as_ref());
+    }
 }
 
 impl TransactionExecute for TopicCreateTransactionData {
@@ -10,12 +10,15 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -54,7 +57,15 @@ impl TopicDeleteTransaction {
     }
 }
 
-impl TransactionData for TopicDeleteTransactionData {}
+impl TransactionData for TopicDeleteTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "topic_id", &self.topic_id);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.optional_entity("topic", self.topic_id.as_ref());
+    }
+}
 
 impl TransactionExecute for TopicDeleteTransactionData {
     fn execute(
@@ -97,7 +97,7 @@ impl FromProtobuf<services::ConsensusGetTopicInfoResponse> for TopicInfo {
         let info = pb_getf!(pb, topic_info)?;
         let admin_key = Option::from_protobuf(info.admin_key)?;
         let submit_key = Option::from_protobuf(info.submit_key)?;
-        let expiration_time = info.expiration_time.map(Into::into);
+        let expiration_time = Option::from_protobuf(info.expiration_time)?;
         let auto_renew_period = info.auto_renew_period.map(Into::into);
         let auto_renew_account_id = Option::from_protobuf(info.auto_renew_account)?;
         let ledger_id = LedgerId::from_bytes(info.ledger_id);
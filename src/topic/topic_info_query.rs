@@ -13,6 +13,7 @@ use crate::query::{
 use crate::{
     BoxGrpcFuture,
     Error,
+    FromProtobuf,
     Query,
     ToProtobuf,
     TopicId,
@@ -61,6 +62,12 @@ impl ToQueryProtobuf for TopicInfoQueryData {
     }
 }
 
+impl FromProtobuf<services::ConsensusGetTopicInfoQuery> for TopicInfoQueryData {
+    fn from_protobuf(pb: services::ConsensusGetTopicInfoQuery) -> crate::Result<Self> {
+        Ok(Self { topic_id: Option::from_protobuf(pb.topic_id)? })
+    }
+}
+
 impl QueryExecute for TopicInfoQueryData {
     type Response = TopicInfo;
 
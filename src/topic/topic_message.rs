@@ -4,7 +4,13 @@ use std::iter;
 
 use time::OffsetDateTime;
 
-use crate::TransactionId;
+use crate::{
+    Error,
+    PublicKey,
+    TopicId,
+    TopicMessageEnvelope,
+    TransactionId,
+};
 
 /// Metadata for an individual chunk
 #[non_exhaustive]
@@ -59,6 +65,51 @@ pub struct TopicMessage {
 }
 
 impl TopicMessage {
+    /// Parse the contents of this message as a [`TopicMessageEnvelope`] and verify its signature,
+    /// where `topic_id` is the topic this message was received from.
+    ///
+    /// Note that this only proves that the envelope's own key signed the payload,
+    /// use [`verify_envelope`](Self::verify_envelope) to check the producer against a known key.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the contents are not an envelope.
+    /// - [`Error::KeyParse`] if the public key in the envelope can't be parsed.
+    /// - [`Error::SignatureVerify`] if the signature is invalid, or was made for a different topic.
+    pub fn envelope(&self, topic_id: TopicId) -> crate::Result<TopicMessageEnvelope> {
+        let envelope = TopicMessageEnvelope::from_bytes(&self.contents)?;
+
+        envelope.verify(topic_id)?;
+
+        Ok(envelope)
+    }
+
+    /// Parse the contents of this message as a [`TopicMessageEnvelope`] and verify that it was signed by `public_key`,
+    /// where `topic_id` is the topic this message was received from.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the contents are not an envelope.
+    /// - [`Error::KeyParse`] if the public key in the envelope can't be parsed.
+    /// - [`Error::SignatureVerify`] if the envelope was signed by a different key, or the signature is invalid,
+    ///   or was made for a different topic.
+    pub fn verify_envelope(
+        &self,
+        topic_id: TopicId,
+        public_key: &PublicKey,
+    ) -> crate::Result<TopicMessageEnvelope> {
+        let envelope = TopicMessageEnvelope::from_bytes(&self.contents)?;
+
+        if envelope.public_key != *public_key {
+            return Err(Error::signature_verify(format!(
+                "topic message envelope was signed by `{}`, expected `{public_key}`",
+                envelope.public_key
+            )));
+        }
+
+        envelope.verify(topic_id)?;
+
+        Ok(envelope)
+    }
+
     pub(crate) fn from_single(pb: PbTopicMessageHeader) -> Self {
         Self {
             consensus_timestamp: pb.consensus_timestamp,
@@ -148,3 +199,77 @@ pub(crate) struct PbTopicMessageChunk {
     pub(crate) number: i32,
     pub(crate) total: i32,
 }
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use time::OffsetDateTime;
+
+    use super::{
+        PbTopicMessageHeader,
+        TopicMessage,
+    };
+    use crate::{
+        Error,
+        PrivateKey,
+        TopicId,
+        TopicMessageEnvelope,
+    };
+
+    const TOPIC_ID: TopicId = TopicId::new(0, 0, 5005);
+
+    fn make_message(contents: Vec<u8>) -> TopicMessage {
+        TopicMessage::from_single(PbTopicMessageHeader {
+            consensus_timestamp: OffsetDateTime::UNIX_EPOCH,
+            sequence_number: 1,
+            running_hash: Vec::new(),
+            running_hash_version: 3,
+            message: contents,
+        })
+    }
+
+    #[test]
+    fn verify_envelope() {
+        let key = PrivateKey::generate_ed25519();
+
+        let message =
+            make_message(TopicMessageEnvelope::sign(TOPIC_ID, b"hello".to_vec(), &key).to_bytes());
+
+        assert_eq!(message.verify_envelope(TOPIC_ID, &key.public_key()).unwrap().payload, b"hello");
+        assert_eq!(message.envelope(TOPIC_ID).unwrap().public_key, key.public_key());
+    }
+
+    #[test]
+    fn verify_envelope_wrong_key() {
+        let key = PrivateKey::generate_ed25519();
+        let other = PrivateKey::generate_ed25519();
+
+        let message =
+            make_message(TopicMessageEnvelope::sign(TOPIC_ID, b"hello".to_vec(), &key).to_bytes());
+
+        assert_matches!(
+            message.verify_envelope(TOPIC_ID, &other.public_key()),
+            Err(Error::SignatureVerify(_))
+        );
+    }
+
+    #[test]
+    fn verify_envelope_wrong_topic() {
+        let key = PrivateKey::generate_ed25519();
+
+        let message =
+            make_message(TopicMessageEnvelope::sign(TOPIC_ID, b"hello".to_vec(), &key).to_bytes());
+
+        assert_matches!(
+            message.verify_envelope(TopicId::new(0, 0, 5006), &key.public_key()),
+            Err(Error::SignatureVerify(_))
+        );
+    }
+
+    #[test]
+    fn verify_envelope_not_envelope() {
+        let message = make_message(b"hello".to_vec());
+
+        assert_matches!(message.envelope(TOPIC_ID), Err(Error::BasicParse(_)));
+    }
+}
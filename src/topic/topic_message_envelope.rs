@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    Error,
+    PrivateKey,
+    PublicKey,
+    TopicId,
+};
+
+/// Marks a topic message as an envelope (and the version of the envelope format).
+const MAGIC: &[u8; 4] = b"HME1";
+
+/// An application-level signed envelope around a topic message payload.
+///
+/// The payer of a [`TopicMessageSubmitTransaction`](crate::TopicMessageSubmitTransaction) says nothing about who *produced* the message,
+/// so producers can sign the payload with an application key, and consumers can check that signature with [`TopicMessage::verify_envelope`](crate::TopicMessage::verify_envelope).
+///
+/// The wire format is:
+/// - the 4 byte magic `HME1`
+/// - a big-endian `u16` length followed by the DER encoded public key of the signer
+/// - a big-endian `u16` length followed by the signature
+/// - the payload (everything that remains).
+///
+/// The signature covers the magic, the shard, realm and num of the topic (each as a big-endian `u64`) and the payload,
+/// so an envelope can't be replayed onto a different topic.
+/// The topic ID itself isn't part of the wire format, it's the topic the message was submitted to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopicMessageEnvelope {
+    /// The key that signed `payload`.
+    pub public_key: PublicKey,
+
+    /// The signature of `payload` (and the topic it was submitted to).
+    pub signature: Vec<u8>,
+
+    /// The application payload.
+    pub payload: Vec<u8>,
+}
+
+impl TopicMessageEnvelope {
+    /// Create a new envelope for a message to `topic_id` by signing `payload` with `key`.
+    #[must_use]
+    pub fn sign(topic_id: TopicId, payload: impl Into<Vec<u8>>, key: &PrivateKey) -> Self {
+        let payload = payload.into();
+
+        Self {
+            public_key: key.public_key(),
+            signature: key.sign(&signed_bytes(topic_id, &payload)),
+            payload,
+        }
+    }
+
+    /// Verify that `payload` was signed by `public_key` for a message to `topic_id`.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature is invalid for `public_key`,
+    ///   IE, the envelope was signed for a different topic.
+    pub fn verify(&self, topic_id: TopicId) -> crate::Result<()> {
+        self.public_key.verify(&signed_bytes(topic_id, &self.payload), &self.signature)
+    }
+
+    /// Returns true if `bytes` looks like an envelope (starts with the envelope magic).
+    ///
+    /// This doesn't validate the rest of the envelope.
+    #[must_use]
+    pub fn is_envelope(bytes: &[u8]) -> bool {
+        bytes.starts_with(MAGIC)
+    }
+
+    /// Parse an envelope from `bytes`.
+    ///
+    /// Note that this does *not* verify the signature, see [`verify`](Self::verify).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `bytes` isn't a well formed envelope.
+    /// - [`Error::KeyParse`] if the public key in the envelope can't be parsed.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let rest = bytes
+            .strip_prefix(MAGIC.as_slice())
+            .ok_or_else(|| Error::basic_parse("topic message is not an envelope"))?;
+
+        let (public_key, rest) = split_length_prefixed(rest)?;
+        let (signature, payload) = split_length_prefixed(rest)?;
+
+        Ok(Self {
+            public_key: PublicKey::from_bytes_der(public_key)?,
+            signature: signature.to_vec(),
+            payload: payload.to_vec(),
+        })
+    }
+
+    /// Convert this envelope to the bytes that get submitted as the topic message.
+    ///
+    /// # Panics
+    /// - If the signature is longer than `u16::MAX` bytes.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let public_key = self.public_key.to_bytes_der();
+
+        let mut bytes = Vec::with_capacity(
+            MAGIC.len() + 2 + public_key.len() + 2 + self.signature.len() + self.payload.len(),
+        );
+
+        bytes.extend_from_slice(MAGIC);
+        push_length_prefixed(&mut bytes, &public_key);
+        push_length_prefixed(&mut bytes, &self.signature);
+        bytes.extend_from_slice(&self.payload);
+
+        bytes
+    }
+}
+
+fn signed_bytes(topic_id: TopicId, payload: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 3 * 8 + payload.len());
+
+    bytes.extend_from_slice(MAGIC);
+
+    for it in [topic_id.shard, topic_id.realm, topic_id.num] {
+        bytes.extend_from_slice(&it.to_be_bytes());
+    }
+
+    bytes.extend_from_slice(payload);
+
+    bytes
+}
+
+fn push_length_prefixed(bytes: &mut Vec<u8>, value: &[u8]) {
+    let len = u16::try_from(value.len()).expect("envelope field too long");
+
+    bytes.extend_from_slice(&len.to_be_bytes());
+    bytes.extend_from_slice(value);
+}
+
+fn split_length_prefixed(bytes: &[u8]) -> crate::Result<(&[u8], &[u8])> {
+    let truncated = || Error::basic_parse("topic message envelope is truncated");
+
+    let (len, rest) = bytes.split_first_chunk::<2>().ok_or_else(truncated)?;
+    let len = usize::from(u16::from_be_bytes(*len));
+
+    if rest.len() < len {
+        return Err(truncated());
+    }
+
+    Ok(rest.split_at(len))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::TopicMessageEnvelope;
+    use crate::{
+        Error,
+        PrivateKey,
+        TopicId,
+    };
+
+    const PAYLOAD: &[u8] = b"hello, world";
+    const TOPIC_ID: TopicId = TopicId::new(0, 0, 5005);
+
+    #[test]
+    fn round_trip_ed25519() {
+        let key = PrivateKey::generate_ed25519();
+
+        let envelope = TopicMessageEnvelope::sign(TOPIC_ID, PAYLOAD, &key);
+        let bytes = envelope.to_bytes();
+
+        assert!(TopicMessageEnvelope::is_envelope(&bytes));
+
+        let envelope2 = TopicMessageEnvelope::from_bytes(&bytes).unwrap();
+
+        assert_eq!(envelope, envelope2);
+        assert_eq!(envelope2.public_key, key.public_key());
+        envelope2.verify(TOPIC_ID).unwrap();
+    }
+
+    #[test]
+    fn round_trip_ecdsa() {
+        let key = PrivateKey::generate_ecdsa();
+
+        let envelope = TopicMessageEnvelope::from_bytes(
+            &TopicMessageEnvelope::sign(TOPIC_ID, PAYLOAD, &key).to_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(envelope.payload, PAYLOAD);
+        envelope.verify(TOPIC_ID).unwrap();
+    }
+
+    #[test]
+    fn tampered_payload_fails() {
+        let key = PrivateKey::generate_ed25519();
+
+        let mut bytes = TopicMessageEnvelope::sign(TOPIC_ID, PAYLOAD, &key).to_bytes();
+        *bytes.last_mut().unwrap() ^= 1;
+
+        let envelope = TopicMessageEnvelope::from_bytes(&bytes).unwrap();
+
+        assert_matches!(envelope.verify(TOPIC_ID), Err(Error::SignatureVerify(_)));
+    }
+
+    #[test]
+    fn other_topic_fails() {
+        let key = PrivateKey::generate_ed25519();
+
+        let envelope = TopicMessageEnvelope::sign(TOPIC_ID, PAYLOAD, &key);
+
+        assert_matches!(envelope.verify(TopicId::new(0, 0, 5006)), Err(Error::SignatureVerify(_)));
+    }
+
+    #[test]
+    fn not_an_envelope() {
+        assert!(!TopicMessageEnvelope::is_envelope(PAYLOAD));
+        assert_matches!(TopicMessageEnvelope::from_bytes(PAYLOAD), Err(Error::BasicParse(_)));
+    }
+
+    #[test]
+    fn truncated() {
+        let key = PrivateKey::generate_ed25519();
+
+        let bytes = TopicMessageEnvelope::sign(TOPIC_ID, PAYLOAD, &key).to_bytes();
+
+        assert_matches!(TopicMessageEnvelope::from_bytes(&bytes[..10]), Err(Error::BasicParse(_)));
+    }
+}
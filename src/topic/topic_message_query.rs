@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::collections::HashMap;
+use std::num::NonZeroUsize;
 use std::{
     mem,
     task,
@@ -9,21 +10,26 @@ use std::{
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
 use futures_core::Stream;
+#[cfg(feature = "mirror-rest")]
+use futures_util::StreamExt;
 use futures_util::TryStreamExt;
 use hedera_proto::mirror;
 use hedera_proto::mirror::consensus_service_client::ConsensusServiceClient;
 use hedera_proto::mirror::ConsensusTopicQuery;
+#[cfg(feature = "mirror-rest")]
+use hedera_proto::services;
 use time::{
     Duration,
     OffsetDateTime,
 };
-use tonic::transport::Channel;
 use tonic::Response;
+use triomphe::Arc;
 
 use super::topic_message::{
     PbTopicMessageChunk,
     PbTopicMessageHeader,
 };
+use crate::client::MirrorChannel;
 use crate::mirror_query::{
     AnyMirrorQueryData,
     AnyMirrorQueryMessage,
@@ -32,10 +38,14 @@ use crate::mirror_query::{
 use crate::protobuf::FromProtobuf;
 use crate::{
     AnyMirrorQueryResponse,
+    Client,
+    Error,
     MirrorQuery,
+    SubscriptionOverflowPolicy,
     ToProtobuf,
     TopicId,
     TopicMessage,
+    TopicMessageSubscription,
     TransactionId,
 };
 
@@ -44,6 +54,9 @@ use crate::{
 
 // TODO: validate checksums after PR is merged
 
+/// The default capacity of the buffer used by [`TopicMessageQuery::subscribe_buffered`].
+const DEFAULT_BUFFER_CAPACITY: usize = 1000;
+
 #[derive(Default)]
 pub struct TopicMessageQueryContext {
     start_time: Option<OffsetDateTime>,
@@ -67,6 +80,12 @@ pub struct TopicMessageQueryData {
 
     /// The maximum number of messages to receive before stopping.
     limit: u64,
+
+    /// The maximum number of messages buffered by [`TopicMessageQuery::subscribe_buffered`].
+    buffer_capacity: Option<NonZeroUsize>,
+
+    /// What [`TopicMessageQuery::subscribe_buffered`] does when the buffer is full.
+    overflow_policy: SubscriptionOverflowPolicy,
 }
 
 impl TopicMessageQueryData {
@@ -76,6 +95,23 @@ impl TopicMessageQueryData {
     {
         MessagesMapStream { inner: stream, incomplete_messages: HashMap::new() }
     }
+
+    /// Like [`map_stream`](Self::map_stream), but ends after `limit` (reassembled) messages (`0` for no limit).
+    #[cfg(feature = "mirror-rest")]
+    fn map_stream_with_limit<S>(
+        stream: S,
+        limit: u64,
+    ) -> BoxStream<'static, crate::Result<TopicMessage>>
+    where
+        S: Stream<Item = crate::Result<mirror::ConsensusTopicResponse>> + Send + 'static,
+    {
+        let stream = Self::map_stream(stream);
+
+        match limit {
+            0 => Box::pin(stream),
+            limit => Box::pin(stream.take(usize::try_from(limit).unwrap_or(usize::MAX))),
+        }
+    }
 }
 
 impl TopicMessageQuery {
@@ -128,6 +164,154 @@ impl TopicMessageQuery {
         self.data.limit = limit;
         self
     }
+
+    /// Returns the maximum number of messages buffered by [`subscribe_buffered`](Self::subscribe_buffered).
+    #[must_use]
+    pub fn get_buffer_capacity(&self) -> usize {
+        self.data.buffer_capacity.map_or(DEFAULT_BUFFER_CAPACITY, NonZeroUsize::get)
+    }
+
+    /// Sets the maximum number of messages buffered by [`subscribe_buffered`](Self::subscribe_buffered).
+    ///
+    /// Defaults to 1000.
+    ///
+    /// # Panics
+    /// If `capacity` == 0
+    pub fn buffer_capacity(&mut self, capacity: usize) -> &mut Self {
+        let Some(capacity) = NonZeroUsize::new(capacity) else {
+            panic!("Cannot set buffer capacity to zero")
+        };
+
+        self.data.buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Returns what [`subscribe_buffered`](Self::subscribe_buffered) does when its buffer is full.
+    #[must_use]
+    pub fn get_overflow_policy(&self) -> SubscriptionOverflowPolicy {
+        self.data.overflow_policy
+    }
+
+    /// Sets what [`subscribe_buffered`](Self::subscribe_buffered) does when its buffer is full.
+    ///
+    /// Defaults to [`SubscriptionOverflowPolicy::Block`].
+    pub fn overflow_policy(&mut self, policy: SubscriptionOverflowPolicy) -> &mut Self {
+        self.data.overflow_policy = policy;
+        self
+    }
+
+    /// Subscribe to this query, first reading history from the mirror node REST API, then following live messages.
+    ///
+    /// Messages in the range [`start_time`](Self::start_time)..[`end_time`](Self::end_time) are read page by page
+    /// from the REST API (which is much faster for large backlogs than the gRPC subscription),
+    /// then the stream switches over to a gRPC subscription starting 1ns after the last historical message,
+    /// so no message is missed or delivered twice.
+    ///
+    /// Unlike [`subscribe`](Self::subscribe), if no start time is set, this starts at the first message of the topic.
+    ///
+    /// [`limit`](Self::limit) applies to the stream as a whole, and counts messages after chunks are reassembled.
+    #[cfg(feature = "mirror-rest")]
+    #[must_use]
+    pub fn backfill_then_follow(
+        &self,
+        client: &Client,
+    ) -> BoxStream<'static, crate::Result<TopicMessage>> {
+        let timeout = client.request_timeout().unwrap_or_else(|| {
+            std::time::Duration::from_millis(backoff::default::MAX_ELAPSED_TIME_MILLIS)
+        });
+
+        let mirrornet = client.mirrornet().load();
+        let rest = client.rest_client();
+        let channel = mirrornet.channel();
+        let logger = Arc::clone(client.logger());
+
+        let data = self.data.clone();
+
+        let stream = async_stream::stream! {
+            let Some(topic_id) = data.topic_id else {
+                yield Err(Error::basic_parse("`topic_id` must be set to backfill a topic"));
+                return;
+            };
+
+            let start_time = data.start_time.unwrap_or(OffsetDateTime::UNIX_EPOCH);
+
+            let path = rest_messages_path(topic_id, start_time, data.end_time);
+
+            // note: `limit` is applied after reassembly, the mirror node counts chunks.
+            let mut history = rest.paginate(Ok(vec![path]), 0, |page: RestTopicMessages| {
+                (page.messages.into_iter().map(RestTopicMessage::into_protobuf), page.links.next)
+            });
+
+            let mut last_timestamp = None;
+
+            while let Some(response) = history.next().await {
+                let response = match response {
+                    Ok(response) => response,
+                    Err(e) => {
+                        yield Err(e);
+                        return;
+                    }
+                };
+
+                last_timestamp = response
+                    .consensus_timestamp
+                    .and_then(|it| OffsetDateTime::from_protobuf(it).ok());
+
+                yield Ok(response);
+            }
+
+            let mut live = data.clone();
+
+            // note: always set, otherwise the mirror node would start at the current time (missing anything sent while backfilling).
+            live.start_time = Some(last_timestamp.map_or(start_time, |it| it + Duration::nanoseconds(1)));
+            live.limit = 0;
+
+            if live.end_time.is_some_and(|end_time| live.start_time >= Some(end_time)) {
+                return;
+            }
+
+            let mut live =
+                std::pin::pin!(crate::mirror_query::subscribe(channel, timeout, live, logger));
+
+            while let Some(item) = live.next().await {
+                yield item;
+            }
+        };
+
+        // note: the history and the live stream are mapped together so that chunks straddling the switch-over still get reassembled.
+        TopicMessageQueryData::map_stream_with_limit(stream, self.data.limit)
+    }
+
+    /// Subscribe to this query with the provided client, buffering messages in the background.
+    ///
+    /// Unlike [`subscribe`](Self::subscribe), the mirror node is read by a background task,
+    /// which buffers up to [`buffer_capacity`](Self::buffer_capacity) messages
+    /// and then applies the configured [`overflow_policy`](Self::overflow_policy).
+    ///
+    /// # Panics
+    /// If called outside of a tokio runtime.
+    #[must_use]
+    pub fn subscribe_buffered(&self, client: &Client) -> TopicMessageSubscription {
+        let timeout = client.request_timeout().unwrap_or_else(|| {
+            std::time::Duration::from_millis(backoff::default::MAX_ELAPSED_TIME_MILLIS)
+        });
+
+        // note: we don't care about keeping the mirrornet around, so, we just take the channel (which is arc-like)
+        let channel = client.mirrornet().load().channel();
+
+        let stream = TopicMessageQueryData::make_item_stream(crate::mirror_query::subscribe(
+            channel,
+            timeout,
+            self.data.clone(),
+            Arc::clone(client.logger()),
+        ));
+
+        TopicMessageSubscription::spawn(
+            stream,
+            self.get_buffer_capacity(),
+            self.data.overflow_policy,
+        )
+    }
 }
 
 impl From<TopicMessageQueryData> for AnyMirrorQueryData {
@@ -152,7 +336,7 @@ impl MirrorRequest for TopicMessageQueryData {
     fn connect(
         &self,
         context: &Self::Context,
-        channel: Channel,
+        channel: MirrorChannel,
     ) -> BoxFuture<'_, tonic::Result<Self::ConnectStream>> {
         let topic_id = self.topic_id.to_protobuf();
 
@@ -197,8 +381,10 @@ impl MirrorRequest for TopicMessageQueryData {
     }
 
     fn update_context(context: &mut Self::Context, item: &Self::GrpcItem) {
-        context.start_time =
-            item.consensus_timestamp.map(OffsetDateTime::from).or(context.start_time);
+        context.start_time = item
+            .consensus_timestamp
+            .and_then(|it| OffsetDateTime::from_protobuf(it).ok())
+            .or(context.start_time);
     }
 }
 
@@ -276,7 +462,7 @@ fn filter_map(
     incomplete_messages: &mut HashMap<TransactionId, IncompleteMessage>,
 ) -> crate::Result<Option<TopicMessage>> {
     let header = PbTopicMessageHeader {
-        consensus_timestamp: pb_getf!(item, consensus_timestamp)?.into(),
+        consensus_timestamp: OffsetDateTime::from_protobuf(pb_getf!(item, consensus_timestamp)?)?,
         sequence_number: item.sequence_number,
         running_hash: item.running_hash,
         running_hash_version: item.running_hash_version,
@@ -331,11 +517,109 @@ fn filter_map(
     }
 }
 
+#[cfg(feature = "mirror-rest")]
+fn rest_messages_path(
+    topic_id: TopicId,
+    start_time: OffsetDateTime,
+    end_time: Option<OffsetDateTime>,
+) -> String {
+    use crate::protobuf::time::format_mirror_timestamp;
+
+    let mut path = format!(
+        "/topics/{topic_id}/messages?order=asc&limit=100&timestamp=gte:{}",
+        format_mirror_timestamp(start_time)
+    );
+
+    if let Some(end_time) = end_time {
+        path.push_str(&format!("&timestamp=lt:{}", format_mirror_timestamp(end_time)));
+    }
+
+    path
+}
+
+#[cfg(feature = "mirror-rest")]
+#[derive(serde_derive::Deserialize)]
+struct RestTopicMessages {
+    messages: Vec<RestTopicMessage>,
+    links: crate::mirror_rest::Links,
+}
+
+#[cfg(feature = "mirror-rest")]
+#[derive(serde_derive::Deserialize)]
+struct RestTopicMessage {
+    consensus_timestamp: String,
+    message: String,
+    running_hash: String,
+    running_hash_version: u64,
+    sequence_number: u64,
+    chunk_info: Option<RestChunkInfo>,
+}
+
+#[cfg(feature = "mirror-rest")]
+#[derive(serde_derive::Deserialize)]
+struct RestChunkInfo {
+    initial_transaction_id: RestTransactionId,
+    number: i32,
+    total: i32,
+}
+
+#[cfg(feature = "mirror-rest")]
+#[derive(serde_derive::Deserialize)]
+struct RestTransactionId {
+    account_id: String,
+    nonce: i32,
+    scheduled: bool,
+    transaction_valid_start: String,
+}
+
+#[cfg(feature = "mirror-rest")]
+impl RestTopicMessage {
+    fn into_protobuf(self) -> crate::Result<mirror::ConsensusTopicResponse> {
+        use base64::Engine;
+
+        use crate::protobuf::time::parse_mirror_timestamp;
+
+        let decode = |it: &str| {
+            base64::engine::general_purpose::STANDARD.decode(it).map_err(Error::basic_parse)
+        };
+
+        let chunk_info = self
+            .chunk_info
+            .map(|chunk_info| -> crate::Result<_> {
+                let tx_id = chunk_info.initial_transaction_id;
+
+                let initial_transaction_id = TransactionId {
+                    account_id: tx_id.account_id.parse()?,
+                    valid_start: parse_mirror_timestamp(&tx_id.transaction_valid_start)?,
+                    nonce: (tx_id.nonce != 0).then_some(tx_id.nonce),
+                    scheduled: tx_id.scheduled,
+                };
+
+                Ok(services::ConsensusMessageChunkInfo {
+                    initial_transaction_id: Some(initial_transaction_id.to_protobuf()),
+                    total: chunk_info.total,
+                    number: chunk_info.number,
+                })
+            })
+            .transpose()?;
+
+        Ok(mirror::ConsensusTopicResponse {
+            consensus_timestamp: Some(parse_mirror_timestamp(&self.consensus_timestamp)?.into()),
+            message: decode(&self.message)?,
+            running_hash: decode(&self.running_hash)?,
+            sequence_number: self.sequence_number,
+            running_hash_version: self.running_hash_version,
+            chunk_info,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use time::OffsetDateTime;
 
     use crate::{
+        SubscriptionOverflowPolicy,
         TopicId,
         TopicMessageQuery,
     };
@@ -372,4 +656,144 @@ mod tests {
 
         assert_eq!(query.get_limit(), 1415);
     }
+
+    #[test]
+    fn get_set_buffer_capacity() {
+        let mut query = TopicMessageQuery::new();
+        assert_eq!(query.get_buffer_capacity(), 1000);
+
+        query.buffer_capacity(16);
+
+        assert_eq!(query.get_buffer_capacity(), 16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_buffer_capacity_zero_panics() {
+        TopicMessageQuery::new().buffer_capacity(0);
+    }
+
+    #[test]
+    fn get_set_overflow_policy() {
+        let mut query = TopicMessageQuery::new();
+        assert_eq!(query.get_overflow_policy(), SubscriptionOverflowPolicy::Block);
+
+        query.overflow_policy(SubscriptionOverflowPolicy::DropOldest);
+
+        assert_eq!(query.get_overflow_policy(), SubscriptionOverflowPolicy::DropOldest);
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    #[test]
+    fn rest_messages_path() {
+        let start_time = OffsetDateTime::UNIX_EPOCH + time::Duration::new(1_700_000_000, 5);
+
+        assert_eq!(
+            super::rest_messages_path(TopicId::new(0, 0, 7), start_time, None),
+            "/topics/0.0.7/messages?order=asc&limit=100&timestamp=gte:1700000000.000000005"
+        );
+
+        assert_eq!(
+            super::rest_messages_path(
+                TopicId::new(0, 0, 7),
+                start_time,
+                Some(start_time + time::Duration::seconds(1))
+            ),
+            "/topics/0.0.7/messages?order=asc&limit=100&timestamp=gte:1700000000.000000005&timestamp=lt:1700000001.000000005"
+        );
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    #[test]
+    fn rest_message_into_protobuf() {
+        let message: super::RestTopicMessage = serde_json::from_str(
+            r#"{
+                "chunk_info": {
+                    "initial_transaction_id": {
+                        "account_id": "0.0.2",
+                        "nonce": 0,
+                        "scheduled": false,
+                        "transaction_valid_start": "1700000000.000000001"
+                    },
+                    "number": 1,
+                    "total": 2
+                },
+                "consensus_timestamp": "1700000001.123456789",
+                "message": "aGVsbG8=",
+                "payer_account_id": "0.0.2",
+                "running_hash": "AAEC",
+                "running_hash_version": 3,
+                "sequence_number": 42,
+                "topic_id": "0.0.7"
+            }"#,
+        )
+        .unwrap();
+
+        let response = message.into_protobuf().unwrap();
+
+        assert_eq!(
+            OffsetDateTime::from(response.consensus_timestamp.unwrap()),
+            OffsetDateTime::UNIX_EPOCH + time::Duration::new(1_700_000_001, 123_456_789)
+        );
+        assert_eq!(response.message, b"hello");
+        assert_eq!(response.running_hash, [0, 1, 2]);
+        assert_eq!(response.running_hash_version, 3);
+        assert_eq!(response.sequence_number, 42);
+
+        let chunk_info = response.chunk_info.unwrap();
+        assert_eq!((chunk_info.number, chunk_info.total), (1, 2));
+        assert_eq!(chunk_info.initial_transaction_id.unwrap().nonce, 0);
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    #[tokio::test]
+    async fn limit_counts_reassembled_messages() {
+        use futures_util::TryStreamExt;
+        use hedera_proto::{
+            mirror,
+            services,
+        };
+
+        use super::TopicMessageQueryData;
+        use crate::{
+            AccountId,
+            ToProtobuf,
+            TransactionId,
+        };
+
+        let initial_transaction_id = TransactionId {
+            account_id: AccountId::new(0, 0, 2),
+            valid_start: OffsetDateTime::UNIX_EPOCH,
+            nonce: None,
+            scheduled: false,
+        };
+
+        let chunk = |number: i32, total: i32| mirror::ConsensusTopicResponse {
+            consensus_timestamp: Some(OffsetDateTime::UNIX_EPOCH.into()),
+            message: vec![number as u8],
+            running_hash: Vec::new(),
+            sequence_number: number as u64,
+            running_hash_version: 3,
+            chunk_info: Some(services::ConsensusMessageChunkInfo {
+                initial_transaction_id: Some(initial_transaction_id.to_protobuf()),
+                total,
+                number,
+            }),
+        };
+
+        // one message split over 3 chunks, then a single chunk message.
+        let responses = [chunk(1, 3), chunk(2, 3), chunk(3, 3), chunk(1, 1)];
+
+        let messages: Vec<_> = TopicMessageQueryData::map_stream_with_limit(
+            futures_util::stream::iter(responses.map(Ok)),
+            2,
+        )
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].contents, [1, 2, 3]);
+        assert_eq!(messages[1].contents, [1]);
+    }
 }
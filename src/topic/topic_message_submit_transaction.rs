@@ -13,6 +13,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_required,
     AnyTransactionData,
     ChunkData,
     ChunkInfo,
@@ -20,13 +21,17 @@ use crate::transaction::{
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
     TransactionExecuteChunked,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
     Error,
+    PrivateKey,
     TopicId,
+    TopicMessageEnvelope,
     Transaction,
     ValidateChecksums,
 };
@@ -74,6 +79,23 @@ impl TopicMessageSubmitTransaction {
         self.data_mut().chunk_data_mut().data = bytes.into();
         self
     }
+
+    /// Sets the topic ID to `topic_id` and the message to be submitted to `payload`
+    /// wrapped in a [`TopicMessageEnvelope`] signed by `key` for that topic.
+    ///
+    /// This lets consumers authenticate the producer of the message independently of the payer account,
+    /// see [`TopicMessage::verify_envelope`](crate::TopicMessage::verify_envelope).
+    pub fn signed_message(
+        &mut self,
+        topic_id: impl Into<TopicId>,
+        payload: impl Into<Vec<u8>>,
+        key: &PrivateKey,
+    ) -> &mut Self {
+        let topic_id = topic_id.into();
+
+        self.topic_id(topic_id)
+            .message(TopicMessageEnvelope::sign(topic_id, payload, key).to_bytes())
+    }
 }
 
 impl TransactionData for TopicMessageSubmitTransactionData {
@@ -84,6 +106,14 @@ impl TransactionData for TopicMessageSubmitTransactionData {
     fn wait_for_receipt(&self) -> bool {
         false
     }
+
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "topic_id", &self.topic_id);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description.optional_entity("topic", self.topic_id.as_ref());
+    }
 }
 
 impl ChunkedTransactionData for TopicMessageSubmitTransactionData {
@@ -175,7 +205,8 @@ impl FromProtobuf<Vec<services::ConsensusSubmitMessageTransactionBody>>
         let total_chunks = pb.len();
 
         let mut iter = pb.into_iter();
-        let pb_first = iter.next().expect("Empty transaction (should've been handled earlier)");
+        let pb_first =
+            iter.next().ok_or_else(|| Error::from_protobuf("transaction had no chunks"))?;
 
         let topic_id = Option::from_protobuf(pb_first.topic_id)?;
 
@@ -196,6 +227,7 @@ impl FromProtobuf<Vec<services::ConsensusSubmitMessageTransactionBody>>
                 chunk_size: NonZeroUsize::new(largest_chunk_size)
                     .unwrap_or_else(|| NonZeroUsize::new(1).unwrap()),
                 data: message,
+                sticky_node: false,
             },
         })
     }
@@ -211,7 +243,9 @@ mod tests {
     };
     use crate::{
         AnyTransaction,
+        PrivateKey,
         TopicId,
+        TopicMessageEnvelope,
         TopicMessageSubmitTransaction,
     };
 
@@ -324,6 +358,22 @@ mod tests {
         assert_eq!(tx.get_message(), Some(MESSAGE));
     }
 
+    #[test]
+    fn signed_message() {
+        let key = PrivateKey::generate_ed25519();
+
+        let mut tx = TopicMessageSubmitTransaction::new();
+        tx.signed_message(TOPIC_ID, MESSAGE, &key);
+
+        assert_eq!(tx.get_topic_id(), Some(TOPIC_ID));
+
+        let envelope = TopicMessageEnvelope::from_bytes(tx.get_message().unwrap()).unwrap();
+
+        assert_eq!(envelope.payload, MESSAGE);
+        assert_eq!(envelope.public_key, key.public_key());
+        envelope.verify(TOPIC_ID).unwrap();
+    }
+
     #[test]
     #[should_panic]
     fn get_set_topic_id_frozen_panics() {
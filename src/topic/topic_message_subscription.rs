@@ -0,0 +1,361 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{
+    self,
+    Poll,
+    Waker,
+};
+
+use futures_core::stream::BoxStream;
+use futures_core::Stream;
+use futures_util::StreamExt;
+use parking_lot::Mutex;
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+use triomphe::Arc;
+
+use crate::{
+    Error,
+    TopicMessage,
+};
+
+/// What a buffered topic subscription does when its buffer is full.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SubscriptionOverflowPolicy {
+    /// Discard the oldest buffered message to make room for the new one, buffered errors are never discarded.
+    DropOldest,
+
+    /// Stop reading from the mirror node until the consumer catches up.
+    ///
+    /// This pushes the backpressure onto the mirror node connection.
+    #[default]
+    Block,
+
+    /// Fail the subscription with [`Error::SubscriptionBufferOverflow`].
+    ///
+    /// Messages buffered before the overflow are still delivered before the error.
+    Error,
+}
+
+/// A snapshot of the state of a [`TopicMessageSubscription`]'s buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SubscriptionMetrics {
+    /// The number of items currently waiting in the buffer.
+    pub buffered: usize,
+
+    /// The maximum number of items the buffer can hold.
+    pub capacity: usize,
+
+    /// The total number of messages received from the mirror node.
+    pub received: u64,
+
+    /// The total number of messages discarded due to [`SubscriptionOverflowPolicy::DropOldest`].
+    pub dropped: u64,
+
+    /// How far (in consensus time) the oldest buffered message is behind the newest received message.
+    ///
+    /// This is zero when the buffer is empty.
+    pub lag: Duration,
+}
+
+struct State {
+    queue: VecDeque<crate::Result<TopicMessage>>,
+    waker: Option<Waker>,
+    closed: bool,
+    received: u64,
+    dropped: u64,
+    newest_received: Option<OffsetDateTime>,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    space_available: Notify,
+    capacity: usize,
+    policy: SubscriptionOverflowPolicy,
+}
+
+impl Shared {
+    /// Push `item` into the buffer according to the overflow policy.
+    ///
+    /// Returns `false` if the subscription should stop.
+    async fn push(&self, item: crate::Result<TopicMessage>) -> bool {
+        let mut item = Some(item);
+
+        loop {
+            // note: this is split out so that the lock can't be held across the `await`.
+            if let Some(keep_going) = self.try_push(&mut item) {
+                return keep_going;
+            }
+
+            self.space_available.notified().await;
+        }
+    }
+
+    /// Returns `None` if the buffer is full and the policy is to block.
+    fn try_push(&self, item: &mut Option<crate::Result<TopicMessage>>) -> Option<bool> {
+        let mut state = self.state.lock();
+
+        if state.queue.len() >= self.capacity {
+            match self.policy {
+                SubscriptionOverflowPolicy::Block => return None,
+                SubscriptionOverflowPolicy::DropOldest => {
+                    // errors are never dropped, the consumer has to see them.
+                    if let Some(index) = state.queue.iter().position(Result::is_ok) {
+                        state.queue.remove(index);
+                        state.dropped += 1;
+                    }
+                }
+                SubscriptionOverflowPolicy::Error => {
+                    state.queue.push_back(Err(Error::SubscriptionBufferOverflow {
+                        capacity: self.capacity,
+                    }));
+                    Self::wake(&mut state);
+                    return Some(false);
+                }
+            }
+        }
+
+        let item = item.take().unwrap();
+
+        if let Ok(message) = &item {
+            state.received += 1;
+            state.newest_received = Some(message.consensus_timestamp);
+        }
+
+        state.queue.push_back(item);
+        Self::wake(&mut state);
+
+        Some(true)
+    }
+
+    fn close(&self) {
+        let mut state = self.state.lock();
+        state.closed = true;
+        Self::wake(&mut state);
+    }
+
+    fn wake(state: &mut State) {
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// A topic message subscription that buffers messages between the mirror node and the consumer.
+///
+/// Created by [`TopicMessageQuery::subscribe_buffered`](crate::TopicMessageQuery::subscribe_buffered).
+///
+/// The mirror node is read by a background task which is stopped when this is dropped.
+pub struct TopicMessageSubscription {
+    shared: Arc<Shared>,
+    task: JoinHandle<()>,
+}
+
+impl TopicMessageSubscription {
+    pub(crate) fn spawn(
+        stream: BoxStream<'static, crate::Result<TopicMessage>>,
+        capacity: usize,
+        policy: SubscriptionOverflowPolicy,
+    ) -> Self {
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State {
+                queue: VecDeque::with_capacity(capacity),
+                waker: None,
+                closed: false,
+                received: 0,
+                dropped: 0,
+                newest_received: None,
+            }),
+            space_available: Notify::new(),
+            capacity,
+            policy,
+        });
+
+        let task = tokio::task::spawn(Self::fill(Arc::clone(&shared), stream));
+
+        Self { shared, task }
+    }
+
+    async fn fill(
+        shared: Arc<Shared>,
+        mut stream: BoxStream<'static, crate::Result<TopicMessage>>,
+    ) {
+        while let Some(item) = stream.next().await {
+            if !shared.push(item).await {
+                break;
+            }
+        }
+
+        shared.close();
+    }
+
+    /// Returns a snapshot of the buffer's current state.
+    #[must_use]
+    pub fn metrics(&self) -> SubscriptionMetrics {
+        let state = self.shared.state.lock();
+
+        let oldest_buffered = state.queue.iter().find_map(|it| it.as_ref().ok());
+
+        let lag = match (oldest_buffered, state.newest_received) {
+            (Some(oldest), Some(newest)) => newest - oldest.consensus_timestamp,
+            _ => Duration::ZERO,
+        };
+
+        SubscriptionMetrics {
+            buffered: state.queue.len(),
+            capacity: self.shared.capacity,
+            received: state.received,
+            dropped: state.dropped,
+            lag,
+        }
+    }
+
+    /// Returns the overflow policy of this subscription.
+    #[must_use]
+    pub fn overflow_policy(&self) -> SubscriptionOverflowPolicy {
+        self.shared.policy
+    }
+}
+
+impl Stream for TopicMessageSubscription {
+    type Item = crate::Result<TopicMessage>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = self.shared.state.lock();
+
+        if let Some(item) = state.queue.pop_front() {
+            drop(state);
+            self.shared.space_available.notify_one();
+            return Poll::Ready(Some(item));
+        }
+
+        if state.closed {
+            return Poll::Ready(None);
+        }
+
+        state.waker = Some(cx.waker().clone());
+
+        Poll::Pending
+    }
+}
+
+impl Drop for TopicMessageSubscription {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use futures_util::StreamExt;
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use super::{
+        SubscriptionOverflowPolicy,
+        TopicMessageSubscription,
+    };
+    use crate::topic::topic_message::PbTopicMessageHeader;
+    use crate::{
+        Error,
+        TopicMessage,
+    };
+
+    fn message(sequence_number: u64) -> crate::Result<TopicMessage> {
+        Ok(TopicMessage::from_single(PbTopicMessageHeader {
+            consensus_timestamp: OffsetDateTime::UNIX_EPOCH
+                + Duration::seconds(sequence_number as i64),
+            sequence_number,
+            running_hash: Vec::new(),
+            running_hash_version: 3,
+            message: Vec::new(),
+        }))
+    }
+
+    fn source(count: u64) -> futures_core::stream::BoxStream<'static, crate::Result<TopicMessage>> {
+        Box::pin(futures_util::stream::iter((1..=count).map(message)))
+    }
+
+    async fn wait_until_closed(subscription: &TopicMessageSubscription) {
+        while !subscription.shared.state.lock().closed {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn block_delivers_everything() {
+        let subscription =
+            TopicMessageSubscription::spawn(source(10), 2, SubscriptionOverflowPolicy::Block);
+
+        let messages: Vec<_> = subscription.map(|it| it.unwrap().sequence_number).collect().await;
+
+        assert_eq!(messages, (1..=10).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_keeps_newest() {
+        let subscription =
+            TopicMessageSubscription::spawn(source(10), 3, SubscriptionOverflowPolicy::DropOldest);
+
+        wait_until_closed(&subscription).await;
+
+        let metrics = subscription.metrics();
+        assert_eq!(metrics.buffered, 3);
+        assert_eq!(metrics.received, 10);
+        assert_eq!(metrics.dropped, 7);
+        assert_eq!(metrics.lag, Duration::seconds(2));
+
+        let messages: Vec<_> = subscription.map(|it| it.unwrap().sequence_number).collect().await;
+
+        assert_eq!(messages, [8, 9, 10]);
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_keeps_errors() {
+        let items = (1..=2)
+            .map(message)
+            .chain([Err(Error::GrpcStatus(tonic::Status::unavailable("stream failed")))])
+            .chain((3..=6).map(message));
+
+        let subscription = TopicMessageSubscription::spawn(
+            Box::pin(futures_util::stream::iter(items)),
+            3,
+            SubscriptionOverflowPolicy::DropOldest,
+        );
+
+        wait_until_closed(&subscription).await;
+
+        assert_eq!(subscription.metrics().dropped, 4);
+
+        let items: Vec<_> = subscription.collect().await;
+
+        assert_eq!(items.len(), 3);
+        assert_matches!(items[0], Err(Error::GrpcStatus(_)));
+        assert_eq!(items[1].as_ref().unwrap().sequence_number, 5);
+        assert_eq!(items[2].as_ref().unwrap().sequence_number, 6);
+    }
+
+    #[tokio::test]
+    async fn error_fails_subscription() {
+        let subscription =
+            TopicMessageSubscription::spawn(source(10), 3, SubscriptionOverflowPolicy::Error);
+
+        wait_until_closed(&subscription).await;
+
+        let items: Vec<_> = subscription.collect().await;
+
+        assert_eq!(items.len(), 4);
+        assert!(items[..3].iter().all(Result::is_ok));
+        assert_matches!(items[3], Err(Error::SubscriptionBufferOverflow { capacity: 3 }));
+    }
+}
@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use futures_core::stream::BoxStream;
+use futures_util::StreamExt;
+use parking_lot::Mutex;
+use tokio::task::JoinHandle;
+use triomphe::Arc;
+
+use crate::client::MirrorChannel;
+use crate::logger::ClientLogger;
+use crate::mirror_query::MirrorRequest;
+use crate::topic::TopicMessageQueryData;
+use crate::{
+    Client,
+    Error,
+    TopicId,
+    TopicMessage,
+    TopicMessageQuery,
+};
+
+/// Manages subscriptions to many topics, routing each topic's messages to its own handler.
+///
+/// All subscriptions share the client's mirror node connection
+/// (each topic is a separate stream multiplexed over that connection),
+/// and topics can be added and removed at any time.
+///
+/// Each topic is read by a background task, handlers for different topics may run concurrently,
+/// but a single topic's handler is only ever called for one message at a time, in order.
+///
+/// All subscriptions are stopped when this is dropped.
+pub struct TopicSubscriber {
+    channel: MirrorChannel,
+    timeout: std::time::Duration,
+    logger: Arc<ClientLogger>,
+    subscriptions: Mutex<HashMap<TopicId, JoinHandle<()>>>,
+}
+
+impl TopicSubscriber {
+    /// Create a new subscriber which uses `client`'s mirror network.
+    #[must_use]
+    pub fn new(client: &Client) -> Self {
+        let timeout = client.request_timeout().unwrap_or_else(|| {
+            std::time::Duration::from_millis(backoff::default::MAX_ELAPSED_TIME_MILLIS)
+        });
+
+        Self {
+            channel: client.mirrornet().load().channel(),
+            timeout,
+            logger: Arc::clone(client.logger()),
+            subscriptions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Subscribe to `topic_id` (starting at the current time), calling `handler` for each message and error.
+    ///
+    /// If the topic was already subscribed to, the old subscription is replaced.
+    ///
+    /// # Panics
+    /// If called outside of a tokio runtime.
+    pub fn add_topic<F>(&self, topic_id: TopicId, handler: F)
+    where
+        F: FnMut(crate::Result<TopicMessage>) + Send + 'static,
+    {
+        self.subscribe(topic_id, TopicMessageQuery::new().topic_id(topic_id).data.clone(), handler);
+    }
+
+    /// Subscribe using `query`, calling `handler` for each message and error.
+    ///
+    /// This allows setting the start time, end time, and limit of the subscription.
+    ///
+    /// If the query's topic was already subscribed to, the old subscription is replaced.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `query` has no topic ID.
+    ///
+    /// # Panics
+    /// If called outside of a tokio runtime.
+    pub fn add_query<F>(&self, query: &TopicMessageQuery, handler: F) -> crate::Result<()>
+    where
+        F: FnMut(crate::Result<TopicMessage>) + Send + 'static,
+    {
+        let topic_id = query
+            .get_topic_id()
+            .ok_or_else(|| Error::basic_parse("`topic_id` must be set to subscribe to a topic"))?;
+
+        self.subscribe(topic_id, query.data.clone(), handler);
+
+        Ok(())
+    }
+
+    fn subscribe<F>(&self, topic_id: TopicId, data: TopicMessageQueryData, handler: F)
+    where
+        F: FnMut(crate::Result<TopicMessage>) + Send + 'static,
+    {
+        let stream = TopicMessageQueryData::make_item_stream(crate::mirror_query::subscribe(
+            self.channel.clone(),
+            self.timeout,
+            data,
+            Arc::clone(&self.logger),
+        ));
+
+        self.insert(topic_id, stream, handler);
+    }
+
+    fn insert<F>(
+        &self,
+        topic_id: TopicId,
+        mut stream: BoxStream<'static, crate::Result<TopicMessage>>,
+        mut handler: F,
+    ) where
+        F: FnMut(crate::Result<TopicMessage>) + Send + 'static,
+    {
+        let task = tokio::task::spawn(async move {
+            while let Some(item) = stream.next().await {
+                handler(item);
+            }
+        });
+
+        if let Some(old) = self.subscriptions.lock().insert(topic_id, task) {
+            old.abort();
+        }
+    }
+
+    /// Stop the subscription to `topic_id`.
+    ///
+    /// Returns `false` if there was no subscription to the topic.
+    pub fn remove_topic(&self, topic_id: TopicId) -> bool {
+        match self.subscriptions.lock().remove(&topic_id) {
+            Some(task) => {
+                task.abort();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns true if there's a subscription to `topic_id` that is still running.
+    ///
+    /// Subscriptions stop running once they reach their end time or limit, or fail.
+    #[must_use]
+    pub fn is_active(&self, topic_id: TopicId) -> bool {
+        self.subscriptions.lock().get(&topic_id).is_some_and(|task| !task.is_finished())
+    }
+
+    /// Returns the topics that are currently subscribed to.
+    #[must_use]
+    pub fn topics(&self) -> Vec<TopicId> {
+        self.subscriptions.lock().keys().copied().collect()
+    }
+
+    /// Stop all subscriptions.
+    pub fn clear(&self) {
+        for (_, task) in self.subscriptions.lock().drain() {
+            task.abort();
+        }
+    }
+}
+
+impl Drop for TopicSubscriber {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use futures_util::StreamExt;
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use crate::topic::topic_message::PbTopicMessageHeader;
+    use crate::{
+        Client,
+        Error,
+        TopicId,
+        TopicMessage,
+        TopicMessageQuery,
+        TopicSubscriber,
+    };
+
+    fn message(sequence_number: u64) -> crate::Result<TopicMessage> {
+        Ok(TopicMessage::from_single(PbTopicMessageHeader {
+            consensus_timestamp: OffsetDateTime::UNIX_EPOCH
+                + Duration::seconds(sequence_number as i64),
+            sequence_number,
+            running_hash: Vec::new(),
+            running_hash_version: 3,
+            message: Vec::new(),
+        }))
+    }
+
+    #[tokio::test]
+    async fn routes_to_topic_handlers() {
+        let subscriber = TopicSubscriber::new(&Client::for_testnet());
+
+        let (sender, receiver) = mpsc::channel();
+
+        for topic in [1, 2] {
+            let sender = sender.clone();
+            subscriber.insert(
+                TopicId::new(0, 0, topic),
+                futures_util::stream::iter((1..=3).map(message)).boxed(),
+                move |item| sender.send((topic, item.unwrap().sequence_number)).unwrap(),
+            );
+        }
+
+        drop(sender);
+
+        while subscriber.topics().iter().any(|&it| subscriber.is_active(it)) {
+            tokio::task::yield_now().await;
+        }
+
+        let mut received: Vec<_> = receiver.iter().collect();
+        received.sort_unstable();
+
+        assert_eq!(received, [(1, 1), (1, 2), (1, 3), (2, 1), (2, 2), (2, 3)]);
+    }
+
+    #[tokio::test]
+    async fn add_remove_topics() {
+        let subscriber = TopicSubscriber::new(&Client::for_testnet());
+
+        let topic = TopicId::new(0, 0, 5);
+
+        subscriber.insert(topic, futures_util::stream::pending().boxed(), |_| {});
+
+        assert_eq!(subscriber.topics(), [topic]);
+        assert!(subscriber.is_active(topic));
+
+        assert!(subscriber.remove_topic(topic));
+        assert!(!subscriber.remove_topic(topic));
+
+        assert!(subscriber.topics().is_empty());
+        assert!(!subscriber.is_active(topic));
+    }
+
+    #[tokio::test]
+    async fn add_query_without_topic_id() {
+        let subscriber = TopicSubscriber::new(&Client::for_testnet());
+
+        let result = subscriber.add_query(&TopicMessageQuery::new(), |_| {});
+
+        assert!(matches!(result, Err(Error::BasicParse(_))), "{result:?}");
+        assert!(subscriber.topics().is_empty());
+    }
+}
@@ -15,12 +15,18 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    check_key,
+    check_memo,
+    check_required,
+    check_updated_key,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -247,7 +253,44 @@ impl TopicUpdateTransaction {
     }
 }
 
-impl TransactionData for TopicUpdateTransactionData {}
+impl TransactionData for TopicUpdateTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        check_required(problems, "topic_id", &self.topic_id);
+
+        if let Some(topic_memo) = &self.topic_memo {
+            check_memo(problems, "topic_memo", topic_memo);
+        }
+
+        let keys = [
+            ("admin_key", &self.admin_key),
+            ("submit_key", &self.submit_key),
+            ("fee_schedule_key", &self.fee_schedule_key),
+        ];
+
+        for (field, key) in keys {
+            if let Some(key) = key {
+                check_updated_key(problems, field, key);
+            }
+        }
+
+        for key in &self.fee_exempt_keys {
+            check_key(problems, "fee_exempt_keys", key);
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        description
+            .optional_entity("topic", self.topic_id.as_ref())
+            .optional_entity("auto_renew_account", self.auto_renew_account_id.as_ref())
+            .key("admin_key", self.admin_key.as_ref())
+            .key("submit_key", self.submit_key.as_ref())
+            .key("fee_schedule_key", self.fee_schedule_key.as_ref());
+
+        for key in &self.fee_exempt_keys {
+            description.key("fee_exempt_key", Some(key));
+        }
+    }
+}
 
 impl TransactionExecute for TopicUpdateTransactionData {
     fn execute(
@@ -317,7 +360,7 @@ impl FromProtobuf<services::ConsensusUpdateTopicTransactionBody> for TopicUpdate
 
         Ok(Self {
             topic_id: Option::from_protobuf(pb.topic_id)?,
-            expiration_time: pb.expiration_time.map(Into::into),
+            expiration_time: Option::from_protobuf(pb.expiration_time)?,
             topic_memo: pb.memo,
             admin_key: Option::from_protobuf(pb.admin_key)?,
             submit_key: Option::from_protobuf(pb.submit_key)?,
@@ -12,7 +12,10 @@ use crate::custom_fee_limit::CustomFeeLimit;
 use crate::downcast::DowncastOwned;
 use crate::entity_id::ValidateChecksums;
 use crate::ledger_id::RefLedgerId;
-use crate::protobuf::FromProtobuf;
+use crate::protobuf::{
+    FromProtobuf,
+    FromProtobufContext,
+};
 use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionBody,
@@ -24,7 +27,9 @@ use crate::{
     Error,
     Hbar,
     Transaction,
+    TransactionDescription,
     TransactionId,
+    ValidationProblem,
 };
 
 mod data {
@@ -41,13 +46,16 @@ mod data {
         NodeUpdateTransactionData as NodeUpdate,
     };
     pub(super) use crate::batch_transaction::BatchTransactionData as Batch;
+    #[cfg(feature = "contracts")]
     pub(super) use crate::contract::{
         ContractCreateTransactionData as ContractCreate,
         ContractDeleteTransactionData as ContractDelete,
         ContractExecuteTransactionData as ContractExecute,
         ContractUpdateTransactionData as ContractUpdate,
     };
+    #[cfg(feature = "contracts")]
     pub(super) use crate::ethereum::EthereumTransactionData as Ethereum;
+    #[cfg(feature = "files")]
     pub(super) use crate::file::{
         FileAppendTransactionData as FileAppend,
         FileCreateTransactionData as FileCreate,
@@ -55,6 +63,7 @@ mod data {
         FileUpdateTransactionData as FileUpdate,
     };
     pub(super) use crate::prng_transaction::PrngTransactionData as Prng;
+    #[cfg(feature = "schedules")]
     pub(super) use crate::schedule::{
         ScheduleCreateTransactionData as ScheduleCreate,
         ScheduleDeleteTransactionData as ScheduleDelete,
@@ -65,6 +74,7 @@ mod data {
         SystemDeleteTransactionData as SystemDelete,
         SystemUndeleteTransactionData as SystemUndelete,
     };
+    #[cfg(feature = "tokens")]
     pub(super) use crate::token::{
         TokenAirdropTransactionData as TokenAirdrop,
         TokenAssociateTransactionData as TokenAssociate,
@@ -87,6 +97,7 @@ mod data {
         TokenUpdateTransactionData as TokenUpdate,
         TokenWipeTransactionData as TokenWipe,
     };
+    #[cfg(feature = "topics")]
     pub(super) use crate::topic::{
         TopicCreateTransactionData as TopicCreate,
         TopicDeleteTransactionData as TopicDelete,
@@ -107,49 +118,85 @@ pub enum AnyTransactionData {
     AccountDelete(data::AccountDelete),
     AccountAllowanceApprove(data::AccountAllowanceApprove),
     AccountAllowanceDelete(data::AccountAllowanceDelete),
+    #[cfg(feature = "contracts")]
     ContractCreate(data::ContractCreate),
+    #[cfg(feature = "contracts")]
     ContractUpdate(data::ContractUpdate),
+    #[cfg(feature = "contracts")]
     ContractDelete(data::ContractDelete),
+    #[cfg(feature = "contracts")]
     ContractExecute(data::ContractExecute),
     Transfer(data::Transfer),
+    #[cfg(feature = "topics")]
     TopicCreate(data::TopicCreate),
+    #[cfg(feature = "topics")]
     TopicUpdate(data::TopicUpdate),
+    #[cfg(feature = "topics")]
     TopicDelete(data::TopicDelete),
+    #[cfg(feature = "topics")]
     TopicMessageSubmit(data::TopicMessageSubmit),
+    #[cfg(feature = "files")]
     FileAppend(data::FileAppend),
+    #[cfg(feature = "files")]
     FileCreate(data::FileCreate),
+    #[cfg(feature = "files")]
     FileUpdate(data::FileUpdate),
+    #[cfg(feature = "files")]
     FileDelete(data::FileDelete),
     Prng(data::Prng),
+    #[cfg(feature = "schedules")]
     ScheduleCreate(data::ScheduleCreate),
+    #[cfg(feature = "schedules")]
     ScheduleSign(data::ScheduleSign),
+    #[cfg(feature = "schedules")]
     ScheduleDelete(data::ScheduleDelete),
+    #[cfg(feature = "tokens")]
     TokenAssociate(data::TokenAssociate),
+    #[cfg(feature = "tokens")]
     TokenBurn(data::TokenBurn),
+    #[cfg(feature = "tokens")]
     TokenCreate(data::TokenCreate),
+    #[cfg(feature = "tokens")]
     TokenDelete(data::TokenDelete),
+    #[cfg(feature = "tokens")]
     TokenDissociate(data::TokenDissociate),
+    #[cfg(feature = "tokens")]
     TokenFeeScheduleUpdate(data::TokenFeeScheduleUpdate),
+    #[cfg(feature = "tokens")]
     TokenFreeze(data::TokenFreeze),
+    #[cfg(feature = "tokens")]
     TokenGrantKyc(data::TokenGrantKyc),
+    #[cfg(feature = "tokens")]
     TokenMint(data::TokenMint),
+    #[cfg(feature = "tokens")]
     TokenPause(data::TokenPause),
+    #[cfg(feature = "tokens")]
     TokenRevokeKyc(data::TokenRevokeKyc),
+    #[cfg(feature = "tokens")]
     TokenUnfreeze(data::TokenUnfreeze),
+    #[cfg(feature = "tokens")]
     TokenUnpause(data::TokenUnpause),
+    #[cfg(feature = "tokens")]
     TokenUpdate(data::TokenUpdate),
+    #[cfg(feature = "tokens")]
     TokenWipe(data::TokenWipe),
     SystemDelete(data::SystemDelete),
     SystemUndelete(data::SystemUndelete),
     Freeze(data::Freeze),
+    #[cfg(feature = "contracts")]
     Ethereum(data::Ethereum),
+    #[cfg(feature = "tokens")]
     TokenUpdateNfts(data::TokenUpdateNfts),
     NodeCreate(data::NodeCreate),
     NodeUpdate(data::NodeUpdate),
     NodeDelete(data::NodeDelete),
+    #[cfg(feature = "tokens")]
     TokenReject(data::TokenReject),
+    #[cfg(feature = "tokens")]
     TokenAirdrop(data::TokenAirdrop),
+    #[cfg(feature = "tokens")]
     TokenClaimAirdrop(data::TokenClaimAirdrop),
+    #[cfg(feature = "tokens")]
     TokenCancelAirdrop(data::TokenCancelAirdrop),
     Batch(data::Batch),
 }
@@ -184,82 +231,110 @@ impl ToTransactionDataProtobuf for AnyTransactionData {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
 
+            #[cfg(feature = "contracts")]
             Self::ContractCreate(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
 
+            #[cfg(feature = "contracts")]
             Self::ContractUpdate(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
 
+            #[cfg(feature = "contracts")]
             Self::ContractDelete(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
 
+            #[cfg(feature = "contracts")]
             Self::ContractExecute(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
 
+            #[cfg(feature = "files")]
             Self::FileAppend(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "files")]
             Self::FileCreate(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "files")]
             Self::FileUpdate(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "files")]
             Self::FileDelete(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
             Self::Prng(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "tokens")]
             Self::TokenAssociate(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
 
+            #[cfg(feature = "tokens")]
             Self::TokenBurn(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "tokens")]
             Self::TokenCreate(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "tokens")]
             Self::TokenDelete(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "tokens")]
             Self::TokenDissociate(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
 
+            #[cfg(feature = "tokens")]
             Self::TokenFeeScheduleUpdate(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
 
+            #[cfg(feature = "tokens")]
             Self::TokenFreeze(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "tokens")]
             Self::TokenGrantKyc(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
 
+            #[cfg(feature = "tokens")]
             Self::TokenMint(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "tokens")]
             Self::TokenPause(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "tokens")]
             Self::TokenRevokeKyc(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
 
+            #[cfg(feature = "tokens")]
             Self::TokenUnfreeze(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
 
+            #[cfg(feature = "tokens")]
             Self::TokenUnpause(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "tokens")]
             Self::TokenUpdate(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "tokens")]
             Self::TokenWipe(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "topics")]
             Self::TopicCreate(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "topics")]
             Self::TopicUpdate(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "topics")]
             Self::TopicDelete(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "tokens")]
             Self::TokenReject(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "topics")]
             Self::TopicMessageSubmit(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
@@ -272,18 +347,23 @@ impl ToTransactionDataProtobuf for AnyTransactionData {
 
             Self::Freeze(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "schedules")]
             Self::ScheduleCreate(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
 
+            #[cfg(feature = "schedules")]
             Self::ScheduleSign(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "schedules")]
             Self::ScheduleDelete(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
 
+            #[cfg(feature = "contracts")]
             Self::Ethereum(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "tokens")]
             Self::TokenUpdateNfts(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
@@ -294,13 +374,17 @@ impl ToTransactionDataProtobuf for AnyTransactionData {
 
             Self::NodeDelete(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "tokens")]
             Self::TokenReject(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "tokens")]
             Self::TokenAirdrop(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
 
+            #[cfg(feature = "tokens")]
             Self::TokenClaimAirdrop(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
+            #[cfg(feature = "tokens")]
             Self::TokenCancelAirdrop(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
@@ -318,48 +402,84 @@ impl TransactionData for AnyTransactionData {
             Self::AccountDelete(transaction) => transaction.default_max_transaction_fee(),
             Self::AccountAllowanceApprove(transaction) => transaction.default_max_transaction_fee(),
             Self::AccountAllowanceDelete(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "contracts")]
             Self::ContractCreate(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "contracts")]
             Self::ContractUpdate(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "contracts")]
             Self::ContractDelete(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "contracts")]
             Self::ContractExecute(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "files")]
             Self::FileAppend(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "files")]
             Self::FileCreate(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "files")]
             Self::FileUpdate(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "files")]
             Self::FileDelete(transaction) => transaction.default_max_transaction_fee(),
             Self::Prng(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenAssociate(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenBurn(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenCreate(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenDelete(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenDissociate(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenFeeScheduleUpdate(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenFreeze(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenGrantKyc(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenMint(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenPause(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenRevokeKyc(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenUnfreeze(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenUnpause(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenUpdate(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenWipe(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "topics")]
             Self::TopicCreate(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "topics")]
             Self::TopicUpdate(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "topics")]
             Self::TopicDelete(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "topics")]
             Self::TopicMessageSubmit(transaction) => transaction.default_max_transaction_fee(),
             Self::SystemDelete(transaction) => transaction.default_max_transaction_fee(),
             Self::SystemUndelete(transaction) => transaction.default_max_transaction_fee(),
             Self::Freeze(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "schedules")]
             Self::ScheduleCreate(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "schedules")]
             Self::ScheduleSign(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "schedules")]
             Self::ScheduleDelete(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "contracts")]
             Self::Ethereum(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenUpdateNfts(transaction) => transaction.default_max_transaction_fee(),
             Self::NodeCreate(transaction) => transaction.default_max_transaction_fee(),
             Self::NodeUpdate(transaction) => transaction.default_max_transaction_fee(),
             Self::NodeDelete(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenReject(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenAirdrop(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenClaimAirdrop(transaction) => transaction.default_max_transaction_fee(),
+            #[cfg(feature = "tokens")]
             Self::TokenCancelAirdrop(transaction) => transaction.default_max_transaction_fee(),
             Self::Batch(transaction) => transaction.default_max_transaction_fee(),
         }
@@ -372,49 +492,85 @@ impl TransactionData for AnyTransactionData {
             Self::AccountDelete(it) => it.maybe_chunk_data(),
             Self::AccountAllowanceApprove(it) => it.maybe_chunk_data(),
             Self::AccountAllowanceDelete(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "contracts")]
             Self::ContractCreate(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "contracts")]
             Self::ContractUpdate(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "contracts")]
             Self::ContractDelete(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "contracts")]
             Self::ContractExecute(it) => it.maybe_chunk_data(),
             Self::Transfer(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "topics")]
             Self::TopicCreate(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "topics")]
             Self::TopicUpdate(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "topics")]
             Self::TopicDelete(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "topics")]
             Self::TopicMessageSubmit(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "files")]
             Self::FileAppend(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "files")]
             Self::FileCreate(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "files")]
             Self::FileUpdate(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "files")]
             Self::FileDelete(it) => it.maybe_chunk_data(),
             Self::Prng(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenAssociate(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenBurn(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenCreate(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenDelete(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenDissociate(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenFeeScheduleUpdate(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenFreeze(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenGrantKyc(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenMint(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenPause(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenRevokeKyc(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenUnfreeze(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenUnpause(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenUpdate(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenWipe(it) => it.maybe_chunk_data(),
             Self::SystemDelete(it) => it.maybe_chunk_data(),
             Self::SystemUndelete(it) => it.maybe_chunk_data(),
             Self::Freeze(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "schedules")]
             Self::ScheduleCreate(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "schedules")]
             Self::ScheduleSign(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "schedules")]
             Self::ScheduleDelete(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "contracts")]
             Self::Ethereum(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenUpdateNfts(it) => it.maybe_chunk_data(),
             Self::NodeCreate(it) => it.maybe_chunk_data(),
             Self::NodeUpdate(it) => it.maybe_chunk_data(),
             Self::NodeDelete(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenReject(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenAirdrop(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenClaimAirdrop(it) => it.maybe_chunk_data(),
+            #[cfg(feature = "tokens")]
             Self::TokenCancelAirdrop(it) => it.maybe_chunk_data(),
             Self::Batch(it) => it.maybe_chunk_data(),
         }
@@ -427,53 +583,370 @@ impl TransactionData for AnyTransactionData {
             Self::AccountDelete(it) => it.wait_for_receipt(),
             Self::AccountAllowanceApprove(it) => it.wait_for_receipt(),
             Self::AccountAllowanceDelete(it) => it.wait_for_receipt(),
+            #[cfg(feature = "contracts")]
             Self::ContractCreate(it) => it.wait_for_receipt(),
+            #[cfg(feature = "contracts")]
             Self::ContractUpdate(it) => it.wait_for_receipt(),
+            #[cfg(feature = "contracts")]
             Self::ContractDelete(it) => it.wait_for_receipt(),
+            #[cfg(feature = "contracts")]
             Self::ContractExecute(it) => it.wait_for_receipt(),
             Self::Transfer(it) => it.wait_for_receipt(),
+            #[cfg(feature = "topics")]
             Self::TopicCreate(it) => it.wait_for_receipt(),
+            #[cfg(feature = "topics")]
             Self::TopicUpdate(it) => it.wait_for_receipt(),
+            #[cfg(feature = "topics")]
             Self::TopicDelete(it) => it.wait_for_receipt(),
+            #[cfg(feature = "topics")]
             Self::TopicMessageSubmit(it) => it.wait_for_receipt(),
+            #[cfg(feature = "files")]
             Self::FileAppend(it) => it.wait_for_receipt(),
+            #[cfg(feature = "files")]
             Self::FileCreate(it) => it.wait_for_receipt(),
+            #[cfg(feature = "files")]
             Self::FileUpdate(it) => it.wait_for_receipt(),
+            #[cfg(feature = "files")]
             Self::FileDelete(it) => it.wait_for_receipt(),
             Self::Prng(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenAssociate(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenBurn(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenCreate(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenDelete(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenDissociate(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenFeeScheduleUpdate(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenFreeze(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenGrantKyc(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenMint(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenPause(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenRevokeKyc(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenUnfreeze(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenUnpause(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenUpdate(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenWipe(it) => it.wait_for_receipt(),
             Self::SystemDelete(it) => it.wait_for_receipt(),
             Self::SystemUndelete(it) => it.wait_for_receipt(),
             Self::Freeze(it) => it.wait_for_receipt(),
+            #[cfg(feature = "schedules")]
             Self::ScheduleCreate(it) => it.wait_for_receipt(),
+            #[cfg(feature = "schedules")]
             Self::ScheduleSign(it) => it.wait_for_receipt(),
+            #[cfg(feature = "schedules")]
             Self::ScheduleDelete(it) => it.wait_for_receipt(),
+            #[cfg(feature = "contracts")]
             Self::Ethereum(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenUpdateNfts(it) => it.wait_for_receipt(),
             Self::NodeCreate(it) => it.wait_for_receipt(),
             Self::NodeUpdate(it) => it.wait_for_receipt(),
             Self::NodeDelete(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenReject(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenAirdrop(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenClaimAirdrop(it) => it.wait_for_receipt(),
+            #[cfg(feature = "tokens")]
             Self::TokenCancelAirdrop(it) => it.wait_for_receipt(),
             Self::Batch(it) => it.wait_for_receipt(),
         }
     }
+
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        match self {
+            Self::Transfer(transaction) => transaction.validate_data(problems),
+            Self::AccountCreate(transaction) => transaction.validate_data(problems),
+            Self::AccountUpdate(transaction) => transaction.validate_data(problems),
+            Self::AccountDelete(transaction) => transaction.validate_data(problems),
+            Self::AccountAllowanceApprove(transaction) => transaction.validate_data(problems),
+            Self::AccountAllowanceDelete(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "contracts")]
+            Self::ContractCreate(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "contracts")]
+            Self::ContractUpdate(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "contracts")]
+            Self::ContractDelete(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "contracts")]
+            Self::ContractExecute(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "files")]
+            Self::FileAppend(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "files")]
+            Self::FileCreate(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "files")]
+            Self::FileUpdate(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "files")]
+            Self::FileDelete(transaction) => transaction.validate_data(problems),
+            Self::Prng(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenAssociate(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenBurn(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenCreate(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenDelete(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenDissociate(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenFeeScheduleUpdate(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenFreeze(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenGrantKyc(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenMint(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenPause(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenRevokeKyc(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenUnfreeze(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenUnpause(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenUpdate(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenWipe(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "topics")]
+            Self::TopicCreate(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "topics")]
+            Self::TopicUpdate(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "topics")]
+            Self::TopicDelete(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "topics")]
+            Self::TopicMessageSubmit(transaction) => transaction.validate_data(problems),
+            Self::SystemDelete(transaction) => transaction.validate_data(problems),
+            Self::SystemUndelete(transaction) => transaction.validate_data(problems),
+            Self::Freeze(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "schedules")]
+            Self::ScheduleCreate(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "schedules")]
+            Self::ScheduleSign(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "schedules")]
+            Self::ScheduleDelete(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "contracts")]
+            Self::Ethereum(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenUpdateNfts(transaction) => transaction.validate_data(problems),
+            Self::NodeCreate(transaction) => transaction.validate_data(problems),
+            Self::NodeUpdate(transaction) => transaction.validate_data(problems),
+            Self::NodeDelete(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenReject(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenAirdrop(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenClaimAirdrop(transaction) => transaction.validate_data(problems),
+            #[cfg(feature = "tokens")]
+            Self::TokenCancelAirdrop(transaction) => transaction.validate_data(problems),
+            Self::Batch(transaction) => transaction.validate_data(problems),
+        }
+    }
+
+    fn required_signers(&self) -> Vec<(&'static str, AccountId)> {
+        match self {
+            Self::Transfer(transaction) => transaction.required_signers(),
+            Self::AccountCreate(transaction) => transaction.required_signers(),
+            Self::AccountUpdate(transaction) => transaction.required_signers(),
+            Self::AccountDelete(transaction) => transaction.required_signers(),
+            Self::AccountAllowanceApprove(transaction) => transaction.required_signers(),
+            Self::AccountAllowanceDelete(transaction) => transaction.required_signers(),
+            #[cfg(feature = "contracts")]
+            Self::ContractCreate(transaction) => transaction.required_signers(),
+            #[cfg(feature = "contracts")]
+            Self::ContractUpdate(transaction) => transaction.required_signers(),
+            #[cfg(feature = "contracts")]
+            Self::ContractDelete(transaction) => transaction.required_signers(),
+            #[cfg(feature = "contracts")]
+            Self::ContractExecute(transaction) => transaction.required_signers(),
+            #[cfg(feature = "files")]
+            Self::FileAppend(transaction) => transaction.required_signers(),
+            #[cfg(feature = "files")]
+            Self::FileCreate(transaction) => transaction.required_signers(),
+            #[cfg(feature = "files")]
+            Self::FileUpdate(transaction) => transaction.required_signers(),
+            #[cfg(feature = "files")]
+            Self::FileDelete(transaction) => transaction.required_signers(),
+            Self::Prng(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenAssociate(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenBurn(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenCreate(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenDelete(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenDissociate(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenFeeScheduleUpdate(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenFreeze(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenGrantKyc(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenMint(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenPause(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenRevokeKyc(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenUnfreeze(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenUnpause(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenUpdate(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenWipe(transaction) => transaction.required_signers(),
+            #[cfg(feature = "topics")]
+            Self::TopicCreate(transaction) => transaction.required_signers(),
+            #[cfg(feature = "topics")]
+            Self::TopicUpdate(transaction) => transaction.required_signers(),
+            #[cfg(feature = "topics")]
+            Self::TopicDelete(transaction) => transaction.required_signers(),
+            #[cfg(feature = "topics")]
+            Self::TopicMessageSubmit(transaction) => transaction.required_signers(),
+            Self::SystemDelete(transaction) => transaction.required_signers(),
+            Self::SystemUndelete(transaction) => transaction.required_signers(),
+            Self::Freeze(transaction) => transaction.required_signers(),
+            #[cfg(feature = "schedules")]
+            Self::ScheduleCreate(transaction) => transaction.required_signers(),
+            #[cfg(feature = "schedules")]
+            Self::ScheduleSign(transaction) => transaction.required_signers(),
+            #[cfg(feature = "schedules")]
+            Self::ScheduleDelete(transaction) => transaction.required_signers(),
+            #[cfg(feature = "contracts")]
+            Self::Ethereum(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenUpdateNfts(transaction) => transaction.required_signers(),
+            Self::NodeCreate(transaction) => transaction.required_signers(),
+            Self::NodeUpdate(transaction) => transaction.required_signers(),
+            Self::NodeDelete(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenReject(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenAirdrop(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenClaimAirdrop(transaction) => transaction.required_signers(),
+            #[cfg(feature = "tokens")]
+            Self::TokenCancelAirdrop(transaction) => transaction.required_signers(),
+            Self::Batch(transaction) => transaction.required_signers(),
+        }
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        match self {
+            Self::Transfer(transaction) => describe_variant(transaction, description),
+            Self::AccountCreate(transaction) => describe_variant(transaction, description),
+            Self::AccountUpdate(transaction) => describe_variant(transaction, description),
+            Self::AccountDelete(transaction) => describe_variant(transaction, description),
+            Self::AccountAllowanceApprove(transaction) => {
+                describe_variant(transaction, description)
+            }
+            Self::AccountAllowanceDelete(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "contracts")]
+            Self::ContractCreate(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "contracts")]
+            Self::ContractUpdate(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "contracts")]
+            Self::ContractDelete(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "contracts")]
+            Self::ContractExecute(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "files")]
+            Self::FileAppend(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "files")]
+            Self::FileCreate(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "files")]
+            Self::FileUpdate(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "files")]
+            Self::FileDelete(transaction) => describe_variant(transaction, description),
+            Self::Prng(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenAssociate(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenBurn(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenCreate(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenDelete(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenDissociate(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenFeeScheduleUpdate(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenFreeze(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenGrantKyc(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenMint(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenPause(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenRevokeKyc(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenUnfreeze(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenUnpause(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenUpdate(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenWipe(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "topics")]
+            Self::TopicCreate(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "topics")]
+            Self::TopicUpdate(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "topics")]
+            Self::TopicDelete(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "topics")]
+            Self::TopicMessageSubmit(transaction) => describe_variant(transaction, description),
+            Self::SystemDelete(transaction) => describe_variant(transaction, description),
+            Self::SystemUndelete(transaction) => describe_variant(transaction, description),
+            Self::Freeze(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "schedules")]
+            Self::ScheduleCreate(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "schedules")]
+            Self::ScheduleSign(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "schedules")]
+            Self::ScheduleDelete(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "contracts")]
+            Self::Ethereum(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenUpdateNfts(transaction) => describe_variant(transaction, description),
+            Self::NodeCreate(transaction) => describe_variant(transaction, description),
+            Self::NodeUpdate(transaction) => describe_variant(transaction, description),
+            Self::NodeDelete(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenReject(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenAirdrop(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenClaimAirdrop(transaction) => describe_variant(transaction, description),
+            #[cfg(feature = "tokens")]
+            Self::TokenCancelAirdrop(transaction) => describe_variant(transaction, description),
+            Self::Batch(transaction) => describe_variant(transaction, description),
+        }
+    }
+}
+
+/// Describes `data` as the kind of transaction it is, rather than as an `AnyTransaction`.
+fn describe_variant<D: TransactionData>(data: &D, description: &mut TransactionDescription) {
+    description.kind = super::describe::kind_name::<D>();
+    data.describe_data(description);
 }
 
 impl TransactionExecute for AnyTransactionData {
@@ -489,48 +962,84 @@ impl TransactionExecute for AnyTransactionData {
             Self::AccountDelete(transaction) => transaction.execute(channel, request),
             Self::AccountAllowanceApprove(transaction) => transaction.execute(channel, request),
             Self::AccountAllowanceDelete(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "contracts")]
             Self::ContractCreate(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "contracts")]
             Self::ContractUpdate(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "contracts")]
             Self::ContractDelete(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "contracts")]
             Self::ContractExecute(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "files")]
             Self::FileAppend(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "files")]
             Self::FileCreate(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "files")]
             Self::FileUpdate(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "files")]
             Self::FileDelete(transaction) => transaction.execute(channel, request),
             Self::Prng(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenAssociate(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenBurn(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenCreate(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenDelete(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenDissociate(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenFeeScheduleUpdate(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenFreeze(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenGrantKyc(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenMint(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenPause(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenRevokeKyc(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenUnfreeze(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenUnpause(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenUpdate(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenWipe(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "topics")]
             Self::TopicCreate(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "topics")]
             Self::TopicUpdate(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "topics")]
             Self::TopicDelete(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "topics")]
             Self::TopicMessageSubmit(transaction) => transaction.execute(channel, request),
             Self::SystemDelete(transaction) => transaction.execute(channel, request),
             Self::SystemUndelete(transaction) => transaction.execute(channel, request),
             Self::Freeze(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "schedules")]
             Self::ScheduleCreate(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "schedules")]
             Self::ScheduleSign(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "schedules")]
             Self::ScheduleDelete(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "contracts")]
             Self::Ethereum(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenUpdateNfts(transaction) => transaction.execute(channel, request),
             Self::NodeCreate(transaction) => transaction.execute(channel, request),
             Self::NodeUpdate(transaction) => transaction.execute(channel, request),
             Self::NodeDelete(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenReject(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenAirdrop(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenClaimAirdrop(transaction) => transaction.execute(channel, request),
+            #[cfg(feature = "tokens")]
             Self::TokenCancelAirdrop(transaction) => transaction.execute(channel, request),
             Self::Batch(transaction) => transaction.execute(channel, request),
         }
@@ -547,49 +1056,85 @@ impl ValidateChecksums for AnyTransactionData {
             Self::AccountDelete(transaction) => transaction.validate_checksums(ledger_id),
             Self::AccountAllowanceApprove(transaction) => transaction.validate_checksums(ledger_id),
             Self::AccountAllowanceDelete(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "contracts")]
             Self::ContractCreate(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "contracts")]
             Self::ContractUpdate(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "contracts")]
             Self::ContractDelete(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "contracts")]
             Self::ContractExecute(transaction) => transaction.validate_checksums(ledger_id),
             Self::Transfer(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "topics")]
             Self::TopicCreate(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "topics")]
             Self::TopicUpdate(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "topics")]
             Self::TopicDelete(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "topics")]
             Self::TopicMessageSubmit(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "files")]
             Self::FileAppend(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "files")]
             Self::FileCreate(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "files")]
             Self::FileUpdate(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "files")]
             Self::FileDelete(transaction) => transaction.validate_checksums(ledger_id),
             Self::Prng(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "schedules")]
             Self::ScheduleCreate(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "schedules")]
             Self::ScheduleSign(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "schedules")]
             Self::ScheduleDelete(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenAssociate(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenBurn(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenCreate(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenDelete(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenDissociate(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenFeeScheduleUpdate(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenFreeze(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenGrantKyc(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenMint(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenPause(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenRevokeKyc(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenUnfreeze(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenUnpause(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenUpdate(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenWipe(transaction) => transaction.validate_checksums(ledger_id),
             Self::SystemDelete(transaction) => transaction.validate_checksums(ledger_id),
             Self::SystemUndelete(transaction) => transaction.validate_checksums(ledger_id),
             Self::Freeze(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "contracts")]
             Self::Ethereum(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenUpdateNfts(transaction) => transaction.validate_checksums(ledger_id),
             Self::NodeCreate(transaction) => transaction.validate_checksums(ledger_id),
             Self::NodeUpdate(transaction) => transaction.validate_checksums(ledger_id),
             Self::NodeDelete(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenReject(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenAirdrop(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenClaimAirdrop(transaction) => transaction.validate_checksums(ledger_id),
+            #[cfg(feature = "tokens")]
             Self::TokenCancelAirdrop(transaction) => transaction.validate_checksums(ledger_id),
             Self::Batch(transaction) => transaction.validate_checksums(ledger_id),
         }
@@ -603,10 +1148,15 @@ impl FromProtobuf<services::transaction_body::Data> for AnyTransactionData {
     {
         use services::transaction_body::Data;
         let data = match pb {
+            #[cfg(feature = "contracts")]
             Data::ContractCall(pb) => data::ContractExecute::from_protobuf(pb)?.into(),
+            #[cfg(feature = "contracts")]
             Data::ContractCreateInstance(pb) => data::ContractCreate::from_protobuf(pb)?.into(),
+            #[cfg(feature = "contracts")]
             Data::ContractUpdateInstance(pb) => data::ContractUpdate::from_protobuf(pb)?.into(),
+            #[cfg(feature = "contracts")]
             Data::ContractDeleteInstance(pb) => data::ContractDelete::from_protobuf(pb)?.into(),
+            #[cfg(feature = "contracts")]
             Data::EthereumTransaction(pb) => data::Ethereum::from_protobuf(pb)?.into(),
             Data::CryptoApproveAllowance(pb) => {
                 data::AccountAllowanceApprove::from_protobuf(pb)?.into()
@@ -618,46 +1168,126 @@ impl FromProtobuf<services::transaction_body::Data> for AnyTransactionData {
             Data::CryptoDelete(pb) => data::AccountDelete::from_protobuf(pb)?.into(),
             Data::CryptoTransfer(pb) => data::Transfer::from_protobuf(pb)?.into(),
             Data::CryptoUpdateAccount(pb) => data::AccountUpdate::from_protobuf(pb)?.into(),
+            #[cfg(feature = "files")]
             Data::FileAppend(pb) => data::FileAppend::from_protobuf(pb)?.into(),
+            #[cfg(feature = "files")]
             Data::FileCreate(pb) => data::FileCreate::from_protobuf(pb)?.into(),
+            #[cfg(feature = "files")]
             Data::FileDelete(pb) => data::FileDelete::from_protobuf(pb)?.into(),
+            #[cfg(feature = "files")]
             Data::FileUpdate(pb) => data::FileUpdate::from_protobuf(pb)?.into(),
             Data::UtilPrng(pb) => data::Prng::from_protobuf(pb)?.into(),
             Data::SystemDelete(pb) => data::SystemDelete::from_protobuf(pb)?.into(),
             Data::SystemUndelete(pb) => data::SystemUndelete::from_protobuf(pb)?.into(),
             Data::Freeze(pb) => data::Freeze::from_protobuf(pb)?.into(),
+            #[cfg(feature = "topics")]
             Data::ConsensusCreateTopic(pb) => data::TopicCreate::from_protobuf(pb)?.into(),
+            #[cfg(feature = "topics")]
             Data::ConsensusUpdateTopic(pb) => data::TopicUpdate::from_protobuf(pb)?.into(),
+            #[cfg(feature = "topics")]
             Data::ConsensusDeleteTopic(pb) => data::TopicDelete::from_protobuf(pb)?.into(),
+            #[cfg(feature = "topics")]
             Data::ConsensusSubmitMessage(pb) => data::TopicMessageSubmit::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenCreation(pb) => data::TokenCreate::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenFreeze(pb) => data::TokenFreeze::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenUnfreeze(pb) => data::TokenUnfreeze::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenGrantKyc(pb) => data::TokenGrantKyc::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenRevokeKyc(pb) => data::TokenRevokeKyc::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenDeletion(pb) => data::TokenDelete::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenUpdate(pb) => data::TokenUpdate::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenMint(pb) => data::TokenMint::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenBurn(pb) => data::TokenBurn::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenWipe(pb) => data::TokenWipe::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenAssociate(pb) => data::TokenAssociate::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenDissociate(pb) => data::TokenDissociate::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenFeeScheduleUpdate(pb) => {
                 data::TokenFeeScheduleUpdate::from_protobuf(pb)?.into()
             }
+            #[cfg(feature = "tokens")]
             Data::TokenPause(pb) => data::TokenPause::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenUnpause(pb) => data::TokenUnpause::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenReject(pb) => data::TokenReject::from_protobuf(pb)?.into(),
+            #[cfg(feature = "schedules")]
             Data::ScheduleCreate(pb) => data::ScheduleCreate::from_protobuf(pb)?.into(),
+            #[cfg(feature = "schedules")]
             Data::ScheduleDelete(pb) => data::ScheduleDelete::from_protobuf(pb)?.into(),
+            #[cfg(feature = "schedules")]
             Data::ScheduleSign(pb) => data::ScheduleSign::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenUpdateNfts(pb) => data::TokenUpdateNfts::from_protobuf(pb)?.into(),
             Data::NodeCreate(pb) => data::NodeCreate::from_protobuf(pb)?.into(),
             Data::NodeUpdate(pb) => data::NodeUpdate::from_protobuf(pb)?.into(),
             Data::NodeDelete(pb) => data::NodeDelete::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenAirdrop(pb) => data::TokenAirdrop::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenClaimAirdrop(pb) => data::TokenClaimAirdrop::from_protobuf(pb)?.into(),
+            #[cfg(feature = "tokens")]
             Data::TokenCancelAirdrop(pb) => data::TokenCancelAirdrop::from_protobuf(pb)?.into(),
+            #[cfg(not(feature = "contracts"))]
+            Data::ContractCall(_)
+            | Data::ContractCreateInstance(_)
+            | Data::ContractUpdateInstance(_)
+            | Data::ContractDeleteInstance(_)
+            | Data::EthereumTransaction(_) => {
+                return Err(Error::from_protobuf("transaction requires the `contracts` feature"))
+            }
+            #[cfg(not(feature = "files"))]
+            Data::FileAppend(_)
+            | Data::FileCreate(_)
+            | Data::FileDelete(_)
+            | Data::FileUpdate(_) => {
+                return Err(Error::from_protobuf("transaction requires the `files` feature"))
+            }
+            #[cfg(not(feature = "schedules"))]
+            Data::ScheduleCreate(_) | Data::ScheduleDelete(_) | Data::ScheduleSign(_) => {
+                return Err(Error::from_protobuf("transaction requires the `schedules` feature"))
+            }
+            #[cfg(not(feature = "tokens"))]
+            Data::TokenCreation(_)
+            | Data::TokenFreeze(_)
+            | Data::TokenUnfreeze(_)
+            | Data::TokenGrantKyc(_)
+            | Data::TokenRevokeKyc(_)
+            | Data::TokenDeletion(_)
+            | Data::TokenUpdate(_)
+            | Data::TokenMint(_)
+            | Data::TokenBurn(_)
+            | Data::TokenWipe(_)
+            | Data::TokenAssociate(_)
+            | Data::TokenDissociate(_)
+            | Data::TokenFeeScheduleUpdate(_)
+            | Data::TokenPause(_)
+            | Data::TokenUnpause(_)
+            | Data::TokenReject(_)
+            | Data::TokenUpdateNfts(_)
+            | Data::TokenAirdrop(_)
+            | Data::TokenClaimAirdrop(_)
+            | Data::TokenCancelAirdrop(_) => {
+                return Err(Error::from_protobuf("transaction requires the `tokens` feature"))
+            }
+            #[cfg(not(feature = "topics"))]
+            Data::ConsensusCreateTopic(_)
+            | Data::ConsensusUpdateTopic(_)
+            | Data::ConsensusDeleteTopic(_)
+            | Data::ConsensusSubmitMessage(_) => {
+                return Err(Error::from_protobuf("transaction requires the `topics` feature"))
+            }
             Data::CryptoAddLiveHash(_) => {
                 return Err(Error::from_protobuf(
                     "unsupported transaction `AddLiveHashTransaction`",
@@ -716,87 +1346,114 @@ impl AnyTransactionData {
             ServicesTransactionDataList::AccountAllowanceDelete(v) => {
                 data::AccountAllowanceDelete::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "contracts")]
             ServicesTransactionDataList::ContractCreate(v) => {
                 data::ContractCreate::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "contracts")]
             ServicesTransactionDataList::ContractUpdate(v) => {
                 data::ContractUpdate::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "contracts")]
             ServicesTransactionDataList::ContractDelete(v) => {
                 data::ContractDelete::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "contracts")]
             ServicesTransactionDataList::ContractExecute(v) => {
                 data::ContractExecute::from_protobuf(try_into_only_element(v)?)?.into()
             }
             ServicesTransactionDataList::Transfer(v) => {
                 data::Transfer::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "topics")]
             ServicesTransactionDataList::TopicCreate(v) => {
                 data::TopicCreate::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "topics")]
             ServicesTransactionDataList::TopicUpdate(v) => {
                 data::TopicUpdate::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "topics")]
             ServicesTransactionDataList::TopicDelete(v) => {
                 data::TopicDelete::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "topics")]
             ServicesTransactionDataList::TopicMessageSubmit(v) => {
                 data::TopicMessageSubmit::from_protobuf(v)?.into()
             }
+            #[cfg(feature = "files")]
             ServicesTransactionDataList::FileAppend(v) => {
                 data::FileAppend::from_protobuf(v)?.into()
             }
+            #[cfg(feature = "files")]
             ServicesTransactionDataList::FileCreate(v) => {
                 data::FileCreate::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "files")]
             ServicesTransactionDataList::FileUpdate(v) => {
                 data::FileUpdate::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "files")]
             ServicesTransactionDataList::FileDelete(v) => {
                 data::FileDelete::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenAssociate(v) => {
                 data::TokenAssociate::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenBurn(v) => {
                 data::TokenBurn::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenCreate(v) => {
                 data::TokenCreate::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenDelete(v) => {
                 data::TokenDelete::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenDissociate(v) => {
                 data::TokenDissociate::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenFeeScheduleUpdate(v) => {
                 data::TokenFeeScheduleUpdate::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenFreeze(v) => {
                 data::TokenFreeze::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenGrantKyc(v) => {
                 data::TokenGrantKyc::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenMint(v) => {
                 data::TokenMint::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenPause(v) => {
                 data::TokenPause::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenRevokeKyc(v) => {
                 data::TokenRevokeKyc::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenUnfreeze(v) => {
                 data::TokenUnfreeze::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenUnpause(v) => {
                 data::TokenUnpause::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenUpdate(v) => {
                 data::TokenUpdate::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenWipe(v) => {
                 data::TokenWipe::from_protobuf(try_into_only_element(v)?)?.into()
             }
@@ -809,24 +1466,30 @@ impl AnyTransactionData {
             ServicesTransactionDataList::Freeze(v) => {
                 data::Freeze::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "schedules")]
             ServicesTransactionDataList::ScheduleCreate(v) => {
                 data::ScheduleCreate::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "schedules")]
             ServicesTransactionDataList::ScheduleSign(v) => {
                 data::ScheduleSign::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "schedules")]
             ServicesTransactionDataList::ScheduleDelete(v) => {
                 data::ScheduleDelete::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "contracts")]
             ServicesTransactionDataList::Ethereum(v) => {
                 data::Ethereum::from_protobuf(try_into_only_element(v)?)?.into()
             }
             ServicesTransactionDataList::UtilPrng(v) => {
                 data::Prng::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenUpdateNfts(v) => {
                 data::TokenUpdateNfts::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenReject(v) => {
                 data::TokenReject::from_protobuf(try_into_only_element(v)?)?.into()
             }
@@ -839,12 +1502,15 @@ impl AnyTransactionData {
             ServicesTransactionDataList::NodeDelete(v) => {
                 data::NodeDelete::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenAirdrop(v) => {
                 data::TokenAirdrop::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenClaimAirdrop(v) => {
                 data::TokenClaimAirdrop::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            #[cfg(feature = "tokens")]
             ServicesTransactionDataList::TokenCancelAirdrop(v) => {
                 data::TokenCancelAirdrop::from_protobuf(try_into_only_element(v)?)?.into()
             }
@@ -858,25 +1524,19 @@ impl AnyTransactionData {
 }
 
 impl AnyTransaction {
-    pub(super) fn from_protobuf(
+    pub(super) fn from_body_protobuf(
         first_body: services::TransactionBody,
         data_chunks: Vec<services::transaction_body::Data>,
     ) -> crate::Result<Self> {
-        let transaction_id: Option<TransactionId> = match first_body.transaction_id {
-            Some(id) => match TransactionId::from_protobuf(id) {
-                Ok(id) => Some(id),
-                Err(_) => None, // Or handle the error differently
-            },
-            None => None,
-        };
+        let transaction_id: Option<TransactionId> =
+            Option::from_protobuf(first_body.transaction_id).in_field("transaction_id")?;
 
-        let node_account_ids = match first_body.node_account_id {
-            Some(id) => match AccountId::from_protobuf(id) {
-                Ok(id) => Some(vec![id]),
-                Err(_) => None, // Handle error gracefully
-            },
-            None => None,
-        };
+        let node_account_ids = first_body
+            .node_account_id
+            .map(AccountId::from_protobuf)
+            .transpose()
+            .in_field("node_account_id")?
+            .map(|id| vec![id]);
 
         // Default to 0 tinybars if transaction_fee is missing
         let transaction_fee = Hbar::from_tinybars(first_body.transaction_fee as i64);
@@ -903,15 +1563,15 @@ impl AnyTransaction {
                 transaction_id,
                 operator: None,
                 is_frozen: false,
+                frozen_environment: None,
                 regenerate_transaction_id: Some(false),
                 custom_fee_limits: first_body
                     .max_custom_fees
                     .into_iter()
                     .map(CustomFeeLimit::from_protobuf)
-                    .collect::<Result<Vec<_>, _>>()?,
-                batch_key: first_body
-                    .batch_key
-                    .map(|key| crate::protobuf::FromProtobuf::from_protobuf(key).unwrap()),
+                    .collect::<Result<Vec<_>, _>>()
+                    .in_field("max_custom_fees")?,
+                batch_key: Option::from_protobuf(first_body.batch_key).in_field("batch_key")?,
             },
             signers: Vec::new(),
             sources: None,
@@ -927,49 +1587,85 @@ enum ServicesTransactionDataList {
     AccountDelete(Vec<services::CryptoDeleteTransactionBody>),
     AccountAllowanceApprove(Vec<services::CryptoApproveAllowanceTransactionBody>),
     AccountAllowanceDelete(Vec<services::CryptoDeleteAllowanceTransactionBody>),
+    #[cfg(feature = "contracts")]
     ContractCreate(Vec<services::ContractCreateTransactionBody>),
+    #[cfg(feature = "contracts")]
     ContractUpdate(Vec<services::ContractUpdateTransactionBody>),
+    #[cfg(feature = "contracts")]
     ContractDelete(Vec<services::ContractDeleteTransactionBody>),
+    #[cfg(feature = "contracts")]
     ContractExecute(Vec<services::ContractCallTransactionBody>),
     Transfer(Vec<services::CryptoTransferTransactionBody>),
+    #[cfg(feature = "topics")]
     TopicCreate(Vec<services::ConsensusCreateTopicTransactionBody>),
+    #[cfg(feature = "topics")]
     TopicUpdate(Vec<services::ConsensusUpdateTopicTransactionBody>),
+    #[cfg(feature = "topics")]
     TopicDelete(Vec<services::ConsensusDeleteTopicTransactionBody>),
+    #[cfg(feature = "topics")]
     TopicMessageSubmit(Vec<services::ConsensusSubmitMessageTransactionBody>),
+    #[cfg(feature = "files")]
     FileAppend(Vec<services::FileAppendTransactionBody>),
+    #[cfg(feature = "files")]
     FileCreate(Vec<services::FileCreateTransactionBody>),
+    #[cfg(feature = "files")]
     FileUpdate(Vec<services::FileUpdateTransactionBody>),
+    #[cfg(feature = "files")]
     FileDelete(Vec<services::FileDeleteTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenAssociate(Vec<services::TokenAssociateTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenBurn(Vec<services::TokenBurnTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenCreate(Vec<services::TokenCreateTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenDelete(Vec<services::TokenDeleteTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenDissociate(Vec<services::TokenDissociateTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenFeeScheduleUpdate(Vec<services::TokenFeeScheduleUpdateTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenFreeze(Vec<services::TokenFreezeAccountTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenGrantKyc(Vec<services::TokenGrantKycTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenMint(Vec<services::TokenMintTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenPause(Vec<services::TokenPauseTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenRevokeKyc(Vec<services::TokenRevokeKycTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenUnfreeze(Vec<services::TokenUnfreezeAccountTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenUnpause(Vec<services::TokenUnpauseTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenUpdate(Vec<services::TokenUpdateTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenWipe(Vec<services::TokenWipeAccountTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenUpdateNfts(Vec<services::TokenUpdateNftsTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenReject(Vec<services::TokenRejectTransactionBody>),
     SystemDelete(Vec<services::SystemDeleteTransactionBody>),
     SystemUndelete(Vec<services::SystemUndeleteTransactionBody>),
     Freeze(Vec<services::FreezeTransactionBody>),
+    #[cfg(feature = "schedules")]
     ScheduleCreate(Vec<services::ScheduleCreateTransactionBody>),
+    #[cfg(feature = "schedules")]
     ScheduleSign(Vec<services::ScheduleSignTransactionBody>),
+    #[cfg(feature = "schedules")]
     ScheduleDelete(Vec<services::ScheduleDeleteTransactionBody>),
+    #[cfg(feature = "contracts")]
     Ethereum(Vec<services::EthereumTransactionBody>),
     UtilPrng(Vec<services::UtilPrngTransactionBody>),
     NodeCreate(Vec<services::NodeCreateTransactionBody>),
     NodeUpdate(Vec<services::NodeUpdateTransactionBody>),
     NodeDelete(Vec<services::NodeDeleteTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenAirdrop(Vec<services::TokenAirdropTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenClaimAirdrop(Vec<services::TokenClaimAirdropTransactionBody>),
+    #[cfg(feature = "tokens")]
     TokenCancelAirdrop(Vec<services::TokenCancelAirdropTransactionBody>),
     AtomicBatch(Vec<services::AtomicBatchTransactionBody>),
 }
@@ -988,16 +1684,20 @@ impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransaction
 
         let mut iter = pb.into_iter();
 
-        let first = iter
-            .next()
-            .expect("empty transaction data list (should be handled earlier up the pipeline)");
+        let first =
+            iter.next().ok_or_else(|| Error::from_protobuf("transaction data list was empty"))?;
 
         // note: this impl is what I (srr) believe to be the "best" impl
         let mut value = match first {
+            #[cfg(feature = "contracts")]
             Data::ContractCall(it) => Self::ContractExecute(make_vec(it, len)),
+            #[cfg(feature = "contracts")]
             Data::ContractCreateInstance(it) => Self::ContractCreate(make_vec(it, len)),
+            #[cfg(feature = "contracts")]
             Data::ContractUpdateInstance(it) => Self::ContractUpdate(make_vec(it, len)),
+            #[cfg(feature = "contracts")]
             Data::ContractDeleteInstance(it) => Self::ContractDelete(make_vec(it, len)),
+            #[cfg(feature = "contracts")]
             Data::EthereumTransaction(it) => Self::Ethereum(make_vec(it, len)),
             Data::CryptoApproveAllowance(it) => Self::AccountAllowanceApprove(make_vec(it, len)),
             Data::CryptoDeleteAllowance(it) => Self::AccountAllowanceDelete(make_vec(it, len)),
@@ -1005,44 +1705,124 @@ impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransaction
             Data::CryptoDelete(it) => Self::AccountDelete(make_vec(it, len)),
             Data::CryptoTransfer(it) => Self::Transfer(make_vec(it, len)),
             Data::CryptoUpdateAccount(it) => Self::AccountUpdate(make_vec(it, len)),
+            #[cfg(feature = "files")]
             Data::FileAppend(it) => Self::FileAppend(make_vec(it, len)),
+            #[cfg(feature = "files")]
             Data::FileCreate(it) => Self::FileCreate(make_vec(it, len)),
+            #[cfg(feature = "files")]
             Data::FileDelete(it) => Self::FileDelete(make_vec(it, len)),
+            #[cfg(feature = "files")]
             Data::FileUpdate(it) => Self::FileUpdate(make_vec(it, len)),
             Data::SystemDelete(it) => Self::SystemDelete(make_vec(it, len)),
             Data::SystemUndelete(it) => Self::SystemUndelete(make_vec(it, len)),
             Data::Freeze(it) => Self::Freeze(make_vec(it, len)),
+            #[cfg(feature = "topics")]
             Data::ConsensusCreateTopic(it) => Self::TopicCreate(make_vec(it, len)),
+            #[cfg(feature = "topics")]
             Data::ConsensusUpdateTopic(it) => Self::TopicUpdate(make_vec(it, len)),
+            #[cfg(feature = "topics")]
             Data::ConsensusDeleteTopic(it) => Self::TopicDelete(make_vec(it, len)),
+            #[cfg(feature = "topics")]
             Data::ConsensusSubmitMessage(it) => Self::TopicMessageSubmit(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenCreation(it) => Self::TokenCreate(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenFreeze(it) => Self::TokenFreeze(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenUnfreeze(it) => Self::TokenUnfreeze(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenGrantKyc(it) => Self::TokenGrantKyc(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenRevokeKyc(it) => Self::TokenRevokeKyc(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenDeletion(it) => Self::TokenDelete(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenUpdate(it) => Self::TokenUpdate(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenMint(it) => Self::TokenMint(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenBurn(it) => Self::TokenBurn(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenWipe(it) => Self::TokenWipe(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenAssociate(it) => Self::TokenAssociate(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenDissociate(it) => Self::TokenDissociate(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenFeeScheduleUpdate(it) => Self::TokenFeeScheduleUpdate(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenPause(it) => Self::TokenPause(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenUnpause(it) => Self::TokenUnpause(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenReject(it) => Self::TokenReject(make_vec(it, len)),
+            #[cfg(feature = "schedules")]
             Data::ScheduleCreate(it) => Self::ScheduleCreate(make_vec(it, len)),
+            #[cfg(feature = "schedules")]
             Data::ScheduleDelete(it) => Self::ScheduleDelete(make_vec(it, len)),
+            #[cfg(feature = "schedules")]
             Data::ScheduleSign(it) => Self::ScheduleSign(make_vec(it, len)),
             Data::UtilPrng(it) => Self::UtilPrng(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenUpdateNfts(it) => Self::TokenUpdateNfts(make_vec(it, len)),
             Data::NodeCreate(it) => Self::NodeCreate(make_vec(it, len)),
             Data::NodeUpdate(it) => Self::NodeUpdate(make_vec(it, len)),
             Data::NodeDelete(it) => Self::NodeDelete(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenAirdrop(it) => Self::TokenAirdrop(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenClaimAirdrop(it) => Self::TokenClaimAirdrop(make_vec(it, len)),
+            #[cfg(feature = "tokens")]
             Data::TokenCancelAirdrop(it) => Self::TokenCancelAirdrop(make_vec(it, len)),
+            #[cfg(not(feature = "contracts"))]
+            Data::ContractCall(_)
+            | Data::ContractCreateInstance(_)
+            | Data::ContractUpdateInstance(_)
+            | Data::ContractDeleteInstance(_)
+            | Data::EthereumTransaction(_) => {
+                return Err(Error::from_protobuf("transaction requires the `contracts` feature"))
+            }
+            #[cfg(not(feature = "files"))]
+            Data::FileAppend(_)
+            | Data::FileCreate(_)
+            | Data::FileDelete(_)
+            | Data::FileUpdate(_) => {
+                return Err(Error::from_protobuf("transaction requires the `files` feature"))
+            }
+            #[cfg(not(feature = "schedules"))]
+            Data::ScheduleCreate(_) | Data::ScheduleDelete(_) | Data::ScheduleSign(_) => {
+                return Err(Error::from_protobuf("transaction requires the `schedules` feature"))
+            }
+            #[cfg(not(feature = "tokens"))]
+            Data::TokenCreation(_)
+            | Data::TokenFreeze(_)
+            | Data::TokenUnfreeze(_)
+            | Data::TokenGrantKyc(_)
+            | Data::TokenRevokeKyc(_)
+            | Data::TokenDeletion(_)
+            | Data::TokenUpdate(_)
+            | Data::TokenMint(_)
+            | Data::TokenBurn(_)
+            | Data::TokenWipe(_)
+            | Data::TokenAssociate(_)
+            | Data::TokenDissociate(_)
+            | Data::TokenFeeScheduleUpdate(_)
+            | Data::TokenPause(_)
+            | Data::TokenUnpause(_)
+            | Data::TokenReject(_)
+            | Data::TokenUpdateNfts(_)
+            | Data::TokenAirdrop(_)
+            | Data::TokenClaimAirdrop(_)
+            | Data::TokenCancelAirdrop(_) => {
+                return Err(Error::from_protobuf("transaction requires the `tokens` feature"))
+            }
+            #[cfg(not(feature = "topics"))]
+            Data::ConsensusCreateTopic(_)
+            | Data::ConsensusUpdateTopic(_)
+            | Data::ConsensusDeleteTopic(_)
+            | Data::ConsensusSubmitMessage(_) => {
+                return Err(Error::from_protobuf("transaction requires the `topics` feature"))
+            }
             Data::CryptoAddLiveHash(_) => {
                 return Err(Error::from_protobuf(
                     "unsupported transaction `AddLiveHashTransaction`",
@@ -1080,48 +1860,82 @@ impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransaction
                 (Self::AccountAllowanceDelete(v), Data::CryptoDeleteAllowance(element)) => {
                     v.push(element);
                 }
+                #[cfg(feature = "contracts")]
                 (Self::ContractCreate(v), Data::ContractCreateInstance(element)) => v.push(element),
+                #[cfg(feature = "contracts")]
                 (Self::ContractUpdate(v), Data::ContractUpdateInstance(element)) => v.push(element),
+                #[cfg(feature = "contracts")]
                 (Self::ContractDelete(v), Data::ContractDeleteInstance(element)) => v.push(element),
+                #[cfg(feature = "contracts")]
                 (Self::ContractExecute(v), Data::ContractCall(element)) => v.push(element),
                 (Self::Transfer(v), Data::CryptoTransfer(element)) => v.push(element),
+                #[cfg(feature = "topics")]
                 (Self::TopicCreate(v), Data::ConsensusCreateTopic(element)) => v.push(element),
+                #[cfg(feature = "topics")]
                 (Self::TopicUpdate(v), Data::ConsensusUpdateTopic(element)) => v.push(element),
+                #[cfg(feature = "topics")]
                 (Self::TopicDelete(v), Data::ConsensusDeleteTopic(element)) => v.push(element),
+                #[cfg(feature = "topics")]
                 (Self::TopicMessageSubmit(v), Data::ConsensusSubmitMessage(element)) => {
                     v.push(element);
                 }
+                #[cfg(feature = "files")]
                 (Self::FileAppend(v), Data::FileAppend(element)) => v.push(element),
+                #[cfg(feature = "files")]
                 (Self::FileCreate(v), Data::FileCreate(element)) => v.push(element),
+                #[cfg(feature = "files")]
                 (Self::FileUpdate(v), Data::FileUpdate(element)) => v.push(element),
+                #[cfg(feature = "files")]
                 (Self::FileDelete(v), Data::FileDelete(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenAssociate(v), Data::TokenAssociate(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenBurn(v), Data::TokenBurn(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenCreate(v), Data::TokenCreation(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenDelete(v), Data::TokenDeletion(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenDissociate(v), Data::TokenDissociate(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenFeeScheduleUpdate(v), Data::TokenFeeScheduleUpdate(element)) => {
                     v.push(element);
                 }
+                #[cfg(feature = "tokens")]
                 (Self::TokenFreeze(v), Data::TokenFreeze(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenGrantKyc(v), Data::TokenGrantKyc(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenMint(v), Data::TokenMint(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenPause(v), Data::TokenPause(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenRevokeKyc(v), Data::TokenRevokeKyc(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenUnfreeze(v), Data::TokenUnfreeze(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenUnpause(v), Data::TokenUnpause(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenUpdate(v), Data::TokenUpdate(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenWipe(v), Data::TokenWipe(element)) => v.push(element),
                 (Self::SystemDelete(v), Data::SystemDelete(element)) => v.push(element),
                 (Self::SystemUndelete(v), Data::SystemUndelete(element)) => v.push(element),
                 (Self::Freeze(v), Data::Freeze(element)) => v.push(element),
+                #[cfg(feature = "schedules")]
                 (Self::ScheduleCreate(v), Data::ScheduleCreate(element)) => v.push(element),
+                #[cfg(feature = "schedules")]
                 (Self::ScheduleSign(v), Data::ScheduleSign(element)) => v.push(element),
+                #[cfg(feature = "schedules")]
                 (Self::ScheduleDelete(v), Data::ScheduleDelete(element)) => v.push(element),
+                #[cfg(feature = "contracts")]
                 (Self::Ethereum(v), Data::EthereumTransaction(element)) => v.push(element),
                 (Self::UtilPrng(v), Data::UtilPrng(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenAirdrop(v), Data::TokenAirdrop(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenClaimAirdrop(v), Data::TokenClaimAirdrop(element)) => v.push(element),
+                #[cfg(feature = "tokens")]
                 (Self::TokenCancelAirdrop(v), Data::TokenCancelAirdrop(element)) => v.push(element),
                 (Self::AtomicBatch(v), Data::AtomicBatch(element)) => v.push(element),
                 _ => return Err(Error::from_protobuf("mismatched transaction types")),
@@ -1150,8 +1964,9 @@ impl AnyTransaction {
 ///
 /// This macro will ensure you get all variants via a pattern match, if something changes (say, another transaction type is added), you'll get a `Missing match arm` compiler error.
 macro_rules! impl_cast_any {
-    ($($id:ident),+$(,)?) => {
+    ($($(#[$meta:meta])* $id:ident),+$(,)?) => {
         $(
+            $(#[$meta])*
             impl $crate::downcast::DowncastOwned<data::$id> for AnyTransactionData {
                 fn downcast_owned(self) -> Result<data::$id, Self> {
                     let Self::$id(data) = self else {
@@ -1162,6 +1977,7 @@ macro_rules! impl_cast_any {
                 }
             }
 
+            $(#[$meta])*
             impl From<Transaction<data::$id>> for AnyTransaction {
                 fn from(transaction: Transaction<data::$id>) -> Self {
                     Self {
@@ -1174,6 +1990,7 @@ macro_rules! impl_cast_any {
                             transaction_id: transaction.body.transaction_id,
                             operator: transaction.body.operator,
                             is_frozen: transaction.body.is_frozen,
+                            frozen_environment: transaction.body.frozen_environment,
                             regenerate_transaction_id: transaction.body.regenerate_transaction_id,
                             custom_fee_limits: transaction.body.custom_fee_limits,
                             batch_key: transaction.body.batch_key,
@@ -1192,7 +2009,7 @@ macro_rules! impl_cast_any {
             fn _assert_exhaustive(d: AnyTransactionData)
             {
                 match d {
-                    $(AnyTransactionData::$id(_) => {},)+
+                    $($(#[$meta])* AnyTransactionData::$id(_) => {},)+
                 }
             }
         }
@@ -1205,49 +2022,85 @@ impl_cast_any! {
     AccountDelete,
     AccountAllowanceApprove,
     AccountAllowanceDelete,
+    #[cfg(feature = "contracts")]
     ContractCreate,
+    #[cfg(feature = "contracts")]
     ContractUpdate,
+    #[cfg(feature = "contracts")]
     ContractDelete,
+    #[cfg(feature = "contracts")]
     ContractExecute,
     Transfer,
+    #[cfg(feature = "topics")]
     TopicCreate,
+    #[cfg(feature = "topics")]
     TopicUpdate,
+    #[cfg(feature = "topics")]
     TopicDelete,
+    #[cfg(feature = "topics")]
     TopicMessageSubmit,
+    #[cfg(feature = "files")]
     FileAppend,
+    #[cfg(feature = "files")]
     FileCreate,
+    #[cfg(feature = "files")]
     FileUpdate,
+    #[cfg(feature = "files")]
     FileDelete,
     Prng,
+    #[cfg(feature = "schedules")]
     ScheduleCreate,
+    #[cfg(feature = "schedules")]
     ScheduleSign,
+    #[cfg(feature = "schedules")]
     ScheduleDelete,
+    #[cfg(feature = "tokens")]
     TokenAssociate,
+    #[cfg(feature = "tokens")]
     TokenBurn,
+    #[cfg(feature = "tokens")]
     TokenCreate,
+    #[cfg(feature = "tokens")]
     TokenDelete,
+    #[cfg(feature = "tokens")]
     TokenDissociate,
+    #[cfg(feature = "tokens")]
     TokenFeeScheduleUpdate,
+    #[cfg(feature = "tokens")]
     TokenFreeze,
+    #[cfg(feature = "tokens")]
     TokenGrantKyc,
+    #[cfg(feature = "tokens")]
     TokenMint,
+    #[cfg(feature = "tokens")]
     TokenPause,
+    #[cfg(feature = "tokens")]
     TokenRevokeKyc,
+    #[cfg(feature = "tokens")]
     TokenUnfreeze,
+    #[cfg(feature = "tokens")]
     TokenUnpause,
+    #[cfg(feature = "tokens")]
     TokenUpdate,
+    #[cfg(feature = "tokens")]
     TokenWipe,
     SystemDelete,
     SystemUndelete,
     Freeze,
+    #[cfg(feature = "contracts")]
     Ethereum,
+    #[cfg(feature = "tokens")]
     TokenUpdateNfts,
     NodeCreate,
     NodeUpdate,
     NodeDelete,
+    #[cfg(feature = "tokens")]
     TokenReject,
-             TokenAirdrop,
+    #[cfg(feature = "tokens")]
+    TokenAirdrop,
+    #[cfg(feature = "tokens")]
     TokenClaimAirdrop,
+    #[cfg(feature = "tokens")]
     TokenCancelAirdrop,
     Batch
 }
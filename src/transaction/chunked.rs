@@ -28,6 +28,8 @@ pub struct ChunkData {
     pub(crate) max_chunks: usize,
     pub(crate) chunk_size: NonZeroUsize,
     pub(crate) data: Vec<u8>,
+    /// Send every chunk to the node that accepted the first one.
+    pub(crate) sticky_node: bool,
 }
 
 impl Default for ChunkData {
@@ -36,6 +38,7 @@ impl Default for ChunkData {
             max_chunks: Self::DEFAULT_MAX_CHUNKS,
             chunk_size: Self::DEFAULT_CHUNK_SIZE,
             data: Vec::new(),
+            sticky_node: false,
         }
     }
 }
@@ -166,11 +169,11 @@ where
     ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
         assert!(self.transaction.is_frozen());
 
-        Ok(self.transaction.make_request_inner(&ChunkInfo::initial(
+        self.transaction.make_request_inner(&ChunkInfo::initial(
             self.total_chunks,
             *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
             node_account_id,
-        )))
+        ))
     }
 
     fn execute(
@@ -227,6 +230,8 @@ pub(super) struct ChunkView<'a, D> {
     pub(super) initial_transaction_id: TransactionId,
     pub(super) current_chunk: usize,
     pub(super) total_chunks: usize,
+    // when `Some`, the chunk is only sent to this node.
+    pub(super) node_account_id: Option<AccountId>,
 }
 
 impl<'a, D> Execute for ChunkView<'a, D>
@@ -242,7 +247,10 @@ where
     type Response = TransactionResponse;
 
     fn node_account_ids(&self) -> Option<&[AccountId]> {
-        self.transaction.body.node_account_ids.as_deref()
+        match &self.node_account_id {
+            Some(it) => Some(std::slice::from_ref(it)),
+            None => self.transaction.body.node_account_ids.as_deref(),
+        }
     }
 
     fn transaction_id(&self) -> Option<TransactionId> {
@@ -268,13 +276,13 @@ where
     ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
         assert!(self.transaction.is_frozen());
 
-        Ok(self.transaction.make_request_inner(&ChunkInfo {
+        self.transaction.make_request_inner(&ChunkInfo {
             total: self.total_chunks,
             current: self.current_chunk,
             initial_transaction_id: self.initial_transaction_id,
             node_account_id: Some(node_account_id),
             current_transaction_id: *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
-        }))
+        })
     }
 
     fn execute(
@@ -7,7 +7,9 @@ use super::{
     ToTransactionDataProtobuf,
     TransactionBody,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     BoxGrpcFuture,
@@ -36,6 +38,7 @@ impl<D: Clone> CostTransaction<D> {
                 transaction_id: transaction.body.transaction_id,
                 operator: transaction.body.operator,
                 is_frozen: transaction.body.is_frozen,
+                frozen_environment: transaction.body.frozen_environment,
                 regenerate_transaction_id: transaction.body.regenerate_transaction_id,
                 custom_fee_limits: transaction.body.custom_fee_limits,
                 batch_key: transaction.body.batch_key,
@@ -58,6 +61,14 @@ impl<D: TransactionData> TransactionData for CostTransactionData<D> {
     fn for_cost_estimate(&self) -> bool {
         true
     }
+
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        self.inner.validate_data(problems);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        self.inner.describe_data(description);
+    }
 }
 
 impl<D: TransactionExecute> TransactionExecute for CostTransactionData<D> {
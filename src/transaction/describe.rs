@@ -0,0 +1,585 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+use super::TransactionExecute;
+#[cfg(feature = "schedules")]
+use super::{
+    AnyTransactionData,
+    TransactionData,
+};
+use crate::staked_id::StakedId;
+use crate::{
+    AccountId,
+    Hbar,
+    Key,
+    NftId,
+    TokenId,
+    Transaction,
+    TransactionId,
+};
+
+/// A human readable summary of a [`Transaction`], made by [`Transaction::describe`].
+///
+/// Meant for confirmation prompts and audit logs,
+/// the [`Display`](fmt::Display) impl gives a multi-line summary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TransactionDescription {
+    /// The kind of transaction, for example `TransferTransaction`.
+    pub kind: &'static str,
+
+    /// The ID of the transaction, if one has been set.
+    pub transaction_id: Option<TransactionId>,
+
+    /// The nodes that the transaction may be submitted to, if they've been set.
+    pub node_account_ids: Vec<AccountId>,
+
+    /// The maximum fee the payer is willing to pay.
+    ///
+    /// If the transaction has no explicit max fee, this is the default for its kind.
+    pub max_transaction_fee: Hbar,
+
+    /// The memo of the transaction.
+    pub transaction_memo: String,
+
+    /// The entities the transaction touches, along with their role (like `"treasury"`).
+    pub entities: Vec<(&'static str, String)>,
+
+    /// Other values set by the transaction that aren't entities, like amounts, along with their name (like `"amount"`).
+    pub fields: Vec<(&'static str, String)>,
+
+    /// The fungible (and hbar) transfers done by the transaction.
+    pub transfers: Vec<DescribedTransfer>,
+
+    /// The NFT transfers done by the transaction.
+    pub nft_transfers: Vec<DescribedNftTransfer>,
+
+    /// The keys set by the transaction, along with their role (like `"admin_key"`).
+    pub keys: Vec<(&'static str, String)>,
+}
+
+/// A fungible token or hbar transfer in a [`TransactionDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DescribedTransfer {
+    /// The token being transferred, or `None` for hbar.
+    pub token_id: Option<TokenId>,
+
+    /// The account being credited (positive) or debited (negative).
+    pub account_id: AccountId,
+
+    /// The amount, in tinybars for hbar or the smallest denomination for tokens.
+    pub amount: i64,
+
+    /// If this transfer uses an allowance.
+    pub is_approval: bool,
+}
+
+/// An NFT transfer in a [`TransactionDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DescribedNftTransfer {
+    /// The NFT being transferred.
+    pub nft_id: NftId,
+
+    /// The account the NFT is transferred from.
+    pub sender: AccountId,
+
+    /// The account the NFT is transferred to.
+    pub receiver: AccountId,
+
+    /// If this transfer uses an allowance.
+    pub is_approval: bool,
+}
+
+impl TransactionDescription {
+    /// Describe `data` on its own, IE, a scheduled transaction, which has no ID or nodes of its own.
+    #[cfg(feature = "schedules")]
+    pub(crate) fn of_data(data: &AnyTransactionData) -> Self {
+        let mut description = Self {
+            kind: kind_name::<AnyTransactionData>(),
+            transaction_id: None,
+            node_account_ids: Vec::new(),
+            max_transaction_fee: data.default_max_transaction_fee(),
+            transaction_memo: String::new(),
+            entities: Vec::new(),
+            fields: Vec::new(),
+            transfers: Vec::new(),
+            nft_transfers: Vec::new(),
+            keys: Vec::new(),
+        };
+
+        data.describe_data(&mut description);
+
+        description
+    }
+
+    /// Record `nested` (a scheduled or inner transaction) as `role`,
+    /// and merge its entities, fields, transfers, and keys into this description.
+    pub(crate) fn nested(&mut self, role: &'static str, nested: Self) -> &mut Self {
+        match nested.transaction_id {
+            Some(transaction_id) => self.entity(role, format!("{} {transaction_id}", nested.kind)),
+            None => self.entity(role, nested.kind),
+        };
+
+        self.entities.extend(nested.entities);
+        self.fields.extend(nested.fields);
+        self.transfers.extend(nested.transfers);
+        self.nft_transfers.extend(nested.nft_transfers);
+        self.keys.extend(nested.keys);
+
+        self
+    }
+
+    /// Record that the transaction touches `id` as `role`.
+    pub(crate) fn entity(&mut self, role: &'static str, id: impl ToString) -> &mut Self {
+        self.entities.push((role, id.to_string()));
+        self
+    }
+
+    /// Record that the transaction touches `id` as `role`, if it's set.
+    pub(crate) fn optional_entity<T: fmt::Display>(
+        &mut self,
+        role: &'static str,
+        id: Option<&T>,
+    ) -> &mut Self {
+        if let Some(id) = id {
+            self.entity(role, id);
+        }
+
+        self
+    }
+
+    /// Record that the transaction sets the field `name` to `value`.
+    pub(crate) fn field(&mut self, name: &'static str, value: impl ToString) -> &mut Self {
+        self.fields.push((name, value.to_string()));
+        self
+    }
+
+    /// Record that the transaction sets the field `name` to `value`, if it's set.
+    pub(crate) fn optional_field<T: fmt::Display>(
+        &mut self,
+        name: &'static str,
+        value: Option<&T>,
+    ) -> &mut Self {
+        if let Some(value) = value {
+            self.field(name, value);
+        }
+
+        self
+    }
+
+    /// Record the account or node that the transaction stakes to, if it's set.
+    pub(crate) fn staked_id(&mut self, staked_id: Option<StakedId>) -> &mut Self {
+        match staked_id {
+            Some(StakedId::AccountId(account_id)) => self.entity("staked_account", account_id),
+            Some(StakedId::NodeId(node_id)) => self.entity("staked_node", node_id),
+            None => self,
+        }
+    }
+
+    /// Record the serial numbers the transaction touches as `role`, if there are any.
+    pub(crate) fn serials<T: fmt::Display>(
+        &mut self,
+        role: &'static str,
+        serials: &[T],
+    ) -> &mut Self {
+        if !serials.is_empty() {
+            let serials: Vec<_> = serials.iter().map(ToString::to_string).collect();
+            self.entity(role, serials.join(", "));
+        }
+
+        self
+    }
+
+    /// Record that the transaction sets `key` as `role`, if it's set.
+    pub(crate) fn key(&mut self, role: &'static str, key: Option<&Key>) -> &mut Self {
+        if let Some(key) = key {
+            self.keys.push((role, describe_key(key)));
+        }
+
+        self
+    }
+
+    /// Record a fungible token or hbar transfer.
+    pub(crate) fn transfer(&mut self, transfer: DescribedTransfer) -> &mut Self {
+        self.transfers.push(transfer);
+        self
+    }
+
+    /// Record an NFT transfer.
+    pub(crate) fn nft_transfer(&mut self, transfer: DescribedNftTransfer) -> &mut Self {
+        self.nft_transfers.push(transfer);
+        self
+    }
+
+    /// Returns this description as JSON.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        use serde_json::json;
+
+        let entities: Vec<_> =
+            self.entities.iter().map(|(role, id)| json!({ "role": role, "id": id })).collect();
+
+        let fields: Vec<_> = self
+            .fields
+            .iter()
+            .map(|(name, value)| json!({ "name": name, "value": value }))
+            .collect();
+
+        let transfers: Vec<_> = self
+            .transfers
+            .iter()
+            .map(|it| {
+                json!({
+                    "tokenId": it.token_id.map(|it| it.to_string()),
+                    "accountId": it.account_id.to_string(),
+                    "amount": it.amount,
+                    "isApproval": it.is_approval,
+                })
+            })
+            .collect();
+
+        let nft_transfers: Vec<_> = self
+            .nft_transfers
+            .iter()
+            .map(|it| {
+                json!({
+                    "nftId": it.nft_id.to_string(),
+                    "sender": it.sender.to_string(),
+                    "receiver": it.receiver.to_string(),
+                    "isApproval": it.is_approval,
+                })
+            })
+            .collect();
+
+        let keys: Vec<_> =
+            self.keys.iter().map(|(role, key)| json!({ "role": role, "key": key })).collect();
+
+        let node_account_ids: Vec<_> =
+            self.node_account_ids.iter().map(ToString::to_string).collect();
+
+        json!({
+            "kind": self.kind,
+            "transactionId": self.transaction_id.map(|it| it.to_string()),
+            "nodeAccountIds": node_account_ids,
+            "maxTransactionFee": self.max_transaction_fee.to_tinybars(),
+            "transactionMemo": self.transaction_memo,
+            "entities": entities,
+            "fields": fields,
+            "transfers": transfers,
+            "nftTransfers": nft_transfers,
+            "keys": keys,
+        })
+    }
+}
+
+impl fmt::Display for TransactionDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.kind)?;
+
+        match &self.transaction_id {
+            Some(id) => writeln!(f, "  transaction ID: {id}")?,
+            None => writeln!(f, "  transaction ID: (not set)")?,
+        }
+
+        if !self.node_account_ids.is_empty() {
+            let nodes: Vec<_> = self.node_account_ids.iter().map(ToString::to_string).collect();
+            writeln!(f, "  nodes: {}", nodes.join(", "))?;
+        }
+
+        writeln!(f, "  max transaction fee: {}", self.max_transaction_fee)?;
+
+        if !self.transaction_memo.is_empty() {
+            writeln!(f, "  memo: {:?}", self.transaction_memo)?;
+        }
+
+        for (role, id) in &self.entities {
+            writeln!(f, "  {role}: {id}")?;
+        }
+
+        for (name, value) in &self.fields {
+            writeln!(f, "  {name}: {value}")?;
+        }
+
+        for transfer in &self.transfers {
+            let approval = if transfer.is_approval { " (approved)" } else { "" };
+            match transfer.token_id {
+                Some(token_id) => writeln!(
+                    f,
+                    "  transfer: {} {} of token {token_id}{approval}",
+                    transfer.account_id, transfer.amount
+                )?,
+                None => writeln!(
+                    f,
+                    "  transfer: {} {}{approval}",
+                    transfer.account_id,
+                    Hbar::from_tinybars(transfer.amount)
+                )?,
+            }
+        }
+
+        for transfer in &self.nft_transfers {
+            let approval = if transfer.is_approval { " (approved)" } else { "" };
+            writeln!(
+                f,
+                "  nft transfer: {} from {} to {}{approval}",
+                transfer.nft_id, transfer.sender, transfer.receiver
+            )?;
+        }
+
+        for (role, key) in &self.keys {
+            writeln!(f, "  {role}: {key}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Describe `key` without its protobuf representation.
+fn describe_key(key: &Key) -> String {
+    match key {
+        Key::Single(key) if key.is_ed25519() => format!("ed25519 {key}"),
+        Key::Single(key) => format!("ecdsa {key}"),
+        Key::ContractId(id) => format!("contract {id}"),
+        Key::DelegateContractId(id) => format!("delegatable contract {id}"),
+        Key::KeyList(list) => {
+            let keys: Vec<_> = list.keys.iter().map(describe_key).collect();
+            let threshold = list.threshold.map_or(list.len(), |it| it as usize);
+
+            format!("{threshold} of [{}]", keys.join(", "))
+        }
+    }
+}
+
+/// Returns the user facing name of the transaction type `D`.
+pub(super) fn kind_name<D>() -> &'static str {
+    let name = std::any::type_name::<D>();
+    let name = name.rsplit("::").next().unwrap_or(name);
+
+    name.strip_suffix("Data").unwrap_or(name)
+}
+
+impl<D: TransactionExecute> Transaction<D> {
+    /// Returns a human readable summary of this transaction.
+    ///
+    /// This includes the kind of transaction, the entities it touches,
+    /// any transfers it makes, the keys it sets, and its fees.
+    ///
+    /// Use the [`Display`](fmt::Display) impl of the result for text,
+    /// or [`TransactionDescription::to_json`] for structured logging.
+    #[must_use]
+    pub fn describe(&self) -> TransactionDescription {
+        let mut description = TransactionDescription {
+            kind: kind_name::<D>(),
+            transaction_id: self.get_transaction_id(),
+            node_account_ids: self.get_node_account_ids().map(<[_]>::to_vec).unwrap_or_default(),
+            max_transaction_fee: self.effective_max_transaction_fee(None),
+            transaction_memo: self.get_transaction_memo().to_owned(),
+            entities: Vec::new(),
+            fields: Vec::new(),
+            transfers: Vec::new(),
+            nft_transfers: Vec::new(),
+            keys: Vec::new(),
+        };
+
+        self.data().describe_data(&mut description);
+
+        description
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use super::{
+        DescribedTransfer,
+        TransactionDescription,
+    };
+    use crate::transaction::test_helpers::TEST_TX_ID;
+    use crate::{
+        AccountId,
+        Hbar,
+        TransferTransaction,
+    };
+
+    #[test]
+    fn transfer() {
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(2.into(), Hbar::new(-1))
+            .hbar_transfer(3.into(), Hbar::new(1))
+            .token_transfer("0.0.10".parse().unwrap(), 2.into(), -5)
+            .token_transfer("0.0.10".parse().unwrap(), 3.into(), 5)
+            .transaction_id(TEST_TX_ID)
+            .node_account_ids([AccountId::new(0, 0, 3)])
+            .transaction_memo("rent");
+
+        let description = tx.describe();
+
+        assert_eq!(description.kind, "TransferTransaction");
+        assert_eq!(
+            description.transfers[0],
+            DescribedTransfer {
+                token_id: None,
+                account_id: 2.into(),
+                amount: -100_000_000,
+                is_approval: false,
+            }
+        );
+
+        expect![[r#"
+            TransferTransaction
+              transaction ID: 0.0.5006@1554158542.0
+              nodes: 0.0.3
+              max transaction fee: 2 ℏ
+              memo: "rent"
+              transfer: 0.0.2 -1 ℏ
+              transfer: 0.0.3 1 ℏ
+              transfer: 0.0.2 -5 of token 0.0.10
+              transfer: 0.0.3 5 of token 0.0.10
+        "#]]
+        .assert_eq(&description.to_string());
+    }
+
+    #[cfg(feature = "tokens")]
+    #[test]
+    fn token_create_keys() {
+        use crate::transaction::test_helpers::unused_private_key;
+        use crate::{
+            KeyList,
+            TokenCreateTransaction,
+        };
+
+        let key = unused_private_key().public_key();
+
+        let mut tx = TokenCreateTransaction::new();
+        tx.name("ffff")
+            .symbol("F")
+            .treasury_account_id(AccountId::new(0, 0, 5))
+            .admin_key(key)
+            .supply_key(KeyList { keys: vec![key.into(), key.into()], threshold: Some(1) });
+
+        let description = tx.describe();
+
+        assert_eq!(description.entities, [("treasury", "0.0.5".to_owned())]);
+        assert_eq!(description.keys[0], ("admin_key", format!("ed25519 {key}")));
+        assert_eq!(
+            description.keys[1],
+            ("supply_key", format!("1 of [ed25519 {key}, ed25519 {key}]"))
+        );
+    }
+
+    #[test]
+    fn account_update_key() {
+        use crate::transaction::test_helpers::unused_private_key;
+        use crate::AccountUpdateTransaction;
+
+        let key = unused_private_key().public_key();
+
+        let mut tx = AccountUpdateTransaction::new();
+        tx.account_id(AccountId::new(0, 0, 5)).key(key);
+
+        let description = tx.describe();
+
+        assert_eq!(description.entities, [("account", "0.0.5".to_owned())]);
+        assert_eq!(description.keys, [("key", format!("ed25519 {key}"))]);
+    }
+
+    #[cfg(feature = "tokens")]
+    #[test]
+    fn token_mint_fields() {
+        use crate::TokenMintTransaction;
+
+        let mut tx = TokenMintTransaction::new();
+        tx.token_id("0.0.10".parse().unwrap()).amount(5);
+
+        let description = tx.describe();
+
+        assert_eq!(description.entities, [("token", "0.0.10".to_owned())]);
+        assert_eq!(description.fields, [("amount", "5".to_owned())]);
+    }
+
+    #[test]
+    fn freeze_type_field() {
+        use crate::{
+            FreezeTransaction,
+            FreezeType,
+        };
+
+        let mut tx = FreezeTransaction::new();
+        tx.freeze_type(FreezeType::FreezeAbort);
+
+        let description = tx.describe();
+
+        assert!(description.entities.is_empty());
+        assert_eq!(description.fields, [("freeze_type", "FREEZE_ABORT".to_owned())]);
+    }
+
+    #[cfg(feature = "schedules")]
+    #[test]
+    fn scheduled_transfer() {
+        use crate::ScheduleCreateTransaction;
+
+        let mut transfer = TransferTransaction::new();
+        transfer.hbar_transfer(2.into(), Hbar::new(-1)).hbar_transfer(3.into(), Hbar::new(1));
+
+        let mut tx = ScheduleCreateTransaction::new();
+        tx.scheduled_transaction(transfer).payer_account_id(AccountId::new(0, 0, 5));
+
+        let description = tx.describe();
+
+        assert_eq!(
+            description.entities,
+            [
+                ("payer", "0.0.5".to_owned()),
+                ("scheduled_transaction", "TransferTransaction".to_owned()),
+            ]
+        );
+        assert_eq!(description.transfers.len(), 2);
+        assert_eq!(description.transfers[1].amount, 100_000_000);
+    }
+
+    #[cfg(feature = "topics")]
+    #[test]
+    fn topic_message_submit_without_id() {
+        use crate::TopicMessageSubmitTransaction;
+
+        let description: TransactionDescription = TopicMessageSubmitTransaction::new().describe();
+
+        assert_eq!(description.kind, "TopicMessageSubmitTransaction");
+        assert_eq!(description.transaction_id, None);
+        assert!(description.entities.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json() {
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(2.into(), Hbar::new(-1)).hbar_transfer(3.into(), Hbar::new(1));
+
+        let json = tx.describe().to_json();
+
+        assert_eq!(json["kind"], "TransferTransaction");
+        assert_eq!(json["transactionId"], serde_json::Value::Null);
+        assert_eq!(json["transfers"][1]["accountId"], "0.0.3");
+        assert_eq!(json["transfers"][1]["amount"], 100_000_000);
+    }
+
+    #[cfg(all(feature = "serde", feature = "tokens"))]
+    #[test]
+    fn json_fields() {
+        use crate::TokenBurnTransaction;
+
+        let mut tx = TokenBurnTransaction::new();
+        tx.token_id("0.0.10".parse().unwrap()).amount(5_u64);
+
+        let json = tx.describe().to_json();
+
+        assert_eq!(json["entities"][0]["id"], "0.0.10");
+        assert_eq!(json["fields"][0]["name"], "amount");
+        assert_eq!(json["fields"][0]["value"], "5");
+    }
+}
@@ -13,10 +13,13 @@ use super::{
     ChunkData,
     TransactionSources,
 };
+use crate::client::InFlightRequest;
 use crate::execute::Execute;
 use crate::ledger_id::RefLedgerId;
 use crate::transaction::any::AnyTransactionData;
+use crate::transaction::describe::TransactionDescription;
 use crate::transaction::protobuf::ToTransactionDataProtobuf;
+use crate::transaction::validate::ValidationProblem;
 use crate::transaction::DEFAULT_TRANSACTION_VALID_DURATION;
 use crate::{
     AccountId,
@@ -24,7 +27,7 @@ use crate::{
     Client,
     Error,
     Hbar,
-    PublicKey,
+    SignaturePair,
     ToProtobuf,
     Transaction,
     TransactionHash,
@@ -33,36 +36,6 @@ use crate::{
     ValidateChecksums,
 };
 
-#[derive(Debug)]
-pub(super) struct SignaturePair {
-    signature: Vec<u8>,
-    public: PublicKey,
-}
-
-impl SignaturePair {
-    pub fn into_protobuf(self) -> services::SignaturePair {
-        let signature = match self.public.kind() {
-            crate::key::KeyKind::Ed25519 => {
-                services::signature_pair::Signature::Ed25519(self.signature)
-            }
-            crate::key::KeyKind::Ecdsa => {
-                services::signature_pair::Signature::EcdsaSecp256k1(self.signature)
-            }
-        };
-        services::SignaturePair {
-            signature: Some(signature),
-            // TODO: is there any way to utilize the _prefix_ nature of this field?
-            pub_key_prefix: self.public.to_bytes_raw(),
-        }
-    }
-}
-
-impl From<(PublicKey, Vec<u8>)> for SignaturePair {
-    fn from((public, signature): (PublicKey, Vec<u8>)) -> Self {
-        Self { signature, public }
-    }
-}
-
 impl<D> Transaction<D>
 where
     D: TransactionData + ToTransactionDataProtobuf,
@@ -70,7 +43,7 @@ where
     pub(crate) fn make_request_inner(
         &self,
         chunk_info: &ChunkInfo,
-    ) -> (services::Transaction, TransactionHash) {
+    ) -> crate::Result<(services::Transaction, TransactionHash)> {
         let transaction_body = self.to_transaction_body_protobuf(chunk_info);
 
         let body_bytes = transaction_body.encode_to_vec();
@@ -78,16 +51,16 @@ where
         let mut signatures = Vec::with_capacity(1 + self.signers.len());
 
         if let Some(operator) = &self.body.operator {
-            let operator_signature = operator.sign(&body_bytes);
+            let operator_signature = operator.sign(&body_bytes)?;
 
-            signatures.push(SignaturePair::from(operator_signature).into_protobuf());
+            signatures.push(SignaturePair::from(operator_signature).to_protobuf());
         }
 
         for signer in &self.signers {
             let public_key = signer.public_key().to_bytes();
             if !signatures.iter().any(|it| public_key.starts_with(&it.pub_key_prefix)) {
-                let signature = signer.sign(&body_bytes);
-                signatures.push(SignaturePair::from(signature).into_protobuf());
+                let signature = signer.sign(&body_bytes)?;
+                signatures.push(SignaturePair::from(signature).to_protobuf());
             }
         }
 
@@ -103,7 +76,7 @@ where
         let transaction =
             services::Transaction { signed_transaction_bytes, ..services::Transaction::default() };
 
-        (transaction, transaction_hash)
+        Ok((transaction, transaction_hash))
     }
 }
 
@@ -133,6 +106,23 @@ pub trait TransactionData: Clone + Into<AnyTransactionData> {
     fn wait_for_receipt(&self) -> bool {
         false
     }
+
+    /// Report problems with the transaction data that can be found without the network.
+    ///
+    /// Used by [`Transaction::validate`].
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>);
+
+    /// Returns the accounts that have to sign the transaction (besides the payer), with the names of their fields.
+    ///
+    /// Used by [`Transaction::validate`].
+    fn required_signers(&self) -> Vec<(&'static str, AccountId)> {
+        Vec::new()
+    }
+
+    /// Add the entities, transfers, and keys of the transaction data to `description`.
+    ///
+    /// Used by [`Transaction::describe`].
+    fn describe_data(&self, description: &mut TransactionDescription);
 }
 
 pub trait TransactionExecute:
@@ -177,6 +167,10 @@ where
         self.body.regenerate_transaction_id
     }
 
+    fn frozen_environment(&self) -> Option<u64> {
+        self.body.frozen_environment
+    }
+
     fn make_request(
         &self,
         transaction_id: Option<&TransactionId>,
@@ -184,10 +178,10 @@ where
     ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
         assert!(self.is_frozen());
 
-        Ok(self.make_request_inner(&ChunkInfo::single(
+        self.make_request_inner(&ChunkInfo::single(
             *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
             node_account_id,
-        )))
+        ))
     }
 
     fn execute(
@@ -291,49 +285,71 @@ pub(crate) struct SourceTransaction<'a, D> {
 }
 
 impl<'a, D> SourceTransaction<'a, D> {
-    pub(crate) fn new(transaction: &'a Transaction<D>, sources: &'a TransactionSources) -> Self {
+    pub(crate) fn new(
+        transaction: &'a Transaction<D>,
+        sources: &'a TransactionSources,
+    ) -> crate::Result<Self> {
         // fixme: be way more lazy.
-        let sources = sources.sign_with(&transaction.signers);
+        let sources = sources.sign_with(&transaction.signers)?;
 
-        Self { inner: transaction, sources }
+        Ok(Self { inner: transaction, sources })
     }
 
     pub(crate) async fn execute(
         &self,
         client: &Client,
+        in_flight: &InFlightRequest<'_>,
         timeout: Option<std::time::Duration>,
     ) -> crate::Result<TransactionResponse>
     where
         D: TransactionExecute,
     {
-        Ok(self.execute_all(client, timeout).await?.swap_remove(0))
+        let mut responses = Vec::with_capacity(self.sources.chunks_len());
+
+        self.execute_all_into(client, in_flight, timeout, &mut responses).await?;
+
+        Ok(responses.swap_remove(0))
     }
 
-    pub(crate) async fn execute_all(
+    /// Execute every chunk as part of `in_flight`, pushing the response of each chunk that's submitted to `responses`
+    /// before its receipt is waited for, so that the chunks before a failing one aren't lost.
+    pub(crate) async fn execute_all_into(
         &self,
         client: &Client,
+        in_flight: &InFlightRequest<'_>,
         timeout_per_chunk: Option<std::time::Duration>,
-    ) -> crate::Result<Vec<TransactionResponse>>
+        responses: &mut Vec<TransactionResponse>,
+    ) -> crate::Result<()>
     where
         D: TransactionExecute,
     {
-        let mut responses = Vec::with_capacity(self.sources.chunks_len());
+        let sticky_node = self.inner.data().maybe_chunk_data().is_some_and(|it| it.sticky_node);
+
+        let first = responses.len();
+        responses.reserve(self.sources.chunks_len());
+
         for chunk in self.sources.chunks() {
-            let response = crate::execute::execute(
+            let node_account_id =
+                responses.get(first).filter(|_| sticky_node).map(|it| it.node_account_id);
+
+            let response = crate::execute::execute_in_flight(
                 client,
-                &SourceTransactionExecuteView::new(self.inner, chunk),
+                in_flight,
+                &SourceTransactionExecuteView::new(self.inner, chunk, node_account_id),
                 timeout_per_chunk,
             )
             .await?;
 
+            responses.push(response);
+
             if self.inner.data().wait_for_receipt() {
-                response.get_receipt(client).await?;
+                let query = responses[responses.len() - 1].get_receipt_query();
+                crate::execute::execute_in_flight(client, in_flight, &query, timeout_per_chunk)
+                    .await?;
             }
-
-            responses.push(response);
         }
 
-        Ok(responses)
+        Ok(())
     }
 }
 
@@ -342,13 +358,19 @@ struct SourceTransactionExecuteView<'a, D> {
     transaction: &'a Transaction<D>,
     chunk: SourceChunk<'a>,
     indecies_by_node_id: HashMap<AccountId, usize>,
+    // when `Some`, the chunk is only sent to this node.
+    node_account_id: Option<AccountId>,
 }
 
 impl<'a, D> SourceTransactionExecuteView<'a, D> {
-    fn new(transaction: &'a Transaction<D>, chunk: SourceChunk<'a>) -> Self {
+    fn new(
+        transaction: &'a Transaction<D>,
+        chunk: SourceChunk<'a>,
+        node_account_id: Option<AccountId>,
+    ) -> Self {
         let indecies_by_node_id =
             chunk.node_ids().iter().copied().enumerate().map(|it| (it.1, it.0)).collect();
-        Self { transaction, chunk, indecies_by_node_id }
+        Self { transaction, chunk, indecies_by_node_id, node_account_id }
     }
 }
 
@@ -368,6 +390,10 @@ impl<'a, D: TransactionExecute> Execute for SourceTransactionExecuteView<'a, D>
     type Response = <Transaction<D> as Execute>::Response;
 
     fn node_account_ids(&self) -> Option<&[AccountId]> {
+        if let Some(node_account_id) = &self.node_account_id {
+            return Some(std::slice::from_ref(node_account_id));
+        }
+
         let node_ids = self.chunk.node_ids();
         if node_ids.is_empty() {
             None // Use client's default nodes
@@ -392,6 +418,10 @@ impl<'a, D: TransactionExecute> Execute for SourceTransactionExecuteView<'a, D>
         Some(self.chunk.transaction_id().is_none())
     }
 
+    fn frozen_environment(&self) -> Option<u64> {
+        self.transaction.body.frozen_environment
+    }
+
     fn make_request(
         &self,
         transaction_id: Option<&TransactionId>,
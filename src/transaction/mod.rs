@@ -14,19 +14,32 @@ use prost::Message;
 use time::Duration;
 use triomphe::Arc;
 
+use crate::client::InFlightRequest;
 use crate::custom_fee_limit::CustomFeeLimit;
 use crate::downcast::DowncastOwned;
-use crate::execute::execute;
+use crate::execute::{
+    cancellable,
+    execute,
+    execute_in_flight,
+    execution_plan,
+};
+use crate::logger::client_log;
+use crate::protobuf::FromProtobufContext;
 use crate::signer::AnySigner;
+#[cfg(feature = "schedules")]
+use crate::ScheduleCreateTransaction;
 use crate::{
     AccountId,
+    CancellationToken,
+    ChunkedTransactionResponse,
     Client,
     Error,
+    ExecutionPlan,
     Hbar,
     Operator,
     PrivateKey,
     PublicKey,
-    ScheduleCreateTransaction,
+    RetryEvent,
     ToProtobuf,
     TransactionHash,
     TransactionId,
@@ -37,11 +50,13 @@ use crate::{
 mod any;
 mod chunked;
 mod cost;
+mod describe;
 mod execute;
 mod protobuf;
 mod source;
 #[cfg(test)]
 mod tests;
+mod validate;
 
 pub use any::AnyTransaction;
 pub(crate) use any::AnyTransactionData;
@@ -51,6 +66,11 @@ pub(crate) use chunked::{
     ChunkedTransactionData,
 };
 pub(crate) use cost::CostTransaction;
+pub use describe::{
+    DescribedNftTransfer,
+    DescribedTransfer,
+    TransactionDescription,
+};
 pub(crate) use execute::{
     TransactionData,
     TransactionExecute,
@@ -61,6 +81,14 @@ pub(crate) use protobuf::{
     ToTransactionDataProtobuf,
 };
 pub(crate) use source::TransactionSources;
+pub use validate::ValidationProblem;
+pub(crate) use validate::{
+    check_key,
+    check_memo,
+    check_required,
+    check_updated_key,
+    MAX_MEMO_LEN,
+};
 
 const DEFAULT_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(120);
 
@@ -92,6 +120,9 @@ pub(crate) struct TransactionBody<D> {
 
     pub(crate) is_frozen: bool,
 
+    /// The environment of the client this was frozen with, see [`Client::switch_environment`].
+    pub(crate) frozen_environment: Option<u64>,
+
     pub(crate) regenerate_transaction_id: Option<bool>,
 
     /// The maximum custom fee that the user is willing to pay for the message.
@@ -118,6 +149,7 @@ where
                 transaction_id: None,
                 operator: None,
                 is_frozen: false,
+                frozen_environment: None,
                 regenerate_transaction_id: None,
                 custom_fee_limits: Vec::new(),
                 batch_key: None,
@@ -168,7 +200,7 @@ impl<D> Transaction<D> {
         self.sources.as_ref()
     }
 
-    fn signed_sources(&self) -> Option<Cow<'_, TransactionSources>> {
+    fn signed_sources(&self) -> Option<crate::Result<Cow<'_, TransactionSources>>> {
         self.sources().map(|it| it.sign_with(&self.signers))
     }
 
@@ -237,7 +269,8 @@ impl<D> Transaction<D> {
 
     /// Sets the duration that this transaction is valid for, once finalized and signed.
     ///
-    /// Defaults to 120 seconds (or two minutes).
+    /// Defaults to 120 seconds (or two minutes), the network allows at most 180 seconds,
+    /// which [`freeze_with`](Self::freeze_with) checks.
     pub fn transaction_valid_duration(&mut self, duration: Duration) -> &mut Self {
         self.body_mut().transaction_valid_duration = Some(duration);
         self
@@ -328,6 +361,18 @@ impl<D> Transaction<D> {
         self.sign_signer(AnySigner::arbitrary(Box::new(public_key), signer))
     }
 
+    /// Sign the transaction with a signer that can fail, IE, a hardware wallet.
+    ///
+    /// A failure is returned by whatever signs the transaction,
+    /// IE, [`execute`](Self::execute) or [`to_bytes`](Self::to_bytes).
+    pub fn try_sign_with<F: Fn(&[u8]) -> crate::Result<Vec<u8>> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.sign_signer(AnySigner::fallible(Box::new(public_key), signer))
+    }
+
     pub(crate) fn sign_signer(&mut self, signer: AnySigner) -> &mut Self {
         // We're _supposed_ to require frozen here, but really there's no reason I can think of to do that.
 
@@ -374,6 +419,25 @@ impl<D: ChunkedTransactionData> Transaction<D> {
         self
     }
 
+    /// Returns whether every chunk of this transaction is sent to the node that accepted the first chunk.
+    #[must_use]
+    pub fn get_sticky_chunk_node(&self) -> bool {
+        self.data().chunk_data().sticky_node
+    }
+
+    /// Sets whether every chunk of this transaction is sent to the node that accepted the first chunk,
+    /// rather than to any of the nodes (IE, any healthy node in [`node_account_ids`](Self::node_account_ids)).
+    ///
+    /// Defaults to `false`.
+    ///
+    /// Note: If that node becomes unhealthy partway through, the remaining chunks are retried against it,
+    /// rather than another node, until the request times out.
+    pub fn sticky_chunk_node(&mut self, sticky: bool) -> &mut Self {
+        self.data_mut().chunk_data_mut().sticky_node = sticky;
+
+        self
+    }
+
     /// Returns whether or not the transaction ID should be refreshed if a [`Status::TransactionExpired`](crate::Status::TransactionExpired) occurs.
     ///
     /// By default, the value on Client will be used.
@@ -398,6 +462,8 @@ impl<D: ValidateChecksums> Transaction<D> {
     ///
     /// # Errors
     /// - [`Error::FreezeUnsetNodeAccountIds`] if no [`node_account_ids`](Self::node_account_ids) were set.
+    /// - [`Error::TransactionValidDurationOutOfRange`] if the [`transaction_valid_duration`](Self::transaction_valid_duration)
+    ///   is out of range.
     ///
     /// # Panics
     /// - If `node_account_ids` is explicitly set to empty (IE: `tx.node_account_ids([]).freeze_with(None)`).
@@ -409,6 +475,8 @@ impl<D: ValidateChecksums> Transaction<D> {
     ///
     /// # Errors
     /// - [`Error::FreezeUnsetNodeAccountIds`] if no [`node_account_ids`](Self::node_account_ids) were set and `client.is_none()`.
+    /// - [`Error::TransactionValidDurationOutOfRange`] if the [`transaction_valid_duration`](Self::transaction_valid_duration)
+    ///   isn't more than 0 seconds and at most 180 seconds.
     ///
     /// # Panics
     /// - If `node_account_ids` is explicitly set to empty (IE: `tx.node_account_ids([]).freeze_with(None)`).
@@ -421,14 +489,30 @@ impl<D: ValidateChecksums> Transaction<D> {
         }
         let client: Option<&Client> = client.into();
 
+        if let Some(duration) = self.body.transaction_valid_duration {
+            if !duration.is_positive() || duration > validate::MAX_TRANSACTION_VALID_DURATION {
+                return Err(Error::TransactionValidDurationOutOfRange(duration));
+            }
+        }
+
         // set transaction id if not set based on client operator
         if self.get_transaction_id().is_none() {
+            let client = client.expect("Client must have an operator");
             let operator: Arc<Operator> =
-                client.and_then(Client::full_load_operator).expect("Client must have an operator");
-            let transaction_id = TransactionId::generate(operator.account_id);
+                client.full_load_operator().expect("Client must have an operator");
+            let transaction_id =
+                TransactionId::generate_at(operator.account_id, client.clock().now());
             self.transaction_id(transaction_id);
         }
 
+        if self.body.transaction_memo.is_empty() {
+            if let (Some(client), Some(transaction_id)) = (client, self.get_transaction_id()) {
+                if let Some(memo) = client.render_memo(&transaction_id) {
+                    self.body.transaction_memo = memo;
+                }
+            }
+        }
+
         let node_account_ids = match &self.body.node_account_ids {
             // the clone here is the lesser of two evils.
             Some(it) => {
@@ -437,12 +521,8 @@ impl<D: ValidateChecksums> Transaction<D> {
             }
             #[allow(clippy::missing_panics_doc)]
             None => {
-                let nodes = client
-                    .ok_or(Error::FreezeUnsetNodeAccountIds)?
-                    .net()
-                    .0
-                    .load()
-                    .random_node_ids();
+                let client = client.ok_or(Error::FreezeUnsetNodeAccountIds)?;
+                let nodes = client.net().0.load().random_node_ids(client.logger());
                 assert!(!nodes.is_empty(), "BUG: Client didn't give any nodes (all unhealthy)");
 
                 nodes
@@ -465,6 +545,7 @@ impl<D: ValidateChecksums> Transaction<D> {
         self.body.max_transaction_fee = max_transaction_fee;
         self.body.operator = operator;
         self.body.is_frozen = true;
+        self.body.frozen_environment = client.map(Client::environment);
         self.body.custom_fee_limits = custom_fee_limits;
 
         if let Some(client) = client {
@@ -574,10 +655,10 @@ impl<D: TransactionExecute> Transaction<D> {
 
     pub(crate) fn make_sources(&self) -> crate::Result<Cow<'_, TransactionSources>> {
         if let Some(sources) = self.signed_sources() {
-            return Ok(sources);
+            return sources;
         }
 
-        return Ok(Cow::Owned(TransactionSources::new(self.make_transaction_list()?).unwrap()));
+        return Ok(Cow::Owned(TransactionSources::new(self.make_transaction_list()?)?));
     }
 
     /// Convert `self` to protobuf encoded bytes.
@@ -588,13 +669,28 @@ impl<D: TransactionExecute> Transaction<D> {
     /// # Panics
     /// - If `!self.is_frozen()`.
     pub fn to_bytes(&self) -> crate::Result<Vec<u8>> {
-        let transaction_list = self
-            .signed_sources()
-            .map_or_else(|| self.make_transaction_list(), |it| Ok(it.transactions().to_vec()))?;
+        let transaction_list = self.signed_transaction_list()?;
         Ok(hedera_proto::sdk::TransactionList { transaction_list }.encode_to_vec())
     }
 
-    pub(crate) fn add_signature_signer(&mut self, signer: &AnySigner) -> Vec<u8> {
+    /// Convert `self` to the protobuf transactions that'd be submitted, one for each node and chunk.
+    ///
+    /// # Errors
+    /// - If `freeze_with` wasn't called with an operator.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    #[cfg(feature = "protobuf")]
+    pub fn to_protobuf(&self) -> crate::Result<Vec<services::Transaction>> {
+        self.signed_transaction_list()
+    }
+
+    fn signed_transaction_list(&self) -> crate::Result<Vec<services::Transaction>> {
+        self.signed_sources()
+            .map_or_else(|| self.make_transaction_list(), |it| Ok(it?.transactions().to_vec()))
+    }
+
+    pub(crate) fn add_signature_signer(&mut self, signer: &AnySigner) -> crate::Result<Vec<u8>> {
         assert!(self.is_frozen());
 
         // note: the following pair of cheecks are for more detailed panic messages
@@ -614,22 +710,22 @@ impl<D: TransactionExecute> Transaction<D> {
             );
         }
 
-        let sources = self.make_sources().unwrap();
+        let sources = self.make_sources()?;
 
         // this is the only check that is for correctness rather than debugability.
         assert!(sources.transactions().len() == 1);
 
-        let sources = sources.sign_with(std::slice::from_ref(signer));
+        let sources = sources.sign_with(std::slice::from_ref(signer))?;
 
         // hack: I don't care about perf here.
-        let ret = signer.sign(&sources.signed_transactions()[0].body_bytes);
+        let ret = signer.sign(&sources.signed_transactions()[0].body_bytes)?;
 
         // if we have a `Cow::Borrowed` that'd mean there was no modification
         if let Cow::Owned(sources) = sources {
             self.sources = Some(sources);
         }
 
-        ret.1
+        Ok(ret.1)
     }
 
     // todo: should this return `Result<&mut Self>`?
@@ -638,17 +734,32 @@ impl<D: TransactionExecute> Transaction<D> {
     /// Only use this as a last resort.
     ///
     /// This forcibly disables transaction ID regeneration.
+    ///
+    /// # Panics
+    /// - If a signer added with [`try_sign_with`](Self::try_sign_with) fails.
     pub fn add_signature(&mut self, pk: PublicKey, signature: Vec<u8>) -> &mut Self {
-        self.add_signature_signer(&AnySigner::arbitrary(Box::new(pk), move |_| signature.clone()));
+        self.add_signature_signer(&AnySigner::arbitrary(Box::new(pk), move |_| signature.clone()))
+            .expect("failed to sign transaction");
 
         self
     }
 
+    /// Wraps this transaction in a [`ScheduleCreateTransaction`].
+    ///
+    /// The transaction ID and valid duration of `self` are moved to the schedule create transaction,
+    /// since that's the transaction that gets submitted;
+    /// the scheduled transaction itself expires with the schedule instead.
+    ///
+    /// No [`expiration_time`](ScheduleCreateTransaction::expiration_time) is set,
+    /// so the schedule expires after the network's default of 30 minutes,
+    /// use [`schedule_until`](Self::schedule_until) for a long-term schedule.
+    ///
     /// # Panics
     /// panics if the transaction is not schedulable, a transaction can be non-schedulable due to:
     /// - if `self.is_frozen`
     /// - being a transaction kind that's non-schedulable, IE, `EthereumTransaction`, or
     /// - being a chunked transaction with multiple chunks.
+    #[cfg(feature = "schedules")]
     pub fn schedule(self) -> ScheduleCreateTransaction {
         self.require_not_frozen();
         assert!(self.get_node_account_ids().is_none(), "The underlying transaction for a scheduled transaction cannot have node account IDs set");
@@ -659,11 +770,37 @@ impl<D: TransactionExecute> Transaction<D> {
             transaction.transaction_id(transaction_id);
         }
 
+        if let Some(duration) = self.get_transaction_valid_duration() {
+            transaction.transaction_valid_duration(duration);
+        }
+
         transaction.scheduled_transaction(self);
 
         transaction
     }
 
+    /// Wraps this transaction in a long-term [`ScheduleCreateTransaction`] (HIP-423),
+    /// which expires at `expiration_time` rather than after the network's default of 30 minutes.
+    ///
+    /// Like [`schedule`](Self::schedule), but with the schedule's [`expiration_time`](ScheduleCreateTransaction::expiration_time) set.
+    /// Neither this nor [`schedule`](Self::schedule) picks an expiration time on their own,
+    /// a schedule is only long-term if one is set explicitly.
+    /// Set [`wait_for_expiry`](ScheduleCreateTransaction::wait_for_expiry) to execute the transaction at `expiration_time`,
+    /// rather than as soon as it has been signed by all required keys.
+    ///
+    /// # Panics
+    /// - in the same situations as [`schedule`](Self::schedule).
+    #[cfg(feature = "schedules")]
+    pub fn schedule_until(
+        self,
+        expiration_time: time::OffsetDateTime,
+    ) -> ScheduleCreateTransaction {
+        let mut transaction = self.schedule();
+        transaction.expiration_time(expiration_time);
+
+        transaction
+    }
+
     /// Get the hash for this transaction.
     ///
     /// Note: Calling this function _disables_ transaction ID regeneration.
@@ -723,6 +860,46 @@ impl<D: TransactionExecute> Transaction<D> {
         Ok(iter.collect())
     }
 
+    /// Get the encoded transaction bodies for this transaction, by node.
+    ///
+    /// These are the exact bytes that get signed, so they can be handed to a signing service
+    /// (see [`sign_body_bytes`](crate::sign_body_bytes)). For a transaction with a single node,
+    /// the resulting signature can be added back with [`add_signature`](Self::add_signature).
+    ///
+    /// Only the first chunk of a chunked transaction is included.
+    ///
+    /// Note: Calling this function _disables_ transaction ID regeneration.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`]
+    ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub fn body_bytes_per_node(&mut self) -> crate::Result<HashMap<AccountId, Vec<u8>>> {
+        assert!(
+            self.is_frozen(),
+            "Transaction must be frozen before calling `body_bytes_per_node`"
+        );
+
+        let sources = self.make_sources()?;
+
+        let sources = match sources {
+            Cow::Borrowed(it) => it,
+            Cow::Owned(it) => &*self.sources.insert(it),
+        };
+
+        let chunk = sources.chunks().next().unwrap();
+
+        let iter = chunk
+            .node_ids()
+            .iter()
+            .zip(chunk.signed_transactions())
+            .map(|(node, it)| (*node, it.body_bytes.clone()));
+
+        Ok(iter.collect())
+    }
+
     #[allow(deprecated)]
     fn make_transaction_list_chunked(&self) -> crate::Result<Vec<services::Transaction>> {
         // todo: fix this with chunked transactions.
@@ -733,11 +910,11 @@ impl<D: TransactionExecute> Transaction<D> {
 
         if node_account_ids.is_empty() {
             // Handle case with no node IDs
-            transaction_list.push(self.create_transaction_for_node(None));
+            transaction_list.push(self.create_transaction_for_node(None)?);
         } else {
             // Handle case with node IDs
             for node_account_id in node_account_ids {
-                transaction_list.push(self.create_transaction_for_node(Some(node_account_id)));
+                transaction_list.push(self.create_transaction_for_node(Some(node_account_id))?);
             }
         }
 
@@ -756,11 +933,11 @@ impl<D: TransactionExecute> Transaction<D> {
 
         if node_account_ids.is_empty() {
             // Handle case with no node IDs
-            transaction_list.push(self.create_transaction_for_node(None));
+            transaction_list.push(self.create_transaction_for_node(None)?);
         } else {
             // Handle case with node IDs
             for node_account_id in node_account_ids {
-                transaction_list.push(self.create_transaction_for_node(Some(node_account_id)));
+                transaction_list.push(self.create_transaction_for_node(Some(node_account_id))?);
             }
         }
 
@@ -768,7 +945,10 @@ impl<D: TransactionExecute> Transaction<D> {
     }
 
     /// Creates a transaction for a specific node and adds it to the transaction list
-    fn create_transaction_for_node(&self, node_opt: Option<&AccountId>) -> services::Transaction {
+    fn create_transaction_for_node(
+        &self,
+        node_opt: Option<&AccountId>,
+    ) -> crate::Result<services::Transaction> {
         let transaction_body = services::TransactionBody {
             transaction_id: self.get_transaction_id().map(|id| id.to_protobuf()),
             generate_record: false,
@@ -799,35 +979,21 @@ impl<D: TransactionExecute> Transaction<D> {
         let mut signatures = Vec::with_capacity(1 + self.signers.len());
 
         if let Some(operator) = &self.body.operator {
-            let operator_signature = operator.sign(&body_bytes);
+            let operator_signature = operator.sign(&body_bytes)?;
             let (pk, sig) = operator_signature;
             signatures.push(services::SignaturePair {
                 pub_key_prefix: pk.to_bytes_raw(),
-                signature: Some(match pk.kind() {
-                    crate::key::KeyKind::Ed25519 => {
-                        services::signature_pair::Signature::Ed25519(sig)
-                    }
-                    crate::key::KeyKind::Ecdsa => {
-                        services::signature_pair::Signature::EcdsaSecp256k1(sig)
-                    }
-                }),
+                signature: Some(pk.kind().signature_protobuf(sig)),
             });
         }
 
         for signer in &self.signers {
             let public_key = signer.public_key().to_bytes();
             if !signatures.iter().any(|it| public_key.starts_with(&it.pub_key_prefix)) {
-                let (pk, sig) = signer.sign(&body_bytes);
+                let (pk, sig) = signer.sign(&body_bytes)?;
                 signatures.push(services::SignaturePair {
                     pub_key_prefix: pk.to_bytes_raw(),
-                    signature: Some(match pk.kind() {
-                        crate::key::KeyKind::Ed25519 => {
-                            services::signature_pair::Signature::Ed25519(sig)
-                        }
-                        crate::key::KeyKind::Ecdsa => {
-                            services::signature_pair::Signature::EcdsaSecp256k1(sig)
-                        }
-                    }),
+                    signature: Some(pk.kind().signature_protobuf(sig)),
                 });
             }
         }
@@ -836,13 +1002,13 @@ impl<D: TransactionExecute> Transaction<D> {
             body_bytes,
             sig_map: Some(services::SignatureMap { sig_pair: signatures.clone() }),
         };
-        services::Transaction {
+        Ok(services::Transaction {
             signed_transaction_bytes: signed_transaction.encode_to_vec(),
             body: None,
             sigs: None,
             body_bytes: signed_transaction.body_bytes,
             sig_map: Some(services::SignatureMap { sig_pair: signatures.clone() }),
-        }
+        })
     }
 }
 
@@ -890,6 +1056,18 @@ where
         }
     }
 
+    /// Returns how this transaction would be executed against `client` right now, without sending it.
+    ///
+    /// Includes the nodes it would be sent to (in order), the timeouts, and the backoff between attempts,
+    /// all of which can be tuned on the client before running real workloads.
+    /// The nodes can also be set on the transaction, with [`node_account_ids`](Self::node_account_ids).
+    ///
+    /// # Errors
+    /// - [`Error::NodeAccountUnknown`] if a node set on this transaction isn't in the client's network.
+    pub fn execution_plan(&self, client: &Client) -> crate::Result<ExecutionPlan> {
+        execution_plan(client, self.body.node_account_ids.as_deref(), None)
+    }
+
     /// Execute this transaction against the provided client of the Hiero network.
     pub async fn execute(&mut self, client: &Client) -> crate::Result<TransactionResponse> {
         self.execute_with_optional_timeout(client, None).await
@@ -903,6 +1081,10 @@ where
         // it's fine to call freeze while already frozen, so, let `freeze_with` handle the freeze check.
         self.freeze_with(Some(client))?;
 
+        if let Some(transaction_id) = self.get_transaction_id() {
+            client.check_balance_watchdog(transaction_id.account_id)?;
+        }
+
         if let Some(sources) = self.sources() {
             // Check if sources are "empty" (no transaction IDs and no node IDs)
             let has_transaction_ids =
@@ -911,8 +1093,10 @@ where
 
             if has_transaction_ids || has_node_ids {
                 // Sources have useful data, use them
-                return self::execute::SourceTransaction::new(self, sources)
-                    .execute(client, timeout)
+                let in_flight = client.in_flight_request().await;
+
+                return self::execute::SourceTransaction::new(self, sources)?
+                    .execute(client, &in_flight, timeout)
                     .await;
             } else {
                 // Sources are empty, clear them and use regular execution
@@ -924,23 +1108,94 @@ where
             // todo: log a warning: user actually wanted `execute_all`.
             // instead of `panic`king we just pretend we were `execute_all` and
             // return the first result (*after* executing all the transactions).
-            return self
-                .execute_all_inner(chunk_data, client, timeout)
-                .await
-                .map(|mut it| it.swap_remove(0));
+            let mut responses = Vec::new();
+            let in_flight = client.in_flight_request().await;
+
+            self.execute_all_inner(chunk_data, client, &in_flight, timeout, &mut responses).await?;
+
+            return Ok(responses.swap_remove(0));
         }
 
+        let result = execute(client, self, timeout).await;
+
+        let Err(Error::TransactionPreCheckStatus {
+            status: services::ResponseCodeEnum::InsufficientTxFee,
+            cost: Some(required_fee),
+            transaction_id,
+        }) = &result
+        else {
+            return result;
+        };
+
+        let Some(new_fee) =
+            self.bumped_fee(*required_fee, client.auto_fee_bump_limit(), Some(client))
+        else {
+            return result;
+        };
+
+        let event = RetryEvent::FeeBumped {
+            transaction_id: **transaction_id,
+            previous_fee: self.effective_max_transaction_fee(Some(client)),
+            new_fee,
+        };
+
+        client_log!(
+            client.logger(),
+            Execution,
+            Info,
+            "Resubmitting transaction after `INSUFFICIENT_TX_FEE`: {event:?}"
+        );
+
+        client.emit_retry_event(&event);
+
+        // note: the body is rebuilt (and re-signed) for every request, so changing the fee after freezing is fine here.
+        self.body.max_transaction_fee = Some(new_fee);
+
         execute(client, self, timeout).await
     }
 
+    /// Returns the max transaction fee that's sent, resolved in the same order as [`freeze_with`](Self::freeze_with):
+    /// the transaction's own, then the default of `client`, then the default for the kind of transaction.
+    fn effective_max_transaction_fee(&self, client: Option<&Client>) -> Hbar {
+        self.body
+            .max_transaction_fee
+            .or_else(|| client.and_then(Client::default_max_transaction_fee))
+            .unwrap_or_else(|| self.data().default_max_transaction_fee())
+    }
+
+    /// Returns the fee to resubmit with after failing with `INSUFFICIENT_TX_FEE`, if any.
+    fn bumped_fee(
+        &self,
+        required_fee: Hbar,
+        limit: Option<Hbar>,
+        client: Option<&Client>,
+    ) -> Option<Hbar> {
+        let limit = limit?;
+
+        // signatures that were added directly (or loaded from bytes) can't be redone for the new body.
+        if self.sources.is_some() {
+            return None;
+        }
+
+        (required_fee > self.effective_max_transaction_fee(client) && required_fee <= limit)
+            .then_some(required_fee)
+    }
+
     // this is in *this* impl block rather than the `: TransactionExecuteChunked` impl block
     //because there's the off chance that someone calls `execute` on a Transaction that wants `execute_all`...
+    //
+    // every chunk that's submitted is pushed to `responses` before its receipt is waited for,
+    // so that a chunk that fails after being sent can be checked by `ChunkedTransactionResponse::resume`.
+    //
+    // every chunk and receipt is sent as part of `in_flight`, so that they all go to the same environment.
     async fn execute_all_inner(
         &self,
         chunk_data: &ChunkData,
         client: &Client,
+        in_flight: &InFlightRequest<'_>,
         timeout_per_chunk: Option<std::time::Duration>,
-    ) -> crate::Result<Vec<TransactionResponse>> {
+        responses: &mut Vec<TransactionResponse>,
+    ) -> crate::Result<()> {
         assert!(self.is_frozen());
 
         let wait_for_receipts = self.data().wait_for_receipt();
@@ -953,51 +1208,81 @@ where
 
         let used_chunks = chunk_data.used_chunks();
 
-        let mut responses = Vec::with_capacity(chunk_data.used_chunks());
+        responses.reserve(used_chunks);
 
-        let initial_transaction_id = {
-            let resp = execute(
+        let (initial_transaction_id, first_node_account_id) = {
+            let resp = execute_in_flight(
                 client,
+                in_flight,
                 &chunked::FirstChunkView { transaction: self, total_chunks: used_chunks },
                 timeout_per_chunk,
             )
             .await?;
 
+            let ids = (resp.transaction_id, resp.node_account_id);
+            responses.push(resp);
+
             if wait_for_receipts {
-                resp.get_receipt_query()
-                    .execute_with_optional_timeout(client, timeout_per_chunk)
-                    .await?;
+                let query = responses[0].get_receipt_query();
+                execute_in_flight(client, in_flight, &query, timeout_per_chunk).await?;
             }
 
-            let initial_transaction_id = resp.transaction_id;
-            responses.push(resp);
-
-            initial_transaction_id
+            ids
         };
 
+        let node_account_id = chunk_data.sticky_node.then_some(first_node_account_id);
+
         for chunk in 1..used_chunks {
-            let resp = execute(
-                client,
-                &chunked::ChunkView {
-                    transaction: self,
+            let resp = self
+                .submit_chunk(
+                    client,
+                    in_flight,
                     initial_transaction_id,
-                    current_chunk: chunk,
-                    total_chunks: used_chunks,
-                },
-                timeout_per_chunk,
-            )
-            .await?;
+                    chunk,
+                    node_account_id,
+                    timeout_per_chunk,
+                )
+                .await?;
+
+            responses.push(resp);
 
             if wait_for_receipts {
-                resp.get_receipt_query()
-                    .execute_with_optional_timeout(client, timeout_per_chunk)
-                    .await?;
+                let query = responses[chunk].get_receipt_query();
+                execute_in_flight(client, in_flight, &query, timeout_per_chunk).await?;
             }
-
-            responses.push(resp);
         }
 
-        Ok(responses)
+        Ok(())
+    }
+
+    /// Submit `chunk` of this transaction (without waiting for its receipt),
+    /// as part of the chunked transaction that started with `initial_transaction_id`.
+    ///
+    /// When `node_account_id` is `Some`, the chunk is only sent to that node.
+    pub(crate) async fn submit_chunk(
+        &self,
+        client: &Client,
+        in_flight: &InFlightRequest<'_>,
+        initial_transaction_id: TransactionId,
+        chunk: usize,
+        node_account_id: Option<AccountId>,
+        timeout: Option<std::time::Duration>,
+    ) -> crate::Result<TransactionResponse> {
+        let total_chunks = self.data().maybe_chunk_data().map_or(1, ChunkData::used_chunks);
+
+        execute_in_flight(
+            client,
+            in_flight,
+            &chunked::ChunkView {
+                transaction: self,
+                initial_transaction_id,
+                current_chunk: chunk,
+                total_chunks,
+                node_account_id,
+            },
+            timeout,
+        )
+        .await
     }
 
     /// Execute this transaction against the provided client of the Hiero network.
@@ -1011,6 +1296,24 @@ where
     ) -> crate::Result<TransactionResponse> {
         self.execute_with_optional_timeout(client, Some(timeout)).await
     }
+
+    /// Execute this transaction against the provided client of the Hiero network,
+    /// stopping early if `cancellation` is cancelled.
+    ///
+    /// Cancelling aborts any in-flight request and pending retry backoff,
+    /// the same as dropping the returned future would.
+    /// Note that a transaction that was already sent to a node may still reach consensus.
+    ///
+    /// # Errors
+    /// - [`Error::Cancelled`] if `cancellation` is cancelled before the transaction is submitted.
+    /// - Any error [`execute`](Self::execute) can return.
+    pub async fn execute_with_cancellation(
+        &mut self,
+        client: &Client,
+        cancellation: &CancellationToken,
+    ) -> crate::Result<TransactionResponse> {
+        cancellable(cancellation, self.execute_with_optional_timeout(client, None)).await
+    }
 }
 
 impl<D> Transaction<D>
@@ -1025,14 +1328,128 @@ where
         self.execute_all_with_optional_timeout(client, None).await
     }
 
+    /// Execute all chunks of this transaction against the provided client of the Hiero network.
+    ///
+    /// Unlike [`execute`](Self::execute), which only returns the response of the first chunk,
+    /// this returns the response of every chunk, so that all of their receipts or records can be fetched.
+    ///
+    /// Chunks are submitted in order, stopping at the first one that fails.
+    ///
+    /// # Errors
+    /// - Any error [`execute_all`](Self::execute_all) can return, if the first chunk fails.
+    /// - [`Error::ChunkedTransactionIncomplete`] if a later chunk fails, with the responses of the chunks before it.
+    ///   This wraps *every* error from a later chunk, including [`Error::ReceiptStatus`],
+    ///   the original error is its `source`.
+    pub async fn execute_chunked(
+        &mut self,
+        client: &Client,
+    ) -> crate::Result<ChunkedTransactionResponse> {
+        self.execute_chunked_with_optional_timeout(client, None, None).await
+    }
+
+    /// Execute all chunks of this transaction against the provided client of the Hiero network,
+    /// with `timeout_per_chunk` applying to each chunk separately.
+    ///
+    /// # Errors
+    /// - Any error [`execute_all`](Self::execute_all) can return, for the first chunk that fails.
+    pub async fn execute_chunked_with_timeout(
+        &mut self,
+        client: &Client,
+        timeout_per_chunk: std::time::Duration,
+    ) -> crate::Result<ChunkedTransactionResponse> {
+        self.execute_chunked_with_optional_timeout(client, Some(timeout_per_chunk), None).await
+    }
+
+    /// Execute all chunks of this transaction against the provided client of the Hiero network,
+    /// stopping early if `cancellation` is cancelled.
+    ///
+    /// Chunks that were submitted before cancellation are kept, see [`execute_chunked`](Self::execute_chunked).
+    ///
+    /// # Errors
+    /// - [`Error::Cancelled`] if `cancellation` is cancelled before the first chunk is submitted.
+    /// - [`Error::ChunkedTransactionIncomplete`] wrapping [`Error::Cancelled`] if it's cancelled after that.
+    /// - Any error [`execute_chunked`](Self::execute_chunked) can return.
+    pub async fn execute_chunked_with_cancellation(
+        &mut self,
+        client: &Client,
+        cancellation: &CancellationToken,
+    ) -> crate::Result<ChunkedTransactionResponse> {
+        self.execute_chunked_with_optional_timeout(client, None, Some(cancellation)).await
+    }
+
+    /// Execute all transactions against the provided client of the Hiero network,
+    /// stopping early if `cancellation` is cancelled.
+    ///
+    /// Cancelling aborts any in-flight request and pending retry backoff,
+    /// chunks that were already sent to a node may still reach consensus.
+    ///
+    /// # Errors
+    /// - [`Error::Cancelled`] if `cancellation` is cancelled before every chunk is submitted.
+    /// - Any error [`execute_all`](Self::execute_all) can return.
+    pub async fn execute_all_with_cancellation(
+        &mut self,
+        client: &Client,
+        cancellation: &CancellationToken,
+    ) -> crate::Result<Vec<TransactionResponse>> {
+        cancellable(cancellation, self.execute_all_with_optional_timeout(client, None)).await
+    }
+
+    async fn execute_chunked_with_optional_timeout(
+        &mut self,
+        client: &Client,
+        timeout_per_chunk: Option<std::time::Duration>,
+        cancellation: Option<&CancellationToken>,
+    ) -> crate::Result<ChunkedTransactionResponse> {
+        let mut responses = Vec::new();
+
+        let fut = self.execute_all_into(client, timeout_per_chunk, &mut responses);
+
+        // the responses of chunks submitted before cancellation are still in `responses`.
+        let res = match cancellation {
+            Some(cancellation) => cancellable(cancellation, fut).await,
+            None => fut.await,
+        };
+
+        let total_chunks = self.data().maybe_chunk_data().map_or(1, ChunkData::used_chunks);
+
+        match (res, ChunkedTransactionResponse::new(responses, total_chunks)) {
+            (Ok(()), Some(response)) => Ok(response),
+            (Err(e), Some(response)) => Err(Error::ChunkedTransactionIncomplete {
+                response: Box::new(response),
+                source: Box::new(e),
+            }),
+            // nothing was submitted, so there's nothing to resume.
+            (Err(e), None) => Err(e),
+            (Ok(()), None) => Err(Error::from_protobuf("transaction had no chunks")),
+        }
+    }
+
     pub(crate) async fn execute_all_with_optional_timeout(
         &mut self,
         client: &Client,
         timeout_per_chunk: Option<std::time::Duration>,
     ) -> crate::Result<Vec<TransactionResponse>> {
+        let mut responses = Vec::new();
+
+        self.execute_all_into(client, timeout_per_chunk, &mut responses).await?;
+
+        Ok(responses)
+    }
+
+    /// Execute all chunks of this transaction, pushing the response of each chunk that's submitted to `responses`.
+    async fn execute_all_into(
+        &mut self,
+        client: &Client,
+        timeout_per_chunk: Option<std::time::Duration>,
+        responses: &mut Vec<TransactionResponse>,
+    ) -> crate::Result<()> {
         // it's fine to call freeze while already frozen, so, let `freeze_with` handle the freeze check.
         self.freeze_with(Some(client))?;
 
+        if let Some(transaction_id) = self.get_transaction_id() {
+            client.check_balance_watchdog(transaction_id.account_id)?;
+        }
+
         // fixme: dedup this with `execute_with_optional_timeout`
         if let Some(sources) = self.sources() {
             // Check if sources are "empty" (no transaction IDs and no node IDs)
@@ -1042,8 +1459,10 @@ where
 
             if has_transaction_ids || has_node_ids {
                 // Sources have useful data, use them
-                return self::execute::SourceTransaction::new(self, sources)
-                    .execute_all(client, timeout_per_chunk)
+                let in_flight = client.in_flight_request().await;
+
+                return self::execute::SourceTransaction::new(self, sources)?
+                    .execute_all_into(client, &in_flight, timeout_per_chunk, responses)
                     .await;
             } else {
                 // Sources are empty, clear them and use regular execution
@@ -1054,12 +1473,14 @@ where
         // sorry for the mess: this can technically infinite loop
         // (it won't, the loop condition would be dependent on chunk_data somehow being `Some` and `None` at the same time).
         let Some(chunk_data) = self.data().maybe_chunk_data() else {
-            return Ok(Vec::from([self
-                .execute_with_optional_timeout(client, timeout_per_chunk)
-                .await?]));
+            responses.push(self.execute_with_optional_timeout(client, timeout_per_chunk).await?);
+
+            return Ok(());
         };
 
-        self.execute_all_inner(chunk_data, client, timeout_per_chunk).await
+        let in_flight = client.in_flight_request().await;
+
+        self.execute_all_inner(chunk_data, client, &in_flight, timeout_per_chunk, responses).await
     }
 }
 
@@ -1084,7 +1505,6 @@ impl AnyTransaction {
     /// ```
     /// # Errors
     /// - [`Error::FromProtobuf`] if a valid transaction cannot be parsed from the bytes.
-    #[allow(deprecated)]
     pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
         let list: hedera_proto::sdk::TransactionList =
             hedera_proto::sdk::TransactionList::decode(bytes).map_err(Error::from_protobuf)?;
@@ -1095,27 +1515,32 @@ impl AnyTransaction {
             list.transaction_list
         };
 
+        Self::from_transaction_list(list)
+    }
+
+    /// Create a transaction from the protobuf transactions for each of its nodes and chunks,
+    /// IE, those returned by [`Transaction::to_protobuf`].
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if a valid transaction cannot be parsed from `transactions`.
+    #[cfg(feature = "protobuf")]
+    pub fn from_protobuf(transactions: Vec<services::Transaction>) -> crate::Result<Self> {
+        Self::from_transaction_list(transactions)
+    }
+
+    #[allow(deprecated)]
+    fn from_transaction_list(list: Vec<services::Transaction>) -> crate::Result<Self> {
         let sources = TransactionSources::new(list)?;
 
-        let transaction_bodies: Result<Vec<_>, _> = if !sources.signed_transactions().is_empty() {
-            sources
-                .signed_transactions()
-                .iter()
-                .map(|transaction| {
-                    services::TransactionBody::decode(&*transaction.body_bytes)
-                        .map_err(Error::from_protobuf)
-                })
-                .collect()
-        } else {
-            sources
-                .transactions()
-                .iter()
-                .map(|transaction| {
-                    services::TransactionBody::decode(&*transaction.body_bytes)
-                        .map_err(Error::from_protobuf)
-                })
-                .collect()
-        };
+        // `TransactionSources::new` already checked that every body decodes.
+        let transaction_bodies: Result<Vec<_>, _> = sources
+            .signed_transactions()
+            .iter()
+            .map(|transaction| {
+                services::TransactionBody::decode(&*transaction.body_bytes)
+                    .map_err(Error::from_protobuf)
+            })
+            .collect();
 
         let transaction_bodies = transaction_bodies?;
         {
@@ -1123,9 +1548,13 @@ impl AnyTransaction {
                 .split_first()
                 .ok_or_else(|| Error::from_protobuf("no transactions found"))?;
 
-            for it in transaction_bodies {
+            for (index, it) in transaction_bodies.iter().enumerate() {
                 if !pb_transaction_body_eq(first, it) {
-                    return Err(Error::from_protobuf("transaction parts unexpectedly unequal"));
+                    return Err(Error::from_protobuf(
+                        "transaction part unexpectedly differs from the first part",
+                    ))
+                    .at_index(index + 1)
+                    .in_field("transaction_list");
                 }
             }
         }
@@ -1135,24 +1564,21 @@ impl AnyTransaction {
             let data: Result<_, _> = sources
                 .chunks()
                 .map(|it| {
-                    if it.transactions().first().unwrap().body_bytes.len() == 0 {
-                        services::TransactionBody::decode(
-                            &*it.signed_transactions().first().unwrap().body_bytes,
-                        )
-                    } else {
-                        services::TransactionBody::decode(
-                            &*it.transactions().first().unwrap().body_bytes,
-                        )
-                    }
-                    .map_err(Error::from_protobuf)
-                    .and_then(|pb| pb_getf!(pb, data))
+                    let signed_transaction = it
+                        .signed_transactions()
+                        .first()
+                        .ok_or_else(|| Error::from_protobuf("transaction chunk is empty"))?;
+
+                    services::TransactionBody::decode(&*signed_transaction.body_bytes)
+                        .map_err(Error::from_protobuf)
+                        .and_then(|pb| pb_getf!(pb, data))
                 })
                 .collect();
 
             data?
         };
 
-        let mut res = Self::from_protobuf(transaction_bodies[0].clone(), transaction_data)?;
+        let mut res = Self::from_body_protobuf(transaction_bodies[0].clone(), transaction_data)?;
 
         // note: this doesn't check freeze for obvious reasons.
 
@@ -1276,6 +1702,7 @@ where
             transaction_id,
             operator,
             is_frozen,
+            frozen_environment,
             regenerate_transaction_id,
             custom_fee_limits,
             batch_key,
@@ -1293,6 +1720,7 @@ where
                     transaction_id,
                     operator,
                     is_frozen,
+                    frozen_environment,
                     regenerate_transaction_id,
                     custom_fee_limits,
                     batch_key,
@@ -1311,6 +1739,7 @@ where
                     transaction_id,
                     operator,
                     is_frozen,
+                    frozen_environment,
                     regenerate_transaction_id,
                     custom_fee_limits,
                     batch_key: batch_key.clone(),
@@ -10,11 +10,16 @@ use hedera_proto::services::{
 use once_cell::sync::OnceCell;
 use prost::Message;
 
-use crate::protobuf::FromProtobuf;
+use crate::protobuf::{
+    FromProtobuf,
+    FromProtobufContext,
+    ToProtobuf,
+};
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
     Error,
+    SignaturePair,
     TransactionHash,
     TransactionId,
 };
@@ -78,18 +83,30 @@ impl TransactionSources {
             return Err(Error::from_protobuf("`TransactionList` had no transactions"));
         }
 
-        let signed_transactions: Vec<SignedTransaction> = transactions
+        // transactions from before `signed_transaction_bytes` existed are normalized into signed transactions,
+        // so that every transaction has exactly one signed transaction at the same index.
+        let signed_transactions = transactions
             .iter()
-            .filter_map(|transaction| {
+            .enumerate()
+            .map(|(index, transaction)| {
                 if !transaction.signed_transaction_bytes.is_empty() {
                     SignedTransaction::decode(&*transaction.signed_transaction_bytes)
                         .map_err(Error::from_protobuf)
-                        .ok()
+                        .in_field("signed_transaction_bytes")
+                } else if !transaction.body_bytes.is_empty() {
+                    Ok(SignedTransaction {
+                        body_bytes: transaction.body_bytes.clone(),
+                        sig_map: transaction.sig_map.clone(),
+                    })
                 } else {
-                    None
+                    Err(Error::from_protobuf(
+                        "transaction has neither `signed_transaction_bytes` nor `body_bytes`",
+                    ))
                 }
+                .at_index(index)
+                .in_field("transaction_list")
             })
-            .collect();
+            .collect::<crate::Result<Vec<_>>>()?;
 
         // ensure all signers (if any) are consistent for all signed transactions.
         // this doesn't compare or validate the signatures,
@@ -121,31 +138,38 @@ impl TransactionSources {
             }
         }
 
-        let transaction_info: Result<Vec<_>, _> = transactions
+        let transaction_info: Result<Vec<_>, _> = signed_transactions
             .iter()
-            .map(|it| {
-                let body_bytes = if it.body_bytes.len() == 0 {
-                    SignedTransaction::decode(&*it.signed_transaction_bytes).unwrap().body_bytes
+            .zip(&transactions)
+            .enumerate()
+            .map(|(index, (it, transaction))| {
+                let body_bytes_field = if transaction.signed_transaction_bytes.is_empty() {
+                    "body_bytes"
                 } else {
-                    it.body_bytes.clone()
+                    "signed_transaction_bytes.body_bytes"
                 };
 
-                services::TransactionBody::decode(body_bytes.as_slice())
+                services::TransactionBody::decode(it.body_bytes.as_slice())
                     .map_err(Error::from_protobuf)
                     .and_then(|body| {
                         // Keep None values for optional fields
                         let transaction_id = body
                             .transaction_id
-                            .map(|id| TransactionId::from_protobuf(id))
-                            .transpose()?;
+                            .map(TransactionId::from_protobuf)
+                            .transpose()
+                            .in_field("transaction_id")?;
 
                         let node_account_id = body
                             .node_account_id
-                            .map(|id| AccountId::from_protobuf(id))
-                            .transpose()?;
+                            .map(AccountId::from_protobuf)
+                            .transpose()
+                            .in_field("node_account_id")?;
 
                         Ok((transaction_id, node_account_id))
                     })
+                    .in_field(body_bytes_field)
+                    .at_index(index)
+                    .in_field("transaction_list")
             })
             .collect();
 
@@ -207,9 +231,9 @@ impl TransactionSources {
         })
     }
 
-    pub(crate) fn sign_with(&self, signers: &[AnySigner]) -> Cow<'_, Self> {
+    pub(crate) fn sign_with(&self, signers: &[AnySigner]) -> crate::Result<Cow<'_, Self>> {
         if signers.is_empty() {
-            return Cow::Borrowed(self);
+            return Ok(Cow::Borrowed(self));
         }
 
         let mut signed_transactions = Cow::Borrowed(&self.signed_transactions);
@@ -221,10 +245,8 @@ impl TransactionSources {
             if signed_transactions
                 .first()
                 .as_ref()
-                .and_then(|it| Some(it.sig_map.as_ref()))
-                .map_or(false, |it| {
-                    it.unwrap().sig_pair.iter().any(|it| pk.starts_with(&it.pub_key_prefix))
-                })
+                .and_then(|it| it.sig_map.as_ref())
+                .map_or(false, |it| it.sig_pair.iter().any(|it| pk.starts_with(&it.pub_key_prefix)))
             {
                 continue;
             }
@@ -232,13 +254,13 @@ impl TransactionSources {
             for tx in signed_transactions.to_mut().iter_mut() {
                 let sig_map = tx.sig_map.get_or_insert_with(services::SignatureMap::default);
                 // todo: reuse `pk_bytes` instead of re-serializing them.
-                let sig_pair = super::execute::SignaturePair::from(signer.sign(&tx.body_bytes));
+                let sig_pair = SignaturePair::from(signer.sign(&tx.body_bytes)?);
 
-                sig_map.sig_pair.push(sig_pair.into_protobuf());
+                sig_map.sig_pair.push(sig_pair.to_protobuf());
             }
         }
 
-        match signed_transactions {
+        Ok(match signed_transactions {
             // if it's still borrowed then no signatures have been added (all signers are duplicates).
             Cow::Borrowed(_) => Cow::Borrowed(self),
             Cow::Owned(signed_transactions) => Cow::Owned(Self {
@@ -249,7 +271,7 @@ impl TransactionSources {
                 node_ids: self.node_ids.clone(),
                 transaction_hashes: self.transaction_hashes.clone(),
             }),
-        }
+        })
     }
 
     pub(crate) fn transactions(&self) -> &[services::Transaction] {
@@ -1,16 +1,25 @@
 use std::collections::HashSet;
 
 use assert_matches::assert_matches;
+use hedera_proto::services;
 use hex_literal::hex;
+use prost::Message;
 use time::OffsetDateTime;
 
-use crate::transaction::AnyTransactionData;
+use crate::clock::MockClock;
+use crate::transaction::{
+    AnyTransactionData,
+    ValidationProblem,
+};
 use crate::{
+    sign_body_bytes,
+    AccountId,
     AnyTransaction,
     Client,
+    FromProtobuf,
     Hbar,
+    MemoTemplate,
     PrivateKey,
-    TopicMessageSubmitTransaction,
     TransactionId,
     TransferTransaction,
 };
@@ -130,8 +139,14 @@ fn from_bytes_sign_to_bytes() -> crate::Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "topics")]
 #[tokio::test]
 async fn chunked_to_from_bytes() -> crate::Result<()> {
+    use crate::{
+        Client,
+        TopicMessageSubmitTransaction,
+    };
+
     let client = Client::for_testnet();
     client.set_operator(0.into(), PrivateKey::generate_ed25519());
 
@@ -156,3 +171,592 @@ async fn chunked_to_from_bytes() -> crate::Result<()> {
 
     Ok(())
 }
+
+/// Randomly truncates or corrupts a few bytes of `bytes`.
+fn mutate(rng: &mut rand::rngs::StdRng, bytes: &[u8]) -> Vec<u8> {
+    use rand::Rng;
+
+    let mut mutated = bytes.to_vec();
+
+    for _ in 0..rng.gen_range(1..4) {
+        if mutated.is_empty() {
+            break;
+        }
+
+        let index = rng.gen_range(0..mutated.len());
+
+        match rng.gen_range(0..3) {
+            0 => mutated.truncate(index),
+            1 => mutated[index] ^= 1 << rng.gen_range(0..8),
+            _ => mutated[index] = rng.gen(),
+        }
+    }
+
+    mutated
+}
+
+/// Feeds thousands of mutations of `bytes` to `parse`, errors are expected, panics aren't.
+fn fuzz(bytes: &[u8], parse: impl Fn(&[u8])) {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    // deterministic, so that a failure can be reproduced.
+    let mut rng = StdRng::seed_from_u64(954);
+
+    for _ in 0..5000 {
+        parse(&mutate(&mut rng, bytes));
+    }
+}
+
+#[test]
+fn from_bytes_malformed_doesnt_panic() -> crate::Result<()> {
+    let key: PrivateKey = "302e020100300506032b657004220420e40d4241d093b22910c78135e0501b137cd9205bbb9c0153c5adf2c65e7dc95a"
+        .parse()
+        .unwrap();
+
+    let bytes = TransferTransaction::new()
+        .transaction_memo("fuzz")
+        .hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .transaction_id(TransactionId {
+            account_id: 101.into(),
+            valid_start: OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap(),
+            nonce: None,
+            scheduled: false,
+        })
+        .node_account_ids([6.into(), 7.into()])
+        .freeze()?
+        .sign(key)
+        .to_bytes()?;
+
+    fuzz(&bytes, |bytes| {
+        if let Ok(tx) = AnyTransaction::from_bytes(bytes) {
+            let _ = tx.to_bytes();
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(feature = "topics")]
+#[test]
+fn chunked_from_bytes_malformed_doesnt_panic() -> crate::Result<()> {
+    use crate::TopicMessageSubmitTransaction;
+
+    let bytes = TopicMessageSubmitTransaction::new()
+        .topic_id(crate::TopicId::new(0, 0, 10))
+        .message(b"hello, hashgraph".to_vec())
+        .chunk_size(4)
+        .transaction_id(TransactionId {
+            account_id: 101.into(),
+            valid_start: OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap(),
+            nonce: None,
+            scheduled: false,
+        })
+        .node_account_ids([6.into(), 7.into()])
+        .freeze()?
+        .to_bytes()?;
+
+    fuzz(&bytes, |bytes| {
+        if let Ok(tx) = AnyTransaction::from_bytes(bytes) {
+            let _ = tx.to_bytes();
+        }
+    });
+
+    Ok(())
+}
+
+#[test]
+fn query_response_malformed_doesnt_panic() {
+    use crate::transaction_receipt::make_receipt;
+    use crate::{
+        AnyQueryResponse,
+        ToProtobuf,
+    };
+
+    let response = services::Response {
+        response: Some(services::response::Response::TransactionGetReceipt(
+            services::TransactionGetReceiptResponse {
+                receipt: Some(make_receipt().to_protobuf()),
+                duplicate_transaction_receipts: vec![make_receipt().to_protobuf()],
+                child_transaction_receipts: vec![make_receipt().to_protobuf()],
+                ..Default::default()
+            },
+        )),
+    };
+
+    fuzz(&response.encode_to_vec(), |bytes| {
+        if let Some(response) = services::Response::decode(bytes).ok().and_then(|it| it.response) {
+            let _ = AnyQueryResponse::from_protobuf(response);
+        }
+    });
+}
+
+#[test]
+fn from_bytes_error_has_path() -> crate::Result<()> {
+    let bytes = TransferTransaction::new()
+        .hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .transaction_id(TransactionId {
+            account_id: 101.into(),
+            valid_start: OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap(),
+            nonce: None,
+            scheduled: false,
+        })
+        .node_account_ids([6.into()])
+        .freeze()?
+        .to_bytes()?;
+
+    let mut list = hedera_proto::sdk::TransactionList::decode(bytes.as_slice()).unwrap();
+    list.transaction_list.push(services::Transaction::default());
+
+    let err = AnyTransaction::from_bytes(&list.encode_to_vec()).unwrap_err();
+
+    let crate::Error::FromProtobuf(err) = err else { panic!("unexpected error: {err:?}") };
+    let err = err.downcast_ref::<crate::FromProtobufError>().unwrap();
+
+    assert_eq!(err.get_path(), "transaction_list[1]");
+
+    Ok(())
+}
+
+#[test]
+fn bumped_fee() {
+    let mut tx = TransferTransaction::new();
+    tx.max_transaction_fee(Hbar::new(1));
+
+    // disabled
+    assert_eq!(tx.bumped_fee(Hbar::new(2), None, None), None);
+
+    assert_eq!(tx.bumped_fee(Hbar::new(2), Some(Hbar::new(5)), None), Some(Hbar::new(2)));
+
+    // over the limit
+    assert_eq!(tx.bumped_fee(Hbar::new(6), Some(Hbar::new(5)), None), None);
+
+    // not actually a bump
+    assert_eq!(tx.bumped_fee(Hbar::new(1), Some(Hbar::new(5)), None), None);
+}
+
+#[test]
+fn bumped_fee_uses_default_max_fee() {
+    let tx = TransferTransaction::new();
+
+    assert_eq!(tx.bumped_fee(tx.default_max_transaction_fee(), Some(Hbar::new(100)), None), None);
+    assert_eq!(
+        tx.bumped_fee(tx.default_max_transaction_fee() + Hbar::new(1), Some(Hbar::new(100)), None),
+        Some(tx.default_max_transaction_fee() + Hbar::new(1))
+    );
+}
+
+#[tokio::test]
+async fn bumped_fee_uses_client_max_fee() {
+    let client = Client::for_testnet();
+    client.set_default_max_transaction_fee(Hbar::new(5));
+
+    let tx = TransferTransaction::new();
+
+    // the client's default was already sent, so this isn't a bump.
+    assert_eq!(tx.bumped_fee(Hbar::new(3), Some(Hbar::new(100)), Some(&client)), None);
+    assert_eq!(
+        tx.bumped_fee(Hbar::new(6), Some(Hbar::new(100)), Some(&client)),
+        Some(Hbar::new(6))
+    );
+}
+
+#[tokio::test]
+async fn auto_fee_bump_limit() {
+    let client = Client::for_testnet();
+    assert_eq!(client.auto_fee_bump_limit(), None);
+
+    client.set_auto_fee_bump_limit(Some(Hbar::new(5)));
+    assert_eq!(client.auto_fee_bump_limit(), Some(Hbar::new(5)));
+
+    client.set_auto_fee_bump_limit(None);
+    assert_eq!(client.auto_fee_bump_limit(), None);
+}
+
+#[tokio::test]
+async fn memo_template() -> crate::Result<()> {
+    let client = Client::for_testnet();
+    client.set_operator(0.into(), PrivateKey::generate_ed25519());
+
+    let mut template = MemoTemplate::new("{app}/{trace_id}")?;
+    template.value("app", "payments");
+
+    client.set_memo_template(Some(template));
+    client.set_memo_values_hook(|| {
+        std::collections::HashMap::from([("trace_id".to_owned(), "4bf92f35".to_owned())])
+    });
+
+    let mut tx = TransferTransaction::new();
+    tx.node_account_ids([3.into()]).freeze_with(&client)?;
+
+    assert_eq!(tx.get_transaction_memo(), "payments/4bf92f35");
+
+    // an explicit memo is kept.
+    let mut tx = TransferTransaction::new();
+    tx.transaction_memo("explicit").node_account_ids([3.into()]).freeze_with(&client)?;
+
+    assert_eq!(tx.get_transaction_memo(), "explicit");
+
+    Ok(())
+}
+
+#[test]
+fn body_bytes_per_node() -> crate::Result<()> {
+    let key = PrivateKey::generate_ed25519();
+
+    let mut tx = TransferTransaction::new();
+    tx.hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .transaction_id(TransactionId::generate(101.into()))
+        .node_account_ids([6.into(), 7.into()])
+        .freeze()?;
+
+    let bodies = tx.body_bytes_per_node()?;
+
+    assert_eq!(bodies.len(), 2);
+
+    for (node, body) in &bodies {
+        let pair = sign_body_bytes(&key, body);
+
+        pair.verify(body)?;
+
+        let tx_body = services::TransactionBody::decode(body.as_slice()).unwrap();
+        assert_eq!(AccountId::from_protobuf(tx_body.node_account_id.unwrap())?, *node);
+    }
+
+    // a signature made from the body can be added back to a transaction for a single node.
+    let mut tx = TransferTransaction::new();
+    tx.hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .transaction_id(TransactionId::generate(101.into()))
+        .node_account_ids([6.into()])
+        .freeze()?;
+
+    let bodies = tx.body_bytes_per_node()?;
+    let pair = sign_body_bytes(&key, &bodies[&AccountId::from(6)]);
+
+    tx.add_signature(pair.public_key, pair.signature);
+
+    // round trip through bytes, so that the signature is checked against the body rather than the signer.
+    let mut tx = AnyTransaction::from_bytes(&tx.to_bytes()?)?;
+
+    key.public_key().verify_transaction(&mut tx)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn freeze_with_uses_client_clock() -> crate::Result<()> {
+    let now = OffsetDateTime::UNIX_EPOCH + time::Duration::days(20_000);
+    let clock = MockClock::new(now);
+
+    let client = Client::for_testnet();
+    client.set_operator(0.into(), PrivateKey::generate_ed25519());
+    client.set_clock(clock.clone());
+
+    let mut tx = TransferTransaction::new();
+    tx.node_account_ids([3.into()]).freeze_with(&client)?;
+
+    let valid_start = tx.get_transaction_id().unwrap().valid_start;
+
+    assert!(valid_start >= now - time::Duration::seconds(8));
+    assert!(valid_start <= now - time::Duration::seconds(5));
+
+    // the transaction hasn't expired until the clock says so.
+    assert_eq!(tx.validate(&client), []);
+
+    clock.advance(time::Duration::minutes(5));
+    assert_matches!(tx.validate(&client)[..], [ValidationProblem::Expired { .. }]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn execution_plan() -> crate::Result<()> {
+    use std::time::Duration;
+
+    let client = Client::for_testnet();
+    client.set_max_attempts(4);
+    client.set_max_backoff(Duration::from_secs(1));
+    client.set_request_timeout(Some(Duration::from_secs(30)));
+
+    let mut tx = TransferTransaction::new();
+    tx.node_account_ids([3.into(), 4.into()]);
+
+    let plan = tx.execution_plan(&client)?;
+
+    let nodes: HashSet<_> = plan.node_account_ids.iter().copied().collect();
+    assert_eq!(nodes, HashSet::from([AccountId::new(0, 0, 3), AccountId::new(0, 0, 4)]));
+    assert_eq!(plan.max_attempts, 4);
+    assert_eq!(plan.request_timeout, Duration::from_secs(30));
+
+    // the backoff grows from the minimum, up to the maximum.
+    let approx = |expected: u64, actual: Duration| {
+        (Duration::from_millis(expected - 1)..=Duration::from_millis(expected + 1))
+            .contains(&actual)
+    };
+
+    assert_matches!(
+        plan.backoff_schedule[..],
+        [a, b, c] if approx(500, a) && approx(750, b) && approx(1000, c)
+    );
+
+    // without a timeout, execution still stops after the backoff's default max elapsed time.
+    client.set_request_timeout(None);
+    assert_eq!(tx.execution_plan(&client)?.request_timeout, Duration::from_secs(15 * 60));
+
+    tx.node_account_ids([AccountId::new(0, 0, 999_999)]);
+    assert_matches!(tx.execution_plan(&client), Err(crate::Error::NodeAccountUnknown(_)));
+
+    Ok(())
+}
+
+#[cfg(feature = "topics")]
+#[test]
+fn sticky_chunk_node() {
+    use crate::execute::Execute;
+    use crate::TopicMessageSubmitTransaction;
+
+    let mut tx = TopicMessageSubmitTransaction::new();
+    assert!(!tx.get_sticky_chunk_node());
+
+    tx.sticky_chunk_node(true).node_account_ids([3.into(), 4.into()]);
+    assert!(tx.get_sticky_chunk_node());
+
+    let view = |node_account_id| super::chunked::ChunkView {
+        transaction: &tx,
+        initial_transaction_id: TransactionId::generate(5006.into()),
+        current_chunk: 1,
+        total_chunks: 2,
+        node_account_id,
+    };
+
+    assert_eq!(view(None).node_account_ids(), Some([3.into(), 4.into()].as_slice()));
+    assert_eq!(view(Some(4.into())).node_account_ids(), Some([4.into()].as_slice()));
+}
+
+#[tokio::test]
+async fn from_bytes_validate() -> crate::Result<()> {
+    let client = Client::for_testnet();
+
+    let transaction_id = TransactionId {
+        account_id: 101.into(),
+        valid_start: OffsetDateTime::now_utc(),
+        nonce: None,
+        scheduled: false,
+    };
+
+    let bytes = TransferTransaction::new()
+        .hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-1))
+        .transaction_id(transaction_id)
+        .node_account_ids([6.into()])
+        .freeze()?
+        .to_bytes()?;
+
+    let tx = AnyTransaction::from_bytes(&bytes)?;
+
+    assert_eq!(
+        tx.validate_at(&client, transaction_id.valid_start),
+        [ValidationProblem::UnbalancedTransfers { token_id: None, sum: 100_000_000 }]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn from_bytes_describe() -> crate::Result<()> {
+    let bytes = TransferTransaction::new()
+        .hbar_transfer(2.into(), Hbar::new(1))
+        .hbar_transfer(101.into(), Hbar::new(-1))
+        .transaction_id(TransactionId {
+            account_id: 101.into(),
+            valid_start: OffsetDateTime::now_utc(),
+            nonce: None,
+            scheduled: false,
+        })
+        .node_account_ids([6.into()])
+        .freeze()?
+        .to_bytes()?;
+
+    let description = AnyTransaction::from_bytes(&bytes)?.describe();
+
+    assert_eq!(description.kind, "TransferTransaction");
+    assert_eq!(description.transfers.len(), 2);
+
+    Ok(())
+}
+
+#[cfg(feature = "topics")]
+#[tokio::test]
+async fn execute_chunked_with_cancellation() {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use crate::{
+        CancellationToken,
+        Error,
+        TopicMessageSubmitTransaction,
+    };
+
+    let client =
+        Client::for_network(HashMap::from([("127.0.0.1:1".to_owned(), AccountId::new(0, 0, 3))]))
+            .unwrap();
+    client.set_operator(0.into(), PrivateKey::generate_ed25519());
+
+    let cancellation = CancellationToken::new();
+
+    tokio::spawn({
+        let cancellation = cancellation.clone();
+        async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            cancellation.cancel();
+        }
+    });
+
+    // the node is unreachable, so without cancellation this would keep retrying.
+    let result = tokio::time::timeout(
+        Duration::from_secs(10),
+        TopicMessageSubmitTransaction::new()
+            .topic_id(314)
+            .message(b"Hello, world!".to_vec())
+            .chunk_size(8)
+            .execute_chunked_with_cancellation(&client, &cancellation),
+    )
+    .await
+    .expect("cancellation should stop the transaction");
+
+    assert!(matches!(result, Err(Error::Cancelled)));
+}
+
+#[tokio::test]
+async fn execute_after_environment_switch() -> crate::Result<()> {
+    let client = Client::for_testnet();
+    client.set_operator(0.into(), PrivateKey::generate_ed25519());
+
+    let mut tx = TransferTransaction::new();
+    tx.freeze_with(&client)?;
+
+    // the transaction's nodes and transaction ID were picked from the old environment.
+    client.switch_environment(crate::Environment::Previewnet).await?;
+
+    assert_matches!(tx.execute(&client).await, Err(crate::Error::EnvironmentSwitched));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn switch_environment_waits_for_in_flight_requests() -> crate::Result<()> {
+    let client = Client::for_testnet();
+
+    // a request that was started against the old environment.
+    let in_flight = client.in_flight_request().await;
+
+    let switch = tokio::spawn({
+        let client = client.clone();
+        async move { client.switch_environment(crate::Environment::Previewnet).await }
+    });
+
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    assert!(!switch.is_finished());
+    assert_eq!(client.environment(), 0);
+
+    drop(in_flight);
+
+    tokio::time::timeout(std::time::Duration::from_secs(10), switch)
+        .await
+        .expect("switching environments should finish once the request does")
+        .unwrap()?;
+
+    assert_eq!(client.environment(), 1);
+
+    Ok(())
+}
+
+#[cfg(feature = "topics")]
+#[tokio::test]
+async fn switch_environment_waits_for_chunked_transactions() -> crate::Result<()> {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use crate::{
+        Error,
+        TopicMessageSubmitTransaction,
+    };
+
+    let client =
+        Client::for_network(HashMap::from([("127.0.0.1:1".to_owned(), AccountId::new(0, 0, 3))]))?;
+    client.set_operator(0.into(), PrivateKey::generate_ed25519());
+    // the node is unreachable, so the first chunk is retried until it times out.
+    client.set_request_timeout(Some(Duration::from_secs(2)));
+
+    let submission = tokio::spawn({
+        let client = client.clone();
+        async move {
+            TopicMessageSubmitTransaction::new()
+                .topic_id(314)
+                .message(b"Hello, world!".to_vec())
+                .chunk_size(4)
+                .execute_chunked(&client)
+                .await
+        }
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let switch = tokio::spawn({
+        let client = client.clone();
+        async move { client.switch_environment(crate::Environment::Previewnet).await }
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // the switch waits for every chunk, not just the one that's being sent.
+    assert!(!submission.is_finished());
+    assert!(!switch.is_finished());
+    assert_eq!(client.environment(), 0);
+
+    let result = tokio::time::timeout(Duration::from_secs(10), submission)
+        .await
+        .expect("the transaction should time out")
+        .unwrap();
+
+    assert!(result.is_err());
+    assert!(!matches!(result, Err(Error::EnvironmentSwitched)));
+
+    tokio::time::timeout(Duration::from_secs(10), switch)
+        .await
+        .expect("switching environments should finish once the transaction does")
+        .unwrap()?;
+
+    assert_eq!(client.environment(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn freeze_rejects_out_of_range_valid_duration() {
+    let mut tx = TransferTransaction::new();
+
+    tx.transaction_id(TransactionId {
+        account_id: 101.into(),
+        valid_start: OffsetDateTime::now_utc(),
+        nonce: None,
+        scheduled: false,
+    })
+    .node_account_ids([6.into(), 7.into()])
+    .transaction_valid_duration(time::Duration::seconds(181));
+
+    assert_matches!(
+        tx.freeze(),
+        Err(crate::Error::TransactionValidDurationOutOfRange(duration)) if duration == time::Duration::seconds(181)
+    );
+    assert!(!tx.is_frozen());
+
+    tx.transaction_valid_duration(time::Duration::seconds(180));
+
+    assert!(tx.freeze().is_ok());
+}
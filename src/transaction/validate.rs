@@ -0,0 +1,566 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+
+use super::{
+    TransactionExecute,
+    DEFAULT_TRANSACTION_VALID_DURATION,
+};
+use crate::entity_id::ValidateChecksums;
+use crate::{
+    AccountId,
+    Client,
+    Key,
+    TokenId,
+    Transaction,
+};
+
+/// The maximum length of a memo, in bytes.
+pub(crate) const MAX_MEMO_LEN: usize = 100;
+
+/// The maximum valid duration of a transaction.
+pub(crate) const MAX_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(180);
+
+/// A problem with a transaction found by [`Transaction::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationProblem {
+    /// A field that's required for this kind of transaction isn't set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+
+    /// A memo is longer than the network allows.
+    MemoTooLong {
+        /// The name of the memo field.
+        field: &'static str,
+
+        /// The length of the memo, in bytes.
+        len: usize,
+    },
+
+    /// A key that can't be empty is an empty key list.
+    EmptyKey {
+        /// The name of the key field.
+        field: &'static str,
+    },
+
+    /// A key list has a threshold that can never be met.
+    UnreachableThreshold {
+        /// The name of the key field.
+        field: &'static str,
+
+        /// The threshold of the key list.
+        threshold: u32,
+
+        /// The number of keys in the key list.
+        keys: usize,
+    },
+
+    /// The transaction's contents need more chunks than it's allowed to use.
+    TooManyChunks {
+        /// The number of chunks that are needed.
+        required: usize,
+
+        /// The maximum number of chunks allowed.
+        max: usize,
+    },
+
+    /// An entity ID has a checksum for a different ledger than the client's.
+    Checksum(String),
+
+    /// The transfers of an asset don't sum to zero.
+    UnbalancedTransfers {
+        /// The token the transfers are for, or `None` for hbar.
+        token_id: Option<TokenId>,
+
+        /// The sum of the transfers.
+        ///
+        /// This is wider than the amounts, so that sums which overflow an `i64` are reported exactly.
+        sum: i128,
+    },
+
+    /// The transaction's valid start is in the future, so the network would reject it.
+    NotYetValid {
+        /// The valid start of the transaction ID.
+        valid_start: OffsetDateTime,
+    },
+
+    /// The transaction's valid duration has already passed.
+    Expired {
+        /// The time the transaction stopped being valid.
+        valid_until: OffsetDateTime,
+    },
+
+    /// The transaction's valid duration isn't positive, or is longer than the network allows (180 seconds).
+    InvalidValidDuration {
+        /// The valid duration of the transaction.
+        duration: Duration,
+    },
+
+    /// A key isn't set, but a flag that needs it is, IE, `freeze_default` without a `freeze_key`.
+    KeyRequired {
+        /// The name of the key field.
+        field: &'static str,
+
+        /// The name of the flag that needs the key.
+        flag: &'static str,
+    },
+
+    /// An account other than the payer has to sign the transaction, but only the payer signs it.
+    ///
+    /// The keys of accounts aren't known locally,
+    /// so this is only reported if nothing besides the payer signs the transaction.
+    MissingSignature {
+        /// The name of the field with the account.
+        field: &'static str,
+
+        /// The account that has to sign.
+        account_id: AccountId,
+    },
+}
+
+impl fmt::Display for ValidationProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingField { field } => write!(f, "`{field}` is required but not set"),
+            Self::MemoTooLong { field, len } => {
+                write!(f, "`{field}` is {len} bytes long, the maximum is {MAX_MEMO_LEN}")
+            }
+            Self::EmptyKey { field } => write!(f, "`{field}` is an empty key list"),
+            Self::UnreachableThreshold { field, threshold, keys } => {
+                write!(f, "`{field}` has a threshold of {threshold} but only contains {keys} keys")
+            }
+            Self::TooManyChunks { required, max } => {
+                write!(f, "the transaction needs {required} chunks, but at most {max} are allowed")
+            }
+            Self::Checksum(message) => write!(f, "checksum mismatch: {message}"),
+            Self::UnbalancedTransfers { token_id: Some(token_id), sum } => {
+                write!(f, "transfers of token `{token_id}` sum to {sum} rather than 0")
+            }
+            Self::UnbalancedTransfers { token_id: None, sum } => {
+                write!(f, "hbar transfers sum to {sum} tinybars rather than 0")
+            }
+            Self::NotYetValid { valid_start } => {
+                write!(f, "the transaction isn't valid until {valid_start}")
+            }
+            Self::Expired { valid_until } => write!(f, "the transaction expired at {valid_until}"),
+            Self::InvalidValidDuration { duration } => write!(
+                f,
+                "the transaction is valid for {duration}, it must be more than 0s and at most {MAX_TRANSACTION_VALID_DURATION}"
+            ),
+            Self::KeyRequired { field, flag } => {
+                write!(f, "`{field}` is required when `{flag}` is set")
+            }
+            Self::MissingSignature { field, account_id } => write!(
+                f,
+                "`{field}` (`{account_id}`) has to sign the transaction, but only the payer signs it"
+            ),
+        }
+    }
+}
+
+/// Report `memo` if it's too long.
+pub(crate) fn check_memo(problems: &mut Vec<ValidationProblem>, field: &'static str, memo: &str) {
+    if memo.len() > MAX_MEMO_LEN {
+        problems.push(ValidationProblem::MemoTooLong { field, len: memo.len() });
+    }
+}
+
+/// Report `key` if it's an empty key list or a key list with an unreachable threshold.
+pub(crate) fn check_key(problems: &mut Vec<ValidationProblem>, field: &'static str, key: &Key) {
+    let Key::KeyList(list) = key else { return };
+
+    if list.is_empty() {
+        problems.push(ValidationProblem::EmptyKey { field });
+        return;
+    }
+
+    if let Some(threshold) = list.threshold {
+        if threshold as usize > list.len() {
+            problems.push(ValidationProblem::UnreachableThreshold {
+                field,
+                threshold,
+                keys: list.len(),
+            });
+        }
+    }
+}
+
+/// Report `key` like [`check_key`], but allow an empty key list, which removes the key it replaces.
+pub(crate) fn check_updated_key(
+    problems: &mut Vec<ValidationProblem>,
+    field: &'static str,
+    key: &Key,
+) {
+    if matches!(key, Key::KeyList(list) if list.is_empty()) {
+        return;
+    }
+
+    check_key(problems, field, key);
+}
+
+/// Report `value` if it's `None`.
+pub(crate) fn check_required<T>(
+    problems: &mut Vec<ValidationProblem>,
+    field: &'static str,
+    value: &Option<T>,
+) {
+    if value.is_none() {
+        problems.push(ValidationProblem::MissingField { field });
+    }
+}
+
+impl<D: TransactionExecute> Transaction<D> {
+    /// Check this transaction for problems that can be found without submitting it.
+    ///
+    /// This checks:
+    /// - fields that this kind of transaction requires,
+    /// - memo lengths,
+    /// - empty (or unsatisfiable) key lists,
+    /// - keys that flags depend on (IE, `freeze_key` for `freeze_default`,
+    ///   there's no KYC counterpart, since tokens have no KYC default to set),
+    /// - that accounts which have to sign (IE, a token's treasury) aren't left to the payer's signature alone,
+    /// - that chunked contents fit in `max_chunks`,
+    /// - entity ID checksums against the client's ledger (if it has one),
+    /// - that the transaction's valid duration is at most 180 seconds,
+    /// - that the transaction's valid start isn't in the future, and it hasn't expired,
+    ///   according to the client's [clock](Client::set_clock).
+    ///
+    /// An empty list means no problems were found, *not* that the network will accept the transaction.
+    #[must_use]
+    pub fn validate(&self, client: &Client) -> Vec<ValidationProblem> {
+        self.validate_at(client, client.clock().now())
+    }
+
+    pub(crate) fn validate_at(
+        &self,
+        client: &Client,
+        now: OffsetDateTime,
+    ) -> Vec<ValidationProblem> {
+        let mut problems = Vec::new();
+
+        self.data().validate_data(&mut problems);
+
+        check_memo(&mut problems, "transaction_memo", self.get_transaction_memo());
+
+        if let Some(duration) = self.get_transaction_valid_duration() {
+            if !duration.is_positive() || duration > MAX_TRANSACTION_VALID_DURATION {
+                problems.push(ValidationProblem::InvalidValidDuration { duration });
+            }
+        }
+
+        if let Some(chunk_data) = self.data().maybe_chunk_data() {
+            if chunk_data.used_chunks() > chunk_data.max_chunks {
+                problems.push(ValidationProblem::TooManyChunks {
+                    required: chunk_data.used_chunks(),
+                    max: chunk_data.max_chunks,
+                });
+            }
+        }
+
+        if let Some(ledger_id) = &*client.ledger_id_internal() {
+            if let Err(e) = self.validate_checksums(ledger_id.as_ref_ledger_id()) {
+                problems.push(ValidationProblem::Checksum(e.to_string()));
+            }
+        }
+
+        let payer = self
+            .get_transaction_id()
+            .map(|it| it.account_id)
+            .or_else(|| client.load_operator().as_ref().map(|it| it.account_id));
+
+        // signatures from sources can't be attributed to accounts, so only count them as "something else signs".
+        let has_other_signers = !self.signers.is_empty() || self.sources.is_some();
+
+        if let Some(payer) = payer {
+            for (field, account_id) in self.data().required_signers() {
+                if account_id != payer && !has_other_signers {
+                    problems.push(ValidationProblem::MissingSignature { field, account_id });
+                }
+            }
+        }
+
+        match self.get_transaction_id() {
+            Some(transaction_id) => {
+                let valid_start = transaction_id.valid_start;
+                let valid_until = valid_start
+                    + self
+                        .get_transaction_valid_duration()
+                        .unwrap_or(DEFAULT_TRANSACTION_VALID_DURATION);
+
+                if valid_start > now {
+                    problems.push(ValidationProblem::NotYetValid { valid_start });
+                } else if valid_until <= now {
+                    problems.push(ValidationProblem::Expired { valid_until });
+                }
+            }
+
+            None if client.load_operator().is_none() => {
+                problems.push(ValidationProblem::MissingField { field: "transaction_id" });
+            }
+
+            None => {}
+        }
+
+        problems
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use super::ValidationProblem;
+    use crate::transaction::test_helpers::{
+        unused_private_key,
+        TEST_ACCOUNT_ID,
+        TEST_TX_ID,
+    };
+    use crate::{
+        AccountCreateTransaction,
+        AccountId,
+        AnyTransaction,
+        Client,
+        Hbar,
+        KeyList,
+        TokenCreateTransaction,
+        TransferTransaction,
+    };
+
+    #[tokio::test]
+    async fn valid_transfer() {
+        let client = Client::for_testnet();
+
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(2.into(), Hbar::new(-1))
+            .hbar_transfer(3.into(), Hbar::new(1))
+            .transaction_id(TEST_TX_ID);
+
+        assert_eq!(tx.validate_at(&client, TEST_TX_ID.valid_start + Duration::seconds(1)), []);
+    }
+
+    #[tokio::test]
+    async fn invalid_transfer() {
+        let client = Client::for_testnet();
+
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(2.into(), Hbar::new(-1))
+            .hbar_transfer(3.into(), Hbar::new(2))
+            .transaction_memo("a".repeat(101));
+
+        assert_eq!(
+            tx.validate(&client),
+            [
+                ValidationProblem::UnbalancedTransfers { token_id: None, sum: 100_000_000 },
+                ValidationProblem::MemoTooLong { field: "transaction_memo", len: 101 },
+                ValidationProblem::MissingField { field: "transaction_id" },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn transfer_sum_overflow() {
+        let client = Client::for_testnet();
+
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(2.into(), Hbar::from_tinybars(i64::MAX))
+            .hbar_transfer(3.into(), Hbar::from_tinybars(i64::MAX))
+            .transaction_id(TEST_TX_ID);
+
+        assert_eq!(
+            tx.validate_at(&client, TEST_TX_ID.valid_start),
+            [ValidationProblem::UnbalancedTransfers {
+                token_id: None,
+                sum: 2 * i128::from(i64::MAX),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn valid_start() {
+        let client = Client::for_testnet();
+
+        let mut tx = TransferTransaction::new();
+        tx.transaction_id(TEST_TX_ID);
+
+        let valid_start = TEST_TX_ID.valid_start;
+
+        assert_eq!(
+            tx.validate_at(&client, valid_start - Duration::seconds(1)),
+            [ValidationProblem::NotYetValid { valid_start }]
+        );
+
+        assert_eq!(
+            tx.validate_at(&client, valid_start + Duration::seconds(120)),
+            [ValidationProblem::Expired { valid_until: valid_start + Duration::seconds(120) }]
+        );
+    }
+
+    #[tokio::test]
+    async fn valid_duration() {
+        let client = Client::for_testnet();
+
+        let mut tx = TransferTransaction::new();
+        tx.transaction_id(TEST_TX_ID).transaction_valid_duration(Duration::seconds(180));
+
+        assert_eq!(tx.validate_at(&client, TEST_TX_ID.valid_start), []);
+
+        tx.transaction_valid_duration(Duration::seconds(181));
+
+        assert_eq!(
+            tx.validate_at(&client, TEST_TX_ID.valid_start),
+            [ValidationProblem::InvalidValidDuration { duration: Duration::seconds(181) }]
+        );
+
+        tx.transaction_valid_duration(Duration::ZERO);
+
+        assert_eq!(
+            tx.validate_at(&client, TEST_TX_ID.valid_start),
+            [
+                ValidationProblem::InvalidValidDuration { duration: Duration::ZERO },
+                ValidationProblem::Expired { valid_until: TEST_TX_ID.valid_start },
+            ]
+        );
+    }
+
+    #[cfg(feature = "topics")]
+    #[tokio::test]
+    async fn topic_message_submit() {
+        use crate::TopicMessageSubmitTransaction;
+
+        let client = Client::for_testnet();
+
+        let mut tx = TopicMessageSubmitTransaction::new();
+        tx.message(vec![0; 1500]).chunk_size(1024).max_chunks(1).transaction_id(TEST_TX_ID);
+
+        assert_eq!(
+            tx.validate_at(&client, TEST_TX_ID.valid_start),
+            [
+                ValidationProblem::MissingField { field: "topic_id" },
+                ValidationProblem::TooManyChunks { required: 2, max: 1 },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn account_create_empty_key() {
+        let client = Client::for_testnet();
+
+        let mut tx = AccountCreateTransaction::new();
+        tx.key(KeyList::new()).transaction_id(TEST_TX_ID);
+
+        assert_eq!(
+            tx.validate_at(&client, TEST_TX_ID.valid_start),
+            [ValidationProblem::EmptyKey { field: "key" }]
+        );
+    }
+
+    #[tokio::test]
+    async fn token_create_freeze_default_and_treasury() {
+        let client = Client::for_testnet();
+        let treasury = AccountId::new(0, 0, 1001);
+
+        let mut tx = TokenCreateTransaction::new();
+        tx.name("ffff")
+            .symbol("F")
+            .treasury_account_id(treasury)
+            .freeze_default(true)
+            .transaction_id(TEST_TX_ID);
+
+        assert_eq!(
+            tx.validate_at(&client, TEST_TX_ID.valid_start),
+            [
+                ValidationProblem::KeyRequired { field: "freeze_key", flag: "freeze_default" },
+                ValidationProblem::MissingSignature {
+                    field: "treasury_account_id",
+                    account_id: treasury,
+                },
+            ]
+        );
+
+        tx.freeze_key(unused_private_key().public_key()).treasury_account_id(TEST_ACCOUNT_ID);
+
+        assert_eq!(tx.validate_at(&client, TEST_TX_ID.valid_start), []);
+
+        tx.treasury_account_id(treasury).sign(unused_private_key());
+
+        assert_eq!(tx.validate_at(&client, TEST_TX_ID.valid_start), []);
+    }
+
+    #[tokio::test]
+    async fn freeze_upgrade_requires_file() {
+        use crate::{
+            FreezeTransaction,
+            FreezeType,
+        };
+
+        let client = Client::for_testnet();
+
+        let mut tx = FreezeTransaction::new();
+        tx.transaction_id(TEST_TX_ID);
+
+        assert_eq!(
+            tx.validate_at(&client, TEST_TX_ID.valid_start),
+            [ValidationProblem::MissingField { field: "freeze_type" }]
+        );
+
+        tx.freeze_type(FreezeType::FreezeUpgrade).start_time(TEST_TX_ID.valid_start);
+
+        assert_eq!(
+            tx.validate_at(&client, TEST_TX_ID.valid_start),
+            [
+                ValidationProblem::MissingField { field: "file_id" },
+                ValidationProblem::MissingField { field: "file_hash" },
+            ]
+        );
+    }
+
+    #[cfg(feature = "tokens")]
+    #[tokio::test]
+    async fn token_update_removes_key() {
+        use crate::TokenUpdateTransaction;
+
+        let client = Client::for_testnet();
+
+        let mut tx = TokenUpdateTransaction::new();
+        tx.token_id("0.0.10".parse().unwrap())
+            .wipe_key(KeyList::new())
+            .kyc_key(KeyList {
+                keys: vec![unused_private_key().public_key().into()],
+                threshold: Some(2),
+            })
+            .transaction_id(TEST_TX_ID);
+
+        // an empty key list removes `wipe_key`, but the threshold of `kyc_key` can't be met.
+        assert_eq!(
+            tx.validate_at(&client, TEST_TX_ID.valid_start),
+            [ValidationProblem::UnreachableThreshold { field: "kyc_key", threshold: 2, keys: 1 }]
+        );
+    }
+
+    #[tokio::test]
+    async fn any_transaction_required_signers() {
+        let client = Client::for_testnet();
+        let treasury = AccountId::new(0, 0, 1001);
+
+        let mut tx = TokenCreateTransaction::new();
+        tx.name("ffff").symbol("F").treasury_account_id(treasury).transaction_id(TEST_TX_ID);
+
+        let tx: AnyTransaction = tx.into();
+
+        assert_eq!(
+            tx.validate_at(&client, TEST_TX_ID.valid_start),
+            [ValidationProblem::MissingSignature {
+                field: "treasury_account_id",
+                account_id: treasury
+            }]
+        );
+    }
+}
@@ -19,6 +19,7 @@ use time::{
 };
 
 use crate::ledger_id::RefLedgerId;
+use crate::protobuf::FromProtobufContext;
 use crate::{
     AccountId,
     Error,
@@ -56,8 +57,14 @@ impl TransactionId {
     /// Generates a new transaction ID for the given account ID.
     #[must_use]
     pub fn generate(account_id: AccountId) -> Self {
-        let valid_start = OffsetDateTime::now_utc()
-            - Duration::nanoseconds(thread_rng().gen_range(5_000_000_000..8_000_000_000));
+        Self::generate_at(account_id, OffsetDateTime::now_utc())
+    }
+
+    /// Generates a new transaction ID for the given account ID, as if the current time was `now`.
+    #[must_use]
+    pub fn generate_at(account_id: AccountId, now: OffsetDateTime) -> Self {
+        let valid_start =
+            now - Duration::nanoseconds(thread_rng().gen_range(5_000_000_000..8_000_000_000));
 
         Self { account_id, valid_start, scheduled: false, nonce: None }
     }
@@ -114,7 +121,8 @@ impl FromProtobuf<services::TransactionId> for TransactionId {
 
         Ok(Self {
             account_id,
-            valid_start: valid_start.into(),
+            valid_start: OffsetDateTime::from_protobuf(valid_start)
+                .in_field("transaction_valid_start")?,
             nonce: (pb.nonce != 0).then_some(pb.nonce),
             scheduled: pb.scheduled,
         })
@@ -205,6 +213,26 @@ mod tests {
         TransactionId,
     };
 
+    #[test]
+    fn from_protobuf_out_of_range_valid_start() {
+        let pb = hedera_proto::services::TransactionId {
+            transaction_valid_start: Some(hedera_proto::services::Timestamp {
+                seconds: i64::MAX,
+                nanos: 0,
+            }),
+            account_id: Some(AccountId::from(5006).to_protobuf()),
+            scheduled: false,
+            nonce: 0,
+        };
+
+        let err = TransactionId::from_protobuf(pb).unwrap_err();
+
+        expect![[r#"
+            "failed to create a SDK type from a protobuf response: `transaction_valid_start`: timestamp `9223372036854775807s 0ns` is out of range"
+        "#]]
+        .assert_debug_eq(&err.to_string());
+    }
+
     #[test]
     fn from_str_wrong_field() {
         assert_matches!(TransactionId::from_str("0.0.31415?1641088801.2"), Err(_));
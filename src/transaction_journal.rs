@@ -0,0 +1,461 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+use parking_lot::Mutex;
+use sha2::{
+    Digest,
+    Sha256,
+};
+
+use crate::transaction::{
+    ChunkData,
+    TransactionExecute,
+};
+use crate::{
+    AnyTransaction,
+    Client,
+    Error,
+    Status,
+    Transaction,
+    TransactionId,
+    TransactionReceipt,
+    TransactionReceiptQuery,
+};
+
+/// The version of the [`JournalEntry`] byte format.
+const ENTRY_VERSION: u8 = 1;
+
+/// A persistent record of submitted transactions, keyed by a caller-chosen idempotency key,
+/// used by [`Transaction::execute_once`] to submit each transaction exactly once, even across crashes.
+///
+/// Entries are written before a transaction is submitted, and again once its receipt is known.
+/// Implementations must make `put` durable before returning, otherwise a crash can lose an entry.
+pub trait TransactionJournal: Send + Sync {
+    /// Returns the entry for `key`, if there is one.
+    ///
+    /// # Errors
+    /// - [`Error::TransactionJournal`] if the entry couldn't be read.
+    fn get(&self, key: &str) -> crate::Result<Option<JournalEntry>>;
+
+    /// Stores `entry` under `key`, replacing any existing entry.
+    ///
+    /// # Errors
+    /// - [`Error::TransactionJournal`] if the entry couldn't be written.
+    fn put(&self, key: &str, entry: &JournalEntry) -> crate::Result<()>;
+}
+
+/// A transaction recorded in a [`TransactionJournal`].
+///
+/// Custom journals can store an entry as opaque bytes with [`to_bytes`](Self::to_bytes) and [`from_bytes`](Self::from_bytes).
+#[derive(Debug, Clone)]
+pub struct JournalEntry {
+    transaction_id: TransactionId,
+    transaction_bytes: Vec<u8>,
+    receipt: Option<TransactionReceipt>,
+}
+
+impl JournalEntry {
+    fn pending(transaction_id: TransactionId, transaction_bytes: Vec<u8>) -> Self {
+        Self { transaction_id, transaction_bytes, receipt: None }
+    }
+
+    /// Returns the ID of the transaction.
+    #[must_use]
+    pub fn get_transaction_id(&self) -> TransactionId {
+        self.transaction_id
+    }
+
+    /// Returns the signed transaction, as from [`Transaction::to_bytes`].
+    #[must_use]
+    pub fn get_transaction_bytes(&self) -> &[u8] {
+        &self.transaction_bytes
+    }
+
+    /// Returns the receipt of the transaction, once it's known.
+    ///
+    /// An entry without a receipt is pending, IE, the transaction may or may not have been submitted.
+    #[must_use]
+    pub fn get_receipt(&self) -> Option<&TransactionReceipt> {
+        self.receipt.as_ref()
+    }
+
+    /// Convert this entry into bytes.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![ENTRY_VERSION];
+
+        write_chunk(&mut bytes, &self.transaction_id.to_bytes());
+        write_chunk(&mut bytes, &self.transaction_bytes);
+
+        if let Some(receipt) = &self.receipt {
+            write_chunk(&mut bytes, &receipt.to_bytes());
+        }
+
+        bytes
+    }
+
+    /// Parse an entry from the bytes made by [`to_bytes`](Self::to_bytes).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `bytes` isn't a valid entry.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let Some((&ENTRY_VERSION, mut rest)) = bytes.split_first() else {
+            return Err(Error::basic_parse("unsupported transaction journal entry version"));
+        };
+
+        let transaction_id = TransactionId::from_bytes(read_chunk(&mut rest)?)?;
+        let transaction_bytes = read_chunk(&mut rest)?.to_vec();
+
+        let receipt = if rest.is_empty() {
+            None
+        } else {
+            Some(TransactionReceipt::from_bytes(read_chunk(&mut rest)?)?)
+        };
+
+        if !rest.is_empty() {
+            return Err(Error::basic_parse("trailing bytes after transaction journal entry"));
+        }
+
+        Ok(Self { transaction_id, transaction_bytes, receipt })
+    }
+}
+
+fn write_chunk(bytes: &mut Vec<u8>, chunk: &[u8]) {
+    let len = u32::try_from(chunk.len()).expect("journal entry chunks are far smaller than 4GiB");
+
+    bytes.extend_from_slice(&len.to_be_bytes());
+    bytes.extend_from_slice(chunk);
+}
+
+fn read_chunk<'a>(bytes: &mut &'a [u8]) -> crate::Result<&'a [u8]> {
+    let truncated = || Error::basic_parse("truncated transaction journal entry");
+
+    let (len, rest) = bytes.split_first_chunk::<4>().ok_or_else(truncated)?;
+    let len = u32::from_be_bytes(*len) as usize;
+
+    if rest.len() < len {
+        return Err(truncated());
+    }
+
+    let (chunk, rest) = rest.split_at(len);
+    *bytes = rest;
+
+    Ok(chunk)
+}
+
+/// A [`TransactionJournal`] that keeps entries in memory.
+///
+/// Entries don't survive the process, so this only protects against submitting twice within it (IE, on retries).
+#[derive(Debug, Default)]
+pub struct InMemoryTransactionJournal {
+    entries: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl InMemoryTransactionJournal {
+    /// Create a new, empty, journal.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TransactionJournal for InMemoryTransactionJournal {
+    fn get(&self, key: &str) -> crate::Result<Option<JournalEntry>> {
+        self.entries.lock().get(key).map(|it| JournalEntry::from_bytes(it)).transpose()
+    }
+
+    fn put(&self, key: &str, entry: &JournalEntry) -> crate::Result<()> {
+        self.entries.lock().insert(key.to_owned(), entry.to_bytes());
+
+        Ok(())
+    }
+}
+
+/// A [`TransactionJournal`] that keeps each entry in its own file in a directory.
+///
+/// Entries are written to a temporary file, synced, and then renamed into place,
+/// so an entry is never left half written. On unix the directory is synced after the rename too,
+/// so the entry survives a crash once [`put`](TransactionJournal::put) returns.
+///
+/// Each entry's file is named after the SHA-256 hash of its key, so keys of any length or content can be used.
+#[derive(Debug, Clone)]
+pub struct FileTransactionJournal {
+    directory: PathBuf,
+}
+
+impl FileTransactionJournal {
+    /// Create a journal that stores entries in `directory`, creating it if it doesn't exist.
+    ///
+    /// # Errors
+    /// - [`Error::TransactionJournal`] if the directory couldn't be created.
+    pub fn new(directory: impl Into<PathBuf>) -> crate::Result<Self> {
+        let directory = directory.into();
+
+        fs::create_dir_all(&directory).map_err(Error::transaction_journal)?;
+
+        Ok(Self { directory })
+    }
+
+    /// Returns the directory entries are stored in.
+    #[must_use]
+    pub fn get_directory(&self) -> &std::path::Path {
+        &self.directory
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        // keys are caller chosen, so hash them rather than trusting them as file names,
+        // this also keeps the name under `NAME_MAX` however long the key is.
+        self.directory.join(format!("{}.entry", hex::encode(Sha256::digest(key))))
+    }
+}
+
+impl TransactionJournal for FileTransactionJournal {
+    fn get(&self, key: &str) -> crate::Result<Option<JournalEntry>> {
+        match fs::read(self.path(key)) {
+            Ok(bytes) => JournalEntry::from_bytes(&bytes).map(Some),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(Error::transaction_journal(e)),
+        }
+    }
+
+    fn put(&self, key: &str, entry: &JournalEntry) -> crate::Result<()> {
+        let path = self.path(key);
+        let tmp = path.with_extension("entry.tmp");
+
+        let write = || -> std::io::Result<()> {
+            let mut file = fs::File::create(&tmp)?;
+            file.write_all(&entry.to_bytes())?;
+            file.sync_all()?;
+
+            fs::rename(&tmp, &path)?;
+
+            // the rename itself is only durable once the directory is synced.
+            // windows can't open directories as files, and NTFS journals renames anyway.
+            #[cfg(unix)]
+            fs::File::open(&self.directory)?.sync_all()?;
+
+            Ok(())
+        };
+
+        write().map_err(Error::transaction_journal)
+    }
+}
+
+impl<D: TransactionExecute> Transaction<D> {
+    /// Execute this transaction exactly once per `key`, recording it in `journal`, and wait for its receipt.
+    ///
+    /// - If `journal` has a receipt for `key`, it's returned without submitting anything.
+    /// - If `journal` has a pending entry for `key` (IE, the process crashed after submitting),
+    ///   the recorded transaction is resubmitted as is, with the same transaction ID, which the network deduplicates.
+    ///   In this case `self` is ignored.
+    /// - Otherwise, this transaction is frozen and signed with the operator,
+    ///   recorded as pending, and then submitted.
+    ///
+    /// The receipt is returned regardless of its status, since a failed transaction still reached consensus.
+    ///
+    /// # Errors
+    /// - [`Error::TransactionJournal`] if the journal couldn't be read or written.
+    /// - [`Error::TransactionJournalChunked`] if this transaction has more than one chunk.
+    /// - [`Error::TransactionOutcomeUnknown`] if a pending transaction expired before its outcome could be confirmed,
+    ///   it may or may not have reached consensus (see [`reconcile`](crate::reconcile) to find out).
+    /// - Any error [`execute`](Self::execute) or [`TransactionReceiptQuery::execute`] can return.
+    pub async fn execute_once(
+        &mut self,
+        client: &Client,
+        journal: &dyn TransactionJournal,
+        key: &str,
+    ) -> crate::Result<TransactionReceipt> {
+        let entry = match journal.get(key)? {
+            Some(JournalEntry { receipt: Some(receipt), .. }) => return Ok(receipt),
+            Some(entry) => entry,
+            None => {
+                let used_chunks = self.data().maybe_chunk_data().map_or(1, ChunkData::used_chunks);
+
+                // only the first chunk's transaction ID would be journaled (and waited for).
+                if used_chunks > 1 {
+                    return Err(Error::TransactionJournalChunked(used_chunks));
+                }
+
+                if client.get_operator_account_id().is_some() {
+                    self.sign_with_operator(client)?;
+                } else {
+                    self.freeze_with(client)?;
+                }
+
+                let transaction_id =
+                    self.get_transaction_id().ok_or(Error::NoPayerAccountOrTransactionId)?;
+
+                let entry = JournalEntry::pending(transaction_id, self.to_bytes()?);
+                journal.put(key, &entry)?;
+
+                entry
+            }
+        };
+
+        let receipt = submit(client, &entry).await?;
+
+        journal.put(key, &JournalEntry { receipt: Some(receipt.clone()), ..entry })?;
+
+        Ok(receipt)
+    }
+}
+
+/// Submits the transaction of a pending `entry`, and returns its receipt.
+async fn submit(client: &Client, entry: &JournalEntry) -> crate::Result<TransactionReceipt> {
+    let mut transaction = AnyTransaction::from_bytes(&entry.transaction_bytes)?;
+
+    match transaction.execute(client).await {
+        // a duplicate means that an earlier submission got through.
+        Ok(_)
+        | Err(Error::TransactionPreCheckStatus { status: Status::DuplicateTransaction, .. }) => {}
+
+        Err(Error::TransactionPreCheckStatus { status: Status::TransactionExpired, .. }) => {
+            return Err(Error::TransactionOutcomeUnknown(Box::new(entry.transaction_id)));
+        }
+
+        Err(e) => return Err(e),
+    }
+
+    TransactionReceiptQuery::new().transaction_id(entry.transaction_id).execute(client).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{
+        FileTransactionJournal,
+        InMemoryTransactionJournal,
+        JournalEntry,
+        TransactionJournal,
+    };
+    use crate::transaction::test_helpers::TEST_TX_ID;
+    use crate::transaction_receipt::make_receipt;
+    use crate::{
+        AccountId,
+        Client,
+        Error,
+        FileAppendTransaction,
+        TransferTransaction,
+    };
+
+    fn pending() -> JournalEntry {
+        JournalEntry::pending(TEST_TX_ID, vec![1, 2, 3])
+    }
+
+    fn completed() -> JournalEntry {
+        JournalEntry { receipt: Some(make_receipt()), ..pending() }
+    }
+
+    fn assert_same(a: &JournalEntry, b: &JournalEntry) {
+        assert_eq!(a.to_bytes(), b.to_bytes());
+    }
+
+    #[test]
+    fn entry_bytes_round_trip() {
+        for entry in [pending(), completed()] {
+            assert_same(&JournalEntry::from_bytes(&entry.to_bytes()).unwrap(), &entry);
+        }
+    }
+
+    #[test]
+    fn entry_from_bad_bytes() {
+        let bytes = completed().to_bytes();
+
+        assert!(JournalEntry::from_bytes(&[]).is_err());
+        assert!(JournalEntry::from_bytes(&[2]).is_err());
+        assert!(JournalEntry::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn in_memory() {
+        let journal = InMemoryTransactionJournal::new();
+
+        assert!(journal.get("a").unwrap().is_none());
+
+        journal.put("a", &pending()).unwrap();
+        journal.put("a", &completed()).unwrap();
+
+        assert_same(&journal.get("a").unwrap().unwrap(), &completed());
+        assert!(journal.get("b").unwrap().is_none());
+    }
+
+    #[test]
+    fn file() {
+        let directory = std::env::temp_dir()
+            .join(format!("hedera-transaction-journal-test-{}", std::process::id()));
+
+        let journal = FileTransactionJournal::new(&directory).unwrap();
+
+        assert!(journal.get("payment/1").unwrap().is_none());
+
+        journal.put("payment/1", &pending()).unwrap();
+        journal.put("payment/1", &completed()).unwrap();
+
+        // a new journal over the same directory, IE, after a restart.
+        let journal = FileTransactionJournal::new(&directory).unwrap();
+        assert_same(&journal.get("payment/1").unwrap().unwrap(), &completed());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn file_long_key() {
+        let directory = std::env::temp_dir()
+            .join(format!("hedera-transaction-journal-long-key-test-{}", std::process::id()));
+
+        let journal = FileTransactionJournal::new(&directory).unwrap();
+
+        // hex encoded, this would be 1000 bytes, well over `NAME_MAX` (255 on most filesystems).
+        let key = "k".repeat(500);
+
+        journal.put(&key, &completed()).unwrap();
+
+        assert_same(&journal.get(&key).unwrap().unwrap(), &completed());
+        assert!(journal.get(&"k".repeat(499)).unwrap().is_none());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[tokio::test]
+    async fn execute_once_returns_recorded_receipt() {
+        // the node is unreachable, so this only passes if nothing is submitted.
+        let client = Client::for_network(HashMap::from([(
+            "127.0.0.1:1".to_owned(),
+            AccountId::new(0, 0, 3),
+        )]))
+        .unwrap();
+
+        let journal = InMemoryTransactionJournal::new();
+        journal.put("a", &completed()).unwrap();
+
+        let receipt =
+            TransferTransaction::new().execute_once(&client, &journal, "a").await.unwrap();
+
+        assert_eq!(receipt.to_bytes(), make_receipt().to_bytes());
+    }
+
+    #[tokio::test]
+    async fn execute_once_rejects_chunked_transactions() {
+        let client = Client::for_network(HashMap::from([(
+            "127.0.0.1:1".to_owned(),
+            AccountId::new(0, 0, 3),
+        )]))
+        .unwrap();
+
+        let journal = InMemoryTransactionJournal::new();
+
+        let result = FileAppendTransaction::new()
+            .contents(vec![0; 5000])
+            .chunk_size(1024)
+            .execute_once(&client, &journal, "a")
+            .await;
+
+        assert!(matches!(result, Err(Error::TransactionJournalChunked(5))));
+        assert!(journal.get("a").unwrap().is_none());
+    }
+}
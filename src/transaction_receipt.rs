@@ -25,28 +25,36 @@ use crate::{
 /// Response from [`TransactionReceiptQuery`][crate::TransactionReceiptQuery].
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TransactionReceipt {
     // fixme(sr): better doc comment.
     /// The ID of the transaction that this is a receipt for.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub transaction_id: Option<TransactionId>,
 
     /// The consensus status of the transaction; is UNKNOWN if consensus has not been reached, or if
     /// the associated transaction did not have a valid payer signature.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::status"))]
     pub status: Status,
 
     /// In the receipt for an `AccountCreateTransaction`, the id of the newly created account.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub account_id: Option<AccountId>,
 
     /// In the receipt for a `FileCreateTransaction`, the id of the newly created file.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub file_id: Option<FileId>,
 
     /// In the receipt for a `ContractCreateTransaction`, the id of the newly created contract.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub contract_id: Option<ContractId>,
 
     /// The exchange rates in effect when the transaction reached consensus.
     pub exchange_rates: Option<ExchangeRates>,
 
     /// In the receipt for a `TopicCreateTransaction`, the id of the newly created topic.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub topic_id: Option<TopicId>,
 
     /// In the receipt for a `TopicMessageSubmitTransaction`, the new sequence number of the topic
@@ -56,6 +64,7 @@ pub struct TransactionReceipt {
     // TODO: use a hash type (for display/debug/serialize purposes)
     /// In the receipt for a `TopicMessageSubmitTransaction`, the new running hash of the
     /// topic that received the message.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_hex"))]
     pub topic_running_hash: Option<Vec<u8>>,
 
     /// In the receipt of a `TopicMessageSubmitTransaction`, the version of the SHA-384
@@ -63,6 +72,7 @@ pub struct TransactionReceipt {
     pub topic_running_hash_version: u64,
 
     /// In the receipt for a `TokenCreateTransaction`, the id of the newly created token.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub token_id: Option<TokenId>,
 
     /// Populated in the receipt of `TokenMint`, `TokenWipe`, and `TokenBurn` transactions.
@@ -72,22 +82,27 @@ pub struct TransactionReceipt {
     pub total_supply: u64,
 
     /// In the receipt for a `ScheduleCreateTransaction`, the id of the newly created schedule.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub schedule_id: Option<ScheduleId>,
 
     /// In the receipt of a `ScheduleCreateTransaction` or `ScheduleSignTransaction` that resolves
     /// to `Success`, the `TransactionId` that should be used to query for the receipt or
     /// record of the relevant scheduled transaction.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub scheduled_transaction_id: Option<TransactionId>,
 
     /// In the receipt of a `TokenMintTransaction` for tokens of type `NonFungibleUnique`,
     /// the serial numbers of the newly created NFTs.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub serials: Vec<i64>,
 
     /// The receipts of processing all transactions with the given id, in consensus time order.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub duplicates: Vec<TransactionReceipt>,
 
     /// The receipts (if any) of all child transactions spawned by the transaction with the
     /// given top-level id, in consensus order.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub children: Vec<TransactionReceipt>,
 
     /// In the receipt of a NodeCreate, NodeUpdate, NodeDelete, the id of the newly created node.
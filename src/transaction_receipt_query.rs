@@ -14,6 +14,7 @@ use crate::query::{
 use crate::{
     BoxGrpcFuture,
     Error,
+    FromProtobuf,
     Query,
     Status,
     ToProtobuf,
@@ -113,6 +114,17 @@ impl ToQueryProtobuf for TransactionReceiptQueryData {
     }
 }
 
+impl FromProtobuf<services::TransactionGetReceiptQuery> for TransactionReceiptQueryData {
+    fn from_protobuf(pb: services::TransactionGetReceiptQuery) -> crate::Result<Self> {
+        Ok(Self {
+            transaction_id: Option::from_protobuf(pb.transaction_id)?,
+            include_children: pb.include_child_receipts,
+            include_duplicates: pb.include_duplicates,
+            validate_status: false,
+        })
+    }
+}
+
 impl QueryExecute for TransactionReceiptQueryData {
     type Response = TransactionReceipt;
 
@@ -5,7 +5,15 @@ use std::collections::HashMap;
 use hedera_proto::services;
 use time::OffsetDateTime;
 
+#[cfg(test)]
+pub(super) use tests::make_record;
+
 use crate::protobuf::ToProtobuf;
+#[cfg(feature = "serde")]
+use crate::transaction_record_export::{
+    serde_nft_transfers,
+    serde_token_transfers,
+};
 use crate::{
     AccountId,
     AssessedCustomFee,
@@ -28,6 +36,8 @@ use crate::{
 /// The complete record for a transaction on Hiero that has reached consensus.
 /// Response from [`TransactionRecordQuery`][crate::TransactionRecordQuery].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TransactionRecord {
     /// The status (reach consensus, or failed, or is unknown) and the ID of
     /// any new account/file/instance created.
@@ -35,9 +45,11 @@ pub struct TransactionRecord {
 
     /// The hash of the Transaction that executed (not the hash of any Transaction that failed for
     /// having a duplicate TransactionID).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::hex"))]
     pub transaction_hash: Vec<u8>,
 
     /// The consensus timestamp.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::timestamp"))]
     pub consensus_timestamp: OffsetDateTime,
 
     /// Record of the value returned by the smart contract function or constructor.
@@ -46,54 +58,71 @@ pub struct TransactionRecord {
     /// All hbar transfers as a result of this transaction, such as fees, or
     /// transfers performed by the transaction, or by a smart contract it calls,
     /// or by the creation of threshold records that it triggers.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub transfers: Vec<Transfer>,
 
     /// All fungible token transfers as a result of this transaction.
+    #[cfg_attr(feature = "serde", serde(default, with = "serde_token_transfers"))]
     pub token_transfers: HashMap<TokenId, HashMap<AccountId, i64>>,
 
     /// All NFT Token transfers as a result of this transaction.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, rename = "nftTransfers", with = "serde_nft_transfers")
+    )]
     pub token_nft_transfers: HashMap<TokenId, Vec<TokenNftTransfer>>,
 
     /// The ID of the transaction this record represents.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::display"))]
     pub transaction_id: TransactionId,
 
     /// The memo that was submitted as part of the transaction.
     pub transaction_memo: String,
 
     /// The actual transaction fee charged.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::tinybars"))]
     pub transaction_fee: Hbar,
 
     /// Reference to the scheduled transaction ID that this transaction record represents.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub schedule_ref: Option<ScheduleId>,
 
     /// All custom fees that were assessed during a [`TransferTransaction`](crate::TransferTransaction), and must be paid if the
     /// transaction status resolved to SUCCESS.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub assessed_custom_fees: Vec<AssessedCustomFee>,
 
     /// All token associations implicitly created while handling this transaction
+    #[cfg_attr(feature = "serde", serde(default))]
     pub automatic_token_associations: Vec<TokenAssociation>,
 
     /// In the record of an internal transaction, the consensus timestamp of the user
     /// transaction that spawned it.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_timestamp"))]
     pub parent_consensus_timestamp: Option<OffsetDateTime>,
 
     /// In the record of an internal CryptoCreate transaction triggered by a user
     /// transaction with a (previously unused) alias, the new account's alias.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub alias_key: Option<PublicKey>,
 
     /// The records of processing all child transaction spawned by the transaction with the given
     /// top-level id, in consensus order. Always empty if the top-level status is UNKNOWN.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub children: Vec<Self>,
 
     /// The records of processing all consensus transaction with the same id as the distinguished
     /// record above, in chronological order.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub duplicates: Vec<Self>,
 
     /// The keccak256 hash of the ethereumData. This field will only be populated for
     /// `EthereumTransaction`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::hex"))]
     pub ethereum_hash: Vec<u8>,
 
     /// In the record of a PRNG transaction with no output range, a pseudorandom 384-bit string.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_hex"))]
     pub prng_bytes: Option<Vec<u8>>,
 
     /// In the record of a PRNG transaction with an output range, the output of a PRNG
@@ -101,12 +130,19 @@ pub struct TransactionRecord {
     pub prng_number: Option<u32>,
 
     /// The last 20 bytes of the keccak-256 hash of a ECDSA_SECP256K1 primitive key.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_with::option_display"))]
     pub evm_address: Option<EvmAddress>,
 
     /// A list of pending token airdrops.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub pending_airdrop_records: Vec<PendingAirdropRecord>,
+
+    /// The staking rewards paid to accounts as a result of this transaction.
+    ///
+    /// Each reward is also in [`transfers`](Self::transfers), as a credit to the account it was paid to.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub paid_staking_rewards: Vec<Transfer>,
 }
-// TODO: paid_staking_rewards
 
 impl TransactionRecord {
     /// Create a new `TransactionRecord` from protobuf-encoded `bytes`.
@@ -136,7 +172,7 @@ impl TransactionRecord {
         let consensus_timestamp = pb_getf!(record, consensus_timestamp)?;
         let transaction_id = pb_getf!(record, transaction_id)?;
         let schedule_ref = Option::from_protobuf(record.schedule_ref)?;
-        let parent_consensus_timestamp = record.parent_consensus_timestamp.map(Into::into);
+        let parent_consensus_timestamp = Option::from_protobuf(record.parent_consensus_timestamp)?;
 
         let alias_key = PublicKey::from_alias_bytes(&record.alias)?;
 
@@ -201,7 +237,7 @@ impl TransactionRecord {
         Ok(Self {
             receipt,
             transaction_hash: record.transaction_hash,
-            consensus_timestamp: consensus_timestamp.into(),
+            consensus_timestamp: FromProtobuf::from_protobuf(consensus_timestamp)?,
             contract_function_result,
             transaction_id: TransactionId::from_protobuf(transaction_id)?,
             transaction_memo: record.memo,
@@ -221,6 +257,7 @@ impl TransactionRecord {
             prng_bytes,
             prng_number,
             pending_airdrop_records,
+            paid_staking_rewards: Vec::from_protobuf(record.paid_staking_rewards)?,
         })
     }
 }
@@ -298,8 +335,11 @@ impl ToProtobuf for TransactionRecord {
             parent_consensus_timestamp: self.parent_consensus_timestamp.to_protobuf(),
             alias: self.alias_key.as_ref().map(ToProtobuf::to_bytes).unwrap_or_default(),
             ethereum_hash: self.ethereum_hash.clone(),
-            // TODO:
-            paid_staking_rewards: Vec::new(),
+            paid_staking_rewards: self
+                .paid_staking_rewards
+                .iter()
+                .map(|it| it.to_protobuf())
+                .collect(),
             evm_address: self
                 .evm_address
                 .as_ref()
@@ -342,7 +382,11 @@ mod tests {
         Transfer,
     };
 
-    fn make_record(prng_bytes: Option<Vec<u8>>, prng_number: Option<u32>) -> TransactionRecord {
+    // needed in `transaction_record_export`.
+    pub(crate) fn make_record(
+        prng_bytes: Option<Vec<u8>>,
+        prng_number: Option<u32>,
+    ) -> TransactionRecord {
         TransactionRecord {
             receipt: crate::transaction_receipt::make_receipt(),
             transaction_hash: b"hello".to_vec(),
@@ -417,6 +461,7 @@ mod tests {
                 ),
                 pending_airdrop_value: Some(2),
             }],
+            paid_staking_rewards: Vec::new(),
         }
     }
 
@@ -0,0 +1,433 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::io;
+
+use crate::protobuf::time::format_mirror_timestamp;
+#[cfg(feature = "serde")]
+use crate::Error;
+use crate::{
+    AccountId,
+    TokenId,
+    TokenNftTransfer,
+    TransactionReceipt,
+    TransactionRecord,
+    Transfer,
+};
+
+/// The columns written by [`TransactionRecordCsvWriter`], in order.
+const CSV_HEADER: [&str; 11] = [
+    "consensus_timestamp",
+    "transaction_type",
+    "transaction_id",
+    "payer_account_id",
+    "status",
+    "transaction_fee",
+    "hbar_transfers",
+    "token_transfers",
+    "nft_transfers",
+    "paid_staking_rewards",
+    "memo",
+];
+
+/// Writes [`TransactionRecord`]s as CSV, one row per record, for accounting exports.
+///
+/// The columns are:
+/// - `consensus_timestamp`: `<seconds>.<nanoseconds>` since the unix epoch.
+/// - `transaction_type`: the kind of transaction, if given with [`write_record_with_type`](Self::write_record_with_type).
+/// - `transaction_id` and `payer_account_id`.
+/// - `status`: the receipt status, IE `SUCCESS`.
+/// - `transaction_fee`: in tinybars.
+/// - `hbar_transfers`: `account:tinybars` pairs, separated by `;`.
+/// - `token_transfers`: `token/account:amount` triples, separated by `;`.
+/// - `nft_transfers`: `token/serial:sender>receiver`, separated by `;`.
+/// - `paid_staking_rewards`: `account:tinybars` pairs, separated by `;`.
+/// - `memo`.
+///
+/// Transfers are sorted, so the same record always produces the same row.
+///
+/// Cells that start with `=`, `+`, `-`, `@`, a tab, or a carriage return are prefixed with `'`,
+/// so spreadsheet applications don't evaluate user controlled values (IE, the memo) as formulas.
+#[derive(Debug)]
+pub struct TransactionRecordCsvWriter<W: io::Write> {
+    writer: W,
+    wrote_header: bool,
+}
+
+impl<W: io::Write> TransactionRecordCsvWriter<W> {
+    /// Create a new writer that writes CSV to `writer`.
+    ///
+    /// The header row is written along with the first record.
+    pub fn new(writer: W) -> Self {
+        Self { writer, wrote_header: false }
+    }
+
+    /// Write `record` as a row, with an empty `transaction_type`.
+    ///
+    /// # Errors
+    /// - If writing to the underlying writer fails.
+    pub fn write_record(&mut self, record: &TransactionRecord) -> io::Result<()> {
+        self.write_row(record, "")
+    }
+
+    /// Write `record` as a row, with the given `transaction_type`, IE `"TransferTransaction"`.
+    ///
+    /// Records don't include the kind of transaction they're for,
+    /// but [`Transaction::describe`](crate::Transaction::describe) does.
+    ///
+    /// # Errors
+    /// - If writing to the underlying writer fails.
+    pub fn write_record_with_type(
+        &mut self,
+        record: &TransactionRecord,
+        transaction_type: &str,
+    ) -> io::Result<()> {
+        self.write_row(record, transaction_type)
+    }
+
+    /// Flush and return the underlying writer.
+    ///
+    /// # Errors
+    /// - If flushing the underlying writer fails.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    fn write_row(&mut self, record: &TransactionRecord, transaction_type: &str) -> io::Result<()> {
+        if !self.wrote_header {
+            writeln!(self.writer, "{}", CSV_HEADER.join(","))?;
+            self.wrote_header = true;
+        }
+
+        let fields = [
+            format_mirror_timestamp(record.consensus_timestamp),
+            transaction_type.to_owned(),
+            record.transaction_id.to_string(),
+            record.transaction_id.account_id.to_string(),
+            record.receipt.status.as_str_name().to_owned(),
+            record.transaction_fee.to_tinybars().to_string(),
+            join_transfers(sorted_hbar_transfers(&record.transfers), |it| {
+                format!("{}:{}", it.account_id, it.amount.to_tinybars())
+            }),
+            join_transfers(
+                sorted_token_transfers(&record.token_transfers),
+                |(token_id, account_id, amount)| format!("{token_id}/{account_id}:{amount}"),
+            ),
+            join_transfers(sorted_nft_transfers(&record.token_nft_transfers), |it| {
+                format!("{}/{}:{}>{}", it.token_id, it.serial, it.sender, it.receiver)
+            }),
+            join_transfers(sorted_hbar_transfers(&record.paid_staking_rewards), |it| {
+                format!("{}:{}", it.account_id, it.amount.to_tinybars())
+            }),
+            record.transaction_memo.clone(),
+        ];
+
+        let row: Vec<_> = fields.iter().map(|it| escape_csv(it)).collect();
+
+        writeln!(self.writer, "{}", row.join(","))
+    }
+}
+
+fn account_key(account_id: &AccountId) -> (u64, u64, u64) {
+    (account_id.shard, account_id.realm, account_id.num)
+}
+
+fn token_key(token_id: &TokenId) -> (u64, u64, u64) {
+    (token_id.shard, token_id.realm, token_id.num)
+}
+
+/// Returns `transfers`, sorted by account.
+fn sorted_hbar_transfers(transfers: &[Transfer]) -> Vec<&Transfer> {
+    let mut transfers: Vec<_> = transfers.iter().collect();
+    transfers.sort_by_key(|it| (account_key(&it.account_id), it.amount.to_tinybars()));
+
+    transfers
+}
+
+/// Returns `transfers` as `(token, account, amount)`, sorted by token, then account.
+fn sorted_token_transfers(
+    transfers: &HashMap<TokenId, HashMap<AccountId, i64>>,
+) -> Vec<(TokenId, AccountId, i64)> {
+    let mut transfers: Vec<_> = transfers
+        .iter()
+        .flat_map(|(token_id, transfers)| {
+            transfers.iter().map(move |(account_id, amount)| (*token_id, *account_id, *amount))
+        })
+        .collect();
+    transfers
+        .sort_by_key(|(token_id, account_id, _)| (token_key(token_id), account_key(account_id)));
+
+    transfers
+}
+
+/// Returns the NFT transfers in `transfers`, sorted by token, then serial.
+fn sorted_nft_transfers(
+    transfers: &HashMap<TokenId, Vec<TokenNftTransfer>>,
+) -> Vec<&TokenNftTransfer> {
+    let mut transfers: Vec<_> = transfers.values().flatten().collect();
+    transfers.sort_by_key(|it| (token_key(&it.token_id), it.serial));
+
+    transfers
+}
+
+/// Format each transfer with `f`, separated by `;`.
+fn join_transfers<T>(transfers: Vec<T>, f: impl FnMut(T) -> String) -> String {
+    transfers.into_iter().map(f).collect::<Vec<_>>().join(";")
+}
+
+/// Neutralize `field` if it looks like a formula, then quote it if it contains a delimiter, quote, or newline.
+fn escape_csv(field: &str) -> String {
+    let field = if field.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        Cow::Owned(format!("'{field}"))
+    } else {
+        Cow::Borrowed(field)
+    };
+
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.into_owned()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TransactionReceipt {
+    /// Returns this receipt as JSON.
+    ///
+    /// This is the same as the receipt's [`Serialize`](serde::Serialize) impl:
+    /// keys are camelCase, entity IDs and transaction IDs are strings,
+    /// and the status is its protobuf name, IE `"SUCCESS"`.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("serializing a `TransactionReceipt` can't fail")
+    }
+
+    /// Parse a receipt from JSON in the format written by [`to_json`](Self::to_json).
+    ///
+    /// Missing arrays are treated as empty.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `value` isn't in that format.
+    pub fn from_json(value: &serde_json::Value) -> crate::Result<Self> {
+        <Self as serde::Deserialize>::deserialize(value).map_err(Error::basic_parse)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TransactionRecord {
+    /// Returns this record as JSON.
+    ///
+    /// This is the same as the record's [`Serialize`](serde::Serialize) impl:
+    /// keys are camelCase, entity IDs, transaction IDs and keys are strings, amounts are integers
+    /// (tinybars for hbar), timestamps are `<seconds>.<nanoseconds>` strings, and bytes are hex.
+    /// Token and NFT transfers are sorted, so the same record always produces the same JSON.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("serializing a `TransactionRecord` can't fail")
+    }
+
+    /// Parse a record from JSON in the format written by [`to_json`](Self::to_json).
+    ///
+    /// Missing arrays are treated as empty.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `value` isn't in that format.
+    pub fn from_json(value: &serde_json::Value) -> crate::Result<Self> {
+        <Self as serde::Deserialize>::deserialize(value).map_err(Error::basic_parse)
+    }
+}
+
+/// (De)serializes [`TransactionRecord::token_transfers`] as a list of `{ tokenId, accountId, amount }`,
+/// sorted by token, then account.
+#[cfg(feature = "serde")]
+pub(crate) mod serde_token_transfers {
+    use std::collections::HashMap;
+
+    use crate::{
+        AccountId,
+        TokenId,
+    };
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct TokenTransfer {
+        #[serde(with = "crate::serde_with::display")]
+        token_id: TokenId,
+
+        #[serde(with = "crate::serde_with::display")]
+        account_id: AccountId,
+
+        amount: i64,
+    }
+
+    pub(crate) fn serialize<S: serde::Serializer>(
+        transfers: &HashMap<TokenId, HashMap<AccountId, i64>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            super::sorted_token_transfers(transfers).into_iter().map(
+                |(token_id, account_id, amount)| TokenTransfer { token_id, account_id, amount },
+            ),
+        )
+    }
+
+    pub(crate) fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<TokenId, HashMap<AccountId, i64>>, D::Error> {
+        let mut transfers: HashMap<TokenId, HashMap<AccountId, i64>> = HashMap::new();
+
+        for it in <Vec<TokenTransfer> as serde::Deserialize>::deserialize(deserializer)? {
+            transfers.entry(it.token_id).or_default().insert(it.account_id, it.amount);
+        }
+
+        Ok(transfers)
+    }
+}
+
+/// (De)serializes [`TransactionRecord::token_nft_transfers`] as a list of [`TokenNftTransfer`]s,
+/// sorted by token, then serial.
+#[cfg(feature = "serde")]
+pub(crate) mod serde_nft_transfers {
+    use std::collections::HashMap;
+
+    use crate::{
+        TokenId,
+        TokenNftTransfer,
+    };
+
+    pub(crate) fn serialize<S: serde::Serializer>(
+        transfers: &HashMap<TokenId, Vec<TokenNftTransfer>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(super::sorted_nft_transfers(transfers))
+    }
+
+    pub(crate) fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<TokenId, Vec<TokenNftTransfer>>, D::Error> {
+        let mut transfers: HashMap<TokenId, Vec<TokenNftTransfer>> = HashMap::new();
+
+        for it in <Vec<TokenNftTransfer> as serde::Deserialize>::deserialize(deserializer)? {
+            transfers.entry(it.token_id).or_default().push(it);
+        }
+
+        Ok(transfers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TransactionRecordCsvWriter;
+    use crate::transaction_record::make_record;
+    use crate::{
+        AccountId,
+        Hbar,
+        Transfer,
+    };
+
+    #[test]
+    fn csv() {
+        let mut record = make_record(None, None);
+        let mut writer = TransactionRecordCsvWriter::new(Vec::new());
+
+        writer.write_record_with_type(&record, "TransferTransaction").unwrap();
+
+        record.transaction_memo = "a \"quoted\", memo".to_owned();
+        record.paid_staking_rewards = Vec::from([Transfer {
+            account_id: AccountId::new(0, 0, 800),
+            amount: Hbar::from_tinybars(-250),
+        }]);
+        writer.write_record(&record).unwrap();
+
+        let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+
+        assert_eq!(
+            csv,
+            "consensus_timestamp,transaction_type,transaction_id,payer_account_id,status,\
+             transaction_fee,hbar_transfers,token_transfers,nft_transfers,paid_staking_rewards,memo\n\
+             1554158542.000000000,TransferTransaction,0.0.5006@1554158542.0,0.0.5006,\
+             SCHEDULE_ALREADY_DELETED,3000,4.4.4:500000000,6.6.6/1.1.1:4,4.4.4/4:1.2.3>3.2.1,,memo\n\
+             1554158542.000000000,,0.0.5006@1554158542.0,0.0.5006,\
+             SCHEDULE_ALREADY_DELETED,3000,4.4.4:500000000,6.6.6/1.1.1:4,4.4.4/4:1.2.3>3.2.1,\
+             0.0.800:-250,\"a \"\"quoted\"\", memo\"\n"
+        );
+    }
+
+    #[test]
+    fn csv_formula() {
+        let mut writer = TransactionRecordCsvWriter::new(Vec::new());
+
+        for memo in ["=1+2", "+1", "-1", "@SUM(A1)", "=HYPERLINK(\"x\",\"y\")"] {
+            let mut record = make_record(None, None);
+            record.transaction_memo = memo.to_owned();
+            writer.write_record(&record).unwrap();
+        }
+
+        let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+        let memos: Vec<_> = csv.lines().skip(1).map(|it| it.rsplit_once(",,").unwrap().1).collect();
+
+        assert_eq!(
+            memos,
+            ["'=1+2", "'+1", "'-1", "'@SUM(A1)", "\"'=HYPERLINK(\"\"x\"\",\"\"y\"\")\""]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json() {
+        let record = make_record(Some(vec![1, 2]), None);
+
+        let json = serde_json::to_value(&record).unwrap();
+
+        assert_eq!(json, record.to_json());
+        assert_eq!(json["consensusTimestamp"], "1554158542.000000000");
+        assert_eq!(json["transactionFee"], 3000);
+        assert_eq!(json["prngBytes"], "0102");
+        assert_eq!(json["receipt"]["status"], "SCHEDULE_ALREADY_DELETED");
+        assert_eq!(json["receipt"]["serials"], serde_json::json!([1, 2, 3]));
+        assert_eq!(json["receipt"]["exchangeRates"]["nextRate"]["hbars"], 200);
+        assert_eq!(
+            json["tokenTransfers"],
+            serde_json::json!([{ "tokenId": "6.6.6", "accountId": "1.1.1", "amount": 4 }])
+        );
+        assert_eq!(json["contractFunctionResult"]["contractId"], "1.2.3");
+        assert_eq!(
+            json["assessedCustomFees"][0]["payerAccountIdList"],
+            serde_json::json!(["3.3.3"])
+        );
+        assert_eq!(json["automaticTokenAssociations"][0]["tokenId"], "5.4.3");
+        assert_eq!(json["pendingAirdropRecords"][0]["pendingAirdropValue"], 2);
+        assert_eq!(json["paidStakingRewards"], serde_json::json!([]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_round_trip() {
+        let mut record = make_record(None, Some(4));
+        record.paid_staking_rewards = Vec::from([Transfer {
+            account_id: AccountId::new(0, 0, 800),
+            amount: Hbar::from_tinybars(-250),
+        }]);
+        record.children = Vec::from([make_record(Some(vec![1, 2]), None)]);
+
+        let json = serde_json::to_string(&record).unwrap();
+        let parsed: crate::TransactionRecord = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.to_json(), record.to_json());
+        assert_eq!(parsed.paid_staking_rewards.len(), 1);
+        assert_eq!(parsed.token_nft_transfers.len(), 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_invalid() {
+        let mut json = make_record(None, None).to_json();
+        json["receipt"]["status"] = "NOT_A_STATUS".into();
+
+        let error = crate::TransactionRecord::from_json(&json).unwrap_err();
+
+        assert!(matches!(error, crate::Error::BasicParse(_)), "{error:?}");
+    }
+}
@@ -111,6 +111,17 @@ impl ToQueryProtobuf for TransactionRecordQueryData {
     }
 }
 
+impl FromProtobuf<services::TransactionGetRecordQuery> for TransactionRecordQueryData {
+    fn from_protobuf(pb: services::TransactionGetRecordQuery) -> crate::Result<Self> {
+        Ok(Self {
+            transaction_id: Option::from_protobuf(pb.transaction_id)?,
+            include_children: pb.include_child_records,
+            include_duplicates: pb.include_duplicates,
+            validate_status: false,
+        })
+    }
+}
+
 impl QueryExecute for TransactionRecordQueryData {
     type Response = TransactionRecord;
 
@@ -1,7 +1,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "mirror-rest")]
+use crate::mirror_rest::format_transaction_id;
+#[cfg(feature = "mirror-rest")]
+use crate::reconcile::{
+    RestTransaction,
+    RestTransactions,
+};
+#[cfg(feature = "mirror-rest")]
+use crate::Status;
 use crate::{
     AccountId,
+    CancellationToken,
     Client,
     TransactionHash,
     TransactionId,
@@ -21,21 +31,27 @@ use crate::{
 /// receipt (free), or can buy a more detailed record (not free).
 ///
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TransactionResponse {
     /// The account ID of the node that the transaction was submitted to.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::display"))]
     pub node_account_id: AccountId,
 
     /// The client-generated transaction ID of the transaction that was submitted.
     ///
     /// This can be used to lookup the transaction in an explorer.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::display"))]
     pub transaction_id: TransactionId,
 
     /// The client-generated SHA-384 hash of the transaction that was submitted.
     ///
     /// This can be used to lookup the transaction in an explorer.
+    #[cfg_attr(feature = "serde", serde(with = "serde_transaction_hash"))]
     pub transaction_hash: TransactionHash,
 
     /// Whether the receipt/record status should be validated.
+    #[cfg_attr(feature = "serde", serde(default = "default_validate_status"))]
     pub validate_status: bool,
 }
 
@@ -70,7 +86,7 @@ impl TransactionResponse {
     /// Will wait for consensus.
     ///
     /// # Errors
-    /// - if [`validate_status`](Self.validate_status) is `true`:
+    /// - if [`validate_status`](Self::validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt.
     ///
     /// fixme: is that it? Surely there are more situations.
@@ -82,7 +98,7 @@ impl TransactionResponse {
     /// Will wait for consensus.
     ///
     /// # Errors
-    /// - if [`validate_status`](Self.validate_status) is `true`:
+    /// - if [`validate_status`](Self::validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt.
     pub async fn get_receipt_with_timeout(
         &self,
@@ -96,7 +112,7 @@ impl TransactionResponse {
     /// Will wait for consensus.
     ///
     /// # Errors
-    /// - if [`validate_status`](Self.validate_status) is `true`:
+    /// - if [`validate_status`](Self::validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
     pub async fn get_record(&self, client: &Client) -> crate::Result<TransactionRecord> {
         self.get_record_query().execute(client).await
@@ -106,7 +122,7 @@ impl TransactionResponse {
     /// Will wait for consensus.
     ///
     /// # Errors
-    /// - if [`validate_status`](Self.validate_status) is `true`:
+    /// - if [`validate_status`](Self::validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
     pub async fn get_record_with_timeout(
         &self,
@@ -115,4 +131,322 @@ impl TransactionResponse {
     ) -> crate::Result<TransactionRecord> {
         self.get_record_query().execute_with_timeout(client, timeout).await
     }
+
+    /// Get the receipt for this transaction.
+    /// Will wait for consensus, unless `cancellation` is cancelled first.
+    ///
+    /// # Errors
+    /// - [`Error::Cancelled`](crate::Error::Cancelled) if `cancellation` is cancelled before the receipt is available.
+    /// - if [`validate_status`](Self::validate_status) is `true`:
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt.
+    pub async fn get_receipt_with_cancellation(
+        &self,
+        client: &Client,
+        cancellation: &CancellationToken,
+    ) -> crate::Result<TransactionReceipt> {
+        self.get_receipt_query().execute_with_cancellation(client, cancellation).await
+    }
+
+    /// Get the record for this transaction.
+    /// Will wait for consensus, unless `cancellation` is cancelled first.
+    ///
+    /// # Errors
+    /// - [`Error::Cancelled`](crate::Error::Cancelled) if `cancellation` is cancelled before the record is available.
+    /// - if [`validate_status`](Self::validate_status) is `true`:
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
+    pub async fn get_record_with_cancellation(
+        &self,
+        client: &Client,
+        cancellation: &CancellationToken,
+    ) -> crate::Result<TransactionRecord> {
+        self.get_record_query().execute_with_cancellation(client, cancellation).await
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TransactionResponse {
+    /// Returns this response as JSON, so that it can be saved and reloaded with [`from_json`](Self::from_json),
+    /// IE, to get the receipt after a crash without submitting the transaction again.
+    ///
+    /// Keys are camelCase, the node account ID and transaction ID are strings, and the hash is hex.
+    /// This is the same as the response's [`Serialize`](serde::Serialize) impl.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("serializing a `TransactionResponse` can't fail")
+    }
+
+    /// Parse a response from the JSON made by [`to_json`](Self::to_json).
+    ///
+    /// `validateStatus` is optional, and defaults to `true`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if `json` isn't a valid response.
+    pub fn from_json(json: &serde_json::Value) -> crate::Result<Self> {
+        <Self as serde::Deserialize>::deserialize(json).map_err(crate::Error::basic_parse)
+    }
+}
+
+#[cfg(feature = "serde")]
+fn default_validate_status() -> bool {
+    true
+}
+
+/// (De)serializes a [`TransactionHash`] as hex.
+#[cfg(feature = "serde")]
+mod serde_transaction_hash {
+    use crate::TransactionHash;
+
+    pub(super) fn serialize<S: serde::Serializer>(
+        hash: &TransactionHash,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(hash)
+    }
+
+    pub(super) fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<TransactionHash, D::Error> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+
+        hex::decode(&value)
+            .ok()
+            .and_then(|it| <[u8; 48]>::try_from(it).ok())
+            .map(TransactionHash)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid transaction hash `{value}`")))
+    }
+}
+
+#[cfg(feature = "mirror-rest")]
+impl TransactionResponse {
+    /// Get the status of this transaction, from the mirror node if it has the transaction,
+    /// or from the receipt otherwise (waiting for consensus).
+    ///
+    /// Nodes only keep receipts for a few minutes after consensus, so a response that's reloaded later
+    /// (IE, with [`from_json`](Self::from_json) after a crash) usually can't get its receipt anymore,
+    /// but the mirror node keeps the outcome of every transaction.
+    /// If neither has the transaction, it never reached consensus (or hasn't reached the mirror node yet).
+    ///
+    /// # Errors
+    /// - if [`validate_status`](Self::validate_status) is `true`:
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing status.
+    /// - [`Error::TransactionOutcomeUnknown`](crate::Error::TransactionOutcomeUnknown)
+    ///   if neither the network nor the mirror node has the transaction.
+    /// - [`Error::MirrorRest`](crate::Error::MirrorRest) if the mirror node request fails.
+    /// - Any error [`get_receipt`](Self::get_receipt) can return, other than a missing receipt.
+    pub async fn get_status(&self, client: &Client) -> crate::Result<Status> {
+        let rest = client.rest_client();
+
+        let path = format!("/transactions/{}", format_transaction_id(&self.transaction_id));
+
+        let status = match rest.get_optional::<RestTransactions>(&path).await? {
+            Some(page) => self.mirror_status(page)?,
+            None => None,
+        };
+
+        let status = match status {
+            Some(status) => status,
+            None => self.receipt_status(client).await?,
+        };
+
+        if self.validate_status && status != Status::Success {
+            return Err(crate::Error::ReceiptStatus {
+                status,
+                transaction_id: Some(Box::new(self.transaction_id)),
+            });
+        }
+
+        Ok(status)
+    }
+
+    /// Returns the status of this transaction in `page`, if it's there.
+    ///
+    /// If the transaction reached consensus more than once (IE, a duplicate), the first one counts.
+    fn mirror_status(&self, page: RestTransactions) -> crate::Result<Option<Status>> {
+        let outcomes = page
+            .transactions
+            .into_iter()
+            .map(RestTransaction::into_outcome)
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        let report = crate::reconcile::reconcile([self.transaction_id], outcomes);
+
+        Ok(report.reached_consensus.first().map(|it| it.status))
+    }
+
+    async fn receipt_status(&self, client: &Client) -> crate::Result<Status> {
+        let mut query = self.get_receipt_query();
+        query.validate_status(false);
+
+        match query.execute(client).await {
+            Ok(receipt) => Ok(receipt.status),
+            Err(e) if is_not_found(&e) => {
+                Err(crate::Error::TransactionOutcomeUnknown(Box::new(self.transaction_id)))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get the receipt for this transaction from the network, or from the mirror node if the network doesn't have it.
+    /// Will wait for consensus.
+    ///
+    /// Nodes only keep receipts for a few minutes after consensus, so this is how to get the receipt of
+    /// a response that's reloaded later (IE, with [`from_json`](Self::from_json) after a crash).
+    /// A receipt from the mirror node only has the status, the transaction ID,
+    /// and the ID of the account, file, contract, topic, token, or schedule the transaction created.
+    ///
+    /// # Errors
+    /// - if [`validate_status`](Self::validate_status) is `true`:
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt.
+    /// - [`Error::TransactionOutcomeUnknown`](crate::Error::TransactionOutcomeUnknown)
+    ///   if neither the network nor the mirror node has the transaction.
+    /// - [`Error::MirrorRest`](crate::Error::MirrorRest) if the mirror node request fails.
+    /// - Any error [`get_receipt`](Self::get_receipt) can return, other than a missing receipt.
+    pub async fn get_receipt_with_mirror_fallback(
+        &self,
+        client: &Client,
+    ) -> crate::Result<TransactionReceipt> {
+        let mut query = self.get_receipt_query();
+        query.validate_status(false);
+
+        let receipt = match query.execute(client).await {
+            Ok(receipt) => receipt,
+            Err(e) if is_not_found(&e) => self.mirror_record(client).await?.receipt,
+            Err(e) => return Err(e),
+        };
+
+        receipt.validate_status(self.validate_status)?;
+
+        Ok(receipt)
+    }
+
+    /// Get the record for this transaction from the network, or from the mirror node if the network doesn't have it.
+    /// Will wait for consensus.
+    ///
+    /// Like [`get_receipt_with_mirror_fallback`](Self::get_receipt_with_mirror_fallback), but for the record.
+    /// A record from the mirror node has the transfers, fee, memo, hash, and staking rewards of the transaction,
+    /// but no contract result, assessed custom fees, or child records.
+    ///
+    /// # Errors
+    /// - if [`validate_status`](Self::validate_status) is `true`:
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
+    /// - [`Error::TransactionOutcomeUnknown`](crate::Error::TransactionOutcomeUnknown)
+    ///   if neither the network nor the mirror node has the transaction.
+    /// - [`Error::MirrorRest`](crate::Error::MirrorRest) if the mirror node request fails.
+    /// - Any error [`get_record`](Self::get_record) can return, other than a missing record.
+    pub async fn get_record_with_mirror_fallback(
+        &self,
+        client: &Client,
+    ) -> crate::Result<TransactionRecord> {
+        let mut query = self.get_record_query();
+        query.validate_status(false);
+
+        let record = match query.execute(client).await {
+            Ok(record) => record,
+            Err(e) if is_not_found(&e) => self.mirror_record(client).await?,
+            Err(e) => return Err(e),
+        };
+
+        record.receipt.validate_status(self.validate_status)?;
+
+        Ok(record)
+    }
+
+    /// Returns the record of this transaction from the mirror node.
+    ///
+    /// If the transaction reached consensus more than once (IE, a duplicate), the first one counts.
+    async fn mirror_record(&self, client: &Client) -> crate::Result<TransactionRecord> {
+        let rest = client.rest_client();
+
+        let path = format!("/transactions/{}", format_transaction_id(&self.transaction_id));
+
+        let transactions = match rest.get_optional::<RestTransactions>(&path).await? {
+            Some(page) => page.transactions,
+            None => Vec::new(),
+        };
+
+        let records = transactions
+            .into_iter()
+            .map(RestTransaction::into_record)
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        records
+            .into_iter()
+            .filter(|it| it.transaction_id == self.transaction_id)
+            .min_by_key(|it| it.consensus_timestamp)
+            .ok_or_else(|| crate::Error::TransactionOutcomeUnknown(Box::new(self.transaction_id)))
+    }
+}
+
+/// Returns true if `error` means the network doesn't have the receipt or record of a transaction.
+#[cfg(feature = "mirror-rest")]
+fn is_not_found(error: &crate::Error) -> bool {
+    match error {
+        crate::Error::QueryPreCheckStatus {
+            status: Status::ReceiptNotFound | Status::RecordNotFound,
+            ..
+        } => true,
+        crate::Error::TimedOut(error) => is_not_found(error),
+        _ => false,
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::transaction::test_helpers::{
+        TEST_ACCOUNT_ID,
+        TEST_TX_ID,
+    };
+    use crate::{
+        TransactionHash,
+        TransactionResponse,
+    };
+
+    fn response() -> TransactionResponse {
+        TransactionResponse {
+            node_account_id: TEST_ACCOUNT_ID,
+            transaction_id: TEST_TX_ID,
+            transaction_hash: TransactionHash([0xab; 48]),
+            validate_status: false,
+        }
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let json = response().to_json();
+
+        assert_eq!(json["nodeAccountId"], "0.0.5006");
+        assert_eq!(json["transactionId"], "0.0.5006@1554158542.0");
+        assert_eq!(json["transactionHash"], "ab".repeat(48));
+
+        let parsed = TransactionResponse::from_json(&json).unwrap();
+
+        assert_eq!(parsed.node_account_id, TEST_ACCOUNT_ID);
+        assert_eq!(parsed.transaction_id, TEST_TX_ID);
+        assert_eq!(parsed.transaction_hash.0, [0xab; 48]);
+        assert!(!parsed.validate_status);
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let text = serde_json::to_string(&response()).unwrap();
+        let parsed: TransactionResponse = serde_json::from_str(&text).unwrap();
+
+        assert_eq!(parsed.transaction_id, TEST_TX_ID);
+    }
+
+    #[test]
+    fn from_json_defaults_validate_status() {
+        let mut json = response().to_json();
+        json.as_object_mut().unwrap().remove("validateStatus");
+
+        assert!(TransactionResponse::from_json(&json).unwrap().validate_status);
+    }
+
+    #[test]
+    fn from_json_invalid_hash() {
+        let mut json = response().to_json();
+        json["transactionHash"] = "abcd".into();
+
+        assert!(TransactionResponse::from_json(&json).is_err());
+    }
 }
@@ -12,14 +12,18 @@ use crate::{
 /// A transfer of [`Hbar`] that occured within a [`Transaction`](crate::Transaction)
 ///
 /// Returned as part of a [`TransactionRecord`](crate::TransactionRecord)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Transfer {
     /// The account ID that this transfer is to/from.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::display"))]
     pub account_id: AccountId,
 
     /// The value of this transfer.
     ///
     /// Negative if the account sends/withdraws hbar, positive if it receives hbar.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_with::tinybars"))]
     pub amount: Hbar,
 }
 
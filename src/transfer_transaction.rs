@@ -12,10 +12,14 @@ use crate::protobuf::FromProtobuf;
 use crate::transaction::{
     AnyTransactionData,
     ChunkInfo,
+    DescribedNftTransfer,
+    DescribedTransfer,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
     TransactionData,
+    TransactionDescription,
     TransactionExecute,
+    ValidationProblem,
 };
 use crate::{
     AccountId,
@@ -24,6 +28,7 @@ use crate::{
     Hbar,
     NftId,
     ToProtobuf,
+    TokenAmount,
     TokenId,
     TokenNftTransfer,
     Transaction,
@@ -45,8 +50,8 @@ pub type TransferTransaction = Transaction<TransferTransactionData>;
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(test, derive(Eq, PartialEq))]
 pub struct TransferTransactionData {
-    transfers: Vec<Transfer>,
-    token_transfers: Vec<TokenTransfer>,
+    pub(crate) transfers: Vec<Transfer>,
+    pub(crate) token_transfers: Vec<TokenTransfer>,
 }
 
 #[derive(Debug, Clone)]
@@ -74,6 +79,49 @@ pub(crate) struct TokenTransfer {
     pub expected_decimals: Option<u32>,
 }
 
+/// Report the tokens in `token_transfers` whose transfers don't sum to zero.
+pub(crate) fn check_token_transfers(
+    problems: &mut Vec<ValidationProblem>,
+    token_transfers: &[TokenTransfer],
+) {
+    for token_transfer in token_transfers {
+        let sum: i128 = token_transfer.transfers.iter().map(|it| i128::from(it.amount)).sum();
+
+        if sum != 0 {
+            problems.push(ValidationProblem::UnbalancedTransfers {
+                token_id: Some(token_transfer.token_id),
+                sum,
+            });
+        }
+    }
+}
+
+/// Add the fungible and NFT transfers in `token_transfers` to `description`.
+pub(crate) fn describe_token_transfers(
+    description: &mut TransactionDescription,
+    token_transfers: &[TokenTransfer],
+) {
+    for token_transfer in token_transfers {
+        for transfer in &token_transfer.transfers {
+            description.transfer(DescribedTransfer {
+                token_id: Some(token_transfer.token_id),
+                account_id: transfer.account_id,
+                amount: transfer.amount,
+                is_approval: transfer.is_approval,
+            });
+        }
+
+        for transfer in &token_transfer.nft_transfers {
+            description.nft_transfer(DescribedNftTransfer {
+                nft_id: NftId { token_id: transfer.token_id, serial: transfer.serial },
+                sender: transfer.sender,
+                receiver: transfer.receiver,
+                is_approval: transfer.is_approved,
+            });
+        }
+    }
+}
+
 impl TransferTransaction {
     fn _hbar_transfer(&mut self, account_id: AccountId, amount: Hbar, approved: bool) -> &mut Self {
         self.data_mut().transfers.push(Transfer {
@@ -183,6 +231,28 @@ impl TransferTransaction {
         self._token_transfer(token_id, account_id, amount, true, Some(expected_decimals))
     }
 
+    /// Add a non-approved transfer of `amount` of a token from `sender` to `receiver`.
+    ///
+    /// The decimals of `amount` are sent as the expected decimals of the token,
+    /// so the transaction fails if the token has a different amount of decimals.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `amount` is larger than [`i64::MAX`].
+    pub fn token_amount_transfer(
+        &mut self,
+        token_id: TokenId,
+        sender: AccountId,
+        receiver: AccountId,
+        amount: TokenAmount,
+    ) -> crate::Result<&mut Self> {
+        let value = amount.to_i64()?;
+        let decimals = Some(amount.get_decimals());
+
+        Ok(self
+            ._token_transfer(token_id, sender, -value, false, decimals)
+            ._token_transfer(token_id, receiver, value, false, decimals))
+    }
+
     /// Returns all the token transfers associated associated with this transaction.
     pub fn get_token_transfers(&self) -> HashMap<TokenId, HashMap<AccountId, i64>> {
         use std::collections::hash_map::Entry;
@@ -275,6 +345,212 @@ impl TransferTransaction {
     }
 }
 
+#[cfg(feature = "serde")]
+impl TransferTransaction {
+    /// Create a transfer with the same hbar, token, and NFT transfers as a transaction from the mirror node REST API.
+    ///
+    /// `json` is a transaction object, as returned by `/api/v1/transactions`,
+    /// with any of the `transfers`, `token_transfers`, and `nft_transfers` arrays.
+    /// Approved transfers (`is_approval`) stay approved.
+    ///
+    /// The mirror node's hbar `transfers` also include the transaction fee and any staking rewards the transaction paid out,
+    /// neither of which are part of the transfer, so they're excluded:
+    /// - Each of the `staking_reward_transfers` is taken back from the account it was paid to,
+    ///   and given back to the staking reward account (`0.0.800`).
+    /// - The `charged_tx_fee` is given back to the payer (from the `transaction_id`),
+    ///   and taken back from what the `node` and the fee collection accounts (`0.0.98`, `0.0.800`, and `0.0.801`) got.
+    ///
+    /// Any other transfer to or from those accounts is kept.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `json` isn't a mirror node transaction,
+    ///   if it has a `charged_tx_fee` but no `transaction_id`,
+    ///   if the `charged_tx_fee` can't be attributed to the node and the fee collection accounts, if an amount overflows,
+    ///   or if it has an NFT mint or burn (an NFT transfer without a sender or a receiver).
+    pub fn from_mirror_transfers(json: &serde_json::Value) -> crate::Result<Self> {
+        let transfers: mirror::Transfers =
+            serde_json::from_value(json.clone()).map_err(Error::basic_parse)?;
+        let fees: mirror::Fees =
+            serde_json::from_value(json.clone()).map_err(Error::basic_parse)?;
+
+        let payer = match fees.transaction_id.as_deref() {
+            Some(it) => Some(it.parse::<crate::TransactionId>()?.account_id),
+            None if fees.charged_tx_fee != 0 => {
+                return Err(Error::basic_parse(
+                    "`charged_tx_fee` can't be excluded without a `transaction_id`",
+                ))
+            }
+            None => None,
+        };
+
+        let mut hbar_transfers = transfers
+            .transfers
+            .into_iter()
+            .map(|it| Ok((it.account.parse()?, it.amount, it.is_approval)))
+            .collect::<crate::Result<Vec<(AccountId, i64, bool)>>>()?;
+
+        for it in fees.staking_reward_transfers {
+            let account_id: AccountId = it.account.parse()?;
+            let staking_reward_account =
+                AccountId::new(account_id.shard, account_id.realm, STAKING_REWARD_ACCOUNT_NUM);
+
+            let amount = it.amount.checked_neg().ok_or_else(mirror_overflow)?;
+            add_mirror_hbar_change(&mut hbar_transfers, account_id, amount)?;
+            add_mirror_hbar_change(&mut hbar_transfers, staking_reward_account, it.amount)?;
+        }
+
+        if let Some(payer) = payer {
+            add_mirror_hbar_change(&mut hbar_transfers, payer, fees.charged_tx_fee)?;
+
+            let node = fees.node.as_deref().map(str::parse::<AccountId>).transpose()?;
+            let fee_collection_accounts = FEE_COLLECTION_ACCOUNT_NUMS
+                .map(|num| AccountId::new(payer.shard, payer.realm, num));
+
+            let mut fee = fees.charged_tx_fee;
+
+            for fee_account in node.into_iter().chain(fee_collection_accounts) {
+                for (_, amount, _) in
+                    hbar_transfers.iter_mut().filter(|(it, ..)| *it == fee_account)
+                {
+                    let collected = (*amount).min(fee).max(0);
+                    *amount -= collected;
+                    fee -= collected;
+                }
+            }
+
+            if fee != 0 {
+                return Err(Error::basic_parse(
+                    "`charged_tx_fee` can't be attributed to the node and the fee collection accounts",
+                ));
+            }
+        }
+
+        let mut tx = Self::new();
+
+        for (account_id, amount, is_approval) in hbar_transfers {
+            if amount == 0 {
+                continue;
+            }
+
+            tx._hbar_transfer(account_id, Hbar::from_tinybars(amount), is_approval);
+        }
+
+        for it in transfers.token_transfers {
+            tx._token_transfer(
+                it.token_id.parse()?,
+                it.account.parse()?,
+                it.amount,
+                it.is_approval,
+                None,
+            );
+        }
+
+        for it in transfers.nft_transfers {
+            let token_id: TokenId = it.token_id.parse()?;
+
+            let (Some(sender), Some(receiver)) = (it.sender_account_id, it.receiver_account_id)
+            else {
+                return Err(Error::basic_parse(format!(
+                    "NFT `{token_id}/{}` was minted or burned, not transferred",
+                    it.serial_number
+                )));
+            };
+
+            tx._nft_transfer(
+                token_id.nft(it.serial_number),
+                sender.parse()?,
+                receiver.parse()?,
+                it.is_approval,
+            );
+        }
+
+        Ok(tx)
+    }
+}
+
+/// The account that staking rewards are paid from.
+#[cfg(feature = "serde")]
+const STAKING_REWARD_ACCOUNT_NUM: u64 = 800;
+
+/// The accounts, besides the node, that transaction fees are paid to (IE, funding, staking rewards, and node rewards).
+#[cfg(feature = "serde")]
+const FEE_COLLECTION_ACCOUNT_NUMS: [u64; 3] = [98, STAKING_REWARD_ACCOUNT_NUM, 801];
+
+/// Adds `amount` to the first of `transfers` for `account_id`, or adds a transfer for it if there isn't one.
+#[cfg(feature = "serde")]
+fn add_mirror_hbar_change(
+    transfers: &mut Vec<(AccountId, i64, bool)>,
+    account_id: AccountId,
+    amount: i64,
+) -> crate::Result<()> {
+    match transfers.iter_mut().find(|(it, ..)| *it == account_id) {
+        Some((_, change, _)) => *change = change.checked_add(amount).ok_or_else(mirror_overflow)?,
+        None => transfers.push((account_id, amount, false)),
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+fn mirror_overflow() -> Error {
+    Error::basic_parse("an hbar transfer overflows once fees are excluded")
+}
+
+#[cfg(feature = "serde")]
+pub(crate) mod mirror {
+    //! The transfers of a transaction, as returned by the mirror node REST API.
+
+    #[derive(serde_derive::Deserialize)]
+    pub(crate) struct Transfers {
+        #[serde(default)]
+        pub(crate) transfers: Vec<HbarTransfer>,
+        #[serde(default)]
+        pub(crate) token_transfers: Vec<TokenTransfer>,
+        #[serde(default)]
+        pub(crate) nft_transfers: Vec<NftTransfer>,
+    }
+
+    /// The parts of a transaction that say which of its hbar transfers were fees, or staking rewards.
+    #[derive(serde_derive::Deserialize)]
+    pub(crate) struct Fees {
+        #[serde(default)]
+        pub(crate) transaction_id: Option<String>,
+        #[serde(default)]
+        pub(crate) charged_tx_fee: i64,
+        #[serde(default)]
+        pub(crate) node: Option<String>,
+        #[serde(default)]
+        pub(crate) staking_reward_transfers: Vec<HbarTransfer>,
+    }
+
+    #[derive(serde_derive::Deserialize)]
+    pub(crate) struct HbarTransfer {
+        pub(crate) account: String,
+        pub(crate) amount: i64,
+        #[serde(default)]
+        pub(crate) is_approval: bool,
+    }
+
+    #[derive(serde_derive::Deserialize)]
+    pub(crate) struct TokenTransfer {
+        pub(crate) token_id: String,
+        pub(crate) account: String,
+        pub(crate) amount: i64,
+        #[serde(default)]
+        pub(crate) is_approval: bool,
+    }
+
+    #[derive(serde_derive::Deserialize)]
+    pub(crate) struct NftTransfer {
+        pub(crate) token_id: String,
+        pub(crate) serial_number: u64,
+        pub(crate) sender_account_id: Option<String>,
+        pub(crate) receiver_account_id: Option<String>,
+        #[serde(default)]
+        pub(crate) is_approval: bool,
+    }
+}
+
 impl TransactionExecute for TransferTransactionData {
     // noinspection DuplicatedCode
     fn execute(
@@ -286,7 +562,30 @@ impl TransactionExecute for TransferTransactionData {
     }
 }
 
-impl TransactionData for TransferTransactionData {}
+impl TransactionData for TransferTransactionData {
+    fn validate_data(&self, problems: &mut Vec<ValidationProblem>) {
+        let sum: i128 = self.transfers.iter().map(|it| i128::from(it.amount)).sum();
+
+        if sum != 0 {
+            problems.push(ValidationProblem::UnbalancedTransfers { token_id: None, sum });
+        }
+
+        check_token_transfers(problems, &self.token_transfers);
+    }
+
+    fn describe_data(&self, description: &mut TransactionDescription) {
+        for transfer in &self.transfers {
+            description.transfer(DescribedTransfer {
+                token_id: None,
+                account_id: transfer.account_id,
+                amount: transfer.amount,
+                is_approval: transfer.is_approval,
+            });
+        }
+
+        describe_token_transfers(description, &self.token_transfers);
+    }
+}
 
 impl ValidateChecksums for TransferTransactionData {
     fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
@@ -427,6 +726,8 @@ impl ToProtobuf for TransferTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use expect_test::expect;
 
     use crate::transaction::test_helpers::{
@@ -437,6 +738,7 @@ mod tests {
         AccountId,
         AnyTransaction,
         Hbar,
+        TokenAmount,
         TokenId,
         TransferTransaction,
     };
@@ -843,4 +1145,180 @@ mod tests {
         tx.token_transfer_with_decimals(TOKEN, AccountId::new(0, 0, 7), -100, 5);
         assert_eq!(tx.get_token_decimals().get(&TOKEN), Some(&5));
     }
+
+    #[test]
+    fn token_amount_transfer() {
+        let mut tx = TransferTransaction::new();
+        const TOKEN: TokenId = TokenId::new(0, 0, 5);
+
+        tx.token_amount_transfer(
+            TOKEN,
+            AccountId::new(0, 0, 7),
+            AccountId::new(0, 0, 8),
+            TokenAmount::new(10055, 2),
+        )
+        .unwrap();
+
+        assert_eq!(
+            tx.get_token_transfers()[&TOKEN],
+            HashMap::from([(AccountId::new(0, 0, 7), -10055), (AccountId::new(0, 0, 8), 10055)])
+        );
+        assert_eq!(tx.get_token_decimals().get(&TOKEN), Some(&2));
+
+        let result = tx.token_amount_transfer(
+            TOKEN,
+            AccountId::new(0, 0, 7),
+            AccountId::new(0, 0, 8),
+            TokenAmount::new(u64::MAX, 2),
+        );
+
+        assert!(matches!(result, Err(crate::Error::BasicParse(_))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_mirror_transfers() {
+        let json = serde_json::json!({
+            "transaction_id": "0.0.5006-1554158542-000000000",
+            "transfers": [
+                { "account": "0.0.5006", "amount": -800, "is_approval": false },
+                { "account": "0.0.5008", "amount": 800, "is_approval": true },
+            ],
+            "token_transfers": [
+                { "token_id": "0.0.5", "account": "0.0.5006", "amount": -10, "is_approval": false },
+                { "token_id": "0.0.5", "account": "0.0.5008", "amount": 10, "is_approval": false },
+            ],
+            "nft_transfers": [{
+                "token_id": "0.0.3",
+                "serial_number": 2,
+                "sender_account_id": "0.0.5006",
+                "receiver_account_id": "0.0.5008",
+                "is_approval": false,
+            }],
+        });
+
+        let tx = TransferTransaction::from_mirror_transfers(&json).unwrap();
+
+        let mut expected = TransferTransaction::new();
+        expected
+            .hbar_transfer(AccountId::new(0, 0, 5006), Hbar::from_tinybars(-800))
+            .approved_hbar_transfer(AccountId::new(0, 0, 5008), Hbar::from_tinybars(800))
+            .token_transfer(TokenId::new(0, 0, 5), AccountId::new(0, 0, 5006), -10)
+            .token_transfer(TokenId::new(0, 0, 5), AccountId::new(0, 0, 5008), 10)
+            .nft_transfer(
+                TokenId::new(0, 0, 3).nft(2),
+                AccountId::new(0, 0, 5006),
+                AccountId::new(0, 0, 5008),
+            );
+
+        assert_eq!(tx.data(), expected.data());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_mirror_transfers_excludes_fees() {
+        let json = serde_json::json!({
+            "transaction_id": "0.0.5006-1554158542-000000000",
+            "charged_tx_fee": 100,
+            "node": "0.0.3",
+            "transfers": [
+                { "account": "0.0.3", "amount": 5, "is_approval": false },
+                { "account": "0.0.98", "amount": 80, "is_approval": false },
+                { "account": "0.0.800", "amount": -35, "is_approval": false },
+                { "account": "0.0.5006", "amount": -900, "is_approval": false },
+                { "account": "0.0.5008", "amount": 850, "is_approval": false },
+            ],
+            "staking_reward_transfers": [
+                { "account": "0.0.5008", "amount": 50 },
+            ],
+        });
+
+        let tx = TransferTransaction::from_mirror_transfers(&json).unwrap();
+
+        assert_eq!(
+            tx.get_hbar_transfers(),
+            HashMap::from([
+                (AccountId::new(0, 0, 5006), Hbar::from_tinybars(-800)),
+                (AccountId::new(0, 0, 5008), Hbar::from_tinybars(800)),
+            ])
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_mirror_transfers_keeps_transfers_to_system_accounts() {
+        let json = serde_json::json!({
+            "transaction_id": "0.0.5006-1554158542-000000000",
+            "charged_tx_fee": 100,
+            "node": "0.0.3",
+            "transfers": [
+                { "account": "0.0.3", "amount": 5, "is_approval": false },
+                { "account": "0.0.98", "amount": 80, "is_approval": false },
+                { "account": "0.0.800", "amount": 1015, "is_approval": false },
+                { "account": "0.0.5006", "amount": -1100, "is_approval": false },
+            ],
+        });
+
+        let tx = TransferTransaction::from_mirror_transfers(&json).unwrap();
+
+        assert_eq!(
+            tx.get_hbar_transfers(),
+            HashMap::from([
+                (AccountId::new(0, 0, 800), Hbar::from_tinybars(1000)),
+                (AccountId::new(0, 0, 5006), Hbar::from_tinybars(-1000)),
+            ])
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_mirror_transfers_unattributed_fee() {
+        let json = serde_json::json!({
+            "transaction_id": "0.0.5006-1554158542-000000000",
+            "charged_tx_fee": 100,
+            "node": "0.0.3",
+            "transfers": [
+                { "account": "0.0.3", "amount": 5, "is_approval": false },
+                { "account": "0.0.5006", "amount": -5, "is_approval": false },
+            ],
+        });
+
+        assert_matches::assert_matches!(
+            TransferTransaction::from_mirror_transfers(&json),
+            Err(crate::Error::BasicParse(_))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_mirror_transfers_fee_without_payer() {
+        let json = serde_json::json!({
+            "charged_tx_fee": 100,
+            "transfers": [{ "account": "0.0.5006", "amount": -100 }],
+        });
+
+        assert_matches::assert_matches!(
+            TransferTransaction::from_mirror_transfers(&json),
+            Err(crate::Error::BasicParse(_))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_mirror_transfers_mint() {
+        let json = serde_json::json!({
+            "nft_transfers": [{
+                "token_id": "0.0.3",
+                "serial_number": 1,
+                "sender_account_id": null,
+                "receiver_account_id": "0.0.5008",
+                "is_approval": false,
+            }],
+        });
+
+        assert_matches::assert_matches!(
+            TransferTransaction::from_mirror_transfers(&json),
+            Err(crate::Error::BasicParse(_))
+        );
+    }
 }
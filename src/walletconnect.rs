@@ -0,0 +1,471 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Delegating signing to end-user wallets over WalletConnect, as specified by [HIP-820](https://hips.hedera.com/hip/hip-820).
+//!
+//! This implements the Hedera JSON-RPC methods.
+//! The WalletConnect session itself (pairing, the relay, and encryption) is left to a WalletConnect client,
+//! which is plugged in by implementing [`WalletConnectSession`].
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use base64::Engine;
+use futures_core::future::BoxFuture;
+use hedera_proto::services;
+use prost::Message;
+use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
+
+use crate::transaction::TransactionExecute;
+use crate::{
+    AccountId,
+    Error,
+    FromProtobuf,
+    LedgerId,
+    PublicKey,
+    SignaturePair,
+    Transaction,
+    TransactionHash,
+    TransactionId,
+    TransactionResponse,
+};
+
+const METHOD_SIGN_TRANSACTION: &str = "hedera_signTransaction";
+const METHOD_EXECUTE_TRANSACTION: &str = "hedera_executeTransaction";
+
+/// A JSON-RPC request to a wallet.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct WalletConnectRequest {
+    /// The CAIP-2 chain ID the request is for, IE, `hedera:testnet`.
+    pub chain_id: String,
+
+    /// The JSON-RPC method, IE, `hedera_signTransaction`.
+    pub method: &'static str,
+
+    /// The JSON-RPC params.
+    pub params: serde_json::Value,
+}
+
+/// An established WalletConnect session with a wallet.
+pub trait WalletConnectSession: Send + Sync {
+    /// Send `request` to the wallet, and return the `result` of its response.
+    ///
+    /// # Errors
+    /// - [`Error::WalletConnect`] if the request fails, or the wallet responds with an error,
+    ///   IE, the user rejected the request.
+    fn request(
+        &self,
+        request: WalletConnectRequest,
+    ) -> BoxFuture<'_, crate::Result<serde_json::Value>>;
+}
+
+/// Signs and executes transactions with the account of a wallet connected over WalletConnect.
+#[derive(Clone)]
+pub struct WalletConnectSigner {
+    session: Arc<dyn WalletConnectSession>,
+    account_id: AccountId,
+    ledger_id: LedgerId,
+}
+
+impl fmt::Debug for WalletConnectSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WalletConnectSigner")
+            .field("account_id", &self.account_id)
+            .field("ledger_id", &self.ledger_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WalletConnectSigner {
+    /// Create a signer for the account `account_id` on the network `ledger_id`, connected over `session`.
+    #[must_use]
+    pub fn new(
+        session: impl WalletConnectSession + 'static,
+        account_id: AccountId,
+        ledger_id: LedgerId,
+    ) -> Self {
+        Self {
+            session: Arc::new(session).unsize(Coercion!(to dyn WalletConnectSession)),
+            account_id,
+            ledger_id,
+        }
+    }
+
+    /// Returns the account that signs transactions.
+    #[must_use]
+    pub fn get_account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the network of the account.
+    #[must_use]
+    pub fn get_ledger_id(&self) -> &LedgerId {
+        &self.ledger_id
+    }
+
+    /// Returns the CAIP-2 chain ID of the network, IE, `hedera:testnet`.
+    ///
+    /// Networks other than mainnet, testnet, and previewnet are `hedera:devnet`.
+    #[must_use]
+    pub fn chain_id(&self) -> String {
+        if self.ledger_id.is_known_network() {
+            format!("hedera:{}", self.ledger_id)
+        } else {
+            "hedera:devnet".to_owned()
+        }
+    }
+
+    /// Returns the CAIP-10 account ID of the account, IE, `hedera:testnet:0.0.1234`.
+    #[must_use]
+    pub fn signer_account_id(&self) -> String {
+        format!("{}:{}", self.chain_id(), self.account_id)
+    }
+
+    /// Sign `transaction` with the wallet, using `hedera_signTransaction`.
+    ///
+    /// The wallet is asked to sign the transaction once for every node (and chunk) it may be submitted to,
+    /// so freeze it with a single node to only ask once.
+    ///
+    /// The signatures are only valid for the transaction as it is,
+    /// so freeze it with [`regenerate_transaction_id(false)`](Transaction::regenerate_transaction_id).
+    ///
+    /// # Errors
+    /// - [`Error::WalletConnect`] if a request fails, the user rejects it, or the wallet responds with an invalid signature map,
+    ///   or with a signature that doesn't verify against the transaction body.
+    /// - [`Error::UnsupportedKeyAlgorithm`] if the wallet signs with a key that isn't Ed25519 or ECDSA(secp256k1).
+    ///
+    /// # Panics
+    /// - If `!transaction.is_frozen()`.
+    pub async fn sign_transaction<D: TransactionExecute>(
+        &self,
+        transaction: &mut Transaction<D>,
+    ) -> crate::Result<()> {
+        assert!(transaction.is_frozen(), "transaction must be frozen to sign with a wallet");
+
+        let body_bytes: Vec<_> = transaction
+            .make_sources()?
+            .signed_transactions()
+            .iter()
+            .map(|it| it.body_bytes.clone())
+            .collect();
+
+        let mut signatures: HashMap<PublicKey, HashMap<Vec<u8>, Vec<u8>>> = HashMap::new();
+
+        for body_bytes in body_bytes {
+            let params = serde_json::json!({
+                "signerAccountId": self.signer_account_id(),
+                "transactionBody": base64::engine::general_purpose::STANDARD.encode(&body_bytes),
+            });
+
+            let result: SignTransactionResult =
+                self.request(METHOD_SIGN_TRANSACTION, params).await?;
+
+            let signature_map = base64::engine::general_purpose::STANDARD
+                .decode(result.signature_map)
+                .map_err(Error::wallet_connect)?;
+
+            let signature_map = services::SignatureMap::decode(signature_map.as_slice())
+                .map_err(Error::wallet_connect)?;
+
+            for pair in signature_map.sig_pair {
+                let pair = SignaturePair::from_protobuf(pair)?;
+
+                pair.public_key.verify(&body_bytes, &pair.signature).map_err(|_| {
+                    Error::wallet_connect(format!(
+                        "wallet responded with an invalid signature for `{}`",
+                        pair.public_key
+                    ))
+                })?;
+
+                signatures
+                    .entry(pair.public_key)
+                    .or_default()
+                    .insert(body_bytes.clone(), pair.signature);
+            }
+        }
+
+        for (public_key, signatures) in signatures {
+            transaction.try_sign_with(public_key, move |body_bytes| {
+                signatures.get(body_bytes).cloned().ok_or_else(|| {
+                    Error::wallet_connect(format!(
+                        "wallet didn't sign transaction body for `{public_key}`"
+                    ))
+                })
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Submit `transaction`, which is already signed, through the wallet, using `hedera_executeTransaction`.
+    ///
+    /// # Errors
+    /// - [`Error::WalletConnect`] if the request fails, the user rejects it, or the wallet responds with an invalid response.
+    /// - If `freeze_with` wasn't called with an operator.
+    ///
+    /// # Panics
+    /// - If `!transaction.is_frozen()`.
+    pub async fn execute_transaction<D: TransactionExecute>(
+        &self,
+        transaction: &Transaction<D>,
+    ) -> crate::Result<TransactionResponse> {
+        let params = serde_json::json!({
+            "transactionList": base64::engine::general_purpose::STANDARD.encode(transaction.to_bytes()?),
+        });
+
+        let result: ExecuteTransactionResult =
+            self.request(METHOD_EXECUTE_TRANSACTION, params).await?;
+
+        result.into_response()
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        method: &'static str,
+        params: serde_json::Value,
+    ) -> crate::Result<T> {
+        let result = self
+            .session
+            .request(WalletConnectRequest { chain_id: self.chain_id(), method, params })
+            .await?;
+
+        serde_json::from_value(result).map_err(Error::wallet_connect)
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignTransactionResult {
+    signature_map: String,
+}
+
+#[derive(serde_derive::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExecuteTransactionResult {
+    node_id: String,
+    transaction_hash: String,
+    transaction_id: String,
+}
+
+impl ExecuteTransactionResult {
+    fn into_response(self) -> crate::Result<TransactionResponse> {
+        let transaction_hash = hex::decode(&self.transaction_hash)
+            .ok()
+            .and_then(|it| <[u8; 48]>::try_from(it).ok())
+            .ok_or_else(|| {
+                Error::wallet_connect(format!(
+                    "invalid transaction hash `{}`",
+                    self.transaction_hash
+                ))
+            })?;
+
+        Ok(TransactionResponse {
+            node_account_id: AccountId::from_str(&self.node_id)?,
+            transaction_id: TransactionId::from_str(&self.transaction_id)?,
+            transaction_hash: TransactionHash(transaction_hash),
+            validate_status: true,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use base64::Engine;
+    use futures_core::future::BoxFuture;
+    use hedera_proto::services;
+    use prost::Message;
+
+    use super::{
+        WalletConnectRequest,
+        WalletConnectSession,
+        WalletConnectSigner,
+    };
+    use crate::transaction::test_helpers::{
+        TEST_ACCOUNT_ID,
+        TEST_NODE_ACCOUNT_IDS,
+        TEST_TX_ID,
+    };
+    use crate::{
+        AccountId,
+        Error,
+        Hbar,
+        LedgerId,
+        PrivateKey,
+        TransferTransaction,
+    };
+
+    /// A wallet that signs every transaction with `key`.
+    struct MockWallet {
+        key: PrivateKey,
+    }
+
+    impl WalletConnectSession for MockWallet {
+        fn request(
+            &self,
+            request: WalletConnectRequest,
+        ) -> BoxFuture<'_, crate::Result<serde_json::Value>> {
+            let result = match request.method {
+                "hedera_signTransaction" => {
+                    let body = request.params["transactionBody"].as_str().unwrap();
+                    let body = base64::engine::general_purpose::STANDARD.decode(body).unwrap();
+
+                    let signature_map = services::SignatureMap {
+                        sig_pair: Vec::from([services::SignaturePair {
+                            pub_key_prefix: self.key.public_key().to_bytes_raw(),
+                            signature: Some(services::signature_pair::Signature::Ed25519(
+                                self.key.sign(&body),
+                            )),
+                        }]),
+                    };
+
+                    Ok(serde_json::json!({
+                        "signatureMap": base64::engine::general_purpose::STANDARD.encode(signature_map.encode_to_vec()),
+                    }))
+                }
+                "hedera_executeTransaction" => Ok(serde_json::json!({
+                    "nodeId": "0.0.3",
+                    "transactionHash": "00".repeat(48),
+                    "transactionId": "0.0.5006@1554158542.000000000",
+                })),
+                _ => Err(Error::wallet_connect("unsupported method")),
+            };
+
+            Box::pin(async move { result })
+        }
+    }
+
+    fn make_transaction() -> TransferTransaction {
+        let mut tx = TransferTransaction::new_for_tests();
+
+        tx.hbar_transfer(TEST_ACCOUNT_ID, Hbar::new(-1))
+            .hbar_transfer(AccountId::new(0, 0, 98), Hbar::new(1))
+            .freeze()
+            .unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn chain_id() {
+        let wallet = MockWallet { key: PrivateKey::generate_ed25519() };
+
+        let signer = WalletConnectSigner::new(wallet, TEST_ACCOUNT_ID, LedgerId::testnet());
+
+        assert_eq!(signer.chain_id(), "hedera:testnet");
+        assert_eq!(signer.signer_account_id(), "hedera:testnet:0.0.5006");
+    }
+
+    #[tokio::test]
+    async fn sign_transaction() {
+        let key = PrivateKey::generate_ed25519();
+        let wallet = MockWallet { key: key.clone() };
+
+        let signer = WalletConnectSigner::new(wallet, TEST_ACCOUNT_ID, LedgerId::mainnet());
+
+        let mut tx = make_transaction();
+
+        signer.sign_transaction(&mut tx).await.unwrap();
+
+        let sources = tx.make_sources().unwrap();
+
+        // one for each node.
+        assert_eq!(sources.signed_transactions().len(), TEST_NODE_ACCOUNT_IDS.len());
+
+        for signed in sources.signed_transactions() {
+            let pair = signed
+                .sig_map
+                .as_ref()
+                .unwrap()
+                .sig_pair
+                .iter()
+                .find(|it| it.pub_key_prefix == key.public_key().to_bytes_raw())
+                .unwrap();
+
+            let signature = assert_matches!(
+                &pair.signature,
+                Some(services::signature_pair::Signature::Ed25519(signature)) => signature
+            );
+
+            key.public_key().verify(&signed.body_bytes, signature).unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_transaction() {
+        let wallet = MockWallet { key: PrivateKey::generate_ed25519() };
+
+        let signer = WalletConnectSigner::new(wallet, TEST_ACCOUNT_ID, LedgerId::mainnet());
+
+        let response = signer.execute_transaction(&make_transaction()).await.unwrap();
+
+        assert_eq!(response.node_account_id, AccountId::new(0, 0, 3));
+        assert_eq!(response.transaction_id, TEST_TX_ID);
+    }
+
+    #[tokio::test]
+    async fn rejected() {
+        struct RejectingWallet;
+
+        impl WalletConnectSession for RejectingWallet {
+            fn request(
+                &self,
+                _request: WalletConnectRequest,
+            ) -> BoxFuture<'_, crate::Result<serde_json::Value>> {
+                Box::pin(async { Err(Error::wallet_connect("user rejected the request")) })
+            }
+        }
+
+        let signer =
+            WalletConnectSigner::new(RejectingWallet, TEST_ACCOUNT_ID, LedgerId::mainnet());
+
+        assert_matches!(
+            signer.sign_transaction(&mut make_transaction()).await,
+            Err(Error::WalletConnect(_))
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_signature() {
+        /// A wallet that claims to sign with `key`, but responds with a garbage signature.
+        struct ForgingWallet {
+            key: PrivateKey,
+        }
+
+        impl WalletConnectSession for ForgingWallet {
+            fn request(
+                &self,
+                _request: WalletConnectRequest,
+            ) -> BoxFuture<'_, crate::Result<serde_json::Value>> {
+                let signature_map = services::SignatureMap {
+                    sig_pair: Vec::from([services::SignaturePair {
+                        pub_key_prefix: self.key.public_key().to_bytes_raw(),
+                        signature: Some(services::signature_pair::Signature::Ed25519(Vec::from(
+                            [0; 64],
+                        ))),
+                    }]),
+                };
+
+                Box::pin(async move {
+                    Ok(serde_json::json!({
+                        "signatureMap": base64::engine::general_purpose::STANDARD.encode(signature_map.encode_to_vec()),
+                    }))
+                })
+            }
+        }
+
+        let wallet = ForgingWallet { key: PrivateKey::generate_ed25519() };
+
+        let signer = WalletConnectSigner::new(wallet, TEST_ACCOUNT_ID, LedgerId::mainnet());
+
+        assert_matches!(
+            signer.sign_transaction(&mut make_transaction()).await,
+            Err(Error::WalletConnect(_))
+        );
+    }
+}
@@ -48,61 +48,68 @@ impl FromStr for KeyType {
     }
 }
 
+/// Returns an `INVALID_PARAMS` error for the common transaction parameter `name`.
+pub(crate) fn invalid_param(name: &str, error: impl ToString) -> ErrorObjectOwned {
+    ErrorObject::owned(INVALID_PARAMS_CODE, format!("{name}: {}", error.to_string()), None::<()>)
+}
+
 pub(crate) fn fill_common_transaction_params<D>(
     transaction: &mut Transaction<D>,
     common_transaction_params: &HashMap<String, Value>,
-) {
-    if let Some(transaction_id) = common_transaction_params.get("transactionId") {
-        match transaction_id {
-            Value::String(transaction_id) => {
-                transaction
-                    .transaction_id(TransactionId::from_str(transaction_id.as_str()).unwrap());
-            }
-            _ => {}
-        }
+) -> Result<(), ErrorObjectOwned> {
+    if let Some(Value::String(transaction_id)) = common_transaction_params.get("transactionId") {
+        transaction.transaction_id(
+            TransactionId::from_str(transaction_id)
+                .map_err(|e| invalid_param("transactionId", e))?,
+        );
     }
 
-    if let Some(node_id) = common_transaction_params.get("nodeId") {
-        match node_id {
-            Value::String(node_id) => {
-                transaction.node_account_ids([AccountId::from_str(&node_id.as_str()).unwrap()]);
-            }
-            _ => {}
-        }
+    if let Some(Value::String(node_id)) = common_transaction_params.get("nodeId") {
+        transaction.node_account_ids([
+            AccountId::from_str(node_id).map_err(|e| invalid_param("nodeId", e))?
+        ]);
     }
 
-    if let Some(max_fee) = common_transaction_params.get("maxTransactionFee") {
-        match max_fee {
-            Value::String(max_fee) => {
-                transaction.max_transaction_fee(Hbar::from_tinybars(
-                    max_fee.as_str().parse::<i64>().unwrap(),
-                ));
-            }
-            _ => {}
-        }
+    if let Some(Value::String(max_fee)) = common_transaction_params.get("maxTransactionFee") {
+        transaction.max_transaction_fee(Hbar::from_tinybars(
+            max_fee.parse::<i64>().map_err(|e| invalid_param("maxTransactionFee", e))?,
+        ));
     }
 
-    if let Some(transaction_valid_duration) =
+    if let Some(Value::String(transaction_valid_duration)) =
         common_transaction_params.get("transactionValidDuration")
     {
-        match transaction_valid_duration {
-            Value::String(transaction_valid_duration) => {
-                transaction.transaction_valid_duration(Duration::seconds(
-                    transaction_valid_duration.as_str().parse::<i64>().unwrap(),
-                ));
-            }
-            _ => {}
-        }
+        transaction.transaction_valid_duration(Duration::seconds(
+            transaction_valid_duration
+                .parse::<i64>()
+                .map_err(|e| invalid_param("transactionValidDuration", e))?,
+        ));
     }
 
-    if let Some(memo) = common_transaction_params.get("memo") {
-        match memo {
-            Value::String(memo) => {
-                transaction.transaction_memo(memo.as_str());
-            }
-            _ => {}
+    if let Some(Value::String(memo)) = common_transaction_params.get("memo") {
+        transaction.transaction_memo(memo.as_str());
+    }
+
+    Ok(())
+}
+
+/// Signs `transaction` with the `signers` of the common transaction parameters.
+pub(crate) fn sign_with_common_signers<D>(
+    transaction: &mut Transaction<D>,
+    common_transaction_params: &HashMap<String, Value>,
+) -> Result<(), ErrorObjectOwned> {
+    let Some(Value::Array(signers)) = common_transaction_params.get("signers") else {
+        return Ok(());
+    };
+
+    for signer in signers {
+        if let Value::String(signer) = signer {
+            transaction
+                .sign(PrivateKey::from_str_der(signer).map_err(|e| invalid_param("signers", e))?);
         }
     }
+
+    Ok(())
 }
 
 pub(crate) fn generate_key_helper(
@@ -0,0 +1,80 @@
+//! A JSON-RPC server that maps the calls of the [hiero SDK TCK](https://github.com/hiero-ledger/hiero-sdk-tck)
+//! onto this SDK, so it can be checked against the same test suite as the other SDKs.
+//!
+//! The `hiero-sdk-tck` binary runs the server on its own,
+//! [`run_server`] embeds it in another program (IE, a test harness that also starts a local network).
+//!
+//! This crate is how the server is opted into, there's no `tck` feature of `hedera`,
+//! since the server depends on `hedera` (see the "TCK Server" section of the README).
+
+use std::net::SocketAddr;
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+use std::sync::Arc;
+
+use futures_util::future::BoxFuture;
+use jsonrpsee::server::middleware::rpc::{
+    RpcService,
+    RpcServiceT,
+};
+use jsonrpsee::server::{
+    RpcServiceBuilder,
+    Server,
+    ServerHandle,
+};
+use jsonrpsee::types::Request;
+use jsonrpsee::MethodResponse;
+pub use methods::{
+    RpcServer,
+    RpcServerImpl,
+};
+
+mod errors;
+mod helpers;
+pub mod methods;
+pub mod responses;
+
+/// The address the TCK connects to by default.
+pub const DEFAULT_SERVER_ADDRESS: &str = "127.0.0.1:8544";
+
+/// Start the TCK server on `address`.
+///
+/// Returns the address the server is bound to (IE, to find the port when binding to port `0`),
+/// and a handle that stops the server when [`ServerHandle::stop`] is called.
+pub async fn run_server(address: &str) -> anyhow::Result<(SocketAddr, ServerHandle)> {
+    let m = RpcServiceBuilder::new().layer_fn(move |service: RpcService| TckMiddleware {
+        service,
+        count: Arc::new(AtomicUsize::new(0)),
+    });
+
+    let server = Server::builder().set_rpc_middleware(m).build(address).await?;
+
+    let addr = server.local_addr()?;
+    let handle = server.start(RpcServerImpl.into_rpc());
+
+    Ok((addr, handle))
+}
+
+#[derive(Clone)]
+struct TckMiddleware<S> {
+    service: S,
+    count: Arc<AtomicUsize>,
+}
+
+impl<'a, S> RpcServiceT<'a> for TckMiddleware<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+{
+    type Future = BoxFuture<'a, MethodResponse>;
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        let count = self.count.clone();
+        let service = self.service.clone();
+        Box::pin(async move {
+            let rp = service.call(req).await;
+            count.fetch_add(1, Ordering::SeqCst);
+            rp
+        })
+    }
+}
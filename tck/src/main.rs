@@ -1,40 +1,28 @@
-use std::net::SocketAddr;
 use std::sync::atomic::{
     AtomicBool,
-    AtomicUsize,
     Ordering,
 };
 use std::sync::Arc;
 
-use futures_util::future::BoxFuture;
-use jsonrpsee::server::middleware::rpc::{
-    RpcService,
-    RpcServiceT,
-};
-use jsonrpsee::server::{
-    RpcServiceBuilder,
-    Server,
-};
-use jsonrpsee::types::Request;
-use jsonrpsee::MethodResponse;
-use methods::{
-    RpcServer,
-    RpcServerImpl,
+use hiero_sdk_tck::{
+    run_server,
+    DEFAULT_SERVER_ADDRESS,
 };
 use tokio::signal;
 
-mod errors;
-mod helpers;
-pub(crate) mod methods;
-mod responses;
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     tracing_subscriber::fmt().init();
 
-    let server_addr = run_server().await?;
+    // the TCK expects the default address, but it can be moved, IE, to run several servers side by side.
+    let address =
+        std::env::var("TCK_SERVER_ADDRESS").unwrap_or_else(|_| DEFAULT_SERVER_ADDRESS.to_owned());
+
+    let (server_addr, handle) = run_server(&address).await?;
     let url = format!("http://{}", server_addr);
 
+    tokio::spawn(handle.stopped());
+
     tracing::info!("Server is running at {}", url);
 
     let running = Arc::new(AtomicBool::new(true));
@@ -52,41 +40,3 @@ async fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
-
-async fn run_server() -> anyhow::Result<SocketAddr> {
-    let m = RpcServiceBuilder::new().layer_fn(move |service: RpcService| TckMiddleware {
-        service,
-        count: Arc::new(AtomicUsize::new(0)),
-    });
-
-    let server = Server::builder().set_rpc_middleware(m).build("127.0.0.1:8544").await?;
-
-    let addr = server.local_addr()?;
-    let handle = server.start(RpcServerImpl.into_rpc());
-
-    tokio::spawn(handle.stopped());
-
-    Ok(addr)
-}
-
-#[derive(Clone)]
-struct TckMiddleware<S> {
-    service: S,
-    count: Arc<AtomicUsize>,
-}
-
-impl<'a, S> RpcServiceT<'a> for TckMiddleware<S>
-where
-    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
-{
-    type Future = BoxFuture<'a, MethodResponse>;
-    fn call(&self, req: Request<'a>) -> Self::Future {
-        let count = self.count.clone();
-        let service = self.service.clone();
-        Box::pin(async move {
-            let rp = service.call(req).await;
-            count.fetch_add(1, Ordering::SeqCst);
-            rp
-        })
-    }
-}
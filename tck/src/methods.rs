@@ -7,6 +7,7 @@ use std::sync::{
 
 use hedera::{
     AccountCreateTransaction,
+    AccountDeleteTransaction,
     AccountId,
     AccountUpdateTransaction,
     Client,
@@ -38,9 +39,12 @@ use crate::helpers::{
     fill_common_transaction_params,
     generate_key_helper,
     get_hedera_key,
+    invalid_param,
+    sign_with_common_signers,
 };
 use crate::responses::{
     AccountCreateResponse,
+    AccountDeleteResponse,
     AccountUpdateResponse,
     GenerateKeyResponse,
 };
@@ -124,6 +128,18 @@ pub trait Rpc {
         common_transaction_params: Option<HashMap<String, Value>>,
     ) -> Result<AccountUpdateResponse, ErrorObjectOwned>;
 
+    /*
+    / Specification:
+    / https://github.com/hiero-ledger/hiero-sdk-tck/blob/main/test-specifications/crypto-service/accountDeleteTransaction.md#deleteAccount
+    */
+    #[method(name = "deleteAccount")]
+    async fn delete_account(
+        &self,
+        delete_account_id: Option<String>,
+        transfer_account_id: Option<String>,
+        common_transaction_params: Option<HashMap<String, Value>>,
+    ) -> Result<AccountDeleteResponse, ErrorObjectOwned>;
+
     /*
     / Specification:
     / https://github.com/hiero-ledger/hiero-sdk-tck/blob/main/test-specifications/token-service/tokenClaimAirdropTransaction.md#tokenClaim
@@ -318,20 +334,11 @@ impl RpcServer for RpcServerImpl {
         }
 
         if let Some(common_transaction_params) = common_transaction_params {
-            let _ =
-                fill_common_transaction_params(&mut account_create_tx, &common_transaction_params);
+            fill_common_transaction_params(&mut account_create_tx, &common_transaction_params)?;
 
             account_create_tx.freeze_with(&client).unwrap();
 
-            if let Some(signers) = common_transaction_params.get("signers") {
-                if let Value::Array(signers) = signers {
-                    for signer in signers {
-                        if let Value::String(signer_str) = signer {
-                            account_create_tx.sign(PrivateKey::from_str_der(signer_str).unwrap());
-                        }
-                    }
-                }
-            }
+            sign_with_common_signers(&mut account_create_tx, &common_transaction_params)?;
         }
 
         let tx_response =
@@ -427,20 +434,11 @@ impl RpcServer for RpcServerImpl {
         }
 
         if let Some(common_transaction_params) = common_transaction_params {
-            let _ =
-                fill_common_transaction_params(&mut account_update_tx, &common_transaction_params);
+            fill_common_transaction_params(&mut account_update_tx, &common_transaction_params)?;
 
             account_update_tx.freeze_with(&client).unwrap();
 
-            if let Some(signers) = common_transaction_params.get("signers") {
-                if let Value::Array(signers) = signers {
-                    for signer in signers {
-                        if let Value::String(signer_str) = signer {
-                            account_update_tx.sign(PrivateKey::from_str_der(signer_str).unwrap());
-                        }
-                    }
-                }
-            }
+            sign_with_common_signers(&mut account_update_tx, &common_transaction_params)?;
         }
 
         let tx_response =
@@ -452,6 +450,56 @@ impl RpcServer for RpcServerImpl {
         Ok(AccountUpdateResponse { status: tx_receipt.status.as_str_name().to_string() })
     }
 
+    async fn delete_account(
+        &self,
+        delete_account_id: Option<String>,
+        transfer_account_id: Option<String>,
+        common_transaction_params: Option<HashMap<String, Value>>,
+    ) -> Result<AccountDeleteResponse, ErrorObjectOwned> {
+        let client = {
+            let guard = GLOBAL_SDK_CLIENT.lock().unwrap();
+            guard
+                .as_ref()
+                .ok_or_else(|| {
+                    ErrorObject::owned(
+                        INTERNAL_ERROR_CODE,
+                        "Client not initialized".to_string(),
+                        None::<()>,
+                    )
+                })?
+                .clone()
+        };
+
+        let mut account_delete_tx = AccountDeleteTransaction::new();
+
+        if let Some(delete_account_id) = delete_account_id {
+            account_delete_tx.account_id(
+                AccountId::from_str(&delete_account_id)
+                    .map_err(|e| invalid_param("deleteAccountId", e))?,
+            );
+        }
+
+        if let Some(transfer_account_id) = transfer_account_id {
+            account_delete_tx.transfer_account_id(
+                AccountId::from_str(&transfer_account_id)
+                    .map_err(|e| invalid_param("transferAccountId", e))?,
+            );
+        }
+
+        if let Some(common_transaction_params) = &common_transaction_params {
+            fill_common_transaction_params(&mut account_delete_tx, common_transaction_params)?;
+            account_delete_tx.freeze_with(&client).map_err(|e| from_hedera_error(e.into()))?;
+            sign_with_common_signers(&mut account_delete_tx, common_transaction_params)?;
+        }
+
+        let tx_response =
+            account_delete_tx.execute(&client).await.map_err(|e| from_hedera_error(e))?;
+        let tx_receipt =
+            tx_response.get_receipt(&client).await.map_err(|e| from_hedera_error(e))?;
+
+        Ok(AccountDeleteResponse { status: tx_receipt.status.as_str_name().to_string() })
+    }
+
     async fn token_claim(
         &self,
         pending_airdrop_ids: Vec<HashMap<String, String>>,
@@ -530,17 +578,9 @@ impl RpcServer for RpcServerImpl {
         tx.pending_airdrop_ids(parsed_ids);
 
         if let Some(common_transaction_params) = &common_transaction_params {
-            let _ = fill_common_transaction_params(&mut tx, common_transaction_params);
+            fill_common_transaction_params(&mut tx, common_transaction_params)?;
             tx.freeze_with(&client).map_err(|e| from_hedera_error(e.into()))?;
-            if let Some(signers) = common_transaction_params.get("signers") {
-                if let Value::Array(signers) = signers {
-                    for signer in signers {
-                        if let Value::String(signer_str) = signer {
-                            tx.sign(PrivateKey::from_str_der(signer_str).unwrap());
-                        }
-                    }
-                }
-            }
+            sign_with_common_signers(&mut tx, common_transaction_params)?;
         }
 
         let tx_response = tx.execute(&client).await.map_err(|e| from_hedera_error(e))?;
@@ -628,17 +668,9 @@ impl RpcServer for RpcServerImpl {
         tx.pending_airdrop_ids(parsed_ids);
 
         if let Some(common_transaction_params) = &common_transaction_params {
-            let _ = fill_common_transaction_params(&mut tx, common_transaction_params);
+            fill_common_transaction_params(&mut tx, common_transaction_params)?;
             tx.freeze_with(&client).map_err(|e| from_hedera_error(e.into()))?;
-            if let Some(signers) = common_transaction_params.get("signers") {
-                if let Value::Array(signers) = signers {
-                    for signer in signers {
-                        if let Value::String(signer_str) = signer {
-                            tx.sign(PrivateKey::from_str_der(signer_str).unwrap());
-                        }
-                    }
-                }
-            }
+            sign_with_common_signers(&mut tx, common_transaction_params)?;
         }
 
         let tx_response = tx.execute(&client).await.map_err(|e| from_hedera_error(e))?;
@@ -0,0 +1,40 @@
+use hedera::{
+    ContractCallQuery,
+    HederaAccountService,
+};
+
+use crate::common::{
+    setup_nonfree,
+    TestEnvironment,
+};
+
+#[tokio::test]
+async fn is_authorized_raw() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let Some(op) = &config.operator else {
+        log::debug!("skipping test due to missing operator");
+        return Ok(());
+    };
+
+    let message = b"hello, hedera";
+    let signature = op.private_key.sign(message);
+
+    let result = ContractCallQuery::new()
+        .contract_id(HederaAccountService::CONTRACT_ID)
+        .gas(1_000_000)
+        .function_parameters(HederaAccountService::is_authorized_raw_call_data(
+            &op.account_id,
+            &op.private_key.public_key(),
+            message,
+            &signature,
+        )?)
+        .execute(&client)
+        .await?;
+
+    assert_eq!(result.get_bool(0), Some(true));
+
+    Ok(())
+}
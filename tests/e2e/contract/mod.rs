@@ -11,6 +11,7 @@ mod create;
 mod create_flow;
 mod delete;
 mod execute;
+mod hedera_account_service;
 mod info;
 mod nonce_info;
 mod update;
@@ -68,6 +68,37 @@ async fn large_message() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn large_message_chunked_receipts() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    let topic = Topic::create(&client).await?;
+
+    let response = TopicMessageSubmitTransaction::new()
+        .topic_id(topic.id)
+        .max_chunks(15)
+        .message(resources::BIG_CONTENTS)
+        .execute_chunked(&client)
+        .await?;
+
+    assert_eq!(response.len(), 14);
+
+    let transaction_ids = response.get_transaction_ids();
+    let receipts = response.get_all_receipts(&client).await?;
+
+    assert_eq!(receipts.len(), 14);
+    assert_eq!(receipts.last().unwrap().topic_sequence_number, 14);
+
+    let unique_ids: std::collections::HashSet<_> = transaction_ids.iter().collect();
+    assert_eq!(unique_ids.len(), transaction_ids.len());
+
+    topic.delete(&client).await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn missing_topic_id_fails() -> anyhow::Result<()> {
     let Some(TestEnvironment { config: _, client }) = setup_nonfree() else {